@@ -1,11 +1,13 @@
 use byte_unit::{Byte, Unit};
 use core::hint::black_box;
 use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use enrede::encoding::NullTerminable;
 use enrede::encoding::{
-    ArrayLike, Ascii, ExtendedAscii, Iso8859_15, Iso8859_2, JisX0201, JisX0208, MacRoman, Utf16BE,
-    Utf16LE, Utf32, Win1251, Win1252, Win1252Loose,
+    ArrayLike, Ascii, Big5, ExtendedAscii, Iso8859_15, Iso8859_2, JisX0201, JisX0208, MacRoman,
+    ShiftJis, ShiftJisAscii, Utf16BE, Utf16LE, Utf32BE, Utf32LE, Utf8, Win1251, Win1252,
+    Win1252Loose,
 };
-use enrede::{Encoding, String};
+use enrede::{CString, Encoding, String};
 use rand::distributions::Distribution;
 use rand::{thread_rng, Rng};
 
@@ -67,32 +69,370 @@ fn bench_decode<E: Encoding + Distribution<char>>(c: &mut Criterion) {
     });
 }
 
+fn bench_recode<E, E2>(c: &mut Criterion, bytes: Byte)
+where
+    E: Encoding + Distribution<char>,
+    E2: Encoding,
+{
+    let mut rng = thread_rng();
+    c.bench_function(
+        &format!(
+            "{}::recode::<{}> ({})",
+            E2::shorthand(),
+            E::shorthand(),
+            bytes
+        ),
+        |b| {
+            b.iter_batched_ref(
+                || {
+                    // Mostly ASCII, as is typical for real-world text
+                    let mut str = String::<E>::new();
+                    while (str.len() as u64) < bytes.as_u64() {
+                        if rng.gen_bool(0.9) {
+                            str.push(rng.gen_range('\0'..'\u{80}'));
+                        } else {
+                            str.push(rng.sample(E::default()));
+                        }
+                    }
+                    let out = vec![0; str.len() * 4];
+                    (str, out)
+                },
+                |(str, out)| E2::recode(black_box(&**str), black_box(out)).unwrap(),
+                BatchSize::LargeInput,
+            )
+        },
+    );
+}
+
+/// Recodes a run of a single multibyte character, which is the worst case for encodings that find
+/// character boundaries by scanning back through the string. Recoding shouldn't need to find any,
+/// so this is linear in the input length.
+fn bench_recode_run<E: Encoding, E2: Encoding>(c: &mut Criterion, bytes: Byte, char: char) {
+    let mut str = String::<E>::new();
+    while (str.len() as u64) < bytes.as_u64() {
+        str.push(char);
+    }
+    let mut out = vec![0; str.len() * 4];
+    c.bench_function(
+        &format!(
+            "{}::recode::<{}> run ({})",
+            E2::shorthand(),
+            E::shorthand(),
+            bytes
+        ),
+        |b| b.iter(|| E2::recode(black_box(&*str), black_box(&mut out)).unwrap()),
+    );
+    c.bench_function(
+        &format!(
+            "{}::recode_lossy::<{}> run ({})",
+            E2::shorthand(),
+            E::shorthand(),
+            bytes
+        ),
+        |b| b.iter(|| black_box(&*str).recode_lossy::<E2>()),
+    );
+}
+
+fn bench_recode_c<E, E2>(c: &mut Criterion, bytes: Byte)
+where
+    E: NullTerminable + Distribution<char>,
+    E2: NullTerminable,
+{
+    let mut rng = thread_rng();
+    let mut setup = || {
+        let mut str = String::<E>::new();
+        while (str.len() as u64) < bytes.as_u64() {
+            let c = rng.sample(E::default());
+            if c != '\0' {
+                str.push(c);
+            }
+        }
+        str.into_cstring().unwrap()
+    };
+
+    c.bench_function(
+        &format!(
+            "{}::recode_c::<{}> ({})",
+            E2::shorthand(),
+            E::shorthand(),
+            bytes
+        ),
+        |b| {
+            b.iter_batched_ref(
+                &mut setup,
+                |str| black_box(&**str).recode_c::<E2>().unwrap(),
+                BatchSize::LargeInput,
+            )
+        },
+    );
+    // The equivalent without `recode_c`, which checks the output for null bytes
+    c.bench_function(
+        &format!(
+            "{}::recode::<{}>.into_cstring ({})",
+            E2::shorthand(),
+            E::shorthand(),
+            bytes
+        ),
+        |b| {
+            b.iter_batched_ref(
+                &mut setup,
+                |str| {
+                    black_box(&**str)
+                        .recode::<E2>()
+                        .unwrap()
+                        .into_cstring()
+                        .unwrap()
+                },
+                BatchSize::LargeInput,
+            )
+        },
+    );
+}
+
+fn bench_cstring_new<E: NullTerminable + Distribution<char>>(c: &mut Criterion, bytes: Byte) {
+    let mut rng = thread_rng();
+    let mut setup = || {
+        let mut str = String::<E>::new();
+        while (str.len() as u64) < bytes.as_u64() {
+            let c = rng.sample(E::default());
+            if c != '\0' {
+                str.push(c);
+            }
+        }
+        str
+    };
+
+    c.bench_function(
+        &format!("{}::CString::new(Vec<u8>) ({})", E::shorthand(), bytes),
+        |b| {
+            b.iter_batched(
+                || setup().into_bytes(),
+                |bytes| CString::<E>::new(black_box(bytes)).unwrap(),
+                BatchSize::LargeInput,
+            )
+        },
+    );
+    // Already valid for the encoding, so only checked for null bytes
+    c.bench_function(
+        &format!("{}::CString::new(String) ({})", E::shorthand(), bytes),
+        |b| {
+            b.iter_batched(
+                &mut setup,
+                |str| CString::new(black_box(str)).unwrap(),
+                BatchSize::LargeInput,
+            )
+        },
+    );
+}
+
+fn bench_char_index<E: Encoding + Distribution<char>>(c: &mut Criterion, bytes: Byte) {
+    let mut rng = thread_rng();
+    c.bench_function(
+        &format!("{}::char_to_byte_index ({})", E::shorthand(), bytes),
+        |b| {
+            b.iter_batched_ref(
+                || {
+                    let mut str = String::<E>::new();
+                    while (str.len() as u64) < bytes.as_u64() {
+                        str.push(rng.sample(E::default()));
+                    }
+                    let mid = str.byte_to_char_index(str.floor_char_boundary(str.len() / 2));
+                    (str, mid.unwrap())
+                },
+                |(str, mid)| black_box(&**str).char_to_byte_index(black_box(*mid)),
+                BatchSize::LargeInput,
+            )
+        },
+    );
+}
+
+fn bench_collect<E: Encoding + Distribution<char>>(c: &mut Criterion) {
+    let mut rng = thread_rng();
+    c.bench_function(&format!("{}::collect (100k chars)", E::shorthand()), |b| {
+        b.iter_batched_ref(
+            || {
+                (0..100_000)
+                    .map(|_| rng.sample(E::default()))
+                    .collect::<Vec<char>>()
+            },
+            |chars| black_box(chars.iter().copied().collect::<String<E>>()),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn bench_push<E: Encoding + Distribution<char>>(c: &mut Criterion) {
+    let mut rng = thread_rng();
+    c.bench_function(&format!("{}::push (1M chars)", E::shorthand()), |b| {
+        b.iter_batched_ref(
+            || {
+                (0..1_000_000)
+                    .map(|_| rng.sample(E::default()))
+                    .collect::<Vec<char>>()
+            },
+            |chars| {
+                let mut str = String::<E>::new();
+                for &c in &*chars {
+                    str.push(c);
+                }
+                black_box(str)
+            },
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn bench_swap_utf16(c: &mut Criterion, bytes: Byte) {
+    let mut rng = thread_rng();
+    c.bench_function(&format!("utf16be::from::<utf16le> ({})", bytes), |b| {
+        b.iter_batched_ref(
+            || {
+                let mut str = String::<Utf16LE>::new();
+                while (str.len() as u64) < bytes.as_u64() {
+                    str.push(rng.sample(Utf16LE::default()));
+                }
+                str
+            },
+            |str| String::<Utf16BE>::from(black_box(&**str)),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn bench_slice_end<E: Encoding + Distribution<char>>(c: &mut Criterion, bytes: Byte) {
+    let mut rng = thread_rng();
+    c.bench_function(&format!("{}::slice_end ({})", E::shorthand(), bytes), |b| {
+        b.iter_batched_ref(
+            || {
+                let mut str = String::<E>::new();
+                while (str.len() as u64) < bytes.as_u64() {
+                    str.push(rng.sample(E::default()));
+                }
+                str
+            },
+            |str| {
+                let idx = str.floor_char_boundary(str.len() - 1);
+                black_box(&black_box(&**str)[idx..]);
+            },
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+/// Worst case for slicing near the end of a string, repeating a double-byte character whose bytes
+/// could all start a character. Encodings which find boundaries by scanning back to a byte that
+/// can only end a character have to scan the whole string.
+fn bench_slice_end_run<E: Encoding>(c: &mut Criterion, bytes: Byte, char: char) {
+    let mut str = String::<E>::new();
+    while (str.len() as u64) < bytes.as_u64() {
+        str.push(char);
+    }
+    c.bench_function(
+        &format!("{}::slice_end run ({})", E::shorthand(), bytes),
+        |b| {
+            b.iter(|| {
+                let idx = str.floor_char_boundary(str.len() - 1);
+                black_box(&black_box(&*str)[idx..]);
+            })
+        },
+    );
+}
+
+/// Counts the matches of a character in a run of it, which checks a boundary at every match. The
+/// search checks each one from the previous match, so this is linear in the input length.
+fn bench_matches_run<E: Encoding>(c: &mut Criterion, bytes: Byte, char: char) {
+    let mut str = String::<E>::new();
+    while (str.len() as u64) < bytes.as_u64() {
+        str.push(char);
+    }
+    c.bench_function(
+        &format!("{}::matches run ({})", E::shorthand(), bytes),
+        |b| b.iter(|| black_box(&*str).matches(black_box(char)).count()),
+    );
+}
+
+/// Worst case for lossy decoding, with an invalid byte between every valid one. This is linear in
+/// the input length, as each call to `validate` stops at the next error.
+fn bench_lossy_alternating<E: Encoding>(c: &mut Criterion, bytes: Byte, invalid: u8) {
+    let data = (0..bytes.as_u64())
+        .map(|idx| if idx % 2 == 0 { invalid } else { b'a' })
+        .collect::<Vec<_>>();
+    c.bench_function(
+        &format!(
+            "{}::from_bytes_lossy alternating ({})",
+            E::shorthand(),
+            bytes
+        ),
+        |b| b.iter(|| String::<E>::from_bytes_lossy(black_box(&data))),
+    );
+}
+
 pub fn bench_encoding<E: Encoding + Distribution<char>>(c: &mut Criterion) {
     bench_validate::<E>(c, KILOBYTE);
     bench_validate::<E>(c, MEGABYTE);
     bench_encode::<E>(c);
     bench_decode::<E>(c);
+    bench_collect::<E>(c);
 }
 
 pub fn bench_all(c: &mut Criterion) {
     bench_encoding::<Ascii>(c);
     bench_encoding::<ExtendedAscii>(c);
 
+    bench_encoding::<Utf8>(c);
     bench_encoding::<Utf16LE>(c);
     bench_encoding::<Utf16BE>(c);
-    bench_encoding::<Utf32>(c);
+    bench_encoding::<Utf32LE>(c);
+    bench_encoding::<Utf32BE>(c);
 
     bench_encoding::<Win1251>(c);
     bench_encoding::<Win1252>(c);
     bench_encoding::<Win1252Loose>(c);
+    bench_push::<Win1252>(c);
+    bench_push::<Utf8>(c);
 
     bench_encoding::<Iso8859_2>(c);
     bench_encoding::<Iso8859_15>(c);
 
     bench_encoding::<JisX0201>(c);
     bench_encoding::<JisX0208>(c);
+    bench_slice_end::<JisX0208>(c, MEGABYTE);
+    bench_slice_end_run::<JisX0208>(c, MEGABYTE, '日');
+    bench_recode_run::<JisX0208, Utf8>(c, MEGABYTE, '日');
+    bench_encoding::<ShiftJis>(c);
+    bench_encoding::<ShiftJisAscii>(c);
+    bench_slice_end::<ShiftJis>(c, MEGABYTE);
+    bench_slice_end::<ShiftJisAscii>(c, MEGABYTE);
+    bench_slice_end_run::<ShiftJis>(c, MEGABYTE, '亜');
+    bench_matches_run::<ShiftJis>(c, MEGABYTE, '亜');
+    bench_recode_run::<ShiftJis, Utf8>(c, MEGABYTE, '亜');
+    bench_recode_run::<ShiftJis, Ascii>(c, MEGABYTE, '亜');
+
+    bench_encoding::<Big5>(c);
+    bench_slice_end::<Big5>(c, MEGABYTE);
+    bench_slice_end_run::<Big5>(c, MEGABYTE, '中');
 
     bench_encoding::<MacRoman>(c);
+
+    for bytes in [KILOBYTE, MEGABYTE] {
+        bench_recode::<Utf8, Utf16LE>(c, bytes);
+        bench_recode::<Utf8, Utf16BE>(c, bytes);
+        bench_recode::<Utf8, Utf32LE>(c, bytes);
+        bench_recode::<Utf16LE, Utf8>(c, bytes);
+        bench_recode::<Utf16BE, Utf8>(c, bytes);
+        bench_recode::<Utf32LE, Utf8>(c, bytes);
+        bench_recode::<Utf16LE, Utf16BE>(c, bytes);
+        bench_swap_utf16(c, bytes);
+        bench_recode_c::<Win1252, Utf8>(c, bytes);
+        bench_cstring_new::<Utf8>(c, bytes);
+        // Constant time for fixed-length encodings, linear otherwise
+        bench_char_index::<Win1252>(c, bytes);
+        bench_char_index::<Utf32LE>(c, bytes);
+        bench_char_index::<Utf16LE>(c, bytes);
+        bench_lossy_alternating::<Utf8>(c, bytes, 0xFF);
+        bench_lossy_alternating::<ShiftJis>(c, bytes, 0xFF);
+        bench_lossy_alternating::<Win1252>(c, bytes, 0x81);
+    }
 }
 
 criterion_group!(name = benches; config = utils::criterion(); targets = bench_all);
@@ -0,0 +1,126 @@
+//! Report how a file decodes in each built-in encoding.
+//!
+//! ```text
+//! cargo run --example inspect --features std -- [--encoding ENC] INPUT
+//! ```
+//!
+//! The input is streamed through a [`RecodeReader`], so files of any size can be inspected. For
+//! each encoding, the number of characters and invalid sequences are reported, along with the
+//! offset of the first invalid sequence. With `--encoding`, only that encoding is checked.
+
+use enrede::encoding::{
+    detect_bom, visit_shorthand, EncodingVisitor, ValidateError, ALL_SHORTHANDS,
+};
+use enrede::io::RecodeReader;
+use enrede::Encoding;
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::{env, fmt, process};
+
+/// Statistics on decoding some input in an encoding
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    /// Number of characters successfully decoded
+    pub chars: usize,
+    /// Number of invalid byte sequences
+    pub invalid: usize,
+    /// The first invalid byte sequence, if any
+    pub first_invalid: Option<ValidateError>,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} chars", self.chars)?;
+        match self.first_invalid {
+            None => write!(f, ", valid"),
+            Some(err) => write!(
+                f,
+                ", {} invalid sequences, first at byte {}",
+                self.invalid,
+                err.valid_up_to(),
+            ),
+        }
+    }
+}
+
+/// Decode all of `reader` in the encoding `name`. Returns [`None`] if no encoding has that name.
+pub fn inspect<R: Read>(reader: R, name: &str) -> Option<io::Result<Report>> {
+    visit_shorthand(name, Inspect(reader))
+}
+
+struct Inspect<R>(R);
+
+impl<R: Read> EncodingVisitor for Inspect<R> {
+    type Output = io::Result<Report>;
+
+    fn visit<E: Encoding>(self) -> Self::Output {
+        let mut reader = RecodeReader::<_, E>::new(self.0);
+        let mut report = Report::default();
+        loop {
+            match reader.read_char() {
+                Ok(Some(_)) => report.chars += 1,
+                Ok(None) => return Ok(report),
+                Err(err) => {
+                    // Invalid data is skipped by the reader, so decoding can continue after it
+                    let Some(&invalid) = err
+                        .get_ref()
+                        .and_then(|inner| inner.downcast_ref::<ValidateError>())
+                    else {
+                        return Err(err);
+                    };
+                    report.invalid += 1;
+                    report.first_invalid.get_or_insert(invalid);
+                }
+            }
+        }
+    }
+}
+
+fn main() {
+    let mut encoding = None;
+    let mut paths = Vec::new();
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match &*arg {
+            "--encoding" => encoding = args.next(),
+            _ => paths.push(arg),
+        }
+    }
+
+    let [path] = &*paths else {
+        eprintln!("usage: inspect [--encoding ENC] INPUT");
+        process::exit(2);
+    };
+
+    let open = || {
+        File::open(path).map(BufReader::new).unwrap_or_else(|err| {
+            eprintln!("failed to read {path}: {err}");
+            process::exit(1);
+        })
+    };
+
+    let mut start = Vec::new();
+    let _ = open().take(4).read_to_end(&mut start);
+    if let Some((name, _)) = detect_bom(&start) {
+        println!("byte order mark: {name}");
+    }
+
+    let names = match &encoding {
+        Some(name) => &[name.as_str()][..],
+        None => ALL_SHORTHANDS,
+    };
+    for name in names {
+        match inspect(open(), name) {
+            Some(Ok(report)) => println!("{name}: {report}"),
+            Some(Err(err)) => {
+                eprintln!("failed to read {path}: {err}");
+                process::exit(1);
+            }
+            None => {
+                eprintln!("unknown encoding '{name}'");
+                process::exit(2);
+            }
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! Convert a file from one encoding to another.
+//!
+//! ```text
+//! cargo run --example transcode --features std -- [--from ENC] --to ENC [--lossy] INPUT [OUTPUT]
+//! ```
+//!
+//! Encodings are given by their shorthand names, such as `utf8` or `win1252`. If `--from` isn't
+//! given, the input encoding is detected from its byte order mark, falling back to UTF-8. By
+//! default, invalid input or characters the output encoding can't represent stop the conversion
+//! with an error pointing at the offending bytes. With `--lossy`, they're replaced instead.
+//!
+//! Output is written to `OUTPUT` if given, otherwise to stdout.
+
+use enrede::encoding::{detect_bom, visit_shorthand, EncodingVisitor, ALL_SHORTHANDS};
+use enrede::{Encoding, Str, String};
+use std::borrow::Cow;
+use std::io::Write;
+use std::{env, fmt, fs, io, process};
+
+/// An error converting between encodings. Offsets are in bytes, relative to the start of the input.
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// No built-in encoding has this name
+    UnknownEncoding(std::string::String),
+    /// The input isn't valid for the input encoding
+    Invalid {
+        encoding: &'static str,
+        offset: usize,
+        len: Option<usize>,
+    },
+    /// A character in the input can't be represented in the output encoding
+    Unencodable {
+        encoding: &'static str,
+        offset: usize,
+        char: char,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnknownEncoding(name) => write!(
+                f,
+                "unknown encoding '{name}', expected one of: {}",
+                ALL_SHORTHANDS.join(", "),
+            ),
+            Error::Invalid {
+                encoding,
+                offset,
+                len: Some(len),
+            } => write!(f, "invalid {encoding} data at byte {offset} ({len} bytes)"),
+            Error::Invalid {
+                encoding,
+                offset,
+                len: None,
+            } => write!(f, "incomplete {encoding} character at byte {offset}"),
+            Error::Unencodable {
+                encoding,
+                offset,
+                char,
+            } => write!(
+                f,
+                "{char:?} at byte {offset} can't be encoded in {encoding}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The result of a successful conversion
+#[derive(Debug, PartialEq)]
+pub struct Transcoded {
+    /// The shorthand of the input encoding, either as given or detected
+    pub from: &'static str,
+    /// The input converted into the output encoding, without a byte order mark
+    pub bytes: Vec<u8>,
+}
+
+/// Convert `input` from the encoding `from` to the encoding `to`. If `from` is `None`, it's
+/// detected from a byte order mark, falling back to UTF-8. A byte order mark matching the input
+/// encoding is skipped.
+pub fn transcode(
+    input: &[u8],
+    from: Option<&str>,
+    to: &str,
+    lossy: bool,
+) -> Result<Transcoded, Error> {
+    let (from, offset) = match (from, detect_bom(input)) {
+        (None, Some((bom, len))) => (bom, len),
+        (Some(from), Some((bom, len))) if from == bom => (bom, len),
+        (Some(from), _) => (from, 0),
+        (None, None) => ("utf8", 0),
+    };
+    let decode = Decode {
+        input: &input[offset..],
+        offset,
+        to,
+        lossy,
+    };
+    visit_shorthand(from, decode).ok_or_else(|| Error::UnknownEncoding(from.to_owned()))?
+}
+
+/// Decode the input, then dispatch to the output encoding with [`Encode`]
+struct Decode<'a> {
+    input: &'a [u8],
+    offset: usize,
+    to: &'a str,
+    lossy: bool,
+}
+
+impl EncodingVisitor for Decode<'_> {
+    type Output = Result<Transcoded, Error>;
+
+    fn visit<E: Encoding>(self) -> Self::Output {
+        let str = if self.lossy {
+            String::<E>::from_bytes_lossy(self.input)
+        } else {
+            let str = Str::<E>::from_bytes(self.input).map_err(|err| Error::Invalid {
+                encoding: E::shorthand(),
+                offset: self.offset + err.valid_up_to(),
+                len: err.error_len(),
+            })?;
+            Cow::Borrowed(str)
+        };
+
+        let encode = Encode {
+            str: &str,
+            offset: self.offset,
+            lossy: self.lossy,
+        };
+        let bytes = visit_shorthand(self.to, encode)
+            .ok_or_else(|| Error::UnknownEncoding(self.to.to_owned()))??;
+        Ok(Transcoded {
+            from: E::shorthand(),
+            bytes,
+        })
+    }
+}
+
+/// Recode a decoded string into the output encoding
+struct Encode<'a, E: Encoding> {
+    str: &'a Str<E>,
+    offset: usize,
+    lossy: bool,
+}
+
+impl<E: Encoding> EncodingVisitor for Encode<'_, E> {
+    type Output = Result<Vec<u8>, Error>;
+
+    fn visit<E2: Encoding>(self) -> Self::Output {
+        if self.lossy {
+            return Ok(self.str.recode_lossy::<E2>().into_bytes());
+        }
+        match self.str.recode::<E2>() {
+            Ok(out) => Ok(out.into_bytes()),
+            Err(err) => Err(Error::Unencodable {
+                encoding: E2::shorthand(),
+                offset: self.offset + err.valid_up_to(),
+                char: err.char(),
+            }),
+        }
+    }
+}
+
+const USAGE: &str = "usage: transcode [--from ENC] --to ENC [--lossy] INPUT [OUTPUT]";
+
+fn main() {
+    let mut from = None;
+    let mut to = None;
+    let mut lossy = false;
+    let mut paths = Vec::new();
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match &*arg {
+            "--from" => from = args.next(),
+            "--to" => to = args.next(),
+            "--lossy" => lossy = true,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return;
+            }
+            _ => paths.push(arg),
+        }
+    }
+
+    let (Some(to), [input, rest @ ..]) = (to, &*paths) else {
+        eprintln!("{USAGE}");
+        process::exit(2);
+    };
+    if rest.len() > 1 {
+        eprintln!("{USAGE}");
+        process::exit(2);
+    }
+
+    let input = fs::read(input).unwrap_or_else(|err| {
+        eprintln!("failed to read {input}: {err}");
+        process::exit(1);
+    });
+    let out = transcode(&input, from.as_deref(), &to, lossy).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        process::exit(1);
+    });
+
+    let written = match rest.first() {
+        Some(output) => fs::write(output, &out.bytes),
+        None => io::stdout().lock().write_all(&out.bytes),
+    };
+    if let Err(err) = written {
+        eprintln!("failed to write output: {err}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,39 @@
+//! Arbitrary bytes in every encoding: valid data must decode and re-encode to the same characters,
+//! and lossy conversion must always produce valid data.
+
+#![no_main]
+
+use enrede::encoding::{ArrayLike, Encoding, EncodingVisitor, ALL_SHORTHANDS};
+use enrede::{Str, String};
+use libfuzzer_sys::fuzz_target;
+
+struct Decode<'a>(&'a [u8]);
+
+impl EncodingVisitor for Decode<'_> {
+    type Output = ();
+
+    fn visit<E: Encoding>(self) {
+        let lossy = String::<E>::from_bytes_lossy(self.0);
+        assert!(E::validate(lossy.as_bytes()).is_ok());
+
+        let Ok(str) = Str::<E>::from_bytes(self.0) else {
+            return;
+        };
+        let mut encoded = Vec::new();
+        for (idx, c) in str.char_indices() {
+            assert!(str.is_char_boundary(idx));
+            let bytes = E::encode_char(c).expect("decoded character should be encodable");
+            assert_eq!(E::char_len(c), Some(bytes.slice().len()));
+            encoded.extend_from_slice(bytes.slice());
+        }
+        let reencoded = Str::<E>::from_bytes(&encoded).expect("encoded data should be valid");
+        assert!(reencoded.chars().eq(str.chars()));
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    if let Some((&enc, data)) = data.split_first() {
+        let name = ALL_SHORTHANDS[enc as usize % ALL_SHORTHANDS.len()];
+        enrede::encoding::visit_shorthand(name, Decode(data));
+    }
+});
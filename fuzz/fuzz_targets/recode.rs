@@ -0,0 +1,55 @@
+//! Valid strings recoded between every pair of encodings: recoding must either round-trip, or
+//! fail on a character the output encoding can't represent.
+
+#![no_main]
+
+use enrede::encoding::{Encoding, EncodingVisitor, ALL_SHORTHANDS};
+use enrede::Str;
+use libfuzzer_sys::fuzz_target;
+
+struct RecodeFrom<'a>(&'a [u8], &'static str);
+
+struct RecodeTo<'a, E>(&'a Str<E>);
+
+impl EncodingVisitor for RecodeFrom<'_> {
+    type Output = ();
+
+    fn visit<E: Encoding>(self) {
+        // Only the valid prefix of the input is used
+        let valid = match E::validate(self.0) {
+            Ok(()) => self.0,
+            Err(err) => &self.0[..err.valid_up_to()],
+        };
+        let str = Str::<E>::from_bytes(valid).unwrap();
+        enrede::encoding::visit_shorthand(self.1, RecodeTo(str));
+    }
+}
+
+impl<E: Encoding> EncodingVisitor for RecodeTo<'_, E> {
+    type Output = ();
+
+    fn visit<E2: Encoding>(self) {
+        match self.0.recode::<E2>() {
+            Ok(out) => {
+                assert!(out.chars().eq(self.0.chars()));
+                let back = out
+                    .recode::<E>()
+                    .expect("recoded characters should round-trip");
+                assert!(back.chars().eq(self.0.chars()));
+            }
+            Err(err) => {
+                assert!(E2::encode_char(err.char()).is_none());
+                let (partial, _) = self.0.recode_partial::<E2>();
+                assert_eq!(partial, self.0[..err.valid_up_to()].recode::<E2>().unwrap());
+            }
+        }
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    if let [from, to, data @ ..] = data {
+        let from = ALL_SHORTHANDS[*from as usize % ALL_SHORTHANDS.len()];
+        let to = ALL_SHORTHANDS[*to as usize % ALL_SHORTHANDS.len()];
+        enrede::encoding::visit_shorthand(from, RecodeFrom(data, to));
+    }
+});
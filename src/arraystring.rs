@@ -0,0 +1,373 @@
+//! Implementation and utilities for a generically encoded, fixed-capacity string type that doesn't
+//! require allocation.
+//!
+//! See also the [`ArrayString<E, N>`] type.
+
+use core::borrow::{Borrow, BorrowMut};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+use crate::encoding::{EncodeError, Encoding, RecodeStepError};
+use crate::str::Str;
+
+/// An error returned when an [`ArrayString`] doesn't have enough remaining capacity for an
+/// operation.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct CapacityError;
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not enough remaining capacity in string")
+    }
+}
+
+impl core::error::Error for CapacityError {}
+
+/// An error returned when pushing a character onto an [`ArrayString`] fails.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum PushError {
+    /// The character isn't valid for the string's encoding
+    InvalidChar,
+    /// The string doesn't have enough remaining capacity to hold the encoded character
+    Capacity,
+}
+
+impl fmt::Display for PushError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PushError::InvalidChar => write!(f, "character isn't valid for the encoding"),
+            PushError::Capacity => fmt::Display::fmt(&CapacityError, f),
+        }
+    }
+}
+
+impl core::error::Error for PushError {}
+
+/// A generically encoded string with a fixed capacity of `N` bytes, stored inline. This type
+/// is similar to [`String<E>`](crate::String), but doesn't require allocation, making it usable
+/// in `no_std` environments without `alloc`.
+///
+/// Operations that would exceed the capacity never write partial characters - the string is always
+/// left valid for its encoding.
+pub struct ArrayString<E, const N: usize> {
+    _phantom: PhantomData<E>,
+    len: usize,
+    bytes: [u8; N],
+}
+
+impl<E: Encoding, const N: usize> ArrayString<E, N> {
+    /// Create a new, empty `ArrayString`
+    pub const fn new() -> ArrayString<E, N> {
+        ArrayString {
+            _phantom: PhantomData,
+            len: 0,
+            bytes: [0; N],
+        }
+    }
+
+    /// Create an `ArrayString` by re-encoding a string in another encoding. This fails if the
+    /// source string contains characters not supported by this encoding, or if the re-encoded
+    /// string doesn't fit in the capacity.
+    pub fn recode_from<E2: Encoding>(str: &Str<E2>) -> Result<ArrayString<E, N>, RecodeStepError> {
+        let mut out = ArrayString::new();
+        out.len = E::recode(str, &mut out.bytes)?;
+        Ok(out)
+    }
+
+    /// Create an `ArrayString` by re-encoding a string in another encoding, replacing any
+    /// characters not supported by this encoding with the encoding's replacement character. This
+    /// fails if the re-encoded string doesn't fit in the capacity.
+    pub fn recode_from_lossy<E2: Encoding>(
+        str: &Str<E2>,
+    ) -> Result<ArrayString<E, N>, CapacityError> {
+        let mut out = ArrayString::new();
+        for c in str.chars() {
+            match out.try_push(c) {
+                Ok(()) => (),
+                Err(PushError::InvalidChar) => {
+                    out.try_push(E::REPLACEMENT).map_err(|_| CapacityError)?
+                }
+                Err(PushError::Capacity) => return Err(CapacityError),
+            }
+        }
+        Ok(out)
+    }
+
+    /// The total capacity of this string in bytes
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// The number of bytes that can still be written into this string
+    pub const fn remaining_capacity(&self) -> usize {
+        N - self.len
+    }
+
+    /// Get this `ArrayString` as a [`Str`]
+    pub fn as_str(&self) -> &Str<E> {
+        // SAFETY: Our internal bytes up to `len` are guaranteed valid for the encoding
+        unsafe { Str::from_bytes_unchecked(&self.bytes[..self.len]) }
+    }
+
+    /// Get this `ArrayString` as a mutable [`Str`]
+    pub fn as_mut_str(&mut self) -> &mut Str<E> {
+        // SAFETY: Our internal bytes up to `len` are guaranteed valid for the encoding
+        unsafe { Str::from_bytes_unchecked_mut(&mut self.bytes[..self.len]) }
+    }
+
+    /// Add a new character to this string. This method panics if the provided character isn't
+    /// valid for the current encoding, or there isn't enough capacity to hold it.
+    pub fn push(&mut self, c: char) {
+        self.try_push(c).unwrap_or_else(|e| match e {
+            PushError::InvalidChar => {
+                panic!("Invalid character {:?} for encoding {}", c, E::shorthand())
+            }
+            PushError::Capacity => panic!(
+                "Not enough capacity to push {:?} (remaining capacity {})",
+                c,
+                self.remaining_capacity(),
+            ),
+        });
+    }
+
+    /// Add a new character to this string. This method returns an error if the provided character
+    /// isn't valid for the current encoding, or there isn't enough capacity to hold it. On error,
+    /// the string is left unchanged.
+    pub fn try_push(&mut self, c: char) -> Result<(), PushError> {
+        match E::encode(c, &mut self.bytes[self.len..]) {
+            Ok(len) => {
+                self.len += len;
+                Ok(())
+            }
+            Err(EncodeError::NeedSpace { .. }) => Err(PushError::Capacity),
+            Err(_) => Err(PushError::InvalidChar),
+        }
+    }
+
+    /// Extend this string with the contents of the provided [`Str`]. This method panics if there
+    /// isn't enough capacity to hold it.
+    pub fn push_str(&mut self, str: &Str<E>) {
+        self.try_push_str(str).unwrap_or_else(|_| {
+            panic!(
+                "Not enough capacity to push string of length {} (remaining capacity {})",
+                str.len(),
+                self.remaining_capacity(),
+            )
+        });
+    }
+
+    /// Extend this string with the contents of the provided [`Str`]. This method returns an error
+    /// if there isn't enough capacity to hold it. On error, the string is left unchanged.
+    pub fn try_push_str(&mut self, str: &Str<E>) -> Result<(), CapacityError> {
+        let new_len = self.len + str.len();
+        if new_len > N {
+            return Err(CapacityError);
+        }
+        self.bytes[self.len..new_len].copy_from_slice(str.as_bytes());
+        self.len = new_len;
+        Ok(())
+    }
+
+    /// Remove all contents of this string, resetting its length to zero
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<E: Encoding, const N: usize> fmt::Debug for ArrayString<E, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Str<E> as fmt::Debug>::fmt(self, f)
+    }
+}
+
+impl<E: Encoding, const N: usize> fmt::Display for ArrayString<E, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Str<E> as fmt::Display>::fmt(self, f)
+    }
+}
+
+impl<E: Encoding, const N: usize> fmt::Write for ArrayString<E, N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.try_push(c).map_err(|_| fmt::Error)
+    }
+}
+
+impl<E: Encoding, const N: usize> Default for ArrayString<E, N> {
+    fn default() -> Self {
+        ArrayString::new()
+    }
+}
+
+impl<E: Encoding, const N: usize> Clone for ArrayString<E, N> {
+    fn clone(&self) -> Self {
+        ArrayString {
+            _phantom: PhantomData,
+            len: self.len,
+            bytes: self.bytes,
+        }
+    }
+}
+
+impl<E: Encoding, const N: usize> PartialEq for ArrayString<E, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<E: Encoding, const N: usize> Eq for ArrayString<E, N> {}
+
+impl<E: Encoding, const N: usize> Hash for ArrayString<E, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<E: Encoding, const N: usize> Deref for ArrayString<E, N> {
+    type Target = Str<E>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<E: Encoding, const N: usize> DerefMut for ArrayString<E, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_str()
+    }
+}
+
+impl<E: Encoding, const N: usize> AsRef<Str<E>> for ArrayString<E, N> {
+    fn as_ref(&self) -> &Str<E> {
+        self
+    }
+}
+
+impl<E: Encoding, const N: usize> AsMut<Str<E>> for ArrayString<E, N> {
+    fn as_mut(&mut self) -> &mut Str<E> {
+        self
+    }
+}
+
+impl<E: Encoding, const N: usize> AsRef<[u8]> for ArrayString<E, N> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<E: Encoding, const N: usize> Borrow<Str<E>> for ArrayString<E, N> {
+    fn borrow(&self) -> &Str<E> {
+        self
+    }
+}
+
+impl<E: Encoding, const N: usize> BorrowMut<Str<E>> for ArrayString<E, N> {
+    fn borrow_mut(&mut self) -> &mut Str<E> {
+        self
+    }
+}
+
+impl<'a, E: Encoding, const N: usize> TryFrom<&'a Str<E>> for ArrayString<E, N> {
+    type Error = CapacityError;
+
+    fn try_from(value: &'a Str<E>) -> Result<Self, Self::Error> {
+        let mut out = ArrayString::new();
+        out.try_push_str(value)?;
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::{Ascii, JisX0208, RecodeCause, Utf16, Utf8};
+    use core::fmt::Write;
+
+    #[test]
+    fn test_push() {
+        let mut str = ArrayString::<Utf8, 8>::new();
+        str.push('A');
+        str.push('𐐷');
+        assert_eq!(&*str, Str::from_std("A𐐷"));
+        assert_eq!(str.remaining_capacity(), 3);
+        assert_eq!(str.try_push('é'), Ok(()));
+        // Not enough space for a 4-byte character, nothing is written
+        assert_eq!(str.try_push('𐐷'), Err(PushError::Capacity));
+        assert_eq!(&*str, Str::from_std("A𐐷é"));
+        assert_eq!(str.try_push('b'), Ok(()));
+        assert_eq!(str.remaining_capacity(), 0);
+
+        let mut str = ArrayString::<Ascii, 8>::new();
+        assert_eq!(str.try_push('é'), Err(PushError::InvalidChar));
+        assert!(str.is_empty());
+    }
+
+    #[test]
+    fn test_push_capacity_mid_char() {
+        let mut str = ArrayString::<JisX0208, 3>::new();
+        str.push('あ');
+        assert_eq!(str.try_push('い'), Err(PushError::Capacity));
+        assert_eq!(str.len(), 2);
+        assert!(str.validate_self().is_ok());
+        str.push(' ');
+        assert_eq!(str.remaining_capacity(), 0);
+    }
+
+    #[test]
+    fn test_push_str() {
+        let mut str = ArrayString::<Utf8, 6>::new();
+        str.push_str(Str::from_std("Abc"));
+        assert_eq!(str.try_push_str(Str::from_std("defg")), Err(CapacityError));
+        assert_eq!(&*str, Str::from_std("Abc"));
+        str.push_str(Str::from_std("def"));
+        assert_eq!(&*str, Str::from_std("Abcdef"));
+        str.clear();
+        assert!(str.is_empty());
+    }
+
+    #[test]
+    #[should_panic = "Not enough capacity to push '𐐷' (remaining capacity 2)"]
+    fn test_push_panic() {
+        let mut str = ArrayString::<Utf8, 2>::new();
+        str.push('𐐷');
+    }
+
+    #[test]
+    fn test_recode_from() {
+        let str = ArrayString::<Utf16, 8>::recode_from(Str::from_std("A𐐷b")).unwrap();
+        assert_eq!(
+            &*str,
+            Str::from_utf16(&[b'A' as u16, 0xD801, 0xDC37, b'b' as u16]).unwrap()
+        );
+
+        let err = ArrayString::<Utf16, 4>::recode_from(Str::from_std("Abc")).unwrap_err();
+        assert!(matches!(err.cause(), RecodeCause::NeedSpace { .. }));
+        let err = ArrayString::<Ascii, 8>::recode_from(Str::from_std("A𐐷b")).unwrap_err();
+        assert!(matches!(
+            err.cause(),
+            RecodeCause::InvalidChar { char: '𐐷', .. }
+        ));
+
+        let str = ArrayString::<Ascii, 8>::recode_from_lossy(Str::from_std("A𐐷b")).unwrap();
+        assert_eq!(&*str, Str::from_bytes(b"A\x1Ab").unwrap());
+        assert_eq!(
+            ArrayString::<Ascii, 2>::recode_from_lossy(Str::from_std("A𐐷b")),
+            Err(CapacityError)
+        );
+    }
+
+    #[test]
+    fn test_write() {
+        let mut str = ArrayString::<Utf16, 16>::new();
+        write!(str, "{}-{}", 12, str.capacity()).unwrap();
+        assert!(str.chars().eq("12-16".chars()));
+        assert!(write!(str, "{:?}", str.capacity()..).is_err());
+    }
+}
@@ -5,35 +5,84 @@
 #[cfg(feature = "alloc")]
 use alloc::borrow::ToOwned;
 use core::borrow::Borrow;
-use core::ffi::c_char;
+use core::cmp::Ordering;
+use core::ffi::{c_char, CStr as CoreCStr};
 use core::hash::{Hash, Hasher};
+use core::iter::{self, Chain, FusedIterator, Once};
 use core::marker::PhantomData;
-use core::ops::RangeBounds;
 use core::ops::{Bound, Deref, Index};
+use core::ops::{RangeBounds, RangeFrom, RangeFull};
 use core::slice::SliceIndex;
 use core::{fmt, ptr};
 
 #[cfg(feature = "alloc")]
 use crate::cstring::CString;
-use crate::encoding::{AlwaysValid, Encoding, NullTerminable, ValidateError};
-use crate::str::Str;
-use crate::utils::RangeOpen;
+use crate::encoding::{AlwaysValid, Encoding, NullChar, NullTerminable, ValidateError};
+#[cfg(feature = "alloc")]
+use crate::str::RecodeError;
+use crate::str::{Chars, LengthMismatch, Str};
+use crate::utils::paranoid_assert;
 
 /// Error encountered when creating a [`CStr`] with no terminating null byte.
+#[derive(Copy, Clone, Debug, PartialEq)]
 #[non_exhaustive]
 pub struct MissingNull;
 
+impl fmt::Display for MissingNull {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "data doesn't contain a null byte")
+    }
+}
+
+impl core::error::Error for MissingNull {}
+
 /// Error encountered while creating a [`CStr`] from bytes until a null byte is encountered
-#[derive(Debug, PartialEq)]
-pub enum FromBytesTilNulError {
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum FromBytesUntilNulError {
     /// The input isn't valid for the desired encoding
     Invalid(ValidateError),
     /// The input doesn't contain any null bytes
     MissingNull,
 }
 
+impl fmt::Display for FromBytesUntilNulError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromBytesUntilNulError::Invalid(_) => write!(f, "data isn't valid for the encoding"),
+            FromBytesUntilNulError::MissingNull => fmt::Display::fmt(&MissingNull, f),
+        }
+    }
+}
+
+impl core::error::Error for FromBytesUntilNulError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            FromBytesUntilNulError::Invalid(e) => Some(e),
+            FromBytesUntilNulError::MissingNull => None,
+        }
+    }
+}
+
+impl From<ValidateError> for FromBytesUntilNulError {
+    fn from(value: ValidateError) -> Self {
+        FromBytesUntilNulError::Invalid(value)
+    }
+}
+
+impl From<MissingNull> for FromBytesUntilNulError {
+    fn from(_: MissingNull) -> Self {
+        FromBytesUntilNulError::MissingNull
+    }
+}
+
+/// Previous name of [`FromBytesUntilNulError`].
+#[deprecated(note = "renamed to `FromBytesUntilNulError`, matching `std`")]
+pub type FromBytesTilNulError = FromBytesUntilNulError;
+
 /// Error encountered while creating a [`CStr`] from bytes with a single terminating null byte
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum FromBytesWithNulError {
     /// The input isn't valid for the desired encoding
     Invalid(ValidateError),
@@ -46,8 +95,51 @@ pub enum FromBytesWithNulError {
     MissingNull,
 }
 
+impl fmt::Display for FromBytesWithNulError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromBytesWithNulError::Invalid(_) => write!(f, "data isn't valid for the encoding"),
+            FromBytesWithNulError::HasNull { idx } => {
+                write!(f, "data contains an interior null byte at index {}", idx)
+            }
+            FromBytesWithNulError::MissingNull => fmt::Display::fmt(&MissingNull, f),
+        }
+    }
+}
+
+impl core::error::Error for FromBytesWithNulError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            FromBytesWithNulError::Invalid(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ValidateError> for FromBytesWithNulError {
+    fn from(value: ValidateError) -> Self {
+        FromBytesWithNulError::Invalid(value)
+    }
+}
+
+impl From<MissingNull> for FromBytesWithNulError {
+    fn from(_: MissingNull) -> Self {
+        FromBytesWithNulError::MissingNull
+    }
+}
+
+impl From<FromBytesWithNulValidError> for FromBytesWithNulError {
+    fn from(value: FromBytesWithNulValidError) -> Self {
+        match value {
+            FromBytesWithNulValidError::HasNull { idx } => FromBytesWithNulError::HasNull { idx },
+            FromBytesWithNulValidError::MissingNull => FromBytesWithNulError::MissingNull,
+        }
+    }
+}
+
 /// Error encountered while creating a [`CStr`] from an [`AlwaysValid`] encoding.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum FromBytesWithNulValidError {
     /// The input contains a null byte not in the final position
     HasNull {
@@ -58,6 +150,89 @@ pub enum FromBytesWithNulValidError {
     MissingNull,
 }
 
+impl fmt::Display for FromBytesWithNulValidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromBytesWithNulValidError::HasNull { idx } => {
+                write!(f, "data contains an interior null byte at index {}", idx)
+            }
+            FromBytesWithNulValidError::MissingNull => fmt::Display::fmt(&MissingNull, f),
+        }
+    }
+}
+
+impl core::error::Error for FromBytesWithNulValidError {}
+
+impl From<MissingNull> for FromBytesWithNulValidError {
+    fn from(_: MissingNull) -> Self {
+        FromBytesWithNulValidError::MissingNull
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A range which can be used to slice a [`CStr`], with [`CStr::get`] or indexing. This is
+/// implemented for the ranges which have no end - [`RangeFrom`] (`idx..`) and [`RangeFull`]
+/// (`..`).
+///
+/// It's also implemented for `(Bound<usize>, Bound<usize>)`, for ranges built at runtime. The end
+/// of these must be [`Bound::Unbounded`], otherwise [`CStr::get`] returns [`None`] and indexing
+/// panics. Other ranges aren't accepted at all, as they could cut off the terminating null byte:
+///
+/// ```compile_fail
+/// # use enrede::{CStr, encoding::Utf8};
+/// let str = CStr::<Utf8>::from_bytes_with_nul(b"abc\0").unwrap();
+/// let _ = &str[1..2];
+/// ```
+///
+/// This trait is sealed, and can't be implemented outside this crate.
+pub trait RangeOpen<T>: sealed::Sealed {
+    /// The start bound of this range
+    fn start_bound(&self) -> Bound<&T>;
+
+    /// The end bound of this range. This is always [`Bound::Unbounded`] for a range which can
+    /// slice a `CStr`.
+    fn end_bound(&self) -> Bound<&T>;
+}
+
+impl<T> sealed::Sealed for RangeFrom<T> {}
+
+impl<T> RangeOpen<T> for RangeFrom<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        <Self as RangeBounds<T>>::start_bound(self)
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        Bound::Unbounded
+    }
+}
+
+impl sealed::Sealed for RangeFull {}
+
+impl<T> RangeOpen<T> for RangeFull {
+    fn start_bound(&self) -> Bound<&T> {
+        Bound::Unbounded
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        Bound::Unbounded
+    }
+}
+
+impl<T> sealed::Sealed for (Bound<T>, Bound<T>) {}
+
+impl<T> RangeOpen<T> for (Bound<T>, Bound<T>) {
+    fn start_bound(&self) -> Bound<&T> {
+        self.0.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        self.1.as_ref()
+    }
+}
+
 /// A C-string slice, representing an encoded string with a single null (or zero) byte at the end.
 /// This is normally represented in C as a `char*`, and is the most common form of string value
 /// there.
@@ -68,10 +243,34 @@ pub enum FromBytesWithNulValidError {
 ///
 /// Unlike the [`std::ffi::CStr`] type, this type implements `Deref` to [`Str<E>`]. This is because
 /// while the `std` CStr isn't guaranteed to be in any particular encoding, and may not be a valid
-/// `str`, this type always contains correctly encoded data.
+/// `str`, this type always contains correctly encoded data. The `Str` doesn't include the
+/// terminating null, so methods such as [`Str::chars`], [`Str::len`], and [`Str::ends_with`]
+/// ignore it.
+///
+/// Comparisons also ignore the terminating null - a `CStr` is equal to a [`Str`] with the same
+/// content, and orders by its content bytes like [`std::ffi::CStr`]. [`Str`] itself has no
+/// ordering, as byte order only matches character order for some encodings - see
+/// [`Str::cmp_bytes`] and [`Str::cmp_chars`].
+///
+/// A `CStr` can only be sliced from an index to its end, such as `&cstr[idx..]` or
+/// [`cstr.slice_from(idx)`](CStr::slice_from), as the result must still end with the terminating
+/// null. Ranges with an end, like `&cstr[..idx]`, would cut it off, and so would produce a plain
+/// [`Str`] - slice through `Deref` with `&cstr.as_str()[..idx]` for that. See [`RangeOpen`] for
+/// the accepted ranges.
 #[repr(transparent)]
 pub struct CStr<E>(PhantomData<E>, [u8]);
 
+impl<E: NullTerminable + 'static> CStr<E> {
+    /// An empty C-string, containing only the terminating null. This is the same as [`Default`],
+    /// but usable in const contexts.
+    pub const EMPTY: &'static CStr<E> = {
+        let ptr = ptr::from_ref::<[u8]>(&[0]) as *const CStr<E>;
+        // SAFETY: `CStr` is `repr(transparent)` containing a [u8]. Empty string slice can never be
+        //         invalid, and there is a single null byte.
+        unsafe { &*ptr }
+    };
+}
+
 impl<E: Encoding + NullTerminable> CStr<E> {
     /// Create a `CStr` from a byte slice without checking whether it is valid for the current
     /// encoding, or whether it ends with a terminating null byte.
@@ -81,8 +280,8 @@ impl<E: Encoding + NullTerminable> CStr<E> {
     /// The bytes passed must be valid for the current encoding, contain a single null byte at the
     /// end.
     pub unsafe fn from_bytes_with_nul_unchecked(bytes: &[u8]) -> &CStr<E> {
-        debug_assert!(E::validate(&bytes[..bytes.len() - 1]).is_ok());
-        debug_assert_eq!(*bytes.last().unwrap(), 0);
+        paranoid_assert!(E::validate(&bytes[..bytes.len() - 1]).is_ok());
+        paranoid_assert!(bytes.last() == Some(&0));
         let ptr = ptr::from_ref(bytes) as *const CStr<E>;
         // SAFETY: `Str` is `repr(transparent)` containing a [u8].
         //         Provided bytes have precondition of being valid encoding
@@ -97,8 +296,8 @@ impl<E: Encoding + NullTerminable> CStr<E> {
     /// The bytes passed must be valid for the current encoding, contain a single null byte at the
     /// end.
     pub unsafe fn from_bytes_with_nul_unchecked_mut(bytes: &mut [u8]) -> &mut CStr<E> {
-        debug_assert!(E::validate(&bytes[..bytes.len() - 1]).is_ok());
-        debug_assert_eq!(*bytes.last().unwrap(), 0);
+        paranoid_assert!(E::validate(&bytes[..bytes.len() - 1]).is_ok());
+        paranoid_assert!(bytes.last() == Some(&0));
         let ptr = ptr::from_mut(bytes) as *mut CStr<E>;
         // SAFETY: `Str` is `repr(transparent)` containing a [u8].
         //         Provided bytes have precondition of being valid encoding
@@ -111,15 +310,50 @@ impl<E: Encoding + NullTerminable> CStr<E> {
     ///
     /// Data *past* the first null byte isn't validated, and a successful return doesn't mean that
     /// data is valid for the current encoding.
-    pub fn from_bytes_til_nul(bytes: &[u8]) -> Result<&CStr<E>, FromBytesTilNulError> {
+    pub fn from_bytes_until_nul(bytes: &[u8]) -> Result<&CStr<E>, FromBytesUntilNulError> {
+        CStr::from_bytes_until_nul_with_rest(bytes).map(|(str, _)| str)
+    }
+
+    /// Create a `CStr` from a byte slice, ending at the first null byte, and also return the
+    /// remaining bytes after that null. See [`CStr::from_bytes_until_nul`].
+    ///
+    /// This allows parsing a sequence of null-terminated strings packed into one buffer, such as a
+    /// string table, without searching for each null twice. See also [`CStr::iter_packed`].
+    pub fn from_bytes_until_nul_with_rest(
+        bytes: &[u8],
+    ) -> Result<(&CStr<E>, &[u8]), FromBytesUntilNulError> {
         let nul = bytes
             .iter()
             .position(|b| *b == 0)
-            .ok_or(FromBytesTilNulError::MissingNull)?;
-        E::validate(&bytes[..nul]).map_err(FromBytesTilNulError::Invalid)?;
+            .ok_or(FromBytesUntilNulError::MissingNull)?;
+        E::validate(&bytes[..nul]).map_err(FromBytesUntilNulError::Invalid)?;
+        let (str, rest) = bytes.split_at(nul + 1);
         // SAFETY: End position is the location of first null byte, prior bytes have been validated
         //         for the encoding.
-        Ok(unsafe { CStr::from_bytes_with_nul_unchecked(&bytes[..=nul]) })
+        Ok((unsafe { CStr::from_bytes_with_nul_unchecked(str) }, rest))
+    }
+
+    /// Iterate over the null-terminated strings packed one after another in a byte slice, such as a
+    /// string table. Each item is the result of [`CStr::from_bytes_until_nul`] on the data after
+    /// the previous string, and iteration ends once all the data is used.
+    ///
+    /// If an entry is invalid, or the data ends without a final null byte, the error is returned
+    /// and iteration stops, as the start of the next string can't be found.
+    ///
+    /// ```
+    /// # use enrede::{CStr, encoding::Ascii};
+    /// let table = b"main\0printf\0\0exit\0";
+    /// let names = CStr::<Ascii>::iter_packed(table)
+    ///     .map(|name| name.unwrap().as_str().as_bytes())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(names, [&b"main"[..], b"printf", b"", b"exit"]);
+    /// ```
+    pub fn iter_packed(bytes: &[u8]) -> IterPacked<'_, E> {
+        IterPacked {
+            bytes,
+            failed: false,
+            _phantom: PhantomData,
+        }
     }
 
     /// Create a `CStr` from a mutable byte slice, ending at the first null byte. If there are no
@@ -128,12 +362,14 @@ impl<E: Encoding + NullTerminable> CStr<E> {
     ///
     /// Data *past* the first null byte isn't validated, and a successful return doesn't mean that
     /// data is valid for the current encoding.
-    pub fn from_bytes_til_nul_mut(bytes: &mut [u8]) -> Result<&mut CStr<E>, FromBytesTilNulError> {
+    pub fn from_bytes_until_nul_mut(
+        bytes: &mut [u8],
+    ) -> Result<&mut CStr<E>, FromBytesUntilNulError> {
         let nul = bytes
             .iter()
             .position(|b| *b == 0)
-            .ok_or(FromBytesTilNulError::MissingNull)?;
-        E::validate(&bytes[..nul]).map_err(FromBytesTilNulError::Invalid)?;
+            .ok_or(FromBytesUntilNulError::MissingNull)?;
+        E::validate(&bytes[..nul]).map_err(FromBytesUntilNulError::Invalid)?;
         // SAFETY: End position is the location of first null byte, prior bytes have been validated
         //         for the encoding.
         Ok(unsafe { CStr::from_bytes_with_nul_unchecked_mut(&mut bytes[..=nul]) })
@@ -193,6 +429,39 @@ impl<E: Encoding + NullTerminable> CStr<E> {
         &self.1
     }
 
+    /// The length of this string in bytes, including the terminating null byte. This is always
+    /// one more than [`len`](Str::len).
+    pub fn len_with_nul(&self) -> usize {
+        self.1.len()
+    }
+
+    /// Convert a [`core::ffi::CStr`] into a `CStr` in this encoding, without copying. An error is
+    /// returned if its content isn't valid for the current encoding.
+    ///
+    /// ```
+    /// # use enrede::{CStr, encoding::Win1252};
+    /// let core = c"caf\xE9";
+    /// let str = CStr::<Win1252>::from_core_cstr(core).unwrap();
+    /// assert_eq!(str.as_str(), "café");
+    /// assert_eq!(str.as_core_cstr(), core);
+    /// ```
+    pub fn from_core_cstr(value: &CoreCStr) -> Result<&CStr<E>, ValidateError> {
+        let bytes = value.to_bytes_with_nul();
+        E::validate(&bytes[..bytes.len() - 1])?;
+        // SAFETY: A `core::ffi::CStr` always ends with its only null byte. Prior bytes have been
+        //         validated for the encoding.
+        Ok(unsafe { CStr::from_bytes_with_nul_unchecked(bytes) })
+    }
+
+    /// Get this string as a [`core::ffi::CStr`], without copying. This loses the encoding
+    /// information, but gives a type accepted by any API expecting a C string.
+    pub fn as_core_cstr(&self) -> &CoreCStr {
+        // SAFETY: Both types hold the string data followed by a single null byte, and our bytes
+        //         contain no other nulls. This goes through the bytes rather than casting the
+        //         reference, as the layout of `core::ffi::CStr` isn't guaranteed.
+        unsafe { CoreCStr::from_bytes_with_nul_unchecked(self.as_bytes_with_nul()) }
+    }
+
     unsafe fn as_bytes_with_nul_mut(&mut self) -> &mut [u8] {
         &mut self.1
     }
@@ -201,19 +470,22 @@ impl<E: Encoding + NullTerminable> CStr<E> {
     where
         R: RangeOpen<usize>,
     {
-        let start = idx.start_bound();
-        let start_idx = match start {
+        // A runtime range must have no end, so the slice always runs to the terminating null
+        if !matches!(idx.end_bound(), Bound::Unbounded) {
+            return None;
+        }
+        let start_idx = match idx.start_bound() {
             Bound::Included(i) => *i,
-            Bound::Excluded(i) => *i + 1,
+            Bound::Excluded(i) => i.checked_add(1)?,
             Bound::Unbounded => 0,
         };
 
-        let end_idx = self.as_bytes().len();
-
-        if !self.is_char_boundary(start_idx) || !self.is_char_boundary(end_idx) {
-            None
-        } else {
+        // This is `Str::is_char_boundary`, so `self.len()` (the position of the null) is a valid
+        // start, producing an empty `CStr`.
+        if self.is_char_boundary(start_idx) {
             Some(())
+        } else {
+            None
         }
     }
 
@@ -222,7 +494,9 @@ impl<E: Encoding + NullTerminable> CStr<E> {
     ///
     /// Unlike with `Str`, indexing a `CStr` may only be done with open-ended ranges, EG `idx..` or
     /// `..`. Otherwise it would be possible to create a `CStr` that didn't end with a terminating
-    /// null byte.
+    /// null byte. Indices are relative to the string data, not including the null, but the null is
+    /// always carried along into the result. As such, `cstr.get(cstr.len()..)` returns an empty
+    /// `CStr`.
     pub fn get<R>(&self, idx: R) -> Option<&CStr<E>>
     where
         R: RangeOpen<usize> + SliceIndex<[u8], Output = [u8]>,
@@ -239,7 +513,9 @@ impl<E: Encoding + NullTerminable> CStr<E> {
     ///
     /// Unlike with `Str`, indexing a `CStr` may only be done with open-ended ranges, EG `idx..` or
     /// `..`. Otherwise it would be possible to create a `CStr` that didn't end with a terminating
-    /// null byte.
+    /// null byte. Indices are relative to the string data, not including the null, but the null is
+    /// always carried along into the result. As such, `cstr.get(cstr.len()..)` returns an empty
+    /// `CStr`.
     pub fn get_mut<R>(&mut self, idx: R) -> Option<&mut CStr<E>>
     where
         R: RangeOpen<usize> + SliceIndex<[u8], Output = [u8]>,
@@ -251,6 +527,56 @@ impl<E: Encoding + NullTerminable> CStr<E> {
         Some(unsafe { CStr::from_bytes_with_nul_unchecked_mut(self.1.get_mut(idx)?) })
     }
 
+    /// Return the part of this `CStr` from byte index `idx` to the end, keeping the terminating
+    /// null. This is the same as `&self[idx..]`. See [`CStr::get`] for a non-panicking
+    /// alternative.
+    ///
+    /// ```
+    /// # use enrede::{CStr, encoding::Utf8};
+    /// let str = CStr::<Utf8>::from_bytes_with_nul("héllo\0".as_bytes()).unwrap();
+    /// assert_eq!(str.slice_from(3).as_bytes_with_nul(), b"llo\0");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `idx` is past the end of the string, or doesn't lie on a character boundary.
+    pub fn slice_from(&self, idx: usize) -> &CStr<E> {
+        &self[idx..]
+    }
+
+    /// Return an iterator over the [`char`]s of this C string, including the terminating null as a
+    /// final `'\0'`. This is mostly useful for debugging - [`Str::chars`], available through
+    /// `Deref`, stops before the null.
+    pub fn chars_with_nul(&self) -> Chain<Chars<'_, E>, Once<char>> {
+        self.chars().chain(iter::once('\0'))
+    }
+
+    /// Get this `CStr` as a [`CString`] in a different [`Encoding`]. This is equivalent to
+    /// [`Str::recode`] followed by [`String::into_cstring`](crate::String::into_cstring), but
+    /// skips checking the output for null bytes - a C string contains no null characters, and a
+    /// [`NullTerminable`] encoding never encodes other characters with a null byte.
+    #[cfg(feature = "alloc")]
+    pub fn recode_c<E2: NullTerminable>(&self) -> Result<CString<E2>, RecodeError> {
+        let mut bytes = self.recode::<E2>()?.into_bytes();
+        bytes.reserve_exact(1);
+        // SAFETY: The input contains no null characters, so neither does the output. `E2` is
+        //         `NullTerminable`, so only encodes the null character with a null byte.
+        Ok(unsafe { CString::from_vec_unchecked(bytes) })
+    }
+
+    /// Get this `CStr` as a [`CString`] in a different [`Encoding`], replacing any characters that
+    /// can't be represented with the encoding's replacement character. Like [`CStr::recode_c`],
+    /// this skips checking the output for null bytes.
+    #[cfg(feature = "alloc")]
+    pub fn recode_c_lossy<E2: NullTerminable>(&self) -> CString<E2> {
+        let mut bytes = self.recode_lossy::<E2>().into_bytes();
+        bytes.reserve_exact(1);
+        // SAFETY: The input contains no null characters, and the replacement character is never
+        //         null, so neither does the output. `E2` is `NullTerminable`, so only encodes the
+        //         null character with a null byte.
+        unsafe { CString::from_vec_unchecked(bytes) }
+    }
+
     /// Convert this `CStr` into a [`Str`]. Unlike the equivalent std method, this is infallible,
     /// because our `CStr` is encoding-specific instead of arbitrary null-terminated bytes.
     ///
@@ -262,6 +588,19 @@ impl<E: Encoding + NullTerminable> CStr<E> {
         unsafe { Str::from_bytes_unchecked(&bytes[..bytes.len() - 1]) }
     }
 
+    /// Whether the content of this `CStr`, excluding the terminating null, is equal to `str`. This
+    /// is the same as `self == str`, but makes it clear the null isn't compared.
+    ///
+    /// ```
+    /// # use enrede::{CStr, Str, encoding::Utf8};
+    /// let cstr = CStr::<Utf8>::from_bytes_with_nul(b"abc\0").unwrap();
+    /// assert!(cstr.content_eq(Str::from_std("abc")));
+    /// assert!(!cstr.content_eq(Str::from_std("abc\0")));
+    /// ```
+    pub fn content_eq(&self, str: &Str<E>) -> bool {
+        self.as_str() == str
+    }
+
     /// Convert this `CStr` into a mutable [`Str`]. This method is unsafe because it is possible to
     /// write null bytes into the string via methods such as [`Str::copy_from`].
     ///
@@ -277,100 +616,101 @@ impl<E: Encoding + NullTerminable> CStr<E> {
     /// Copy the data of another C-string into this C-string. Due to the limitations of slicing C
     /// strings only till the end, the [`CStr::copy_range`] method is provided as it is most often
     /// more useful than this one.
+    ///
+    /// Only the string data is copied, so the terminating null byte is always left in place.
+    ///
+    /// # Panics
+    ///
+    /// If the two strings have different lengths, excluding their terminating null bytes. See
+    /// [`CStr::try_copy_from`] for a non-panicking variant.
     pub fn copy_from(&mut self, other: &CStr<E>) {
-        if self.len() != other.len() {
-            panic!(
-                "Source string length ({}) doesn't match destination C string length ({})",
-                self.len(),
-                other.len(),
-            );
+        if let Err(err) = self.try_copy_from(other) {
+            panic!("{}", err);
         }
-        self.1.copy_from_slice(other.as_bytes());
     }
 
-    /// Copy the data from one C string into this one, taking the data from the first range and
-    /// inserting it into the second range. This method panics if any of the range ends don't fall
-    /// on a character boundary. This is the more powerful variant of [`CStr::copy_from`]`.
+    /// Copy the data of another C-string into this C-string, returning a [`LengthMismatch`] error
+    /// if the two strings have different lengths, excluding their terminating null bytes. The
+    /// terminating null byte is always left in place.
+    pub fn try_copy_from(&mut self, other: &CStr<E>) -> Result<(), LengthMismatch> {
+        let len = self.len();
+        if len != other.len() {
+            return Err(LengthMismatch {
+                source_len: other.len(),
+                dest_len: len,
+            });
+        }
+        // `other` contains no null bytes before its end, so this can't introduce an internal null
+        self.1[..len].copy_from_slice(other.as_bytes());
+        Ok(())
+    }
+
+    /// Copy the data from one C string into this one, taking the data from `src_range` in `other`
+    /// and inserting it into `dest_range` in `self`. Both ranges index the string data, excluding
+    /// the terminating null byte. This is the more powerful variant of [`CStr::copy_from`].
+    ///
+    /// This method panics if either range is out of bounds, if any of the range ends don't fall on
+    /// a character boundary, or if the two ranges have different lengths.
     pub fn copy_range<R1, R2>(&mut self, other: &CStr<E>, src_range: R1, dest_range: R2)
     where
         R1: RangeBounds<usize> + SliceIndex<[u8], Output = [u8]> + Clone,
         R2: RangeBounds<usize> + SliceIndex<[u8], Output = [u8]> + Clone,
     {
-        #[cfg(feature = "alloc")]
-        use core::fmt::Write;
-
-        #[cfg(feature = "alloc")]
-        fn bounds_to_range<W: Write>(f: &mut W, range: &impl RangeBounds<usize>) -> fmt::Result {
-            match range.start_bound() {
-                Bound::Included(i) => write!(f, "{}..", i)?,
-                Bound::Excluded(e) => write!(f, "{}..", e + 1)?,
-                Bound::Unbounded => (),
-            }
-            match range.end_bound() {
-                Bound::Included(i) => write!(f, "={}", i)?,
-                Bound::Excluded(e) => write!(f, "{}", e)?,
-                Bound::Unbounded => (),
-            }
-            Ok(())
-        }
+        let (src_start, src_end) = char_range(other, src_range, "Source");
+        let (dest_start, dest_end) = char_range(self, dest_range, "Destination");
 
-        #[cfg(feature = "alloc")]
-        let self_len = self.len();
-
-        let dest = self.1.get_mut(src_range.clone()).unwrap_or_else(|| {
-            #[cfg(feature = "alloc")]
-            let str = {
-                use alloc::string::String;
-                let mut str = String::new();
-                write!(&mut str, "Source string range (").unwrap();
-                bounds_to_range(&mut str, &src_range).unwrap();
-                write!(
-                    &mut str,
-                    ") out of bounds for C string length ({})",
-                    self_len
-                )
-                .unwrap();
-                str
-            };
-            #[cfg(not(feature = "alloc"))]
-            let str = "Source string range out of bounds for C string length";
-            panic!("{}", str)
-        });
-
-        let src = other.1.get(dest_range.clone()).unwrap_or_else(|| {
-            #[cfg(feature = "alloc")]
-            let str = {
-                use alloc::string::String;
-                let mut str = String::new();
-                write!(&mut str, "Destination string range (").unwrap();
-                bounds_to_range(&mut str, &dest_range).unwrap();
-                write!(
-                    &mut str,
-                    ") out of bounds for C string length ({})",
-                    other.len()
-                )
-                .unwrap();
-                str
-            };
-            #[cfg(not(feature = "alloc"))]
-            let str = "Destination string range out of bounds for C string length";
-            panic!("{}", str)
-        });
-
-        if dest.len() != src.len() {
+        if dest_end - dest_start != src_end - src_start {
             panic!(
                 "Source range length ({}) doesn't match destination range length ({})",
-                src.len(),
-                dest.len(),
+                src_end - src_start,
+                dest_end - dest_start,
             );
         }
 
-        dest.copy_from_slice(src)
+        self.1[dest_start..dest_end].copy_from_slice(&other.as_bytes()[src_start..src_end])
+    }
+
+    /// Write `src` into a fixed-size buffer as a C string, such as a `char buf[N]` field of a C
+    /// struct, truncating it to fit. Returns the number of bytes of `src` written.
+    ///
+    /// As many whole characters as fit are copied, leaving room for the terminating null byte, so a
+    /// multi-byte character is never split. Copying also stops before any null character in `src`,
+    /// as it would end the C string early. The rest of the buffer after the copied data is filled
+    /// with null bytes, so no old data is left behind. The result can be read back with
+    /// [`CStr::from_bytes_until_nul`].
+    ///
+    /// This writes to a byte buffer rather than a `CStr`, as the null terminator may need to move.
+    ///
+    /// ```
+    /// # use enrede::{CStr, Str, encoding::Utf8};
+    /// let mut buf = [b'x'; 8];
+    /// assert_eq!(CStr::fill_truncated(&mut buf, Str::from_std("Hello, world!")), 7);
+    /// assert_eq!(&buf, b"Hello, \0");
+    ///
+    /// assert_eq!(CStr::fill_truncated(&mut buf, Str::from_std("Hi")), 2);
+    /// assert_eq!(&buf, b"Hi\0\0\0\0\0\0");
+    /// let str = CStr::<Utf8>::from_bytes_until_nul(&buf).unwrap();
+    /// assert_eq!(str, Str::from_std("Hi"));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `buf` is empty, as there is no room for the terminating null byte.
+    pub fn fill_truncated(buf: &mut [u8], src: &Str<E>) -> usize {
+        let Some(max) = buf.len().checked_sub(1) else {
+            panic!("Buffer has no room for a terminating null byte");
+        };
+        // `E` is `NullTerminable`, so a null byte is always a null character, and a char boundary
+        let nul = src.as_bytes().iter().position(|b| *b == 0);
+        let len = src.floor_char_boundary(nul.map_or(max, |nul| nul.min(max)));
+        buf[..len].copy_from_slice(&src.as_bytes()[..len]);
+        buf[len..].fill(0);
+        len
     }
 
-    /// Split this string at an index, returning the two substrings on either side. This method
-    /// panics if the index doesn't lie on a character boundary. The right-side substring is
-    /// returned as a `CStr`, as it retains the trailing null.
+    /// Split this string at an index, returning the two substrings on either side. Returns [`None`]
+    /// if the index doesn't lie on a character boundary, or isn't before the end of the string.
+    /// The right-side substring is returned as a `CStr`, as it retains the trailing null.
     pub fn split_at(&self, idx: usize) -> Option<(&Str<E>, &CStr<E>)> {
         if self.is_char_boundary(idx) && idx < self.len() {
             let (start, end) = self.1.split_at(idx);
@@ -384,9 +724,9 @@ impl<E: Encoding + NullTerminable> CStr<E> {
         }
     }
 
-    /// Split this string mutably at an index, returning the two substrings on either side. This
-    /// method panics if the index doesn't lie on a character boundary. The right-side substring is
-    /// returned as a `CStr`, as it retains the trailing null.
+    /// Split this string mutably at an index, returning the two substrings on either side. Returns
+    /// [`None`] if the index doesn't lie on a character boundary, or isn't before the end of the
+    /// string. The right-side substring is returned as a `CStr`, as it retains the trailing null.
     pub fn split_at_mut(&mut self, idx: usize) -> Option<(&mut Str<E>, &mut CStr<E>)> {
         if self.is_char_boundary(idx) && idx < self.len() {
             let (start, end) = self.1.split_at_mut(idx);
@@ -399,26 +739,165 @@ impl<E: Encoding + NullTerminable> CStr<E> {
             None
         }
     }
+
+    /// Previous name of [`CStr::from_bytes_until_nul`].
+    #[deprecated(note = "renamed to `from_bytes_until_nul`, matching `std`")]
+    pub fn from_bytes_til_nul(bytes: &[u8]) -> Result<&CStr<E>, FromBytesUntilNulError> {
+        CStr::from_bytes_until_nul(bytes)
+    }
+
+    /// Previous name of [`CStr::from_bytes_until_nul_mut`].
+    #[deprecated(note = "renamed to `from_bytes_until_nul_mut`, matching `std`")]
+    pub fn from_bytes_til_nul_mut(
+        bytes: &mut [u8],
+    ) -> Result<&mut CStr<E>, FromBytesUntilNulError> {
+        CStr::from_bytes_until_nul_mut(bytes)
+    }
+}
+
+/// Iterator over null-terminated strings packed into a byte slice.
+///
+/// See [`CStr::iter_packed`].
+pub struct IterPacked<'a, E> {
+    bytes: &'a [u8],
+    failed: bool,
+    _phantom: PhantomData<&'a CStr<E>>,
+}
+
+impl<'a, E> IterPacked<'a, E> {
+    /// The data not yet parsed by this iterator. After an error, this is the data starting at the
+    /// entry which failed to parse.
+    pub fn remainder(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<E> Clone for IterPacked<'_, E> {
+    fn clone(&self) -> Self {
+        IterPacked {
+            bytes: self.bytes,
+            failed: self.failed,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: Encoding> fmt::Debug for IterPacked<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IterPacked")
+            .field("encoding", &E::shorthand())
+            .field("remainder", &self.bytes)
+            .field("failed", &self.failed)
+            .finish()
+    }
+}
+
+impl<'a, E: NullTerminable> Iterator for IterPacked<'a, E> {
+    type Item = Result<&'a CStr<E>, FromBytesUntilNulError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.bytes.is_empty() {
+            return None;
+        }
+        match CStr::from_bytes_until_nul_with_rest(self.bytes) {
+            Ok((str, rest)) => {
+                self.bytes = rest;
+                Some(Ok(str))
+            }
+            Err(err) => {
+                self.failed = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl<E: NullTerminable> FusedIterator for IterPacked<'_, E> {}
+
+struct DisplayRange<'a, R>(&'a R);
+
+impl<R: RangeBounds<usize>> fmt::Display for DisplayRange<'_, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.start_bound() {
+            Bound::Included(i) => write!(f, "{}..", i)?,
+            Bound::Excluded(e) => write!(f, "{}..", e + 1)?,
+            Bound::Unbounded => write!(f, "..")?,
+        }
+        match self.0.end_bound() {
+            Bound::Included(i) => write!(f, "={}", i),
+            Bound::Excluded(e) => write!(f, "{}", e),
+            Bound::Unbounded => Ok(()),
+        }
+    }
+}
+
+/// Resolve a range over the string data of a `CStr` into start and end indices, panicking if it is
+/// out of bounds or doesn't lie on character boundaries.
+fn char_range<E, R>(str: &CStr<E>, range: R, name: &str) -> (usize, usize)
+where
+    E: NullTerminable,
+    R: RangeBounds<usize> + SliceIndex<[u8], Output = [u8]> + Clone,
+{
+    let len = str.as_bytes().get(range.clone()).map(<[u8]>::len);
+    let len = len.unwrap_or_else(|| {
+        panic!(
+            "{} string range ({}) out of bounds for C string length ({})",
+            name,
+            DisplayRange(&range),
+            str.len(),
+        )
+    });
+    // Indexing succeeded, so this can't overflow
+    let start = match range.start_bound() {
+        Bound::Included(i) => *i,
+        Bound::Excluded(i) => *i + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = start + len;
+    if !str.is_char_boundary(start) || !str.is_char_boundary(end) {
+        panic!(
+            "{} string range ({}) doesn't lie on character boundaries",
+            name,
+            DisplayRange(&range),
+        );
+    }
+    (start, end)
+}
+
+impl<E: NullChar> CStr<E> {
+    /// Convert this `CStr` into a [`Str`] including the terminating null, as a trailing `'\0'`
+    /// character. This is only available for encodings where the null byte is a valid character.
+    ///
+    /// ```
+    /// # use enrede::{CStr, encoding::Utf8};
+    /// let str = CStr::<Utf8>::from_bytes_with_nul(b"hi\0").unwrap();
+    /// assert_eq!(str.as_str_with_nul(), "hi\0");
+    /// ```
+    pub fn as_str_with_nul(&self) -> &Str<E> {
+        // SAFETY: Our content is valid for the encoding, and `E` is `NullChar`, so the terminating
+        //         null byte is a valid character following it.
+        unsafe { Str::from_bytes_unchecked(self.as_bytes_with_nul()) }
+    }
 }
 
 impl<E: NullTerminable + AlwaysValid> CStr<E> {
     /// Create a `CStr` from a byte slice, ending at the first null byte. See
-    /// [`CStr::from_bytes_til_nul`]
+    /// [`CStr::from_bytes_until_nul`]
     ///
     /// This method is provided for encodings that have no invalid byte patterns, meaning encoding
     /// validity checking is skipped.
-    pub fn from_bytes_til_nul_valid(bytes: &[u8]) -> Result<&CStr<E>, MissingNull> {
+    pub fn from_bytes_until_nul_valid(bytes: &[u8]) -> Result<&CStr<E>, MissingNull> {
         let nul_pos = bytes.iter().position(|b| *b == 0).ok_or(MissingNull)?;
         // SAFETY: Encoding has no invalid byte patterns. Data contains no internal nulls.
         Ok(unsafe { Self::from_bytes_with_nul_unchecked(&bytes[..=nul_pos]) })
     }
 
     /// Create a `CStr` from a mutable byte slice, ending at the first null byte. See
-    /// [`CStr::from_bytes_til_nul_mut`]
+    /// [`CStr::from_bytes_until_nul_mut`]
     ///
     /// This method is provided for encodings that have no invalid byte patterns, meaning encoding
     /// validity checking is skipped.
-    pub fn from_bytes_til_nul_valid_mut(bytes: &mut [u8]) -> Result<&mut CStr<E>, MissingNull> {
+    pub fn from_bytes_until_nul_valid_mut(bytes: &mut [u8]) -> Result<&mut CStr<E>, MissingNull> {
         let nul_pos = bytes.iter().position(|b| *b == 0).ok_or(MissingNull)?;
         // SAFETY: Encoding has no invalid byte patterns. Data contains no internal nulls.
         Ok(unsafe { Self::from_bytes_with_nul_unchecked_mut(&mut bytes[..=nul_pos]) })
@@ -464,6 +943,18 @@ impl<E: NullTerminable + AlwaysValid> CStr<E> {
         //         encoding.
         Ok(unsafe { CStr::from_bytes_with_nul_unchecked_mut(bytes) })
     }
+
+    /// Previous name of [`CStr::from_bytes_until_nul_valid`].
+    #[deprecated(note = "renamed to `from_bytes_until_nul_valid`, matching `std`")]
+    pub fn from_bytes_til_nul_valid(bytes: &[u8]) -> Result<&CStr<E>, MissingNull> {
+        CStr::from_bytes_until_nul_valid(bytes)
+    }
+
+    /// Previous name of [`CStr::from_bytes_until_nul_valid_mut`].
+    #[deprecated(note = "renamed to `from_bytes_until_nul_valid_mut`, matching `std`")]
+    pub fn from_bytes_til_nul_valid_mut(bytes: &mut [u8]) -> Result<&mut CStr<E>, MissingNull> {
+        CStr::from_bytes_until_nul_valid_mut(bytes)
+    }
 }
 
 impl<E: NullTerminable> fmt::Debug for CStr<E> {
@@ -488,9 +979,33 @@ impl<E: NullTerminable> PartialEq for CStr<E> {
 
 impl<E: NullTerminable> Eq for CStr<E> {}
 
+impl<E: NullTerminable> PartialEq<Str<E>> for CStr<E> {
+    fn eq(&self, other: &Str<E>) -> bool {
+        self.content_eq(other)
+    }
+}
+
+impl<E: NullTerminable> PartialEq<CStr<E>> for Str<E> {
+    fn eq(&self, other: &CStr<E>) -> bool {
+        other.content_eq(self)
+    }
+}
+
+impl<E: NullTerminable> PartialOrd for CStr<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<E: NullTerminable> Ord for CStr<E> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
+
 impl<E: NullTerminable> Hash for CStr<E> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.as_bytes().hash(state)
+        self.as_str().hash(state)
     }
 }
 
@@ -502,6 +1017,12 @@ impl<E: NullTerminable> ToOwned for CStr<E> {
         // SAFETY: Internal bytes are guaranteed valid for encoding and to contain no null bytes.
         unsafe { CString::from_vec_unchecked(self.as_bytes().to_vec()) }
     }
+
+    fn clone_into(&self, target: &mut Self::Owned) {
+        // Our bytes include the trailing null, so the target stays null-terminated
+        target.1.clear();
+        target.1.extend_from_slice(self.as_bytes_with_nul());
+    }
 }
 
 impl<E, R> Index<R> for CStr<E>
@@ -512,6 +1033,9 @@ where
     type Output = CStr<E>;
 
     fn index(&self, index: R) -> &Self::Output {
+        if !matches!(index.end_bound(), Bound::Unbounded) {
+            panic!("Attempted to slice C-string with a range which has an end");
+        }
         self.get(index)
             .expect("Attempted to slice C-string at non-character boundary")
     }
@@ -525,6 +1049,34 @@ impl<E: NullTerminable> Deref for CStr<E> {
     }
 }
 
+impl<'a, E: NullTerminable> TryFrom<&'a CoreCStr> for &'a CStr<E> {
+    type Error = ValidateError;
+
+    fn try_from(value: &'a CoreCStr) -> Result<Self, Self::Error> {
+        CStr::from_core_cstr(value)
+    }
+}
+
+impl<'a, E: NullTerminable> From<&'a CStr<E>> for &'a CoreCStr {
+    fn from(value: &'a CStr<E>) -> Self {
+        value.as_core_cstr()
+    }
+}
+
+impl<E: NullTerminable> AsRef<CStr<E>> for CStr<E> {
+    fn as_ref(&self) -> &CStr<E> {
+        self
+    }
+}
+
+/// The bytes of the string, not including the terminating null. See
+/// [`CStr::as_bytes_with_nul`] to include it.
+impl<E: NullTerminable> AsRef<[u8]> for CStr<E> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
 impl<E: NullTerminable> AsRef<Str<E>> for CStr<E> {
     fn as_ref(&self) -> &Str<E> {
         self
@@ -537,10 +1089,30 @@ impl<E: NullTerminable> Borrow<Str<E>> for CStr<E> {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<E: NullTerminable> defmt::Format for CStr<E> {
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        <Str<E> as defmt::Format>::format(self, fmt)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<E: NullTerminable> ufmt::uDisplay for CStr<E> {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        <Str<E> as ufmt::uDisplay>::fmt(self, f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::encoding::{Ascii, Utf8};
+    use crate::encoding::{
+        Ascii, Big5, ExtendedAscii, Iso8859_15, Iso8859_2, JisX0201, JisX0208, MacRoman, ShiftJis,
+        ShiftJisAscii, Utf8, Win1251, Win1252, Win1252Loose,
+    };
 
     #[test]
     fn test_from_bytes_with_nul() {
@@ -560,31 +1132,452 @@ mod tests {
     }
 
     #[test]
-    fn test_from_bytes_til_nul() {
-        let base = CStr::<Ascii>::from_bytes_til_nul(b"Hello World!\0").unwrap();
+    fn test_from_bytes_until_nul() {
+        let base = CStr::<Ascii>::from_bytes_until_nul(b"Hello World!\0").unwrap();
         assert_eq!(
-            CStr::<Ascii>::from_bytes_til_nul(b"Hello World!\0Goodbye"),
+            CStr::<Ascii>::from_bytes_until_nul(b"Hello World!\0Goodbye"),
             Ok(base),
         );
         assert_eq!(
-            CStr::<Ascii>::from_bytes_til_nul(b"Hello World!"),
-            Err(FromBytesTilNulError::MissingNull),
+            CStr::<Ascii>::from_bytes_until_nul(b"Hello World!"),
+            Err(FromBytesUntilNulError::MissingNull),
         );
         assert!(matches!(
-            CStr::<Utf8>::from_bytes_til_nul(b"Hello\x86World!\0"),
-            Err(FromBytesTilNulError::Invalid(_))
+            CStr::<Utf8>::from_bytes_until_nul(b"Hello\x86World!\0"),
+            Err(FromBytesUntilNulError::Invalid(_))
         ));
         assert_eq!(
-            CStr::<Utf8>::from_bytes_til_nul(b"Hello World!\0\x86"),
-            CStr::from_bytes_til_nul(b"Hello World!\0"),
+            CStr::<Utf8>::from_bytes_until_nul(b"Hello World!\0\x86"),
+            CStr::from_bytes_until_nul(b"Hello World!\0"),
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_until_nul_with_rest() {
+        let (str, rest) = CStr::<Ascii>::from_bytes_until_nul_with_rest(b"Hello\0World\0").unwrap();
+        assert_eq!(str.as_bytes(), b"Hello");
+        assert_eq!(rest, b"World\0");
+        let (str, rest) = CStr::<Ascii>::from_bytes_until_nul_with_rest(b"\0").unwrap();
+        assert_eq!(str.as_bytes(), b"");
+        assert_eq!(rest, b"");
+        assert_eq!(
+            CStr::<Ascii>::from_bytes_until_nul_with_rest(b"Hello"),
+            Err(FromBytesUntilNulError::MissingNull),
+        );
+    }
+
+    #[test]
+    fn test_iter_packed() {
+        let table = b"\0.text\0.data\0\0.bss\0";
+        let mut iter = CStr::<Utf8>::iter_packed(table);
+        for name in ["", ".text", ".data", "", ".bss"] {
+            assert_eq!(iter.next().unwrap().unwrap().as_str(), Str::from_std(name));
+        }
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.remainder(), b"");
+
+        // Unterminated final entry
+        let mut iter = CStr::<Utf8>::iter_packed(b"abc\0de");
+        assert_eq!(iter.next().unwrap().unwrap().as_str(), Str::from_std("abc"));
+        assert_eq!(iter.next(), Some(Err(FromBytesUntilNulError::MissingNull)));
+        assert_eq!(iter.remainder(), b"de");
+        assert_eq!(iter.next(), None);
+
+        // Trailing garbage stops iteration at the first invalid entry
+        let mut iter = CStr::<Utf8>::iter_packed(b"abc\0\xFF\xFE\0def\0");
+        assert_eq!(iter.next().unwrap().unwrap().as_str(), Str::from_std("abc"));
+        assert!(matches!(
+            iter.next(),
+            Some(Err(FromBytesUntilNulError::Invalid(_)))
+        ));
+        assert_eq!(iter.remainder(), b"\xFF\xFE\0def\0");
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(CStr::<Utf8>::iter_packed(b"").next(), None);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_from_bytes_til_nul_deprecated() {
+        let err: FromBytesTilNulError = CStr::<Ascii>::from_bytes_til_nul(b"abc").unwrap_err();
+        assert_eq!(err, FromBytesUntilNulError::MissingNull);
+        let mut bytes = *b"abc\0d";
+        assert_eq!(
+            CStr::<Ascii>::from_bytes_til_nul_mut(&mut bytes)
+                .unwrap()
+                .as_bytes(),
+            b"abc"
+        );
+        assert_eq!(
+            CStr::<ExtendedAscii>::from_bytes_til_nul_valid(b"ab\0c")
+                .unwrap()
+                .as_bytes(),
+            b"ab"
+        );
+        let mut bytes = *b"abc";
+        assert_eq!(
+            CStr::<ExtendedAscii>::from_bytes_til_nul_valid_mut(&mut bytes),
+            Err(MissingNull)
         );
     }
 
     #[test]
     fn test_bytes_with_nul() {
-        let c = CStr::<Utf8>::from_bytes_til_nul(b"Hello World!\0").unwrap();
+        let c = CStr::<Utf8>::from_bytes_until_nul(b"Hello World!\0").unwrap();
 
         assert_eq!(c.as_bytes(), b"Hello World!");
         assert_eq!(c.as_bytes_with_nul(), b"Hello World!\0");
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_recode_c() {
+        use crate::encoding::{JisX0208, Win1252};
+
+        let str = CStr::<JisX0208>::from_bytes_with_nul(b"\x25\x4F \x25\x6D\0").unwrap();
+        let out = str.recode_c::<Utf8>().unwrap();
+        assert_eq!(out.as_bytes_with_nul(), "ハ ロ\0".as_bytes());
+
+        let str = CStr::<Utf8>::from_bytes_with_nul("a€b\0".as_bytes()).unwrap();
+        let out = str.recode_c::<Win1252>().unwrap();
+        assert_eq!(out.as_bytes_with_nul(), b"a\x80b\0");
+        let err = str.recode_c::<Ascii>().unwrap_err();
+        assert_eq!((err.valid_up_to(), err.char()), (1, '€'));
+
+        let out = str.recode_c_lossy::<Ascii>();
+        assert_eq!(out.as_bytes_with_nul(), b"a\x1Ab\0");
+
+        let str = CStr::<Utf8>::from_bytes_with_nul(b"\0").unwrap();
+        assert_eq!(
+            str.recode_c::<JisX0208>().unwrap().as_bytes_with_nul(),
+            b"\0"
+        );
+    }
+
+    #[test]
+    fn test_copy_from() {
+        let mut bytes = *b"Hello\0";
+        let dest = CStr::<Utf8>::from_bytes_with_nul_mut(&mut bytes).unwrap();
+        dest.copy_from(CStr::from_bytes_with_nul(b"World\0").unwrap());
+        assert_eq!(dest.as_bytes_with_nul(), b"World\0");
+
+        // Only the string data is compared and copied, the null stays at the end
+        let src = CStr::from_bytes_with_nul(b"Hi\0").unwrap();
+        let err = dest.try_copy_from(src).unwrap_err();
+        assert_eq!((err.source_len(), err.dest_len()), (2, 5));
+        let src = CStr::from_bytes_with_nul(b"Hello!\0").unwrap();
+        assert!(dest.try_copy_from(src).is_err());
+        assert_eq!(dest.as_bytes_with_nul(), b"World\0");
+
+        let mut bytes = *b"\0";
+        let dest = CStr::<Utf8>::from_bytes_with_nul_mut(&mut bytes).unwrap();
+        dest.copy_from(CStr::from_bytes_with_nul(b"\0").unwrap());
+        assert_eq!(dest.as_bytes_with_nul(), b"\0");
+    }
+
+    #[test]
+    #[should_panic = "source string length (6) doesn't match destination string length (5)"]
+    fn test_copy_from_mismatch() {
+        let mut bytes = *b"Hello\0";
+        let dest = CStr::<Utf8>::from_bytes_with_nul_mut(&mut bytes).unwrap();
+        dest.copy_from(CStr::from_bytes_with_nul(b"Hello!\0").unwrap());
+    }
+
+    #[test]
+    fn test_copy_range() {
+        let mut bytes = *b"Hello World!\0";
+        let dest = CStr::<Utf8>::from_bytes_with_nul_mut(&mut bytes).unwrap();
+        let src = CStr::<Utf8>::from_bytes_with_nul(b"Goodbye Moon\0").unwrap();
+
+        dest.copy_range(src, 8..12, 6..10);
+        assert_eq!(dest.as_bytes_with_nul(), b"Hello Moond!\0");
+        dest.copy_range(src, ..4, 0..=3);
+        assert_eq!(dest.as_bytes_with_nul(), b"Goodo Moond!\0");
+        dest.copy_range(src, .., ..);
+        assert_eq!(dest.as_bytes_with_nul(), b"Goodbye Moon\0");
+    }
+
+    #[test]
+    fn test_copy_range_multibyte() {
+        let mut bytes = *b"A\xF0\x90\x90\xB7B\0";
+        let dest = CStr::<Utf8>::from_bytes_with_nul_mut(&mut bytes).unwrap();
+        let src = CStr::<Utf8>::from_bytes_with_nul("€abcd\0".as_bytes()).unwrap();
+
+        dest.copy_range(src, 3..7, 1..5);
+        assert_eq!(dest.as_bytes_with_nul(), b"AabcdB\0");
+    }
+
+    #[test]
+    #[should_panic = "Source string range (0..14) out of bounds for C string length (13)"]
+    fn test_copy_range_src_oob() {
+        let mut bytes = *b"Hello World!!\0";
+        let dest = CStr::<Utf8>::from_bytes_with_nul_mut(&mut bytes).unwrap();
+        let src = CStr::<Utf8>::from_bytes_with_nul(b"Goodbye Moon!\0").unwrap();
+        // Including the null byte is out of bounds
+        dest.copy_range(src, 0..14, 0..13);
+    }
+
+    #[test]
+    #[should_panic = "Destination string range (5..) out of bounds for C string length (3)"]
+    fn test_copy_range_dest_oob() {
+        let mut bytes = *b"Hey\0";
+        let dest = CStr::<Utf8>::from_bytes_with_nul_mut(&mut bytes).unwrap();
+        let src = CStr::<Utf8>::from_bytes_with_nul(b"Goodbye Moon!\0").unwrap();
+        dest.copy_range(src, 0..0, 5..);
+    }
+
+    #[test]
+    #[should_panic = "Source string range (1..3) doesn't lie on character boundaries"]
+    fn test_copy_range_src_boundary() {
+        let mut bytes = *b"Hello\0";
+        let dest = CStr::<Utf8>::from_bytes_with_nul_mut(&mut bytes).unwrap();
+        let src = CStr::<Utf8>::from_bytes_with_nul("€abc\0".as_bytes()).unwrap();
+        dest.copy_range(src, 1..3, 0..2);
+    }
+
+    #[test]
+    #[should_panic = "Destination string range (2..4) doesn't lie on character boundaries"]
+    fn test_copy_range_dest_boundary() {
+        let mut bytes = *b"A\xF0\x90\x90\xB7B\0";
+        let dest = CStr::<Utf8>::from_bytes_with_nul_mut(&mut bytes).unwrap();
+        let src = CStr::<Utf8>::from_bytes_with_nul(b"Hello\0").unwrap();
+        dest.copy_range(src, 0..2, 2..4);
+    }
+
+    #[test]
+    #[should_panic = "Source range length (2) doesn't match destination range length (3)"]
+    fn test_copy_range_len_mismatch() {
+        let mut bytes = *b"Hello\0";
+        let dest = CStr::<Utf8>::from_bytes_with_nul_mut(&mut bytes).unwrap();
+        let src = CStr::<Utf8>::from_bytes_with_nul(b"World\0").unwrap();
+        dest.copy_range(src, 0..2, 0..3);
+    }
+
+    #[test]
+    fn test_fill_truncated() {
+        let src = Str::<ShiftJis>::from_bytes(b"a\x82\xA0b").unwrap();
+
+        // Truncating inside the two-byte character drops it entirely
+        let mut buf = [0xFF; 3];
+        assert_eq!(CStr::fill_truncated(&mut buf, src), 1);
+        assert_eq!(&buf, b"a\0\0");
+
+        let mut buf = [0xFF; 4];
+        assert_eq!(CStr::fill_truncated(&mut buf, src), 3);
+        assert_eq!(&buf, b"a\x82\xA0\0");
+
+        // Shorter source zero-fills the remainder
+        let mut buf = [0xFF; 8];
+        assert_eq!(CStr::fill_truncated(&mut buf, src), 4);
+        assert_eq!(&buf, b"a\x82\xA0b\0\0\0\0");
+        let str = CStr::<ShiftJis>::from_bytes_until_nul(&buf).unwrap();
+        assert_eq!(str.as_str(), src);
+
+        let mut buf = [0xFF; 1];
+        assert_eq!(CStr::fill_truncated(&mut buf, src), 0);
+        assert_eq!(&buf, b"\0");
+
+        // Stops before an interior null character
+        let mut buf = [0xFF; 8];
+        assert_eq!(
+            CStr::fill_truncated(&mut buf, Str::<Utf8>::from_std("ab\0cd")),
+            2
+        );
+        assert_eq!(&buf, b"ab\0\0\0\0\0\0");
+    }
+
+    #[test]
+    #[should_panic = "Buffer has no room for a terminating null byte"]
+    fn test_fill_truncated_empty() {
+        CStr::fill_truncated(&mut [], Str::<Utf8>::from_std("a"));
+    }
+
+    #[test]
+    fn test_get() {
+        let empty = CStr::<Utf8>::from_bytes_with_nul(b"\0").unwrap();
+        assert_eq!(empty.get(..), Some(empty));
+        assert_eq!(empty.get(0..), Some(empty));
+        assert_eq!(empty.get(1..), None);
+
+        let single = CStr::<Utf8>::from_bytes_with_nul(b"A\0").unwrap();
+        assert_eq!(single.get(..), Some(single));
+        assert_eq!(single.get(0..), Some(single));
+        assert_eq!(single.get(1..), Some(empty));
+        assert_eq!(single.get(2..), None);
+
+        let multi = CStr::<Utf8>::from_bytes_with_nul("A𐐷b\0".as_bytes()).unwrap();
+        assert_eq!(multi.get(..), Some(multi));
+        assert_eq!(
+            multi.get(1..).map(CStr::as_bytes_with_nul),
+            Some("𐐷b\0".as_bytes())
+        );
+        assert_eq!(multi.get(2..), None);
+        assert_eq!(multi.get(3..), None);
+        assert_eq!(multi.get(4..), None);
+        assert_eq!(
+            multi.get(5..).map(CStr::as_bytes_with_nul),
+            Some(&b"b\0"[..])
+        );
+        assert_eq!(multi.get(6..), Some(empty));
+        assert_eq!(multi.get(7..), None);
+        assert_eq!(multi.get(usize::MAX..), None);
+    }
+
+    #[test]
+    fn test_index() {
+        let multi = CStr::<Utf8>::from_bytes_with_nul("A𐐷b\0".as_bytes()).unwrap();
+        assert_eq!(multi[..].as_bytes_with_nul(), multi.as_bytes_with_nul());
+        assert_eq!(multi[5..].as_bytes_with_nul(), b"b\0");
+        assert_eq!(multi[6..].as_bytes_with_nul(), b"\0");
+        assert_eq!(multi.slice_from(1).as_bytes_with_nul(), "𐐷b\0".as_bytes());
+    }
+
+    #[test]
+    fn test_get_bounds() {
+        let multi = CStr::<Utf8>::from_bytes_with_nul("A𐐷b\0".as_bytes()).unwrap();
+        let empty = CStr::<Utf8>::from_bytes_with_nul(b"\0").unwrap();
+        assert_eq!(multi.get((Bound::Unbounded, Bound::Unbounded)), Some(multi));
+        assert_eq!(
+            multi.get((Bound::Included(5), Bound::Unbounded)),
+            Some(&multi[5..])
+        );
+        assert_eq!(
+            multi.get((Bound::Excluded(0), Bound::Unbounded)),
+            Some(&multi[1..])
+        );
+        assert_eq!(
+            multi.get((Bound::Excluded(5), Bound::Unbounded)),
+            Some(empty)
+        );
+        assert_eq!(multi.get((Bound::Included(2), Bound::Unbounded)), None);
+        assert_eq!(
+            multi.get((Bound::Excluded(usize::MAX), Bound::Unbounded)),
+            None
+        );
+        assert_eq!(multi.get((Bound::Included(0), Bound::Excluded(6))), None);
+        assert_eq!(multi.get((Bound::Included(0), Bound::Included(6))), None);
+
+        let mut bytes = *b"abc\0";
+        let str = CStr::<Utf8>::from_bytes_with_nul_mut(&mut bytes).unwrap();
+        assert_eq!(
+            str.get_mut((Bound::Included(1), Bound::Unbounded))
+                .map(|s| s.as_bytes_with_nul()),
+            Some(&b"bc\0"[..])
+        );
+        assert_eq!(str.get_mut((Bound::Included(1), Bound::Excluded(2))), None);
+    }
+
+    #[test]
+    #[should_panic = "Attempted to slice C-string with a range which has an end"]
+    fn test_index_bounded_end() {
+        let str = CStr::<Utf8>::from_bytes_with_nul(b"abc\0").unwrap();
+        let _ = &str[(Bound::Included(0), Bound::Excluded(2))];
+    }
+
+    #[test]
+    #[should_panic = "Attempted to slice C-string at non-character boundary"]
+    fn test_slice_from_non_boundary() {
+        let str = CStr::<Utf8>::from_bytes_with_nul("é\0".as_bytes()).unwrap();
+        str.slice_from(1);
+    }
+
+    #[test]
+    #[should_panic = "Attempted to slice C-string at non-character boundary"]
+    fn test_index_non_boundary() {
+        let multi = CStr::<Utf8>::from_bytes_with_nul("A𐐷b\0".as_bytes()).unwrap();
+        let _ = &multi[2..];
+    }
+
+    #[test]
+    fn test_chars_with_nul() {
+        let c = CStr::<Utf8>::from_bytes_with_nul("A𐐷\0".as_bytes()).unwrap();
+        assert!(c.chars().eq(['A', '𐐷']));
+        assert!(c.chars_with_nul().eq(['A', '𐐷', '\0']));
+    }
+
+    #[test]
+    fn test_split_at() {
+        let c = CStr::<Utf8>::from_bytes_with_nul(b"ab\0").unwrap();
+        let (start, end) = c.split_at(1).unwrap();
+        assert_eq!(
+            (start.as_bytes(), end.as_bytes_with_nul()),
+            (&b"a"[..], &b"b\0"[..])
+        );
+        assert!(c.split_at(2).is_none());
+        assert!(c.split_at(3).is_none());
+    }
+
+    #[test]
+    fn test_core_cstr() {
+        let core = c"caf\xE9 \x80";
+        let str = CStr::<Win1252>::from_core_cstr(core).unwrap();
+        assert_eq!(str.as_str(), "café €");
+        assert_eq!(str.as_bytes_with_nul().as_ptr(), core.as_ptr().cast());
+        assert_eq!(str.as_core_cstr(), core);
+
+        let str: &CStr<Win1252> = core.try_into().unwrap();
+        let back: &CoreCStr = str.into();
+        assert_eq!(back, core);
+
+        let empty = CStr::<Utf8>::from_core_cstr(c"").unwrap();
+        assert_eq!(empty, CStr::EMPTY);
+        assert_eq!(empty.as_core_cstr(), c"");
+
+        assert_eq!(
+            CStr::<Utf8>::from_core_cstr(c"caf\xE9!"),
+            Err(ValidateError::new(3, Some(1)))
+        );
+        assert_eq!(
+            <&CStr<Ascii>>::try_from(c"caf\xE9"),
+            Err(ValidateError::new(3, Some(1)))
+        );
+    }
+
+    #[test]
+    fn test_as_str_with_nul() {
+        fn check<E: NullChar>(bytes: &[u8]) {
+            let cstr = CStr::<E>::from_bytes_with_nul(bytes).unwrap();
+            let str = cstr.as_str_with_nul();
+            assert_eq!(str.len(), cstr.len() + 1, "{}", E::shorthand());
+            assert_eq!(cstr.len_with_nul(), str.len());
+            assert_eq!(str.as_bytes(), bytes);
+            assert!(
+                str.chars().eq(cstr.chars().chain(['\0'])),
+                "{}",
+                E::shorthand()
+            );
+        }
+        check::<Ascii>(b"abc\0");
+        check::<ExtendedAscii>(b"a\xFF\0");
+        check::<Big5>(b"\xA4\xA4a\0");
+        check::<Iso8859_2>(b"a\xA1\0");
+        check::<Iso8859_15>(b"a\xA4\0");
+        check::<JisX0201>(b"a\xB1\0");
+        check::<JisX0208>(b"\x30\x21\0");
+        check::<MacRoman>(b"a\x80\0");
+        check::<ShiftJis>(b"\x83\x6E\0");
+        check::<ShiftJisAscii>(b"a\x83\x6E\0");
+        check::<Utf8>(b"a\xC3\xA9\0");
+        check::<Utf8>(b"\0");
+        check::<Win1251>(b"a\xC6\0");
+        check::<Win1252>(b"a\x80\0");
+        check::<Win1252Loose>(b"a\x81\0");
+    }
+
+    #[test]
+    fn test_empty() {
+        static EMPTY: &CStr<Ascii> = CStr::EMPTY;
+        assert_eq!(EMPTY.as_bytes_with_nul(), b"\0");
+        assert!(EMPTY.as_str().is_empty());
+
+        fn check<E: NullTerminable + 'static>() {
+            assert_eq!(CStr::<E>::EMPTY, <&CStr<E>>::default());
+            assert_eq!(CStr::<E>::EMPTY.as_bytes_with_nul(), b"\0");
+        }
+        check::<Utf8>();
+        check::<Win1252>();
+        check::<Big5>();
+        check::<JisX0208>();
+        check::<ShiftJisAscii>();
+    }
 }
@@ -1,19 +1,22 @@
 //! Implementation and utilities for a generically encoded [`std::ffi::CString`] equivalent type.
 
+use alloc::collections::TryReserveError;
 use alloc::vec::Vec;
 use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
 use core::fmt;
 use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
 use core::ops::{Deref, DerefMut};
 
-use crate::cstr::CStr;
+use crate::cstr::{CStr, FromBytesWithNulError};
 use crate::encoding::{AlwaysValid, Encoding, NullTerminable, ValidateError};
 use crate::str::Str;
 use crate::string::String;
+use crate::utils::paranoid_assert;
 
 /// The cause of an error while creating a [`CString`]
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
 pub enum CStringErrorCause {
     /// The input wasn't valid for the desired encoding
@@ -23,10 +26,25 @@ pub enum CStringErrorCause {
         /// The position of the null byte in the input
         idx: usize,
     },
+    /// Allocating space for the trailing null byte or a copy of the input failed. Only returned
+    /// by [`CString::try_new`].
+    Alloc(TryReserveError),
+}
+
+impl fmt::Display for CStringErrorCause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CStringErrorCause::Invalid(_) => write!(f, "data isn't valid for the encoding"),
+            CStringErrorCause::HasNull { idx } => {
+                write!(f, "data contains an interior null byte at index {}", idx)
+            }
+            CStringErrorCause::Alloc(_) => write!(f, "failed to allocate C string"),
+        }
+    }
 }
 
 /// An error encountered while creating a new [`CString`] from a container of bytes
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct CStringError {
     bytes: Vec<u8>,
     cause: CStringErrorCause,
@@ -44,11 +62,28 @@ impl CStringError {
     }
 }
 
-/// An error encountered while converting a [`String`] into a [`CString`]
-#[derive(Debug, PartialEq)]
+impl fmt::Display for CStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.cause, f)
+    }
+}
+
+impl core::error::Error for CStringError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self.cause {
+            CStringErrorCause::Invalid(e) => Some(e),
+            CStringErrorCause::HasNull { .. } => None,
+            CStringErrorCause::Alloc(e) => Some(e),
+        }
+    }
+}
+
+/// An error encountered while converting a [`String`] into a [`CString`] or boxed
+/// [`WideCStr`](crate::WideCStr)
+#[derive(Clone, Debug, PartialEq)]
 pub struct NulError {
-    bytes: Vec<u8>,
-    nul_pos: usize,
+    pub(crate) bytes: Vec<u8>,
+    pub(crate) nul_pos: usize,
 }
 
 impl NulError {
@@ -64,12 +99,138 @@ impl NulError {
     }
 }
 
+impl fmt::Display for NulError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "data contains an interior null byte at index {}",
+            self.nul_pos
+        )
+    }
+}
+
+impl core::error::Error for NulError {}
+
+/// An error encountered while converting a vector of bytes with a trailing null byte into a
+/// [`CString`], returned by [`CString::from_vec_with_nul`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FromVecWithNulError {
+    bytes: Vec<u8>,
+    cause: FromBytesWithNulError,
+}
+
+impl FromVecWithNulError {
+    /// Get the cause of this error
+    pub fn cause(&self) -> &FromBytesWithNulError {
+        &self.cause
+    }
+
+    /// Get the input bytes which generated the error
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Consume this error, returning the input bytes which generated the error in the first place.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl fmt::Display for FromVecWithNulError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.cause, f)
+    }
+}
+
+impl core::error::Error for FromVecWithNulError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        self.cause.source()
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Input accepted by [`CString::new`]. Byte containers are checked for null bytes and encoding
+/// validity, while strings already known to be valid for the encoding are only checked for null
+/// bytes.
+///
+/// This trait is sealed, as [`CString::new`] trusts implementations to only skip validation for
+/// valid data.
+pub trait IntoCStringInput<E: NullTerminable>: sealed::Sealed {
+    /// Convert this input into bytes, and whether they're already known to be valid for `E`
+    #[doc(hidden)]
+    fn into_input(self) -> (Vec<u8>, bool);
+
+    /// Like [`into_input`](Self::into_input), but reporting failure to copy borrowed input
+    #[doc(hidden)]
+    fn try_into_input(self) -> Result<(Vec<u8>, bool), TryReserveError>
+    where
+        Self: Sized,
+    {
+        Ok(self.into_input())
+    }
+}
+
+impl<T: Into<Vec<u8>>> sealed::Sealed for T {}
+
+impl<E: NullTerminable, T: Into<Vec<u8>>> IntoCStringInput<E> for T {
+    fn into_input(self) -> (Vec<u8>, bool) {
+        (self.into(), false)
+    }
+}
+
+impl<E> sealed::Sealed for &Str<E> {}
+
+impl<E: NullTerminable> IntoCStringInput<E> for &Str<E> {
+    fn into_input(self) -> (Vec<u8>, bool) {
+        (self.as_bytes().to_vec(), true)
+    }
+
+    fn try_into_input(self) -> Result<(Vec<u8>, bool), TryReserveError> {
+        Ok((self.try_to_owned()?.into_bytes(), true))
+    }
+}
+
+impl<E> sealed::Sealed for &String<E> {}
+
+impl<E: NullTerminable> IntoCStringInput<E> for &String<E> {
+    fn into_input(self) -> (Vec<u8>, bool) {
+        (self.as_bytes().to_vec(), true)
+    }
+
+    fn try_into_input(self) -> Result<(Vec<u8>, bool), TryReserveError> {
+        Ok((self.try_to_owned()?.into_bytes(), true))
+    }
+}
+
+impl<E> sealed::Sealed for String<E> {}
+
+impl<E: NullTerminable> IntoCStringInput<E> for String<E> {
+    fn into_input(self) -> (Vec<u8>, bool) {
+        (self.into_bytes(), true)
+    }
+}
+
+impl<E> sealed::Sealed for &CStr<E> {}
+
+impl<E: NullTerminable> IntoCStringInput<E> for &CStr<E> {
+    fn into_input(self) -> (Vec<u8>, bool) {
+        (self.as_bytes().to_vec(), true)
+    }
+
+    fn try_into_input(self) -> Result<(Vec<u8>, bool), TryReserveError> {
+        Ok((self.try_to_owned()?.into_bytes(), true))
+    }
+}
+
 /// A type representing an owned, generically-encoded C-string. This means the string contains a
 /// single trailing null byte, with no other null bytes internally.
 ///
 /// This type is to [`CStr`] as [`String`] is to [`Str`] - it represents the owned form of C string,
 /// while [`CStr`] represents the borrowed form.
-pub struct CString<E>(PhantomData<E>, Vec<u8>);
+pub struct CString<E>(PhantomData<E>, pub(crate) Vec<u8>);
 
 impl<E: Encoding + NullTerminable> CString<E> {
     /// Create a C string from a byte vector, without checking for interior null
@@ -83,24 +244,62 @@ impl<E: Encoding + NullTerminable> CString<E> {
     /// The provided vector must contain no null bytes and be valid for the
     /// current encoding.
     pub unsafe fn from_vec_unchecked(mut bytes: Vec<u8>) -> CString<E> {
+        paranoid_assert!(!bytes.contains(&0));
+        paranoid_assert!(E::validate(&bytes).is_ok());
         bytes.push(0);
         CString(PhantomData, bytes)
     }
 
-    /// Create a new C string from a container of bytes. The provided data should contain no null
-    /// bytes.
+    /// Create a C string from a byte vector which already ends with a null byte, without checking
+    /// for interior null bytes or valid encoding. The vector is used as-is, without reallocating.
+    ///
+    /// # Safety
+    ///
+    /// The provided vector must end with a null byte, contain no other null bytes, and be valid
+    /// for the current encoding.
+    pub unsafe fn from_vec_with_nul_unchecked(bytes: Vec<u8>) -> CString<E> {
+        paranoid_assert!(bytes.last() == Some(&0));
+        paranoid_assert!(!bytes[..bytes.len() - 1].contains(&0));
+        paranoid_assert!(E::validate(&bytes[..bytes.len() - 1]).is_ok());
+        CString(PhantomData, bytes)
+    }
+
+    /// Create a C string from a byte vector which already ends with a single null byte, such as
+    /// one returned by [`CString::into_bytes_with_nul`]. The vector is used as-is, without
+    /// reallocating.
+    ///
+    /// An error is returned if the vector doesn't end in a null byte, contains null bytes at any
+    /// other position, or isn't valid in the current encoding. The error can be converted back
+    /// into the original vector.
+    pub fn from_vec_with_nul(bytes: Vec<u8>) -> Result<CString<E>, FromVecWithNulError> {
+        match CStr::<E>::from_bytes_with_nul(&bytes).map(|_| ()) {
+            // SAFETY: Data validated to end with the only null byte, and be valid for the encoding
+            Ok(()) => Ok(unsafe { Self::from_vec_with_nul_unchecked(bytes) }),
+            Err(cause) => Err(FromVecWithNulError { bytes, cause }),
+        }
+    }
+
+    /// Create a new C string from a container of bytes or a string. The provided data should
+    /// contain no null bytes.
     ///
     /// This function will consume and validate the provided data, checking that it contains no null
     /// bytes and is valid for the current encoding. If those checks pass, a single null byte is
-    /// appended to the end.
-    ///
-    /// If you have a [`String<E>`], you should prefer the [`CString::try_from`] implementation.
-    /// It is capable of skipping the encoding check and only validating the lack of null bytes.
+    /// appended to the end. When passed a [`Str<E>`], [`String<E>`], or [`CStr<E>`], the encoding
+    /// check is skipped, as the data is already known to be valid. See [`IntoCStringInput`] for
+    /// all accepted types.
     pub fn new<T>(bytes: T) -> Result<CString<E>, CStringError>
     where
-        T: Into<Vec<u8>>,
+        T: IntoCStringInput<E>,
     {
-        let bytes = bytes.into();
+        let (bytes, valid) = bytes.into_input();
+        let bytes = Self::check_input(bytes, valid)?;
+        // SAFETY: Data validated to contain no nulls and be valid for the encoding
+        Ok(unsafe { Self::from_vec_unchecked(bytes) })
+    }
+
+    /// Check input to [`CString::new`] or [`CString::try_new`] for null bytes, and if it's not
+    /// already known to be valid, for encoding validity.
+    fn check_input(bytes: Vec<u8>, valid: bool) -> Result<Vec<u8>, CStringError> {
         let nul_pos = bytes.iter().position(|b| *b == 0);
         if let Some(idx) = nul_pos {
             return Err(CStringError {
@@ -108,17 +307,66 @@ impl<E: Encoding + NullTerminable> CString<E> {
                 cause: CStringErrorCause::HasNull { idx },
             });
         }
-        // Can't use map_err due to moving `bytes`, sad :(
-        if let Err(e) = E::validate(&bytes) {
+        if !valid {
+            // Can't use map_err due to moving `bytes`, sad :(
+            if let Err(e) = E::validate(&bytes) {
+                return Err(CStringError {
+                    bytes,
+                    cause: CStringErrorCause::Invalid(e),
+                });
+            }
+        }
+        Ok(bytes)
+    }
+
+    /// Create a new C string from a container of bytes or a string, like [`CString::new`], but
+    /// returning [`CStringErrorCause::Alloc`] instead of aborting if allocation fails.
+    ///
+    /// Borrowed strings are copied fallibly. Byte containers are converted into a [`Vec<u8>`]
+    /// with [`Into`] as usual, so may still abort when that conversion allocates - pass an owned
+    /// `Vec<u8>` to avoid this. In that case, the error can be converted back into the input.
+    pub fn try_new<T>(bytes: T) -> Result<CString<E>, CStringError>
+    where
+        T: IntoCStringInput<E>,
+    {
+        let (bytes, valid) = bytes.try_into_input().map_err(|e| CStringError {
+            bytes: Vec::new(),
+            cause: CStringErrorCause::Alloc(e),
+        })?;
+        let mut bytes = Self::check_input(bytes, valid)?;
+        if let Err(e) = bytes.try_reserve_exact(1) {
             return Err(CStringError {
                 bytes,
-                cause: CStringErrorCause::Invalid(e),
+                cause: CStringErrorCause::Alloc(e),
             });
         }
         // SAFETY: Data validated to contain no nulls and be valid for the encoding
         Ok(unsafe { Self::from_vec_unchecked(bytes) })
     }
 
+    /// Create a C string from as much of `src` as fits in `n` bytes, including the terminating null
+    /// byte, with a capacity of `n`. This truncates `src` the same way as
+    /// [`CStr::fill_truncated`], so a multi-byte character is never split, and the string ends
+    /// before any null character in `src`.
+    ///
+    /// ```
+    /// # use enrede::{CString, Str, encoding::Utf8};
+    /// let str = CString::<Utf8>::with_capacity_filled(8, Str::from_std("Hello, world!"));
+    /// assert_eq!(str.as_bytes_with_nul(), b"Hello, \0");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `n` is zero, as there is no room for the terminating null byte.
+    pub fn with_capacity_filled(n: usize, src: &Str<E>) -> CString<E> {
+        let mut bytes = alloc::vec![0; n];
+        let len = CStr::fill_truncated(&mut bytes, src);
+        bytes.truncate(len + 1);
+        // SAFETY: The data is a prefix of `src` ending on a character boundary, before any null
+        //         bytes, followed by a single null byte
+        unsafe { Self::from_vec_with_nul_unchecked(bytes) }
+    }
+
     /// Convert this `CString` into a [`String`] by removing the trailing null. Unlike the
     /// equivalent `std` method, this is infallible because our `CString` is encoding-specific.
     pub fn into_string(self) -> String<E> {
@@ -173,6 +421,16 @@ impl<E: NullTerminable> fmt::Debug for CString<E> {
     }
 }
 
+impl<E: NullTerminable> Clone for CString<E> {
+    fn clone(&self) -> Self {
+        CString(PhantomData, self.1.clone())
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.1.clone_from(&source.1);
+    }
+}
+
 impl<E: NullTerminable> Default for CString<E> {
     fn default() -> Self {
         // SAFETY: Empty vector is trivially valid
@@ -188,9 +446,59 @@ impl<E: NullTerminable> PartialEq for CString<E> {
 
 impl<E: NullTerminable> Eq for CString<E> {}
 
+impl<E: NullTerminable> PartialEq<CStr<E>> for CString<E> {
+    fn eq(&self, other: &CStr<E>) -> bool {
+        **self == *other
+    }
+}
+
+impl<E: NullTerminable> PartialEq<&CStr<E>> for CString<E> {
+    fn eq(&self, other: &&CStr<E>) -> bool {
+        **self == **other
+    }
+}
+
+impl<E: NullTerminable> PartialEq<CString<E>> for CStr<E> {
+    fn eq(&self, other: &CString<E>) -> bool {
+        *self == **other
+    }
+}
+
+impl<E: NullTerminable> PartialEq<Str<E>> for CString<E> {
+    fn eq(&self, other: &Str<E>) -> bool {
+        self.content_eq(other)
+    }
+}
+
+impl<E: NullTerminable> PartialEq<&Str<E>> for CString<E> {
+    fn eq(&self, other: &&Str<E>) -> bool {
+        self.content_eq(other)
+    }
+}
+
+impl<E: NullTerminable> PartialEq<CString<E>> for Str<E> {
+    fn eq(&self, other: &CString<E>) -> bool {
+        other.content_eq(self)
+    }
+}
+
+/// Orders by the content bytes, like [`CStr`]. For ordering by character, compare the contained
+/// strings with [`Str::cmp_chars`].
+impl<E: NullTerminable> PartialOrd for CString<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<E: NullTerminable> Ord for CString<E> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
 impl<E: NullTerminable> Hash for CString<E> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.as_bytes().hash(state)
+        (**self).hash(state)
     }
 }
 
@@ -228,6 +536,14 @@ impl<E: NullTerminable> AsRef<Str<E>> for CString<E> {
     }
 }
 
+/// The bytes of the string, not including the terminating null. See
+/// [`CStr::as_bytes_with_nul`] to include it.
+impl<E: NullTerminable> AsRef<[u8]> for CString<E> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
 impl<E: NullTerminable> Borrow<CStr<E>> for CString<E> {
     fn borrow(&self) -> &CStr<E> {
         self
@@ -240,6 +556,17 @@ impl<E: NullTerminable> BorrowMut<CStr<E>> for CString<E> {
     }
 }
 
+/// Zeroes the full capacity of the string, leaving it empty except for the terminating null. Wrap
+/// the string in [`Zeroizing`](zeroize::Zeroizing) to zero it when dropped.
+#[cfg(feature = "zeroize")]
+impl<E: NullTerminable> zeroize::Zeroize for CString<E> {
+    fn zeroize(&mut self) {
+        self.1.zeroize();
+        // Zeroizing keeps the capacity, so this never reallocates
+        self.1.push(0);
+    }
+}
+
 impl<E: NullTerminable> TryFrom<String<E>> for CString<E> {
     type Error = NulError;
 
@@ -255,6 +582,14 @@ impl<E: NullTerminable> TryFrom<String<E>> for CString<E> {
     }
 }
 
+impl<E: NullTerminable> TryFrom<Vec<u8>> for CString<E> {
+    type Error = CStringError;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        CString::new(value)
+    }
+}
+
 impl<E: NullTerminable> TryFrom<alloc::ffi::CString> for CString<E> {
     type Error = ValidateError;
 
@@ -268,3 +603,268 @@ impl<E: NullTerminable> From<CString<E>> for alloc::ffi::CString {
         value.into_std()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::{ShiftJis, Utf8};
+
+    #[cfg(any(debug_assertions, feature = "paranoid-debug"))]
+    #[test]
+    #[should_panic]
+    fn test_paranoid_from_vec_unchecked() {
+        // SAFETY: Not actually safe - checks that internal nulls are caught in paranoid mode
+        let _ = unsafe { CString::<Utf8>::from_vec_unchecked(b"Ab\0c".to_vec()) };
+    }
+
+    #[test]
+    fn test_from_vec_with_nul() {
+        let str = CString::<Utf8>::new("Hello, ä").unwrap();
+        let bytes = str.into_bytes_with_nul();
+        let (ptr, cap) = (bytes.as_ptr(), bytes.capacity());
+
+        let str = CString::<Utf8>::from_vec_with_nul(bytes).unwrap();
+        assert_eq!(str.as_bytes_with_nul(), "Hello, ä\0".as_bytes());
+        let bytes = str.into_bytes_with_nul();
+        assert_eq!((bytes.as_ptr(), bytes.capacity()), (ptr, cap));
+
+        let str = CString::<Utf8>::from_vec_with_nul(b"\0".to_vec()).unwrap();
+        assert_eq!(str.as_bytes(), b"");
+    }
+
+    #[test]
+    fn test_with_capacity_filled() {
+        let src = Str::<ShiftJis>::from_bytes(b"a\x82\xA0b").unwrap();
+        let str = CString::with_capacity_filled(3, src);
+        assert_eq!(str.as_bytes_with_nul(), b"a\0");
+        assert_eq!(str.into_bytes_with_nul().capacity(), 3);
+
+        let str = CString::with_capacity_filled(16, src);
+        assert_eq!(str.as_bytes_with_nul(), b"a\x82\xA0b\0");
+        assert_eq!(str.into_bytes_with_nul().capacity(), 16);
+
+        let str = CString::<Utf8>::with_capacity_filled(16, Str::from_std("ab\0cd"));
+        assert_eq!(str.as_bytes_with_nul(), b"ab\0");
+    }
+
+    #[test]
+    fn test_from_vec_with_nul_invalid() {
+        let err = CString::<Utf8>::from_vec_with_nul(b"abc".to_vec()).unwrap_err();
+        assert_eq!(err.cause(), &FromBytesWithNulError::MissingNull);
+        assert_eq!(err.into_bytes(), b"abc");
+
+        let err = CString::<Utf8>::from_vec_with_nul(Vec::new()).unwrap_err();
+        assert_eq!(err.cause(), &FromBytesWithNulError::MissingNull);
+
+        let err = CString::<Utf8>::from_vec_with_nul(b"a\0b\0".to_vec()).unwrap_err();
+        assert_eq!(err.cause(), &FromBytesWithNulError::HasNull { idx: 1 });
+        assert_eq!(err.as_bytes(), b"a\0b\0");
+
+        let err = CString::<Utf8>::from_vec_with_nul(b"a\0\0".to_vec()).unwrap_err();
+        assert_eq!(err.cause(), &FromBytesWithNulError::HasNull { idx: 1 });
+
+        let err = CString::<Utf8>::from_vec_with_nul(b"a\xFF\0".to_vec()).unwrap_err();
+        assert_eq!(
+            err.cause(),
+            &FromBytesWithNulError::Invalid(ValidateError::new(1, Some(1)))
+        );
+        assert_eq!(err.into_bytes(), b"a\xFF\0");
+    }
+
+    #[cfg(any(debug_assertions, feature = "paranoid-debug"))]
+    #[test]
+    #[should_panic]
+    fn test_paranoid_from_vec_with_nul_unchecked() {
+        // SAFETY: Not actually safe - checks that a missing null is caught in paranoid mode
+        let _ = unsafe { CString::<Utf8>::from_vec_with_nul_unchecked(b"Abc".to_vec()) };
+    }
+
+    #[test]
+    fn test_try_from_vec() {
+        let str: CString<Utf8> = b"abc".to_vec().try_into().unwrap();
+        assert_eq!(str.as_bytes_with_nul(), b"abc\0");
+        let err = CString::<Utf8>::try_from(b"a\0c".to_vec()).unwrap_err();
+        assert_eq!(err.cause(), &CStringErrorCause::HasNull { idx: 1 });
+        assert_eq!(err.into_vec(), b"a\0c");
+        let err = CString::<Utf8>::try_from(b"a\xFF".to_vec()).unwrap_err();
+        assert_eq!(
+            err.cause(),
+            &CStringErrorCause::Invalid(ValidateError::new(1, Some(1)))
+        );
+    }
+
+    #[test]
+    fn test_clone() {
+        let str = CString::<Utf8>::new(b"Hello".to_vec()).unwrap();
+        let mut cloned = str.clone();
+        assert_eq!(cloned, str);
+        cloned.clone_from(&CString::new(b"World".to_vec()).unwrap());
+        assert_eq!(cloned.as_bytes_with_nul(), b"World\0");
+    }
+
+    #[test]
+    fn test_as_ref() {
+        fn bytes(s: impl AsRef<[u8]>) -> usize {
+            s.as_ref().len()
+        }
+        fn c_str(s: impl AsRef<CStr<Utf8>>) -> usize {
+            s.as_ref().as_bytes_with_nul().len()
+        }
+
+        let str = CString::<Utf8>::new(b"Hello".to_vec()).unwrap();
+        assert_eq!(AsRef::<[u8]>::as_ref(&str), b"Hello");
+        assert_eq!(bytes(&*str), 5);
+        assert_eq!(c_str(&*str), 6);
+        assert_eq!(c_str(&str), 6);
+        assert_eq!(bytes(str), 5);
+    }
+
+    #[test]
+    fn test_new_inputs() {
+        let expected = b"Hello\0";
+        let str = Str::from_std("Hello");
+        let string = String::from(str);
+        let c_str = CStr::<Utf8>::from_bytes_with_nul(expected).unwrap();
+
+        assert_eq!(
+            CString::<Utf8>::new(b"Hello".to_vec())
+                .unwrap()
+                .as_bytes_with_nul(),
+            expected
+        );
+        assert_eq!(
+            CString::<Utf8>::new(&b"Hello"[..])
+                .unwrap()
+                .as_bytes_with_nul(),
+            expected
+        );
+        assert_eq!(
+            CString::<Utf8>::new(*b"Hello").unwrap().as_bytes_with_nul(),
+            expected
+        );
+        assert_eq!(CString::new(str).unwrap().as_bytes_with_nul(), expected);
+        assert_eq!(CString::new(&string).unwrap().as_bytes_with_nul(), expected);
+        assert_eq!(CString::new(string).unwrap().as_bytes_with_nul(), expected);
+        assert_eq!(CString::new(c_str).unwrap().as_bytes_with_nul(), expected);
+
+        // Valid strings are still checked for nulls
+        let err = CString::new(Str::from_std("a\0b")).unwrap_err();
+        assert_eq!(err.cause(), &CStringErrorCause::HasNull { idx: 1 });
+        assert_eq!(err.into_vec(), b"a\0b");
+        let err = CString::<Utf8>::new(b"\xFF".to_vec()).unwrap_err();
+        assert!(matches!(err.cause(), CStringErrorCause::Invalid(_)));
+    }
+
+    #[test]
+    fn test_try_new() {
+        let expected = b"Hello\0";
+        let str = Str::from_std("Hello");
+        let c_str = CStr::<Utf8>::from_bytes_with_nul(expected).unwrap();
+
+        assert_eq!(
+            CString::<Utf8>::try_new(b"Hello".to_vec())
+                .unwrap()
+                .as_bytes_with_nul(),
+            expected
+        );
+        assert_eq!(CString::try_new(str).unwrap().as_bytes_with_nul(), expected);
+        let string = String::from(str);
+        assert_eq!(
+            CString::try_new(&string).unwrap().as_bytes_with_nul(),
+            expected
+        );
+        assert_eq!(
+            CString::try_new(c_str).unwrap().as_bytes_with_nul(),
+            expected
+        );
+
+        let err = CString::try_new(Str::from_std("a\0b")).unwrap_err();
+        assert_eq!(err.cause(), &CStringErrorCause::HasNull { idx: 1 });
+        let err = CString::<Utf8>::try_new(b"\xFF".to_vec()).unwrap_err();
+        assert!(matches!(err.cause(), CStringErrorCause::Invalid(_)));
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize() {
+        use zeroize::Zeroize;
+
+        let mut str = CString::<Utf8>::new(b"hunter2".to_vec()).unwrap();
+        let (ptr, cap) = (str.1.as_ptr(), str.1.capacity());
+        str.zeroize();
+        assert_eq!(str.as_bytes_with_nul(), b"\0");
+        assert_eq!((str.1.as_ptr(), str.1.capacity()), (ptr, cap));
+        // SAFETY: Zeroizing initialized the full capacity, and the allocation is unchanged
+        let mem = unsafe { core::slice::from_raw_parts(ptr, cap) };
+        assert!(mem.iter().all(|b| *b == 0));
+        assert!(str.is_empty());
+        assert_eq!(str.into_string(), String::new());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hash() {
+        use std::collections::HashMap;
+        use std::hash::{BuildHasher, RandomState};
+
+        let state = RandomState::new();
+        let c_str = CStr::<Utf8>::from_bytes_with_nul(b"Abc\xF0\x90\x90\xB7d\0").unwrap();
+        let string = CString::new(c_str).unwrap();
+
+        // Consistent with `Borrow`, and with the contained `Str`
+        assert_eq!(state.hash_one(c_str), state.hash_one(&string));
+        assert_eq!(
+            state.hash_one(c_str),
+            state.hash_one(Str::from_std("Abc𐐷d"))
+        );
+
+        let mut map = HashMap::new();
+        map.insert(string, 1);
+        assert_eq!(map.get(c_str), Some(&1));
+
+        let a = [
+            CString::<Utf8>::new("ab").unwrap(),
+            CString::<Utf8>::new("c").unwrap(),
+        ];
+        let b = [
+            CString::<Utf8>::new("a").unwrap(),
+            CString::<Utf8>::new("bc").unwrap(),
+        ];
+        assert_ne!(state.hash_one(&a), state.hash_one(&b));
+    }
+
+    #[test]
+    fn test_eq_str() {
+        let cstring = CString::<Utf8>::new("abc").unwrap();
+        let str = Str::from_std("abc");
+        assert_eq!(cstring, *str);
+        assert_eq!(cstring, str);
+        assert_eq!(*str, cstring);
+        assert!(cstring.content_eq(str));
+        assert_ne!(cstring, Str::from_std("ab"));
+        assert_ne!(cstring, Str::from_std("abcd"));
+
+        let cstr = CStr::<Utf8>::from_bytes_with_nul(b"abc\0").unwrap();
+        assert_eq!(cstring, cstr);
+        assert_eq!(*cstr, cstring);
+        assert_eq!(cstr, str);
+        assert_eq!(str, cstr);
+
+        // Empty strings compare equal, despite the C-string holding a null byte
+        let empty = CString::<Utf8>::default();
+        assert_eq!(empty, Str::from_std(""));
+        assert_eq!(&*empty, <&CStr<Utf8>>::default());
+        assert!(empty.content_eq(Str::EMPTY));
+        assert_ne!(empty, Str::from_std("\0"));
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut strs = ["b", "", "ab", "a", "ba"].map(|s| CString::<Utf8>::new(s).unwrap());
+        strs.sort();
+        let sorted = strs.iter().map(|s| s.as_std()).collect::<Vec<_>>();
+        assert_eq!(sorted, ["", "a", "ab", "b", "ba"]);
+        assert!(strs[1] < strs[2]);
+        assert!(*strs[4] > *strs[3]);
+    }
+}
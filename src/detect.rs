@@ -0,0 +1,156 @@
+//! Heuristic detection of the encoding of text without a byte order mark.
+//!
+//! Where data starts with a byte order mark, [`detect_bom`](crate::encoding::detect_bom) identifies
+//! its encoding exactly. Otherwise, [`guess_encoding`] can rank likely encodings by how plausible
+//! the data looks when decoded in each of them.
+
+use alloc::vec::Vec;
+
+use crate::encoding::{visit_shorthand, Encoding, EncodingKind, EncodingVisitor};
+use crate::str::Str;
+
+/// The candidates checked by [`guess_encoding`] when the caller has no better idea, in order of
+/// preference for breaking ties. This covers Unicode, along with the common legacy encodings for
+/// Japanese, Chinese, Cyrillic, and Western European text.
+pub const DEFAULT_CANDIDATES: &[EncodingKind] = &[
+    EncodingKind::Utf8,
+    EncodingKind::Utf16LE,
+    EncodingKind::Utf16BE,
+    EncodingKind::Utf32LE,
+    EncodingKind::Utf32BE,
+    EncodingKind::ShiftJis,
+    EncodingKind::Big5,
+    EncodingKind::Win1251,
+    EncodingKind::Win1252,
+];
+
+/// Guess the encoding of `bytes` out of `candidates`, returning each candidate with a score
+/// between `0.0` and `1.0`, best first. Candidates with equal scores keep their order from
+/// `candidates`, so preferred encodings should be listed first. [`EncodingKind::Custom`] isn't a
+/// specific encoding, so is skipped.
+///
+/// This is a best-effort heuristic, not a guarantee - short inputs in particular are often
+/// plausible in several encodings. Each candidate is scored by the proportion of bytes which both
+/// decode and look like text:
+///
+/// - Bytes which aren't valid in the encoding score nothing, so invalid candidates fall behind
+/// - Control characters, including nulls, and characters which almost never appear in text score
+///   nothing. This includes characters whose low byte is zero, which is how UTF-16 or UTF-32 text
+///   decodes in the wrong endianness.
+/// - Accented Latin letters decoded from a single byte score half, as most single-byte encodings
+///   decode high bytes into them, whether or not the text is in a Latin script
+/// - A letter directly following one from a different cased script scores nothing, as words
+///   rarely mix Latin, Greek, and Cyrillic letters
+/// - Other symbols and punctuation score half
+///
+/// UTF-16 and UTF-32 candidates are also scored lower if the text decodes to no characters below
+/// U+0100 at all, meaning there are no zero bytes where the encoding expects them for spaces and
+/// newlines.
+///
+/// ```
+/// # use enrede::detect::{guess_encoding, DEFAULT_CANDIDATES};
+/// # use enrede::encoding::EncodingKind;
+/// let bytes = b"\xcf\xf0\xe8\xe2\xe5\xf2, \xec\xe8\xf0!";
+/// let ranked = guess_encoding(bytes, DEFAULT_CANDIDATES);
+/// assert_eq!(ranked[0].0, EncodingKind::Win1251);
+/// ```
+pub fn guess_encoding(bytes: &[u8], candidates: &[EncodingKind]) -> Vec<(EncodingKind, f32)> {
+    let mut scores = candidates
+        .iter()
+        .filter_map(|&kind| visit_shorthand(kind.shorthand(), Score(bytes)).map(|s| (kind, s)))
+        .collect::<Vec<_>>();
+    // Stable sort, so ties keep their order from `candidates`
+    scores.sort_by(|a, b| b.1.total_cmp(&a.1));
+    scores
+}
+
+/// Score how plausible some bytes are as text in an encoding
+struct Score<'a>(&'a [u8]);
+
+impl EncodingVisitor for Score<'_> {
+    type Output = f32;
+
+    fn visit<E: Encoding>(self) -> f32 {
+        if self.0.is_empty() {
+            return 1.0;
+        }
+
+        let mut total = 0.0;
+        let mut has_narrow = false;
+        for chunk in Str::<E>::chunks(self.0) {
+            let mut prev = Script::None;
+            for (span, c) in chunk.valid().char_spans() {
+                let (score, script) = plausibility(c, span.len(), prev);
+                total += score * span.len() as f32;
+                prev = script;
+                has_narrow |= (c as u32) < 0x100;
+            }
+        }
+
+        let mut score = total / self.0.len() as f32;
+        let wide = matches!(
+            E::KIND,
+            EncodingKind::Utf16LE
+                | EncodingKind::Utf16BE
+                | EncodingKind::Utf32LE
+                | EncodingKind::Utf32BE
+        );
+        if wide && !has_narrow {
+            score *= 0.75;
+        }
+        score
+    }
+}
+
+/// The script of a letter, for spotting words which mix scripts
+#[derive(Copy, Clone, PartialEq)]
+enum Script {
+    /// Not a letter
+    None,
+    Latin,
+    Greek,
+    Cyrillic,
+    /// Any other script, which is allowed to mix with others
+    Other,
+}
+
+impl Script {
+    fn of(c: char) -> Script {
+        match c {
+            'A'..='Z' | 'a'..='z' | '\u{C0}'..='\u{24F}' | '\u{1E00}'..='\u{1EFF}' => Script::Latin,
+            '\u{370}'..='\u{3FF}' => Script::Greek,
+            '\u{400}'..='\u{52F}' => Script::Cyrillic,
+            _ => Script::Other,
+        }
+    }
+}
+
+/// How plausible `c` is as a character of text, decoded from `len` bytes and following a
+/// character of script `prev`. Returns the score, and the script of `c`.
+fn plausibility(c: char, len: usize, prev: Script) -> (f32, Script) {
+    let code = c as u32;
+    let unlikely = (c.is_control() && !matches!(c, '\t' | '\n' | '\r'))
+        || (code > 0xFF && code & 0xFF == 0)
+        || matches!(c, '\u{E000}'..='\u{F8FF}' | '\u{FDD0}'..='\u{FDEF}' | '\u{FFFD}')
+        || code & 0xFFFE == 0xFFFE;
+    if unlikely {
+        return (0.0, Script::None);
+    }
+    if c.is_ascii() && !c.is_ascii_alphabetic() {
+        return (1.0, Script::None);
+    }
+    if !c.is_alphabetic() {
+        return (0.5, Script::None);
+    }
+
+    let script = Script::of(c);
+    let cased = |s| matches!(s, Script::Latin | Script::Greek | Script::Cyrillic);
+    let score = if cased(prev) && cased(script) && prev != script {
+        0.0
+    } else if script == Script::Latin && !c.is_ascii() && len == 1 {
+        0.5
+    } else {
+        1.0
+    };
+    (score, script)
+}
@@ -0,0 +1,529 @@
+//! Reading and writing encoded text through [`embedded_io`] readers and writers, without
+//! allocating.
+//!
+//! [`EncodedReader`] decodes text from a reader such as a UART, handling characters split between
+//! reads. [`EncodedWriter`] writes strings to a writer, encoding characters as needed.
+
+use crate::encoding::{ArrayLike, Encoding, ValidateError};
+use crate::str::Str;
+use core::fmt;
+use core::marker::PhantomData;
+use embedded_io::{ErrorKind, Read, Write};
+
+/// An error encountered while reading from an [`EncodedReader`]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ReadError<E> {
+    /// The inner reader returned an error
+    Io(E),
+    /// The input isn't valid for the encoding. The position is relative to the start of the
+    /// input, and the invalid bytes have been skipped.
+    Invalid(ValidateError),
+}
+
+impl<E: fmt::Debug> fmt::Display for ReadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "error reading input: {:?}", e),
+            ReadError::Invalid(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+impl<E: fmt::Debug> core::error::Error for ReadError<E> {}
+
+impl<E: embedded_io::Error> embedded_io::Error for ReadError<E> {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            ReadError::Io(e) => e.kind(),
+            ReadError::Invalid(_) => ErrorKind::InvalidData,
+        }
+    }
+}
+
+/// An error encountered while writing to an [`EncodedWriter`]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum WriteError<E> {
+    /// The inner writer returned an error
+    Io(E),
+    /// A character can't be represented in the encoding. Nothing was written for it.
+    InvalidChar(char),
+}
+
+impl<E: fmt::Debug> fmt::Display for WriteError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WriteError::Io(e) => write!(f, "error writing output: {:?}", e),
+            WriteError::InvalidChar(c) => {
+                write!(f, "character {:?} isn't valid for the encoding", c)
+            }
+        }
+    }
+}
+
+impl<E: fmt::Debug> core::error::Error for WriteError<E> {}
+
+impl<E: embedded_io::Error> embedded_io::Error for WriteError<E> {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            WriteError::Io(e) => e.kind(),
+            WriteError::InvalidChar(_) => ErrorKind::InvalidData,
+        }
+    }
+}
+
+/// The result of looking at the next character in the buffer
+enum Peek {
+    /// A character, and its length in bytes
+    Char(char, usize),
+    /// No complete characters are buffered, and more weren't read
+    Empty,
+    /// The input has ended
+    Eof,
+}
+
+/// A reader which decodes text in the encoding `E` from an inner [`Read`], using a buffer of `N`
+/// bytes.
+///
+/// Characters split between reads are held in the buffer until the rest of their bytes arrive,
+/// so text can be read in pieces of any size. Decoded text is copied into caller-provided buffers
+/// with [`read_str`](Self::read_str) and [`read_line`](Self::read_line), or read a character at a
+/// time with [`read_char`](Self::read_char).
+///
+/// ```
+/// # use enrede::embedded::EncodedReader;
+/// # use enrede::encoding::Utf8;
+/// let mut reader = EncodedReader::<_, Utf8>::new("héllo\nworld".as_bytes());
+/// let mut line = [0; 16];
+/// assert_eq!(reader.read_line(&mut line).unwrap(), "héllo\n");
+/// assert_eq!(reader.read_line(&mut line).unwrap(), "world");
+/// assert!(reader.read_line(&mut line).unwrap().is_empty());
+/// ```
+pub struct EncodedReader<R: Read, E, const N: usize = 64> {
+    inner: R,
+    buf: [u8; N],
+    // Bytes in `buf[start..valid]` are known valid, `buf[valid..end]` haven't been checked yet
+    start: usize,
+    valid: usize,
+    end: usize,
+    // Number of bytes consumed before the start of the buffer, for error positions
+    consumed: usize,
+    // An error to return on the next read, after returning the data before it
+    pending_err: Option<ReadError<R::Error>>,
+    _phantom: PhantomData<E>,
+}
+
+impl<R: Read, E: Encoding, const N: usize> EncodedReader<R, E, N> {
+    /// Create a new reader. The buffer size `N` must be able to hold at least one character, which
+    /// is checked at compile time.
+    pub fn new(inner: R) -> EncodedReader<R, E, N> {
+        const { assert!(N >= E::MAX_LEN, "Buffer must be able to hold a character") };
+        EncodedReader {
+            inner,
+            buf: [0; N],
+            start: 0,
+            valid: 0,
+            end: 0,
+            consumed: 0,
+            pending_err: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Get a reference to the inner reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner reader. Reading from it directly will skip data
+    /// without decoding it.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Return the inner reader. Any buffered data is lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Read and decode a single character, returning [`None`] at the end of the input.
+    pub fn read_char(&mut self) -> Result<Option<char>, ReadError<R::Error>> {
+        if let Some(err) = self.pending_err.take() {
+            return Err(err);
+        }
+        match self.peek(true)? {
+            Peek::Char(c, len) => {
+                self.start += len;
+                Ok(Some(c))
+            }
+            Peek::Empty | Peek::Eof => Ok(None),
+        }
+    }
+
+    /// Read as much text as is available into `buf`, returning the part of `buf` that was filled.
+    /// This reads from the inner reader at most once, unless a character is split between reads.
+    /// Only whole characters are copied, so `buf` should be at least [`Encoding::MAX_LEN`] bytes.
+    ///
+    /// An empty string is returned at the end of the input. If an error occurs after some text
+    /// has been read, that text is returned, and the error is returned by the next call.
+    pub fn read_str<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a Str<E>, ReadError<R::Error>> {
+        self.read_until(buf, false)
+    }
+
+    /// Read text into `buf` up to and including the next `'\n'`, returning the part of `buf` that
+    /// was filled. Reading also stops early at the end of the input, or once `buf` can't hold the
+    /// next character - check whether the result ends in `'\n'` to tell these apart.
+    ///
+    /// If an error occurs after some text has been read, that text is returned, and the error is
+    /// returned by the next call.
+    pub fn read_line<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a Str<E>, ReadError<R::Error>> {
+        self.read_until(buf, true)
+    }
+
+    fn read_until<'a>(
+        &mut self,
+        buf: &'a mut [u8],
+        line: bool,
+    ) -> Result<&'a Str<E>, ReadError<R::Error>> {
+        if let Some(err) = self.pending_err.take() {
+            return Err(err);
+        }
+        let mut len = 0;
+        loop {
+            // Whole lines may need multiple reads, but anything else returns as soon as there's
+            // text to return
+            let peek = match self.peek(line || len == 0) {
+                Ok(peek) => peek,
+                Err(err) if len == 0 => return Err(err),
+                Err(err) => {
+                    self.pending_err = Some(err);
+                    break;
+                }
+            };
+            let Peek::Char(c, char_len) = peek else {
+                break;
+            };
+            if len + char_len > buf.len() {
+                break;
+            }
+            buf[len..len + char_len].copy_from_slice(&self.buf[self.start..self.start + char_len]);
+            self.start += char_len;
+            len += char_len;
+            if line && c == '\n' {
+                break;
+            }
+        }
+        // SAFETY: Only whole characters from the validated part of the buffer were copied
+        Ok(unsafe { Str::from_bytes_unchecked(&buf[..len]) })
+    }
+
+    /// Decode the next character in the buffer without consuming it. If `fill` is true, reads from
+    /// the inner reader until a character is available or the input ends.
+    fn peek(&mut self, fill: bool) -> Result<Peek, ReadError<R::Error>> {
+        loop {
+            if self.start < self.valid {
+                // SAFETY: Bytes in `start..valid` have been validated, and `start` is always on a
+                //         character boundary
+                let str =
+                    unsafe { Str::<E>::from_bytes_unchecked(&self.buf[self.start..self.valid]) };
+                let (c, rest) = E::decode_char(str);
+                return Ok(Peek::Char(c, str.len() - rest.len()));
+            }
+
+            match E::validate(&self.buf[self.start..self.end]) {
+                Ok(()) if self.start < self.end => self.valid = self.end,
+                Err(err) if err.valid_up_to() > 0 => self.valid = self.start + err.valid_up_to(),
+                Err(err) if err.error_len().is_some() => {
+                    let err = ValidateError::new(self.pos(), err.error_len());
+                    self.start += err.error_len().unwrap();
+                    self.valid = self.start;
+                    return Err(ReadError::Invalid(err));
+                }
+                // Either the buffer is empty, or it ends with an incomplete character
+                _ => {
+                    if !fill {
+                        return Ok(Peek::Empty);
+                    }
+                    if !self.fill_buf()? {
+                        if self.start == self.end {
+                            return Ok(Peek::Eof);
+                        }
+                        // Input ended part-way through a character
+                        let err = ValidateError::new(self.pos(), None);
+                        self.start = self.end;
+                        self.valid = self.end;
+                        return Err(ReadError::Invalid(err));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Position of the start of the buffer in the input
+    fn pos(&self) -> usize {
+        self.consumed + self.start
+    }
+
+    /// Move unread data to the front of the buffer, and read more after it. Returns `false` if
+    /// the inner reader has no more data.
+    fn fill_buf(&mut self) -> Result<bool, ReadError<R::Error>> {
+        self.buf.copy_within(self.start..self.end, 0);
+        self.consumed += self.start;
+        self.end -= self.start;
+        self.valid -= self.start;
+        self.start = 0;
+        let len = self
+            .inner
+            .read(&mut self.buf[self.end..])
+            .map_err(ReadError::Io)?;
+        self.end += len;
+        Ok(len != 0)
+    }
+}
+
+impl<R: Read, E: Encoding, const N: usize> Iterator for EncodedReader<R, E, N> {
+    type Item = Result<char, ReadError<R::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_char().transpose()
+    }
+}
+
+impl<R: Read + fmt::Debug, E: Encoding, const N: usize> fmt::Debug for EncodedReader<R, E, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncodedReader")
+            .field("inner", &self.inner)
+            .field("encoding", &E::shorthand())
+            .field("buffered", &(self.end - self.start))
+            .finish()
+    }
+}
+
+/// A writer which writes text in the encoding `E` to an inner [`Write`].
+///
+/// Output isn't buffered, strings are written directly to the inner writer. Text in other
+/// encodings can be written through [`fmt::Write`], or with [`write_char`](Self::write_char).
+///
+/// ```
+/// # use enrede::embedded::EncodedWriter;
+/// # use enrede::encoding::Win1252;
+/// # use core::fmt::Write;
+/// let mut out = [0; 16];
+/// let mut writer = EncodedWriter::<_, Win1252>::new(&mut out[..]);
+/// write!(writer, "{}€", 10).unwrap();
+/// assert_eq!(&out[..3], b"10\x80");
+/// ```
+pub struct EncodedWriter<W: Write, E> {
+    inner: W,
+    _phantom: PhantomData<E>,
+}
+
+impl<W: Write, E: Encoding> EncodedWriter<W, E> {
+    /// Create a new writer
+    pub fn new(inner: W) -> EncodedWriter<W, E> {
+        EncodedWriter {
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Get a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Return the inner writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Write a string in this writer's encoding.
+    pub fn write_str(&mut self, str: &Str<E>) -> Result<(), WriteError<W::Error>> {
+        self.inner.write_all(str.as_bytes()).map_err(WriteError::Io)
+    }
+
+    /// Encode a single character and write it. Returns [`WriteError::InvalidChar`] if the
+    /// character can't be encoded.
+    pub fn write_char(&mut self, c: char) -> Result<(), WriteError<W::Error>> {
+        let bytes = E::encode_char(c).ok_or(WriteError::InvalidChar(c))?;
+        self.inner.write_all(bytes.slice()).map_err(WriteError::Io)
+    }
+
+    /// Flush the inner writer.
+    pub fn flush(&mut self) -> Result<(), WriteError<W::Error>> {
+        self.inner.flush().map_err(WriteError::Io)
+    }
+}
+
+/// Writing through [`fmt::Write`] recodes from UTF-8. As [`fmt::Error`] can't carry any
+/// information, prefer [`EncodedWriter::write_char`] where the cause of errors matters.
+impl<W: Write, E: Encoding> fmt::Write for EncodedWriter<W, E> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| fmt::Write::write_char(self, c))
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        EncodedWriter::write_char(self, c).map_err(|_| fmt::Error)
+    }
+}
+
+impl<W: Write + fmt::Debug, E: Encoding> fmt::Debug for EncodedWriter<W, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncodedWriter")
+            .field("inner", &self.inner)
+            .field("encoding", &E::shorthand())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::{ShiftJis, Utf16LE, Utf8, Win1252};
+    use core::convert::Infallible;
+    use embedded_io::ErrorType;
+
+    /// A reader which returns at most 1, 2, then 3 bytes per read, repeating
+    struct Trickle<'a> {
+        data: &'a [u8],
+        reads: usize,
+    }
+
+    impl<'a> Trickle<'a> {
+        fn new(data: &'a [u8]) -> Self {
+            Trickle { data, reads: 0 }
+        }
+    }
+
+    impl ErrorType for Trickle<'_> {
+        type Error = Infallible;
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Infallible> {
+            let len = usize::min(self.reads % 3 + 1, usize::min(buf.len(), self.data.len()));
+            self.reads += 1;
+            buf[..len].copy_from_slice(&self.data[..len]);
+            self.data = &self.data[len..];
+            Ok(len)
+        }
+    }
+
+    /// A writer which accepts at most 2 bytes per write, into a fixed buffer
+    struct Narrow {
+        out: [u8; 64],
+        len: usize,
+    }
+
+    impl ErrorType for Narrow {
+        type Error = Infallible;
+    }
+
+    impl Write for Narrow {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, Infallible> {
+            let len = usize::min(buf.len(), 2);
+            self.out[self.len..self.len + len].copy_from_slice(&buf[..len]);
+            self.len += len;
+            Ok(len)
+        }
+
+        fn flush(&mut self) -> Result<(), Infallible> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_read_char() {
+        let text = "a€𐐷é\nz";
+        let mut reader = EncodedReader::<_, Utf8, 4>::new(Trickle::new(text.as_bytes()));
+        assert!(reader.by_ref().map(Result::unwrap).eq(text.chars()));
+        assert_eq!(reader.read_char(), Ok(None));
+
+        let mut bytes = [0; 32];
+        let mut len = 0;
+        for unit in text.encode_utf16() {
+            bytes[len..len + 2].copy_from_slice(&unit.to_le_bytes());
+            len += 2;
+        }
+        let reader = EncodedReader::<_, Utf16LE, 4>::new(Trickle::new(&bytes[..len]));
+        assert!(reader.map(Result::unwrap).eq(text.chars()));
+    }
+
+    #[test]
+    fn test_read_line() {
+        // "ハロー\nワールド\n!" in Shift JIS
+        let bytes = b"\x83\x6E\x83\x8D\x81\x5B\n\x83\x8F\x81\x5B\x83\x8B\x83\x68\n!";
+        let mut reader = EncodedReader::<_, ShiftJis, 2>::new(Trickle::new(bytes));
+        let mut buf = [0; 16];
+        assert_eq!(reader.read_line(&mut buf).unwrap(), "ハロー\n");
+        assert_eq!(reader.read_line(&mut buf).unwrap(), "ワールド\n");
+        assert_eq!(reader.read_line(&mut buf).unwrap(), "!");
+        assert!(reader.read_line(&mut buf).unwrap().is_empty());
+
+        // Stops before a character that doesn't fit
+        let mut reader = EncodedReader::<_, ShiftJis>::new(Trickle::new(bytes));
+        let mut buf = [0; 5];
+        assert_eq!(reader.read_line(&mut buf).unwrap(), "ハロ");
+        assert_eq!(reader.read_line(&mut buf).unwrap(), "ー\n");
+    }
+
+    #[test]
+    fn test_read_str() {
+        let text = "€100 for 𐐷";
+        let mut reader = EncodedReader::<_, Utf8, 4>::new(Trickle::new(text.as_bytes()));
+        let mut buf = [0; 4];
+        let mut out = [0; 32];
+        let mut len = 0;
+        loop {
+            let str = reader.read_str(&mut buf).unwrap();
+            if str.is_empty() {
+                break;
+            }
+            out[len..len + str.len()].copy_from_slice(str.as_bytes());
+            len += str.len();
+        }
+        assert_eq!(&out[..len], text.as_bytes());
+    }
+
+    #[test]
+    fn test_read_invalid() {
+        let mut reader = EncodedReader::<_, Utf8, 4>::new(Trickle::new(b"ab\xFFc\xE2\x82"));
+        let mut buf = [0; 8];
+        assert_eq!(reader.read_line(&mut buf).unwrap(), "ab");
+        assert_eq!(
+            reader.read_line(&mut buf),
+            Err(ReadError::Invalid(ValidateError::new(2, Some(1))))
+        );
+        assert_eq!(reader.read_line(&mut buf).unwrap(), "c");
+        assert_eq!(
+            reader.read_char(),
+            Err(ReadError::Invalid(ValidateError::new(4, None)))
+        );
+        assert_eq!(reader.read_char(), Ok(None));
+    }
+
+    #[test]
+    fn test_write() {
+        let mut writer = EncodedWriter::<_, Utf16LE>::new(Narrow {
+            out: [0; 64],
+            len: 0,
+        });
+        writer
+            .write_str(Str::from_bytes(b"\x01\xD8\x37\xDC").unwrap())
+            .unwrap();
+        fmt::Write::write_str(&mut writer, "a€").unwrap();
+        let out = writer.into_inner();
+        assert_eq!(&out.out[..out.len], b"\x01\xD8\x37\xDCa\x00\xAC\x20");
+
+        let mut out = [0; 4];
+        let mut writer = EncodedWriter::<_, Win1252>::new(&mut out[..]);
+        assert_eq!(writer.write_char('Ж'), Err(WriteError::InvalidChar('Ж')));
+        assert!(fmt::Write::write_str(&mut writer, "Ж").is_err());
+        writer.write_char('€').unwrap();
+        assert_eq!(out[0], 0x80);
+    }
+}
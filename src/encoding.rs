@@ -5,47 +5,73 @@
 //! want more low-level encoding operations, you can perform them directly through methods such
 //! as [`Encoding::encode`].
 
+pub use crate::errors::ErrorContext;
 use crate::str::Str;
 use arrayvec::ArrayVec;
-use core::slice;
+use core::{fmt, slice};
 
 mod ascii;
+mod big5;
+mod custom;
 mod iso;
 mod jis;
 mod mac;
+mod registry;
 mod utf;
 mod win;
 
 pub use ascii::*;
+pub use big5::*;
+pub use custom::*;
 pub use iso::*;
 pub use jis::*;
 pub use mac::*;
+pub use registry::*;
 pub use utf::*;
 pub use win::*;
 
-mod sealed {
+pub(crate) mod sealed {
     pub trait Sealed: Sized {}
+
+    /// The Unicode encoding form of an encoding, used to select fast recoding paths
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub enum UnicodeForm {
+        Utf8,
+        Utf16LE,
+        Utf16BE,
+        Utf32LE,
+        Utf32BE,
+    }
 }
 use sealed::Sealed;
 
 #[doc(hidden)]
 pub trait ArrayLike {
+    /// The maximum number of bytes this type can hold
+    const CAPACITY: usize;
+
     fn slice(&self) -> &[u8];
 }
 
 impl<const N: usize> ArrayLike for ArrayVec<u8, N> {
+    const CAPACITY: usize = N;
+
     fn slice(&self) -> &[u8] {
         self
     }
 }
 
 impl<const N: usize> ArrayLike for [u8; N] {
+    const CAPACITY: usize = N;
+
     fn slice(&self) -> &[u8] {
         self
     }
 }
 
 impl ArrayLike for u8 {
+    const CAPACITY: usize = 1;
+
     fn slice(&self) -> &[u8] {
         slice::from_ref(self)
     }
@@ -54,18 +80,53 @@ impl ArrayLike for u8 {
 /// An arbitrary encoding. Examples include [`Utf8`], [`Ascii`], or [`Win1252`].
 ///
 /// This trait is sealed, and multiple internal items are unstable, preventing downstream
-/// implementations. If you want an encoding not currently supported, please open an issue.
+/// implementations. Custom single-byte encodings can be defined with [`CustomSingleByte`] instead.
+/// If you want an encoding not currently supported, please open an issue.
 pub trait Encoding: Default + Sealed {
+    /// The character used to replace unrepresentable characters in lossy conversions. This must
+    /// always be encodable.
     #[doc(hidden)]
     const REPLACEMENT: char;
+    /// The maximum length of a single character in bytes. This must match the capacity of
+    /// [`Bytes`](Self::Bytes).
     #[doc(hidden)]
     const MAX_LEN: usize;
+    /// The type [`encode_char`](Self::encode_char) returns, holding up to `MAX_LEN` bytes
     #[doc(hidden)]
     type Bytes: ArrayLike;
+    #[doc(hidden)]
+    const UNICODE: Option<sealed::UnicodeForm> = None;
+    /// Whether every character in this encoding is exactly [`MAX_LEN`](Self::MAX_LEN) bytes long,
+    /// allowing character positions to be converted to byte positions without decoding.
+    #[doc(hidden)]
+    const FIXED_LEN: bool = Self::MAX_LEN == 1;
+    /// Whether this encoding upholds the requirements of [`AsciiCompatible`]. Every implementor of
+    /// the marker sets this, which is checked at compile time, and it also covers encodings which
+    /// can't implement the marker, such as [`CustomSingleByte`].
+    #[doc(hidden)]
+    const ASCII_COMPATIBLE: bool = false;
+
+    /// The kind of this encoding, identifying it at runtime. See [`EncodingKind`].
+    const KIND: EncodingKind;
 
     #[doc(hidden)]
     fn shorthand() -> &'static str;
 
+    /// A human-readable name for this encoding, such as `"Windows-1252"`. See
+    /// [`EncodingKind::name`].
+    fn name() -> &'static str {
+        Self::KIND.name()
+    }
+
+    /// Labels naming this encoding, in lowercase, which are recognized by
+    /// [`from_label`](crate::encoding::from_label). These follow the
+    /// [WHATWG Encoding Standard](https://encoding.spec.whatwg.org/#names-and-labels) where it
+    /// covers the encoding, and the IANA character set registry otherwise. Encodings with no
+    /// standard name, such as [`ExtendedAscii`], have none.
+    fn aliases() -> &'static [&'static str] {
+        &[]
+    }
+
     /// Given a byte slice, determine whether it is valid for the current encoding.
     fn validate(bytes: &[u8]) -> Result<(), ValidateError>;
 
@@ -88,23 +149,12 @@ pub trait Encoding: Default + Sealed {
 
     /// Given a string in another encoding, re-encode it into this encoding character by character.
     /// On success, returns the length of the output that was written.
-    fn recode<E: Encoding>(str: &Str<E>, out: &mut [u8]) -> Result<usize, RecodeError> {
-        str.char_indices().try_fold(0, |out_pos, (idx, c)| {
-            match Self::encode(c, &mut out[out_pos..]) {
-                Ok(len) => Ok(out_pos + len),
-                Err(e) => Err(RecodeError {
-                    input_used: idx,
-                    output_valid: out_pos,
-                    cause: match e {
-                        EncodeError::NeedSpace { len } => RecodeCause::NeedSpace { len },
-                        EncodeError::InvalidChar => RecodeCause::InvalidChar {
-                            char: c,
-                            len: E::char_len(c),
-                        },
-                    },
-                }),
-            }
-        })
+    fn recode<E: Encoding>(str: &Str<E>, out: &mut [u8]) -> Result<usize, RecodeStepError> {
+        if let (Some(from), Some(to)) = (E::UNICODE, Self::UNICODE) {
+            utf::recode_unicode::<E, Self>(from, to, str, out)
+        } else {
+            recode_chars::<E, Self>(str, 0, out, 0)
+        }
     }
 
     #[doc(hidden)]
@@ -115,13 +165,75 @@ pub trait Encoding: Default + Sealed {
     /// Determine whether the provided index is a character boundary in the provided string.
     /// Implementations may generally assume idx is in-bounds, though this is not a safety
     /// precondition.
+    ///
+    /// This may scan back through the string, so code which already knows a boundary before
+    /// `idx`, such as while decoding, should check relative to it instead of the whole string.
     #[doc(hidden)]
     fn char_bound(str: &Str<Self>, idx: usize) -> bool;
 
-    /// Get the length of the given character in this encoding. Characters not supported by the
-    /// encoding are expected to return 0 currently, this may change in the future.
+    /// Get the length of the given character in this encoding, or [`None`] if the encoding can't
+    /// represent it.
     #[doc(hidden)]
-    fn char_len(c: char) -> usize;
+    fn char_len(c: char) -> Option<usize>;
+}
+
+/// Recode a string character by character, starting from `in_pos` in the input and `out_pos` in
+/// the output.
+fn recode_chars<E: Encoding, E2: Encoding>(
+    str: &Str<E>,
+    mut in_pos: usize,
+    out: &mut [u8],
+    mut out_pos: usize,
+) -> Result<usize, RecodeStepError> {
+    // Keep the undecoded remainder, rather than slicing the input at `in_pos` for each character,
+    // as checking that `in_pos` is a boundary may scan back through the string
+    let mut rest = &str[in_pos..];
+    while !rest.is_empty() {
+        let (c, next) = E::decode_char(rest);
+        let len = rest.len() - next.len();
+        out_pos = recode_char::<E2>(c, len, in_pos, out, out_pos)?;
+        in_pos += len;
+        rest = next;
+    }
+    Ok(out_pos)
+}
+
+/// Recode a single character of `len` bytes at `in_pos` in the input into `out_pos` in the output,
+/// returning the new output position. The length is the number of bytes the character was decoded
+/// from, which may not be what encoding it again would produce.
+fn recode_char<E2: Encoding>(
+    c: char,
+    len: usize,
+    in_pos: usize,
+    out: &mut [u8],
+    out_pos: usize,
+) -> Result<usize, RecodeStepError> {
+    match E2::encode(c, &mut out[out_pos..]) {
+        Ok(len) => Ok(out_pos + len),
+        Err(e) => Err(RecodeStepError {
+            input_used: in_pos,
+            output_valid: out_pos,
+            cause: match e {
+                EncodeError::NeedSpace { len } => RecodeCause::NeedSpace { len },
+                EncodeError::InvalidChar => RecodeCause::InvalidChar { char: c, len },
+            },
+        }),
+    }
+}
+
+/// Generate a uniformly random character from a single-byte encoding, by sampling bytes until one
+/// is valid. Every valid byte is a distinct character, so this is uniform over the characters.
+#[cfg(feature = "rand")]
+fn sample_single_byte<E: Encoding, R: rand::Rng + ?Sized>(rng: &mut R) -> char {
+    const { assert!(E::MAX_LEN == 1) };
+    loop {
+        let byte = [rng.gen::<u8>()];
+        if E::validate(&byte).is_ok() {
+            // SAFETY: We just checked the byte is valid for the encoding
+            let str = unsafe { Str::from_bytes_unchecked(&byte) };
+            return E::decode_char(str).0;
+        }
+    }
 }
 
 /// An encoding that can be used in a C-string, meaning it may encode valid data with no internal
@@ -132,22 +244,110 @@ pub trait Encoding: Default + Sealed {
 /// - The encoding doesn't require null bytes to encode non-null text. This excludes formats
 ///   such as UTF-16, which needs internal null bytes to encode ASCII value.
 /// - The format either doesn't map the null byte to a character, or maps it to the null character.
+///
+/// Every built-in encoding implements this except [`Utf16LE`], [`Utf16BE`], [`Utf32LE`], and
+/// [`Utf32BE`], which all use null bytes to encode common characters.
 pub trait NullTerminable: Encoding {}
 
+/// A [`NullTerminable`] encoding where the null byte is the null character, rather than being
+/// invalid. This means a C-string's content together with its terminator is a valid string, see
+/// [`CStr::as_str_with_nul`](crate::CStr::as_str_with_nul).
+///
+/// Every built-in encoding implementing [`NullTerminable`] implements this.
+/// [`CustomSingleByte`] doesn't, as its table may leave the null byte unmapped.
+pub trait NullChar: NullTerminable {}
+
+/// An encoding that can be used in a wide C-string, meaning it is terminated by a whole code unit
+/// of zero bytes rather than a single null byte. See [`WideCStr`](crate::wcstr::WideCStr).
+///
+/// ## Requirements
+///
+/// - All characters are encoded as a whole number of code units, each [`Self::UNIT_LEN`] bytes.
+/// - The only character encoded with an all-zero code unit is the null character.
+///
+/// This is implemented for [`Utf16LE`], [`Utf16BE`], [`Utf32LE`], and [`Utf32BE`].
+pub trait WideNullTerminable: Encoding {
+    /// The length of a single code unit in bytes, and so the length of the terminator
+    const UNIT_LEN: usize;
+}
+
 /// An encoding for which all bytes are always valid, meaning validation of a byte slice for this
 /// encoding will never fail.
 pub trait AlwaysValid: Encoding {}
 
+/// An encoding in which every character is encoded as exactly one byte. Replacing any byte of a
+/// valid string with the encoding of another character always leaves the string valid, which allows
+/// safe in-place mutation such as [`Str::fill`] and [`Str::make_ascii_uppercase`].
+///
+/// This is implemented by every built-in encoding with a [`MAX_LEN`](Encoding::MAX_LEN) of `1`,
+/// and by [`CustomSingleByte`]. Multi-byte encodings don't implement it, even when ASCII
+/// compatible, as changing a single byte may split or merge characters:
+///
+/// ```compile_fail
+/// # use enrede::{Str, encoding::Utf8};
+/// let mut bytes = *b"abc";
+/// let str = Str::<Utf8>::from_bytes_mut(&mut bytes).unwrap();
+/// str.make_ascii_uppercase();
+/// ```
+pub trait SingleByte: Encoding {}
+
+/// An encoding in which ASCII text is encoded as plain ASCII bytes, meaning any byte below `0x80`
+/// on its own decodes to the matching ASCII character. Strings in these encodings can be checked
+/// for being ASCII with a simple byte scan, and reinterpreted as [`Ascii`] without copying.
+///
+/// This excludes [`JisX0201`] and [`ShiftJis`], which map `0x5C` and `0x7E` to `'¥'` and `'‾'`,
+/// [`JisX0208`], which encodes double-byte characters entirely with bytes below `0x80`, and the
+/// UTF-16 and UTF-32 encodings. Multi-byte encodings may still use bytes below `0x80` after a high
+/// lead byte, as [`Big5`] does.
+pub trait AsciiCompatible: Encoding {}
+
+/// Implement [`AsciiCompatible`] for an encoding, and check at compile time that the encoding also
+/// sets [`Encoding::ASCII_COMPATIBLE`], so the marker and the constant can't disagree.
+macro_rules! impl_ascii_compatible {
+    ($enc:ty) => {
+        impl $crate::encoding::AsciiCompatible for $enc {}
+        const _: () = assert!(<$enc as $crate::encoding::Encoding>::ASCII_COMPATIBLE);
+    };
+}
+
+pub(crate) use impl_ascii_compatible;
+
+/// An encoding which can represent every Unicode scalar value, meaning recoding into this encoding
+/// will never fail. This allows infallible conversions such as [`Str::recode_infallible`] and
+/// `From<&str>` for [`String<E>`](crate::String).
+pub trait UnicodeComplete: Encoding {}
+
 /// An error encountered while validating a byte stream for a certain encoding.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct ValidateError {
     valid_up_to: usize,
     error_len: Option<u8>,
 }
 
 impl ValidateError {
-    /// The length of valid data in the byte stream before the error was encountered. Data up to
-    /// this point may be passed to [`Str::from_bytes_unchecked`] soundly.
+    /// Create a new validation error. This is mostly useful for comparing against errors in tests,
+    /// or creating errors for a wrapper around an encoding.
+    ///
+    /// # Panics
+    ///
+    /// No encoding has characters longer than 255 bytes, so this panics if `error_len` is larger
+    /// than [`u8::MAX`].
+    pub const fn new(valid_up_to: usize, error_len: Option<usize>) -> ValidateError {
+        let error_len = match error_len {
+            Some(len) if len > u8::MAX as usize => panic!("Validation error length must fit in u8"),
+            Some(len) => Some(len as u8),
+            None => None,
+        };
+        ValidateError {
+            valid_up_to,
+            error_len,
+        }
+    }
+
+    /// The length of valid data in the byte stream before the error was encountered. For an error
+    /// returned by validating some data, that data up to this point may be passed to
+    /// [`Str::from_bytes_unchecked`] soundly. Errors created with [`ValidateError::new`] carry no
+    /// such guarantee.
     pub fn valid_up_to(&self) -> usize {
         self.valid_up_to
     }
@@ -159,8 +359,39 @@ impl ValidateError {
     pub fn error_len(&self) -> Option<usize> {
         self.error_len.map(|e| e as usize)
     }
+
+    /// Get the input surrounding this error, for reporting it. `bytes` should be the input that
+    /// was validated to produce this error, in the encoding `E`. The input before the error is
+    /// re-validated, so a mismatched error or encoding gives misleading context rather than
+    /// invalid strings.
+    ///
+    /// # Panics
+    ///
+    /// If `bytes` is shorter than [`valid_up_to`](Self::valid_up_to).
+    pub fn context<E: Encoding>(self, bytes: &[u8]) -> ErrorContext<'_, E> {
+        ErrorContext::new(self, bytes)
+    }
+}
+
+impl fmt::Display for ValidateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.error_len {
+            Some(len) => write!(
+                f,
+                "invalid byte sequence of {} bytes from index {}",
+                len, self.valid_up_to
+            ),
+            None => write!(
+                f,
+                "incomplete byte sequence from index {}",
+                self.valid_up_to
+            ),
+        }
+    }
 }
 
+impl core::error::Error for ValidateError {}
+
 /// An error while encoding a `char` directly into a buffer
 #[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
@@ -174,6 +405,19 @@ pub enum EncodeError {
     InvalidChar,
 }
 
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::NeedSpace { len } => {
+                write!(f, "output too small, {} bytes required", len)
+            }
+            EncodeError::InvalidChar => write!(f, "character isn't valid for the encoding"),
+        }
+    }
+}
+
+impl core::error::Error for EncodeError {}
+
 /// The cause of a recoding error.
 #[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
@@ -192,15 +436,18 @@ pub enum RecodeCause {
     },
 }
 
-/// An error encountered while encoding a string into another format.
+/// An error encountered while encoding a string into a caller-provided buffer with
+/// [`Encoding::recode`]. Conversions which allocate their output, such as
+/// [`Str::recode`](crate::Str::recode), return [`str::RecodeError`](crate::str::RecodeError)
+/// instead.
 #[derive(Clone, Debug, PartialEq)]
-pub struct RecodeError {
+pub struct RecodeStepError {
     input_used: usize,
     output_valid: usize,
     cause: RecodeCause,
 }
 
-impl RecodeError {
+impl RecodeStepError {
     /// The amount of input successfully consumed. Data up to this point in the input has been
     /// encoded into the output.
     pub fn input_used(&self) -> usize {
@@ -218,3 +465,129 @@ impl RecodeError {
         &self.cause
     }
 }
+
+impl fmt::Display for RecodeStepError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.cause {
+            RecodeCause::NeedSpace { len } => write!(
+                f,
+                "output too small after {} bytes of input, {} more bytes required",
+                self.input_used, len,
+            ),
+            RecodeCause::InvalidChar { char, .. } => write!(
+                f,
+                "character {:?} at index {} isn't valid for the output encoding",
+                char, self.input_used,
+            ),
+        }
+    }
+}
+
+impl core::error::Error for RecodeStepError {}
+
+/// Previous name of [`RecodeStepError`].
+#[deprecated(note = "renamed to `RecodeStepError`, to distinguish it from `str::RecodeError`")]
+pub type RecodeError = RecodeStepError;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_recode_error_deprecated() {
+        let err: RecodeError = Ascii::recode(Str::from_std("aé"), &mut [0; 4]).unwrap_err();
+        assert_eq!(err.input_used(), 1);
+        assert_eq!(err.output_valid(), 1);
+    }
+
+    #[test]
+    fn test_validate_error() {
+        let err = ValidateError::new(2, Some(4));
+        assert_eq!(err, Utf16BE::validate(b"\0a\xD8\x01\xD8\x01").unwrap_err());
+        assert_eq!(err.valid_up_to(), 2);
+        assert_eq!(err.error_len(), Some(4));
+        assert_eq!(
+            err.to_string(),
+            "invalid byte sequence of 4 bytes from index 2"
+        );
+
+        let err = ValidateError::new(3, Some(2));
+        assert_eq!(
+            err.to_string(),
+            "invalid byte sequence of 2 bytes from index 3"
+        );
+
+        let err = ValidateError::new(5, None);
+        assert_eq!(err, Utf8::validate(b"abcde\xC3").unwrap_err());
+        assert_eq!(err.to_string(), "incomplete byte sequence from index 5");
+    }
+
+    #[test]
+    fn test_encoding_consts() {
+        // Buffers are sized with `MAX_LEN` and `char_len(REPLACEMENT)`, so these must be accurate
+        fn check<E: Encoding>() {
+            const { assert!(E::MAX_LEN == <E::Bytes as ArrayLike>::CAPACITY) };
+            let replacement = E::encode_char(E::REPLACEMENT);
+            assert!(replacement.is_some(), "{}", E::shorthand());
+            assert_eq!(
+                Some(replacement.unwrap().slice().len()),
+                E::char_len(E::REPLACEMENT)
+            );
+        }
+
+        macro_rules! check {
+            ($enc:ty, $name:literal) => {
+                check::<$enc>();
+            };
+        }
+        crate::for_each_encoding!(check);
+    }
+
+    #[test]
+    fn test_single_byte_controls() {
+        // Every single-byte encoding supports ASCII control characters, so plain text works
+        fn check<E: Encoding>() {
+            let text = "line1\n\tline2\r\n\0\x7F";
+            for c in text.chars() {
+                assert_eq!(E::encode_char(c).unwrap().slice(), &[c as u8]);
+                assert_eq!(E::char_len(c), Some(1));
+            }
+            let str = Str::<E>::from_bytes(text.as_bytes()).unwrap();
+            assert!(str.chars().eq(text.chars()), "{}", E::shorthand());
+        }
+
+        macro_rules! check {
+            ($enc:ty, $name:literal) => {
+                if <$enc>::MAX_LEN == 1 {
+                    check::<$enc>();
+                }
+            };
+        }
+        crate::for_each_encoding!(check);
+    }
+
+    #[test]
+    fn test_single_byte_marker() {
+        fn check<E: SingleByte>() {
+            const { assert!(E::MAX_LEN == 1) };
+        }
+
+        check::<Ascii>();
+        check::<ExtendedAscii>();
+        check::<Iso8859_2>();
+        check::<Iso8859_15>();
+        check::<JisX0201>();
+        check::<MacRoman>();
+        check::<Win1251>();
+        check::<Win1252>();
+        check::<Win1252Loose>();
+    }
+
+    #[test]
+    #[should_panic = "Validation error length must fit in u8"]
+    fn test_validate_error_too_long() {
+        let _ = ValidateError::new(0, Some(256));
+    }
+}
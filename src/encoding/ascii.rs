@@ -1,12 +1,15 @@
 use crate::encoding::sealed::Sealed;
-use crate::encoding::{AlwaysValid, NullTerminable, ValidateError};
+use crate::encoding::{
+    impl_ascii_compatible, AlwaysValid, EncodingKind, NullChar, NullTerminable, SingleByte,
+    ValidateError,
+};
 use crate::{Encoding, Str};
 #[cfg(feature = "rand")]
 use rand::{distributions::Distribution, Rng};
 
 /// The [ASCII](https://en.wikipedia.org/wiki/ASCII) encoding.
 #[non_exhaustive]
-#[derive(Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Ascii;
 
 impl Sealed for Ascii {}
@@ -15,11 +18,28 @@ impl Encoding for Ascii {
     const REPLACEMENT: char = '\x1A';
     const MAX_LEN: usize = 1;
     type Bytes = u8;
+    const KIND: EncodingKind = EncodingKind::Ascii;
+    const ASCII_COMPATIBLE: bool = true;
 
     fn shorthand() -> &'static str {
         "ascii"
     }
 
+    fn aliases() -> &'static [&'static str] {
+        &[
+            "us-ascii",
+            "ansi_x3.4-1968",
+            "ansi_x3.4-1986",
+            "iso646-us",
+            "iso_646.irv:1991",
+            "iso-ir-6",
+            "csascii",
+            "us",
+            "ibm367",
+            "cp367",
+        ]
+    }
+
     fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
         bytes.iter().enumerate().try_for_each(|(idx, c)| {
             if *c > 127 {
@@ -49,17 +69,19 @@ impl Encoding for Ascii {
         true
     }
 
-    fn char_len(c: char) -> usize {
-        if (c as u32) < 128 {
-            1
-        } else {
-            0
-        }
+    fn char_len(c: char) -> Option<usize> {
+        ((c as u32) < 128).then_some(1)
     }
 }
 
 impl NullTerminable for Ascii {}
 
+impl NullChar for Ascii {}
+
+impl_ascii_compatible!(Ascii);
+
+impl SingleByte for Ascii {}
+
 #[cfg(feature = "rand")]
 impl Distribution<char> for Ascii {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
@@ -71,7 +93,7 @@ impl Distribution<char> for Ascii {
 /// not assign any particular meaning to values beyond 127 - it simply round-trips them as `char`s
 /// of that exact codepoint value.
 #[non_exhaustive]
-#[derive(Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ExtendedAscii;
 
 impl Sealed for ExtendedAscii {}
@@ -80,6 +102,8 @@ impl Encoding for ExtendedAscii {
     const REPLACEMENT: char = '\x1A';
     const MAX_LEN: usize = 1;
     type Bytes = u8;
+    const KIND: EncodingKind = EncodingKind::ExtendedAscii;
+    const ASCII_COMPATIBLE: bool = true;
 
     fn shorthand() -> &'static str {
         "ascii_ext"
@@ -105,19 +129,21 @@ impl Encoding for ExtendedAscii {
         true
     }
 
-    fn char_len(c: char) -> usize {
-        if (c as u32) < 256 {
-            1
-        } else {
-            0
-        }
+    fn char_len(c: char) -> Option<usize> {
+        ((c as u32) < 256).then_some(1)
     }
 }
 
 impl NullTerminable for ExtendedAscii {}
 
+impl NullChar for ExtendedAscii {}
+
+impl_ascii_compatible!(ExtendedAscii);
+
 impl AlwaysValid for ExtendedAscii {}
 
+impl SingleByte for ExtendedAscii {}
+
 #[cfg(feature = "rand")]
 impl Distribution<char> for ExtendedAscii {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
@@ -0,0 +1,238 @@
+use crate::encoding::sealed::Sealed;
+use crate::encoding::{
+    impl_ascii_compatible, EncodingKind, NullChar, NullTerminable, ValidateError,
+};
+use crate::{Encoding, Str};
+use arrayvec::ArrayVec;
+#[cfg(feature = "rand")]
+use rand::{distributions::Distribution, Rng};
+
+mod tables;
+
+/// Convert a trail byte into its column in the decode map, if it's a valid trail byte.
+const fn trail_col(b: u8) -> Option<usize> {
+    match b {
+        0x40..=0x7E => Some((b - 0x40) as usize),
+        0xA1..=0xFE => Some((b - 0xA1) as usize + 63),
+        _ => None,
+    }
+}
+
+/// Look up the character for a lead and trail byte, returning [`None`] if the pair isn't mapped.
+fn lookup(lead: u8, trail: u8) -> Option<char> {
+    let row = tables::DECODE_MAP_BIG5.get(lead.wrapping_sub(0xA1) as usize)?;
+    let c = row[trail_col(trail)?];
+    (c != '�').then_some(c)
+}
+
+/// The [Big5](https://en.wikipedia.org/wiki/Big5) encoding. This supports the standard Big5
+/// character set, without vendor extensions such as HKSCS.
+///
+/// ASCII characters are encoded as single bytes, and all other characters as a lead byte in the
+/// range `0x81..=0xFE` followed by a trail byte in either `0x40..=0x7E` or `0xA1..=0xFE`. Note
+/// that trail bytes may look like ASCII characters.
+///
+/// Bytes from `0xA1` can be either a lead or trail byte, so a character boundary can only be found
+/// by counting back to the nearest byte below `0x80`. Slicing and other boundary checks are linear
+/// in that distance, which may be the whole string for text such as a run of `'中'`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Big5;
+
+impl Sealed for Big5 {}
+
+impl Encoding for Big5 {
+    const REPLACEMENT: char = '?';
+    const MAX_LEN: usize = 2;
+    type Bytes = ArrayVec<u8, 2>;
+    const KIND: EncodingKind = EncodingKind::Big5;
+    const ASCII_COMPATIBLE: bool = true;
+
+    fn shorthand() -> &'static str {
+        "big5"
+    }
+
+    fn aliases() -> &'static [&'static str] {
+        &["big5", "cn-big5", "csbig5", "x-x-big5"]
+    }
+
+    fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
+        let mut idx = 0;
+        while idx < bytes.len() {
+            let lead = bytes[idx];
+            if lead < 0x80 {
+                idx += 1;
+                continue;
+            } else if lead == 0x80 || lead == 0xFF {
+                return Err(ValidateError {
+                    valid_up_to: idx,
+                    error_len: Some(1),
+                });
+            }
+
+            let Some(&trail) = bytes.get(idx + 1) else {
+                return Err(ValidateError {
+                    valid_up_to: idx,
+                    error_len: None,
+                });
+            };
+            if lookup(lead, trail).is_none() {
+                // An ASCII byte following the lead isn't part of the error, it starts the next
+                // character
+                let error_len = if trail < 0x80 { 1 } else { 2 };
+                return Err(ValidateError {
+                    valid_up_to: idx,
+                    error_len: Some(error_len),
+                });
+            }
+            idx += 2;
+        }
+        Ok(())
+    }
+
+    fn encode_char(c: char) -> Option<Self::Bytes> {
+        if c.is_ascii() {
+            Some(ArrayVec::from_iter([c as u8]))
+        } else {
+            let idx = tables::ENCODE_MAP_BIG5
+                .binary_search_by(|(c2, _)| c2.cmp(&c))
+                .ok()?;
+            let (_, (row, col)) = tables::ENCODE_MAP_BIG5[idx];
+            let trail = if col < 63 {
+                col + 0x40
+            } else {
+                col - 63 + 0xA1
+            };
+            Some(ArrayVec::from([row as u8 + 0xA1, trail as u8]))
+        }
+    }
+
+    fn decode_char(str: &Str<Self>) -> (char, &Str<Self>) {
+        let bytes = str.as_bytes();
+        let first = bytes[0];
+        if first < 0x80 {
+            (char::from(first), &str[1..])
+        } else {
+            let c = lookup(first, bytes[1]).expect("Big5 string contained an unmapped byte pair");
+            (c, &str[2..])
+        }
+    }
+
+    fn char_bound(str: &Str<Self>, idx: usize) -> bool {
+        // Bytes below 0x80 always end a character, either as ASCII or as a trail byte, so are
+        // sync points. Bytes after the last sync point must be lead/trail pairs, so we're on a
+        // boundary if that run is even.
+        let run = str.as_bytes()[..idx]
+            .iter()
+            .rev()
+            .take_while(|b| **b >= 0x80)
+            .count();
+        run.is_multiple_of(2)
+    }
+
+    fn char_len(c: char) -> Option<usize> {
+        if c.is_ascii() {
+            Some(1)
+        } else if tables::ENCODE_MAP_BIG5
+            .binary_search_by(|(c2, _)| c2.cmp(&c))
+            .is_ok()
+        {
+            Some(2)
+        } else {
+            None
+        }
+    }
+}
+
+impl NullTerminable for Big5 {}
+
+impl NullChar for Big5 {}
+
+impl_ascii_compatible!(Big5);
+
+#[cfg(feature = "rand")]
+impl Distribution<char> for Big5 {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        // ASCII, then every double-byte character
+        let idx = rng.gen_range(0..0x80 + tables::ENCODE_MAP_BIG5.len());
+        if idx < 0x80 {
+            char::from(idx as u8)
+        } else {
+            tables::ENCODE_MAP_BIG5[idx - 0x80].0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let str = Str::<Big5>::from_bytes(b"\xA7\x41\xA6\x6E").unwrap();
+        assert!(str.chars().eq("你好".chars()));
+        assert_eq!(Big5::encode_char('你').unwrap().as_slice(), b"\xA7\x41");
+        assert_eq!(Big5::encode_char('好').unwrap().as_slice(), b"\xA6\x6E");
+        assert_eq!(Big5::char_len('你'), Some(2));
+        assert_eq!(Big5::char_len('a'), Some(1));
+        assert_eq!(Big5::char_len('€'), None);
+
+        for row in tables::DECODE_MAP_BIG5 {
+            for c in row.iter().copied().filter(|c| *c != '�') {
+                let bytes = Big5::encode_char(c).unwrap();
+                let str = Str::<Big5>::from_bytes(&bytes).unwrap();
+                assert_eq!(str.chars().next(), Some(c));
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(Big5::validate(b"ab\xA7\x41c"), Ok(()));
+        // Dangling lead byte
+        assert_eq!(
+            Big5::validate(b"ab\xA7"),
+            Err(ValidateError {
+                valid_up_to: 2,
+                error_len: None,
+            })
+        );
+        // Invalid lead byte
+        assert_eq!(
+            Big5::validate(b"a\x80\x41"),
+            Err(ValidateError {
+                valid_up_to: 1,
+                error_len: Some(1),
+            })
+        );
+        // Unmapped pair with an ASCII byte following the lead
+        assert_eq!(
+            Big5::validate(b"a\xA7\x20"),
+            Err(ValidateError {
+                valid_up_to: 1,
+                error_len: Some(1),
+            })
+        );
+        // Unmapped pair
+        assert_eq!(
+            Big5::validate(b"a\xC8\xA1"),
+            Err(ValidateError {
+                valid_up_to: 1,
+                error_len: Some(2),
+            })
+        );
+    }
+
+    #[test]
+    fn test_char_bound() {
+        // The trail byte of '你' is 'A'
+        let str = Str::<Big5>::from_bytes(b"A\xA7\x41\xA6\xA1A").unwrap();
+        let bounds = (0..=str.len())
+            .filter(|&idx| str.is_char_boundary(idx))
+            .collect::<alloc::vec::Vec<_>>();
+        assert_eq!(bounds, [0, 1, 3, 5, 6]);
+        assert!(str.get(2..).is_none());
+        assert!(str.contains('A'));
+        assert_eq!(str.matches('A').count(), 2);
+    }
+}
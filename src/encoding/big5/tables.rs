@@ -0,0 +1,14996 @@
+/// Big5 represents non-ASCII characters as a lead byte followed by a trail byte. Rows are indexed
+/// by lead byte, starting at `0xA1`, and columns by trail byte - `0x40..=0x7E` followed by
+/// `0xA1..=0xFE`. This map includes every row with mapped characters, using '�' to represent
+/// byte pairs that aren't mapped as part of the standard.
+pub const DECODE_MAP_BIG5: &[[char; 157]; 89] = &[
+    // Lead 0xA1
+    [
+        '　', '，', '、', '。', '．', '•', '；', '：', '？', '！', '︰', '…', '‥', '﹐', '､', '﹒',
+        '·', '﹔', '﹕', '﹖', '﹗', '｜', '–', '︱', '—', '︳', '╴', '︴', '﹏', '（', '）', '︵',
+        '︶', '｛', '｝', '︷', '︸', '〔', '〕', '︹', '︺', '【', '】', '︻', '︼', '《', '》',
+        '︽', '︾', '〈', '〉', '︿', '﹀', '「', '」', '﹁', '﹂', '『', '』', '﹃', '﹄', '﹙',
+        '﹚', '﹛', '﹜', '﹝', '﹞', '‘', '’', '“', '”', '〝', '〞', '‵', '′', '＃', '＆', '＊',
+        '※', '§', '〃', '○', '●', '△', '▲', '◎', '☆', '★', '◇', '◆', '□', '■', '▽', '▼', '㊣', '℅',
+        '‾', '￣', '＿', 'ˍ', '﹉', '﹊', '﹍', '﹎', '﹋', '﹌', '﹟', '﹠', '﹡', '＋', '－',
+        '×', '÷', '±', '√', '＜', '＞', '＝', '≦', '≧', '≠', '∞', '≒', '≡', '﹢', '﹣', '﹤', '﹥',
+        '﹦', '∼', '∩', '∪', '⊥', '∠', '∟', '⊿', '㏒', '㏑', '∫', '∮', '∵', '∴', '♀', '♂', '♁',
+        '☉', '↑', '↓', '←', '→', '↖', '↗', '↙', '↘', '∥', '∣', '／',
+    ],
+    // Lead 0xA2
+    [
+        '＼', '／', '＼', '＄', '¥', '〒', '¢', '£', '％', '＠', '℃', '℉', '﹩', '﹪', '﹫', '㏕',
+        '㎜', '㎝', '㎞', '㏎', '㎡', '㎎', '㎏', '㏄', '°', '兙', '兛', '兞', '兝', '兡', '兣',
+        '嗧', '瓩', '糎', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█', '▏', '▎', '▍', '▌', '▋', '▊',
+        '▉', '┼', '┴', '┬', '┤', '├', '▔', '─', '│', '▕', '┌', '┐', '└', '┘', '╭', '╮', '╰', '╯',
+        '═', '╞', '╪', '╡', '◢', '◣', '◥', '◤', '╱', '╲', '╳', '０', '１', '２', '３', '４', '５',
+        '６', '７', '８', '９', 'Ⅰ', 'Ⅱ', 'Ⅲ', 'Ⅳ', 'Ⅴ', 'Ⅵ', 'Ⅶ', 'Ⅷ', 'Ⅸ', 'Ⅹ', '〡', '〢', '〣',
+        '〤', '〥', '〦', '〧', '〨', '〩', '十', '卄', '卅', 'Ａ', 'Ｂ', 'Ｃ', 'Ｄ', 'Ｅ', 'Ｆ',
+        'Ｇ', 'Ｈ', 'Ｉ', 'Ｊ', 'Ｋ', 'Ｌ', 'Ｍ', 'Ｎ', 'Ｏ', 'Ｐ', 'Ｑ', 'Ｒ', 'Ｓ', 'Ｔ', 'Ｕ',
+        'Ｖ', 'Ｗ', 'Ｘ', 'Ｙ', 'Ｚ', 'ａ', 'ｂ', 'ｃ', 'ｄ', 'ｅ', 'ｆ', 'ｇ', 'ｈ', 'ｉ', 'ｊ',
+        'ｋ', 'ｌ', 'ｍ', 'ｎ', 'ｏ', 'ｐ', 'ｑ', 'ｒ', 'ｓ', 'ｔ', 'ｕ', 'ｖ',
+    ],
+    // Lead 0xA3
+    [
+        'ｗ', 'ｘ', 'ｙ', 'ｚ', 'Α', 'Β', 'Γ', 'Δ', 'Ε', 'Ζ', 'Η', 'Θ', 'Ι', 'Κ', 'Λ', 'Μ', 'Ν',
+        'Ξ', 'Ο', 'Π', 'Ρ', 'Σ', 'Τ', 'Υ', 'Φ', 'Χ', 'Ψ', 'Ω', 'α', 'β', 'γ', 'δ', 'ε', 'ζ', 'η',
+        'θ', 'ι', 'κ', 'λ', 'μ', 'ν', 'ξ', 'ο', 'π', 'ρ', 'σ', 'τ', 'υ', 'φ', 'χ', 'ψ', 'ω', 'ㄅ',
+        'ㄆ', 'ㄇ', 'ㄈ', 'ㄉ', 'ㄊ', 'ㄋ', 'ㄌ', 'ㄍ', 'ㄎ', 'ㄏ', 'ㄐ', 'ㄑ', 'ㄒ', 'ㄓ', 'ㄔ',
+        'ㄕ', 'ㄖ', 'ㄗ', 'ㄘ', 'ㄙ', 'ㄚ', 'ㄛ', 'ㄜ', 'ㄝ', 'ㄞ', 'ㄟ', 'ㄠ', 'ㄡ', 'ㄢ', 'ㄣ',
+        'ㄤ', 'ㄥ', 'ㄦ', 'ㄧ', 'ㄨ', 'ㄩ', '˙', 'ˉ', 'ˊ', 'ˇ', 'ˋ', '�', '�', '�', '�', '�', '�',
+        '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�',
+        '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�',
+        '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�',
+        '�', '�', '�',
+    ],
+    // Lead 0xA4
+    [
+        '一', '乙', '丁', '七', '乃', '九', '了', '二', '人', '儿', '入', '八', '几', '刀', '刁',
+        '力', '匕', '十', '卜', '又', '三', '下', '丈', '上', '丫', '丸', '凡', '久', '么', '也',
+        '乞', '于', '亡', '兀', '刃', '勺', '千', '叉', '口', '土', '士', '夕', '大', '女', '子',
+        '孑', '孓', '寸', '小', '尢', '尸', '山', '川', '工', '己', '已', '巳', '巾', '干', '廾',
+        '弋', '弓', '才', '丑', '丐', '不', '中', '丰', '丹', '之', '尹', '予', '云', '井', '互',
+        '五', '亢', '仁', '什', '仃', '仆', '仇', '仍', '今', '介', '仄', '元', '允', '內', '六',
+        '兮', '公', '冗', '凶', '分', '切', '刈', '勻', '勾', '勿', '化', '匹', '午', '升', '卅',
+        '卞', '厄', '友', '及', '反', '壬', '天', '夫', '太', '夭', '孔', '少', '尤', '尺', '屯',
+        '巴', '幻', '廿', '弔', '引', '心', '戈', '戶', '手', '扎', '支', '文', '斗', '斤', '方',
+        '日', '曰', '月', '木', '欠', '止', '歹', '毋', '比', '毛', '氏', '水', '火', '爪', '父',
+        '爻', '片', '牙', '牛', '犬', '王', '丙',
+    ],
+    // Lead 0xA5
+    [
+        '世', '丕', '且', '丘', '主', '乍', '乏', '乎', '以', '付', '仔', '仕', '他', '仗', '代',
+        '令', '仙', '仞', '充', '兄', '冉', '冊', '冬', '凹', '出', '凸', '刊', '加', '功', '包',
+        '匆', '北', '匝', '仟', '半', '卉', '卡', '占', '卯', '卮', '去', '可', '古', '右', '召',
+        '叮', '叩', '叨', '叼', '司', '叵', '叫', '另', '只', '史', '叱', '台', '句', '叭', '叻',
+        '四', '囚', '外', '央', '失', '奴', '奶', '孕', '它', '尼', '巨', '巧', '左', '市', '布',
+        '平', '幼', '弁', '弘', '弗', '必', '戊', '打', '扔', '扒', '扑', '斥', '旦', '朮', '本',
+        '未', '末', '札', '正', '母', '民', '氐', '永', '汁', '汀', '氾', '犯', '玄', '玉', '瓜',
+        '瓦', '甘', '生', '用', '甩', '田', '由', '甲', '申', '疋', '白', '皮', '皿', '目', '矛',
+        '矢', '石', '示', '禾', '穴', '立', '丞', '丟', '乒', '乓', '乩', '亙', '交', '亦', '亥',
+        '仿', '伉', '伙', '伊', '伕', '伍', '伐', '休', '伏', '仲', '件', '任', '仰', '仳', '份',
+        '企', '伋', '光', '兇', '兆', '先', '全',
+    ],
+    // Lead 0xA6
+    [
+        '共', '再', '冰', '列', '刑', '划', '刎', '刖', '劣', '匈', '匡', '匠', '印', '危', '吉',
+        '吏', '同', '吊', '吐', '吁', '吋', '各', '向', '名', '合', '吃', '后', '吆', '吒', '因',
+        '回', '囝', '圳', '地', '在', '圭', '圬', '圯', '圩', '夙', '多', '夷', '夸', '妄', '奸',
+        '妃', '好', '她', '如', '妁', '字', '存', '宇', '守', '宅', '安', '寺', '尖', '屹', '州',
+        '帆', '并', '年', '式', '弛', '忙', '忖', '戎', '戌', '戍', '成', '扣', '扛', '托', '收',
+        '早', '旨', '旬', '旭', '曲', '曳', '有', '朽', '朴', '朱', '朵', '次', '此', '死', '氖',
+        '汝', '汗', '汙', '江', '池', '汐', '汕', '污', '汛', '汍', '汎', '灰', '牟', '牝', '百',
+        '竹', '米', '糸', '缶', '羊', '羽', '老', '考', '而', '耒', '耳', '聿', '肉', '肋', '肌',
+        '臣', '自', '至', '臼', '舌', '舛', '舟', '艮', '色', '艾', '虫', '血', '行', '衣', '西',
+        '阡', '串', '亨', '位', '住', '佇', '佗', '佞', '伴', '佛', '何', '估', '佐', '佑', '伽',
+        '伺', '伸', '佃', '佔', '似', '但', '佣',
+    ],
+    // Lead 0xA7
+    [
+        '作', '你', '伯', '低', '伶', '余', '佝', '佈', '佚', '兌', '克', '免', '兵', '冶', '冷',
+        '別', '判', '利', '刪', '刨', '劫', '助', '努', '劬', '匣', '即', '卵', '吝', '吭', '吞',
+        '吾', '否', '呎', '吧', '呆', '呃', '吳', '呈', '呂', '君', '吩', '告', '吹', '吻', '吸',
+        '吮', '吵', '吶', '吠', '吼', '呀', '吱', '含', '吟', '听', '囪', '困', '囤', '囫', '坊',
+        '坑', '址', '坍', '均', '坎', '圾', '坐', '坏', '圻', '壯', '夾', '妝', '妒', '妨', '妞',
+        '妣', '妙', '妖', '妍', '妤', '妓', '妊', '妥', '孝', '孜', '孚', '孛', '完', '宋', '宏',
+        '尬', '局', '屁', '尿', '尾', '岐', '岑', '岔', '岌', '巫', '希', '序', '庇', '床', '廷',
+        '弄', '弟', '彤', '形', '彷', '役', '忘', '忌', '志', '忍', '忱', '快', '忸', '忪', '戒',
+        '我', '抄', '抗', '抖', '技', '扶', '抉', '扭', '把', '扼', '找', '批', '扳', '抒', '扯',
+        '折', '扮', '投', '抓', '抑', '抆', '改', '攻', '攸', '旱', '更', '束', '李', '杏', '材',
+        '村', '杜', '杖', '杞', '杉', '杆', '杠',
+    ],
+    // Lead 0xA8
+    [
+        '杓', '杗', '步', '每', '求', '汞', '沙', '沁', '沈', '沉', '沅', '沛', '汪', '決', '沐',
+        '汰', '沌', '汨', '沖', '沒', '汽', '沃', '汲', '汾', '汴', '沆', '汶', '沍', '沔', '沘',
+        '沂', '灶', '灼', '災', '灸', '牢', '牡', '牠', '狄', '狂', '玖', '甬', '甫', '男', '甸',
+        '皂', '盯', '矣', '私', '秀', '禿', '究', '系', '罕', '肖', '肓', '肝', '肘', '肛', '肚',
+        '育', '良', '芒', '芋', '芍', '見', '角', '言', '谷', '豆', '豕', '貝', '赤', '走', '足',
+        '身', '車', '辛', '辰', '迂', '迆', '迅', '迄', '巡', '邑', '邢', '邪', '邦', '那', '酉',
+        '釆', '里', '防', '阮', '阱', '阪', '阬', '並', '乖', '乳', '事', '些', '亞', '享', '京',
+        '佯', '依', '侍', '佳', '使', '佬', '供', '例', '來', '侃', '佰', '併', '侈', '佩', '佻',
+        '侖', '佾', '侏', '侑', '佺', '兔', '兒', '兕', '兩', '具', '其', '典', '冽', '函', '刻',
+        '券', '刷', '刺', '到', '刮', '制', '剁', '劾', '劻', '卒', '協', '卓', '卑', '卦', '卷',
+        '卸', '卹', '取', '叔', '受', '味', '呵',
+    ],
+    // Lead 0xA9
+    [
+        '咖', '呸', '咕', '咀', '呻', '呷', '咄', '咒', '咆', '呼', '咐', '呱', '呶', '和', '咚',
+        '呢', '周', '咋', '命', '咎', '固', '垃', '坷', '坪', '坩', '坡', '坦', '坤', '坼', '夜',
+        '奉', '奇', '奈', '奄', '奔', '妾', '妻', '委', '妹', '妮', '姑', '姆', '姐', '姍', '始',
+        '姓', '姊', '妯', '妳', '姒', '姅', '孟', '孤', '季', '宗', '定', '官', '宜', '宙', '宛',
+        '尚', '屈', '居', '屆', '岷', '岡', '岸', '岩', '岫', '岱', '岳', '帘', '帚', '帖', '帕',
+        '帛', '帑', '幸', '庚', '店', '府', '底', '庖', '延', '弦', '弧', '弩', '往', '征', '彿',
+        '彼', '忝', '忠', '忽', '念', '忿', '怏', '怔', '怯', '怵', '怖', '怪', '怕', '怡', '性',
+        '怩', '怫', '怛', '或', '戕', '房', '戾', '所', '承', '拉', '拌', '拄', '抿', '拂', '抹',
+        '拒', '招', '披', '拓', '拔', '拋', '拈', '抨', '抽', '押', '拐', '拙', '拇', '拍', '抵',
+        '拚', '抱', '拘', '拖', '拗', '拆', '抬', '拎', '放', '斧', '於', '旺', '昔', '易', '昌',
+        '昆', '昂', '明', '昀', '昏', '昕', '昊',
+    ],
+    // Lead 0xAA
+    [
+        '昇', '服', '朋', '杭', '枋', '枕', '東', '果', '杳', '杷', '枇', '枝', '林', '杯', '杰',
+        '板', '枉', '松', '析', '杵', '枚', '枓', '杼', '杪', '杲', '欣', '武', '歧', '歿', '氓',
+        '氛', '泣', '注', '泳', '沱', '泌', '泥', '河', '沽', '沾', '沼', '波', '沫', '法', '泓',
+        '沸', '泄', '油', '況', '沮', '泗', '泅', '泱', '沿', '治', '泡', '泛', '泊', '沬', '泯',
+        '泜', '泖', '泠', '炕', '炎', '炒', '炊', '炙', '爬', '爭', '爸', '版', '牧', '物', '狀',
+        '狎', '狙', '狗', '狐', '玩', '玨', '玟', '玫', '玥', '甽', '疝', '疙', '疚', '的', '盂',
+        '盲', '直', '知', '矽', '社', '祀', '祁', '秉', '秈', '空', '穹', '竺', '糾', '罔', '羌',
+        '羋', '者', '肺', '肥', '肢', '肱', '股', '肫', '肩', '肴', '肪', '肯', '臥', '臾', '舍',
+        '芳', '芝', '芙', '芭', '芽', '芟', '芹', '花', '芬', '芥', '芯', '芸', '芣', '芰', '芾',
+        '芷', '虎', '虱', '初', '表', '軋', '迎', '返', '近', '邵', '邸', '邱', '邶', '采', '金',
+        '長', '門', '阜', '陀', '阿', '阻', '附',
+    ],
+    // Lead 0xAB
+    [
+        '陂', '隹', '雨', '青', '非', '亟', '亭', '亮', '信', '侵', '侯', '便', '俠', '俑', '俏',
+        '保', '促', '侶', '俘', '俟', '俊', '俗', '侮', '俐', '俄', '係', '俚', '俎', '俞', '侷',
+        '兗', '冒', '冑', '冠', '剎', '剃', '削', '前', '剌', '剋', '則', '勇', '勉', '勃', '勁',
+        '匍', '南', '卻', '厚', '叛', '咬', '哀', '咨', '哎', '哉', '咸', '咦', '咳', '哇', '哂',
+        '咽', '咪', '品', '哄', '哈', '咯', '咫', '咱', '咻', '咩', '咧', '咿', '囿', '垂', '型',
+        '垠', '垣', '垢', '城', '垮', '垓', '奕', '契', '奏', '奎', '奐', '姜', '姘', '姿', '姣',
+        '姨', '娃', '姥', '姪', '姚', '姦', '威', '姻', '孩', '宣', '宦', '室', '客', '宥', '封',
+        '屎', '屏', '屍', '屋', '峙', '峒', '巷', '帝', '帥', '帟', '幽', '庠', '度', '建', '弈',
+        '弭', '彥', '很', '待', '徊', '律', '徇', '後', '徉', '怒', '思', '怠', '急', '怎', '怨',
+        '恍', '恰', '恨', '恢', '恆', '恃', '恬', '恫', '恪', '恤', '扁', '拜', '挖', '按', '拼',
+        '拭', '持', '拮', '拽', '指', '拱', '拷',
+    ],
+    // Lead 0xAC
+    [
+        '拯', '括', '拾', '拴', '挑', '挂', '政', '故', '斫', '施', '既', '春', '昭', '映', '昧',
+        '是', '星', '昨', '昱', '昤', '曷', '柿', '染', '柱', '柔', '某', '柬', '架', '枯', '柵',
+        '柩', '柯', '柄', '柑', '枴', '柚', '查', '枸', '柏', '柞', '柳', '枰', '柙', '柢', '柝',
+        '柒', '歪', '殃', '殆', '段', '毒', '毗', '氟', '泉', '洋', '洲', '洪', '流', '津', '洌',
+        '洱', '洞', '洗', '活', '洽', '派', '洶', '洛', '泵', '洹', '洧', '洸', '洩', '洮', '洵',
+        '洎', '洫', '炫', '為', '炳', '炬', '炯', '炭', '炸', '炮', '炤', '爰', '牲', '牯', '牴',
+        '狩', '狠', '狡', '玷', '珊', '玻', '玲', '珍', '珀', '玳', '甚', '甭', '畏', '界', '畎',
+        '畋', '疫', '疤', '疥', '疢', '疣', '癸', '皆', '皇', '皈', '盈', '盆', '盃', '盅', '省',
+        '盹', '相', '眉', '看', '盾', '盼', '眇', '矜', '砂', '研', '砌', '砍', '祆', '祉', '祈',
+        '祇', '禹', '禺', '科', '秒', '秋', '穿', '突', '竿', '竽', '籽', '紂', '紅', '紀', '紉',
+        '紇', '約', '紆', '缸', '美', '羿', '耄',
+    ],
+    // Lead 0xAD
+    [
+        '耐', '耍', '耑', '耶', '胖', '胥', '胚', '胃', '胄', '背', '胡', '胛', '胎', '胞', '胤',
+        '胝', '致', '舢', '苧', '范', '茅', '苣', '苛', '苦', '茄', '若', '茂', '茉', '苒', '苗',
+        '英', '茁', '苜', '苔', '苑', '苞', '苓', '苟', '苯', '茆', '虐', '虹', '虻', '虺', '衍',
+        '衫', '要', '觔', '計', '訂', '訃', '貞', '負', '赴', '赳', '趴', '軍', '軌', '述', '迦',
+        '迢', '迪', '迥', '迭', '迫', '迤', '迨', '郊', '郎', '郁', '郃', '酋', '酊', '重', '閂',
+        '限', '陋', '陌', '降', '面', '革', '韋', '韭', '音', '頁', '風', '飛', '食', '首', '香',
+        '乘', '亳', '倌', '倍', '倣', '俯', '倦', '倥', '俸', '倩', '倖', '倆', '值', '借', '倚',
+        '倒', '們', '俺', '倀', '倔', '倨', '俱', '倡', '個', '候', '倘', '俳', '修', '倭', '倪',
+        '俾', '倫', '倉', '兼', '冤', '冥', '冢', '凍', '凌', '准', '凋', '剖', '剜', '剔', '剛',
+        '剝', '匪', '卿', '原', '厝', '叟', '哨', '唐', '唁', '唷', '哼', '哥', '哲', '唆', '哺',
+        '唔', '哩', '哭', '員', '唉', '哮', '哪',
+    ],
+    // Lead 0xAE
+    [
+        '哦', '唧', '唇', '哽', '唏', '圃', '圄', '埂', '埔', '埋', '埃', '堉', '夏', '套', '奘',
+        '奚', '娑', '娘', '娜', '娟', '娛', '娓', '姬', '娠', '娣', '娩', '娥', '娌', '娉', '孫',
+        '屘', '宰', '害', '家', '宴', '宮', '宵', '容', '宸', '射', '屑', '展', '屐', '峭', '峽',
+        '峻', '峪', '峨', '峰', '島', '崁', '峴', '差', '席', '師', '庫', '庭', '座', '弱', '徒',
+        '徑', '徐', '恙', '恣', '恥', '恐', '恕', '恭', '恩', '息', '悄', '悟', '悚', '悍', '悔',
+        '悌', '悅', '悖', '扇', '拳', '挈', '拿', '捎', '挾', '振', '捕', '捂', '捆', '捏', '捉',
+        '挺', '捐', '挽', '挪', '挫', '挨', '捍', '捌', '效', '敉', '料', '旁', '旅', '時', '晉',
+        '晏', '晃', '晒', '晌', '晅', '晁', '書', '朔', '朕', '朗', '校', '核', '案', '框', '桓',
+        '根', '桂', '桔', '栩', '梳', '栗', '桌', '桑', '栽', '柴', '桐', '桀', '格', '桃', '株',
+        '桅', '栓', '栘', '桁', '殊', '殉', '殷', '氣', '氧', '氨', '氦', '氤', '泰', '浪', '涕',
+        '消', '涇', '浦', '浸', '海', '浙', '涓',
+    ],
+    // Lead 0xAF
+    [
+        '浬', '涉', '浮', '浚', '浴', '浩', '涌', '涊', '浹', '涅', '浥', '涔', '烊', '烘', '烤',
+        '烙', '烈', '烏', '爹', '特', '狼', '狹', '狽', '狸', '狷', '玆', '班', '琉', '珮', '珠',
+        '珪', '珞', '畔', '畝', '畜', '畚', '留', '疾', '病', '症', '疲', '疳', '疽', '疼', '疹',
+        '痂', '疸', '皋', '皰', '益', '盍', '盎', '眩', '真', '眠', '眨', '矩', '砰', '砧', '砸',
+        '砝', '破', '砷', '砥', '砭', '砠', '砟', '砲', '祕', '祐', '祠', '祟', '祖', '神', '祝',
+        '祗', '祚', '秤', '秣', '秧', '租', '秦', '秩', '秘', '窄', '窈', '站', '笆', '笑', '粉',
+        '紡', '紗', '紋', '紊', '素', '索', '純', '紐', '紕', '級', '紜', '納', '紙', '紛', '缺',
+        '罟', '羔', '翅', '翁', '耆', '耘', '耕', '耙', '耗', '耽', '耿', '胱', '脂', '胰', '脅',
+        '胭', '胴', '脆', '胸', '胳', '脈', '能', '脊', '胼', '胯', '臭', '臬', '舀', '舐', '航',
+        '舫', '舨', '般', '芻', '茫', '荒', '荔', '荊', '茸', '荐', '草', '茵', '茴', '荏', '茲',
+        '茹', '茶', '茗', '荀', '茱', '茨', '荃',
+    ],
+    // Lead 0xB0
+    [
+        '虔', '蚊', '蚪', '蚓', '蚤', '蚩', '蚌', '蚣', '蚜', '衰', '衷', '袁', '袂', '衽', '衹',
+        '記', '訐', '討', '訌', '訕', '訊', '託', '訓', '訖', '訏', '訑', '豈', '豺', '豹', '財',
+        '貢', '起', '躬', '軒', '軔', '軏', '辱', '送', '逆', '迷', '退', '迺', '迴', '逃', '追',
+        '逅', '迸', '邕', '郡', '郝', '郢', '酒', '配', '酌', '釘', '針', '釗', '釜', '釙', '閃',
+        '院', '陣', '陡', '陛', '陝', '除', '陘', '陞', '隻', '飢', '馬', '骨', '高', '鬥', '鬲',
+        '鬼', '乾', '偺', '偽', '停', '假', '偃', '偌', '做', '偉', '健', '偶', '偎', '偕', '偵',
+        '側', '偷', '偏', '倏', '偯', '偭', '兜', '冕', '凰', '剪', '副', '勒', '務', '勘', '動',
+        '匐', '匏', '匙', '匿', '區', '匾', '參', '曼', '商', '啪', '啦', '啄', '啞', '啡', '啃',
+        '啊', '唱', '啖', '問', '啕', '唯', '啤', '唸', '售', '啜', '唬', '啣', '唳', '啁', '啗',
+        '圈', '國', '圉', '域', '堅', '堊', '堆', '埠', '埤', '基', '堂', '堵', '執', '培', '夠',
+        '奢', '娶', '婁', '婉', '婦', '婪', '婀',
+    ],
+    // Lead 0xB1
+    [
+        '娼', '婢', '婚', '婆', '婊', '孰', '寇', '寅', '寄', '寂', '宿', '密', '尉', '專', '將',
+        '屠', '屜', '屝', '崇', '崆', '崎', '崛', '崖', '崢', '崑', '崩', '崔', '崙', '崤', '崧',
+        '崗', '巢', '常', '帶', '帳', '帷', '康', '庸', '庶', '庵', '庾', '張', '強', '彗', '彬',
+        '彩', '彫', '得', '徙', '從', '徘', '御', '徠', '徜', '恿', '患', '悉', '悠', '您', '惋',
+        '悴', '惦', '悽', '情', '悻', '悵', '惜', '悼', '惘', '惕', '惆', '惟', '悸', '惚', '惇',
+        '戚', '戛', '扈', '掠', '控', '捲', '掖', '探', '接', '捷', '捧', '掘', '措', '捱', '掩',
+        '掉', '掃', '掛', '捫', '推', '掄', '授', '掙', '採', '掬', '排', '掏', '掀', '捻', '捩',
+        '捨', '捺', '敝', '敖', '救', '教', '敗', '啟', '敏', '敘', '敕', '敔', '斜', '斛', '斬',
+        '族', '旋', '旌', '旎', '晝', '晚', '晤', '晨', '晦', '晞', '曹', '勗', '望', '梁', '梯',
+        '梢', '梓', '梵', '桿', '桶', '梱', '梧', '梗', '械', '梃', '棄', '梭', '梆', '梅', '梔',
+        '條', '梨', '梟', '梡', '梂', '欲', '殺',
+    ],
+    // Lead 0xB2
+    [
+        '毫', '毬', '氫', '涎', '涼', '淳', '淙', '液', '淡', '淌', '淤', '添', '淺', '清', '淇',
+        '淋', '涯', '淑', '涮', '淞', '淹', '涸', '混', '淵', '淅', '淒', '渚', '涵', '淚', '淫',
+        '淘', '淪', '深', '淮', '淨', '淆', '淄', '涪', '淬', '涿', '淦', '烹', '焉', '焊', '烽',
+        '烯', '爽', '牽', '犁', '猜', '猛', '猖', '猓', '猙', '率', '琅', '琊', '球', '理', '現',
+        '琍', '瓠', '瓶', '瓷', '甜', '產', '略', '畦', '畢', '異', '疏', '痔', '痕', '疵', '痊',
+        '痍', '皎', '盔', '盒', '盛', '眷', '眾', '眼', '眶', '眸', '眺', '硫', '硃', '硎', '祥',
+        '票', '祭', '移', '窒', '窕', '笠', '笨', '笛', '第', '符', '笙', '笞', '笮', '粒', '粗',
+        '粕', '絆', '絃', '統', '紮', '紹', '紼', '絀', '細', '紳', '組', '累', '終', '紲', '紱',
+        '缽', '羞', '羚', '翌', '翎', '習', '耜', '聊', '聆', '脯', '脖', '脣', '脫', '脩', '脰',
+        '脤', '舂', '舵', '舷', '舶', '船', '莎', '莞', '莘', '荸', '莢', '莖', '莽', '莫', '莒',
+        '莊', '莓', '莉', '莠', '荷', '荻', '荼',
+    ],
+    // Lead 0xB3
+    [
+        '莆', '莧', '處', '彪', '蛇', '蛀', '蚶', '蛄', '蚵', '蛆', '蛋', '蚱', '蚯', '蛉', '術',
+        '袞', '袈', '被', '袒', '袖', '袍', '袋', '覓', '規', '訪', '訝', '訣', '訥', '許', '設',
+        '訟', '訛', '訢', '豉', '豚', '販', '責', '貫', '貨', '貪', '貧', '赧', '赦', '趾', '趺',
+        '軛', '軟', '這', '逍', '通', '逗', '連', '速', '逝', '逐', '逕', '逞', '造', '透', '逢',
+        '逖', '逛', '途', '部', '郭', '都', '酗', '野', '釵', '釦', '釣', '釧', '釭', '釩', '閉',
+        '陪', '陵', '陳', '陸', '陰', '陴', '陶', '陷', '陬', '雀', '雪', '雩', '章', '竟', '頂',
+        '頃', '魚', '鳥', '鹵', '鹿', '麥', '麻', '傢', '傍', '傅', '備', '傑', '傀', '傖', '傘',
+        '傚', '最', '凱', '割', '剴', '創', '剩', '勞', '勝', '勛', '博', '厥', '啻', '喀', '喧',
+        '啼', '喊', '喝', '喘', '喂', '喜', '喪', '喔', '喇', '喋', '喃', '喳', '單', '喟', '唾',
+        '喲', '喚', '喻', '喬', '喱', '啾', '喉', '喫', '喙', '圍', '堯', '堪', '場', '堤', '堰',
+        '報', '堡', '堝', '堠', '壹', '壺', '奠',
+    ],
+    // Lead 0xB4
+    [
+        '婷', '媚', '婿', '媒', '媛', '媧', '孳', '孱', '寒', '富', '寓', '寐', '尊', '尋', '就',
+        '嵌', '嵐', '崴', '嵇', '巽', '幅', '帽', '幀', '幃', '幾', '廊', '廁', '廂', '廄', '弼',
+        '彭', '復', '循', '徨', '惑', '惡', '悲', '悶', '惠', '愜', '愣', '惺', '愕', '惰', '惻',
+        '惴', '慨', '惱', '愎', '惶', '愉', '愀', '愒', '戟', '扉', '掣', '掌', '描', '揀', '揩',
+        '揉', '揆', '揍', '插', '揣', '提', '握', '揖', '揭', '揮', '捶', '援', '揪', '換', '摒',
+        '揚', '揹', '敞', '敦', '敢', '散', '斑', '斐', '斯', '普', '晰', '晴', '晶', '景', '暑',
+        '智', '晾', '晷', '曾', '替', '期', '朝', '棺', '棕', '棠', '棘', '棗', '椅', '棟', '棵',
+        '森', '棧', '棹', '棒', '棲', '棣', '棋', '棍', '植', '椒', '椎', '棉', '棚', '楮', '棻',
+        '款', '欺', '欽', '殘', '殖', '殼', '毯', '氮', '氯', '氬', '港', '游', '湔', '渡', '渲',
+        '湧', '湊', '渠', '渥', '渣', '減', '湛', '湘', '渤', '湖', '湮', '渭', '渦', '湯', '渴',
+        '湍', '渺', '測', '湃', '渝', '渾', '滋',
+    ],
+    // Lead 0xB5
+    [
+        '溉', '渙', '湎', '湣', '湄', '湲', '湩', '湟', '焙', '焚', '焦', '焰', '無', '然', '煮',
+        '焜', '牌', '犄', '犀', '猶', '猥', '猴', '猩', '琺', '琪', '琳', '琢', '琥', '琵', '琶',
+        '琴', '琯', '琛', '琦', '琨', '甥', '甦', '畫', '番', '痢', '痛', '痣', '痙', '痘', '痞',
+        '痠', '登', '發', '皖', '皓', '皴', '盜', '睏', '短', '硝', '硬', '硯', '稍', '稈', '程',
+        '稅', '稀', '窘', '窗', '窖', '童', '竣', '等', '策', '筆', '筐', '筒', '答', '筍', '筋',
+        '筏', '筑', '粟', '粥', '絞', '結', '絨', '絕', '紫', '絮', '絲', '絡', '給', '絢', '絰',
+        '絳', '善', '翔', '翕', '耋', '聒', '肅', '腕', '腔', '腋', '腑', '腎', '脹', '腆', '脾',
+        '腌', '腓', '腴', '舒', '舜', '菩', '萃', '菸', '萍', '菠', '菅', '萋', '菁', '華', '菱',
+        '菴', '著', '萊', '菰', '萌', '菌', '菽', '菲', '菊', '萸', '萎', '萄', '菜', '萇', '菔',
+        '菟', '虛', '蛟', '蛙', '蛭', '蛔', '蛛', '蛤', '蛐', '蛞', '街', '裁', '裂', '袱', '覃',
+        '視', '註', '詠', '評', '詞', '証', '詁',
+    ],
+    // Lead 0xB6
+    [
+        '詔', '詛', '詐', '詆', '訴', '診', '訶', '詖', '象', '貂', '貯', '貼', '貳', '貽', '賁',
+        '費', '賀', '貴', '買', '貶', '貿', '貸', '越', '超', '趁', '跎', '距', '跋', '跚', '跑',
+        '跌', '跛', '跆', '軻', '軸', '軼', '辜', '逮', '逵', '週', '逸', '進', '逶', '鄂', '郵',
+        '鄉', '郾', '酣', '酥', '量', '鈔', '鈕', '鈣', '鈉', '鈞', '鈍', '鈐', '鈇', '鈑', '閔',
+        '閏', '開', '閑', '間', '閒', '閎', '隊', '階', '隋', '陽', '隅', '隆', '隍', '陲', '隄',
+        '雁', '雅', '雄', '集', '雇', '雯', '雲', '韌', '項', '順', '須', '飧', '飪', '飯', '飩',
+        '飲', '飭', '馮', '馭', '黃', '黍', '黑', '亂', '傭', '債', '傲', '傳', '僅', '傾', '催',
+        '傷', '傻', '傯', '僇', '剿', '剷', '剽', '募', '勦', '勤', '勢', '勣', '匯', '嗟', '嗨',
+        '嗓', '嗦', '嗎', '嗜', '嗇', '嗑', '嗣', '嗤', '嗯', '嗚', '嗡', '嗅', '嗆', '嗥', '嗉',
+        '園', '圓', '塞', '塑', '塘', '塗', '塚', '塔', '填', '塌', '塭', '塊', '塢', '塒', '塋',
+        '奧', '嫁', '嫉', '嫌', '媾', '媽', '媼',
+    ],
+    // Lead 0xB7
+    [
+        '媳', '嫂', '媲', '嵩', '嵯', '幌', '幹', '廉', '廈', '弒', '彙', '徬', '微', '愚', '意',
+        '慈', '感', '想', '愛', '惹', '愁', '愈', '慎', '慌', '慄', '慍', '愾', '愴', '愧', '愍',
+        '愆', '愷', '戡', '戢', '搓', '搾', '搞', '搪', '搭', '搽', '搬', '搏', '搜', '搔', '損',
+        '搶', '搖', '搗', '搆', '敬', '斟', '新', '暗', '暉', '暇', '暈', '暖', '暄', '暘', '暍',
+        '會', '榔', '業', '楚', '楷', '楠', '楔', '極', '椰', '概', '楊', '楨', '楫', '楞', '楓',
+        '楹', '榆', '楝', '楣', '楛', '歇', '歲', '毀', '殿', '毓', '毽', '溢', '溯', '滓', '溶',
+        '滂', '源', '溝', '滇', '滅', '溥', '溘', '溼', '溺', '溫', '滑', '準', '溜', '滄', '滔',
+        '溪', '溧', '溴', '煎', '煙', '煩', '煤', '煉', '照', '煜', '煬', '煦', '煌', '煥', '煞',
+        '煆', '煨', '煖', '爺', '牒', '猷', '獅', '猿', '猾', '瑯', '瑚', '瑕', '瑟', '瑞', '瑁',
+        '琿', '瑙', '瑛', '瑜', '當', '畸', '瘀', '痰', '瘁', '痲', '痱', '痺', '痿', '痴', '痳',
+        '盞', '盟', '睛', '睫', '睦', '睞', '督',
+    ],
+    // Lead 0xB8
+    [
+        '睹', '睪', '睬', '睜', '睥', '睨', '睢', '矮', '碎', '碰', '碗', '碘', '碌', '碉', '硼',
+        '碑', '碓', '硿', '祺', '祿', '禁', '萬', '禽', '稜', '稚', '稠', '稔', '稟', '稞', '窟',
+        '窠', '筷', '節', '筠', '筮', '筧', '粱', '粳', '粵', '經', '絹', '綑', '綁', '綏', '絛',
+        '置', '罩', '罪', '署', '義', '羨', '群', '聖', '聘', '肆', '肄', '腱', '腰', '腸', '腥',
+        '腮', '腳', '腫', '腹', '腺', '腦', '舅', '艇', '蒂', '葷', '落', '萱', '葵', '葦', '葫',
+        '葉', '葬', '葛', '萼', '萵', '葡', '董', '葩', '葭', '葆', '虞', '虜', '號', '蛹', '蜓',
+        '蜈', '蜇', '蜀', '蛾', '蛻', '蜂', '蜃', '蜆', '蜊', '衙', '裟', '裔', '裙', '補', '裘',
+        '裝', '裡', '裊', '裕', '裒', '覜', '解', '詫', '該', '詳', '試', '詩', '詰', '誇', '詼',
+        '詣', '誠', '話', '誅', '詭', '詢', '詮', '詬', '詹', '詻', '訾', '詨', '豢', '貊', '貉',
+        '賊', '資', '賈', '賄', '貲', '賃', '賂', '賅', '跡', '跟', '跨', '路', '跳', '跺', '跪',
+        '跤', '跦', '躲', '較', '載', '軾', '輊',
+    ],
+    // Lead 0xB9
+    [
+        '辟', '農', '運', '遊', '道', '遂', '達', '逼', '違', '遐', '遇', '遏', '過', '遍', '遑',
+        '逾', '遁', '鄒', '鄗', '酬', '酪', '酩', '釉', '鈷', '鉗', '鈸', '鈽', '鉀', '鈾', '鉛',
+        '鉋', '鉤', '鉑', '鈴', '鉉', '鉍', '鉅', '鈹', '鈿', '鉚', '閘', '隘', '隔', '隕', '雍',
+        '雋', '雉', '雊', '雷', '電', '雹', '零', '靖', '靴', '靶', '預', '頑', '頓', '頊', '頒',
+        '頌', '飼', '飴', '飽', '飾', '馳', '馱', '馴', '髡', '鳩', '麂', '鼎', '鼓', '鼠', '僧',
+        '僮', '僥', '僖', '僭', '僚', '僕', '像', '僑', '僱', '僎', '僩', '兢', '凳', '劃', '劂',
+        '匱', '厭', '嗾', '嘀', '嘛', '嘗', '嗽', '嘔', '嘆', '嘉', '嘍', '嘎', '嗷', '嘖', '嘟',
+        '嘈', '嘐', '嗶', '團', '圖', '塵', '塾', '境', '墓', '墊', '塹', '墅', '塽', '壽', '夥',
+        '夢', '夤', '奪', '奩', '嫡', '嫦', '嫩', '嫗', '嫖', '嫘', '嫣', '孵', '寞', '寧', '寡',
+        '寥', '實', '寨', '寢', '寤', '察', '對', '屢', '嶄', '嶇', '幛', '幣', '幕', '幗', '幔',
+        '廓', '廖', '弊', '彆', '彰', '徹', '慇',
+    ],
+    // Lead 0xBA
+    [
+        '愿', '態', '慷', '慢', '慣', '慟', '慚', '慘', '慵', '截', '撇', '摘', '摔', '撤', '摸',
+        '摟', '摺', '摑', '摧', '搴', '摭', '摻', '敲', '斡', '旗', '旖', '暢', '暨', '暝', '榜',
+        '榨', '榕', '槁', '榮', '槓', '構', '榛', '榷', '榻', '榫', '榴', '槐', '槍', '榭', '槌',
+        '榦', '槃', '榣', '歉', '歌', '氳', '漳', '演', '滾', '漓', '滴', '漩', '漾', '漠', '漬',
+        '漏', '漂', '漢', '滿', '滯', '漆', '漱', '漸', '漲', '漣', '漕', '漫', '漯', '澈', '漪',
+        '滬', '漁', '滲', '滌', '滷', '熔', '熙', '煽', '熊', '熄', '熒', '爾', '犒', '犖', '獄',
+        '獐', '瑤', '瑣', '瑪', '瑰', '瑭', '甄', '疑', '瘧', '瘍', '瘋', '瘉', '瘓', '盡', '監',
+        '瞄', '睽', '睿', '睡', '磁', '碟', '碧', '碳', '碩', '碣', '禎', '福', '禍', '種', '稱',
+        '窪', '窩', '竭', '端', '管', '箕', '箋', '筵', '算', '箝', '箔', '箏', '箸', '箇', '箄',
+        '粹', '粽', '精', '綻', '綰', '綜', '綽', '綾', '綠', '緊', '綴', '網', '綱', '綺', '綢',
+        '綿', '綵', '綸', '維', '緒', '緇', '綬',
+    ],
+    // Lead 0xBB
+    [
+        '罰', '翠', '翡', '翟', '聞', '聚', '肇', '腐', '膀', '膏', '膈', '膊', '腿', '膂', '臧',
+        '臺', '與', '舔', '舞', '艋', '蓉', '蒿', '蓆', '蓄', '蒙', '蒞', '蒲', '蒜', '蓋', '蒸',
+        '蓀', '蓓', '蒐', '蒼', '蓑', '蓊', '蜿', '蜜', '蜻', '蜢', '蜥', '蜴', '蜘', '蝕', '蜷',
+        '蜩', '裳', '褂', '裴', '裹', '裸', '製', '裨', '褚', '裯', '誦', '誌', '語', '誣', '認',
+        '誡', '誓', '誤', '說', '誥', '誨', '誘', '誑', '誚', '誧', '豪', '貍', '貌', '賓', '賑',
+        '賒', '赫', '趙', '趕', '跼', '輔', '輒', '輕', '輓', '辣', '遠', '遘', '遜', '遣', '遙',
+        '遞', '遢', '遝', '遛', '鄙', '鄘', '鄞', '酵', '酸', '酷', '酴', '鉸', '銀', '銅', '銘',
+        '銖', '鉻', '銓', '銜', '銨', '鉼', '銑', '閡', '閨', '閩', '閣', '閥', '閤', '隙', '障',
+        '際', '雌', '雒', '需', '靼', '鞅', '韶', '頗', '領', '颯', '颱', '餃', '餅', '餌', '餉',
+        '駁', '骯', '骰', '髦', '魁', '魂', '鳴', '鳶', '鳳', '麼', '鼻', '齊', '億', '儀', '僻',
+        '僵', '價', '儂', '儈', '儉', '儅', '凜',
+    ],
+    // Lead 0xBC
+    [
+        '劇', '劈', '劉', '劍', '劊', '勰', '厲', '嘮', '嘻', '嘹', '嘲', '嘿', '嘴', '嘩', '噓',
+        '噎', '噗', '噴', '嘶', '嘯', '嘰', '墀', '墟', '增', '墳', '墜', '墮', '墩', '墦', '奭',
+        '嬉', '嫻', '嬋', '嫵', '嬌', '嬈', '寮', '寬', '審', '寫', '層', '履', '嶝', '嶔', '幢',
+        '幟', '幡', '廢', '廚', '廟', '廝', '廣', '廠', '彈', '影', '德', '徵', '慶', '慧', '慮',
+        '慝', '慕', '憂', '慼', '慰', '慫', '慾', '憧', '憐', '憫', '憎', '憬', '憚', '憤', '憔',
+        '憮', '戮', '摩', '摯', '摹', '撞', '撲', '撈', '撐', '撰', '撥', '撓', '撕', '撩', '撒',
+        '撮', '播', '撫', '撚', '撬', '撙', '撢', '撳', '敵', '敷', '數', '暮', '暫', '暴', '暱',
+        '樣', '樟', '槨', '樁', '樞', '標', '槽', '模', '樓', '樊', '槳', '樂', '樅', '槭', '樑',
+        '歐', '歎', '殤', '毅', '毆', '漿', '潼', '澄', '潑', '潦', '潔', '澆', '潭', '潛', '潸',
+        '潮', '澎', '潺', '潰', '潤', '澗', '潘', '滕', '潯', '潠', '潟', '熟', '熬', '熱', '熨',
+        '牖', '犛', '獎', '獗', '瑩', '璋', '璃',
+    ],
+    // Lead 0xBD
+    [
+        '瑾', '璀', '畿', '瘠', '瘩', '瘟', '瘤', '瘦', '瘡', '瘢', '皚', '皺', '盤', '瞎', '瞇',
+        '瞌', '瞑', '瞋', '磋', '磅', '確', '磊', '碾', '磕', '碼', '磐', '稿', '稼', '穀', '稽',
+        '稷', '稻', '窯', '窮', '箭', '箱', '範', '箴', '篆', '篇', '篁', '箠', '篌', '糊', '締',
+        '練', '緯', '緻', '緘', '緬', '緝', '編', '緣', '線', '緞', '緩', '綞', '緙', '緲', '緹',
+        '罵', '罷', '羯', '翩', '耦', '膛', '膜', '膝', '膠', '膚', '膘', '蔗', '蔽', '蔚', '蓮',
+        '蔬', '蔭', '蔓', '蔑', '蔣', '蔡', '蔔', '蓬', '蔥', '蓿', '蔆', '螂', '蝴', '蝶', '蝠',
+        '蝦', '蝸', '蝨', '蝙', '蝗', '蝌', '蝓', '衛', '衝', '褐', '複', '褒', '褓', '褕', '褊',
+        '誼', '諒', '談', '諄', '誕', '請', '諸', '課', '諉', '諂', '調', '誰', '論', '諍', '誶',
+        '誹', '諛', '豌', '豎', '豬', '賠', '賞', '賦', '賤', '賬', '賭', '賢', '賣', '賜', '質',
+        '賡', '赭', '趟', '趣', '踫', '踐', '踝', '踢', '踏', '踩', '踟', '踡', '踞', '躺', '輝',
+        '輛', '輟', '輩', '輦', '輪', '輜', '輞',
+    ],
+    // Lead 0xBE
+    [
+        '輥', '適', '遮', '遨', '遭', '遷', '鄰', '鄭', '鄧', '鄱', '醇', '醉', '醋', '醃', '鋅',
+        '銻', '銷', '鋪', '銬', '鋤', '鋁', '銳', '銼', '鋒', '鋇', '鋰', '銲', '閭', '閱', '霄',
+        '霆', '震', '霉', '靠', '鞍', '鞋', '鞏', '頡', '頫', '頜', '颳', '養', '餓', '餒', '餘',
+        '駝', '駐', '駟', '駛', '駑', '駕', '駒', '駙', '骷', '髮', '髯', '鬧', '魅', '魄', '魷',
+        '魯', '鴆', '鴉', '鴃', '麩', '麾', '黎', '墨', '齒', '儒', '儘', '儔', '儐', '儕', '冀',
+        '冪', '凝', '劑', '劓', '勳', '噙', '噫', '噹', '噩', '噤', '噸', '噪', '器', '噥', '噱',
+        '噯', '噬', '噢', '噶', '壁', '墾', '壇', '壅', '奮', '嬝', '嬴', '學', '寰', '導', '彊',
+        '憲', '憑', '憩', '憊', '懍', '憶', '憾', '懊', '懈', '戰', '擅', '擁', '擋', '撻', '撼',
+        '據', '擄', '擇', '擂', '操', '撿', '擒', '擔', '撾', '整', '曆', '曉', '暹', '曄', '曇',
+        '暸', '樽', '樸', '樺', '橙', '橫', '橘', '樹', '橄', '橢', '橡', '橋', '橇', '樵', '機',
+        '橈', '歙', '歷', '氅', '濂', '澱', '澡',
+    ],
+    // Lead 0xBF
+    [
+        '濃', '澤', '濁', '澧', '澳', '激', '澹', '澶', '澦', '澠', '澴', '熾', '燉', '燐', '燒',
+        '燈', '燕', '熹', '燎', '燙', '燜', '燃', '燄', '獨', '璜', '璣', '璘', '璟', '璞', '瓢',
+        '甌', '甍', '瘴', '瘸', '瘺', '盧', '盥', '瞠', '瞞', '瞟', '瞥', '磨', '磚', '磬', '磧',
+        '禦', '積', '穎', '穆', '穌', '穋', '窺', '篙', '簑', '築', '篤', '篛', '篡', '篩', '篦',
+        '糕', '糖', '縊', '縑', '縈', '縛', '縣', '縞', '縝', '縉', '縐', '罹', '羲', '翰', '翱',
+        '翮', '耨', '膳', '膩', '膨', '臻', '興', '艘', '艙', '蕊', '蕙', '蕈', '蕨', '蕩', '蕃',
+        '蕉', '蕭', '蕪', '蕞', '螃', '螟', '螞', '螢', '融', '衡', '褪', '褲', '褥', '褫', '褡',
+        '親', '覦', '諦', '諺', '諫', '諱', '謀', '諜', '諧', '諮', '諾', '謁', '謂', '諷', '諭',
+        '諳', '諶', '諼', '豫', '豭', '貓', '賴', '蹄', '踱', '踴', '蹂', '踹', '踵', '輻', '輯',
+        '輸', '輳', '辨', '辦', '遵', '遴', '選', '遲', '遼', '遺', '鄴', '醒', '錠', '錶', '鋸',
+        '錳', '錯', '錢', '鋼', '錫', '錄', '錚',
+    ],
+    // Lead 0xC0
+    [
+        '錐', '錦', '錡', '錕', '錮', '錙', '閻', '隧', '隨', '險', '雕', '霎', '霑', '霖', '霍',
+        '霓', '霏', '靛', '靜', '靦', '鞘', '頰', '頸', '頻', '頷', '頭', '頹', '頤', '餐', '館',
+        '餞', '餛', '餡', '餚', '駭', '駢', '駱', '骸', '骼', '髻', '髭', '鬨', '鮑', '鴕', '鴣',
+        '鴦', '鴨', '鴒', '鴛', '默', '黔', '龍', '龜', '優', '償', '儡', '儲', '勵', '嚎', '嚀',
+        '嚐', '嚅', '嚇', '嚏', '壕', '壓', '壑', '壎', '嬰', '嬪', '嬤', '孺', '尷', '屨', '嶼',
+        '嶺', '嶽', '嶸', '幫', '彌', '徽', '應', '懂', '懇', '懦', '懋', '戲', '戴', '擎', '擊',
+        '擘', '擠', '擰', '擦', '擬', '擱', '擢', '擭', '斂', '斃', '曙', '曖', '檀', '檔', '檄',
+        '檢', '檜', '櫛', '檣', '橾', '檗', '檐', '檠', '歜', '殮', '毚', '氈', '濘', '濱', '濟',
+        '濠', '濛', '濤', '濫', '濯', '澀', '濬', '濡', '濩', '濕', '濮', '濰', '燧', '營', '燮',
+        '燦', '燥', '燭', '燬', '燴', '燠', '爵', '牆', '獰', '獲', '璩', '環', '璦', '璨', '癆',
+        '療', '癌', '盪', '瞳', '瞪', '瞰', '瞬',
+    ],
+    // Lead 0xC1
+    [
+        '瞧', '瞭', '矯', '磷', '磺', '磴', '磯', '礁', '禧', '禪', '穗', '窿', '簇', '簍', '篾',
+        '篷', '簌', '篠', '糠', '糜', '糞', '糢', '糟', '糙', '糝', '縮', '績', '繆', '縷', '縲',
+        '繃', '縫', '總', '縱', '繅', '繁', '縴', '縹', '繈', '縵', '縿', '縯', '罄', '翳', '翼',
+        '聱', '聲', '聰', '聯', '聳', '臆', '臃', '膺', '臂', '臀', '膿', '膽', '臉', '膾', '臨',
+        '舉', '艱', '薪', '薄', '蕾', '薜', '薑', '薔', '薯', '薛', '薇', '薨', '薊', '虧', '蟀',
+        '蟑', '螳', '蟒', '蟆', '螫', '螻', '螺', '蟈', '蟋', '褻', '褶', '襄', '褸', '褽', '覬',
+        '謎', '謗', '謙', '講', '謊', '謠', '謝', '謄', '謐', '豁', '谿', '豳', '賺', '賽', '購',
+        '賸', '賻', '趨', '蹉', '蹋', '蹈', '蹊', '轄', '輾', '轂', '轅', '輿', '避', '遽', '還',
+        '邁', '邂', '邀', '鄹', '醣', '醞', '醜', '鍍', '鎂', '錨', '鍵', '鍊', '鍥', '鍋', '錘',
+        '鍾', '鍬', '鍛', '鍰', '鍚', '鍔', '闊', '闋', '闌', '闈', '闆', '隱', '隸', '雖', '霜',
+        '霞', '鞠', '韓', '顆', '颶', '餵', '騁',
+    ],
+    // Lead 0xC2
+    [
+        '駿', '鮮', '鮫', '鮪', '鮭', '鴻', '鴿', '麋', '黏', '點', '黜', '黝', '黛', '鼾', '齋',
+        '叢', '嚕', '嚮', '壙', '壘', '嬸', '彝', '懣', '戳', '擴', '擲', '擾', '攆', '擺', '擻',
+        '擷', '斷', '曜', '朦', '檳', '檬', '櫃', '檻', '檸', '櫂', '檮', '檯', '歟', '歸', '殯',
+        '瀉', '瀋', '濾', '瀆', '濺', '瀑', '瀏', '燻', '燼', '燾', '燸', '獷', '獵', '璧', '璿',
+        '甕', '癖', '癘', '癒', '瞽', '瞿', '瞻', '瞼', '礎', '禮', '穡', '穢', '穠', '竄', '竅',
+        '簫', '簧', '簪', '簞', '簣', '簡', '糧', '織', '繕', '繞', '繚', '繡', '繒', '繙', '罈',
+        '翹', '翻', '職', '聶', '臍', '臏', '舊', '藏', '薩', '藍', '藐', '藉', '薰', '薺', '薹',
+        '薦', '蟯', '蟬', '蟲', '蟠', '覆', '覲', '觴', '謨', '謹', '謬', '謫', '豐', '贅', '蹙',
+        '蹣', '蹦', '蹤', '蹟', '蹕', '軀', '轉', '轍', '邇', '邃', '邈', '醫', '醬', '釐', '鎔',
+        '鎊', '鎖', '鎢', '鎳', '鎮', '鎬', '鎰', '鎘', '鎚', '鎗', '闔', '闖', '闐', '闕', '離',
+        '雜', '雙', '雛', '雞', '霤', '鞣', '鞦',
+    ],
+    // Lead 0xC3
+    [
+        '鞭', '韹', '額', '顏', '題', '顎', '顓', '颺', '餾', '餿', '餽', '餮', '馥', '騎', '髁',
+        '鬃', '鬆', '魏', '魎', '魍', '鯊', '鯉', '鯽', '鯈', '鯀', '鵑', '鵝', '鵠', '黠', '鼕',
+        '鼬', '儳', '嚥', '壞', '壟', '壢', '寵', '龐', '廬', '懲', '懷', '懶', '懵', '攀', '攏',
+        '曠', '曝', '櫥', '櫝', '櫚', '櫓', '瀛', '瀟', '瀨', '瀚', '瀝', '瀕', '瀘', '爆', '爍',
+        '牘', '犢', '獸', '獺', '璽', '瓊', '瓣', '疇', '疆', '癟', '癡', '矇', '礙', '禱', '穫',
+        '穩', '簾', '簿', '簸', '簽', '簷', '籀', '繫', '繭', '繹', '繩', '繪', '羅', '繳', '羶',
+        '羹', '羸', '臘', '藩', '藝', '藪', '藕', '藤', '藥', '藷', '蟻', '蠅', '蠍', '蟹', '蟾',
+        '襠', '襟', '襖', '襞', '譁', '譜', '識', '證', '譚', '譎', '譏', '譆', '譙', '贈', '贊',
+        '蹼', '蹲', '躇', '蹶', '蹬', '蹺', '蹴', '轔', '轎', '辭', '邊', '邋', '醱', '醮', '鏡',
+        '鏑', '鏟', '鏃', '鏈', '鏜', '鏝', '鏖', '鏢', '鏍', '鏘', '鏤', '鏗', '鏨', '關', '隴',
+        '難', '霪', '霧', '靡', '韜', '韻', '類',
+    ],
+    // Lead 0xC4
+    [
+        '願', '顛', '颼', '饅', '饉', '騖', '騙', '鬍', '鯨', '鯧', '鯖', '鯛', '鶉', '鵡', '鵲',
+        '鵪', '鵬', '麒', '麗', '麓', '麴', '勸', '嚨', '嚷', '嚶', '嚴', '嚼', '壤', '孀', '孃',
+        '孽', '寶', '巉', '懸', '懺', '攘', '攔', '攙', '曦', '朧', '櫬', '瀾', '瀰', '瀲', '爐',
+        '獻', '瓏', '癢', '癥', '礦', '礪', '礬', '礫', '竇', '競', '籌', '籃', '籍', '糯', '糰',
+        '辮', '繽', '繼', '纂', '罌', '耀', '臚', '艦', '藻', '藹', '蘑', '藺', '蘆', '蘋', '蘇',
+        '蘊', '蠔', '蠕', '襤', '覺', '觸', '議', '譬', '警', '譯', '譟', '譫', '贏', '贍', '躉',
+        '躁', '躅', '躂', '醴', '釋', '鐘', '鐃', '鏽', '闡', '霰', '飄', '饒', '饑', '馨', '騫',
+        '騰', '騷', '騵', '鰓', '鰍', '鹹', '麵', '黨', '鼯', '齟', '齣', '齡', '儷', '儸', '囁',
+        '囀', '囂', '夔', '屬', '巍', '懼', '懾', '攝', '攜', '斕', '曩', '櫻', '欄', '櫺', '殲',
+        '灌', '爛', '犧', '瓖', '瓔', '癩', '矓', '籐', '纏', '續', '羼', '蘗', '蘭', '蘚', '蠣',
+        '蠢', '蠡', '蠟', '襪', '襬', '覽', '譴',
+    ],
+    // Lead 0xC5
+    [
+        '護', '譽', '贓', '躊', '躍', '躋', '轟', '辯', '醺', '鐮', '鐳', '鐵', '鐺', '鐸', '鐲',
+        '鐫', '闢', '霸', '霹', '露', '響', '顧', '顥', '饗', '驅', '驃', '驀', '騾', '髏', '魔',
+        '魑', '鰭', '鰥', '鶯', '鶴', '鷂', '鶸', '麝', '黯', '鼙', '齜', '齦', '齧', '儼', '儻',
+        '囈', '囊', '囉', '孿', '巔', '巒', '彎', '懿', '攤', '權', '歡', '灑', '灘', '玀', '瓤',
+        '疊', '癮', '癬', '禳', '籠', '籟', '聾', '聽', '臟', '襲', '襯', '觼', '讀', '贖', '贗',
+        '躑', '躓', '轡', '酈', '鑄', '鑑', '鑒', '霽', '霾', '韃', '韁', '顫', '饕', '驕', '驍',
+        '髒', '鬚', '鱉', '鰱', '鰾', '鰻', '鷓', '鷗', '鼴', '齬', '齪', '龔', '囌', '巖', '戀',
+        '攣', '攫', '攪', '曬', '欐', '瓚', '竊', '籤', '籣', '籥', '纓', '纖', '纔', '臢', '蘸',
+        '蘿', '蠱', '變', '邐', '邏', '鑣', '鑠', '鑤', '靨', '顯', '饜', '驚', '驛', '驗', '髓',
+        '體', '髑', '鱔', '鱗', '鱖', '鷥', '麟', '黴', '囑', '壩', '攬', '灞', '癱', '癲', '矗',
+        '罐', '羈', '蠶', '蠹', '衢', '讓', '讒',
+    ],
+    // Lead 0xC6
+    [
+        '讖', '艷', '贛', '釀', '鑪', '靂', '靈', '靄', '韆', '顰', '驟', '鬢', '魘', '鱟', '鷹',
+        '鷺', '鹼', '鹽', '鼇', '齷', '齲', '廳', '欖', '灣', '籬', '籮', '蠻', '觀', '躡', '釁',
+        '鑲', '鑰', '顱', '饞', '髖', '鬣', '黌', '灤', '矚', '讚', '鑷', '韉', '驢', '驥', '纜',
+        '讜', '躪', '釅', '鑽', '鑾', '鑼', '鱷', '鱸', '黷', '豔', '鑿', '鸚', '爨', '驪', '鬱',
+        '鸛', '鸞', '籲', 'ヾ', 'ゝ', 'ゞ', '々', 'ぁ', 'あ', 'ぃ', 'い', 'ぅ', 'う', 'ぇ', 'え',
+        'ぉ', 'お', 'か', 'が', 'き', 'ぎ', 'く', 'ぐ', 'け', 'げ', 'こ', 'ご', 'さ', 'ざ', 'し',
+        'じ', 'す', 'ず', 'せ', 'ぜ', 'そ', 'ぞ', 'た', 'だ', 'ち', 'ぢ', 'っ', 'つ', 'づ', 'て',
+        'で', 'と', 'ど', 'な', 'に', 'ぬ', 'ね', 'の', 'は', 'ば', 'ぱ', 'ひ', 'び', 'ぴ', 'ふ',
+        'ぶ', 'ぷ', 'へ', 'べ', 'ぺ', 'ほ', 'ぼ', 'ぽ', 'ま', 'み', 'む', 'め', 'も', 'ゃ', 'や',
+        'ゅ', 'ゆ', 'ょ', 'よ', 'ら', 'り', 'る', 'れ', 'ろ', 'ゎ', 'わ', 'ゐ', 'ゑ', 'を', 'ん',
+        'ァ', 'ア', 'ィ', 'イ', 'ゥ', 'ウ', 'ェ',
+    ],
+    // Lead 0xC7
+    [
+        'エ', 'ォ', 'オ', 'カ', 'ガ', 'キ', 'ギ', 'ク', 'グ', 'ケ', 'ゲ', 'コ', 'ゴ', 'サ', 'ザ',
+        'シ', 'ジ', 'ス', 'ズ', 'セ', 'ゼ', 'ソ', 'ゾ', 'タ', 'ダ', 'チ', 'ヂ', 'ッ', 'ツ', 'ヅ',
+        'テ', 'デ', 'ト', 'ド', 'ナ', 'ニ', 'ヌ', 'ネ', 'ノ', 'ハ', 'バ', 'パ', 'ヒ', 'ビ', 'ピ',
+        'フ', 'ブ', 'プ', 'ヘ', 'ベ', 'ペ', 'ホ', 'ボ', 'ポ', 'マ', 'ミ', 'ム', 'メ', 'モ', 'ャ',
+        'ヤ', 'ュ', 'ユ', 'ョ', 'ヨ', 'ラ', 'リ', 'ル', 'レ', 'ロ', 'ヮ', 'ワ', 'ヰ', 'ヱ', 'ヲ',
+        'ン', 'ヴ', 'ヵ', 'ヶ', 'Д', 'Е', 'Ё', 'Ж', 'З', 'И', 'Й', 'К', 'Л', 'М', 'У', 'Ф', 'Х',
+        'Ц', 'Ч', 'Ш', 'Щ', 'Ъ', 'Ы', 'Ь', 'Э', 'Ю', 'Я', 'а', 'б', 'в', 'г', 'д', 'е', 'ё', 'ж',
+        'з', 'и', 'й', 'к', 'л', 'м', 'н', 'о', 'п', 'р', 'с', 'т', 'у', 'ф', 'х', 'ц', 'ч', 'ш',
+        'щ', 'ъ', 'ы', 'ь', 'э', 'ю', 'я', '①', '②', '③', '④', '⑤', '⑥', '⑦', '⑧', '⑨', '⑩', '⑴',
+        '⑵', '⑶', '⑷', '⑸', '⑹', '⑺', '⑻', '⑼', '⑽', '�', '�',
+    ],
+    // Lead 0xC8
+    [
+        '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�',
+        '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�',
+        '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�',
+        '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�',
+        '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�',
+        '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�',
+        '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�',
+        '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�',
+        '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�',
+    ],
+    // Lead 0xC9
+    [
+        '乂', '乜', '凵', '匚', '厂', '万', '丌', '乇', '亍', '囗', '兀', '屮', '彳', '丏', '冇',
+        '与', '丮', '亓', '仂', '仉', '仈', '冘', '勼', '卬', '厹', '圠', '夃', '夬', '尐', '巿',
+        '旡', '殳', '毌', '气', '爿', '丱', '丼', '仨', '仜', '仩', '仡', '仝', '仚', '刌', '匜',
+        '卌', '圢', '圣', '夗', '夯', '宁', '宄', '尒', '尻', '屴', '屳', '帄', '庀', '庂', '忉',
+        '戉', '扐', '氕', '氶', '汃', '氿', '氻', '犮', '犰', '玊', '禸', '肊', '阞', '伎', '优',
+        '伬', '仵', '伔', '仱', '伀', '价', '伈', '伝', '伂', '伅', '伢', '伓', '伄', '仴', '伒',
+        '冱', '刓', '刉', '刐', '劦', '匢', '匟', '卍', '厊', '吇', '囡', '囟', '圮', '圪', '圴',
+        '夼', '妀', '奼', '妅', '奻', '奾', '奷', '奿', '孖', '尕', '尥', '屼', '屺', '屻', '屾',
+        '巟', '幵', '庄', '异', '弚', '彴', '忕', '忔', '忏', '扜', '扞', '扤', '扡', '扦', '扢',
+        '扙', '扠', '扚', '扥', '旯', '旮', '朾', '朹', '朸', '朻', '机', '朿', '朼', '朳', '氘',
+        '汆', '汒', '汜', '汏', '汊', '汔', '汋',
+    ],
+    // Lead 0xCA
+    [
+        '汌', '灱', '牞', '犴', '犵', '玎', '甪', '癿', '穵', '网', '艸', '艼', '芀', '艽', '艿',
+        '虍', '襾', '邙', '邗', '邘', '邛', '邔', '阢', '阤', '阠', '阣', '佖', '伻', '佢', '佉',
+        '体', '佤', '伾', '佧', '佒', '佟', '佁', '佘', '伭', '伳', '伿', '佡', '冏', '冹', '刜',
+        '刞', '刡', '劭', '劮', '匉', '卣', '卲', '厎', '厏', '吰', '吷', '吪', '呔', '呅', '吙',
+        '吜', '吥', '吘', '吽', '呏', '呁', '吨', '吤', '呇', '囮', '囧', '囥', '坁', '坅', '坌',
+        '坉', '坋', '坒', '夆', '奀', '妦', '妘', '妠', '妗', '妎', '妢', '妐', '妏', '妧', '妡',
+        '宎', '宒', '尨', '尪', '岍', '岏', '岈', '岋', '岉', '岒', '岊', '岆', '岓', '岕', '巠',
+        '帊', '帎', '庋', '庉', '庌', '庈', '庍', '弅', '弝', '彸', '彶', '忒', '忑', '忐', '忭',
+        '忨', '忮', '忳', '忡', '忤', '忣', '忺', '忯', '忷', '忻', '怀', '忴', '戺', '抃', '抌',
+        '抎', '抏', '抔', '抇', '扱', '扻', '扺', '扰', '抁', '抈', '扷', '扽', '扲', '扴', '攷',
+        '旰', '旴', '旳', '旲', '旵', '杅', '杇',
+    ],
+    // Lead 0xCB
+    [
+        '杙', '杕', '杌', '杈', '杝', '杍', '杚', '杋', '毐', '氙', '氚', '汸', '汧', '汫', '沄',
+        '沋', '沏', '汱', '汯', '汩', '沚', '汭', '沇', '沕', '沜', '汦', '汳', '汥', '汻', '沎',
+        '灴', '灺', '牣', '犿', '犽', '狃', '狆', '狁', '犺', '狅', '玕', '玗', '玓', '玔', '玒',
+        '町', '甹', '疔', '疕', '皁', '礽', '耴', '肕', '肙', '肐', '肒', '肜', '芐', '芏', '芅',
+        '芎', '芑', '芓', '芊', '芃', '芄', '豸', '迉', '辿', '邟', '邡', '邥', '邞', '邧', '邠',
+        '阰', '阨', '阯', '阭', '丳', '侘', '佼', '侅', '佽', '侀', '侇', '佶', '佴', '侉', '侄',
+        '佷', '佌', '侗', '佪', '侚', '佹', '侁', '佸', '侐', '侜', '侔', '侞', '侒', '侂', '侕',
+        '佫', '佮', '冞', '冼', '冾', '刵', '刲', '刳', '剆', '刱', '劼', '匊', '匋', '匼', '厒',
+        '厔', '咇', '呿', '咁', '咑', '咂', '咈', '呫', '呺', '呾', '呥', '呬', '呴', '呦', '咍',
+        '呯', '呡', '呠', '咘', '呣', '呧', '呤', '囷', '囹', '坯', '坲', '坭', '坫', '坱', '坰',
+        '坶', '垀', '坵', '坻', '坳', '坴', '坢',
+    ],
+    // Lead 0xCC
+    [
+        '坨', '坽', '夌', '奅', '妵', '妺', '姏', '姎', '妲', '姌', '姁', '妶', '妼', '姃', '姖',
+        '妱', '妽', '姀', '姈', '妴', '姇', '孢', '孥', '宓', '宕', '屄', '屇', '岮', '岤', '岠',
+        '岵', '岯', '岨', '岬', '岟', '岣', '岭', '岢', '岪', '岧', '岝', '岥', '岶', '岰', '岦',
+        '帗', '帔', '帙', '弨', '弢', '弣', '弤', '彔', '徂', '彾', '彽', '忞', '忥', '怭', '怦',
+        '怙', '怲', '怋', '怴', '怊', '怗', '怳', '怚', '怞', '怬', '怢', '怍', '怐', '怮', '怓',
+        '怑', '怌', '怉', '怜', '戔', '戽', '抭', '抴', '拑', '抾', '抪', '抶', '拊', '抮', '抳',
+        '抯', '抻', '抩', '抰', '抸', '攽', '斨', '斻', '昉', '旼', '昄', '昒', '昈', '旻', '昃',
+        '昋', '昍', '昅', '旽', '昑', '昐', '曶', '朊', '枅', '杬', '枎', '枒', '杶', '杻', '枘',
+        '枆', '构', '杴', '枍', '枌', '杺', '枟', '枑', '枙', '枃', '杽', '极', '杸', '杹', '枔',
+        '欥', '殀', '歾', '毞', '氝', '沓', '泬', '泫', '泮', '泙', '沶', '泔', '沭', '泧', '沷',
+        '泐', '泂', '沺', '泃', '泆', '泭', '泲',
+    ],
+    // Lead 0xCD
+    [
+        '泒', '泝', '沴', '沊', '沝', '沀', '泞', '泀', '洰', '泍', '泇', '沰', '泹', '泏', '泩',
+        '泑', '炔', '炘', '炅', '炓', '炆', '炄', '炑', '炖', '炂', '炚', '炃', '牪', '狖', '狋',
+        '狘', '狉', '狜', '狒', '狔', '狚', '狌', '狑', '玤', '玡', '玭', '玦', '玢', '玠', '玬',
+        '玝', '瓝', '瓨', '甿', '畀', '甾', '疌', '疘', '皯', '盳', '盱', '盰', '盵', '矸', '矼',
+        '矹', '矻', '矺', '矷', '祂', '礿', '秅', '穸', '穻', '竻', '籵', '糽', '耵', '肏', '肮',
+        '肣', '肸', '肵', '肭', '舠', '芠', '苀', '芫', '芚', '芘', '芛', '芵', '芧', '芮', '芼',
+        '芞', '芺', '芴', '芨', '芡', '芩', '苂', '芤', '苃', '芶', '芢', '虰', '虯', '虭', '虮',
+        '豖', '迒', '迋', '迓', '迍', '迖', '迕', '迗', '邲', '邴', '邯', '邳', '邰', '阹', '阽',
+        '阼', '阺', '陃', '俍', '俅', '俓', '侲', '俉', '俋', '俁', '俔', '俜', '俙', '侻', '侳',
+        '俛', '俇', '俖', '侺', '俀', '侹', '俬', '剄', '剉', '勀', '勂', '匽', '卼', '厗', '厖',
+        '厙', '厘', '咺', '咡', '咭', '咥', '哏',
+    ],
+    // Lead 0xCE
+    [
+        '哃', '茍', '咷', '咮', '哖', '咶', '哅', '哆', '咠', '呰', '咼', '咢', '咾', '呲', '哞',
+        '咰', '垵', '垞', '垟', '垤', '垌', '垗', '垝', '垛', '垔', '垘', '垏', '垙', '垥', '垚',
+        '垕', '壴', '复', '奓', '姡', '姞', '姮', '娀', '姱', '姝', '姺', '姽', '姼', '姶', '姤',
+        '姲', '姷', '姛', '姩', '姳', '姵', '姠', '姾', '姴', '姭', '宨', '屌', '峐', '峘', '峌',
+        '峗', '峋', '峛', '峞', '峚', '峉', '峇', '峊', '峖', '峓', '峔', '峏', '峈', '峆', '峎',
+        '峟', '峸', '巹', '帡', '帢', '帣', '帠', '帤', '庰', '庤', '庢', '庛', '庣', '庥', '弇',
+        '弮', '彖', '徆', '怷', '怹', '恔', '恲', '恞', '恅', '恓', '恇', '恉', '恛', '恌', '恀',
+        '恂', '恟', '怤', '恄', '恘', '恦', '恮', '扂', '扃', '拏', '挍', '挋', '拵', '挎', '挃',
+        '拫', '拹', '挏', '挌', '拸', '拶', '挀', '挓', '挔', '拺', '挕', '拻', '拰', '敁', '敃',
+        '斪', '斿', '昶', '昡', '昲', '昵', '昜', '昦', '昢', '昳', '昫', '昺', '昝', '昴', '昹',
+        '昮', '朏', '朐', '柁', '柲', '柈', '枺',
+    ],
+    // Lead 0xCF
+    [
+        '柜', '枻', '柸', '柘', '柀', '枷', '柅', '柫', '柤', '柟', '枵', '柍', '枳', '柷', '柶',
+        '柮', '柣', '柂', '枹', '柎', '柧', '柰', '枲', '柼', '柆', '柭', '柌', '枮', '柦', '柛',
+        '柺', '柉', '柊', '柃', '柪', '柋', '欨', '殂', '殄', '殶', '毖', '毘', '毠', '氠', '氡',
+        '洨', '洴', '洭', '洟', '洼', '洿', '洒', '洊', '泚', '洳', '洄', '洙', '洺', '洚', '洑',
+        '洀', '洝', '浂', '洁', '洘', '洷', '洃', '洏', '浀', '洇', '洠', '洬', '洈', '洢', '洉',
+        '洐', '炷', '炟', '炾', '炱', '炰', '炡', '炴', '炵', '炩', '牁', '牉', '牊', '牬', '牰',
+        '牳', '牮', '狊', '狤', '狨', '狫', '狟', '狪', '狦', '狣', '玅', '珌', '珂', '珈', '珅',
+        '玹', '玶', '玵', '玴', '珫', '玿', '珇', '玾', '珃', '珆', '玸', '珋', '瓬', '瓮', '甮',
+        '畇', '畈', '疧', '疪', '癹', '盄', '眈', '眃', '眄', '眅', '眊', '盷', '盻', '盺', '矧',
+        '矨', '砆', '砑', '砒', '砅', '砐', '砏', '砎', '砉', '砃', '砓', '祊', '祌', '祋', '祅',
+        '祄', '秕', '种', '秏', '秖', '秎', '窀',
+    ],
+    // Lead 0xD0
+    [
+        '穾', '竑', '笀', '笁', '籺', '籸', '籹', '籿', '粀', '粁', '紃', '紈', '紁', '罘', '羑',
+        '羍', '羾', '耇', '耎', '耏', '耔', '耷', '胘', '胇', '胠', '胑', '胈', '胂', '胐', '胅',
+        '胣', '胙', '胜', '胊', '胕', '胉', '胏', '胗', '胦', '胍', '臿', '舡', '芔', '苙', '苾',
+        '苹', '茇', '苨', '茀', '苕', '茺', '苫', '苖', '苴', '苬', '苡', '苲', '苵', '茌', '苻',
+        '苶', '苰', '苪', '苤', '苠', '苺', '苳', '苭', '虷', '虴', '虼', '虳', '衁', '衎', '衧',
+        '衪', '衩', '觓', '訄', '訇', '赲', '迣', '迡', '迮', '迠', '郱', '邽', '邿', '郕', '郅',
+        '邾', '郇', '郋', '郈', '釔', '釓', '陔', '陏', '陑', '陓', '陊', '陎', '倞', '倅', '倇',
+        '倓', '倢', '倰', '倛', '俵', '俴', '倳', '倷', '倬', '俶', '俷', '倗', '倜', '倠', '倧',
+        '倵', '倯', '倱', '倎', '党', '冔', '冓', '凊', '凄', '凅', '凈', '凎', '剡', '剚', '剒',
+        '剞', '剟', '剕', '剢', '勍', '匎', '厞', '唦', '哢', '唗', '唒', '哧', '哳', '哤', '唚',
+        '哿', '唄', '唈', '哫', '唑', '唅', '哱',
+    ],
+    // Lead 0xD1
+    [
+        '唊', '哻', '哷', '哸', '哠', '唎', '唃', '唋', '圁', '圂', '埌', '堲', '埕', '埒', '垺',
+        '埆', '垽', '垼', '垸', '垶', '垿', '埇', '埐', '垹', '埁', '夎', '奊', '娙', '娖', '娭',
+        '娮', '娕', '娏', '娗', '娊', '娞', '娳', '孬', '宧', '宭', '宬', '尃', '屖', '屔', '峬',
+        '峿', '峮', '峱', '峷', '崀', '峹', '帩', '帨', '庨', '庮', '庪', '庬', '弳', '弰', '彧',
+        '恝', '恚', '恧', '恁', '悢', '悈', '悀', '悒', '悁', '悝', '悃', '悕', '悛', '悗', '悇',
+        '悜', '悎', '戙', '扆', '拲', '挐', '捖', '挬', '捄', '捅', '挶', '捃', '揤', '挹', '捋',
+        '捊', '挼', '挩', '捁', '挴', '捘', '捔', '捙', '挭', '捇', '挳', '捚', '捑', '挸', '捗',
+        '捀', '捈', '敊', '敆', '旆', '旃', '旄', '旂', '晊', '晟', '晇', '晑', '朒', '朓', '栟',
+        '栚', '桉', '栲', '栳', '栻', '桋', '桏', '栖', '栱', '栜', '栵', '栫', '栭', '栯', '桎',
+        '桄', '栴', '栝', '栒', '栔', '栦', '栨', '栮', '桍', '栺', '栥', '栠', '欬', '欯', '欭',
+        '欱', '欴', '歭', '肂', '殈', '毦', '毤',
+    ],
+    // Lead 0xD2
+    [
+        '毨', '毣', '毢', '毧', '氥', '浺', '浣', '浤', '浶', '洍', '浡', '涒', '浘', '浢', '浭',
+        '浯', '涑', '涍', '淯', '浿', '涆', '浞', '浧', '浠', '涗', '浰', '浼', '浟', '涂', '涘',
+        '洯', '浨', '涋', '浾', '涀', '涄', '洖', '涃', '浻', '浽', '浵', '涐', '烜', '烓', '烑',
+        '烝', '烋', '缹', '烢', '烗', '烒', '烞', '烠', '烔', '烍', '烅', '烆', '烇', '烚', '烎',
+        '烡', '牂', '牸', '牷', '牶', '猀', '狺', '狴', '狾', '狶', '狳', '狻', '猁', '珓', '珙',
+        '珥', '珖', '玼', '珧', '珣', '珩', '珜', '珒', '珛', '珔', '珝', '珚', '珗', '珘', '珨',
+        '瓞', '瓟', '瓴', '瓵', '甡', '畛', '畟', '疰', '痁', '疻', '痄', '痀', '疿', '疶', '疺',
+        '皊', '盉', '眝', '眛', '眐', '眓', '眒', '眣', '眑', '眕', '眙', '眚', '眢', '眧', '砣',
+        '砬', '砢', '砵', '砯', '砨', '砮', '砫', '砡', '砩', '砳', '砪', '砱', '祔', '祛', '祏',
+        '祜', '祓', '祒', '祑', '秫', '秬', '秠', '秮', '秭', '秪', '秜', '秞', '秝', '窆', '窉',
+        '窅', '窋', '窌', '窊', '窇', '竘', '笐',
+    ],
+    // Lead 0xD3
+    [
+        '笄', '笓', '笅', '笏', '笈', '笊', '笎', '笉', '笒', '粄', '粑', '粊', '粌', '粈', '粍',
+        '粅', '紞', '紝', '紑', '紎', '紘', '紖', '紓', '紟', '紒', '紏', '紌', '罜', '罡', '罞',
+        '罠', '罝', '罛', '羖', '羒', '翃', '翂', '翀', '耖', '耾', '耹', '胺', '胲', '胹', '胵',
+        '脁', '胻', '脀', '舁', '舯', '舥', '茳', '茭', '荄', '茙', '荑', '茥', '荖', '茿', '荁',
+        '茦', '茜', '茢', '荂', '荎', '茛', '茪', '茈', '茼', '荍', '茖', '茤', '茠', '茷', '茯',
+        '茩', '荇', '荅', '荌', '荓', '茞', '茬', '荋', '茧', '荈', '虓', '虒', '蚢', '蚨', '蚖',
+        '蚍', '蚑', '蚞', '蚇', '蚗', '蚆', '蚋', '蚚', '蚅', '蚥', '蚙', '蚡', '蚧', '蚕', '蚘',
+        '蚎', '蚝', '蚐', '蚔', '衃', '衄', '衭', '衵', '衶', '衲', '袀', '衱', '衿', '衯', '袃',
+        '衾', '衴', '衼', '訒', '豇', '豗', '豻', '貤', '貣', '赶', '赸', '趵', '趷', '趶', '軑',
+        '軓', '迾', '迵', '适', '迿', '迻', '逄', '迼', '迶', '郖', '郠', '郙', '郚', '郣', '郟',
+        '郥', '郘', '郛', '郗', '郜', '郤', '酐',
+    ],
+    // Lead 0xD4
+    [
+        '酎', '酏', '釕', '釢', '釚', '陜', '陟', '隼', '飣', '髟', '鬯', '乿', '偰', '偪', '偡',
+        '偞', '偠', '偓', '偋', '偝', '偲', '偈', '偍', '偁', '偛', '偊', '偢', '倕', '偅', '偟',
+        '偩', '偫', '偣', '偤', '偆', '偀', '偮', '偳', '偗', '偑', '凐', '剫', '剭', '剬', '剮',
+        '勖', '勓', '匭', '厜', '啵', '啶', '唼', '啍', '啐', '唴', '唪', '啑', '啢', '唶', '唵',
+        '唰', '啒', '啅', '唌', '唲', '啥', '啎', '唹', '啈', '唭', '唻', '啀', '啋', '圊', '圇',
+        '埻', '堔', '埢', '埶', '埜', '埴', '堀', '埭', '埽', '堈', '埸', '堋', '埳', '埏', '堇',
+        '埮', '埣', '埲', '埥', '埬', '埡', '堎', '埼', '堐', '埧', '堁', '堌', '埱', '埩', '埰',
+        '堍', '堄', '奜', '婠', '婘', '婕', '婧', '婞', '娸', '娵', '婭', '婐', '婟', '婥', '婬',
+        '婓', '婤', '婗', '婃', '婝', '婒', '婄', '婛', '婈', '媎', '娾', '婍', '娹', '婌', '婰',
+        '婩', '婇', '婑', '婖', '婂', '婜', '孲', '孮', '寁', '寀', '屙', '崞', '崋', '崝', '崚',
+        '崠', '崌', '崨', '崍', '崦', '崥', '崏',
+    ],
+    // Lead 0xD5
+    [
+        '崰', '崒', '崣', '崟', '崮', '帾', '帴', '庱', '庴', '庹', '庲', '庳', '弶', '弸', '徛',
+        '徖', '徟', '悊', '悐', '悆', '悾', '悰', '悺', '惓', '惔', '惏', '惤', '惙', '惝', '惈',
+        '悱', '惛', '悷', '惊', '悿', '惃', '惍', '惀', '挲', '捥', '掊', '掂', '捽', '掽', '掞',
+        '掭', '掝', '掗', '掫', '掎', '捯', '掇', '掐', '据', '掯', '捵', '掜', '捭', '掮', '捼',
+        '掤', '挻', '掟', '捸', '掅', '掁', '掑', '掍', '捰', '敓', '旍', '晥', '晡', '晛', '晙',
+        '晜', '晢', '朘', '桹', '梇', '梐', '梜', '桭', '桮', '梮', '梫', '楖', '桯', '梣', '梬',
+        '梩', '桵', '桴', '梲', '梏', '桷', '梒', '桼', '桫', '桲', '梪', '梀', '桱', '桾', '梛',
+        '梖', '梋', '梠', '梉', '梤', '桸', '桻', '梑', '梌', '梊', '桽', '欶', '欳', '欷', '欸',
+        '殑', '殏', '殍', '殎', '殌', '氪', '淀', '涫', '涴', '涳', '湴', '涬', '淩', '淢', '涷',
+        '淶', '淔', '渀', '淈', '淠', '淟', '淖', '涾', '淥', '淜', '淝', '淛', '淴', '淊', '涽',
+        '淭', '淰', '涺', '淕', '淂', '淏', '淉',
+    ],
+    // Lead 0xD6
+    [
+        '淐', '淲', '淓', '淽', '淗', '淍', '淣', '涻', '烺', '焍', '烷', '焗', '烴', '焌', '烰',
+        '焄', '烳', '焐', '烼', '烿', '焆', '焓', '焀', '烸', '烶', '焋', '焂', '焎', '牾', '牻',
+        '牼', '牿', '猝', '猗', '猇', '猑', '猘', '猊', '猈', '狿', '猏', '猞', '玈', '珶', '珸',
+        '珵', '琄', '琁', '珽', '琇', '琀', '珺', '珼', '珿', '琌', '琋', '珴', '琈', '畤', '畣',
+        '痎', '痒', '痏', '痋', '痌', '痑', '痐', '皏', '皉', '盓', '眹', '眯', '眭', '眱', '眲',
+        '眴', '眳', '眽', '眥', '眻', '眵', '硈', '硒', '硉', '硍', '硊', '硌', '砦', '硅', '硐',
+        '祤', '祧', '祩', '祪', '祣', '祫', '祡', '离', '秺', '秸', '秶', '秷', '窏', '窔', '窐',
+        '笵', '筇', '笴', '笥', '笰', '笢', '笤', '笳', '笘', '笪', '笝', '笱', '笫', '笭', '笯',
+        '笲', '笸', '笚', '笣', '粔', '粘', '粖', '粣', '紵', '紽', '紸', '紶', '紺', '絅', '紬',
+        '紩', '絁', '絇', '紾', '紿', '絊', '紻', '紨', '罣', '羕', '羜', '羝', '羛', '翊', '翋',
+        '翍', '翐', '翑', '翇', '翏', '翉', '耟',
+    ],
+    // Lead 0xD7
+    [
+        '耞', '耛', '聇', '聃', '聈', '脘', '脥', '脙', '脛', '脭', '脟', '脬', '脞', '脡', '脕',
+        '脧', '脝', '脢', '舑', '舸', '舳', '舺', '舴', '舲', '艴', '莐', '莣', '莨', '莍', '荺',
+        '荳', '莤', '荴', '莏', '莁', '莕', '莙', '荵', '莔', '莩', '荽', '莃', '莌', '莝', '莛',
+        '莪', '莋', '荾', '莥', '莯', '莈', '莗', '莰', '荿', '莦', '莇', '莮', '荶', '莚', '虙',
+        '虖', '蚿', '蚷', '蛂', '蛁', '蛅', '蚺', '蚰', '蛈', '蚹', '蚳', '蚸', '蛌', '蚴', '蚻',
+        '蚼', '蛃', '蚽', '蚾', '衒', '袉', '袕', '袨', '袢', '袪', '袚', '袑', '袡', '袟', '袘',
+        '袧', '袙', '袛', '袗', '袤', '袬', '袌', '袓', '袎', '覂', '觖', '觙', '觕', '訰', '訧',
+        '訬', '訞', '谹', '谻', '豜', '豝', '豽', '貥', '赽', '赻', '赹', '趼', '跂', '趹', '趿',
+        '跁', '軘', '軞', '軝', '軜', '軗', '軠', '軡', '逤', '逋', '逑', '逜', '逌', '逡', '郯',
+        '郪', '郰', '郴', '郲', '郳', '郔', '郫', '郬', '郩', '酖', '酘', '酚', '酓', '酕', '釬',
+        '釴', '釱', '釳', '釸', '釤', '釹', '釪',
+    ],
+    // Lead 0xD8
+    [
+        '釫', '釷', '釨', '釮', '镺', '閆', '閈', '陼', '陭', '陫', '陱', '陯', '隿', '靪', '頄',
+        '飥', '馗', '傛', '傕', '傔', '傞', '傋', '傣', '傃', '傌', '傎', '傝', '偨', '傜', '傒',
+        '傂', '傇', '兟', '凔', '匒', '匑', '厤', '厧', '喑', '喨', '喥', '喭', '啷', '噅', '喢',
+        '喓', '喈', '喏', '喵', '喁', '喣', '喒', '喤', '啽', '喌', '喦', '啿', '喕', '喡', '喎',
+        '圌', '堩', '堷', '堙', '堞', '堧', '堣', '堨', '埵', '塈', '堥', '堜', '堛', '堳', '堿',
+        '堶', '堮', '堹', '堸', '堭', '堬', '堻', '奡', '媯', '媔', '媟', '婺', '媢', '媞', '婸',
+        '媦', '婼', '媥', '媬', '媕', '媮', '娷', '媄', '媊', '媗', '媃', '媋', '媩', '婻', '婽',
+        '媌', '媜', '媏', '媓', '媝', '寪', '寍', '寋', '寔', '寑', '寊', '寎', '尌', '尰', '崷',
+        '嵃', '嵫', '嵁', '嵋', '崿', '崵', '嵑', '嵎', '嵕', '崳', '崺', '嵒', '崽', '崱', '嵙',
+        '嵂', '崹', '嵉', '崸', '崼', '崲', '崶', '嵀', '嵅', '幄', '幁', '彘', '徦', '徥', '徫',
+        '惉', '悹', '惌', '惢', '惎', '惄', '愔',
+    ],
+    // Lead 0xD9
+    [
+        '惲', '愊', '愖', '愅', '惵', '愓', '惸', '惼', '惾', '惁', '愃', '愘', '愝', '愐', '惿',
+        '愄', '愋', '扊', '掔', '掱', '掰', '揎', '揥', '揨', '揯', '揃', '撝', '揳', '揊', '揠',
+        '揶', '揕', '揲', '揵', '摡', '揟', '掾', '揝', '揜', '揄', '揘', '揓', '揂', '揇', '揌',
+        '揋', '揈', '揰', '揗', '揙', '攲', '敧', '敪', '敤', '敜', '敨', '敥', '斌', '斝', '斞',
+        '斮', '旐', '旒', '晼', '晬', '晻', '暀', '晱', '晹', '晪', '晲', '朁', '椌', '棓', '椄',
+        '棜', '椪', '棬', '棪', '棱', '椏', '棖', '棷', '棫', '棤', '棶', '椓', '椐', '棳', '棡',
+        '椇', '棌', '椈', '楰', '梴', '椑', '棯', '棆', '椔', '棸', '棐', '棽', '棼', '棨', '椋',
+        '椊', '椗', '棎', '棈', '棝', '棞', '棦', '棴', '棑', '椆', '棔', '棩', '椕', '椥', '棇',
+        '欹', '欻', '欿', '欼', '殔', '殗', '殙', '殕', '殽', '毰', '毲', '毳', '氰', '淼', '湆',
+        '湇', '渟', '湉', '溈', '渼', '渽', '湅', '湢', '渫', '渿', '湁', '湝', '湳', '渜', '渳',
+        '湋', '湀', '湑', '渻', '渃', '渮', '湞',
+    ],
+    // Lead 0xDA
+    [
+        '湨', '湜', '湡', '渱', '渨', '湠', '湱', '湫', '渹', '渢', '渰', '湓', '湥', '渧', '湸',
+        '湤', '湷', '湕', '湹', '湒', '湦', '渵', '渶', '湚', '焠', '焞', '焯', '烻', '焮', '焱',
+        '焣', '焥', '焢', '焲', '焟', '焨', '焺', '焛', '牋', '牚', '犈', '犉', '犆', '犅', '犋',
+        '猒', '猋', '猰', '猢', '猱', '猳', '猧', '猲', '猭', '猦', '猣', '猵', '猌', '琮', '琬',
+        '琰', '琫', '琖', '琚', '琡', '琭', '琱', '琤', '琣', '琝', '琩', '琠', '琲', '瓻', '甯',
+        '畯', '畬', '痧', '痚', '痡', '痦', '痝', '痟', '痤', '痗', '皕', '皒', '盚', '睆', '睇',
+        '睄', '睍', '睅', '睊', '睎', '睋', '睌', '矞', '矬', '硠', '硤', '硥', '硜', '硭', '硱',
+        '硪', '确', '硰', '硩', '硨', '硞', '硢', '祴', '祳', '祲', '祰', '稂', '稊', '稃', '稌',
+        '稄', '窙', '竦', '竤', '筊', '笻', '筄', '筈', '筌', '筎', '筀', '筘', '筅', '粢', '粞',
+        '粨', '粡', '絘', '絯', '絣', '絓', '絖', '絧', '絪', '絏', '絭', '絜', '絫', '絒', '絔',
+        '絩', '絑', '絟', '絎', '缾', '缿', '罥',
+    ],
+    // Lead 0xDB
+    [
+        '罦', '羢', '羠', '羡', '翗', '聑', '聏', '聐', '胾', '胔', '腃', '腊', '腒', '腏', '腇',
+        '脽', '腍', '脺', '臦', '臮', '臷', '臸', '臹', '舄', '舼', '舽', '舿', '艵', '茻', '菏',
+        '菹', '萣', '菀', '菨', '萒', '菧', '菤', '菼', '菶', '萐', '菆', '菈', '菫', '菣', '莿',
+        '萁', '菝', '菥', '菘', '菿', '菡', '菋', '菎', '菖', '菵', '菉', '萉', '萏', '菞', '萑',
+        '萆', '菂', '菳', '菕', '菺', '菇', '菑', '菪', '萓', '菃', '菬', '菮', '菄', '菻', '菗',
+        '菢', '萛', '菛', '菾', '蛘', '蛢', '蛦', '蛓', '蛣', '蛚', '蛪', '蛝', '蛫', '蛜', '蛬',
+        '蛩', '蛗', '蛨', '蛑', '衈', '衖', '衕', '袺', '裗', '袹', '袸', '裀', '袾', '袶', '袼',
+        '袷', '袽', '袲', '褁', '裉', '覕', '覘', '覗', '觝', '觚', '觛', '詎', '詍', '訹', '詙',
+        '詀', '詗', '詘', '詄', '詅', '詒', '詈', '詑', '詊', '詌', '詏', '豟', '貁', '貀', '貺',
+        '貾', '貰', '貹', '貵', '趄', '趀', '趉', '跘', '跓', '跍', '跇', '跖', '跜', '跏', '跕',
+        '跙', '跈', '跗', '跅', '軯', '軷', '軺',
+    ],
+    // Lead 0xDC
+    [
+        '軹', '軦', '軮', '軥', '軵', '軧', '軨', '軶', '軫', '軱', '軬', '軴', '軩', '逭', '逴',
+        '逯', '鄆', '鄬', '鄄', '郿', '郼', '鄈', '郹', '郻', '鄁', '鄀', '鄇', '鄅', '鄃', '酡',
+        '酤', '酟', '酢', '酠', '鈁', '鈊', '鈥', '鈃', '鈚', '鈦', '鈏', '鈌', '鈀', '鈒', '釿',
+        '釽', '鈆', '鈄', '鈧', '鈂', '鈜', '鈤', '鈙', '鈗', '鈅', '鈖', '镻', '閍', '閌', '閐',
+        '隇', '陾', '隈', '隉', '隃', '隀', '雂', '雈', '雃', '雱', '雰', '靬', '靰', '靮', '頇',
+        '颩', '飫', '鳦', '黹', '亃', '亄', '亶', '傽', '傿', '僆', '傮', '僄', '僊', '傴', '僈',
+        '僂', '傰', '僁', '傺', '傱', '僋', '僉', '傶', '傸', '凗', '剺', '剸', '剻', '剼', '嗃',
+        '嗛', '嗌', '嗐', '嗋', '嗊', '嗝', '嗀', '嗔', '嗄', '嗩', '喿', '嗒', '喍', '嗏', '嗕',
+        '嗢', '嗖', '嗈', '嗲', '嗍', '嗙', '嗂', '圔', '塓', '塨', '塤', '塏', '塍', '塉', '塯',
+        '塕', '塎', '塝', '塙', '塥', '塛', '堽', '塣', '塱', '壼', '嫇', '嫄', '嫋', '媺', '媸',
+        '媱', '媵', '媰', '媿', '嫈', '媻', '嫆',
+    ],
+    // Lead 0xDD
+    [
+        '媷', '嫀', '嫊', '媴', '媶', '嫍', '媹', '媐', '寖', '寘', '寙', '尟', '尳', '嵱', '嵣',
+        '嵊', '嵥', '嵲', '嵬', '嵞', '嵨', '嵧', '嵢', '巰', '幏', '幎', '幊', '幍', '幋', '廅',
+        '廌', '廆', '廋', '廇', '彀', '徯', '徭', '惷', '慉', '慊', '愫', '慅', '愶', '愲', '愮',
+        '慆', '愯', '慏', '愩', '慀', '戠', '酨', '戣', '戥', '戤', '揅', '揱', '揫', '搐', '搒',
+        '搉', '搠', '搤', '搳', '摃', '搟', '搕', '搘', '搹', '搷', '搢', '搣', '搌', '搦', '搰',
+        '搨', '摁', '搵', '搯', '搊', '搚', '摀', '搥', '搧', '搋', '揧', '搛', '搮', '搡', '搎',
+        '敯', '斒', '旓', '暆', '暌', '暕', '暐', '暋', '暊', '暙', '暔', '晸', '朠', '楦', '楟',
+        '椸', '楎', '楢', '楱', '椿', '楅', '楪', '椹', '楂', '楗', '楙', '楺', '楈', '楉', '椵',
+        '楬', '椳', '椽', '楥', '棰', '楸', '椴', '楩', '楀', '楯', '楄', '楶', '楘', '楁', '楴',
+        '楌', '椻', '楋', '椷', '楜', '楏', '楑', '椲', '楒', '椯', '楻', '椼', '歆', '歅', '歃',
+        '歂', '歈', '歁', '殛', '嗀', '毻', '毼',
+    ],
+    // Lead 0xDE
+    [
+        '毹', '毷', '毸', '溛', '滖', '滈', '溏', '滀', '溟', '溓', '溔', '溠', '溱', '溹', '滆',
+        '滒', '溽', '滁', '溞', '滉', '溷', '溰', '滍', '溦', '滏', '溲', '溾', '滃', '滜', '滘',
+        '溙', '溒', '溎', '溍', '溤', '溡', '溿', '溳', '滐', '滊', '溗', '溮', '溣', '煇', '煔',
+        '煒', '煣', '煠', '煁', '煝', '煢', '煲', '煸', '煪', '煡', '煂', '煘', '煃', '煋', '煰',
+        '煟', '煐', '煓', '煄', '煍', '煚', '牏', '犍', '犌', '犑', '犐', '犎', '猼', '獂', '猻',
+        '猺', '獀', '獊', '獉', '瑄', '瑊', '瑋', '瑒', '瑑', '瑗', '瑀', '瑏', '瑐', '瑎', '瑂',
+        '瑆', '瑍', '瑔', '瓡', '瓿', '瓾', '瓽', '甝', '畹', '畷', '榃', '痯', '瘏', '瘃', '痷',
+        '痾', '痼', '痹', '痸', '瘐', '痻', '痶', '痭', '痵', '痽', '皙', '皵', '盝', '睕', '睟',
+        '睠', '睒', '睖', '睚', '睩', '睧', '睔', '睙', '睭', '矠', '碇', '碚', '碔', '碏', '碄',
+        '碕', '碅', '碆', '碡', '碃', '硹', '碙', '碀', '碖', '硻', '祼', '禂', '祽', '祹', '稑',
+        '稘', '稙', '稒', '稗', '稕', '稢', '稓',
+    ],
+    // Lead 0xDF
+    [
+        '稛', '稐', '窣', '窢', '窞', '竫', '筦', '筤', '筭', '筴', '筩', '筲', '筥', '筳', '筱',
+        '筰', '筡', '筸', '筶', '筣', '粲', '粴', '粯', '綈', '綆', '綀', '綍', '絿', '綅', '絺',
+        '綎', '絻', '綃', '絼', '綌', '綔', '綄', '絽', '綒', '罭', '罫', '罧', '罨', '罬', '羦',
+        '羥', '羧', '翛', '翜', '耡', '腤', '腠', '腷', '腜', '腩', '腛', '腢', '腲', '朡', '腞',
+        '腶', '腧', '腯', '腄', '腡', '舝', '艉', '艄', '艀', '艂', '艅', '蓱', '萿', '葖', '葶',
+        '葹', '蒏', '蒍', '葥', '葑', '葀', '蒆', '葧', '萰', '葍', '葽', '葚', '葙', '葴', '葳',
+        '葝', '蔇', '葞', '萷', '萺', '萴', '葺', '葃', '葸', '萲', '葅', '萩', '菙', '葋', '萯',
+        '葂', '萭', '葟', '葰', '萹', '葎', '葌', '葒', '葯', '蓅', '蒎', '萻', '葇', '萶', '萳',
+        '葨', '葾', '葄', '萫', '葠', '葔', '葮', '葐', '蜋', '蜄', '蛷', '蜌', '蛺', '蛖', '蛵',
+        '蝍', '蛸', '蜎', '蜉', '蜁', '蛶', '蜍', '蜅', '裖', '裋', '裍', '裎', '裞', '裛', '裚',
+        '裌', '裐', '覅', '覛', '觟', '觥', '觤',
+    ],
+    // Lead 0xE0
+    [
+        '觡', '觠', '觢', '觜', '触', '詶', '誆', '詿', '詡', '訿', '詷', '誂', '誄', '詵', '誃',
+        '誁', '詴', '詺', '谼', '豋', '豊', '豥', '豤', '豦', '貆', '貄', '貅', '賌', '赨', '赩',
+        '趑', '趌', '趎', '趏', '趍', '趓', '趔', '趐', '趒', '跰', '跠', '跬', '跱', '跮', '跐',
+        '跩', '跣', '跢', '跧', '跲', '跫', '跴', '輆', '軿', '輁', '輀', '輅', '輇', '輈', '輂',
+        '輋', '遒', '逿', '遄', '遉', '逽', '鄐', '鄍', '鄏', '鄑', '鄖', '鄔', '鄋', '鄎', '酮',
+        '酯', '鉈', '鉒', '鈰', '鈺', '鉦', '鈳', '鉥', '鉞', '銃', '鈮', '鉊', '鉆', '鉭', '鉬',
+        '鉏', '鉠', '鉧', '鉯', '鈶', '鉡', '鉰', '鈱', '鉔', '鉣', '鉐', '鉲', '鉎', '鉓', '鉌',
+        '鉖', '鈲', '閟', '閜', '閞', '閛', '隒', '隓', '隑', '隗', '雎', '雺', '雽', '雸', '雵',
+        '靳', '靷', '靸', '靲', '頏', '頍', '頎', '颬', '飶', '飹', '馯', '馲', '馰', '馵', '骭',
+        '骫', '魛', '鳪', '鳭', '鳧', '麀', '黽', '僦', '僔', '僗', '僨', '僳', '僛', '僪', '僝',
+        '僤', '僓', '僬', '僰', '僯', '僣', '僠',
+    ],
+    // Lead 0xE1
+    [
+        '凘', '劀', '劁', '勩', '勫', '匰', '厬', '嘧', '嘕', '嘌', '嘒', '嗼', '嘏', '嘜', '嘁',
+        '嘓', '嘂', '嗺', '嘝', '嘄', '嗿', '嗹', '墉', '塼', '墐', '墘', '墆', '墁', '塿', '塴',
+        '墋', '塺', '墇', '墑', '墎', '塶', '墂', '墈', '塻', '墔', '墏', '壾', '奫', '嫜', '嫮',
+        '嫥', '嫕', '嫪', '嫚', '嫭', '嫫', '嫳', '嫢', '嫠', '嫛', '嫬', '嫞', '嫝', '嫙', '嫨',
+        '嫟', '孷', '寠', '寣', '屣', '嶂', '嶀', '嵽', '嶆', '嵺', '嶁', '嵷', '嶊', '嶉', '嶈',
+        '嵾', '嵼', '嶍', '嵹', '嵿', '幘', '幙', '幓', '廘', '廑', '廗', '廎', '廜', '廕', '廙',
+        '廒', '廔', '彄', '彃', '彯', '徶', '愬', '愨', '慁', '慞', '慱', '慳', '慒', '慓', '慲',
+        '慬', '憀', '慴', '慔', '慺', '慛', '慥', '愻', '慪', '慡', '慖', '戩', '戧', '戫', '搫',
+        '摍', '摛', '摝', '摴', '摶', '摲', '摳', '摽', '摵', '摦', '撦', '摎', '撂', '摞', '摜',
+        '摋', '摓', '摠', '摐', '摿', '搿', '摬', '摫', '摙', '摥', '摷', '敳', '斠', '暡', '暠',
+        '暟', '朅', '朄', '朢', '榱', '榶', '槉',
+    ],
+    // Lead 0xE2
+    [
+        '榠', '槎', '榖', '榰', '榬', '榼', '榑', '榙', '榎', '榧', '榍', '榩', '榾', '榯', '榿',
+        '槄', '榽', '榤', '槔', '榹', '槊', '榚', '槏', '榳', '榓', '榪', '榡', '榞', '槙', '榗',
+        '榐', '槂', '榵', '榥', '槆', '歊', '歍', '歋', '殞', '殟', '殠', '毃', '毄', '毾', '滎',
+        '滵', '滱', '漃', '漥', '滸', '漷', '滻', '漮', '漉', '潎', '漙', '漚', '漧', '漘', '漻',
+        '漒', '滭', '漊', '漶', '潳', '滹', '滮', '漭', '潀', '漰', '漼', '漵', '滫', '漇', '漎',
+        '潃', '漅', '滽', '滶', '漹', '漜', '滼', '漺', '漟', '漍', '漞', '漈', '漡', '熇', '熐',
+        '熉', '熀', '熅', '熂', '熏', '煻', '熆', '熁', '熗', '牄', '牓', '犗', '犕', '犓', '獃',
+        '獍', '獑', '獌', '瑢', '瑳', '瑱', '瑵', '瑲', '瑧', '瑮', '甀', '甂', '甃', '畽', '疐',
+        '瘖', '瘈', '瘌', '瘕', '瘑', '瘊', '瘔', '皸', '瞁', '睼', '瞅', '瞂', '睮', '瞀', '睯',
+        '睾', '瞃', '碲', '碪', '碴', '碭', '碨', '硾', '碫', '碞', '碥', '碠', '碬', '碢', '碤',
+        '禘', '禊', '禋', '禖', '禕', '禔', '禓',
+    ],
+    // Lead 0xE3
+    [
+        '禗', '禈', '禒', '禐', '稫', '穊', '稰', '稯', '稨', '稦', '窨', '窫', '窬', '竮', '箈',
+        '箜', '箊', '箑', '箐', '箖', '箍', '箌', '箛', '箎', '箅', '箘', '劄', '箙', '箤', '箂',
+        '粻', '粿', '粼', '粺', '綧', '綷', '緂', '綣', '綪', '緁', '緀', '緅', '綝', '緎', '緄',
+        '緆', '緋', '緌', '綯', '綹', '綖', '綼', '綟', '綦', '綮', '綩', '綡', '緉', '罳', '翢',
+        '翣', '翥', '翞', '耤', '聝', '聜', '膉', '膆', '膃', '膇', '膍', '膌', '膋', '舕', '蒗',
+        '蒤', '蒡', '蒟', '蒺', '蓎', '蓂', '蒬', '蒮', '蒫', '蒹', '蒴', '蓁', '蓍', '蒪', '蒚',
+        '蒱', '蓐', '蒝', '蒧', '蒻', '蒢', '蒔', '蓇', '蓌', '蒛', '蒩', '蒯', '蒨', '蓖', '蒘',
+        '蒶', '蓏', '蒠', '蓗', '蓔', '蓒', '蓛', '蒰', '蒑', '虡', '蜳', '蜣', '蜨', '蝫', '蝀',
+        '蜮', '蜞', '蜡', '蜙', '蜛', '蝃', '蜬', '蝁', '蜾', '蝆', '蜠', '蜲', '蜪', '蜭', '蜼',
+        '蜒', '蜺', '蜱', '蜵', '蝂', '蜦', '蜧', '蜸', '蜤', '蜚', '蜰', '蜑', '裷', '裧', '裱',
+        '裲', '裺', '裾', '裮', '裼', '裶', '裻',
+    ],
+    // Lead 0xE4
+    [
+        '裰', '裬', '裫', '覝', '覡', '覟', '覞', '觩', '觫', '觨', '誫', '誙', '誋', '誒', '誏',
+        '誖', '谽', '豨', '豩', '賕', '賏', '賗', '趖', '踉', '踂', '跿', '踍', '跽', '踊', '踃',
+        '踇', '踆', '踅', '跾', '踀', '踄', '輐', '輑', '輎', '輍', '鄣', '鄜', '鄠', '鄢', '鄟',
+        '鄝', '鄚', '鄤', '鄡', '鄛', '酺', '酲', '酹', '酳', '銥', '銤', '鉶', '銛', '鉺', '銠',
+        '銔', '銪', '銍', '銦', '銚', '銫', '鉹', '銗', '鉿', '銣', '鋮', '銎', '銂', '銕', '銢',
+        '鉽', '銈', '銡', '銊', '銆', '銌', '銙', '銧', '鉾', '銇', '銩', '銝', '銋', '鈭', '隞',
+        '隡', '雿', '靘', '靽', '靺', '靾', '鞃', '鞀', '鞂', '靻', '鞄', '鞁', '靿', '韎', '韍',
+        '頖', '颭', '颮', '餂', '餀', '餇', '馝', '馜', '駃', '馹', '馻', '馺', '駂', '馽', '駇',
+        '骱', '髣', '髧', '鬾', '鬿', '魠', '魡', '魟', '鳱', '鳲', '鳵', '麧', '僿', '儃', '儰',
+        '僸', '儆', '儇', '僶', '僾', '儋', '儌', '僽', '儊', '劋', '劌', '勱', '勯', '噈', '噂',
+        '噌', '嘵', '噁', '噊', '噉', '噆', '噘',
+    ],
+    // Lead 0xE5
+    [
+        '噚', '噀', '嘳', '嘽', '嘬', '嘾', '嘸', '嘪', '嘺', '圚', '墫', '墝', '墱', '墠', '墣',
+        '墯', '墬', '墥', '墡', '壿', '嫿', '嫴', '嫽', '嫷', '嫶', '嬃', '嫸', '嬂', '嫹', '嬁',
+        '嬇', '嬅', '嬏', '屧', '嶙', '嶗', '嶟', '嶒', '嶢', '嶓', '嶕', '嶠', '嶜', '嶡', '嶚',
+        '嶞', '幩', '幝', '幠', '幜', '緳', '廛', '廞', '廡', '彉', '徲', '憋', '憃', '慹', '憱',
+        '憰', '憢', '憉', '憛', '憓', '憯', '憭', '憟', '憒', '憪', '憡', '憍', '慦', '憳', '戭',
+        '摮', '摰', '撖', '撠', '撅', '撗', '撜', '撏', '撋', '撊', '撌', '撣', '撟', '摨', '撱',
+        '撘', '敶', '敺', '敹', '敻', '斲', '斳', '暵', '暰', '暩', '暲', '暷', '暪', '暯', '樀',
+        '樆', '樗', '槥', '槸', '樕', '槱', '槤', '樠', '槿', '槬', '槢', '樛', '樝', '槾', '樧',
+        '槲', '槮', '樔', '槷', '槧', '橀', '樈', '槦', '槻', '樍', '槼', '槫', '樉', '樄', '樘',
+        '樥', '樏', '槶', '樦', '樇', '槴', '樖', '歑', '殥', '殣', '殢', '殦', '氁', '氀', '毿',
+        '氂', '潁', '漦', '潾', '澇', '濆', '澒',
+    ],
+    // Lead 0xE6
+    [
+        '澍', '澉', '澌', '潢', '潏', '澅', '潚', '澖', '潶', '潬', '澂', '潕', '潲', '潒', '潐',
+        '潗', '澔', '澓', '潝', '漀', '潡', '潫', '潽', '潧', '澐', '潓', '澋', '潩', '潿', '澕',
+        '潣', '潷', '潪', '潻', '熲', '熯', '熛', '熰', '熠', '熚', '熩', '熵', '熝', '熥', '熞',
+        '熤', '熡', '熪', '熜', '熧', '熳', '犘', '犚', '獘', '獒', '獞', '獟', '獠', '獝', '獛',
+        '獡', '獚', '獙', '獢', '璇', '璉', '璊', '璆', '璁', '瑽', '璅', '璈', '瑼', '瑹', '甈',
+        '甇', '畾', '瘥', '瘞', '瘙', '瘝', '瘜', '瘣', '瘚', '瘨', '瘛', '皜', '皝', '皞', '皛',
+        '瞍', '瞏', '瞉', '瞈', '磍', '碻', '磏', '磌', '磑', '磎', '磔', '磈', '磃', '磄', '磉',
+        '禚', '禡', '禠', '禜', '禢', '禛', '歶', '稹', '窲', '窴', '窳', '箷', '篋', '箾', '箬',
+        '篎', '箯', '箹', '篊', '箵', '糅', '糈', '糌', '糋', '緷', '緛', '緪', '緧', '緗', '緡',
+        '縃', '緺', '緦', '緶', '緱', '緰', '緮', '緟', '罶', '羬', '羰', '羭', '翭', '翫', '翪',
+        '翬', '翦', '翨', '聤', '聧', '膣', '膟',
+    ],
+    // Lead 0xE7
+    [
+        '膞', '膕', '膢', '膙', '膗', '舖', '艏', '艓', '艒', '艐', '艎', '艑', '蔤', '蔻', '蔏',
+        '蔀', '蔩', '蔎', '蔉', '蔍', '蔟', '蔊', '蔧', '蔜', '蓻', '蔫', '蓺', '蔈', '蔌', '蓴',
+        '蔪', '蓲', '蔕', '蓷', '蓫', '蓳', '蓼', '蔒', '蓪', '蓩', '蔖', '蓾', '蔨', '蔝', '蔮',
+        '蔂', '蓽', '蔞', '蓶', '蔱', '蔦', '蓧', '蓨', '蓰', '蓯', '蓹', '蔘', '蔠', '蔰', '蔋',
+        '蔙', '蔯', '虢', '蝖', '蝣', '蝤', '蝷', '蟡', '蝳', '蝘', '蝔', '蝛', '蝒', '蝡', '蝚',
+        '蝑', '蝞', '蝭', '蝪', '蝐', '蝎', '蝟', '蝝', '蝯', '蝬', '蝺', '蝮', '蝜', '蝥', '蝏',
+        '蝻', '蝵', '蝢', '蝧', '蝩', '衚', '褅', '褌', '褔', '褋', '褗', '褘', '褙', '褆', '褖',
+        '褑', '褎', '褉', '覢', '覤', '覣', '觭', '觰', '觬', '諏', '諆', '誸', '諓', '諑', '諔',
+        '諕', '誻', '諗', '誾', '諀', '諅', '諘', '諃', '誺', '誽', '諙', '谾', '豍', '貏', '賥',
+        '賟', '賙', '賨', '賚', '賝', '賧', '趠', '趜', '趡', '趛', '踠', '踣', '踥', '踤', '踮',
+        '踕', '踛', '踖', '踑', '踙', '踦', '踧',
+    ],
+    // Lead 0xE8
+    [
+        '踔', '踒', '踘', '踓', '踜', '踗', '踚', '輬', '輤', '輘', '輚', '輠', '輣', '輖', '輗',
+        '遳', '遰', '遯', '遧', '遫', '鄯', '鄫', '鄩', '鄪', '鄲', '鄦', '鄮', '醅', '醆', '醊',
+        '醁', '醂', '醄', '醀', '鋐', '鋃', '鋄', '鋀', '鋙', '銶', '鋏', '鋱', '鋟', '鋘', '鋩',
+        '鋗', '鋝', '鋌', '鋯', '鋂', '鋨', '鋊', '鋈', '鋎', '鋦', '鋍', '鋕', '鋉', '鋠', '鋞',
+        '鋧', '鋑', '鋓', '銵', '鋡', '鋆', '銴', '镼', '閬', '閫', '閮', '閰', '隤', '隢', '雓',
+        '霅', '霈', '霂', '靚', '鞊', '鞎', '鞈', '韐', '韏', '頞', '頝', '頦', '頩', '頨', '頠',
+        '頛', '頧', '颲', '餈', '飺', '餑', '餔', '餖', '餗', '餕', '駜', '駍', '駏', '駓', '駔',
+        '駎', '駉', '駖', '駘', '駋', '駗', '駌', '骳', '髬', '髫', '髳', '髲', '髱', '魆', '魃',
+        '魧', '魴', '魱', '魦', '魶', '魵', '魰', '魨', '魤', '魬', '鳼', '鳺', '鳽', '鳿', '鳷',
+        '鴇', '鴀', '鳹', '鳻', '鴈', '鴅', '鴄', '麃', '黓', '鼏', '鼐', '儜', '儓', '儗', '儚',
+        '儑', '凞', '匴', '叡', '噰', '噠', '噮',
+    ],
+    // Lead 0xE9
+    [
+        '噳', '噦', '噣', '噭', '噲', '噞', '噷', '圜', '圛', '壈', '墽', '壉', '墿', '墺', '壂',
+        '墼', '壆', '嬗', '嬙', '嬛', '嬡', '嬔', '嬓', '嬐', '嬖', '嬨', '嬚', '嬠', '嬞', '寯',
+        '嶬', '嶱', '嶩', '嶧', '嶵', '嶰', '嶮', '嶪', '嶨', '嶲', '嶭', '嶯', '嶴', '幧', '幨',
+        '幦', '幯', '廩', '廧', '廦', '廨', '廥', '彋', '徼', '憝', '憨', '憖', '懅', '憴', '懆',
+        '懁', '懌', '憺', '憿', '憸', '憌', '擗', '擖', '擐', '擏', '擉', '撽', '撉', '擃', '擛',
+        '擳', '擙', '攳', '敿', '敼', '斢', '曈', '暾', '曀', '曊', '曋', '曏', '暽', '暻', '暺',
+        '曌', '朣', '樴', '橦', '橉', '橧', '樲', '橨', '樾', '橝', '橭', '橶', '橛', '橑', '樨',
+        '橚', '樻', '樿', '橁', '橪', '橤', '橐', '橏', '橔', '橯', '橩', '橠', '樼', '橞', '橖',
+        '橕', '橍', '橎', '橆', '歕', '歔', '歖', '殧', '殪', '殫', '毈', '毇', '氄', '氃', '氆',
+        '澭', '濋', '澣', '濇', '澼', '濎', '濈', '潞', '濄', '澽', '澞', '濊', '澨', '瀄', '澥',
+        '澮', '澺', '澬', '澪', '濏', '澿', '澸',
+    ],
+    // Lead 0xEA
+    [
+        '澢', '濉', '澫', '濍', '澯', '澲', '澰', '燅', '燂', '熿', '熸', '燖', '燀', '燁', '燋',
+        '燔', '燊', '燇', '燏', '熽', '燘', '熼', '燆', '燚', '燛', '犝', '犞', '獩', '獦', '獧',
+        '獬', '獥', '獫', '獪', '瑿', '璚', '璠', '璔', '璒', '璕', '璡', '甋', '疀', '瘯', '瘭',
+        '瘱', '瘽', '瘳', '瘼', '瘵', '瘲', '瘰', '皻', '盦', '瞚', '瞝', '瞡', '瞜', '瞛', '瞢',
+        '瞣', '瞕', '瞙', '瞗', '磝', '磩', '磥', '磪', '磞', '磣', '磛', '磡', '磢', '磭', '磟',
+        '磠', '禤', '穄', '穈', '穇', '窶', '窸', '窵', '窱', '窷', '篞', '篣', '篧', '篝', '篕',
+        '篥', '篚', '篨', '篹', '篔', '篪', '篢', '篜', '篫', '篘', '篟', '糒', '糔', '糗', '糐',
+        '糑', '縒', '縡', '縗', '縌', '縟', '縠', '縓', '縎', '縜', '縕', '縚', '縢', '縋', '縏',
+        '縖', '縍', '縔', '縥', '縤', '罃', '罻', '罼', '罺', '羱', '翯', '耪', '耩', '聬', '膱',
+        '膦', '膮', '膹', '膵', '膫', '膰', '膬', '膴', '膲', '膷', '膧', '臲', '艕', '艖', '艗',
+        '蕖', '蕅', '蕫', '蕍', '蕓', '蕡', '蕘',
+    ],
+    // Lead 0xEB
+    [
+        '蕀', '蕆', '蕤', '蕁', '蕢', '蕄', '蕑', '蕇', '蕣', '蔾', '蕛', '蕱', '蕎', '蕮', '蕵',
+        '蕕', '蕧', '蕠', '薌', '蕦', '蕝', '蕔', '蕥', '蕬', '虣', '虥', '虤', '螛', '螏', '螗',
+        '螓', '螒', '螈', '螁', '螖', '螘', '蝹', '螇', '螣', '螅', '螐', '螑', '螝', '螄', '螔',
+        '螜', '螚', '螉', '褞', '褦', '褰', '褭', '褮', '褧', '褱', '褢', '褩', '褣', '褯', '褬',
+        '褟', '觱', '諠', '諢', '諲', '諴', '諵', '諝', '謔', '諤', '諟', '諰', '諈', '諞', '諡',
+        '諨', '諿', '諯', '諻', '貑', '貒', '貐', '賵', '賮', '賱', '賰', '賳', '赬', '赮', '趥',
+        '趧', '踳', '踾', '踸', '蹀', '蹅', '踶', '踼', '踽', '蹁', '踰', '踿', '躽', '輶', '輮',
+        '輵', '輲', '輹', '輷', '輴', '遶', '遹', '遻', '邆', '郺', '鄳', '鄵', '鄶', '醓', '醐',
+        '醑', '醍', '醏', '錧', '錞', '錈', '錟', '錆', '錏', '鍺', '錸', '錼', '錛', '錣', '錒',
+        '錁', '鍆', '錭', '錎', '錍', '鋋', '錝', '鋺', '錥', '錓', '鋹', '鋷', '錴', '錂', '錤',
+        '鋿', '錩', '錹', '錵', '錪', '錔', '錌',
+    ],
+    // Lead 0xEC
+    [
+        '錋', '鋾', '錉', '錀', '鋻', '錖', '閼', '闍', '閾', '閹', '閺', '閶', '閿', '閵', '閽',
+        '隩', '雔', '霋', '霒', '霐', '鞙', '鞗', '鞔', '韰', '韸', '頵', '頯', '頲', '餤', '餟',
+        '餧', '餩', '馞', '駮', '駬', '駥', '駤', '駰', '駣', '駪', '駩', '駧', '骹', '骿', '骴',
+        '骻', '髶', '髺', '髹', '髷', '鬳', '鮀', '鮅', '鮇', '魼', '魾', '魻', '鮂', '鮓', '鮒',
+        '鮐', '魺', '鮕', '魽', '鮈', '鴥', '鴗', '鴠', '鴞', '鴔', '鴩', '鴝', '鴘', '鴢', '鴐',
+        '鴙', '鴟', '麈', '麆', '麇', '麮', '麭', '黕', '黖', '黺', '鼒', '鼽', '儦', '儥', '儢',
+        '儤', '儠', '儩', '勴', '嚓', '嚌', '嚍', '嚆', '嚄', '嚃', '噾', '嚂', '噿', '嚁', '壖',
+        '壔', '壏', '壒', '嬭', '嬥', '嬲', '嬣', '嬬', '嬧', '嬦', '嬯', '嬮', '孻', '寱', '寲',
+        '嶷', '幬', '幪', '徾', '徻', '懃', '憵', '憼', '懧', '懠', '懥', '懤', '懨', '懞', '擯',
+        '擩', '擣', '擫', '擤', '擨', '斁', '斀', '斶', '旚', '曒', '檍', '檖', '檁', '檥', '檉',
+        '檟', '檛', '檡', '檞', '檇', '檓', '檎',
+    ],
+    // Lead 0xED
+    [
+        '檕', '檃', '檨', '檤', '檑', '橿', '檦', '檚', '檅', '檌', '檒', '歛', '殭', '氉', '濌',
+        '澩', '濴', '濔', '濣', '濜', '濭', '濧', '濦', '濞', '濲', '濝', '濢', '濨', '燡', '燱',
+        '燨', '燲', '燤', '燰', '燢', '獳', '獮', '獯', '璗', '璲', '璫', '璐', '璪', '璭', '璱',
+        '璥', '璯', '甐', '甑', '甒', '甏', '疄', '癃', '癈', '癉', '癇', '皤', '盩', '瞵', '瞫',
+        '瞲', '瞷', '瞶', '瞴', '瞱', '瞨', '矰', '磳', '磽', '礂', '磻', '磼', '磲', '礅', '磹',
+        '磾', '礄', '禫', '禨', '穜', '穛', '穖', '穘', '穔', '穚', '窾', '竀', '竁', '簅', '簏',
+        '篲', '簀', '篿', '篻', '簎', '篴', '簋', '篳', '簂', '簉', '簃', '簁', '篸', '篽', '簆',
+        '篰', '篱', '簐', '簊', '糨', '縭', '縼', '繂', '縳', '顈', '縸', '縪', '繉', '繀', '繇',
+        '縩', '繌', '縰', '縻', '縶', '繄', '縺', '罅', '罿', '罾', '罽', '翴', '翲', '耬', '膻',
+        '臄', '臌', '臊', '臅', '臇', '膼', '臩', '艛', '艚', '艜', '薃', '薀', '薏', '薧', '薕',
+        '薠', '薋', '薣', '蕻', '薤', '薚', '薞',
+    ],
+    // Lead 0xEE
+    [
+        '蕷', '蕼', '薉', '薡', '蕺', '蕸', '蕗', '薎', '薖', '薆', '薍', '薙', '薝', '薁', '薢',
+        '薂', '薈', '薅', '蕹', '蕶', '薘', '薐', '薟', '虨', '螾', '螪', '螭', '蟅', '螰', '螬',
+        '螹', '螵', '螼', '螮', '蟉', '蟃', '蟂', '蟌', '螷', '螯', '蟄', '蟊', '螴', '螶', '螿',
+        '螸', '螽', '蟞', '螲', '褵', '褳', '褼', '褾', '襁', '襒', '褷', '襂', '覭', '覯', '覮',
+        '觲', '觳', '謞', '謘', '謖', '謑', '謅', '謋', '謢', '謏', '謒', '謕', '謇', '謍', '謈',
+        '謆', '謜', '謓', '謚', '豏', '豰', '豲', '豱', '豯', '貕', '貔', '賹', '赯', '蹎', '蹍',
+        '蹓', '蹐', '蹌', '蹇', '轃', '轀', '邅', '遾', '鄸', '醚', '醢', '醛', '醙', '醟', '醡',
+        '醝', '醠', '鎡', '鎃', '鎯', '鍤', '鍖', '鍇', '鍼', '鍘', '鍜', '鍶', '鍉', '鍐', '鍑',
+        '鍠', '鍭', '鎏', '鍌', '鍪', '鍹', '鍗', '鍕', '鍒', '鍏', '鍱', '鍷', '鍻', '鍡', '鍞',
+        '鍣', '鍧', '鎀', '鍎', '鍙', '闇', '闀', '闉', '闃', '闅', '閷', '隮', '隰', '隬', '霠',
+        '霟', '霘', '霝', '霙', '鞚', '鞡', '鞜',
+    ],
+    // Lead 0xEF
+    [
+        '鞞', '鞝', '韕', '韔', '韱', '顁', '顄', '顊', '顉', '顅', '顃', '餥', '餫', '餬', '餪',
+        '餳', '餲', '餯', '餭', '餱', '餰', '馘', '馣', '馡', '騂', '駺', '駴', '駷', '駹', '駸',
+        '駶', '駻', '駽', '駾', '駼', '騃', '骾', '髾', '髽', '鬁', '髼', '魈', '鮚', '鮨', '鮞',
+        '鮛', '鮦', '鮡', '鮥', '鮤', '鮆', '鮢', '鮠', '鮯', '鴳', '鵁', '鵧', '鴶', '鴮', '鴯',
+        '鴱', '鴸', '鴰', '鵅', '鵂', '鵃', '鴾', '鴷', '鵀', '鴽', '翵', '鴭', '麊', '麉', '麍',
+        '麰', '黈', '黚', '黻', '黿', '鼤', '鼣', '鼢', '齔', '龠', '儱', '儭', '儮', '嚘', '嚜',
+        '嚗', '嚚', '嚝', '嚙', '奰', '嬼', '屩', '屪', '巀', '幭', '幮', '懘', '懟', '懭', '懮',
+        '懱', '懪', '懰', '懫', '懖', '懩', '擿', '攄', '擽', '擸', '攁', '攃', '擼', '斔', '旛',
+        '曚', '曛', '曘', '櫅', '檹', '檽', '櫡', '櫆', '檺', '檶', '檷', '櫇', '檴', '檭', '歞',
+        '毉', '氋', '瀇', '瀌', '瀍', '瀁', '瀅', '瀔', '瀎', '濿', '瀀', '濻', '瀦', '濼', '濷',
+        '瀊', '爁', '燿', '燹', '爃', '燽', '獶',
+    ],
+    // Lead 0xF0
+    [
+        '璸', '瓀', '璵', '瓁', '璾', '璶', '璻', '瓂', '甔', '甓', '癜', '癤', '癙', '癐', '癓',
+        '癗', '癚', '皦', '皽', '盬', '矂', '瞺', '磿', '礌', '礓', '礔', '礉', '礐', '礒', '礑',
+        '禭', '禬', '穟', '簜', '簩', '簙', '簠', '簟', '簭', '簝', '簦', '簨', '簢', '簥', '簰',
+        '繜', '繐', '繖', '繣', '繘', '繢', '繟', '繑', '繠', '繗', '繓', '羵', '羳', '翷', '翸',
+        '聵', '臑', '臒', '臐', '艟', '艞', '薴', '藆', '藀', '藃', '藂', '薳', '薵', '薽', '藇',
+        '藄', '薿', '藋', '藎', '藈', '藅', '薱', '薶', '藒', '蘤', '薸', '薷', '薾', '虩', '蟧',
+        '蟦', '蟢', '蟛', '蟫', '蟪', '蟥', '蟟', '蟳', '蟤', '蟔', '蟜', '蟓', '蟭', '蟘', '蟣',
+        '螤', '蟗', '蟙', '蠁', '蟴', '蟨', '蟝', '襓', '襋', '襏', '襌', '襆', '襐', '襑', '襉',
+        '謪', '謧', '謣', '謳', '謰', '謵', '譇', '謯', '謼', '謾', '謱', '謥', '謷', '謦', '謶',
+        '謮', '謤', '謻', '謽', '謺', '豂', '豵', '貙', '貘', '貗', '賾', '贄', '贂', '贀', '蹜',
+        '蹢', '蹠', '蹗', '蹖', '蹞', '蹥', '蹧',
+    ],
+    // Lead 0xF1
+    [
+        '蹛', '蹚', '蹡', '蹝', '蹩', '蹔', '轆', '轇', '轈', '轋', '鄨', '鄺', '鄻', '鄾', '醨',
+        '醥', '醧', '醯', '醪', '鎵', '鎌', '鎒', '鎷', '鎛', '鎝', '鎉', '鎧', '鎎', '鎪', '鎞',
+        '鎦', '鎕', '鎈', '鎙', '鎟', '鎍', '鎱', '鎑', '鎲', '鎤', '鎨', '鎴', '鎣', '鎥', '闒',
+        '闓', '闑', '隳', '雗', '雚', '巂', '雟', '雘', '雝', '霣', '霢', '霥', '鞬', '鞮', '鞨',
+        '鞫', '鞤', '鞪', '鞢', '鞥', '韗', '韙', '韖', '韘', '韺', '顐', '顑', '顒', '颸', '饁',
+        '餼', '餺', '騏', '騋', '騉', '騍', '騄', '騑', '騊', '騅', '騇', '騆', '髀', '髜', '鬈',
+        '鬄', '鬅', '鬩', '鬵', '魊', '魌', '魋', '鯇', '鯆', '鯃', '鮿', '鯁', '鮵', '鮸', '鯓',
+        '鮶', '鯄', '鮹', '鮽', '鵜', '鵓', '鵏', '鵊', '鵛', '鵋', '鵙', '鵖', '鵌', '鵗', '鵒',
+        '鵔', '鵟', '鵘', '鵚', '麎', '麌', '黟', '鼁', '鼀', '鼖', '鼥', '鼫', '鼪', '鼩', '鼨',
+        '齌', '齕', '儴', '儵', '劖', '勷', '厴', '嚫', '嚭', '嚦', '嚧', '嚪', '嚬', '壚', '壝',
+        '壛', '夒', '嬽', '嬾', '嬿', '巃', '幰',
+    ],
+    // Lead 0xF2
+    [
+        '徿', '懻', '攇', '攐', '攍', '攉', '攌', '攎', '斄', '旞', '旝', '曞', '櫧', '櫠', '櫌',
+        '櫑', '櫙', '櫋', '櫟', '櫜', '櫐', '櫫', '櫏', '櫍', '櫞', '歠', '殰', '氌', '瀙', '瀧',
+        '瀠', '瀖', '瀫', '瀡', '瀢', '瀣', '瀩', '瀗', '瀤', '瀜', '瀪', '爌', '爊', '爇', '爂',
+        '爅', '犥', '犦', '犤', '犣', '犡', '瓋', '瓅', '璷', '瓃', '甖', '癠', '矉', '矊', '矄',
+        '矱', '礝', '礛', '礡', '礜', '礗', '礞', '禰', '穧', '穨', '簳', '簼', '簹', '簬', '簻',
+        '糬', '糪', '繶', '繵', '繸', '繰', '繷', '繯', '繺', '繲', '繴', '繨', '罋', '罊', '羃',
+        '羆', '羷', '翽', '翾', '聸', '臗', '臕', '艤', '艡', '艣', '藫', '藱', '藭', '藙', '藡',
+        '藨', '藚', '藗', '藬', '藲', '藸', '藘', '藟', '藣', '藜', '藑', '藰', '藦', '藯', '藞',
+        '藢', '蠀', '蟺', '蠃', '蟶', '蟷', '蠉', '蠌', '蠋', '蠆', '蟼', '蠈', '蟿', '蠊', '蠂',
+        '襢', '襚', '襛', '襗', '襡', '襜', '襘', '襝', '襙', '覈', '覷', '覶', '觶', '譐', '譈',
+        '譊', '譀', '譓', '譖', '譔', '譋', '譕',
+    ],
+    // Lead 0xF3
+    [
+        '譑', '譂', '譒', '譗', '豃', '豷', '豶', '貚', '贆', '贇', '贉', '趬', '趪', '趭', '趫',
+        '蹭', '蹸', '蹳', '蹪', '蹯', '蹻', '軂', '轒', '轑', '轏', '轐', '轓', '辴', '酀', '鄿',
+        '醰', '醭', '鏞', '鏇', '鏏', '鏂', '鏚', '鏐', '鏹', '鏬', '鏌', '鏙', '鎩', '鏦', '鏊',
+        '鏔', '鏮', '鏣', '鏕', '鏄', '鏎', '鏀', '鏒', '鏧', '镽', '闚', '闛', '雡', '霩', '霫',
+        '霬', '霨', '霦', '鞳', '鞷', '鞶', '韝', '韞', '韟', '顜', '顙', '顝', '顗', '颿', '颽',
+        '颻', '颾', '饈', '饇', '饃', '馦', '馧', '騚', '騕', '騥', '騝', '騤', '騛', '騢', '騠',
+        '騧', '騣', '騞', '騜', '騔', '髂', '鬋', '鬊', '鬎', '鬌', '鬷', '鯪', '鯫', '鯠', '鯞',
+        '鯤', '鯦', '鯢', '鯰', '鯔', '鯗', '鯬', '鯜', '鯙', '鯥', '鯕', '鯡', '鯚', '鵷', '鶁',
+        '鶊', '鶄', '鶈', '鵱', '鶀', '鵸', '鶆', '鶋', '鶌', '鵽', '鵫', '鵴', '鵵', '鵰', '鵩',
+        '鶅', '鵳', '鵻', '鶂', '鵯', '鵹', '鵿', '鶇', '鵨', '麔', '麑', '黀', '黼', '鼭', '齀',
+        '齁', '齍', '齖', '齗', '齘', '匷', '嚲',
+    ],
+    // Lead 0xF4
+    [
+        '嚵', '嚳', '壣', '孅', '巆', '巇', '廮', '廯', '忀', '忁', '懹', '攗', '攖', '攕', '攓',
+        '旟', '曨', '曣', '曤', '櫳', '櫰', '櫪', '櫨', '櫹', '櫱', '櫮', '櫯', '瀼', '瀵', '瀯',
+        '瀷', '瀴', '瀱', '灂', '瀸', '瀿', '瀺', '瀹', '灀', '瀻', '瀳', '灁', '爓', '爔', '犨',
+        '獽', '獼', '璺', '皫', '皪', '皾', '盭', '矌', '矎', '矏', '矍', '矲', '礥', '礣', '礧',
+        '礨', '礤', '礩', '禲', '穮', '穬', '穭', '竷', '籉', '籈', '籊', '籇', '籅', '糮', '繻',
+        '繾', '纁', '纀', '羺', '翿', '聹', '臛', '臙', '舋', '艨', '艩', '蘢', '藿', '蘁', '藾',
+        '蘛', '蘀', '藶', '蘄', '蘉', '蘅', '蘌', '藽', '蠙', '蠐', '蠑', '蠗', '蠓', '蠖', '襣',
+        '襦', '覹', '觷', '譠', '譪', '譝', '譨', '譣', '譥', '譧', '譭', '趮', '躆', '躈', '躄',
+        '轙', '轖', '轗', '轕', '轘', '轚', '邍', '酃', '酁', '醷', '醵', '醲', '醳', '鐋', '鐓',
+        '鏻', '鐠', '鐏', '鐔', '鏾', '鐕', '鐐', '鐨', '鐙', '鐍', '鏵', '鐀', '鏷', '鐇', '鐎',
+        '鐖', '鐒', '鏺', '鐉', '鏸', '鐊', '鏿',
+    ],
+    // Lead 0xF5
+    [
+        '鏼', '鐌', '鏶', '鐑', '鐆', '闞', '闠', '闟', '霮', '霯', '鞹', '鞻', '韽', '韾', '顠',
+        '顢', '顣', '顟', '飁', '飂', '饐', '饎', '饙', '饌', '饋', '饓', '騲', '騴', '騱', '騬',
+        '騪', '騶', '騩', '騮', '騸', '騭', '髇', '髊', '髆', '鬐', '鬒', '鬑', '鰋', '鰈', '鯷',
+        '鰅', '鰒', '鯸', '鱀', '鰇', '鰎', '鰆', '鰗', '鰔', '鰉', '鶟', '鶙', '鶤', '鶝', '鶒',
+        '鶘', '鶐', '鶛', '鶠', '鶔', '鶜', '鶪', '鶗', '鶡', '鶚', '鶢', '鶨', '鶞', '鶣', '鶿',
+        '鶩', '鶖', '鶦', '鶧', '麙', '麛', '麚', '黥', '黤', '黧', '黦', '鼰', '鼮', '齛', '齠',
+        '齞', '齝', '齙', '龑', '儺', '儹', '劘', '劗', '囃', '嚽', '嚾', '孈', '孇', '巋', '巏',
+        '廱', '懽', '攛', '欂', '櫼', '欃', '櫸', '欀', '灃', '灄', '灊', '灈', '灉', '灅', '灆',
+        '爝', '爚', '爙', '獾', '甗', '癪', '矐', '礭', '礱', '礯', '籔', '籓', '糲', '纊', '纇',
+        '纈', '纋', '纆', '纍', '罍', '羻', '耰', '臝', '蘘', '蘪', '蘦', '蘟', '蘣', '蘜', '蘙',
+        '蘧', '蘮', '蘡', '蘠', '蘩', '蘞', '蘥',
+    ],
+    // Lead 0xF6
+    [
+        '蠩', '蠝', '蠛', '蠠', '蠤', '蠜', '蠫', '衊', '襭', '襩', '襮', '襫', '觺', '譹', '譸',
+        '譅', '譺', '譻', '贐', '贔', '趯', '躎', '躌', '轞', '轛', '轝', '酆', '酄', '酅', '醹',
+        '鐿', '鐻', '鐶', '鐩', '鐽', '鐼', '鐰', '鐹', '鐪', '鐷', '鐬', '鑀', '鐱', '闥', '闤',
+        '闣', '霵', '霺', '鞿', '韡', '顤', '飉', '飆', '飀', '饘', '饖', '騹', '騽', '驆', '驄',
+        '驂', '驁', '騺', '騿', '髍', '鬕', '鬗', '鬘', '鬖', '鬺', '魒', '鰫', '鰝', '鰜', '鰬',
+        '鰣', '鰨', '鰩', '鰤', '鰡', '鶷', '鶶', '鶼', '鷁', '鷇', '鷊', '鷏', '鶾', '鷅', '鷃',
+        '鶻', '鶵', '鷎', '鶹', '鶺', '鶬', '鷈', '鶱', '鶭', '鷌', '鶳', '鷍', '鶲', '鹺', '麜',
+        '黫', '黮', '黭', '鼛', '鼘', '鼚', '鼱', '齎', '齥', '齤', '龒', '亹', '囆', '囅', '囋',
+        '奱', '孋', '孌', '巕', '巑', '廲', '攡', '攠', '攦', '攢', '欋', '欈', '欉', '氍', '灕',
+        '灖', '灗', '灒', '爞', '爟', '犩', '獿', '瓘', '瓕', '瓙', '瓗', '癭', '皭', '礵', '禴',
+        '穰', '穱', '籗', '籜', '籙', '籛', '籚',
+    ],
+    // Lead 0xF7
+    [
+        '糴', '糱', '纑', '罏', '羇', '臞', '艫', '蘴', '蘵', '蘳', '蘬', '蘲', '蘶', '蠬', '蠨',
+        '蠦', '蠪', '蠥', '襱', '覿', '覾', '觻', '譾', '讄', '讂', '讆', '讅', '譿', '贕', '躕',
+        '躔', '躚', '躒', '躐', '躖', '躗', '轠', '轢', '酇', '鑌', '鑐', '鑊', '鑋', '鑏', '鑇',
+        '鑅', '鑈', '鑉', '鑆', '霿', '韣', '顪', '顩', '飋', '饔', '饛', '驎', '驓', '驔', '驌',
+        '驏', '驈', '驊', '驉', '驒', '驐', '髐', '鬙', '鬫', '鬻', '魖', '魕', '鱆', '鱈', '鰿',
+        '鱄', '鰹', '鰳', '鱁', '鰼', '鰷', '鰴', '鰲', '鰽', '鰶', '鷛', '鷒', '鷞', '鷚', '鷋',
+        '鷐', '鷜', '鷑', '鷟', '鷩', '鷙', '鷘', '鷖', '鷵', '鷕', '鷝', '麶', '黰', '鼵', '鼳',
+        '鼲', '齂', '齫', '龕', '龢', '儽', '劙', '壨', '壧', '奲', '孍', '巘', '蠯', '彏', '戁',
+        '戃', '戄', '攩', '攥', '斖', '曫', '欑', '欒', '欏', '毊', '灛', '灚', '爢', '玂', '玁',
+        '玃', '癰', '矔', '籧', '籦', '纕', '艬', '蘺', '虀', '蘹', '蘼', '蘱', '蘻', '蘾', '蠰',
+        '蠲', '蠮', '蠳', '襶', '襴', '襳', '觾',
+    ],
+    // Lead 0xF8
+    [
+        '讌', '讎', '讋', '讈', '豅', '贙', '躘', '轤', '轣', '醼', '鑢', '鑕', '鑝', '鑗', '鑞',
+        '韄', '韅', '頀', '驖', '驙', '鬞', '鬟', '鬠', '鱒', '鱘', '鱐', '鱊', '鱍', '鱋', '鱕',
+        '鱙', '鱌', '鱎', '鷻', '鷷', '鷯', '鷣', '鷫', '鷸', '鷤', '鷶', '鷡', '鷮', '鷦', '鷲',
+        '鷰', '鷢', '鷬', '鷴', '鷳', '鷨', '鷭', '黂', '黐', '黲', '黳', '鼆', '鼜', '鼸', '鼷',
+        '鼶', '齃', '齏', '齱', '齰', '齮', '齯', '囓', '囍', '孎', '屭', '攭', '曭', '曮', '欓',
+        '灟', '灡', '灝', '灠', '爣', '瓛', '瓥', '矕', '礸', '禷', '禶', '籪', '纗', '羉', '艭',
+        '虃', '蠸', '蠷', '蠵', '衋', '讔', '讕', '躞', '躟', '躠', '躝', '醾', '醽', '釂', '鑫',
+        '鑨', '鑩', '雥', '靆', '靃', '靇', '韇', '韥', '驞', '髕', '魙', '鱣', '鱧', '鱦', '鱢',
+        '鱞', '鱠', '鸂', '鷾', '鸇', '鸃', '鸆', '鸅', '鸀', '鸁', '鸉', '鷿', '鷽', '鸄', '麠',
+        '鼞', '齆', '齴', '齵', '齶', '囔', '攮', '斸', '欘', '欙', '欗', '欚', '灢', '爦', '犪',
+        '矘', '矙', '礹', '籩', '籫', '糶', '纚',
+    ],
+    // Lead 0xF9
+    [
+        '纘', '纛', '纙', '臠', '臡', '虆', '虇', '虈', '襹', '襺', '襼', '襻', '觿', '讘', '讙',
+        '躥', '躤', '躣', '鑮', '鑭', '鑯', '鑱', '鑳', '靉', '顲', '饟', '鱨', '鱮', '鱭', '鸋',
+        '鸍', '鸐', '鸏', '鸒', '鸑', '麡', '黵', '鼉', '齇', '齸', '齻', '齺', '齹', '圞', '灦',
+        '籯', '蠼', '趲', '躦', '釃', '鑴', '鑸', '鑶', '鑵', '驠', '鱴', '鱳', '鱱', '鱵', '鸔',
+        '鸓', '黶', '鼊', '龤', '灨', '灥', '糷', '虪', '蠾', '蠽', '蠿', '讞', '貜', '躩', '軉',
+        '靋', '顳', '顴', '飌', '饡', '馫', '驤', '驦', '驧', '鬤', '鸕', '鸗', '齈', '戇', '欞',
+        '爧', '虌', '躨', '钂', '钀', '钁', '驩', '驨', '鬮', '鸙', '爩', '虋', '讟', '钃', '鱹',
+        '麷', '癵', '驫', '鱺', '鸝', '灩', '灪', '麤', '齾', '齉', '龘', '�', '�', '�', '�', '�',
+        '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�',
+        '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�', '�',
+    ],
+];
+
+pub const ENCODE_MAP_BIG5: &[(char, (usize, usize)); 13706] = &[
+    ('¢', (1, 6)),
+    ('£', (1, 7)),
+    ('¥', (1, 4)),
+    ('§', (0, 79)),
+    ('°', (1, 24)),
+    ('±', (0, 113)),
+    ('·', (0, 16)),
+    ('×', (0, 111)),
+    ('÷', (0, 112)),
+    ('ˇ', (2, 92)),
+    ('ˉ', (2, 90)),
+    ('ˊ', (2, 91)),
+    ('ˋ', (2, 93)),
+    ('ˍ', (0, 99)),
+    ('˙', (2, 89)),
+    ('Α', (2, 4)),
+    ('Β', (2, 5)),
+    ('Γ', (2, 6)),
+    ('Δ', (2, 7)),
+    ('Ε', (2, 8)),
+    ('Ζ', (2, 9)),
+    ('Η', (2, 10)),
+    ('Θ', (2, 11)),
+    ('Ι', (2, 12)),
+    ('Κ', (2, 13)),
+    ('Λ', (2, 14)),
+    ('Μ', (2, 15)),
+    ('Ν', (2, 16)),
+    ('Ξ', (2, 17)),
+    ('Ο', (2, 18)),
+    ('Π', (2, 19)),
+    ('Ρ', (2, 20)),
+    ('Σ', (2, 21)),
+    ('Τ', (2, 22)),
+    ('Υ', (2, 23)),
+    ('Φ', (2, 24)),
+    ('Χ', (2, 25)),
+    ('Ψ', (2, 26)),
+    ('Ω', (2, 27)),
+    ('α', (2, 28)),
+    ('β', (2, 29)),
+    ('γ', (2, 30)),
+    ('δ', (2, 31)),
+    ('ε', (2, 32)),
+    ('ζ', (2, 33)),
+    ('η', (2, 34)),
+    ('θ', (2, 35)),
+    ('ι', (2, 36)),
+    ('κ', (2, 37)),
+    ('λ', (2, 38)),
+    ('μ', (2, 39)),
+    ('ν', (2, 40)),
+    ('ξ', (2, 41)),
+    ('ο', (2, 42)),
+    ('π', (2, 43)),
+    ('ρ', (2, 44)),
+    ('σ', (2, 45)),
+    ('τ', (2, 46)),
+    ('υ', (2, 47)),
+    ('φ', (2, 48)),
+    ('χ', (2, 49)),
+    ('ψ', (2, 50)),
+    ('ω', (2, 51)),
+    ('Ё', (38, 81)),
+    ('Д', (38, 79)),
+    ('Е', (38, 80)),
+    ('Ж', (38, 82)),
+    ('З', (38, 83)),
+    ('И', (38, 84)),
+    ('Й', (38, 85)),
+    ('К', (38, 86)),
+    ('Л', (38, 87)),
+    ('М', (38, 88)),
+    ('У', (38, 89)),
+    ('Ф', (38, 90)),
+    ('Х', (38, 91)),
+    ('Ц', (38, 92)),
+    ('Ч', (38, 93)),
+    ('Ш', (38, 94)),
+    ('Щ', (38, 95)),
+    ('Ъ', (38, 96)),
+    ('Ы', (38, 97)),
+    ('Ь', (38, 98)),
+    ('Э', (38, 99)),
+    ('Ю', (38, 100)),
+    ('Я', (38, 101)),
+    ('а', (38, 102)),
+    ('б', (38, 103)),
+    ('в', (38, 104)),
+    ('г', (38, 105)),
+    ('д', (38, 106)),
+    ('е', (38, 107)),
+    ('ж', (38, 109)),
+    ('з', (38, 110)),
+    ('и', (38, 111)),
+    ('й', (38, 112)),
+    ('к', (38, 113)),
+    ('л', (38, 114)),
+    ('м', (38, 115)),
+    ('н', (38, 116)),
+    ('о', (38, 117)),
+    ('п', (38, 118)),
+    ('р', (38, 119)),
+    ('с', (38, 120)),
+    ('т', (38, 121)),
+    ('у', (38, 122)),
+    ('ф', (38, 123)),
+    ('х', (38, 124)),
+    ('ц', (38, 125)),
+    ('ч', (38, 126)),
+    ('ш', (38, 127)),
+    ('щ', (38, 128)),
+    ('ъ', (38, 129)),
+    ('ы', (38, 130)),
+    ('ь', (38, 131)),
+    ('э', (38, 132)),
+    ('ю', (38, 133)),
+    ('я', (38, 134)),
+    ('ё', (38, 108)),
+    ('–', (0, 22)),
+    ('—', (0, 24)),
+    ('‘', (0, 67)),
+    ('’', (0, 68)),
+    ('“', (0, 69)),
+    ('”', (0, 70)),
+    ('•', (0, 5)),
+    ('‥', (0, 12)),
+    ('…', (0, 11)),
+    ('′', (0, 74)),
+    ('‵', (0, 73)),
+    ('※', (0, 78)),
+    ('‾', (0, 96)),
+    ('℃', (1, 10)),
+    ('℅', (0, 95)),
+    ('℉', (1, 11)),
+    ('Ⅰ', (1, 87)),
+    ('Ⅱ', (1, 88)),
+    ('Ⅲ', (1, 89)),
+    ('Ⅳ', (1, 90)),
+    ('Ⅴ', (1, 91)),
+    ('Ⅵ', (1, 92)),
+    ('Ⅶ', (1, 93)),
+    ('Ⅷ', (1, 94)),
+    ('Ⅸ', (1, 95)),
+    ('Ⅹ', (1, 96)),
+    ('←', (0, 148)),
+    ('↑', (0, 146)),
+    ('→', (0, 149)),
+    ('↓', (0, 147)),
+    ('↖', (0, 150)),
+    ('↗', (0, 151)),
+    ('↘', (0, 153)),
+    ('↙', (0, 152)),
+    ('√', (0, 114)),
+    ('∞', (0, 121)),
+    ('∟', (0, 134)),
+    ('∠', (0, 133)),
+    ('∣', (0, 155)),
+    ('∥', (0, 154)),
+    ('∩', (0, 130)),
+    ('∪', (0, 131)),
+    ('∫', (0, 138)),
+    ('∮', (0, 139)),
+    ('∴', (0, 141)),
+    ('∵', (0, 140)),
+    ('∼', (0, 129)),
+    ('≒', (0, 122)),
+    ('≠', (0, 120)),
+    ('≡', (0, 123)),
+    ('≦', (0, 118)),
+    ('≧', (0, 119)),
+    ('⊥', (0, 132)),
+    ('⊿', (0, 135)),
+    ('①', (38, 135)),
+    ('②', (38, 136)),
+    ('③', (38, 137)),
+    ('④', (38, 138)),
+    ('⑤', (38, 139)),
+    ('⑥', (38, 140)),
+    ('⑦', (38, 141)),
+    ('⑧', (38, 142)),
+    ('⑨', (38, 143)),
+    ('⑩', (38, 144)),
+    ('⑴', (38, 145)),
+    ('⑵', (38, 146)),
+    ('⑶', (38, 147)),
+    ('⑷', (38, 148)),
+    ('⑸', (38, 149)),
+    ('⑹', (38, 150)),
+    ('⑺', (38, 151)),
+    ('⑻', (38, 152)),
+    ('⑼', (38, 153)),
+    ('⑽', (38, 154)),
+    ('─', (1, 55)),
+    ('│', (1, 56)),
+    ('┌', (1, 58)),
+    ('┐', (1, 59)),
+    ('└', (1, 60)),
+    ('┘', (1, 61)),
+    ('├', (1, 53)),
+    ('┤', (1, 52)),
+    ('┬', (1, 51)),
+    ('┴', (1, 50)),
+    ('┼', (1, 49)),
+    ('═', (1, 66)),
+    ('╞', (1, 67)),
+    ('╡', (1, 69)),
+    ('╪', (1, 68)),
+    ('╭', (1, 62)),
+    ('╮', (1, 63)),
+    ('╯', (1, 65)),
+    ('╰', (1, 64)),
+    ('╱', (1, 74)),
+    ('╲', (1, 75)),
+    ('╳', (1, 76)),
+    ('╴', (0, 26)),
+    ('▁', (1, 34)),
+    ('▂', (1, 35)),
+    ('▃', (1, 36)),
+    ('▄', (1, 37)),
+    ('▅', (1, 38)),
+    ('▆', (1, 39)),
+    ('▇', (1, 40)),
+    ('█', (1, 41)),
+    ('▉', (1, 48)),
+    ('▊', (1, 47)),
+    ('▋', (1, 46)),
+    ('▌', (1, 45)),
+    ('▍', (1, 44)),
+    ('▎', (1, 43)),
+    ('▏', (1, 42)),
+    ('▔', (1, 54)),
+    ('▕', (1, 57)),
+    ('■', (0, 91)),
+    ('□', (0, 90)),
+    ('▲', (0, 84)),
+    ('△', (0, 83)),
+    ('▼', (0, 93)),
+    ('▽', (0, 92)),
+    ('◆', (0, 89)),
+    ('◇', (0, 88)),
+    ('○', (0, 81)),
+    ('◎', (0, 85)),
+    ('●', (0, 82)),
+    ('◢', (1, 70)),
+    ('◣', (1, 71)),
+    ('◤', (1, 73)),
+    ('◥', (1, 72)),
+    ('★', (0, 87)),
+    ('☆', (0, 86)),
+    ('☉', (0, 145)),
+    ('♀', (0, 142)),
+    ('♁', (0, 144)),
+    ('♂', (0, 143)),
+    ('　', (0, 0)),
+    ('、', (0, 2)),
+    ('。', (0, 3)),
+    ('〃', (0, 80)),
+    ('々', (37, 66)),
+    ('〈', (0, 49)),
+    ('〉', (0, 50)),
+    ('《', (0, 45)),
+    ('》', (0, 46)),
+    ('「', (0, 53)),
+    ('」', (0, 54)),
+    ('『', (0, 57)),
+    ('』', (0, 58)),
+    ('【', (0, 41)),
+    ('】', (0, 42)),
+    ('〒', (1, 5)),
+    ('〔', (0, 37)),
+    ('〕', (0, 38)),
+    ('〝', (0, 71)),
+    ('〞', (0, 72)),
+    ('〡', (1, 97)),
+    ('〢', (1, 98)),
+    ('〣', (1, 99)),
+    ('〤', (1, 100)),
+    ('〥', (1, 101)),
+    ('〦', (1, 102)),
+    ('〧', (1, 103)),
+    ('〨', (1, 104)),
+    ('〩', (1, 105)),
+    ('ぁ', (37, 67)),
+    ('あ', (37, 68)),
+    ('ぃ', (37, 69)),
+    ('い', (37, 70)),
+    ('ぅ', (37, 71)),
+    ('う', (37, 72)),
+    ('ぇ', (37, 73)),
+    ('え', (37, 74)),
+    ('ぉ', (37, 75)),
+    ('お', (37, 76)),
+    ('か', (37, 77)),
+    ('が', (37, 78)),
+    ('き', (37, 79)),
+    ('ぎ', (37, 80)),
+    ('く', (37, 81)),
+    ('ぐ', (37, 82)),
+    ('け', (37, 83)),
+    ('げ', (37, 84)),
+    ('こ', (37, 85)),
+    ('ご', (37, 86)),
+    ('さ', (37, 87)),
+    ('ざ', (37, 88)),
+    ('し', (37, 89)),
+    ('じ', (37, 90)),
+    ('す', (37, 91)),
+    ('ず', (37, 92)),
+    ('せ', (37, 93)),
+    ('ぜ', (37, 94)),
+    ('そ', (37, 95)),
+    ('ぞ', (37, 96)),
+    ('た', (37, 97)),
+    ('だ', (37, 98)),
+    ('ち', (37, 99)),
+    ('ぢ', (37, 100)),
+    ('っ', (37, 101)),
+    ('つ', (37, 102)),
+    ('づ', (37, 103)),
+    ('て', (37, 104)),
+    ('で', (37, 105)),
+    ('と', (37, 106)),
+    ('ど', (37, 107)),
+    ('な', (37, 108)),
+    ('に', (37, 109)),
+    ('ぬ', (37, 110)),
+    ('ね', (37, 111)),
+    ('の', (37, 112)),
+    ('は', (37, 113)),
+    ('ば', (37, 114)),
+    ('ぱ', (37, 115)),
+    ('ひ', (37, 116)),
+    ('び', (37, 117)),
+    ('ぴ', (37, 118)),
+    ('ふ', (37, 119)),
+    ('ぶ', (37, 120)),
+    ('ぷ', (37, 121)),
+    ('へ', (37, 122)),
+    ('べ', (37, 123)),
+    ('ぺ', (37, 124)),
+    ('ほ', (37, 125)),
+    ('ぼ', (37, 126)),
+    ('ぽ', (37, 127)),
+    ('ま', (37, 128)),
+    ('み', (37, 129)),
+    ('む', (37, 130)),
+    ('め', (37, 131)),
+    ('も', (37, 132)),
+    ('ゃ', (37, 133)),
+    ('や', (37, 134)),
+    ('ゅ', (37, 135)),
+    ('ゆ', (37, 136)),
+    ('ょ', (37, 137)),
+    ('よ', (37, 138)),
+    ('ら', (37, 139)),
+    ('り', (37, 140)),
+    ('る', (37, 141)),
+    ('れ', (37, 142)),
+    ('ろ', (37, 143)),
+    ('ゎ', (37, 144)),
+    ('わ', (37, 145)),
+    ('ゐ', (37, 146)),
+    ('ゑ', (37, 147)),
+    ('を', (37, 148)),
+    ('ん', (37, 149)),
+    ('ゝ', (37, 64)),
+    ('ゞ', (37, 65)),
+    ('ァ', (37, 150)),
+    ('ア', (37, 151)),
+    ('ィ', (37, 152)),
+    ('イ', (37, 153)),
+    ('ゥ', (37, 154)),
+    ('ウ', (37, 155)),
+    ('ェ', (37, 156)),
+    ('エ', (38, 0)),
+    ('ォ', (38, 1)),
+    ('オ', (38, 2)),
+    ('カ', (38, 3)),
+    ('ガ', (38, 4)),
+    ('キ', (38, 5)),
+    ('ギ', (38, 6)),
+    ('ク', (38, 7)),
+    ('グ', (38, 8)),
+    ('ケ', (38, 9)),
+    ('ゲ', (38, 10)),
+    ('コ', (38, 11)),
+    ('ゴ', (38, 12)),
+    ('サ', (38, 13)),
+    ('ザ', (38, 14)),
+    ('シ', (38, 15)),
+    ('ジ', (38, 16)),
+    ('ス', (38, 17)),
+    ('ズ', (38, 18)),
+    ('セ', (38, 19)),
+    ('ゼ', (38, 20)),
+    ('ソ', (38, 21)),
+    ('ゾ', (38, 22)),
+    ('タ', (38, 23)),
+    ('ダ', (38, 24)),
+    ('チ', (38, 25)),
+    ('ヂ', (38, 26)),
+    ('ッ', (38, 27)),
+    ('ツ', (38, 28)),
+    ('ヅ', (38, 29)),
+    ('テ', (38, 30)),
+    ('デ', (38, 31)),
+    ('ト', (38, 32)),
+    ('ド', (38, 33)),
+    ('ナ', (38, 34)),
+    ('ニ', (38, 35)),
+    ('ヌ', (38, 36)),
+    ('ネ', (38, 37)),
+    ('ノ', (38, 38)),
+    ('ハ', (38, 39)),
+    ('バ', (38, 40)),
+    ('パ', (38, 41)),
+    ('ヒ', (38, 42)),
+    ('ビ', (38, 43)),
+    ('ピ', (38, 44)),
+    ('フ', (38, 45)),
+    ('ブ', (38, 46)),
+    ('プ', (38, 47)),
+    ('ヘ', (38, 48)),
+    ('ベ', (38, 49)),
+    ('ペ', (38, 50)),
+    ('ホ', (38, 51)),
+    ('ボ', (38, 52)),
+    ('ポ', (38, 53)),
+    ('マ', (38, 54)),
+    ('ミ', (38, 55)),
+    ('ム', (38, 56)),
+    ('メ', (38, 57)),
+    ('モ', (38, 58)),
+    ('ャ', (38, 59)),
+    ('ヤ', (38, 60)),
+    ('ュ', (38, 61)),
+    ('ユ', (38, 62)),
+    ('ョ', (38, 63)),
+    ('ヨ', (38, 64)),
+    ('ラ', (38, 65)),
+    ('リ', (38, 66)),
+    ('ル', (38, 67)),
+    ('レ', (38, 68)),
+    ('ロ', (38, 69)),
+    ('ヮ', (38, 70)),
+    ('ワ', (38, 71)),
+    ('ヰ', (38, 72)),
+    ('ヱ', (38, 73)),
+    ('ヲ', (38, 74)),
+    ('ン', (38, 75)),
+    ('ヴ', (38, 76)),
+    ('ヵ', (38, 77)),
+    ('ヶ', (38, 78)),
+    ('ヾ', (37, 63)),
+    ('ㄅ', (2, 52)),
+    ('ㄆ', (2, 53)),
+    ('ㄇ', (2, 54)),
+    ('ㄈ', (2, 55)),
+    ('ㄉ', (2, 56)),
+    ('ㄊ', (2, 57)),
+    ('ㄋ', (2, 58)),
+    ('ㄌ', (2, 59)),
+    ('ㄍ', (2, 60)),
+    ('ㄎ', (2, 61)),
+    ('ㄏ', (2, 62)),
+    ('ㄐ', (2, 63)),
+    ('ㄑ', (2, 64)),
+    ('ㄒ', (2, 65)),
+    ('ㄓ', (2, 66)),
+    ('ㄔ', (2, 67)),
+    ('ㄕ', (2, 68)),
+    ('ㄖ', (2, 69)),
+    ('ㄗ', (2, 70)),
+    ('ㄘ', (2, 71)),
+    ('ㄙ', (2, 72)),
+    ('ㄚ', (2, 73)),
+    ('ㄛ', (2, 74)),
+    ('ㄜ', (2, 75)),
+    ('ㄝ', (2, 76)),
+    ('ㄞ', (2, 77)),
+    ('ㄟ', (2, 78)),
+    ('ㄠ', (2, 79)),
+    ('ㄡ', (2, 80)),
+    ('ㄢ', (2, 81)),
+    ('ㄣ', (2, 82)),
+    ('ㄤ', (2, 83)),
+    ('ㄥ', (2, 84)),
+    ('ㄦ', (2, 85)),
+    ('ㄧ', (2, 86)),
+    ('ㄨ', (2, 87)),
+    ('ㄩ', (2, 88)),
+    ('㊣', (0, 94)),
+    ('㎎', (1, 21)),
+    ('㎏', (1, 22)),
+    ('㎜', (1, 16)),
+    ('㎝', (1, 17)),
+    ('㎞', (1, 18)),
+    ('㎡', (1, 20)),
+    ('㏄', (1, 23)),
+    ('㏎', (1, 19)),
+    ('㏑', (0, 137)),
+    ('㏒', (0, 136)),
+    ('㏕', (1, 15)),
+    ('一', (3, 0)),
+    ('丁', (3, 2)),
+    ('七', (3, 3)),
+    ('万', (40, 5)),
+    ('丈', (3, 22)),
+    ('三', (3, 20)),
+    ('上', (3, 23)),
+    ('下', (3, 21)),
+    ('丌', (40, 6)),
+    ('不', (3, 65)),
+    ('与', (40, 15)),
+    ('丏', (40, 13)),
+    ('丐', (3, 64)),
+    ('丑', (3, 63)),
+    ('且', (4, 2)),
+    ('丕', (4, 1)),
+    ('世', (4, 0)),
+    ('丘', (4, 3)),
+    ('丙', (3, 156)),
+    ('丞', (4, 126)),
+    ('丟', (4, 127)),
+    ('並', (7, 97)),
+    ('丫', (3, 24)),
+    ('中', (3, 66)),
+    ('丮', (40, 16)),
+    ('丰', (3, 67)),
+    ('丱', (40, 35)),
+    ('串', (5, 136)),
+    ('丳', (42, 79)),
+    ('丸', (3, 25)),
+    ('丹', (3, 68)),
+    ('主', (4, 4)),
+    ('丼', (40, 36)),
+    ('乂', (40, 0)),
+    ('乃', (3, 4)),
+    ('久', (3, 27)),
+    ('乇', (40, 7)),
+    ('么', (3, 28)),
+    ('之', (3, 69)),
+    ('乍', (4, 5)),
+    ('乎', (4, 7)),
+    ('乏', (4, 6)),
+    ('乒', (4, 128)),
+    ('乓', (4, 129)),
+    ('乖', (7, 98)),
+    ('乘', (12, 90)),
+    ('乙', (3, 1)),
+    ('乜', (40, 1)),
+    ('九', (3, 5)),
+    ('乞', (3, 30)),
+    ('也', (3, 29)),
+    ('乩', (4, 130)),
+    ('乳', (7, 99)),
+    ('乾', (15, 76)),
+    ('乿', (51, 11)),
+    ('亂', (21, 97)),
+    ('亃', (59, 79)),
+    ('亄', (59, 80)),
+    ('了', (3, 6)),
+    ('予', (3, 71)),
+    ('事', (7, 100)),
+    ('二', (3, 7)),
+    ('亍', (40, 8)),
+    ('于', (3, 31)),
+    ('云', (3, 72)),
+    ('互', (3, 74)),
+    ('亓', (40, 17)),
+    ('五', (3, 75)),
+    ('井', (3, 73)),
+    ('亙', (4, 131)),
+    ('些', (7, 101)),
+    ('亞', (7, 102)),
+    ('亟', (10, 5)),
+    ('亡', (3, 32)),
+    ('亢', (3, 76)),
+    ('交', (4, 132)),
+    ('亥', (4, 134)),
+    ('亦', (4, 133)),
+    ('亨', (5, 137)),
+    ('享', (7, 103)),
+    ('京', (7, 104)),
+    ('亭', (10, 6)),
+    ('亮', (10, 7)),
+    ('亳', (12, 91)),
+    ('亶', (59, 81)),
+    ('亹', (85, 116)),
+    ('人', (3, 8)),
+    ('什', (3, 78)),
+    ('仁', (3, 77)),
+    ('仂', (40, 18)),
+    ('仃', (3, 79)),
+    ('仄', (3, 85)),
+    ('仆', (3, 80)),
+    ('仇', (3, 81)),
+    ('仈', (40, 20)),
+    ('仉', (40, 19)),
+    ('今', (3, 83)),
+    ('介', (3, 84)),
+    ('仍', (3, 82)),
+    ('仔', (4, 10)),
+    ('仕', (4, 11)),
+    ('他', (4, 12)),
+    ('仗', (4, 13)),
+    ('付', (4, 9)),
+    ('仙', (4, 16)),
+    ('仚', (40, 42)),
+    ('仜', (40, 38)),
+    ('仝', (40, 41)),
+    ('仞', (4, 17)),
+    ('仟', (4, 33)),
+    ('仡', (40, 40)),
+    ('代', (4, 14)),
+    ('令', (4, 15)),
+    ('以', (4, 8)),
+    ('仨', (40, 37)),
+    ('仩', (40, 39)),
+    ('仰', (4, 147)),
+    ('仱', (40, 78)),
+    ('仲', (4, 144)),
+    ('仳', (4, 148)),
+    ('仴', (40, 88)),
+    ('仵', (40, 76)),
+    ('件', (4, 145)),
+    ('价', (40, 80)),
+    ('任', (4, 146)),
+    ('份', (4, 149)),
+    ('仿', (4, 135)),
+    ('伀', (40, 79)),
+    ('企', (4, 150)),
+    ('伂', (40, 83)),
+    ('伄', (40, 87)),
+    ('伅', (40, 84)),
+    ('伈', (40, 81)),
+    ('伉', (4, 136)),
+    ('伊', (4, 138)),
+    ('伋', (4, 151)),
+    ('伍', (4, 140)),
+    ('伎', (40, 73)),
+    ('伏', (4, 143)),
+    ('伐', (4, 141)),
+    ('休', (4, 142)),
+    ('伒', (40, 89)),
+    ('伓', (40, 86)),
+    ('伔', (40, 77)),
+    ('伕', (4, 139)),
+    ('优', (40, 74)),
+    ('伙', (4, 137)),
+    ('伝', (40, 82)),
+    ('伢', (40, 85)),
+    ('伬', (40, 75)),
+    ('伭', (41, 38)),
+    ('伯', (6, 2)),
+    ('估', (5, 146)),
+    ('伳', (41, 39)),
+    ('伴', (5, 143)),
+    ('伶', (6, 4)),
+    ('伸', (5, 151)),
+    ('伺', (5, 150)),
+    ('伻', (41, 27)),
+    ('似', (5, 154)),
+    ('伽', (5, 149)),
+    ('伾', (41, 32)),
+    ('伿', (41, 40)),
+    ('佁', (41, 36)),
+    ('佃', (5, 152)),
+    ('但', (5, 155)),
+    ('佇', (5, 140)),
+    ('佈', (6, 7)),
+    ('佉', (41, 29)),
+    ('佌', (42, 91)),
+    ('位', (5, 138)),
+    ('低', (6, 3)),
+    ('住', (5, 139)),
+    ('佐', (5, 147)),
+    ('佑', (5, 148)),
+    ('佒', (41, 34)),
+    ('体', (41, 30)),
+    ('佔', (5, 153)),
+    ('何', (5, 145)),
+    ('佖', (41, 26)),
+    ('佗', (5, 141)),
+    ('佘', (41, 37)),
+    ('余', (6, 5)),
+    ('佚', (6, 8)),
+    ('佛', (5, 144)),
+    ('作', (6, 0)),
+    ('佝', (6, 6)),
+    ('佞', (5, 142)),
+    ('佟', (41, 35)),
+    ('你', (6, 1)),
+    ('佡', (41, 41)),
+    ('佢', (41, 28)),
+    ('佣', (5, 156)),
+    ('佤', (41, 31)),
+    ('佧', (41, 33)),
+    ('佩', (7, 118)),
+    ('佪', (42, 93)),
+    ('佫', (42, 105)),
+    ('佬', (7, 110)),
+    ('佮', (42, 106)),
+    ('佯', (7, 105)),
+    ('佰', (7, 115)),
+    ('佳', (7, 108)),
+    ('佴', (42, 87)),
+    ('併', (7, 116)),
+    ('佶', (42, 86)),
+    ('佷', (42, 90)),
+    ('佸', (42, 97)),
+    ('佹', (42, 95)),
+    ('佺', (7, 124)),
+    ('佻', (7, 119)),
+    ('佼', (42, 81)),
+    ('佽', (42, 83)),
+    ('佾', (7, 121)),
+    ('使', (7, 109)),
+    ('侀', (42, 84)),
+    ('侁', (42, 96)),
+    ('侂', (42, 103)),
+    ('侃', (7, 114)),
+    ('侄', (42, 89)),
+    ('侅', (42, 82)),
+    ('來', (7, 113)),
+    ('侇', (42, 85)),
+    ('侈', (7, 117)),
+    ('侉', (42, 88)),
+    ('例', (7, 112)),
+    ('侍', (7, 107)),
+    ('侏', (7, 122)),
+    ('侐', (42, 98)),
+    ('侑', (7, 123)),
+    ('侒', (42, 102)),
+    ('侔', (42, 100)),
+    ('侕', (42, 104)),
+    ('侖', (7, 120)),
+    ('侗', (42, 92)),
+    ('侘', (42, 80)),
+    ('侚', (42, 94)),
+    ('供', (7, 111)),
+    ('侜', (42, 99)),
+    ('依', (7, 106)),
+    ('侞', (42, 101)),
+    ('侮', (10, 22)),
+    ('侯', (10, 10)),
+    ('侲', (44, 126)),
+    ('侳', (44, 134)),
+    ('侵', (10, 9)),
+    ('侶', (10, 17)),
+    ('侷', (10, 29)),
+    ('侹', (44, 140)),
+    ('侺', (44, 138)),
+    ('侻', (44, 133)),
+    ('便', (10, 11)),
+    ('俀', (44, 139)),
+    ('俁', (44, 129)),
+    ('係', (10, 25)),
+    ('促', (10, 16)),
+    ('俄', (10, 24)),
+    ('俅', (44, 124)),
+    ('俇', (44, 136)),
+    ('俉', (44, 127)),
+    ('俊', (10, 20)),
+    ('俋', (44, 128)),
+    ('俍', (44, 123)),
+    ('俎', (10, 27)),
+    ('俏', (10, 14)),
+    ('俐', (10, 23)),
+    ('俑', (10, 13)),
+    ('俓', (44, 125)),
+    ('俔', (44, 130)),
+    ('俖', (44, 137)),
+    ('俗', (10, 21)),
+    ('俘', (10, 18)),
+    ('俙', (44, 132)),
+    ('俚', (10, 26)),
+    ('俛', (44, 135)),
+    ('俜', (44, 131)),
+    ('保', (10, 15)),
+    ('俞', (10, 28)),
+    ('俟', (10, 19)),
+    ('俠', (10, 12)),
+    ('信', (10, 8)),
+    ('俬', (44, 141)),
+    ('修', (12, 117)),
+    ('俯', (12, 95)),
+    ('俱', (12, 111)),
+    ('俳', (12, 116)),
+    ('俴', (47, 110)),
+    ('俵', (47, 109)),
+    ('俶', (47, 114)),
+    ('俷', (47, 115)),
+    ('俸', (12, 98)),
+    ('俺', (12, 107)),
+    ('俾', (12, 120)),
+    ('倀', (12, 108)),
+    ('倅', (47, 103)),
+    ('倆', (12, 101)),
+    ('倇', (47, 104)),
+    ('倉', (12, 122)),
+    ('個', (12, 113)),
+    ('倌', (12, 92)),
+    ('倍', (12, 93)),
+    ('倎', (47, 123)),
+    ('倏', (15, 93)),
+    ('們', (12, 106)),
+    ('倒', (12, 105)),
+    ('倓', (47, 105)),
+    ('倔', (12, 109)),
+    ('倕', (51, 27)),
+    ('倖', (12, 100)),
+    ('倗', (47, 116)),
+    ('倘', (12, 115)),
+    ('候', (12, 114)),
+    ('倚', (12, 104)),
+    ('倛', (47, 108)),
+    ('倜', (47, 117)),
+    ('倞', (47, 102)),
+    ('借', (12, 103)),
+    ('倠', (47, 118)),
+    ('倡', (12, 112)),
+    ('倢', (47, 106)),
+    ('倣', (12, 94)),
+    ('倥', (12, 97)),
+    ('倦', (12, 96)),
+    ('倧', (47, 119)),
+    ('倨', (12, 110)),
+    ('倩', (12, 99)),
+    ('倪', (12, 119)),
+    ('倫', (12, 121)),
+    ('倬', (47, 113)),
+    ('倭', (12, 118)),
+    ('倯', (47, 121)),
+    ('倰', (47, 107)),
+    ('倱', (47, 122)),
+    ('倳', (47, 111)),
+    ('倵', (47, 120)),
+    ('倷', (47, 112)),
+    ('值', (12, 102)),
+    ('偀', (51, 35)),
+    ('偁', (51, 23)),
+    ('偃', (15, 81)),
+    ('偅', (51, 28)),
+    ('偆', (51, 34)),
+    ('假', (15, 80)),
+    ('偈', (51, 21)),
+    ('偉', (15, 84)),
+    ('偊', (51, 25)),
+    ('偋', (51, 18)),
+    ('偌', (15, 82)),
+    ('偍', (51, 22)),
+    ('偎', (15, 87)),
+    ('偏', (15, 92)),
+    ('偑', (51, 39)),
+    ('偓', (51, 17)),
+    ('偕', (15, 88)),
+    ('偗', (51, 38)),
+    ('做', (15, 83)),
+    ('偛', (51, 24)),
+    ('停', (15, 79)),
+    ('偝', (51, 19)),
+    ('偞', (51, 15)),
+    ('偟', (51, 29)),
+    ('偠', (51, 16)),
+    ('偡', (51, 14)),
+    ('偢', (51, 26)),
+    ('偣', (51, 32)),
+    ('偤', (51, 33)),
+    ('健', (15, 85)),
+    ('偨', (55, 27)),
+    ('偩', (51, 30)),
+    ('偪', (51, 13)),
+    ('偫', (51, 31)),
+    ('偭', (15, 95)),
+    ('偮', (51, 36)),
+    ('偯', (15, 94)),
+    ('偰', (51, 12)),
+    ('偲', (51, 20)),
+    ('偳', (51, 37)),
+    ('側', (15, 90)),
+    ('偵', (15, 89)),
+    ('偶', (15, 86)),
+    ('偷', (15, 91)),
+    ('偺', (15, 77)),
+    ('偽', (15, 78)),
+    ('傀', (18, 102)),
+    ('傂', (55, 30)),
+    ('傃', (55, 23)),
+    ('傅', (18, 99)),
+    ('傇', (55, 31)),
+    ('傋', (55, 21)),
+    ('傌', (55, 24)),
+    ('傍', (18, 98)),
+    ('傎', (55, 25)),
+    ('傑', (18, 101)),
+    ('傒', (55, 29)),
+    ('傔', (55, 19)),
+    ('傕', (55, 18)),
+    ('傖', (18, 103)),
+    ('傘', (18, 104)),
+    ('備', (18, 100)),
+    ('傚', (18, 105)),
+    ('傛', (55, 17)),
+    ('傜', (55, 28)),
+    ('傝', (55, 26)),
+    ('傞', (55, 20)),
+    ('傢', (18, 97)),
+    ('傣', (55, 22)),
+    ('催', (21, 104)),
+    ('傭', (21, 98)),
+    ('傮', (59, 85)),
+    ('傯', (21, 107)),
+    ('傰', (59, 91)),
+    ('傱', (59, 94)),
+    ('傲', (21, 100)),
+    ('傳', (21, 101)),
+    ('傴', (59, 88)),
+    ('債', (21, 99)),
+    ('傶', (59, 97)),
+    ('傷', (21, 105)),
+    ('傸', (59, 98)),
+    ('傺', (59, 93)),
+    ('傻', (21, 106)),
+    ('傽', (59, 82)),
+    ('傾', (21, 103)),
+    ('傿', (59, 83)),
+    ('僁', (59, 92)),
+    ('僂', (59, 90)),
+    ('僄', (59, 86)),
+    ('僅', (21, 102)),
+    ('僆', (59, 84)),
+    ('僇', (21, 108)),
+    ('僈', (59, 89)),
+    ('僉', (59, 96)),
+    ('僊', (59, 87)),
+    ('僋', (59, 95)),
+    ('僎', (24, 84)),
+    ('像', (24, 81)),
+    ('僑', (24, 82)),
+    ('僓', (63, 151)),
+    ('僔', (63, 143)),
+    ('僕', (24, 80)),
+    ('僖', (24, 77)),
+    ('僗', (63, 144)),
+    ('僚', (24, 79)),
+    ('僛', (63, 147)),
+    ('僝', (63, 149)),
+    ('僠', (63, 156)),
+    ('僣', (63, 155)),
+    ('僤', (63, 150)),
+    ('僥', (24, 76)),
+    ('僦', (63, 142)),
+    ('僧', (24, 74)),
+    ('僨', (63, 145)),
+    ('僩', (24, 85)),
+    ('僪', (63, 148)),
+    ('僬', (63, 152)),
+    ('僭', (24, 78)),
+    ('僮', (24, 75)),
+    ('僯', (63, 154)),
+    ('僰', (63, 153)),
+    ('僱', (24, 83)),
+    ('僳', (63, 146)),
+    ('僵', (26, 150)),
+    ('僶', (67, 138)),
+    ('僸', (67, 135)),
+    ('價', (26, 151)),
+    ('僻', (26, 149)),
+    ('僽', (67, 142)),
+    ('僾', (67, 139)),
+    ('僿', (67, 132)),
+    ('儀', (26, 148)),
+    ('儂', (26, 152)),
+    ('儃', (67, 133)),
+    ('億', (26, 147)),
+    ('儅', (26, 155)),
+    ('儆', (67, 136)),
+    ('儇', (67, 137)),
+    ('儈', (26, 153)),
+    ('儉', (26, 154)),
+    ('儊', (67, 143)),
+    ('儋', (67, 140)),
+    ('儌', (67, 141)),
+    ('儐', (29, 72)),
+    ('儑', (71, 150)),
+    ('儒', (29, 69)),
+    ('儓', (71, 147)),
+    ('儔', (29, 71)),
+    ('儕', (29, 73)),
+    ('儗', (71, 148)),
+    ('儘', (29, 70)),
+    ('儚', (71, 149)),
+    ('儜', (71, 146)),
+    ('償', (31, 54)),
+    ('儠', (75, 91)),
+    ('儡', (31, 55)),
+    ('儢', (75, 89)),
+    ('儤', (75, 90)),
+    ('儥', (75, 88)),
+    ('儦', (75, 87)),
+    ('儩', (75, 92)),
+    ('優', (31, 53)),
+    ('儭', (78, 86)),
+    ('儮', (78, 87)),
+    ('儰', (67, 134)),
+    ('儱', (78, 85)),
+    ('儲', (31, 56)),
+    ('儳', (34, 31)),
+    ('儴', (80, 137)),
+    ('儵', (80, 138)),
+    ('儷', (35, 117)),
+    ('儸', (35, 118)),
+    ('儹', (84, 95)),
+    ('儺', (84, 94)),
+    ('儻', (36, 44)),
+    ('儼', (36, 43)),
+    ('儽', (86, 110)),
+    ('儿', (3, 9)),
+    ('兀', (3, 33)),
+    ('允', (3, 87)),
+    ('元', (3, 86)),
+    ('兄', (4, 19)),
+    ('充', (4, 18)),
+    ('兆', (4, 154)),
+    ('兇', (4, 153)),
+    ('先', (4, 155)),
+    ('光', (4, 152)),
+    ('克', (6, 10)),
+    ('兌', (6, 9)),
+    ('免', (6, 11)),
+    ('兒', (7, 126)),
+    ('兔', (7, 125)),
+    ('兕', (7, 127)),
+    ('兗', (10, 30)),
+    ('兙', (1, 25)),
+    ('党', (47, 124)),
+    ('兛', (1, 26)),
+    ('兜', (15, 96)),
+    ('兝', (1, 28)),
+    ('兞', (1, 27)),
+    ('兟', (55, 32)),
+    ('兡', (1, 29)),
+    ('兢', (24, 86)),
+    ('兣', (1, 30)),
+    ('入', (3, 10)),
+    ('內', (3, 88)),
+    ('全', (4, 156)),
+    ('兩', (7, 128)),
+    ('八', (3, 11)),
+    ('公', (3, 91)),
+    ('六', (3, 89)),
+    ('兮', (3, 90)),
+    ('共', (5, 0)),
+    ('兵', (6, 12)),
+    ('其', (7, 130)),
+    ('具', (7, 129)),
+    ('典', (7, 131)),
+    ('兼', (12, 123)),
+    ('冀', (29, 74)),
+    ('冇', (40, 14)),
+    ('冉', (4, 20)),
+    ('冊', (4, 21)),
+    ('再', (5, 1)),
+    ('冏', (41, 42)),
+    ('冑', (10, 32)),
+    ('冒', (10, 31)),
+    ('冓', (47, 126)),
+    ('冔', (47, 125)),
+    ('冕', (15, 97)),
+    ('冗', (3, 92)),
+    ('冘', (40, 21)),
+    ('冞', (42, 107)),
+    ('冠', (10, 33)),
+    ('冢', (12, 126)),
+    ('冤', (12, 124)),
+    ('冥', (12, 125)),
+    ('冪', (29, 75)),
+    ('冬', (4, 22)),
+    ('冰', (5, 2)),
+    ('冱', (40, 90)),
+    ('冶', (6, 13)),
+    ('冷', (6, 14)),
+    ('冹', (41, 43)),
+    ('冼', (42, 108)),
+    ('冽', (7, 132)),
+    ('冾', (42, 109)),
+    ('凄', (47, 128)),
+    ('凅', (47, 129)),
+    ('准', (12, 129)),
+    ('凈', (47, 130)),
+    ('凊', (47, 127)),
+    ('凋', (12, 130)),
+    ('凌', (12, 128)),
+    ('凍', (12, 127)),
+    ('凎', (47, 131)),
+    ('凐', (51, 40)),
+    ('凔', (55, 33)),
+    ('凗', (59, 99)),
+    ('凘', (64, 0)),
+    ('凜', (26, 156)),
+    ('凝', (29, 76)),
+    ('凞', (71, 151)),
+    ('几', (3, 12)),
+    ('凡', (3, 26)),
+    ('凰', (15, 98)),
+    ('凱', (18, 107)),
+    ('凳', (24, 87)),
+    ('凵', (40, 2)),
+    ('凶', (3, 93)),
+    ('凸', (4, 25)),
+    ('凹', (4, 23)),
+    ('出', (4, 24)),
+    ('函', (7, 133)),
+    ('刀', (3, 13)),
+    ('刁', (3, 14)),
+    ('刃', (3, 34)),
+    ('分', (3, 94)),
+    ('切', (3, 95)),
+    ('刈', (3, 96)),
+    ('刉', (40, 92)),
+    ('刊', (4, 26)),
+    ('刌', (40, 43)),
+    ('刎', (5, 6)),
+    ('刐', (40, 93)),
+    ('刑', (5, 4)),
+    ('划', (5, 5)),
+    ('刓', (40, 91)),
+    ('刖', (5, 7)),
+    ('列', (5, 3)),
+    ('刜', (41, 44)),
+    ('初', (9, 138)),
+    ('刞', (41, 45)),
+    ('刡', (41, 46)),
+    ('判', (6, 16)),
+    ('別', (6, 15)),
+    ('刨', (6, 19)),
+    ('利', (6, 17)),
+    ('刪', (6, 18)),
+    ('刮', (7, 139)),
+    ('到', (7, 138)),
+    ('刱', (42, 114)),
+    ('刲', (42, 111)),
+    ('刳', (42, 112)),
+    ('刵', (42, 110)),
+    ('制', (7, 140)),
+    ('刷', (7, 136)),
+    ('券', (7, 135)),
+    ('刺', (7, 137)),
+    ('刻', (7, 134)),
+    ('剁', (7, 141)),
+    ('剃', (10, 35)),
+    ('剄', (44, 142)),
+    ('剆', (42, 113)),
+    ('則', (10, 40)),
+    ('剉', (44, 143)),
+    ('削', (10, 36)),
+    ('剋', (10, 39)),
+    ('剌', (10, 38)),
+    ('前', (10, 37)),
+    ('剎', (10, 34)),
+    ('剒', (47, 134)),
+    ('剔', (12, 133)),
+    ('剕', (47, 137)),
+    ('剖', (12, 131)),
+    ('剚', (47, 133)),
+    ('剛', (12, 134)),
+    ('剜', (12, 132)),
+    ('剝', (12, 135)),
+    ('剞', (47, 135)),
+    ('剟', (47, 136)),
+    ('剡', (47, 132)),
+    ('剢', (47, 138)),
+    ('剩', (18, 111)),
+    ('剪', (15, 99)),
+    ('剫', (51, 41)),
+    ('剬', (51, 43)),
+    ('剭', (51, 42)),
+    ('剮', (51, 44)),
+    ('副', (15, 100)),
+    ('割', (18, 108)),
+    ('剴', (18, 109)),
+    ('創', (18, 110)),
+    ('剷', (21, 110)),
+    ('剸', (59, 101)),
+    ('剺', (59, 100)),
+    ('剻', (59, 102)),
+    ('剼', (59, 103)),
+    ('剽', (21, 111)),
+    ('剿', (21, 109)),
+    ('劀', (64, 1)),
+    ('劁', (64, 2)),
+    ('劂', (24, 89)),
+    ('劃', (24, 88)),
+    ('劄', (66, 26)),
+    ('劇', (27, 0)),
+    ('劈', (27, 1)),
+    ('劉', (27, 2)),
+    ('劊', (27, 4)),
+    ('劋', (67, 144)),
+    ('劌', (67, 145)),
+    ('劍', (27, 3)),
+    ('劑', (29, 77)),
+    ('劓', (29, 78)),
+    ('劖', (80, 139)),
+    ('劗', (84, 97)),
+    ('劘', (84, 96)),
+    ('劙', (86, 111)),
+    ('力', (3, 15)),
+    ('功', (4, 28)),
+    ('加', (4, 27)),
+    ('劣', (5, 8)),
+    ('劦', (40, 94)),
+    ('助', (6, 21)),
+    ('努', (6, 22)),
+    ('劫', (6, 20)),
+    ('劬', (6, 23)),
+    ('劭', (41, 47)),
+    ('劮', (41, 48)),
+    ('劻', (7, 143)),
+    ('劼', (42, 115)),
+    ('劾', (7, 142)),
+    ('勀', (44, 144)),
+    ('勁', (10, 44)),
+    ('勂', (44, 145)),
+    ('勃', (10, 43)),
+    ('勇', (10, 41)),
+    ('勉', (10, 42)),
+    ('勍', (47, 139)),
+    ('勒', (15, 101)),
+    ('勓', (51, 46)),
+    ('動', (15, 104)),
+    ('勖', (51, 45)),
+    ('勗', (16, 131)),
+    ('勘', (15, 103)),
+    ('務', (15, 102)),
+    ('勛', (18, 114)),
+    ('勝', (18, 113)),
+    ('勞', (18, 112)),
+    ('募', (21, 112)),
+    ('勢', (21, 115)),
+    ('勣', (21, 116)),
+    ('勤', (21, 114)),
+    ('勦', (21, 113)),
+    ('勩', (64, 3)),
+    ('勫', (64, 4)),
+    ('勯', (67, 147)),
+    ('勰', (27, 5)),
+    ('勱', (67, 146)),
+    ('勳', (29, 79)),
+    ('勴', (75, 93)),
+    ('勵', (31, 57)),
+    ('勷', (80, 140)),
+    ('勸', (35, 21)),
+    ('勺', (3, 35)),
+    ('勻', (3, 97)),
+    ('勼', (40, 22)),
+    ('勾', (3, 98)),
+    ('勿', (3, 99)),
+    ('包', (4, 29)),
+    ('匆', (4, 30)),
+    ('匈', (5, 9)),
+    ('匉', (41, 49)),
+    ('匊', (42, 116)),
+    ('匋', (42, 117)),
+    ('匍', (10, 45)),
+    ('匎', (47, 140)),
+    ('匏', (15, 106)),
+    ('匐', (15, 105)),
+    ('匑', (55, 35)),
+    ('匒', (55, 34)),
+    ('匕', (3, 16)),
+    ('化', (3, 100)),
+    ('北', (4, 31)),
+    ('匙', (15, 107)),
+    ('匚', (40, 3)),
+    ('匜', (40, 44)),
+    ('匝', (4, 32)),
+    ('匟', (40, 96)),
+    ('匠', (5, 11)),
+    ('匡', (5, 10)),
+    ('匢', (40, 95)),
+    ('匣', (6, 24)),
+    ('匪', (12, 136)),
+    ('匭', (51, 47)),
+    ('匯', (21, 117)),
+    ('匰', (64, 5)),
+    ('匱', (24, 90)),
+    ('匴', (71, 152)),
+    ('匷', (82, 155)),
+    ('匹', (3, 101)),
+    ('匼', (42, 118)),
+    ('匽', (44, 146)),
+    ('匾', (15, 110)),
+    ('匿', (15, 108)),
+    ('區', (15, 109)),
+    ('十', (3, 17)),
+    ('千', (3, 36)),
+    ('卄', (1, 107)),
+    ('卅', (3, 104)),
+    ('升', (3, 103)),
+    ('午', (3, 102)),
+    ('卉', (4, 35)),
+    ('半', (4, 34)),
+    ('卌', (40, 45)),
+    ('卍', (40, 97)),
+    ('卑', (7, 147)),
+    ('卒', (7, 144)),
+    ('卓', (7, 146)),
+    ('協', (7, 145)),
+    ('南', (10, 46)),
+    ('博', (18, 115)),
+    ('卜', (3, 18)),
+    ('卞', (3, 105)),
+    ('占', (4, 37)),
+    ('卡', (4, 36)),
+    ('卣', (41, 50)),
+    ('卦', (7, 148)),
+    ('卬', (40, 23)),
+    ('卮', (4, 39)),
+    ('卯', (4, 38)),
+    ('印', (5, 12)),
+    ('危', (5, 13)),
+    ('卲', (41, 51)),
+    ('即', (6, 25)),
+    ('卵', (6, 26)),
+    ('卷', (7, 149)),
+    ('卸', (7, 150)),
+    ('卹', (7, 151)),
+    ('卻', (10, 47)),
+    ('卼', (44, 147)),
+    ('卿', (12, 137)),
+    ('厂', (40, 4)),
+    ('厄', (3, 106)),
+    ('厊', (40, 98)),
+    ('厎', (41, 52)),
+    ('厏', (41, 53)),
+    ('厒', (42, 119)),
+    ('厔', (42, 120)),
+    ('厖', (44, 149)),
+    ('厗', (44, 148)),
+    ('厘', (44, 151)),
+    ('厙', (44, 150)),
+    ('厚', (10, 48)),
+    ('厜', (51, 48)),
+    ('厝', (12, 139)),
+    ('厞', (47, 141)),
+    ('原', (12, 138)),
+    ('厤', (55, 36)),
+    ('厥', (18, 116)),
+    ('厧', (55, 37)),
+    ('厬', (64, 6)),
+    ('厭', (24, 91)),
+    ('厲', (27, 6)),
+    ('厴', (80, 141)),
+    ('厹', (40, 24)),
+    ('去', (4, 40)),
+    ('參', (15, 111)),
+    ('又', (3, 19)),
+    ('叉', (3, 37)),
+    ('及', (3, 108)),
+    ('友', (3, 107)),
+    ('反', (3, 109)),
+    ('叔', (7, 153)),
+    ('取', (7, 152)),
+    ('受', (7, 154)),
+    ('叛', (10, 49)),
+    ('叟', (12, 140)),
+    ('叡', (71, 153)),
+    ('叢', (33, 15)),
+    ('口', (3, 38)),
+    ('古', (4, 42)),
+    ('句', (4, 57)),
+    ('另', (4, 52)),
+    ('叨', (4, 47)),
+    ('叩', (4, 46)),
+    ('只', (4, 53)),
+    ('叫', (4, 51)),
+    ('召', (4, 44)),
+    ('叭', (4, 58)),
+    ('叮', (4, 45)),
+    ('可', (4, 41)),
+    ('台', (4, 56)),
+    ('叱', (4, 55)),
+    ('史', (4, 54)),
+    ('右', (4, 43)),
+    ('叵', (4, 50)),
+    ('司', (4, 49)),
+    ('叻', (4, 59)),
+    ('叼', (4, 48)),
+    ('吁', (5, 19)),
+    ('吃', (5, 25)),
+    ('各', (5, 21)),
+    ('吆', (5, 27)),
+    ('吇', (40, 99)),
+    ('合', (5, 24)),
+    ('吉', (5, 14)),
+    ('吊', (5, 17)),
+    ('吋', (5, 20)),
+    ('同', (5, 16)),
+    ('名', (5, 23)),
+    ('后', (5, 26)),
+    ('吏', (5, 15)),
+    ('吐', (5, 18)),
+    ('向', (5, 22)),
+    ('吒', (5, 28)),
+    ('吘', (41, 62)),
+    ('吙', (41, 59)),
+    ('君', (6, 39)),
+    ('吜', (41, 60)),
+    ('吝', (6, 27)),
+    ('吞', (6, 29)),
+    ('吟', (6, 53)),
+    ('吠', (6, 48)),
+    ('吤', (41, 67)),
+    ('吥', (41, 61)),
+    ('否', (6, 31)),
+    ('吧', (6, 33)),
+    ('吨', (41, 66)),
+    ('吩', (6, 40)),
+    ('吪', (41, 56)),
+    ('含', (6, 52)),
+    ('听', (6, 54)),
+    ('吭', (6, 28)),
+    ('吮', (6, 45)),
+    ('吰', (41, 54)),
+    ('吱', (6, 51)),
+    ('吳', (6, 36)),
+    ('吵', (6, 46)),
+    ('吶', (6, 47)),
+    ('吷', (41, 55)),
+    ('吸', (6, 44)),
+    ('吹', (6, 42)),
+    ('吻', (6, 43)),
+    ('吼', (6, 49)),
+    ('吽', (41, 63)),
+    ('吾', (6, 30)),
+    ('呀', (6, 50)),
+    ('呁', (41, 65)),
+    ('呂', (6, 38)),
+    ('呃', (6, 35)),
+    ('呅', (41, 58)),
+    ('呆', (6, 34)),
+    ('呇', (41, 68)),
+    ('呈', (6, 37)),
+    ('告', (6, 41)),
+    ('呎', (6, 32)),
+    ('呏', (41, 64)),
+    ('呔', (41, 57)),
+    ('呠', (42, 137)),
+    ('呡', (42, 136)),
+    ('呢', (8, 15)),
+    ('呣', (42, 139)),
+    ('呤', (42, 141)),
+    ('呥', (42, 130)),
+    ('呦', (42, 133)),
+    ('呧', (42, 140)),
+    ('周', (8, 16)),
+    ('呫', (42, 127)),
+    ('呬', (42, 131)),
+    ('呯', (42, 135)),
+    ('呰', (45, 9)),
+    ('呱', (8, 11)),
+    ('呲', (45, 13)),
+    ('味', (7, 155)),
+    ('呴', (42, 132)),
+    ('呵', (7, 156)),
+    ('呶', (8, 12)),
+    ('呷', (8, 5)),
+    ('呸', (8, 1)),
+    ('呺', (42, 128)),
+    ('呻', (8, 4)),
+    ('呼', (8, 9)),
+    ('命', (8, 18)),
+    ('呾', (42, 129)),
+    ('呿', (42, 122)),
+    ('咀', (8, 3)),
+    ('咁', (42, 123)),
+    ('咂', (42, 125)),
+    ('咄', (8, 6)),
+    ('咆', (8, 8)),
+    ('咇', (42, 121)),
+    ('咈', (42, 126)),
+    ('咋', (8, 17)),
+    ('和', (8, 13)),
+    ('咍', (42, 134)),
+    ('咎', (8, 19)),
+    ('咐', (8, 10)),
+    ('咑', (42, 124)),
+    ('咒', (8, 7)),
+    ('咕', (8, 2)),
+    ('咖', (8, 0)),
+    ('咘', (42, 138)),
+    ('咚', (8, 14)),
+    ('咠', (45, 8)),
+    ('咡', (44, 153)),
+    ('咢', (45, 11)),
+    ('咥', (44, 155)),
+    ('咦', (10, 56)),
+    ('咧', (10, 70)),
+    ('咨', (10, 52)),
+    ('咩', (10, 69)),
+    ('咪', (10, 61)),
+    ('咫', (10, 66)),
+    ('咬', (10, 50)),
+    ('咭', (44, 154)),
+    ('咮', (45, 3)),
+    ('咯', (10, 65)),
+    ('咰', (45, 15)),
+    ('咱', (10, 67)),
+    ('咳', (10, 57)),
+    ('咶', (45, 5)),
+    ('咷', (45, 2)),
+    ('咸', (10, 55)),
+    ('咺', (44, 152)),
+    ('咻', (10, 68)),
+    ('咼', (45, 10)),
+    ('咽', (10, 60)),
+    ('咾', (45, 12)),
+    ('咿', (10, 71)),
+    ('哀', (10, 51)),
+    ('品', (10, 62)),
+    ('哂', (10, 59)),
+    ('哃', (45, 0)),
+    ('哄', (10, 63)),
+    ('哅', (45, 6)),
+    ('哆', (45, 7)),
+    ('哇', (10, 58)),
+    ('哈', (10, 64)),
+    ('哉', (10, 54)),
+    ('哎', (10, 53)),
+    ('哏', (44, 156)),
+    ('哖', (45, 4)),
+    ('哞', (45, 14)),
+    ('哠', (48, 4)),
+    ('員', (12, 153)),
+    ('哢', (47, 143)),
+    ('哤', (47, 148)),
+    ('哥', (12, 146)),
+    ('哦', (13, 0)),
+    ('哧', (47, 146)),
+    ('哨', (12, 141)),
+    ('哩', (12, 151)),
+    ('哪', (12, 156)),
+    ('哫', (47, 153)),
+    ('哭', (12, 152)),
+    ('哮', (12, 155)),
+    ('哱', (47, 156)),
+    ('哲', (12, 147)),
+    ('哳', (47, 147)),
+    ('哷', (48, 2)),
+    ('哸', (48, 3)),
+    ('哺', (12, 149)),
+    ('哻', (48, 1)),
+    ('哼', (12, 145)),
+    ('哽', (13, 3)),
+    ('哿', (47, 150)),
+    ('唁', (12, 143)),
+    ('唃', (48, 6)),
+    ('唄', (47, 151)),
+    ('唅', (47, 155)),
+    ('唆', (12, 148)),
+    ('唇', (13, 2)),
+    ('唈', (47, 152)),
+    ('唉', (12, 154)),
+    ('唊', (48, 0)),
+    ('唋', (48, 7)),
+    ('唌', (51, 63)),
+    ('唎', (48, 5)),
+    ('唏', (13, 4)),
+    ('唐', (12, 142)),
+    ('唑', (47, 154)),
+    ('唒', (47, 145)),
+    ('唔', (12, 150)),
+    ('唗', (47, 144)),
+    ('唚', (47, 149)),
+    ('唦', (47, 142)),
+    ('唧', (13, 1)),
+    ('唪', (51, 55)),
+    ('唬', (15, 130)),
+    ('唭', (51, 69)),
+    ('售', (15, 128)),
+    ('唯', (15, 125)),
+    ('唰', (51, 60)),
+    ('唱', (15, 121)),
+    ('唲', (51, 64)),
+    ('唳', (15, 132)),
+    ('唴', (51, 54)),
+    ('唵', (51, 59)),
+    ('唶', (51, 58)),
+    ('唷', (12, 144)),
+    ('唸', (15, 127)),
+    ('唹', (51, 67)),
+    ('唻', (51, 70)),
+    ('唼', (51, 51)),
+    ('唾', (18, 134)),
+    ('啀', (51, 71)),
+    ('啁', (15, 133)),
+    ('啃', (15, 119)),
+    ('啄', (15, 116)),
+    ('啅', (51, 62)),
+    ('商', (15, 113)),
+    ('啈', (51, 68)),
+    ('啊', (15, 120)),
+    ('啋', (51, 72)),
+    ('啍', (51, 52)),
+    ('啎', (51, 66)),
+    ('問', (15, 123)),
+    ('啐', (51, 53)),
+    ('啑', (51, 56)),
+    ('啒', (51, 61)),
+    ('啕', (15, 124)),
+    ('啖', (15, 122)),
+    ('啗', (15, 134)),
+    ('啜', (15, 129)),
+    ('啞', (15, 117)),
+    ('啟', (16, 112)),
+    ('啡', (15, 118)),
+    ('啢', (51, 57)),
+    ('啣', (15, 131)),
+    ('啤', (15, 126)),
+    ('啥', (51, 65)),
+    ('啦', (15, 115)),
+    ('啪', (15, 114)),
+    ('啵', (51, 49)),
+    ('啶', (51, 50)),
+    ('啷', (55, 42)),
+    ('啻', (18, 117)),
+    ('啼', (18, 120)),
+    ('啽', (55, 53)),
+    ('啾', (18, 140)),
+    ('啿', (55, 56)),
+    ('喀', (18, 118)),
+    ('喁', (55, 49)),
+    ('喂', (18, 124)),
+    ('喃', (18, 130)),
+    ('善', (20, 91)),
+    ('喇', (18, 128)),
+    ('喈', (55, 46)),
+    ('喉', (18, 141)),
+    ('喊', (18, 121)),
+    ('喋', (18, 129)),
+    ('喌', (55, 54)),
+    ('喍', (59, 117)),
+    ('喎', (55, 59)),
+    ('喏', (55, 47)),
+    ('喑', (55, 38)),
+    ('喒', (55, 51)),
+    ('喓', (55, 45)),
+    ('喔', (18, 127)),
+    ('喕', (55, 57)),
+    ('喘', (18, 123)),
+    ('喙', (18, 143)),
+    ('喚', (18, 136)),
+    ('喜', (18, 125)),
+    ('喝', (18, 122)),
+    ('喟', (18, 133)),
+    ('喡', (55, 58)),
+    ('喢', (55, 44)),
+    ('喣', (55, 50)),
+    ('喤', (55, 52)),
+    ('喥', (55, 40)),
+    ('喦', (55, 55)),
+    ('喧', (18, 119)),
+    ('喨', (55, 39)),
+    ('喪', (18, 126)),
+    ('喫', (18, 142)),
+    ('喬', (18, 138)),
+    ('喭', (55, 41)),
+    ('單', (18, 132)),
+    ('喱', (18, 139)),
+    ('喲', (18, 135)),
+    ('喳', (18, 131)),
+    ('喵', (55, 48)),
+    ('喻', (18, 137)),
+    ('喿', (59, 115)),
+    ('嗀', (59, 111)),
+    ('嗂', (59, 126)),
+    ('嗃', (59, 104)),
+    ('嗄', (59, 113)),
+    ('嗅', (21, 131)),
+    ('嗆', (21, 132)),
+    ('嗇', (21, 124)),
+    ('嗈', (59, 122)),
+    ('嗉', (21, 134)),
+    ('嗊', (59, 109)),
+    ('嗋', (59, 108)),
+    ('嗌', (59, 106)),
+    ('嗍', (59, 124)),
+    ('嗎', (21, 122)),
+    ('嗏', (59, 118)),
+    ('嗐', (59, 107)),
+    ('嗑', (21, 125)),
+    ('嗒', (59, 116)),
+    ('嗓', (21, 120)),
+    ('嗔', (59, 112)),
+    ('嗕', (59, 119)),
+    ('嗖', (59, 121)),
+    ('嗙', (59, 125)),
+    ('嗚', (21, 129)),
+    ('嗛', (59, 105)),
+    ('嗜', (21, 123)),
+    ('嗝', (59, 110)),
+    ('嗟', (21, 118)),
+    ('嗡', (21, 130)),
+    ('嗢', (59, 120)),
+    ('嗣', (21, 126)),
+    ('嗤', (21, 127)),
+    ('嗥', (21, 133)),
+    ('嗦', (21, 121)),
+    ('嗧', (1, 31)),
+    ('嗨', (21, 119)),
+    ('嗩', (59, 114)),
+    ('嗯', (21, 128)),
+    ('嗲', (59, 123)),
+    ('嗶', (24, 107)),
+    ('嗷', (24, 102)),
+    ('嗹', (64, 21)),
+    ('嗺', (64, 17)),
+    ('嗼', (64, 11)),
+    ('嗽', (24, 96)),
+    ('嗾', (24, 92)),
+    ('嗿', (64, 20)),
+    ('嘀', (24, 93)),
+    ('嘁', (64, 14)),
+    ('嘂', (64, 16)),
+    ('嘄', (64, 19)),
+    ('嘆', (24, 98)),
+    ('嘈', (24, 105)),
+    ('嘉', (24, 99)),
+    ('嘌', (64, 9)),
+    ('嘍', (24, 100)),
+    ('嘎', (24, 101)),
+    ('嘏', (64, 12)),
+    ('嘐', (24, 106)),
+    ('嘒', (64, 10)),
+    ('嘓', (64, 15)),
+    ('嘔', (24, 97)),
+    ('嘕', (64, 8)),
+    ('嘖', (24, 103)),
+    ('嘗', (24, 95)),
+    ('嘛', (24, 94)),
+    ('嘜', (64, 13)),
+    ('嘝', (64, 18)),
+    ('嘟', (24, 104)),
+    ('嘧', (64, 7)),
+    ('嘩', (27, 13)),
+    ('嘪', (68, 7)),
+    ('嘬', (68, 4)),
+    ('嘮', (27, 7)),
+    ('嘯', (27, 19)),
+    ('嘰', (27, 20)),
+    ('嘲', (27, 10)),
+    ('嘳', (68, 2)),
+    ('嘴', (27, 12)),
+    ('嘵', (67, 151)),
+    ('嘶', (27, 18)),
+    ('嘸', (68, 6)),
+    ('嘹', (27, 9)),
+    ('嘺', (68, 8)),
+    ('嘻', (27, 8)),
+    ('嘽', (68, 3)),
+    ('嘾', (68, 5)),
+    ('嘿', (27, 11)),
+    ('噀', (68, 1)),
+    ('噁', (67, 152)),
+    ('噂', (67, 149)),
+    ('噅', (55, 43)),
+    ('噆', (67, 155)),
+    ('噈', (67, 148)),
+    ('噉', (67, 154)),
+    ('噊', (67, 153)),
+    ('噌', (67, 150)),
+    ('噎', (27, 15)),
+    ('噓', (27, 14)),
+    ('噗', (27, 16)),
+    ('噘', (67, 156)),
+    ('噙', (29, 80)),
+    ('噚', (68, 0)),
+    ('噞', (72, 5)),
+    ('噠', (71, 155)),
+    ('噢', (29, 92)),
+    ('噣', (72, 2)),
+    ('噤', (29, 84)),
+    ('噥', (29, 88)),
+    ('噦', (72, 1)),
+    ('器', (29, 87)),
+    ('噩', (29, 83)),
+    ('噪', (29, 86)),
+    ('噫', (29, 81)),
+    ('噬', (29, 91)),
+    ('噭', (72, 3)),
+    ('噮', (71, 156)),
+    ('噯', (29, 90)),
+    ('噰', (71, 154)),
+    ('噱', (29, 89)),
+    ('噲', (72, 4)),
+    ('噳', (72, 0)),
+    ('噴', (27, 17)),
+    ('噶', (29, 93)),
+    ('噷', (72, 6)),
+    ('噸', (29, 85)),
+    ('噹', (29, 82)),
+    ('噾', (75, 100)),
+    ('噿', (75, 102)),
+    ('嚀', (31, 59)),
+    ('嚁', (75, 103)),
+    ('嚂', (75, 101)),
+    ('嚃', (75, 99)),
+    ('嚄', (75, 98)),
+    ('嚅', (31, 61)),
+    ('嚆', (75, 97)),
+    ('嚇', (31, 62)),
+    ('嚌', (75, 95)),
+    ('嚍', (75, 96)),
+    ('嚎', (31, 58)),
+    ('嚏', (31, 63)),
+    ('嚐', (31, 60)),
+    ('嚓', (75, 94)),
+    ('嚕', (33, 16)),
+    ('嚗', (78, 90)),
+    ('嚘', (78, 88)),
+    ('嚙', (78, 93)),
+    ('嚚', (78, 91)),
+    ('嚜', (78, 89)),
+    ('嚝', (78, 92)),
+    ('嚥', (34, 32)),
+    ('嚦', (80, 144)),
+    ('嚧', (80, 145)),
+    ('嚨', (35, 22)),
+    ('嚪', (80, 146)),
+    ('嚫', (80, 142)),
+    ('嚬', (80, 147)),
+    ('嚭', (80, 143)),
+    ('嚮', (33, 17)),
+    ('嚲', (82, 156)),
+    ('嚳', (83, 1)),
+    ('嚴', (35, 25)),
+    ('嚵', (83, 0)),
+    ('嚶', (35, 24)),
+    ('嚷', (35, 23)),
+    ('嚼', (35, 26)),
+    ('嚽', (84, 99)),
+    ('嚾', (84, 100)),
+    ('囀', (35, 120)),
+    ('囁', (35, 119)),
+    ('囂', (35, 121)),
+    ('囃', (84, 98)),
+    ('囅', (85, 118)),
+    ('囆', (85, 117)),
+    ('囈', (36, 45)),
+    ('囉', (36, 47)),
+    ('囊', (36, 46)),
+    ('囋', (85, 119)),
+    ('囌', (36, 102)),
+    ('囍', (87, 68)),
+    ('囑', (36, 143)),
+    ('囓', (87, 67)),
+    ('囔', (87, 140)),
+    ('囗', (40, 9)),
+    ('囚', (4, 61)),
+    ('四', (4, 60)),
+    ('囝', (5, 31)),
+    ('回', (5, 30)),
+    ('囟', (40, 101)),
+    ('因', (5, 29)),
+    ('囡', (40, 100)),
+    ('囤', (6, 57)),
+    ('囥', (41, 71)),
+    ('囧', (41, 70)),
+    ('囪', (6, 55)),
+    ('囫', (6, 58)),
+    ('囮', (41, 69)),
+    ('困', (6, 56)),
+    ('囷', (42, 142)),
+    ('囹', (42, 143)),
+    ('固', (8, 20)),
+    ('囿', (10, 72)),
+    ('圁', (48, 8)),
+    ('圂', (48, 9)),
+    ('圃', (13, 5)),
+    ('圄', (13, 6)),
+    ('圇', (51, 74)),
+    ('圈', (15, 135)),
+    ('圉', (15, 137)),
+    ('圊', (51, 73)),
+    ('國', (15, 136)),
+    ('圌', (55, 60)),
+    ('圍', (18, 144)),
+    ('園', (21, 135)),
+    ('圓', (21, 136)),
+    ('圔', (59, 127)),
+    ('圖', (24, 109)),
+    ('團', (24, 108)),
+    ('圚', (68, 9)),
+    ('圛', (72, 8)),
+    ('圜', (72, 7)),
+    ('圞', (88, 43)),
+    ('土', (3, 39)),
+    ('圠', (40, 25)),
+    ('圢', (40, 46)),
+    ('圣', (40, 47)),
+    ('在', (5, 34)),
+    ('圩', (5, 38)),
+    ('圪', (40, 103)),
+    ('圬', (5, 36)),
+    ('圭', (5, 35)),
+    ('圮', (40, 102)),
+    ('圯', (5, 37)),
+    ('地', (5, 33)),
+    ('圳', (5, 32)),
+    ('圴', (40, 104)),
+    ('圻', (6, 68)),
+    ('圾', (6, 65)),
+    ('址', (6, 61)),
+    ('坁', (41, 72)),
+    ('坅', (41, 73)),
+    ('均', (6, 63)),
+    ('坉', (41, 75)),
+    ('坊', (6, 59)),
+    ('坋', (41, 76)),
+    ('坌', (41, 74)),
+    ('坍', (6, 62)),
+    ('坎', (6, 64)),
+    ('坏', (6, 67)),
+    ('坐', (6, 66)),
+    ('坑', (6, 60)),
+    ('坒', (41, 77)),
+    ('坡', (8, 25)),
+    ('坢', (42, 156)),
+    ('坤', (8, 27)),
+    ('坦', (8, 26)),
+    ('坨', (43, 0)),
+    ('坩', (8, 24)),
+    ('坪', (8, 23)),
+    ('坫', (42, 147)),
+    ('坭', (42, 146)),
+    ('坯', (42, 144)),
+    ('坰', (42, 149)),
+    ('坱', (42, 148)),
+    ('坲', (42, 145)),
+    ('坳', (42, 154)),
+    ('坴', (42, 155)),
+    ('坵', (42, 152)),
+    ('坶', (42, 150)),
+    ('坷', (8, 22)),
+    ('坻', (42, 153)),
+    ('坼', (8, 28)),
+    ('坽', (43, 1)),
+    ('垀', (42, 151)),
+    ('垂', (10, 73)),
+    ('垃', (8, 21)),
+    ('型', (10, 74)),
+    ('垌', (45, 20)),
+    ('垏', (45, 26)),
+    ('垓', (10, 80)),
+    ('垔', (45, 24)),
+    ('垕', (45, 30)),
+    ('垗', (45, 21)),
+    ('垘', (45, 25)),
+    ('垙', (45, 27)),
+    ('垚', (45, 29)),
+    ('垛', (45, 23)),
+    ('垝', (45, 22)),
+    ('垞', (45, 17)),
+    ('垟', (45, 18)),
+    ('垠', (10, 75)),
+    ('垢', (10, 77)),
+    ('垣', (10, 76)),
+    ('垤', (45, 19)),
+    ('垥', (45, 28)),
+    ('垮', (10, 79)),
+    ('垵', (45, 16)),
+    ('垶', (48, 19)),
+    ('垸', (48, 18)),
+    ('垹', (48, 23)),
+    ('垺', (48, 14)),
+    ('垼', (48, 17)),
+    ('垽', (48, 16)),
+    ('垿', (48, 20)),
+    ('埁', (48, 24)),
+    ('埂', (13, 7)),
+    ('埃', (13, 10)),
+    ('埆', (48, 15)),
+    ('埇', (48, 21)),
+    ('埋', (13, 9)),
+    ('埌', (48, 10)),
+    ('城', (10, 78)),
+    ('埏', (51, 88)),
+    ('埐', (48, 22)),
+    ('埒', (48, 13)),
+    ('埔', (13, 8)),
+    ('埕', (48, 12)),
+    ('埜', (51, 79)),
+    ('域', (15, 138)),
+    ('埠', (15, 142)),
+    ('埡', (51, 95)),
+    ('埢', (51, 77)),
+    ('埣', (51, 91)),
+    ('埤', (15, 143)),
+    ('埥', (51, 93)),
+    ('埧', (51, 99)),
+    ('埩', (51, 103)),
+    ('埬', (51, 94)),
+    ('埭', (51, 82)),
+    ('埮', (51, 90)),
+    ('埰', (51, 104)),
+    ('埱', (51, 102)),
+    ('埲', (51, 92)),
+    ('埳', (51, 87)),
+    ('埴', (51, 80)),
+    ('埵', (55, 68)),
+    ('埶', (51, 78)),
+    ('執', (15, 147)),
+    ('埸', (51, 85)),
+    ('培', (15, 148)),
+    ('基', (15, 144)),
+    ('埻', (51, 75)),
+    ('埼', (51, 97)),
+    ('埽', (51, 83)),
+    ('堀', (51, 81)),
+    ('堁', (51, 100)),
+    ('堂', (15, 145)),
+    ('堄', (51, 106)),
+    ('堅', (15, 139)),
+    ('堆', (15, 141)),
+    ('堇', (51, 89)),
+    ('堈', (51, 84)),
+    ('堉', (13, 11)),
+    ('堊', (15, 140)),
+    ('堋', (51, 86)),
+    ('堌', (51, 101)),
+    ('堍', (51, 105)),
+    ('堎', (51, 96)),
+    ('堐', (51, 98)),
+    ('堔', (51, 76)),
+    ('堙', (55, 63)),
+    ('堛', (55, 72)),
+    ('堜', (55, 71)),
+    ('堝', (18, 152)),
+    ('堞', (55, 64)),
+    ('堠', (18, 153)),
+    ('堡', (18, 151)),
+    ('堣', (55, 66)),
+    ('堤', (18, 148)),
+    ('堥', (55, 70)),
+    ('堧', (55, 65)),
+    ('堨', (55, 67)),
+    ('堩', (55, 61)),
+    ('堪', (18, 146)),
+    ('堬', (55, 80)),
+    ('堭', (55, 79)),
+    ('堮', (55, 76)),
+    ('堯', (18, 145)),
+    ('堰', (18, 149)),
+    ('報', (18, 150)),
+    ('堲', (48, 11)),
+    ('堳', (55, 73)),
+    ('場', (18, 147)),
+    ('堵', (15, 146)),
+    ('堶', (55, 75)),
+    ('堷', (55, 62)),
+    ('堸', (55, 78)),
+    ('堹', (55, 77)),
+    ('堻', (55, 81)),
+    ('堽', (59, 141)),
+    ('堿', (55, 74)),
+    ('塈', (55, 69)),
+    ('塉', (59, 133)),
+    ('塊', (21, 146)),
+    ('塋', (21, 149)),
+    ('塌', (21, 144)),
+    ('塍', (59, 132)),
+    ('塎', (59, 136)),
+    ('塏', (59, 131)),
+    ('塑', (21, 138)),
+    ('塒', (21, 148)),
+    ('塓', (59, 128)),
+    ('塔', (21, 142)),
+    ('塕', (59, 135)),
+    ('塗', (21, 140)),
+    ('塘', (21, 139)),
+    ('塙', (59, 138)),
+    ('塚', (21, 141)),
+    ('塛', (59, 140)),
+    ('塝', (59, 137)),
+    ('塞', (21, 137)),
+    ('塢', (21, 147)),
+    ('塣', (59, 142)),
+    ('塤', (59, 130)),
+    ('塥', (59, 139)),
+    ('塨', (59, 129)),
+    ('填', (21, 143)),
+    ('塭', (21, 145)),
+    ('塯', (59, 134)),
+    ('塱', (59, 143)),
+    ('塴', (64, 29)),
+    ('塵', (24, 110)),
+    ('塶', (64, 35)),
+    ('塹', (24, 115)),
+    ('塺', (64, 31)),
+    ('塻', (64, 38)),
+    ('塼', (64, 23)),
+    ('塽', (24, 117)),
+    ('塾', (24, 111)),
+    ('塿', (64, 28)),
+    ('墀', (27, 21)),
+    ('墁', (64, 27)),
+    ('墂', (64, 36)),
+    ('境', (24, 112)),
+    ('墅', (24, 116)),
+    ('墆', (64, 26)),
+    ('墇', (64, 32)),
+    ('墈', (64, 37)),
+    ('墉', (64, 22)),
+    ('墊', (24, 114)),
+    ('墋', (64, 30)),
+    ('墎', (64, 34)),
+    ('墏', (64, 40)),
+    ('墐', (64, 24)),
+    ('墑', (64, 33)),
+    ('墓', (24, 113)),
+    ('墔', (64, 39)),
+    ('墘', (64, 25)),
+    ('墜', (27, 25)),
+    ('墝', (68, 11)),
+    ('增', (27, 23)),
+    ('墟', (27, 22)),
+    ('墠', (68, 13)),
+    ('墡', (68, 18)),
+    ('墣', (68, 14)),
+    ('墥', (68, 17)),
+    ('墦', (27, 28)),
+    ('墨', (29, 67)),
+    ('墩', (27, 27)),
+    ('墫', (68, 10)),
+    ('墬', (68, 16)),
+    ('墮', (27, 26)),
+    ('墯', (68, 15)),
+    ('墱', (68, 12)),
+    ('墳', (27, 24)),
+    ('墺', (72, 13)),
+    ('墼', (72, 15)),
+    ('墽', (72, 10)),
+    ('墾', (29, 95)),
+    ('墿', (72, 12)),
+    ('壁', (29, 94)),
+    ('壂', (72, 14)),
+    ('壅', (29, 97)),
+    ('壆', (72, 16)),
+    ('壇', (29, 96)),
+    ('壈', (72, 9)),
+    ('壉', (72, 11)),
+    ('壎', (31, 67)),
+    ('壏', (75, 106)),
+    ('壑', (31, 66)),
+    ('壒', (75, 107)),
+    ('壓', (31, 65)),
+    ('壔', (75, 105)),
+    ('壕', (31, 64)),
+    ('壖', (75, 104)),
+    ('壘', (33, 19)),
+    ('壙', (33, 18)),
+    ('壚', (80, 148)),
+    ('壛', (80, 150)),
+    ('壝', (80, 149)),
+    ('壞', (34, 33)),
+    ('壟', (34, 34)),
+    ('壢', (34, 35)),
+    ('壣', (83, 2)),
+    ('壤', (35, 27)),
+    ('壧', (86, 113)),
+    ('壨', (86, 112)),
+    ('壩', (36, 144)),
+    ('士', (3, 40)),
+    ('壬', (3, 110)),
+    ('壯', (6, 69)),
+    ('壴', (45, 31)),
+    ('壹', (18, 154)),
+    ('壺', (18, 155)),
+    ('壼', (59, 144)),
+    ('壽', (24, 118)),
+    ('壾', (64, 41)),
+    ('壿', (68, 19)),
+    ('夃', (40, 26)),
+    ('夆', (41, 78)),
+    ('夌', (43, 2)),
+    ('复', (45, 32)),
+    ('夎', (48, 25)),
+    ('夏', (13, 12)),
+    ('夒', (80, 151)),
+    ('夔', (35, 122)),
+    ('夕', (3, 41)),
+    ('外', (4, 62)),
+    ('夗', (40, 48)),
+    ('夙', (5, 39)),
+    ('多', (5, 40)),
+    ('夜', (8, 29)),
+    ('夠', (15, 149)),
+    ('夢', (24, 120)),
+    ('夤', (24, 121)),
+    ('夥', (24, 119)),
+    ('大', (3, 42)),
+    ('天', (3, 111)),
+    ('太', (3, 113)),
+    ('夫', (3, 112)),
+    ('夬', (40, 27)),
+    ('夭', (3, 114)),
+    ('央', (4, 63)),
+    ('夯', (40, 49)),
+    ('失', (4, 64)),
+    ('夷', (5, 41)),
+    ('夸', (5, 42)),
+    ('夼', (40, 105)),
+    ('夾', (6, 70)),
+    ('奀', (41, 79)),
+    ('奄', (8, 33)),
+    ('奅', (43, 3)),
+    ('奇', (8, 31)),
+    ('奈', (8, 32)),
+    ('奉', (8, 30)),
+    ('奊', (48, 26)),
+    ('奎', (10, 84)),
+    ('奏', (10, 83)),
+    ('奐', (10, 85)),
+    ('契', (10, 82)),
+    ('奓', (45, 33)),
+    ('奔', (8, 34)),
+    ('奕', (10, 81)),
+    ('套', (13, 13)),
+    ('奘', (13, 14)),
+    ('奚', (13, 15)),
+    ('奜', (51, 107)),
+    ('奠', (18, 156)),
+    ('奡', (55, 82)),
+    ('奢', (15, 150)),
+    ('奧', (21, 150)),
+    ('奩', (24, 123)),
+    ('奪', (24, 122)),
+    ('奫', (64, 42)),
+    ('奭', (27, 29)),
+    ('奮', (29, 98)),
+    ('奰', (78, 94)),
+    ('奱', (85, 120)),
+    ('奲', (86, 114)),
+    ('女', (3, 43)),
+    ('奴', (4, 65)),
+    ('奶', (4, 66)),
+    ('奷', (40, 111)),
+    ('奸', (5, 44)),
+    ('她', (5, 47)),
+    ('奻', (40, 109)),
+    ('奼', (40, 107)),
+    ('好', (5, 46)),
+    ('奾', (40, 110)),
+    ('奿', (40, 112)),
+    ('妀', (40, 106)),
+    ('妁', (5, 49)),
+    ('如', (5, 48)),
+    ('妃', (5, 45)),
+    ('妄', (5, 43)),
+    ('妅', (40, 108)),
+    ('妊', (6, 81)),
+    ('妍', (6, 78)),
+    ('妎', (41, 84)),
+    ('妏', (41, 87)),
+    ('妐', (41, 86)),
+    ('妒', (6, 72)),
+    ('妓', (6, 80)),
+    ('妖', (6, 77)),
+    ('妗', (41, 83)),
+    ('妘', (41, 81)),
+    ('妙', (6, 76)),
+    ('妝', (6, 71)),
+    ('妞', (6, 74)),
+    ('妠', (41, 82)),
+    ('妡', (41, 89)),
+    ('妢', (41, 85)),
+    ('妣', (6, 75)),
+    ('妤', (6, 79)),
+    ('妥', (6, 82)),
+    ('妦', (41, 80)),
+    ('妧', (41, 88)),
+    ('妨', (6, 73)),
+    ('妮', (8, 39)),
+    ('妯', (8, 47)),
+    ('妱', (43, 15)),
+    ('妲', (43, 8)),
+    ('妳', (8, 48)),
+    ('妴', (43, 19)),
+    ('妵', (43, 4)),
+    ('妶', (43, 11)),
+    ('妹', (8, 38)),
+    ('妺', (43, 5)),
+    ('妻', (8, 36)),
+    ('妼', (43, 12)),
+    ('妽', (43, 16)),
+    ('妾', (8, 35)),
+    ('姀', (43, 17)),
+    ('姁', (43, 10)),
+    ('姃', (43, 13)),
+    ('姅', (8, 50)),
+    ('姆', (8, 41)),
+    ('姇', (43, 20)),
+    ('姈', (43, 18)),
+    ('姊', (8, 46)),
+    ('始', (8, 44)),
+    ('姌', (43, 9)),
+    ('姍', (8, 43)),
+    ('姎', (43, 7)),
+    ('姏', (43, 6)),
+    ('姐', (8, 42)),
+    ('姑', (8, 40)),
+    ('姒', (8, 49)),
+    ('姓', (8, 45)),
+    ('委', (8, 37)),
+    ('姖', (43, 14)),
+    ('姘', (10, 87)),
+    ('姚', (10, 94)),
+    ('姛', (45, 47)),
+    ('姜', (10, 86)),
+    ('姝', (45, 39)),
+    ('姞', (45, 35)),
+    ('姠', (45, 51)),
+    ('姡', (45, 34)),
+    ('姣', (10, 89)),
+    ('姤', (45, 44)),
+    ('姥', (10, 92)),
+    ('姦', (10, 95)),
+    ('姨', (10, 90)),
+    ('姩', (45, 48)),
+    ('姪', (10, 93)),
+    ('姬', (13, 22)),
+    ('姭', (45, 54)),
+    ('姮', (45, 36)),
+    ('姱', (45, 38)),
+    ('姲', (45, 45)),
+    ('姳', (45, 49)),
+    ('姴', (45, 53)),
+    ('姵', (45, 50)),
+    ('姶', (45, 43)),
+    ('姷', (45, 46)),
+    ('姺', (45, 40)),
+    ('姻', (10, 97)),
+    ('姼', (45, 42)),
+    ('姽', (45, 41)),
+    ('姾', (45, 52)),
+    ('姿', (10, 88)),
+    ('娀', (45, 37)),
+    ('威', (10, 96)),
+    ('娃', (10, 91)),
+    ('娉', (13, 28)),
+    ('娊', (48, 34)),
+    ('娌', (13, 27)),
+    ('娏', (48, 32)),
+    ('娑', (13, 16)),
+    ('娓', (13, 21)),
+    ('娕', (48, 31)),
+    ('娖', (48, 28)),
+    ('娗', (48, 33)),
+    ('娘', (13, 17)),
+    ('娙', (48, 27)),
+    ('娛', (13, 20)),
+    ('娜', (13, 18)),
+    ('娞', (48, 35)),
+    ('娟', (13, 19)),
+    ('娠', (13, 23)),
+    ('娣', (13, 24)),
+    ('娥', (13, 26)),
+    ('娩', (13, 25)),
+    ('娭', (48, 29)),
+    ('娮', (48, 30)),
+    ('娳', (48, 36)),
+    ('娵', (51, 114)),
+    ('娶', (15, 151)),
+    ('娷', (55, 96)),
+    ('娸', (51, 113)),
+    ('娹', (51, 132)),
+    ('娼', (16, 0)),
+    ('娾', (51, 130)),
+    ('婀', (15, 156)),
+    ('婁', (15, 152)),
+    ('婂', (51, 139)),
+    ('婃', (51, 123)),
+    ('婄', (51, 126)),
+    ('婆', (16, 3)),
+    ('婇', (51, 136)),
+    ('婈', (51, 128)),
+    ('婉', (15, 153)),
+    ('婊', (16, 4)),
+    ('婌', (51, 133)),
+    ('婍', (51, 131)),
+    ('婐', (51, 116)),
+    ('婑', (51, 137)),
+    ('婒', (51, 125)),
+    ('婓', (51, 120)),
+    ('婕', (51, 110)),
+    ('婖', (51, 138)),
+    ('婗', (51, 122)),
+    ('婘', (51, 109)),
+    ('婚', (16, 2)),
+    ('婛', (51, 127)),
+    ('婜', (51, 140)),
+    ('婝', (51, 124)),
+    ('婞', (51, 112)),
+    ('婟', (51, 117)),
+    ('婠', (51, 108)),
+    ('婢', (16, 1)),
+    ('婤', (51, 121)),
+    ('婥', (51, 118)),
+    ('婦', (15, 154)),
+    ('婧', (51, 111)),
+    ('婩', (51, 135)),
+    ('婪', (15, 155)),
+    ('婬', (51, 119)),
+    ('婭', (51, 115)),
+    ('婰', (51, 134)),
+    ('婷', (19, 0)),
+    ('婸', (55, 89)),
+    ('婺', (55, 86)),
+    ('婻', (55, 103)),
+    ('婼', (55, 91)),
+    ('婽', (55, 104)),
+    ('婿', (19, 2)),
+    ('媃', (55, 100)),
+    ('媄', (55, 97)),
+    ('媊', (55, 98)),
+    ('媋', (55, 101)),
+    ('媌', (55, 105)),
+    ('媎', (51, 129)),
+    ('媏', (55, 107)),
+    ('媐', (60, 7)),
+    ('媒', (19, 3)),
+    ('媓', (55, 108)),
+    ('媔', (55, 84)),
+    ('媕', (55, 94)),
+    ('媗', (55, 99)),
+    ('媚', (19, 1)),
+    ('媛', (19, 4)),
+    ('媜', (55, 106)),
+    ('媝', (55, 109)),
+    ('媞', (55, 88)),
+    ('媟', (55, 85)),
+    ('媢', (55, 87)),
+    ('媥', (55, 92)),
+    ('媦', (55, 90)),
+    ('媧', (19, 5)),
+    ('媩', (55, 102)),
+    ('媬', (55, 93)),
+    ('媮', (55, 95)),
+    ('媯', (55, 83)),
+    ('媰', (59, 152)),
+    ('媱', (59, 150)),
+    ('媲', (22, 2)),
+    ('媳', (22, 0)),
+    ('媴', (60, 3)),
+    ('媵', (59, 151)),
+    ('媶', (60, 4)),
+    ('媷', (60, 0)),
+    ('媸', (59, 149)),
+    ('媹', (60, 6)),
+    ('媺', (59, 148)),
+    ('媻', (59, 155)),
+    ('媼', (21, 156)),
+    ('媽', (21, 155)),
+    ('媾', (21, 154)),
+    ('媿', (59, 153)),
+    ('嫀', (60, 1)),
+    ('嫁', (21, 151)),
+    ('嫂', (22, 1)),
+    ('嫄', (59, 146)),
+    ('嫆', (59, 156)),
+    ('嫇', (59, 145)),
+    ('嫈', (59, 154)),
+    ('嫉', (21, 152)),
+    ('嫊', (60, 2)),
+    ('嫋', (59, 147)),
+    ('嫌', (21, 153)),
+    ('嫍', (60, 5)),
+    ('嫕', (64, 46)),
+    ('嫖', (24, 128)),
+    ('嫗', (24, 127)),
+    ('嫘', (24, 129)),
+    ('嫙', (64, 58)),
+    ('嫚', (64, 48)),
+    ('嫛', (64, 54)),
+    ('嫜', (64, 43)),
+    ('嫝', (64, 57)),
+    ('嫞', (64, 56)),
+    ('嫟', (64, 60)),
+    ('嫠', (64, 53)),
+    ('嫡', (24, 124)),
+    ('嫢', (64, 52)),
+    ('嫣', (24, 130)),
+    ('嫥', (64, 45)),
+    ('嫦', (24, 125)),
+    ('嫨', (64, 59)),
+    ('嫩', (24, 126)),
+    ('嫪', (64, 47)),
+    ('嫫', (64, 50)),
+    ('嫬', (64, 55)),
+    ('嫭', (64, 49)),
+    ('嫮', (64, 44)),
+    ('嫳', (64, 51)),
+    ('嫴', (68, 21)),
+    ('嫵', (27, 33)),
+    ('嫶', (68, 24)),
+    ('嫷', (68, 23)),
+    ('嫸', (68, 26)),
+    ('嫹', (68, 28)),
+    ('嫻', (27, 31)),
+    ('嫽', (68, 22)),
+    ('嫿', (68, 20)),
+    ('嬁', (68, 29)),
+    ('嬂', (68, 27)),
+    ('嬃', (68, 25)),
+    ('嬅', (68, 31)),
+    ('嬇', (68, 30)),
+    ('嬈', (27, 35)),
+    ('嬉', (27, 30)),
+    ('嬋', (27, 32)),
+    ('嬌', (27, 34)),
+    ('嬏', (68, 32)),
+    ('嬐', (72, 23)),
+    ('嬓', (72, 22)),
+    ('嬔', (72, 21)),
+    ('嬖', (72, 24)),
+    ('嬗', (72, 17)),
+    ('嬙', (72, 18)),
+    ('嬚', (72, 26)),
+    ('嬛', (72, 19)),
+    ('嬝', (29, 99)),
+    ('嬞', (72, 28)),
+    ('嬠', (72, 27)),
+    ('嬡', (72, 20)),
+    ('嬣', (75, 111)),
+    ('嬤', (31, 70)),
+    ('嬥', (75, 109)),
+    ('嬦', (75, 114)),
+    ('嬧', (75, 113)),
+    ('嬨', (72, 25)),
+    ('嬪', (31, 69)),
+    ('嬬', (75, 112)),
+    ('嬭', (75, 108)),
+    ('嬮', (75, 116)),
+    ('嬯', (75, 115)),
+    ('嬰', (31, 68)),
+    ('嬲', (75, 110)),
+    ('嬴', (29, 100)),
+    ('嬸', (33, 20)),
+    ('嬼', (78, 95)),
+    ('嬽', (80, 152)),
+    ('嬾', (80, 153)),
+    ('嬿', (80, 154)),
+    ('孀', (35, 28)),
+    ('孃', (35, 29)),
+    ('孅', (83, 3)),
+    ('孇', (84, 102)),
+    ('孈', (84, 101)),
+    ('孋', (85, 121)),
+    ('孌', (85, 122)),
+    ('孍', (86, 115)),
+    ('孎', (87, 69)),
+    ('子', (3, 44)),
+    ('孑', (3, 45)),
+    ('孓', (3, 46)),
+    ('孔', (3, 115)),
+    ('孕', (4, 67)),
+    ('孖', (40, 113)),
+    ('字', (5, 50)),
+    ('存', (5, 51)),
+    ('孚', (6, 85)),
+    ('孛', (6, 86)),
+    ('孜', (6, 84)),
+    ('孝', (6, 83)),
+    ('孟', (8, 51)),
+    ('孢', (43, 21)),
+    ('季', (8, 53)),
+    ('孤', (8, 52)),
+    ('孥', (43, 22)),
+    ('孩', (10, 98)),
+    ('孫', (13, 29)),
+    ('孬', (48, 37)),
+    ('孮', (51, 142)),
+    ('孰', (16, 5)),
+    ('孱', (19, 7)),
+    ('孲', (51, 141)),
+    ('孳', (19, 6)),
+    ('孵', (24, 131)),
+    ('孷', (64, 61)),
+    ('學', (29, 101)),
+    ('孺', (31, 71)),
+    ('孻', (75, 117)),
+    ('孽', (35, 30)),
+    ('孿', (36, 48)),
+    ('宁', (40, 50)),
+    ('它', (4, 68)),
+    ('宄', (40, 51)),
+    ('宅', (5, 54)),
+    ('宇', (5, 52)),
+    ('守', (5, 53)),
+    ('安', (5, 55)),
+    ('宋', (6, 88)),
+    ('完', (6, 87)),
+    ('宎', (41, 90)),
+    ('宏', (6, 89)),
+    ('宒', (41, 91)),
+    ('宓', (43, 23)),
+    ('宕', (43, 24)),
+    ('宗', (8, 54)),
+    ('官', (8, 56)),
+    ('宙', (8, 58)),
+    ('定', (8, 55)),
+    ('宛', (8, 59)),
+    ('宜', (8, 57)),
+    ('客', (10, 102)),
+    ('宣', (10, 99)),
+    ('室', (10, 101)),
+    ('宥', (10, 103)),
+    ('宦', (10, 100)),
+    ('宧', (48, 38)),
+    ('宨', (45, 55)),
+    ('宬', (48, 40)),
+    ('宭', (48, 39)),
+    ('宮', (13, 35)),
+    ('宰', (13, 31)),
+    ('害', (13, 32)),
+    ('宴', (13, 34)),
+    ('宵', (13, 36)),
+    ('家', (13, 33)),
+    ('宸', (13, 38)),
+    ('容', (13, 37)),
+    ('宿', (16, 10)),
+    ('寀', (51, 144)),
+    ('寁', (51, 143)),
+    ('寂', (16, 9)),
+    ('寄', (16, 8)),
+    ('寅', (16, 7)),
+    ('密', (16, 11)),
+    ('寇', (16, 6)),
+    ('寊', (55, 115)),
+    ('寋', (55, 112)),
+    ('富', (19, 9)),
+    ('寍', (55, 111)),
+    ('寎', (55, 116)),
+    ('寐', (19, 11)),
+    ('寑', (55, 114)),
+    ('寒', (19, 8)),
+    ('寓', (19, 10)),
+    ('寔', (55, 113)),
+    ('寖', (60, 8)),
+    ('寘', (60, 9)),
+    ('寙', (60, 10)),
+    ('寞', (24, 132)),
+    ('察', (24, 140)),
+    ('寠', (64, 62)),
+    ('寡', (24, 134)),
+    ('寢', (24, 138)),
+    ('寣', (64, 63)),
+    ('寤', (24, 139)),
+    ('寥', (24, 135)),
+    ('實', (24, 136)),
+    ('寧', (24, 133)),
+    ('寨', (24, 137)),
+    ('審', (27, 38)),
+    ('寪', (55, 110)),
+    ('寫', (27, 39)),
+    ('寬', (27, 37)),
+    ('寮', (27, 36)),
+    ('寯', (72, 29)),
+    ('寰', (29, 102)),
+    ('寱', (75, 118)),
+    ('寲', (75, 119)),
+    ('寵', (34, 36)),
+    ('寶', (35, 31)),
+    ('寸', (3, 47)),
+    ('寺', (5, 56)),
+    ('封', (10, 104)),
+    ('尃', (48, 41)),
+    ('射', (13, 39)),
+    ('將', (16, 14)),
+    ('專', (16, 13)),
+    ('尉', (16, 12)),
+    ('尊', (19, 12)),
+    ('尋', (19, 13)),
+    ('尌', (55, 117)),
+    ('對', (24, 141)),
+    ('導', (29, 103)),
+    ('小', (3, 48)),
+    ('尐', (40, 28)),
+    ('少', (3, 116)),
+    ('尒', (40, 52)),
+    ('尕', (40, 114)),
+    ('尖', (5, 57)),
+    ('尚', (8, 60)),
+    ('尟', (60, 11)),
+    ('尢', (3, 49)),
+    ('尤', (3, 117)),
+    ('尥', (40, 115)),
+    ('尨', (41, 92)),
+    ('尪', (41, 93)),
+    ('尬', (6, 90)),
+    ('尰', (55, 118)),
+    ('就', (19, 14)),
+    ('尳', (60, 12)),
+    ('尷', (31, 72)),
+    ('尸', (3, 50)),
+    ('尹', (3, 70)),
+    ('尺', (3, 118)),
+    ('尻', (40, 53)),
+    ('尼', (4, 69)),
+    ('尾', (6, 94)),
+    ('尿', (6, 93)),
+    ('局', (6, 91)),
+    ('屁', (6, 92)),
+    ('屄', (43, 25)),
+    ('居', (8, 62)),
+    ('屆', (8, 63)),
+    ('屇', (43, 26)),
+    ('屈', (8, 61)),
+    ('屋', (10, 108)),
+    ('屌', (45, 56)),
+    ('屍', (10, 107)),
+    ('屎', (10, 105)),
+    ('屏', (10, 106)),
+    ('屐', (13, 42)),
+    ('屑', (13, 40)),
+    ('屔', (48, 43)),
+    ('展', (13, 41)),
+    ('屖', (48, 42)),
+    ('屘', (13, 30)),
+    ('屙', (51, 145)),
+    ('屜', (16, 16)),
+    ('屝', (16, 17)),
+    ('屠', (16, 15)),
+    ('屢', (24, 142)),
+    ('屣', (64, 64)),
+    ('層', (27, 40)),
+    ('履', (27, 41)),
+    ('屧', (68, 33)),
+    ('屨', (31, 73)),
+    ('屩', (78, 96)),
+    ('屪', (78, 97)),
+    ('屬', (35, 123)),
+    ('屭', (87, 70)),
+    ('屮', (40, 11)),
+    ('屯', (3, 119)),
+    ('山', (3, 51)),
+    ('屳', (40, 55)),
+    ('屴', (40, 54)),
+    ('屹', (5, 58)),
+    ('屺', (40, 117)),
+    ('屻', (40, 118)),
+    ('屼', (40, 116)),
+    ('屾', (40, 119)),
+    ('岆', (41, 101)),
+    ('岈', (41, 96)),
+    ('岉', (41, 98)),
+    ('岊', (41, 100)),
+    ('岋', (41, 97)),
+    ('岌', (6, 98)),
+    ('岍', (41, 94)),
+    ('岏', (41, 95)),
+    ('岐', (6, 95)),
+    ('岑', (6, 96)),
+    ('岒', (41, 99)),
+    ('岓', (41, 102)),
+    ('岔', (6, 97)),
+    ('岕', (41, 103)),
+    ('岝', (43, 40)),
+    ('岟', (43, 34)),
+    ('岠', (43, 29)),
+    ('岡', (8, 65)),
+    ('岢', (43, 37)),
+    ('岣', (43, 35)),
+    ('岤', (43, 28)),
+    ('岥', (43, 41)),
+    ('岦', (43, 44)),
+    ('岧', (43, 39)),
+    ('岨', (43, 32)),
+    ('岩', (8, 67)),
+    ('岪', (43, 38)),
+    ('岫', (8, 68)),
+    ('岬', (43, 33)),
+    ('岭', (43, 36)),
+    ('岮', (43, 27)),
+    ('岯', (43, 31)),
+    ('岰', (43, 43)),
+    ('岱', (8, 69)),
+    ('岳', (8, 70)),
+    ('岵', (43, 30)),
+    ('岶', (43, 42)),
+    ('岷', (8, 64)),
+    ('岸', (8, 66)),
+    ('峆', (45, 73)),
+    ('峇', (45, 66)),
+    ('峈', (45, 72)),
+    ('峉', (45, 65)),
+    ('峊', (45, 67)),
+    ('峋', (45, 61)),
+    ('峌', (45, 59)),
+    ('峎', (45, 74)),
+    ('峏', (45, 71)),
+    ('峐', (45, 57)),
+    ('峒', (10, 110)),
+    ('峓', (45, 69)),
+    ('峔', (45, 70)),
+    ('峖', (45, 68)),
+    ('峗', (45, 60)),
+    ('峘', (45, 58)),
+    ('峙', (10, 109)),
+    ('峚', (45, 64)),
+    ('峛', (45, 62)),
+    ('峞', (45, 63)),
+    ('峟', (45, 75)),
+    ('峨', (13, 47)),
+    ('峪', (13, 46)),
+    ('峬', (48, 44)),
+    ('峭', (13, 43)),
+    ('峮', (48, 46)),
+    ('峰', (13, 48)),
+    ('峱', (48, 47)),
+    ('峴', (13, 51)),
+    ('島', (13, 49)),
+    ('峷', (48, 48)),
+    ('峸', (45, 76)),
+    ('峹', (48, 50)),
+    ('峻', (13, 45)),
+    ('峽', (13, 44)),
+    ('峿', (48, 45)),
+    ('崀', (48, 49)),
+    ('崁', (13, 50)),
+    ('崆', (16, 19)),
+    ('崇', (16, 18)),
+    ('崋', (51, 147)),
+    ('崌', (51, 151)),
+    ('崍', (51, 153)),
+    ('崎', (16, 20)),
+    ('崏', (51, 156)),
+    ('崑', (16, 24)),
+    ('崒', (52, 1)),
+    ('崔', (16, 26)),
+    ('崖', (16, 22)),
+    ('崗', (16, 30)),
+    ('崙', (16, 27)),
+    ('崚', (51, 149)),
+    ('崛', (16, 21)),
+    ('崝', (51, 148)),
+    ('崞', (51, 146)),
+    ('崟', (52, 3)),
+    ('崠', (51, 150)),
+    ('崢', (16, 23)),
+    ('崣', (52, 2)),
+    ('崤', (16, 28)),
+    ('崥', (51, 155)),
+    ('崦', (51, 154)),
+    ('崧', (16, 29)),
+    ('崨', (51, 152)),
+    ('崩', (16, 25)),
+    ('崮', (52, 4)),
+    ('崰', (52, 0)),
+    ('崱', (55, 133)),
+    ('崲', (55, 140)),
+    ('崳', (55, 129)),
+    ('崴', (19, 17)),
+    ('崵', (55, 125)),
+    ('崶', (55, 141)),
+    ('崷', (55, 119)),
+    ('崸', (55, 138)),
+    ('崹', (55, 136)),
+    ('崺', (55, 130)),
+    ('崼', (55, 139)),
+    ('崽', (55, 132)),
+    ('崿', (55, 124)),
+    ('嵀', (55, 142)),
+    ('嵁', (55, 122)),
+    ('嵂', (55, 135)),
+    ('嵃', (55, 120)),
+    ('嵅', (55, 143)),
+    ('嵇', (19, 18)),
+    ('嵉', (55, 137)),
+    ('嵊', (60, 15)),
+    ('嵋', (55, 123)),
+    ('嵌', (19, 15)),
+    ('嵎', (55, 127)),
+    ('嵐', (19, 16)),
+    ('嵑', (55, 126)),
+    ('嵒', (55, 131)),
+    ('嵕', (55, 128)),
+    ('嵙', (55, 134)),
+    ('嵞', (60, 19)),
+    ('嵢', (60, 22)),
+    ('嵣', (60, 14)),
+    ('嵥', (60, 16)),
+    ('嵧', (60, 21)),
+    ('嵨', (60, 20)),
+    ('嵩', (22, 3)),
+    ('嵫', (55, 121)),
+    ('嵬', (60, 18)),
+    ('嵯', (22, 4)),
+    ('嵱', (60, 13)),
+    ('嵲', (60, 17)),
+    ('嵷', (64, 71)),
+    ('嵹', (64, 78)),
+    ('嵺', (64, 69)),
+    ('嵼', (64, 76)),
+    ('嵽', (64, 67)),
+    ('嵾', (64, 75)),
+    ('嵿', (64, 79)),
+    ('嶀', (64, 66)),
+    ('嶁', (64, 70)),
+    ('嶂', (64, 65)),
+    ('嶄', (24, 143)),
+    ('嶆', (64, 68)),
+    ('嶇', (24, 144)),
+    ('嶈', (64, 74)),
+    ('嶉', (64, 73)),
+    ('嶊', (64, 72)),
+    ('嶍', (64, 77)),
+    ('嶒', (68, 37)),
+    ('嶓', (68, 39)),
+    ('嶔', (27, 43)),
+    ('嶕', (68, 40)),
+    ('嶗', (68, 35)),
+    ('嶙', (68, 34)),
+    ('嶚', (68, 44)),
+    ('嶜', (68, 42)),
+    ('嶝', (27, 42)),
+    ('嶞', (68, 45)),
+    ('嶟', (68, 36)),
+    ('嶠', (68, 41)),
+    ('嶡', (68, 43)),
+    ('嶢', (68, 38)),
+    ('嶧', (72, 33)),
+    ('嶨', (72, 38)),
+    ('嶩', (72, 32)),
+    ('嶪', (72, 37)),
+    ('嶬', (72, 30)),
+    ('嶭', (72, 40)),
+    ('嶮', (72, 36)),
+    ('嶯', (72, 41)),
+    ('嶰', (72, 35)),
+    ('嶱', (72, 31)),
+    ('嶲', (72, 39)),
+    ('嶴', (72, 42)),
+    ('嶵', (72, 34)),
+    ('嶷', (75, 120)),
+    ('嶸', (31, 77)),
+    ('嶺', (31, 75)),
+    ('嶼', (31, 74)),
+    ('嶽', (31, 76)),
+    ('巀', (78, 98)),
+    ('巂', (80, 50)),
+    ('巃', (80, 155)),
+    ('巆', (83, 4)),
+    ('巇', (83, 5)),
+    ('巉', (35, 32)),
+    ('巋', (84, 103)),
+    ('巍', (35, 124)),
+    ('巏', (84, 104)),
+    ('巑', (85, 124)),
+    ('巒', (36, 50)),
+    ('巔', (36, 49)),
+    ('巕', (85, 123)),
+    ('巖', (36, 103)),
+    ('巘', (86, 116)),
+    ('川', (3, 52)),
+    ('州', (5, 59)),
+    ('巟', (40, 120)),
+    ('巠', (41, 104)),
+    ('巡', (7, 83)),
+    ('巢', (16, 31)),
+    ('工', (3, 53)),
+    ('左', (4, 72)),
+    ('巧', (4, 71)),
+    ('巨', (4, 70)),
+    ('巫', (6, 99)),
+    ('差', (13, 52)),
+    ('巰', (60, 23)),
+    ('己', (3, 54)),
+    ('已', (3, 55)),
+    ('巳', (3, 56)),
+    ('巴', (3, 120)),
+    ('巷', (10, 111)),
+    ('巹', (45, 77)),
+    ('巽', (19, 19)),
+    ('巾', (3, 57)),
+    ('巿', (40, 29)),
+    ('市', (4, 73)),
+    ('布', (4, 74)),
+    ('帄', (40, 56)),
+    ('帆', (5, 60)),
+    ('帊', (41, 105)),
+    ('希', (6, 100)),
+    ('帎', (41, 106)),
+    ('帑', (8, 76)),
+    ('帔', (43, 46)),
+    ('帕', (8, 74)),
+    ('帖', (8, 73)),
+    ('帗', (43, 45)),
+    ('帘', (8, 71)),
+    ('帙', (43, 47)),
+    ('帚', (8, 72)),
+    ('帛', (8, 75)),
+    ('帝', (10, 112)),
+    ('帟', (10, 114)),
+    ('帠', (45, 81)),
+    ('帡', (45, 78)),
+    ('帢', (45, 79)),
+    ('帣', (45, 80)),
+    ('帤', (45, 82)),
+    ('帥', (10, 113)),
+    ('帨', (48, 52)),
+    ('帩', (48, 51)),
+    ('師', (13, 54)),
+    ('席', (13, 53)),
+    ('帳', (16, 34)),
+    ('帴', (52, 6)),
+    ('帶', (16, 33)),
+    ('帷', (16, 35)),
+    ('常', (16, 32)),
+    ('帽', (19, 21)),
+    ('帾', (52, 5)),
+    ('幀', (19, 22)),
+    ('幁', (55, 145)),
+    ('幃', (19, 23)),
+    ('幄', (55, 144)),
+    ('幅', (19, 20)),
+    ('幊', (60, 26)),
+    ('幋', (60, 28)),
+    ('幌', (22, 5)),
+    ('幍', (60, 27)),
+    ('幎', (60, 25)),
+    ('幏', (60, 24)),
+    ('幓', (64, 82)),
+    ('幔', (24, 149)),
+    ('幕', (24, 147)),
+    ('幗', (24, 148)),
+    ('幘', (64, 80)),
+    ('幙', (64, 81)),
+    ('幛', (24, 145)),
+    ('幜', (68, 49)),
+    ('幝', (68, 47)),
+    ('幟', (27, 45)),
+    ('幠', (68, 48)),
+    ('幡', (27, 46)),
+    ('幢', (27, 44)),
+    ('幣', (24, 146)),
+    ('幦', (72, 45)),
+    ('幧', (72, 43)),
+    ('幨', (72, 44)),
+    ('幩', (68, 46)),
+    ('幪', (75, 122)),
+    ('幫', (31, 78)),
+    ('幬', (75, 121)),
+    ('幭', (78, 99)),
+    ('幮', (78, 100)),
+    ('幯', (72, 46)),
+    ('幰', (80, 156)),
+    ('干', (3, 58)),
+    ('平', (4, 75)),
+    ('年', (5, 62)),
+    ('幵', (40, 121)),
+    ('并', (5, 61)),
+    ('幸', (8, 77)),
+    ('幹', (22, 6)),
+    ('幻', (3, 121)),
+    ('幼', (4, 76)),
+    ('幽', (10, 115)),
+    ('幾', (19, 24)),
+    ('庀', (40, 57)),
+    ('庂', (40, 58)),
+    ('庄', (40, 122)),
+    ('庇', (6, 102)),
+    ('庈', (41, 110)),
+    ('庉', (41, 108)),
+    ('床', (6, 103)),
+    ('庋', (41, 107)),
+    ('庌', (41, 109)),
+    ('庍', (41, 111)),
+    ('序', (6, 101)),
+    ('底', (8, 81)),
+    ('庖', (8, 82)),
+    ('店', (8, 79)),
+    ('庚', (8, 78)),
+    ('庛', (45, 86)),
+    ('府', (8, 80)),
+    ('庠', (10, 116)),
+    ('庢', (45, 85)),
+    ('庣', (45, 87)),
+    ('庤', (45, 84)),
+    ('庥', (45, 88)),
+    ('度', (10, 117)),
+    ('座', (13, 57)),
+    ('庨', (48, 53)),
+    ('庪', (48, 55)),
+    ('庫', (13, 55)),
+    ('庬', (48, 56)),
+    ('庭', (13, 56)),
+    ('庮', (48, 54)),
+    ('庰', (45, 83)),
+    ('庱', (52, 7)),
+    ('庲', (52, 10)),
+    ('庳', (52, 11)),
+    ('庴', (52, 8)),
+    ('庵', (16, 39)),
+    ('庶', (16, 38)),
+    ('康', (16, 36)),
+    ('庸', (16, 37)),
+    ('庹', (52, 9)),
+    ('庾', (16, 40)),
+    ('廁', (19, 26)),
+    ('廂', (19, 27)),
+    ('廄', (19, 28)),
+    ('廅', (60, 29)),
+    ('廆', (60, 31)),
+    ('廇', (60, 33)),
+    ('廈', (22, 8)),
+    ('廉', (22, 7)),
+    ('廊', (19, 25)),
+    ('廋', (60, 32)),
+    ('廌', (60, 30)),
+    ('廎', (64, 86)),
+    ('廑', (64, 84)),
+    ('廒', (64, 90)),
+    ('廓', (24, 150)),
+    ('廔', (64, 91)),
+    ('廕', (64, 88)),
+    ('廖', (24, 151)),
+    ('廗', (64, 85)),
+    ('廘', (64, 83)),
+    ('廙', (64, 89)),
+    ('廚', (27, 48)),
+    ('廛', (68, 51)),
+    ('廜', (64, 87)),
+    ('廝', (27, 50)),
+    ('廞', (68, 52)),
+    ('廟', (27, 49)),
+    ('廠', (27, 52)),
+    ('廡', (68, 53)),
+    ('廢', (27, 47)),
+    ('廣', (27, 51)),
+    ('廥', (72, 51)),
+    ('廦', (72, 49)),
+    ('廧', (72, 48)),
+    ('廨', (72, 50)),
+    ('廩', (72, 47)),
+    ('廬', (34, 38)),
+    ('廮', (83, 6)),
+    ('廯', (83, 7)),
+    ('廱', (84, 105)),
+    ('廲', (85, 125)),
+    ('廳', (37, 21)),
+    ('延', (8, 83)),
+    ('廷', (6, 104)),
+    ('建', (10, 118)),
+    ('廾', (3, 59)),
+    ('廿', (3, 122)),
+    ('弁', (4, 77)),
+    ('异', (40, 123)),
+    ('弄', (6, 105)),
+    ('弅', (41, 112)),
+    ('弇', (45, 89)),
+    ('弈', (10, 119)),
+    ('弊', (24, 152)),
+    ('弋', (3, 60)),
+    ('式', (5, 63)),
+    ('弒', (22, 9)),
+    ('弓', (3, 61)),
+    ('弔', (3, 123)),
+    ('引', (3, 124)),
+    ('弗', (4, 79)),
+    ('弘', (4, 78)),
+    ('弚', (40, 124)),
+    ('弛', (5, 64)),
+    ('弝', (41, 113)),
+    ('弟', (6, 106)),
+    ('弢', (43, 49)),
+    ('弣', (43, 50)),
+    ('弤', (43, 51)),
+    ('弦', (8, 84)),
+    ('弧', (8, 85)),
+    ('弨', (43, 48)),
+    ('弩', (8, 86)),
+    ('弭', (10, 120)),
+    ('弮', (45, 90)),
+    ('弰', (48, 58)),
+    ('弱', (13, 58)),
+    ('弳', (48, 57)),
+    ('張', (16, 41)),
+    ('弶', (52, 12)),
+    ('強', (16, 42)),
+    ('弸', (52, 13)),
+    ('弼', (19, 29)),
+    ('彀', (60, 34)),
+    ('彃', (64, 93)),
+    ('彄', (64, 92)),
+    ('彆', (24, 153)),
+    ('彈', (27, 53)),
+    ('彉', (68, 54)),
+    ('彊', (29, 104)),
+    ('彋', (72, 52)),
+    ('彌', (31, 79)),
+    ('彎', (36, 51)),
+    ('彏', (86, 118)),
+    ('彔', (43, 52)),
+    ('彖', (45, 91)),
+    ('彗', (16, 43)),
+    ('彘', (55, 146)),
+    ('彙', (22, 10)),
+    ('彝', (33, 21)),
+    ('形', (6, 108)),
+    ('彤', (6, 107)),
+    ('彥', (10, 121)),
+    ('彧', (48, 59)),
+    ('彩', (16, 45)),
+    ('彪', (18, 3)),
+    ('彫', (16, 46)),
+    ('彬', (16, 44)),
+    ('彭', (19, 30)),
+    ('彯', (64, 94)),
+    ('彰', (24, 154)),
+    ('影', (27, 54)),
+    ('彳', (40, 12)),
+    ('彴', (40, 125)),
+    ('彶', (41, 115)),
+    ('彷', (6, 109)),
+    ('彸', (41, 114)),
+    ('役', (6, 110)),
+    ('彼', (8, 90)),
+    ('彽', (43, 55)),
+    ('彾', (43, 54)),
+    ('彿', (8, 89)),
+    ('往', (8, 87)),
+    ('征', (8, 88)),
+    ('徂', (43, 53)),
+    ('待', (10, 123)),
+    ('徆', (45, 92)),
+    ('徇', (10, 126)),
+    ('很', (10, 122)),
+    ('徉', (10, 128)),
+    ('徊', (10, 124)),
+    ('律', (10, 125)),
+    ('後', (10, 127)),
+    ('徐', (13, 61)),
+    ('徑', (13, 60)),
+    ('徒', (13, 59)),
+    ('徖', (52, 15)),
+    ('得', (16, 47)),
+    ('徘', (16, 50)),
+    ('徙', (16, 48)),
+    ('徛', (52, 14)),
+    ('徜', (16, 53)),
+    ('從', (16, 49)),
+    ('徟', (52, 16)),
+    ('徠', (16, 52)),
+    ('御', (16, 51)),
+    ('徥', (55, 148)),
+    ('徦', (55, 147)),
+    ('徨', (19, 33)),
+    ('復', (19, 31)),
+    ('循', (19, 32)),
+    ('徫', (55, 149)),
+    ('徬', (22, 11)),
+    ('徭', (60, 36)),
+    ('微', (22, 12)),
+    ('徯', (60, 35)),
+    ('徲', (68, 55)),
+    ('徵', (27, 56)),
+    ('徶', (64, 95)),
+    ('德', (27, 55)),
+    ('徹', (24, 155)),
+    ('徻', (75, 124)),
+    ('徼', (72, 53)),
+    ('徽', (31, 80)),
+    ('徾', (75, 123)),
+    ('徿', (81, 0)),
+    ('忀', (83, 8)),
+    ('忁', (83, 9)),
+    ('心', (3, 125)),
+    ('必', (4, 80)),
+    ('忉', (40, 59)),
+    ('忌', (6, 112)),
+    ('忍', (6, 114)),
+    ('忏', (40, 128)),
+    ('忐', (41, 118)),
+    ('忑', (41, 117)),
+    ('忒', (41, 116)),
+    ('忔', (40, 127)),
+    ('忕', (40, 126)),
+    ('忖', (5, 66)),
+    ('志', (6, 113)),
+    ('忘', (6, 111)),
+    ('忙', (5, 65)),
+    ('忝', (8, 91)),
+    ('忞', (43, 56)),
+    ('忠', (8, 92)),
+    ('忡', (41, 123)),
+    ('忣', (41, 125)),
+    ('忤', (41, 124)),
+    ('忥', (43, 57)),
+    ('忨', (41, 120)),
+    ('忪', (6, 118)),
+    ('快', (6, 116)),
+    ('忭', (41, 119)),
+    ('忮', (41, 121)),
+    ('忯', (41, 127)),
+    ('忱', (6, 115)),
+    ('忳', (41, 122)),
+    ('忴', (41, 131)),
+    ('念', (8, 94)),
+    ('忷', (41, 128)),
+    ('忸', (6, 117)),
+    ('忺', (41, 126)),
+    ('忻', (41, 129)),
+    ('忽', (8, 93)),
+    ('忿', (8, 95)),
+    ('怀', (41, 130)),
+    ('怉', (43, 77)),
+    ('怊', (43, 64)),
+    ('怋', (43, 62)),
+    ('怌', (43, 76)),
+    ('怍', (43, 71)),
+    ('怎', (10, 133)),
+    ('怏', (8, 96)),
+    ('怐', (43, 72)),
+    ('怑', (43, 75)),
+    ('怒', (10, 129)),
+    ('怓', (43, 74)),
+    ('怔', (8, 97)),
+    ('怕', (8, 102)),
+    ('怖', (8, 100)),
+    ('怗', (43, 65)),
+    ('怙', (43, 60)),
+    ('怚', (43, 67)),
+    ('怛', (8, 107)),
+    ('怜', (43, 78)),
+    ('思', (10, 130)),
+    ('怞', (43, 68)),
+    ('怠', (10, 131)),
+    ('怡', (8, 103)),
+    ('怢', (43, 70)),
+    ('怤', (45, 107)),
+    ('急', (10, 132)),
+    ('怦', (43, 59)),
+    ('性', (8, 104)),
+    ('怨', (10, 134)),
+    ('怩', (8, 105)),
+    ('怪', (8, 101)),
+    ('怫', (8, 106)),
+    ('怬', (43, 69)),
+    ('怭', (43, 58)),
+    ('怮', (43, 73)),
+    ('怯', (8, 98)),
+    ('怲', (43, 61)),
+    ('怳', (43, 66)),
+    ('怴', (43, 63)),
+    ('怵', (8, 99)),
+    ('怷', (45, 93)),
+    ('怹', (45, 94)),
+    ('恀', (45, 104)),
+    ('恁', (48, 63)),
+    ('恂', (45, 105)),
+    ('恃', (10, 140)),
+    ('恄', (45, 108)),
+    ('恅', (45, 98)),
+    ('恆', (10, 139)),
+    ('恇', (45, 100)),
+    ('恉', (45, 101)),
+    ('恌', (45, 103)),
+    ('恍', (10, 135)),
+    ('恐', (13, 65)),
+    ('恓', (45, 99)),
+    ('恔', (45, 95)),
+    ('恕', (13, 66)),
+    ('恘', (45, 109)),
+    ('恙', (13, 62)),
+    ('恚', (48, 61)),
+    ('恛', (45, 102)),
+    ('恝', (48, 60)),
+    ('恞', (45, 97)),
+    ('恟', (45, 106)),
+    ('恢', (10, 138)),
+    ('恣', (13, 63)),
+    ('恤', (10, 144)),
+    ('恥', (13, 64)),
+    ('恦', (45, 110)),
+    ('恧', (48, 62)),
+    ('恨', (10, 137)),
+    ('恩', (13, 68)),
+    ('恪', (10, 143)),
+    ('恫', (10, 142)),
+    ('恬', (10, 141)),
+    ('恭', (13, 67)),
+    ('恮', (45, 111)),
+    ('息', (13, 69)),
+    ('恰', (10, 136)),
+    ('恲', (45, 96)),
+    ('恿', (16, 54)),
+    ('悀', (48, 66)),
+    ('悁', (48, 68)),
+    ('悃', (48, 70)),
+    ('悄', (13, 70)),
+    ('悅', (13, 76)),
+    ('悆', (52, 19)),
+    ('悇', (48, 74)),
+    ('悈', (48, 65)),
+    ('悉', (16, 56)),
+    ('悊', (52, 17)),
+    ('悌', (13, 75)),
+    ('悍', (13, 73)),
+    ('悎', (48, 76)),
+    ('悐', (52, 18)),
+    ('悒', (48, 67)),
+    ('悔', (13, 74)),
+    ('悕', (48, 71)),
+    ('悖', (13, 77)),
+    ('悗', (48, 73)),
+    ('悚', (13, 72)),
+    ('悛', (48, 72)),
+    ('悜', (48, 75)),
+    ('悝', (48, 69)),
+    ('悟', (13, 71)),
+    ('悠', (16, 57)),
+    ('悢', (48, 64)),
+    ('患', (16, 55)),
+    ('您', (16, 58)),
+    ('悰', (52, 21)),
+    ('悱', (52, 30)),
+    ('悲', (19, 36)),
+    ('悴', (16, 60)),
+    ('悵', (16, 65)),
+    ('悶', (19, 37)),
+    ('悷', (52, 32)),
+    ('悸', (16, 72)),
+    ('悹', (55, 151)),
+    ('悺', (52, 22)),
+    ('悻', (16, 64)),
+    ('悼', (16, 67)),
+    ('悽', (16, 62)),
+    ('悾', (52, 20)),
+    ('悿', (52, 34)),
+    ('惀', (52, 37)),
+    ('惁', (56, 9)),
+    ('惃', (52, 35)),
+    ('惄', (55, 155)),
+    ('情', (16, 63)),
+    ('惆', (16, 70)),
+    ('惇', (16, 74)),
+    ('惈', (52, 29)),
+    ('惉', (55, 150)),
+    ('惊', (52, 33)),
+    ('惋', (16, 59)),
+    ('惌', (55, 152)),
+    ('惍', (52, 36)),
+    ('惎', (55, 154)),
+    ('惏', (52, 25)),
+    ('惑', (19, 34)),
+    ('惓', (52, 23)),
+    ('惔', (52, 24)),
+    ('惕', (16, 69)),
+    ('惘', (16, 68)),
+    ('惙', (52, 27)),
+    ('惚', (16, 73)),
+    ('惛', (52, 31)),
+    ('惜', (16, 66)),
+    ('惝', (52, 28)),
+    ('惟', (16, 71)),
+    ('惠', (19, 38)),
+    ('惡', (19, 35)),
+    ('惢', (55, 153)),
+    ('惤', (52, 26)),
+    ('惦', (16, 61)),
+    ('惰', (19, 43)),
+    ('惱', (19, 47)),
+    ('惲', (56, 0)),
+    ('想', (22, 17)),
+    ('惴', (19, 45)),
+    ('惵', (56, 4)),
+    ('惶', (19, 49)),
+    ('惷', (60, 37)),
+    ('惸', (56, 6)),
+    ('惹', (22, 19)),
+    ('惺', (19, 41)),
+    ('惻', (19, 44)),
+    ('惼', (56, 7)),
+    ('惾', (56, 8)),
+    ('惿', (56, 14)),
+    ('愀', (19, 51)),
+    ('愁', (22, 20)),
+    ('愃', (56, 10)),
+    ('愄', (56, 15)),
+    ('愅', (56, 3)),
+    ('愆', (22, 30)),
+    ('愈', (22, 21)),
+    ('愉', (19, 50)),
+    ('愊', (56, 1)),
+    ('愋', (56, 16)),
+    ('愍', (22, 29)),
+    ('愎', (19, 48)),
+    ('意', (22, 14)),
+    ('愐', (56, 13)),
+    ('愒', (19, 52)),
+    ('愓', (56, 5)),
+    ('愔', (55, 156)),
+    ('愕', (19, 42)),
+    ('愖', (56, 2)),
+    ('愘', (56, 11)),
+    ('愚', (22, 13)),
+    ('愛', (22, 18)),
+    ('愜', (19, 39)),
+    ('愝', (56, 12)),
+    ('感', (22, 16)),
+    ('愣', (19, 40)),
+    ('愧', (22, 28)),
+    ('愨', (64, 97)),
+    ('愩', (60, 48)),
+    ('愫', (60, 40)),
+    ('愬', (64, 96)),
+    ('愮', (60, 44)),
+    ('愯', (60, 46)),
+    ('愲', (60, 43)),
+    ('愴', (22, 27)),
+    ('愶', (60, 42)),
+    ('愷', (22, 31)),
+    ('愻', (64, 112)),
+    ('愾', (22, 26)),
+    ('愿', (25, 0)),
+    ('慀', (60, 49)),
+    ('慁', (64, 98)),
+    ('慄', (22, 24)),
+    ('慅', (60, 41)),
+    ('慆', (60, 45)),
+    ('慇', (24, 156)),
+    ('慈', (22, 15)),
+    ('慉', (60, 38)),
+    ('慊', (60, 39)),
+    ('態', (25, 1)),
+    ('慌', (22, 23)),
+    ('慍', (22, 25)),
+    ('慎', (22, 22)),
+    ('慏', (60, 47)),
+    ('慒', (64, 102)),
+    ('慓', (64, 103)),
+    ('慔', (64, 108)),
+    ('慕', (27, 61)),
+    ('慖', (64, 115)),
+    ('慘', (25, 7)),
+    ('慚', (25, 6)),
+    ('慛', (64, 110)),
+    ('慝', (27, 60)),
+    ('慞', (64, 99)),
+    ('慟', (25, 5)),
+    ('慡', (64, 114)),
+    ('慢', (25, 3)),
+    ('慣', (25, 4)),
+    ('慥', (64, 111)),
+    ('慦', (68, 72)),
+    ('慧', (27, 58)),
+    ('慨', (19, 46)),
+    ('慪', (64, 113)),
+    ('慫', (27, 65)),
+    ('慬', (64, 105)),
+    ('慮', (27, 59)),
+    ('慰', (27, 64)),
+    ('慱', (64, 100)),
+    ('慲', (64, 104)),
+    ('慳', (64, 101)),
+    ('慴', (64, 107)),
+    ('慵', (25, 8)),
+    ('慶', (27, 57)),
+    ('慷', (25, 2)),
+    ('慹', (68, 58)),
+    ('慺', (64, 109)),
+    ('慼', (27, 63)),
+    ('慾', (27, 66)),
+    ('憀', (64, 106)),
+    ('憂', (27, 62)),
+    ('憃', (68, 57)),
+    ('憉', (68, 62)),
+    ('憊', (29, 108)),
+    ('憋', (68, 56)),
+    ('憌', (72, 65)),
+    ('憍', (68, 71)),
+    ('憎', (27, 70)),
+    ('憐', (27, 68)),
+    ('憑', (29, 106)),
+    ('憒', (68, 68)),
+    ('憓', (68, 64)),
+    ('憔', (27, 74)),
+    ('憖', (72, 56)),
+    ('憚', (27, 72)),
+    ('憛', (68, 63)),
+    ('憝', (72, 54)),
+    ('憟', (68, 67)),
+    ('憡', (68, 70)),
+    ('憢', (68, 61)),
+    ('憤', (27, 73)),
+    ('憧', (27, 67)),
+    ('憨', (72, 55)),
+    ('憩', (29, 107)),
+    ('憪', (68, 69)),
+    ('憫', (27, 69)),
+    ('憬', (27, 71)),
+    ('憭', (68, 66)),
+    ('憮', (27, 75)),
+    ('憯', (68, 65)),
+    ('憰', (68, 60)),
+    ('憱', (68, 59)),
+    ('憲', (29, 105)),
+    ('憳', (68, 73)),
+    ('憴', (72, 58)),
+    ('憵', (75, 126)),
+    ('憶', (29, 110)),
+    ('憸', (72, 64)),
+    ('憺', (72, 62)),
+    ('憼', (75, 127)),
+    ('憾', (29, 111)),
+    ('憿', (72, 63)),
+    ('懁', (72, 60)),
+    ('懂', (31, 82)),
+    ('懃', (75, 125)),
+    ('懅', (72, 57)),
+    ('懆', (72, 59)),
+    ('懇', (31, 83)),
+    ('懈', (29, 113)),
+    ('應', (31, 81)),
+    ('懊', (29, 112)),
+    ('懋', (31, 85)),
+    ('懌', (72, 61)),
+    ('懍', (29, 109)),
+    ('懖', (78, 109)),
+    ('懘', (78, 101)),
+    ('懞', (75, 133)),
+    ('懟', (78, 102)),
+    ('懠', (75, 129)),
+    ('懣', (33, 22)),
+    ('懤', (75, 131)),
+    ('懥', (75, 130)),
+    ('懦', (31, 84)),
+    ('懧', (75, 128)),
+    ('懨', (75, 132)),
+    ('懩', (78, 110)),
+    ('懪', (78, 106)),
+    ('懫', (78, 108)),
+    ('懭', (78, 103)),
+    ('懮', (78, 104)),
+    ('懰', (78, 107)),
+    ('懱', (78, 105)),
+    ('懲', (34, 39)),
+    ('懵', (34, 42)),
+    ('懶', (34, 41)),
+    ('懷', (34, 40)),
+    ('懸', (35, 33)),
+    ('懹', (83, 10)),
+    ('懺', (35, 34)),
+    ('懻', (81, 1)),
+    ('懼', (35, 125)),
+    ('懽', (84, 106)),
+    ('懾', (35, 126)),
+    ('懿', (36, 52)),
+    ('戀', (36, 104)),
+    ('戁', (86, 119)),
+    ('戃', (86, 120)),
+    ('戄', (86, 121)),
+    ('戇', (88, 88)),
+    ('戈', (3, 126)),
+    ('戉', (40, 60)),
+    ('戊', (4, 81)),
+    ('戌', (5, 68)),
+    ('戍', (5, 69)),
+    ('戎', (5, 67)),
+    ('成', (5, 70)),
+    ('我', (6, 120)),
+    ('戒', (6, 119)),
+    ('戔', (43, 79)),
+    ('戕', (8, 109)),
+    ('或', (8, 108)),
+    ('戙', (48, 77)),
+    ('戚', (16, 75)),
+    ('戛', (16, 76)),
+    ('戟', (19, 53)),
+    ('戠', (60, 50)),
+    ('戡', (22, 32)),
+    ('戢', (22, 33)),
+    ('戣', (60, 52)),
+    ('戤', (60, 54)),
+    ('戥', (60, 53)),
+    ('戧', (64, 117)),
+    ('戩', (64, 116)),
+    ('截', (25, 9)),
+    ('戫', (64, 118)),
+    ('戭', (68, 74)),
+    ('戮', (27, 76)),
+    ('戰', (29, 114)),
+    ('戲', (31, 86)),
+    ('戳', (33, 23)),
+    ('戴', (31, 87)),
+    ('戶', (3, 127)),
+    ('戺', (41, 132)),
+    ('戽', (43, 80)),
+    ('戾', (8, 111)),
+    ('房', (8, 110)),
+    ('所', (8, 112)),
+    ('扁', (10, 145)),
+    ('扂', (45, 112)),
+    ('扃', (45, 113)),
+    ('扆', (48, 78)),
+    ('扇', (13, 78)),
+    ('扈', (16, 77)),
+    ('扉', (19, 54)),
+    ('扊', (56, 17)),
+    ('手', (3, 128)),
+    ('才', (3, 62)),
+    ('扎', (3, 129)),
+    ('扐', (40, 61)),
+    ('扑', (4, 85)),
+    ('扒', (4, 84)),
+    ('打', (4, 82)),
+    ('扔', (4, 83)),
+    ('托', (5, 73)),
+    ('扙', (40, 135)),
+    ('扚', (40, 137)),
+    ('扛', (5, 72)),
+    ('扜', (40, 129)),
+    ('扞', (40, 130)),
+    ('扠', (40, 136)),
+    ('扡', (40, 132)),
+    ('扢', (40, 134)),
+    ('扣', (5, 71)),
+    ('扤', (40, 131)),
+    ('扥', (40, 138)),
+    ('扦', (40, 133)),
+    ('扭', (6, 127)),
+    ('扮', (6, 136)),
+    ('扯', (6, 134)),
+    ('扰', (41, 142)),
+    ('扱', (41, 139)),
+    ('扲', (41, 147)),
+    ('扳', (6, 132)),
+    ('扴', (41, 148)),
+    ('扶', (6, 125)),
+    ('扷', (41, 145)),
+    ('批', (6, 131)),
+    ('扺', (41, 141)),
+    ('扻', (41, 140)),
+    ('扼', (6, 129)),
+    ('扽', (41, 146)),
+    ('找', (6, 130)),
+    ('承', (8, 113)),
+    ('技', (6, 124)),
+    ('抁', (41, 143)),
+    ('抃', (41, 133)),
+    ('抄', (6, 121)),
+    ('抆', (6, 140)),
+    ('抇', (41, 138)),
+    ('抈', (41, 144)),
+    ('抉', (6, 126)),
+    ('把', (6, 128)),
+    ('抌', (41, 134)),
+    ('抎', (41, 135)),
+    ('抏', (41, 136)),
+    ('抑', (6, 139)),
+    ('抒', (6, 133)),
+    ('抓', (6, 138)),
+    ('抔', (41, 137)),
+    ('投', (6, 137)),
+    ('抖', (6, 123)),
+    ('抗', (6, 122)),
+    ('折', (6, 135)),
+    ('抨', (8, 127)),
+    ('抩', (43, 92)),
+    ('抪', (43, 85)),
+    ('披', (8, 122)),
+    ('抬', (8, 141)),
+    ('抭', (43, 81)),
+    ('抮', (43, 88)),
+    ('抯', (43, 90)),
+    ('抰', (43, 93)),
+    ('抱', (8, 136)),
+    ('抳', (43, 89)),
+    ('抴', (43, 82)),
+    ('抵', (8, 134)),
+    ('抶', (43, 86)),
+    ('抸', (43, 94)),
+    ('抹', (8, 119)),
+    ('抻', (43, 91)),
+    ('押', (8, 129)),
+    ('抽', (8, 128)),
+    ('抾', (43, 84)),
+    ('抿', (8, 117)),
+    ('拂', (8, 118)),
+    ('拄', (8, 116)),
+    ('拆', (8, 140)),
+    ('拇', (8, 132)),
+    ('拈', (8, 126)),
+    ('拉', (8, 114)),
+    ('拊', (43, 87)),
+    ('拋', (8, 125)),
+    ('拌', (8, 115)),
+    ('拍', (8, 133)),
+    ('拎', (8, 142)),
+    ('拏', (45, 114)),
+    ('拐', (8, 130)),
+    ('拑', (43, 83)),
+    ('拒', (8, 120)),
+    ('拓', (8, 123)),
+    ('拔', (8, 124)),
+    ('拖', (8, 138)),
+    ('拗', (8, 139)),
+    ('拘', (8, 137)),
+    ('拙', (8, 131)),
+    ('拚', (8, 135)),
+    ('招', (8, 121)),
+    ('拜', (10, 146)),
+    ('拫', (45, 120)),
+    ('括', (11, 1)),
+    ('拭', (10, 150)),
+    ('拮', (10, 152)),
+    ('拯', (11, 0)),
+    ('拰', (45, 132)),
+    ('拱', (10, 155)),
+    ('拲', (48, 79)),
+    ('拳', (13, 79)),
+    ('拴', (11, 3)),
+    ('拵', (45, 117)),
+    ('拶', (45, 125)),
+    ('拷', (10, 156)),
+    ('拸', (45, 124)),
+    ('拹', (45, 121)),
+    ('拺', (45, 129)),
+    ('拻', (45, 131)),
+    ('拼', (10, 149)),
+    ('拽', (10, 153)),
+    ('拾', (11, 2)),
+    ('拿', (13, 81)),
+    ('挀', (45, 126)),
+    ('持', (10, 151)),
+    ('挂', (11, 5)),
+    ('挃', (45, 119)),
+    ('指', (10, 154)),
+    ('挈', (13, 80)),
+    ('按', (10, 148)),
+    ('挋', (45, 116)),
+    ('挌', (45, 123)),
+    ('挍', (45, 115)),
+    ('挎', (45, 118)),
+    ('挏', (45, 122)),
+    ('挐', (48, 80)),
+    ('挑', (11, 4)),
+    ('挓', (45, 127)),
+    ('挔', (45, 128)),
+    ('挕', (45, 130)),
+    ('挖', (10, 147)),
+    ('挨', (13, 95)),
+    ('挩', (48, 92)),
+    ('挪', (13, 93)),
+    ('挫', (13, 94)),
+    ('挬', (48, 82)),
+    ('挭', (48, 98)),
+    ('振', (13, 84)),
+    ('挲', (52, 38)),
+    ('挳', (48, 100)),
+    ('挴', (48, 94)),
+    ('挶', (48, 85)),
+    ('挸', (48, 103)),
+    ('挹', (48, 88)),
+    ('挺', (13, 90)),
+    ('挻', (52, 61)),
+    ('挼', (48, 91)),
+    ('挽', (13, 92)),
+    ('挾', (13, 83)),
+    ('捀', (48, 105)),
+    ('捁', (48, 93)),
+    ('捂', (13, 86)),
+    ('捃', (48, 86)),
+    ('捄', (48, 83)),
+    ('捅', (48, 84)),
+    ('捆', (13, 87)),
+    ('捇', (48, 99)),
+    ('捈', (48, 106)),
+    ('捉', (13, 89)),
+    ('捊', (48, 90)),
+    ('捋', (48, 89)),
+    ('捌', (13, 97)),
+    ('捍', (13, 96)),
+    ('捎', (13, 82)),
+    ('捏', (13, 88)),
+    ('捐', (13, 91)),
+    ('捑', (48, 102)),
+    ('捔', (48, 96)),
+    ('捕', (13, 85)),
+    ('捖', (48, 81)),
+    ('捗', (48, 104)),
+    ('捘', (48, 95)),
+    ('捙', (48, 97)),
+    ('捚', (48, 101)),
+    ('捥', (52, 39)),
+    ('捧', (16, 85)),
+    ('捨', (16, 105)),
+    ('捩', (16, 104)),
+    ('捫', (16, 93)),
+    ('捭', (52, 57)),
+    ('据', (52, 53)),
+    ('捯', (52, 50)),
+    ('捰', (52, 68)),
+    ('捱', (16, 88)),
+    ('捲', (16, 80)),
+    ('捵', (52, 55)),
+    ('捶', (19, 70)),
+    ('捷', (16, 84)),
+    ('捸', (52, 63)),
+    ('捺', (16, 106)),
+    ('捻', (16, 103)),
+    ('捼', (52, 59)),
+    ('捽', (52, 42)),
+    ('掀', (16, 102)),
+    ('掁', (52, 65)),
+    ('掂', (52, 41)),
+    ('掃', (16, 91)),
+    ('掄', (16, 95)),
+    ('掅', (52, 64)),
+    ('掇', (52, 51)),
+    ('授', (16, 96)),
+    ('掉', (16, 90)),
+    ('掊', (52, 40)),
+    ('掌', (19, 56)),
+    ('掍', (52, 67)),
+    ('掎', (52, 49)),
+    ('掏', (16, 101)),
+    ('掐', (52, 52)),
+    ('掑', (52, 66)),
+    ('排', (16, 100)),
+    ('掔', (56, 18)),
+    ('掖', (16, 81)),
+    ('掗', (52, 47)),
+    ('掘', (16, 86)),
+    ('掙', (16, 97)),
+    ('掛', (16, 92)),
+    ('掜', (52, 56)),
+    ('掝', (52, 46)),
+    ('掞', (52, 44)),
+    ('掟', (52, 62)),
+    ('掠', (16, 78)),
+    ('採', (16, 98)),
+    ('探', (16, 82)),
+    ('掣', (19, 55)),
+    ('掤', (52, 60)),
+    ('接', (16, 83)),
+    ('控', (16, 79)),
+    ('推', (16, 94)),
+    ('掩', (16, 89)),
+    ('措', (16, 87)),
+    ('掫', (52, 48)),
+    ('掬', (16, 99)),
+    ('掭', (52, 45)),
+    ('掮', (52, 58)),
+    ('掯', (52, 54)),
+    ('掰', (56, 20)),
+    ('掱', (56, 19)),
+    ('掽', (52, 43)),
+    ('掾', (56, 36)),
+    ('揀', (19, 58)),
+    ('揂', (56, 42)),
+    ('揃', (56, 25)),
+    ('揄', (56, 39)),
+    ('揅', (60, 55)),
+    ('揆', (19, 61)),
+    ('揇', (56, 43)),
+    ('揈', (56, 46)),
+    ('揉', (19, 60)),
+    ('揊', (56, 28)),
+    ('揋', (56, 45)),
+    ('揌', (56, 44)),
+    ('揍', (19, 62)),
+    ('揎', (56, 21)),
+    ('描', (19, 57)),
+    ('提', (19, 65)),
+    ('插', (19, 63)),
+    ('揓', (56, 41)),
+    ('揕', (56, 31)),
+    ('揖', (19, 67)),
+    ('揗', (56, 48)),
+    ('揘', (56, 40)),
+    ('揙', (56, 49)),
+    ('揚', (19, 75)),
+    ('換', (19, 73)),
+    ('揜', (56, 38)),
+    ('揝', (56, 37)),
+    ('揟', (56, 35)),
+    ('揠', (56, 29)),
+    ('握', (19, 66)),
+    ('揣', (19, 64)),
+    ('揤', (48, 87)),
+    ('揥', (56, 22)),
+    ('揧', (60, 85)),
+    ('揨', (56, 23)),
+    ('揩', (19, 59)),
+    ('揪', (19, 72)),
+    ('揫', (60, 57)),
+    ('揭', (19, 68)),
+    ('揮', (19, 69)),
+    ('揯', (56, 24)),
+    ('揰', (56, 47)),
+    ('揱', (60, 56)),
+    ('揲', (56, 32)),
+    ('揳', (56, 27)),
+    ('援', (19, 71)),
+    ('揵', (56, 33)),
+    ('揶', (56, 30)),
+    ('揹', (19, 76)),
+    ('搆', (22, 48)),
+    ('搉', (60, 60)),
+    ('搊', (60, 79)),
+    ('搋', (60, 84)),
+    ('搌', (60, 72)),
+    ('損', (22, 44)),
+    ('搎', (60, 89)),
+    ('搏', (22, 41)),
+    ('搐', (60, 58)),
+    ('搒', (60, 59)),
+    ('搓', (22, 34)),
+    ('搔', (22, 43)),
+    ('搕', (60, 66)),
+    ('搖', (22, 46)),
+    ('搗', (22, 47)),
+    ('搘', (60, 67)),
+    ('搚', (60, 80)),
+    ('搛', (60, 86)),
+    ('搜', (22, 42)),
+    ('搞', (22, 36)),
+    ('搟', (60, 65)),
+    ('搠', (60, 61)),
+    ('搡', (60, 88)),
+    ('搢', (60, 70)),
+    ('搣', (60, 71)),
+    ('搤', (60, 62)),
+    ('搥', (60, 82)),
+    ('搦', (60, 73)),
+    ('搧', (60, 83)),
+    ('搨', (60, 75)),
+    ('搪', (22, 37)),
+    ('搫', (64, 119)),
+    ('搬', (22, 40)),
+    ('搭', (22, 38)),
+    ('搮', (60, 87)),
+    ('搯', (60, 78)),
+    ('搰', (60, 74)),
+    ('搳', (60, 63)),
+    ('搴', (25, 19)),
+    ('搵', (60, 77)),
+    ('搶', (22, 45)),
+    ('搷', (60, 69)),
+    ('搹', (60, 68)),
+    ('搽', (22, 39)),
+    ('搾', (22, 35)),
+    ('搿', (64, 140)),
+    ('摀', (60, 81)),
+    ('摁', (60, 76)),
+    ('摃', (60, 64)),
+    ('摋', (64, 135)),
+    ('摍', (64, 120)),
+    ('摎', (64, 131)),
+    ('摐', (64, 138)),
+    ('摑', (25, 17)),
+    ('摒', (19, 74)),
+    ('摓', (64, 136)),
+    ('摔', (25, 12)),
+    ('摘', (25, 11)),
+    ('摙', (64, 143)),
+    ('摛', (64, 121)),
+    ('摜', (64, 134)),
+    ('摝', (64, 122)),
+    ('摞', (64, 133)),
+    ('摟', (25, 15)),
+    ('摠', (64, 137)),
+    ('摡', (56, 34)),
+    ('摥', (64, 144)),
+    ('摦', (64, 129)),
+    ('摧', (25, 18)),
+    ('摨', (68, 88)),
+    ('摩', (27, 77)),
+    ('摫', (64, 142)),
+    ('摬', (64, 141)),
+    ('摭', (25, 20)),
+    ('摮', (68, 75)),
+    ('摯', (27, 78)),
+    ('摰', (68, 76)),
+    ('摲', (64, 125)),
+    ('摳', (64, 126)),
+    ('摴', (64, 123)),
+    ('摵', (64, 128)),
+    ('摶', (64, 124)),
+    ('摷', (64, 145)),
+    ('摸', (25, 14)),
+    ('摹', (27, 79)),
+    ('摺', (25, 16)),
+    ('摻', (25, 21)),
+    ('摽', (64, 127)),
+    ('摿', (64, 139)),
+    ('撂', (64, 132)),
+    ('撅', (68, 79)),
+    ('撇', (25, 10)),
+    ('撈', (27, 82)),
+    ('撉', (72, 72)),
+    ('撊', (68, 84)),
+    ('撋', (68, 83)),
+    ('撌', (68, 85)),
+    ('撏', (68, 82)),
+    ('撐', (27, 83)),
+    ('撒', (27, 89)),
+    ('撓', (27, 86)),
+    ('撕', (27, 87)),
+    ('撖', (68, 77)),
+    ('撗', (68, 80)),
+    ('撘', (68, 90)),
+    ('撙', (27, 95)),
+    ('撚', (27, 93)),
+    ('撜', (68, 81)),
+    ('撝', (56, 26)),
+    ('撞', (27, 80)),
+    ('撟', (68, 87)),
+    ('撠', (68, 78)),
+    ('撢', (27, 96)),
+    ('撣', (68, 86)),
+    ('撤', (25, 13)),
+    ('撥', (27, 85)),
+    ('撦', (64, 130)),
+    ('撩', (27, 88)),
+    ('撫', (27, 92)),
+    ('撬', (27, 94)),
+    ('播', (27, 91)),
+    ('撮', (27, 90)),
+    ('撰', (27, 84)),
+    ('撱', (68, 89)),
+    ('撲', (27, 81)),
+    ('撳', (27, 97)),
+    ('撻', (29, 118)),
+    ('撼', (29, 119)),
+    ('撽', (72, 71)),
+    ('撾', (29, 128)),
+    ('撿', (29, 125)),
+    ('擁', (29, 116)),
+    ('擂', (29, 123)),
+    ('擃', (72, 73)),
+    ('擄', (29, 121)),
+    ('擅', (29, 115)),
+    ('擇', (29, 122)),
+    ('擉', (72, 70)),
+    ('擊', (31, 89)),
+    ('擋', (29, 117)),
+    ('操', (29, 124)),
+    ('擎', (31, 88)),
+    ('擏', (72, 69)),
+    ('擐', (72, 68)),
+    ('擒', (29, 126)),
+    ('擔', (29, 127)),
+    ('擖', (72, 67)),
+    ('擗', (72, 66)),
+    ('擘', (31, 90)),
+    ('擙', (72, 76)),
+    ('據', (29, 120)),
+    ('擛', (72, 74)),
+    ('擠', (31, 91)),
+    ('擢', (31, 96)),
+    ('擣', (75, 136)),
+    ('擤', (75, 138)),
+    ('擦', (31, 93)),
+    ('擨', (75, 139)),
+    ('擩', (75, 135)),
+    ('擫', (75, 137)),
+    ('擬', (31, 94)),
+    ('擭', (31, 97)),
+    ('擯', (75, 134)),
+    ('擰', (31, 92)),
+    ('擱', (31, 95)),
+    ('擲', (33, 25)),
+    ('擳', (72, 75)),
+    ('擴', (33, 24)),
+    ('擷', (33, 30)),
+    ('擸', (78, 114)),
+    ('擺', (33, 28)),
+    ('擻', (33, 29)),
+    ('擼', (78, 117)),
+    ('擽', (78, 113)),
+    ('擾', (33, 26)),
+    ('擿', (78, 111)),
+    ('攀', (34, 43)),
+    ('攁', (78, 115)),
+    ('攃', (78, 116)),
+    ('攄', (78, 112)),
+    ('攆', (33, 27)),
+    ('攇', (81, 2)),
+    ('攉', (81, 5)),
+    ('攌', (81, 6)),
+    ('攍', (81, 4)),
+    ('攎', (81, 7)),
+    ('攏', (34, 44)),
+    ('攐', (81, 3)),
+    ('攓', (83, 14)),
+    ('攔', (35, 36)),
+    ('攕', (83, 13)),
+    ('攖', (83, 12)),
+    ('攗', (83, 11)),
+    ('攘', (35, 35)),
+    ('攙', (35, 37)),
+    ('攛', (84, 107)),
+    ('攜', (35, 128)),
+    ('攝', (35, 127)),
+    ('攠', (85, 127)),
+    ('攡', (85, 126)),
+    ('攢', (85, 129)),
+    ('攣', (36, 105)),
+    ('攤', (36, 53)),
+    ('攥', (86, 123)),
+    ('攦', (85, 128)),
+    ('攩', (86, 122)),
+    ('攪', (36, 107)),
+    ('攫', (36, 106)),
+    ('攬', (36, 145)),
+    ('攭', (87, 71)),
+    ('攮', (87, 141)),
+    ('支', (3, 130)),
+    ('攲', (56, 50)),
+    ('攳', (72, 77)),
+    ('收', (5, 74)),
+    ('攷', (41, 149)),
+    ('攸', (6, 143)),
+    ('改', (6, 141)),
+    ('攻', (6, 142)),
+    ('攽', (43, 95)),
+    ('放', (8, 143)),
+    ('政', (11, 6)),
+    ('敁', (45, 133)),
+    ('敃', (45, 134)),
+    ('故', (11, 7)),
+    ('敆', (48, 108)),
+    ('效', (13, 98)),
+    ('敉', (13, 99)),
+    ('敊', (48, 107)),
+    ('敏', (16, 113)),
+    ('救', (16, 109)),
+    ('敓', (52, 69)),
+    ('敔', (16, 116)),
+    ('敕', (16, 115)),
+    ('敖', (16, 108)),
+    ('敗', (16, 111)),
+    ('敘', (16, 114)),
+    ('教', (16, 110)),
+    ('敜', (56, 54)),
+    ('敝', (16, 107)),
+    ('敞', (19, 77)),
+    ('敢', (19, 79)),
+    ('散', (19, 80)),
+    ('敤', (56, 53)),
+    ('敥', (56, 56)),
+    ('敦', (19, 78)),
+    ('敧', (56, 51)),
+    ('敨', (56, 55)),
+    ('敪', (56, 52)),
+    ('敬', (22, 49)),
+    ('敯', (60, 90)),
+    ('敲', (25, 22)),
+    ('敳', (64, 146)),
+    ('整', (29, 129)),
+    ('敵', (27, 98)),
+    ('敶', (68, 91)),
+    ('敷', (27, 99)),
+    ('數', (27, 100)),
+    ('敹', (68, 93)),
+    ('敺', (68, 92)),
+    ('敻', (68, 94)),
+    ('敼', (72, 79)),
+    ('敿', (72, 78)),
+    ('斀', (75, 141)),
+    ('斁', (75, 140)),
+    ('斂', (31, 98)),
+    ('斃', (31, 99)),
+    ('斄', (81, 8)),
+    ('文', (3, 131)),
+    ('斌', (56, 57)),
+    ('斐', (19, 82)),
+    ('斑', (19, 81)),
+    ('斒', (60, 91)),
+    ('斔', (78, 118)),
+    ('斕', (35, 129)),
+    ('斖', (86, 124)),
+    ('斗', (3, 132)),
+    ('料', (13, 100)),
+    ('斛', (16, 118)),
+    ('斜', (16, 117)),
+    ('斝', (56, 58)),
+    ('斞', (56, 59)),
+    ('斟', (22, 50)),
+    ('斠', (64, 147)),
+    ('斡', (25, 23)),
+    ('斢', (72, 80)),
+    ('斤', (3, 133)),
+    ('斥', (4, 86)),
+    ('斧', (8, 144)),
+    ('斨', (43, 96)),
+    ('斪', (45, 135)),
+    ('斫', (11, 8)),
+    ('斬', (16, 119)),
+    ('斮', (56, 60)),
+    ('斯', (19, 83)),
+    ('新', (22, 51)),
+    ('斲', (68, 95)),
+    ('斳', (68, 96)),
+    ('斶', (75, 142)),
+    ('斷', (33, 31)),
+    ('斸', (87, 142)),
+    ('方', (3, 134)),
+    ('斻', (43, 97)),
+    ('於', (8, 145)),
+    ('施', (11, 9)),
+    ('斿', (45, 136)),
+    ('旁', (13, 101)),
+    ('旂', (48, 112)),
+    ('旃', (48, 110)),
+    ('旄', (48, 111)),
+    ('旅', (13, 102)),
+    ('旆', (48, 109)),
+    ('旋', (16, 121)),
+    ('旌', (16, 122)),
+    ('旍', (52, 70)),
+    ('旎', (16, 123)),
+    ('族', (16, 120)),
+    ('旐', (56, 61)),
+    ('旒', (56, 62)),
+    ('旓', (60, 92)),
+    ('旖', (25, 25)),
+    ('旗', (25, 24)),
+    ('旚', (75, 143)),
+    ('旛', (78, 119)),
+    ('旝', (81, 10)),
+    ('旞', (81, 9)),
+    ('旟', (83, 15)),
+    ('旡', (40, 30)),
+    ('既', (11, 10)),
+    ('日', (3, 135)),
+    ('旦', (4, 87)),
+    ('旨', (5, 76)),
+    ('早', (5, 75)),
+    ('旬', (5, 77)),
+    ('旭', (5, 78)),
+    ('旮', (40, 140)),
+    ('旯', (40, 139)),
+    ('旰', (41, 150)),
+    ('旱', (6, 144)),
+    ('旲', (41, 153)),
+    ('旳', (41, 152)),
+    ('旴', (41, 151)),
+    ('旵', (41, 154)),
+    ('旺', (8, 146)),
+    ('旻', (43, 103)),
+    ('旼', (43, 99)),
+    ('旽', (43, 108)),
+    ('昀', (8, 153)),
+    ('昂', (8, 151)),
+    ('昃', (43, 104)),
+    ('昄', (43, 100)),
+    ('昅', (43, 107)),
+    ('昆', (8, 150)),
+    ('昇', (9, 0)),
+    ('昈', (43, 102)),
+    ('昉', (43, 98)),
+    ('昊', (8, 156)),
+    ('昋', (43, 105)),
+    ('昌', (8, 149)),
+    ('昍', (43, 106)),
+    ('明', (8, 152)),
+    ('昏', (8, 154)),
+    ('昐', (43, 110)),
+    ('昑', (43, 109)),
+    ('昒', (43, 101)),
+    ('易', (8, 148)),
+    ('昔', (8, 147)),
+    ('昕', (8, 155)),
+    ('昜', (45, 141)),
+    ('昝', (45, 147)),
+    ('星', (11, 16)),
+    ('映', (11, 13)),
+    ('昡', (45, 138)),
+    ('昢', (45, 143)),
+    ('昤', (11, 19)),
+    ('春', (11, 11)),
+    ('昦', (45, 142)),
+    ('昧', (11, 14)),
+    ('昨', (11, 17)),
+    ('昫', (45, 145)),
+    ('昭', (11, 12)),
+    ('昮', (45, 150)),
+    ('是', (11, 15)),
+    ('昱', (11, 18)),
+    ('昲', (45, 139)),
+    ('昳', (45, 144)),
+    ('昴', (45, 148)),
+    ('昵', (45, 140)),
+    ('昶', (45, 137)),
+    ('昹', (45, 149)),
+    ('昺', (45, 146)),
+    ('晁', (13, 110)),
+    ('時', (13, 103)),
+    ('晃', (13, 106)),
+    ('晅', (13, 109)),
+    ('晇', (48, 115)),
+    ('晉', (13, 104)),
+    ('晊', (48, 113)),
+    ('晌', (13, 108)),
+    ('晏', (13, 105)),
+    ('晑', (48, 116)),
+    ('晒', (13, 107)),
+    ('晙', (52, 74)),
+    ('晚', (16, 125)),
+    ('晛', (52, 73)),
+    ('晜', (52, 75)),
+    ('晝', (16, 124)),
+    ('晞', (16, 129)),
+    ('晟', (48, 114)),
+    ('晡', (52, 72)),
+    ('晢', (52, 76)),
+    ('晤', (16, 126)),
+    ('晥', (52, 71)),
+    ('晦', (16, 128)),
+    ('晨', (16, 127)),
+    ('晪', (56, 69)),
+    ('晬', (56, 64)),
+    ('普', (19, 84)),
+    ('景', (19, 88)),
+    ('晰', (19, 85)),
+    ('晱', (56, 67)),
+    ('晲', (56, 70)),
+    ('晴', (19, 86)),
+    ('晶', (19, 87)),
+    ('晷', (19, 92)),
+    ('晸', (60, 101)),
+    ('晹', (56, 68)),
+    ('智', (19, 90)),
+    ('晻', (56, 65)),
+    ('晼', (56, 63)),
+    ('晾', (19, 91)),
+    ('暀', (56, 66)),
+    ('暄', (22, 57)),
+    ('暆', (60, 93)),
+    ('暇', (22, 54)),
+    ('暈', (22, 55)),
+    ('暉', (22, 53)),
+    ('暊', (60, 98)),
+    ('暋', (60, 97)),
+    ('暌', (60, 94)),
+    ('暍', (22, 59)),
+    ('暐', (60, 96)),
+    ('暑', (19, 89)),
+    ('暔', (60, 100)),
+    ('暕', (60, 95)),
+    ('暖', (22, 56)),
+    ('暗', (22, 52)),
+    ('暘', (22, 58)),
+    ('暙', (60, 99)),
+    ('暝', (25, 28)),
+    ('暟', (64, 150)),
+    ('暠', (64, 149)),
+    ('暡', (64, 148)),
+    ('暢', (25, 26)),
+    ('暨', (25, 27)),
+    ('暩', (68, 99)),
+    ('暪', (68, 102)),
+    ('暫', (27, 102)),
+    ('暮', (27, 101)),
+    ('暯', (68, 103)),
+    ('暰', (68, 98)),
+    ('暱', (27, 104)),
+    ('暲', (68, 100)),
+    ('暴', (27, 103)),
+    ('暵', (68, 97)),
+    ('暷', (68, 101)),
+    ('暸', (29, 135)),
+    ('暹', (29, 132)),
+    ('暺', (72, 89)),
+    ('暻', (72, 88)),
+    ('暽', (72, 87)),
+    ('暾', (72, 82)),
+    ('曀', (72, 83)),
+    ('曄', (29, 133)),
+    ('曆', (29, 130)),
+    ('曇', (29, 134)),
+    ('曈', (72, 81)),
+    ('曉', (29, 131)),
+    ('曊', (72, 84)),
+    ('曋', (72, 85)),
+    ('曌', (72, 90)),
+    ('曏', (72, 86)),
+    ('曒', (75, 144)),
+    ('曖', (31, 101)),
+    ('曘', (78, 122)),
+    ('曙', (31, 100)),
+    ('曚', (78, 120)),
+    ('曛', (78, 121)),
+    ('曜', (33, 32)),
+    ('曝', (34, 46)),
+    ('曞', (81, 11)),
+    ('曠', (34, 45)),
+    ('曣', (83, 17)),
+    ('曤', (83, 18)),
+    ('曦', (35, 38)),
+    ('曨', (83, 16)),
+    ('曩', (35, 130)),
+    ('曫', (86, 125)),
+    ('曬', (36, 108)),
+    ('曭', (87, 72)),
+    ('曮', (87, 73)),
+    ('曰', (3, 136)),
+    ('曲', (5, 79)),
+    ('曳', (5, 80)),
+    ('更', (6, 145)),
+    ('曶', (43, 111)),
+    ('曷', (11, 20)),
+    ('書', (13, 111)),
+    ('曹', (16, 130)),
+    ('曼', (15, 112)),
+    ('曾', (19, 93)),
+    ('替', (19, 94)),
+    ('最', (18, 106)),
+    ('朁', (56, 71)),
+    ('會', (22, 60)),
+    ('朄', (64, 152)),
+    ('朅', (64, 151)),
+    ('月', (3, 137)),
+    ('有', (5, 81)),
+    ('朊', (43, 112)),
+    ('朋', (9, 2)),
+    ('服', (9, 1)),
+    ('朏', (45, 151)),
+    ('朐', (45, 152)),
+    ('朒', (48, 117)),
+    ('朓', (48, 118)),
+    ('朔', (13, 112)),
+    ('朕', (13, 113)),
+    ('朗', (13, 114)),
+    ('朘', (52, 77)),
+    ('望', (16, 132)),
+    ('朝', (19, 96)),
+    ('期', (19, 95)),
+    ('朠', (60, 102)),
+    ('朡', (62, 58)),
+    ('朢', (64, 153)),
+    ('朣', (72, 91)),
+    ('朦', (33, 33)),
+    ('朧', (35, 39)),
+    ('木', (3, 138)),
+    ('未', (4, 90)),
+    ('末', (4, 91)),
+    ('本', (4, 89)),
+    ('札', (4, 92)),
+    ('朮', (4, 88)),
+    ('朱', (5, 84)),
+    ('朳', (40, 148)),
+    ('朴', (5, 83)),
+    ('朵', (5, 85)),
+    ('朸', (40, 143)),
+    ('朹', (40, 142)),
+    ('机', (40, 145)),
+    ('朻', (40, 144)),
+    ('朼', (40, 147)),
+    ('朽', (5, 82)),
+    ('朾', (40, 141)),
+    ('朿', (40, 146)),
+    ('杅', (41, 155)),
+    ('杆', (6, 155)),
+    ('杇', (41, 156)),
+    ('杈', (42, 3)),
+    ('杉', (6, 154)),
+    ('杋', (42, 7)),
+    ('杌', (42, 2)),
+    ('杍', (42, 5)),
+    ('李', (6, 147)),
+    ('杏', (6, 148)),
+    ('材', (6, 149)),
+    ('村', (6, 150)),
+    ('杓', (7, 0)),
+    ('杕', (42, 1)),
+    ('杖', (6, 152)),
+    ('杗', (7, 1)),
+    ('杙', (42, 0)),
+    ('杚', (42, 6)),
+    ('杜', (6, 151)),
+    ('杝', (42, 4)),
+    ('杞', (6, 153)),
+    ('束', (6, 146)),
+    ('杠', (6, 156)),
+    ('杪', (9, 23)),
+    ('杬', (43, 114)),
+    ('杭', (9, 3)),
+    ('杯', (9, 13)),
+    ('杰', (9, 14)),
+    ('東', (9, 6)),
+    ('杲', (9, 24)),
+    ('杳', (9, 8)),
+    ('杴', (43, 122)),
+    ('杵', (9, 19)),
+    ('杶', (43, 117)),
+    ('杷', (9, 9)),
+    ('杸', (43, 132)),
+    ('杹', (43, 133)),
+    ('杺', (43, 125)),
+    ('杻', (43, 118)),
+    ('杼', (9, 22)),
+    ('杽', (43, 130)),
+    ('松', (9, 17)),
+    ('板', (9, 15)),
+    ('极', (43, 131)),
+    ('枃', (43, 129)),
+    ('构', (43, 121)),
+    ('枅', (43, 113)),
+    ('枆', (43, 120)),
+    ('枇', (9, 10)),
+    ('枉', (9, 16)),
+    ('枋', (9, 4)),
+    ('枌', (43, 124)),
+    ('枍', (43, 123)),
+    ('枎', (43, 115)),
+    ('析', (9, 18)),
+    ('枑', (43, 127)),
+    ('枒', (43, 116)),
+    ('枓', (9, 21)),
+    ('枔', (43, 134)),
+    ('枕', (9, 5)),
+    ('林', (9, 12)),
+    ('枘', (43, 119)),
+    ('枙', (43, 128)),
+    ('枚', (9, 20)),
+    ('果', (9, 7)),
+    ('枝', (9, 11)),
+    ('枟', (43, 126)),
+    ('枮', (46, 27)),
+    ('枯', (11, 28)),
+    ('枰', (11, 41)),
+    ('枲', (46, 22)),
+    ('枳', (46, 12)),
+    ('枴', (11, 34)),
+    ('枵', (46, 10)),
+    ('架', (11, 27)),
+    ('枷', (46, 5)),
+    ('枸', (11, 37)),
+    ('枹', (46, 18)),
+    ('枺', (45, 156)),
+    ('枻', (46, 1)),
+    ('柀', (46, 4)),
+    ('柁', (45, 153)),
+    ('柂', (46, 17)),
+    ('柃', (46, 33)),
+    ('柄', (11, 32)),
+    ('柅', (46, 6)),
+    ('柆', (46, 24)),
+    ('柈', (45, 155)),
+    ('柉', (46, 31)),
+    ('柊', (46, 32)),
+    ('柋', (46, 35)),
+    ('柌', (46, 26)),
+    ('柍', (46, 11)),
+    ('柎', (46, 19)),
+    ('柏', (11, 38)),
+    ('某', (11, 25)),
+    ('柑', (11, 33)),
+    ('柒', (11, 45)),
+    ('染', (11, 22)),
+    ('柔', (11, 24)),
+    ('柘', (46, 3)),
+    ('柙', (11, 42)),
+    ('柚', (11, 35)),
+    ('柛', (46, 29)),
+    ('柜', (46, 0)),
+    ('柝', (11, 44)),
+    ('柞', (11, 39)),
+    ('柟', (46, 9)),
+    ('柢', (11, 43)),
+    ('柣', (46, 16)),
+    ('柤', (46, 8)),
+    ('查', (11, 36)),
+    ('柦', (46, 28)),
+    ('柧', (46, 20)),
+    ('柩', (11, 30)),
+    ('柪', (46, 34)),
+    ('柫', (46, 7)),
+    ('柬', (11, 26)),
+    ('柭', (46, 25)),
+    ('柮', (46, 15)),
+    ('柯', (11, 31)),
+    ('柰', (46, 21)),
+    ('柱', (11, 23)),
+    ('柲', (45, 154)),
+    ('柳', (11, 40)),
+    ('柴', (13, 129)),
+    ('柵', (11, 29)),
+    ('柶', (46, 14)),
+    ('柷', (46, 13)),
+    ('柸', (46, 2)),
+    ('柺', (46, 30)),
+    ('柼', (46, 23)),
+    ('柿', (11, 21)),
+    ('栒', (48, 138)),
+    ('栓', (13, 136)),
+    ('栔', (48, 139)),
+    ('栖', (48, 127)),
+    ('栗', (13, 125)),
+    ('栘', (13, 137)),
+    ('栚', (48, 120)),
+    ('栜', (48, 129)),
+    ('栝', (48, 137)),
+    ('栟', (48, 119)),
+    ('栠', (48, 146)),
+    ('校', (13, 115)),
+    ('栥', (48, 145)),
+    ('栦', (48, 140)),
+    ('栨', (48, 141)),
+    ('栩', (13, 123)),
+    ('株', (13, 134)),
+    ('栫', (48, 131)),
+    ('栭', (48, 132)),
+    ('栮', (48, 142)),
+    ('栯', (48, 133)),
+    ('栱', (48, 128)),
+    ('栲', (48, 122)),
+    ('栳', (48, 123)),
+    ('栴', (48, 136)),
+    ('栵', (48, 130)),
+    ('核', (13, 116)),
+    ('根', (13, 120)),
+    ('栺', (48, 144)),
+    ('栻', (48, 124)),
+    ('格', (13, 132)),
+    ('栽', (13, 128)),
+    ('桀', (13, 131)),
+    ('桁', (13, 138)),
+    ('桂', (13, 121)),
+    ('桃', (13, 133)),
+    ('桄', (48, 135)),
+    ('桅', (13, 135)),
+    ('框', (13, 118)),
+    ('案', (13, 117)),
+    ('桉', (48, 121)),
+    ('桋', (48, 125)),
+    ('桌', (13, 126)),
+    ('桍', (48, 143)),
+    ('桎', (48, 134)),
+    ('桏', (48, 126)),
+    ('桐', (13, 130)),
+    ('桑', (13, 127)),
+    ('桓', (13, 119)),
+    ('桔', (13, 122)),
+    ('桫', (52, 98)),
+    ('桭', (52, 82)),
+    ('桮', (52, 83)),
+    ('桯', (52, 87)),
+    ('桱', (52, 102)),
+    ('桲', (52, 99)),
+    ('桴', (52, 92)),
+    ('桵', (52, 91)),
+    ('桶', (16, 139)),
+    ('桷', (52, 95)),
+    ('桸', (52, 110)),
+    ('桹', (52, 78)),
+    ('桻', (52, 111)),
+    ('桼', (52, 97)),
+    ('桽', (52, 115)),
+    ('桾', (52, 103)),
+    ('桿', (16, 138)),
+    ('梀', (52, 101)),
+    ('梁', (16, 133)),
+    ('梂', (16, 154)),
+    ('梃', (16, 144)),
+    ('梅', (16, 148)),
+    ('梆', (16, 147)),
+    ('梇', (52, 79)),
+    ('梉', (52, 108)),
+    ('梊', (52, 114)),
+    ('梋', (52, 106)),
+    ('梌', (52, 113)),
+    ('梏', (52, 94)),
+    ('梐', (52, 80)),
+    ('梑', (52, 112)),
+    ('梒', (52, 96)),
+    ('梓', (16, 136)),
+    ('梔', (16, 149)),
+    ('梖', (52, 105)),
+    ('梗', (16, 142)),
+    ('梛', (52, 104)),
+    ('梜', (52, 81)),
+    ('條', (16, 150)),
+    ('梟', (16, 152)),
+    ('梠', (52, 107)),
+    ('梡', (16, 153)),
+    ('梢', (16, 135)),
+    ('梣', (52, 88)),
+    ('梤', (52, 109)),
+    ('梧', (16, 141)),
+    ('梨', (16, 151)),
+    ('梩', (52, 90)),
+    ('梪', (52, 100)),
+    ('梫', (52, 85)),
+    ('梬', (52, 89)),
+    ('梭', (16, 146)),
+    ('梮', (52, 84)),
+    ('梯', (16, 134)),
+    ('械', (16, 143)),
+    ('梱', (16, 140)),
+    ('梲', (52, 93)),
+    ('梳', (13, 124)),
+    ('梴', (56, 94)),
+    ('梵', (16, 137)),
+    ('棄', (16, 145)),
+    ('棆', (56, 97)),
+    ('棇', (56, 119)),
+    ('棈', (56, 108)),
+    ('棉', (19, 116)),
+    ('棋', (19, 111)),
+    ('棌', (56, 91)),
+    ('棍', (19, 112)),
+    ('棎', (56, 107)),
+    ('棐', (56, 100)),
+    ('棑', (56, 113)),
+    ('棒', (19, 108)),
+    ('棓', (56, 73)),
+    ('棔', (56, 115)),
+    ('棕', (19, 98)),
+    ('棖', (56, 81)),
+    ('棗', (19, 101)),
+    ('棘', (19, 100)),
+    ('棚', (19, 117)),
+    ('棜', (56, 75)),
+    ('棝', (56, 109)),
+    ('棞', (56, 110)),
+    ('棟', (19, 103)),
+    ('棠', (19, 99)),
+    ('棡', (56, 89)),
+    ('棣', (19, 110)),
+    ('棤', (56, 84)),
+    ('棦', (56, 111)),
+    ('棧', (19, 106)),
+    ('棨', (56, 103)),
+    ('棩', (56, 116)),
+    ('棪', (56, 78)),
+    ('棫', (56, 83)),
+    ('棬', (56, 77)),
+    ('森', (19, 105)),
+    ('棯', (56, 96)),
+    ('棰', (60, 124)),
+    ('棱', (56, 79)),
+    ('棲', (19, 109)),
+    ('棳', (56, 88)),
+    ('棴', (56, 112)),
+    ('棵', (19, 104)),
+    ('棶', (56, 85)),
+    ('棷', (56, 82)),
+    ('棸', (56, 99)),
+    ('棹', (19, 107)),
+    ('棺', (19, 97)),
+    ('棻', (19, 119)),
+    ('棼', (56, 102)),
+    ('棽', (56, 101)),
+    ('椄', (56, 74)),
+    ('椅', (19, 102)),
+    ('椆', (56, 114)),
+    ('椇', (56, 90)),
+    ('椈', (56, 92)),
+    ('椊', (56, 105)),
+    ('椋', (56, 104)),
+    ('椌', (56, 72)),
+    ('植', (19, 113)),
+    ('椎', (19, 115)),
+    ('椏', (56, 80)),
+    ('椐', (56, 87)),
+    ('椑', (56, 95)),
+    ('椒', (19, 114)),
+    ('椓', (56, 86)),
+    ('椔', (56, 98)),
+    ('椕', (56, 117)),
+    ('椗', (56, 106)),
+    ('椥', (56, 118)),
+    ('椪', (56, 76)),
+    ('椯', (60, 144)),
+    ('椰', (22, 68)),
+    ('椲', (60, 142)),
+    ('椳', (60, 121)),
+    ('椴', (60, 126)),
+    ('椵', (60, 119)),
+    ('椷', (60, 138)),
+    ('椸', (60, 105)),
+    ('椹', (60, 112)),
+    ('椻', (60, 136)),
+    ('椼', (60, 146)),
+    ('椽', (60, 122)),
+    ('椿', (60, 109)),
+    ('楀', (60, 128)),
+    ('楁', (60, 133)),
+    ('楂', (60, 113)),
+    ('楄', (60, 130)),
+    ('楅', (60, 110)),
+    ('楈', (60, 117)),
+    ('楉', (60, 118)),
+    ('楊', (22, 70)),
+    ('楋', (60, 137)),
+    ('楌', (60, 135)),
+    ('楎', (60, 106)),
+    ('楏', (60, 140)),
+    ('楑', (60, 141)),
+    ('楒', (60, 143)),
+    ('楓', (22, 74)),
+    ('楔', (22, 66)),
+    ('楖', (52, 86)),
+    ('楗', (60, 114)),
+    ('楘', (60, 132)),
+    ('楙', (60, 115)),
+    ('楚', (22, 63)),
+    ('楛', (22, 79)),
+    ('楜', (60, 139)),
+    ('楝', (22, 77)),
+    ('楞', (22, 73)),
+    ('楟', (60, 104)),
+    ('楠', (22, 65)),
+    ('楢', (60, 107)),
+    ('楣', (22, 78)),
+    ('楥', (60, 123)),
+    ('楦', (60, 103)),
+    ('楨', (22, 71)),
+    ('楩', (60, 127)),
+    ('楪', (60, 111)),
+    ('楫', (22, 72)),
+    ('楬', (60, 120)),
+    ('業', (22, 62)),
+    ('楮', (19, 118)),
+    ('楯', (60, 129)),
+    ('楰', (56, 93)),
+    ('楱', (60, 108)),
+    ('楴', (60, 134)),
+    ('極', (22, 67)),
+    ('楶', (60, 131)),
+    ('楷', (22, 64)),
+    ('楸', (60, 125)),
+    ('楹', (22, 75)),
+    ('楺', (60, 116)),
+    ('楻', (60, 145)),
+    ('概', (22, 69)),
+    ('榃', (61, 100)),
+    ('榆', (22, 76)),
+    ('榍', (65, 10)),
+    ('榎', (65, 8)),
+    ('榐', (65, 30)),
+    ('榑', (65, 6)),
+    ('榓', (65, 24)),
+    ('榔', (22, 61)),
+    ('榕', (25, 31)),
+    ('榖', (65, 2)),
+    ('榗', (65, 29)),
+    ('榙', (65, 7)),
+    ('榚', (65, 21)),
+    ('榛', (25, 36)),
+    ('榜', (25, 29)),
+    ('榞', (65, 27)),
+    ('榠', (65, 0)),
+    ('榡', (65, 26)),
+    ('榣', (25, 47)),
+    ('榤', (65, 17)),
+    ('榥', (65, 33)),
+    ('榦', (25, 45)),
+    ('榧', (65, 9)),
+    ('榨', (25, 30)),
+    ('榩', (65, 11)),
+    ('榪', (65, 25)),
+    ('榫', (25, 39)),
+    ('榬', (65, 4)),
+    ('榭', (25, 43)),
+    ('榮', (25, 33)),
+    ('榯', (65, 13)),
+    ('榰', (65, 3)),
+    ('榱', (64, 154)),
+    ('榳', (65, 23)),
+    ('榴', (25, 40)),
+    ('榵', (65, 32)),
+    ('榶', (64, 155)),
+    ('榷', (25, 37)),
+    ('榹', (65, 19)),
+    ('榻', (25, 38)),
+    ('榼', (65, 5)),
+    ('榽', (65, 16)),
+    ('榾', (65, 12)),
+    ('榿', (65, 14)),
+    ('槁', (25, 32)),
+    ('槂', (65, 31)),
+    ('槃', (25, 46)),
+    ('槄', (65, 15)),
+    ('槆', (65, 34)),
+    ('槉', (64, 156)),
+    ('槊', (65, 20)),
+    ('構', (25, 35)),
+    ('槌', (25, 44)),
+    ('槍', (25, 42)),
+    ('槎', (65, 1)),
+    ('槏', (65, 22)),
+    ('槐', (25, 41)),
+    ('槓', (25, 34)),
+    ('槔', (65, 18)),
+    ('槙', (65, 28)),
+    ('槢', (68, 115)),
+    ('槤', (68, 111)),
+    ('槥', (68, 107)),
+    ('槦', (68, 127)),
+    ('槧', (68, 124)),
+    ('槨', (27, 107)),
+    ('槫', (68, 131)),
+    ('槬', (68, 114)),
+    ('槭', (27, 118)),
+    ('槮', (68, 121)),
+    ('槱', (68, 110)),
+    ('槲', (68, 120)),
+    ('槳', (27, 115)),
+    ('槴', (68, 140)),
+    ('槶', (68, 137)),
+    ('槷', (68, 123)),
+    ('槸', (68, 108)),
+    ('槻', (68, 128)),
+    ('槼', (68, 130)),
+    ('槽', (27, 111)),
+    ('槾', (68, 118)),
+    ('槿', (68, 113)),
+    ('樀', (68, 104)),
+    ('樁', (27, 108)),
+    ('樂', (27, 116)),
+    ('樄', (68, 133)),
+    ('樅', (27, 117)),
+    ('樆', (68, 105)),
+    ('樇', (68, 139)),
+    ('樈', (68, 126)),
+    ('樉', (68, 132)),
+    ('樊', (27, 114)),
+    ('樍', (68, 129)),
+    ('樏', (68, 136)),
+    ('樑', (27, 119)),
+    ('樓', (27, 113)),
+    ('樔', (68, 122)),
+    ('樕', (68, 109)),
+    ('樖', (68, 141)),
+    ('樗', (68, 106)),
+    ('樘', (68, 134)),
+    ('標', (27, 110)),
+    ('樛', (68, 116)),
+    ('樝', (68, 117)),
+    ('樞', (27, 109)),
+    ('樟', (27, 106)),
+    ('樠', (68, 112)),
+    ('模', (27, 112)),
+    ('樣', (27, 105)),
+    ('樥', (68, 135)),
+    ('樦', (68, 138)),
+    ('樧', (68, 119)),
+    ('樨', (72, 104)),
+    ('樲', (72, 96)),
+    ('樴', (72, 92)),
+    ('樵', (29, 148)),
+    ('樸', (29, 137)),
+    ('樹', (29, 142)),
+    ('樺', (29, 138)),
+    ('樻', (72, 106)),
+    ('樼', (72, 117)),
+    ('樽', (29, 136)),
+    ('樾', (72, 98)),
+    ('樿', (72, 107)),
+    ('橀', (68, 125)),
+    ('橁', (72, 108)),
+    ('橄', (29, 143)),
+    ('橆', (72, 123)),
+    ('橇', (29, 147)),
+    ('橈', (29, 150)),
+    ('橉', (72, 94)),
+    ('橋', (29, 146)),
+    ('橍', (72, 121)),
+    ('橎', (72, 122)),
+    ('橏', (72, 112)),
+    ('橐', (72, 111)),
+    ('橑', (72, 103)),
+    ('橔', (72, 113)),
+    ('橕', (72, 120)),
+    ('橖', (72, 119)),
+    ('橘', (29, 141)),
+    ('橙', (29, 139)),
+    ('橚', (72, 105)),
+    ('橛', (72, 102)),
+    ('橝', (72, 99)),
+    ('橞', (72, 118)),
+    ('機', (29, 149)),
+    ('橠', (72, 116)),
+    ('橡', (29, 145)),
+    ('橢', (29, 144)),
+    ('橤', (72, 110)),
+    ('橦', (72, 93)),
+    ('橧', (72, 95)),
+    ('橨', (72, 97)),
+    ('橩', (72, 115)),
+    ('橪', (72, 109)),
+    ('橫', (29, 140)),
+    ('橭', (72, 100)),
+    ('橯', (72, 114)),
+    ('橶', (72, 101)),
+    ('橾', (31, 109)),
+    ('橿', (76, 5)),
+    ('檀', (31, 102)),
+    ('檁', (75, 147)),
+    ('檃', (76, 1)),
+    ('檄', (31, 104)),
+    ('檅', (76, 8)),
+    ('檇', (75, 154)),
+    ('檉', (75, 149)),
+    ('檌', (76, 9)),
+    ('檍', (75, 145)),
+    ('檎', (75, 156)),
+    ('檐', (31, 111)),
+    ('檑', (76, 4)),
+    ('檒', (76, 10)),
+    ('檓', (75, 155)),
+    ('檔', (31, 103)),
+    ('檕', (76, 0)),
+    ('檖', (75, 146)),
+    ('檗', (31, 110)),
+    ('檚', (76, 7)),
+    ('檛', (75, 151)),
+    ('檜', (31, 106)),
+    ('檞', (75, 153)),
+    ('檟', (75, 150)),
+    ('檠', (31, 112)),
+    ('檡', (75, 152)),
+    ('檢', (31, 105)),
+    ('檣', (31, 108)),
+    ('檤', (76, 3)),
+    ('檥', (75, 148)),
+    ('檦', (76, 6)),
+    ('檨', (76, 2)),
+    ('檬', (33, 35)),
+    ('檭', (78, 133)),
+    ('檮', (33, 40)),
+    ('檯', (33, 41)),
+    ('檳', (33, 34)),
+    ('檴', (78, 132)),
+    ('檶', (78, 129)),
+    ('檷', (78, 130)),
+    ('檸', (33, 38)),
+    ('檹', (78, 124)),
+    ('檺', (78, 128)),
+    ('檻', (33, 37)),
+    ('檽', (78, 125)),
+    ('櫂', (33, 39)),
+    ('櫃', (33, 36)),
+    ('櫅', (78, 123)),
+    ('櫆', (78, 127)),
+    ('櫇', (78, 131)),
+    ('櫋', (81, 17)),
+    ('櫌', (81, 14)),
+    ('櫍', (81, 23)),
+    ('櫏', (81, 22)),
+    ('櫐', (81, 20)),
+    ('櫑', (81, 15)),
+    ('櫓', (34, 50)),
+    ('櫙', (81, 16)),
+    ('櫚', (34, 49)),
+    ('櫛', (31, 107)),
+    ('櫜', (81, 19)),
+    ('櫝', (34, 48)),
+    ('櫞', (81, 24)),
+    ('櫟', (81, 18)),
+    ('櫠', (81, 13)),
+    ('櫡', (78, 126)),
+    ('櫥', (34, 47)),
+    ('櫧', (81, 12)),
+    ('櫨', (83, 22)),
+    ('櫪', (83, 21)),
+    ('櫫', (81, 21)),
+    ('櫬', (35, 40)),
+    ('櫮', (83, 25)),
+    ('櫯', (83, 26)),
+    ('櫰', (83, 20)),
+    ('櫱', (83, 24)),
+    ('櫳', (83, 19)),
+    ('櫸', (84, 111)),
+    ('櫹', (83, 23)),
+    ('櫺', (35, 133)),
+    ('櫻', (35, 131)),
+    ('櫼', (84, 109)),
+    ('欀', (84, 112)),
+    ('欂', (84, 108)),
+    ('欃', (84, 110)),
+    ('欄', (35, 132)),
+    ('欈', (85, 131)),
+    ('欉', (85, 132)),
+    ('權', (36, 54)),
+    ('欋', (85, 130)),
+    ('欏', (86, 128)),
+    ('欐', (36, 109)),
+    ('欑', (86, 126)),
+    ('欒', (86, 127)),
+    ('欓', (87, 74)),
+    ('欖', (37, 22)),
+    ('欗', (87, 145)),
+    ('欘', (87, 143)),
+    ('欙', (87, 144)),
+    ('欚', (87, 146)),
+    ('欞', (88, 89)),
+    ('欠', (3, 139)),
+    ('次', (5, 86)),
+    ('欣', (9, 25)),
+    ('欥', (43, 135)),
+    ('欨', (46, 36)),
+    ('欬', (48, 147)),
+    ('欭', (48, 149)),
+    ('欯', (48, 148)),
+    ('欱', (48, 150)),
+    ('欲', (16, 155)),
+    ('欳', (52, 117)),
+    ('欴', (48, 151)),
+    ('欶', (52, 116)),
+    ('欷', (52, 118)),
+    ('欸', (52, 119)),
+    ('欹', (56, 120)),
+    ('欺', (19, 121)),
+    ('欻', (56, 121)),
+    ('欼', (56, 123)),
+    ('欽', (19, 122)),
+    ('款', (19, 120)),
+    ('欿', (56, 122)),
+    ('歁', (60, 152)),
+    ('歂', (60, 150)),
+    ('歃', (60, 149)),
+    ('歅', (60, 148)),
+    ('歆', (60, 147)),
+    ('歇', (22, 80)),
+    ('歈', (60, 151)),
+    ('歉', (25, 48)),
+    ('歊', (65, 35)),
+    ('歋', (65, 37)),
+    ('歌', (25, 49)),
+    ('歍', (65, 36)),
+    ('歎', (27, 121)),
+    ('歐', (27, 120)),
+    ('歑', (68, 142)),
+    ('歔', (72, 125)),
+    ('歕', (72, 124)),
+    ('歖', (72, 126)),
+    ('歙', (29, 151)),
+    ('歛', (76, 11)),
+    ('歜', (31, 113)),
+    ('歞', (78, 134)),
+    ('歟', (33, 42)),
+    ('歠', (81, 25)),
+    ('歡', (36, 55)),
+    ('止', (3, 140)),
+    ('正', (4, 93)),
+    ('此', (5, 87)),
+    ('步', (7, 2)),
+    ('武', (9, 26)),
+    ('歧', (9, 27)),
+    ('歪', (11, 46)),
+    ('歭', (48, 152)),
+    ('歲', (22, 81)),
+    ('歶', (69, 111)),
+    ('歷', (29, 152)),
+    ('歸', (33, 43)),
+    ('歹', (3, 141)),
+    ('死', (5, 88)),
+    ('歾', (43, 137)),
+    ('歿', (9, 28)),
+    ('殀', (43, 136)),
+    ('殂', (46, 37)),
+    ('殃', (11, 47)),
+    ('殄', (46, 38)),
+    ('殆', (11, 48)),
+    ('殈', (48, 154)),
+    ('殉', (13, 140)),
+    ('殊', (13, 139)),
+    ('殌', (52, 124)),
+    ('殍', (52, 122)),
+    ('殎', (52, 123)),
+    ('殏', (52, 121)),
+    ('殑', (52, 120)),
+    ('殔', (56, 124)),
+    ('殕', (56, 127)),
+    ('殖', (19, 124)),
+    ('殗', (56, 125)),
+    ('殘', (19, 123)),
+    ('殙', (56, 126)),
+    ('殛', (60, 153)),
+    ('殞', (65, 38)),
+    ('殟', (65, 39)),
+    ('殠', (65, 40)),
+    ('殢', (68, 145)),
+    ('殣', (68, 144)),
+    ('殤', (27, 122)),
+    ('殥', (68, 143)),
+    ('殦', (68, 146)),
+    ('殧', (72, 127)),
+    ('殪', (72, 128)),
+    ('殫', (72, 129)),
+    ('殭', (76, 12)),
+    ('殮', (31, 114)),
+    ('殯', (33, 44)),
+    ('殰', (81, 26)),
+    ('殲', (35, 134)),
+    ('殳', (40, 31)),
+    ('段', (11, 49)),
+    ('殶', (46, 39)),
+    ('殷', (13, 141)),
+    ('殺', (16, 156)),
+    ('殼', (19, 125)),
+    ('殽', (56, 128)),
+    ('殿', (22, 83)),
+    ('毀', (22, 82)),
+    ('毃', (65, 41)),
+    ('毄', (65, 42)),
+    ('毅', (27, 123)),
+    ('毆', (27, 124)),
+    ('毇', (72, 131)),
+    ('毈', (72, 130)),
+    ('毉', (78, 135)),
+    ('毊', (86, 129)),
+    ('毋', (3, 142)),
+    ('毌', (40, 32)),
+    ('母', (4, 94)),
+    ('每', (7, 3)),
+    ('毐', (42, 8)),
+    ('毒', (11, 50)),
+    ('毓', (22, 84)),
+    ('比', (3, 143)),
+    ('毖', (46, 40)),
+    ('毗', (11, 51)),
+    ('毘', (46, 41)),
+    ('毚', (31, 115)),
+    ('毛', (3, 144)),
+    ('毞', (43, 138)),
+    ('毠', (46, 42)),
+    ('毢', (49, 2)),
+    ('毣', (49, 1)),
+    ('毤', (48, 156)),
+    ('毦', (48, 155)),
+    ('毧', (49, 3)),
+    ('毨', (49, 0)),
+    ('毫', (17, 0)),
+    ('毬', (17, 1)),
+    ('毯', (19, 126)),
+    ('毰', (56, 129)),
+    ('毲', (56, 130)),
+    ('毳', (56, 131)),
+    ('毷', (61, 1)),
+    ('毸', (61, 2)),
+    ('毹', (61, 0)),
+    ('毻', (60, 155)),
+    ('毼', (60, 156)),
+    ('毽', (22, 85)),
+    ('毾', (65, 43)),
+    ('毿', (68, 149)),
+    ('氀', (68, 148)),
+    ('氁', (68, 147)),
+    ('氂', (68, 150)),
+    ('氃', (72, 133)),
+    ('氄', (72, 132)),
+    ('氅', (29, 153)),
+    ('氆', (72, 134)),
+    ('氈', (31, 116)),
+    ('氉', (76, 13)),
+    ('氋', (78, 136)),
+    ('氌', (81, 27)),
+    ('氍', (85, 133)),
+    ('氏', (3, 145)),
+    ('氐', (4, 96)),
+    ('民', (4, 95)),
+    ('氓', (9, 29)),
+    ('气', (40, 33)),
+    ('氕', (40, 62)),
+    ('氖', (5, 89)),
+    ('氘', (40, 149)),
+    ('氙', (42, 9)),
+    ('氚', (42, 10)),
+    ('氛', (9, 30)),
+    ('氝', (43, 139)),
+    ('氟', (11, 52)),
+    ('氠', (46, 43)),
+    ('氡', (46, 44)),
+    ('氣', (13, 142)),
+    ('氤', (13, 146)),
+    ('氥', (49, 4)),
+    ('氦', (13, 145)),
+    ('氧', (13, 143)),
+    ('氨', (13, 144)),
+    ('氪', (52, 125)),
+    ('氫', (17, 2)),
+    ('氬', (19, 129)),
+    ('氮', (19, 127)),
+    ('氯', (19, 128)),
+    ('氰', (56, 132)),
+    ('氳', (25, 50)),
+    ('水', (3, 146)),
+    ('氶', (40, 63)),
+    ('永', (4, 97)),
+    ('氻', (40, 66)),
+    ('氾', (4, 100)),
+    ('氿', (40, 65)),
+    ('汀', (4, 99)),
+    ('汁', (4, 98)),
+    ('求', (7, 4)),
+    ('汃', (40, 64)),
+    ('汆', (40, 150)),
+    ('汊', (40, 154)),
+    ('汋', (40, 156)),
+    ('汌', (41, 0)),
+    ('汍', (5, 99)),
+    ('汎', (5, 100)),
+    ('汏', (40, 153)),
+    ('汐', (5, 95)),
+    ('汒', (40, 151)),
+    ('汔', (40, 155)),
+    ('汕', (5, 96)),
+    ('汗', (5, 91)),
+    ('汙', (5, 92)),
+    ('汛', (5, 98)),
+    ('汜', (40, 152)),
+    ('汝', (5, 90)),
+    ('汞', (7, 5)),
+    ('江', (5, 93)),
+    ('池', (5, 94)),
+    ('污', (5, 97)),
+    ('汥', (42, 27)),
+    ('汦', (42, 25)),
+    ('汧', (42, 12)),
+    ('汨', (7, 17)),
+    ('汩', (42, 19)),
+    ('汪', (7, 12)),
+    ('汫', (42, 13)),
+    ('汭', (42, 21)),
+    ('汯', (42, 18)),
+    ('汰', (7, 15)),
+    ('汱', (42, 17)),
+    ('汲', (7, 22)),
+    ('汳', (42, 26)),
+    ('汴', (7, 24)),
+    ('汶', (7, 26)),
+    ('汸', (42, 11)),
+    ('決', (7, 13)),
+    ('汻', (42, 28)),
+    ('汽', (7, 20)),
+    ('汾', (7, 23)),
+    ('沀', (44, 5)),
+    ('沁', (7, 7)),
+    ('沂', (7, 30)),
+    ('沃', (7, 21)),
+    ('沄', (42, 14)),
+    ('沅', (7, 10)),
+    ('沆', (7, 25)),
+    ('沇', (42, 22)),
+    ('沈', (7, 8)),
+    ('沉', (7, 9)),
+    ('沊', (44, 3)),
+    ('沋', (42, 15)),
+    ('沌', (7, 16)),
+    ('沍', (7, 27)),
+    ('沎', (42, 29)),
+    ('沏', (42, 16)),
+    ('沐', (7, 14)),
+    ('沒', (7, 19)),
+    ('沓', (43, 140)),
+    ('沔', (7, 28)),
+    ('沕', (42, 23)),
+    ('沖', (7, 18)),
+    ('沘', (7, 29)),
+    ('沙', (7, 6)),
+    ('沚', (42, 20)),
+    ('沛', (7, 11)),
+    ('沜', (42, 24)),
+    ('沝', (44, 4)),
+    ('沫', (9, 42)),
+    ('沬', (9, 58)),
+    ('沭', (43, 147)),
+    ('沮', (9, 49)),
+    ('沰', (44, 11)),
+    ('沱', (9, 34)),
+    ('河', (9, 37)),
+    ('沴', (44, 2)),
+    ('沶', (43, 145)),
+    ('沷', (43, 149)),
+    ('沸', (9, 45)),
+    ('油', (9, 47)),
+    ('沺', (43, 152)),
+    ('治', (9, 54)),
+    ('沼', (9, 40)),
+    ('沽', (9, 38)),
+    ('沾', (9, 39)),
+    ('沿', (9, 53)),
+    ('泀', (44, 7)),
+    ('況', (9, 48)),
+    ('泂', (43, 151)),
+    ('泃', (43, 153)),
+    ('泄', (9, 46)),
+    ('泅', (9, 51)),
+    ('泆', (43, 154)),
+    ('泇', (44, 10)),
+    ('泉', (11, 53)),
+    ('泊', (9, 57)),
+    ('泌', (9, 35)),
+    ('泍', (44, 9)),
+    ('泏', (44, 13)),
+    ('泐', (43, 150)),
+    ('泑', (44, 15)),
+    ('泒', (44, 0)),
+    ('泓', (9, 44)),
+    ('泔', (43, 146)),
+    ('法', (9, 43)),
+    ('泖', (9, 61)),
+    ('泗', (9, 50)),
+    ('泙', (43, 144)),
+    ('泚', (46, 53)),
+    ('泛', (9, 56)),
+    ('泜', (9, 60)),
+    ('泝', (44, 1)),
+    ('泞', (44, 6)),
+    ('泠', (9, 62)),
+    ('泡', (9, 55)),
+    ('波', (9, 41)),
+    ('泣', (9, 31)),
+    ('泥', (9, 36)),
+    ('泧', (43, 148)),
+    ('注', (9, 32)),
+    ('泩', (44, 14)),
+    ('泫', (43, 142)),
+    ('泬', (43, 141)),
+    ('泭', (43, 155)),
+    ('泮', (43, 143)),
+    ('泯', (9, 59)),
+    ('泰', (13, 147)),
+    ('泱', (9, 52)),
+    ('泲', (43, 156)),
+    ('泳', (9, 33)),
+    ('泵', (11, 68)),
+    ('泹', (44, 12)),
+    ('洀', (46, 60)),
+    ('洁', (46, 63)),
+    ('洃', (46, 66)),
+    ('洄', (46, 55)),
+    ('洇', (46, 69)),
+    ('洈', (46, 72)),
+    ('洉', (46, 74)),
+    ('洊', (46, 52)),
+    ('洋', (11, 54)),
+    ('洌', (11, 59)),
+    ('洍', (49, 9)),
+    ('洎', (11, 75)),
+    ('洏', (46, 67)),
+    ('洐', (46, 75)),
+    ('洑', (46, 59)),
+    ('洒', (46, 51)),
+    ('洖', (49, 36)),
+    ('洗', (11, 62)),
+    ('洘', (46, 64)),
+    ('洙', (46, 56)),
+    ('洚', (46, 58)),
+    ('洛', (11, 67)),
+    ('洝', (46, 61)),
+    ('洞', (11, 61)),
+    ('洟', (46, 48)),
+    ('洠', (46, 70)),
+    ('洢', (46, 73)),
+    ('津', (11, 58)),
+    ('洧', (11, 70)),
+    ('洨', (46, 45)),
+    ('洩', (11, 72)),
+    ('洪', (11, 56)),
+    ('洫', (11, 76)),
+    ('洬', (46, 71)),
+    ('洭', (46, 47)),
+    ('洮', (11, 73)),
+    ('洯', (49, 30)),
+    ('洰', (44, 8)),
+    ('洱', (11, 60)),
+    ('洲', (11, 55)),
+    ('洳', (46, 54)),
+    ('洴', (46, 46)),
+    ('洵', (11, 74)),
+    ('洶', (11, 66)),
+    ('洷', (46, 65)),
+    ('洸', (11, 71)),
+    ('洹', (11, 69)),
+    ('洺', (46, 57)),
+    ('活', (11, 63)),
+    ('洼', (46, 49)),
+    ('洽', (11, 64)),
+    ('派', (11, 65)),
+    ('洿', (46, 50)),
+    ('浀', (46, 68)),
+    ('流', (11, 57)),
+    ('浂', (46, 62)),
+    ('浘', (49, 12)),
+    ('浙', (13, 155)),
+    ('浚', (14, 3)),
+    ('浞', (49, 21)),
+    ('浟', (49, 27)),
+    ('浠', (49, 23)),
+    ('浡', (49, 10)),
+    ('浢', (49, 13)),
+    ('浣', (49, 6)),
+    ('浤', (49, 7)),
+    ('浥', (14, 10)),
+    ('浦', (13, 152)),
+    ('浧', (49, 22)),
+    ('浨', (49, 31)),
+    ('浩', (14, 5)),
+    ('浪', (13, 148)),
+    ('浬', (14, 0)),
+    ('浭', (49, 14)),
+    ('浮', (14, 2)),
+    ('浯', (49, 15)),
+    ('浰', (49, 25)),
+    ('浴', (14, 4)),
+    ('浵', (49, 40)),
+    ('浶', (49, 8)),
+    ('海', (13, 154)),
+    ('浸', (13, 153)),
+    ('浹', (14, 8)),
+    ('浺', (49, 5)),
+    ('浻', (49, 38)),
+    ('浼', (49, 26)),
+    ('浽', (49, 39)),
+    ('浾', (49, 33)),
+    ('浿', (49, 19)),
+    ('涀', (49, 34)),
+    ('涂', (49, 28)),
+    ('涃', (49, 37)),
+    ('涄', (49, 35)),
+    ('涅', (14, 9)),
+    ('涆', (49, 20)),
+    ('涇', (13, 151)),
+    ('消', (13, 150)),
+    ('涉', (14, 1)),
+    ('涊', (14, 7)),
+    ('涋', (49, 32)),
+    ('涌', (14, 6)),
+    ('涍', (49, 17)),
+    ('涎', (17, 3)),
+    ('涐', (49, 41)),
+    ('涑', (49, 16)),
+    ('涒', (49, 11)),
+    ('涓', (13, 156)),
+    ('涔', (14, 11)),
+    ('涕', (13, 149)),
+    ('涗', (49, 24)),
+    ('涘', (49, 29)),
+    ('涪', (17, 37)),
+    ('涫', (52, 127)),
+    ('涬', (52, 131)),
+    ('涮', (17, 18)),
+    ('涯', (17, 16)),
+    ('液', (17, 7)),
+    ('涳', (52, 129)),
+    ('涴', (52, 128)),
+    ('涵', (17, 27)),
+    ('涷', (52, 134)),
+    ('涸', (17, 21)),
+    ('涺', (52, 152)),
+    ('涻', (53, 7)),
+    ('涼', (17, 4)),
+    ('涽', (52, 149)),
+    ('涾', (52, 142)),
+    ('涿', (17, 39)),
+    ('淀', (52, 126)),
+    ('淂', (52, 154)),
+    ('淄', (17, 36)),
+    ('淅', (17, 24)),
+    ('淆', (17, 35)),
+    ('淇', (17, 14)),
+    ('淈', (52, 138)),
+    ('淉', (52, 156)),
+    ('淊', (52, 148)),
+    ('淋', (17, 15)),
+    ('淌', (17, 9)),
+    ('淍', (53, 5)),
+    ('淏', (52, 155)),
+    ('淐', (53, 0)),
+    ('淑', (17, 17)),
+    ('淒', (17, 25)),
+    ('淓', (53, 2)),
+    ('淔', (52, 136)),
+    ('淕', (52, 153)),
+    ('淖', (52, 141)),
+    ('淗', (53, 4)),
+    ('淘', (17, 30)),
+    ('淙', (17, 6)),
+    ('淚', (17, 28)),
+    ('淛', (52, 146)),
+    ('淜', (52, 144)),
+    ('淝', (52, 145)),
+    ('淞', (17, 19)),
+    ('淟', (52, 140)),
+    ('淠', (52, 139)),
+    ('淡', (17, 8)),
+    ('淢', (52, 133)),
+    ('淣', (53, 6)),
+    ('淤', (17, 10)),
+    ('淥', (52, 143)),
+    ('淦', (17, 40)),
+    ('淨', (17, 34)),
+    ('淩', (52, 132)),
+    ('淪', (17, 31)),
+    ('淫', (17, 29)),
+    ('淬', (17, 38)),
+    ('淭', (52, 150)),
+    ('淮', (17, 33)),
+    ('淯', (49, 18)),
+    ('淰', (52, 151)),
+    ('深', (17, 32)),
+    ('淲', (53, 1)),
+    ('淳', (17, 5)),
+    ('淴', (52, 147)),
+    ('淵', (17, 23)),
+    ('淶', (52, 135)),
+    ('混', (17, 22)),
+    ('淹', (17, 20)),
+    ('淺', (17, 12)),
+    ('添', (17, 11)),
+    ('淼', (56, 133)),
+    ('淽', (53, 3)),
+    ('渀', (52, 137)),
+    ('渃', (56, 154)),
+    ('清', (17, 13)),
+    ('渙', (20, 1)),
+    ('渚', (17, 26)),
+    ('減', (19, 140)),
+    ('渜', (56, 148)),
+    ('渝', (19, 154)),
+    ('渟', (56, 136)),
+    ('渠', (19, 137)),
+    ('渡', (19, 133)),
+    ('渢', (57, 9)),
+    ('渣', (19, 139)),
+    ('渤', (19, 143)),
+    ('渥', (19, 138)),
+    ('渦', (19, 147)),
+    ('渧', (57, 13)),
+    ('渨', (57, 4)),
+    ('渫', (56, 143)),
+    ('測', (19, 152)),
+    ('渭', (19, 146)),
+    ('渮', (56, 155)),
+    ('港', (19, 130)),
+    ('渰', (57, 10)),
+    ('渱', (57, 3)),
+    ('渲', (19, 134)),
+    ('渳', (56, 149)),
+    ('渴', (19, 149)),
+    ('渵', (57, 21)),
+    ('渶', (57, 22)),
+    ('游', (19, 131)),
+    ('渹', (57, 8)),
+    ('渺', (19, 151)),
+    ('渻', (56, 153)),
+    ('渼', (56, 139)),
+    ('渽', (56, 140)),
+    ('渾', (19, 155)),
+    ('渿', (56, 144)),
+    ('湀', (56, 151)),
+    ('湁', (56, 145)),
+    ('湃', (19, 153)),
+    ('湄', (20, 4)),
+    ('湅', (56, 141)),
+    ('湆', (56, 134)),
+    ('湇', (56, 135)),
+    ('湉', (56, 137)),
+    ('湊', (19, 136)),
+    ('湋', (56, 150)),
+    ('湍', (19, 150)),
+    ('湎', (20, 2)),
+    ('湑', (56, 152)),
+    ('湒', (57, 19)),
+    ('湓', (57, 11)),
+    ('湔', (19, 132)),
+    ('湕', (57, 17)),
+    ('湖', (19, 144)),
+    ('湘', (19, 142)),
+    ('湚', (57, 23)),
+    ('湛', (19, 141)),
+    ('湜', (57, 1)),
+    ('湝', (56, 146)),
+    ('湞', (56, 156)),
+    ('湟', (20, 7)),
+    ('湠', (57, 5)),
+    ('湡', (57, 2)),
+    ('湢', (56, 142)),
+    ('湣', (20, 3)),
+    ('湤', (57, 15)),
+    ('湥', (57, 12)),
+    ('湦', (57, 20)),
+    ('湧', (19, 135)),
+    ('湨', (57, 0)),
+    ('湩', (20, 6)),
+    ('湫', (57, 7)),
+    ('湮', (19, 145)),
+    ('湯', (19, 148)),
+    ('湱', (57, 6)),
+    ('湲', (20, 5)),
+    ('湳', (56, 147)),
+    ('湴', (52, 130)),
+    ('湷', (57, 16)),
+    ('湸', (57, 14)),
+    ('湹', (57, 18)),
+    ('溈', (56, 138)),
+    ('溉', (20, 0)),
+    ('溍', (61, 33)),
+    ('溎', (61, 32)),
+    ('溏', (61, 6)),
+    ('源', (22, 91)),
+    ('溒', (61, 31)),
+    ('溓', (61, 9)),
+    ('溔', (61, 10)),
+    ('準', (22, 101)),
+    ('溗', (61, 40)),
+    ('溘', (22, 96)),
+    ('溙', (61, 30)),
+    ('溛', (61, 3)),
+    ('溜', (22, 102)),
+    ('溝', (22, 92)),
+    ('溞', (61, 18)),
+    ('溟', (61, 8)),
+    ('溠', (61, 11)),
+    ('溡', (61, 35)),
+    ('溢', (22, 86)),
+    ('溣', (61, 42)),
+    ('溤', (61, 34)),
+    ('溥', (22, 95)),
+    ('溦', (61, 23)),
+    ('溧', (22, 106)),
+    ('溪', (22, 105)),
+    ('溫', (22, 99)),
+    ('溮', (61, 41)),
+    ('溯', (22, 87)),
+    ('溰', (61, 21)),
+    ('溱', (61, 12)),
+    ('溲', (61, 25)),
+    ('溳', (61, 37)),
+    ('溴', (22, 107)),
+    ('溶', (22, 89)),
+    ('溷', (61, 20)),
+    ('溹', (61, 13)),
+    ('溺', (22, 98)),
+    ('溼', (22, 97)),
+    ('溽', (61, 16)),
+    ('溾', (61, 26)),
+    ('溿', (61, 36)),
+    ('滀', (61, 7)),
+    ('滁', (61, 17)),
+    ('滂', (22, 90)),
+    ('滃', (61, 27)),
+    ('滄', (22, 103)),
+    ('滅', (22, 94)),
+    ('滆', (61, 14)),
+    ('滇', (22, 93)),
+    ('滈', (61, 5)),
+    ('滉', (61, 19)),
+    ('滊', (61, 39)),
+    ('滋', (19, 156)),
+    ('滌', (25, 78)),
+    ('滍', (61, 22)),
+    ('滎', (65, 44)),
+    ('滏', (61, 24)),
+    ('滐', (61, 38)),
+    ('滑', (22, 100)),
+    ('滒', (61, 15)),
+    ('滓', (22, 88)),
+    ('滔', (22, 104)),
+    ('滕', (27, 142)),
+    ('滖', (61, 4)),
+    ('滘', (61, 29)),
+    ('滜', (61, 28)),
+    ('滫', (65, 72)),
+    ('滬', (25, 75)),
+    ('滭', (65, 61)),
+    ('滮', (65, 66)),
+    ('滯', (25, 64)),
+    ('滱', (65, 46)),
+    ('滲', (25, 77)),
+    ('滴', (25, 55)),
+    ('滵', (65, 45)),
+    ('滶', (65, 78)),
+    ('滷', (25, 79)),
+    ('滸', (65, 49)),
+    ('滹', (65, 65)),
+    ('滻', (65, 51)),
+    ('滼', (65, 81)),
+    ('滽', (65, 77)),
+    ('滾', (25, 53)),
+    ('滿', (25, 63)),
+    ('漀', (69, 19)),
+    ('漁', (25, 76)),
+    ('漂', (25, 61)),
+    ('漃', (65, 47)),
+    ('漅', (65, 76)),
+    ('漆', (25, 65)),
+    ('漇', (65, 73)),
+    ('漈', (65, 86)),
+    ('漉', (65, 53)),
+    ('漊', (65, 62)),
+    ('漍', (65, 84)),
+    ('漎', (65, 74)),
+    ('漏', (25, 60)),
+    ('漒', (65, 60)),
+    ('漓', (25, 54)),
+    ('演', (25, 52)),
+    ('漕', (25, 70)),
+    ('漘', (65, 58)),
+    ('漙', (65, 55)),
+    ('漚', (65, 56)),
+    ('漜', (65, 80)),
+    ('漞', (65, 85)),
+    ('漟', (65, 83)),
+    ('漠', (25, 58)),
+    ('漡', (65, 87)),
+    ('漢', (25, 62)),
+    ('漣', (25, 69)),
+    ('漥', (65, 48)),
+    ('漦', (68, 152)),
+    ('漧', (65, 57)),
+    ('漩', (25, 56)),
+    ('漪', (25, 74)),
+    ('漫', (25, 71)),
+    ('漬', (25, 59)),
+    ('漭', (65, 67)),
+    ('漮', (65, 52)),
+    ('漯', (25, 72)),
+    ('漰', (65, 69)),
+    ('漱', (25, 66)),
+    ('漲', (25, 68)),
+    ('漳', (25, 51)),
+    ('漵', (65, 71)),
+    ('漶', (65, 63)),
+    ('漷', (65, 50)),
+    ('漸', (25, 67)),
+    ('漹', (65, 79)),
+    ('漺', (65, 82)),
+    ('漻', (65, 59)),
+    ('漼', (65, 70)),
+    ('漾', (25, 57)),
+    ('漿', (27, 125)),
+    ('潀', (65, 68)),
+    ('潁', (68, 151)),
+    ('潃', (65, 75)),
+    ('潎', (65, 54)),
+    ('潏', (69, 4)),
+    ('潐', (69, 14)),
+    ('潑', (27, 128)),
+    ('潒', (69, 13)),
+    ('潓', (69, 25)),
+    ('潔', (27, 130)),
+    ('潕', (69, 11)),
+    ('潗', (69, 15)),
+    ('潘', (27, 141)),
+    ('潚', (69, 6)),
+    ('潛', (27, 133)),
+    ('潝', (69, 18)),
+    ('潞', (72, 142)),
+    ('潟', (27, 145)),
+    ('潠', (27, 144)),
+    ('潡', (69, 20)),
+    ('潢', (69, 3)),
+    ('潣', (69, 30)),
+    ('潤', (27, 139)),
+    ('潦', (27, 129)),
+    ('潧', (69, 23)),
+    ('潩', (69, 27)),
+    ('潪', (69, 32)),
+    ('潫', (69, 21)),
+    ('潬', (69, 9)),
+    ('潭', (27, 132)),
+    ('潮', (27, 135)),
+    ('潯', (27, 143)),
+    ('潰', (27, 138)),
+    ('潲', (69, 12)),
+    ('潳', (65, 64)),
+    ('潶', (69, 8)),
+    ('潷', (69, 31)),
+    ('潸', (27, 134)),
+    ('潺', (27, 137)),
+    ('潻', (69, 33)),
+    ('潼', (27, 126)),
+    ('潽', (69, 22)),
+    ('潾', (68, 153)),
+    ('潿', (69, 28)),
+    ('澀', (31, 125)),
+    ('澂', (69, 10)),
+    ('澄', (27, 127)),
+    ('澅', (69, 5)),
+    ('澆', (27, 131)),
+    ('澇', (68, 154)),
+    ('澈', (25, 73)),
+    ('澉', (69, 1)),
+    ('澋', (69, 26)),
+    ('澌', (69, 2)),
+    ('澍', (69, 0)),
+    ('澎', (27, 136)),
+    ('澐', (69, 24)),
+    ('澒', (68, 156)),
+    ('澓', (69, 17)),
+    ('澔', (69, 16)),
+    ('澕', (69, 29)),
+    ('澖', (69, 7)),
+    ('澗', (27, 140)),
+    ('澞', (72, 145)),
+    ('澠', (30, 9)),
+    ('澡', (29, 156)),
+    ('澢', (73, 0)),
+    ('澣', (72, 137)),
+    ('澤', (30, 1)),
+    ('澥', (72, 149)),
+    ('澦', (30, 8)),
+    ('澧', (30, 3)),
+    ('澨', (72, 147)),
+    ('澩', (76, 15)),
+    ('澪', (72, 153)),
+    ('澫', (73, 2)),
+    ('澬', (72, 152)),
+    ('澭', (72, 135)),
+    ('澮', (72, 150)),
+    ('澯', (73, 4)),
+    ('澰', (73, 6)),
+    ('澱', (29, 155)),
+    ('澲', (73, 5)),
+    ('澳', (30, 4)),
+    ('澴', (30, 10)),
+    ('澶', (30, 7)),
+    ('澸', (72, 156)),
+    ('澹', (30, 6)),
+    ('澺', (72, 151)),
+    ('澼', (72, 139)),
+    ('澽', (72, 144)),
+    ('澿', (72, 155)),
+    ('激', (30, 5)),
+    ('濁', (30, 2)),
+    ('濂', (29, 154)),
+    ('濃', (30, 0)),
+    ('濄', (72, 143)),
+    ('濆', (68, 155)),
+    ('濇', (72, 138)),
+    ('濈', (72, 141)),
+    ('濉', (73, 1)),
+    ('濊', (72, 146)),
+    ('濋', (72, 136)),
+    ('濌', (76, 14)),
+    ('濍', (73, 3)),
+    ('濎', (72, 140)),
+    ('濏', (72, 154)),
+    ('濔', (76, 17)),
+    ('濕', (31, 129)),
+    ('濘', (31, 117)),
+    ('濛', (31, 121)),
+    ('濜', (76, 19)),
+    ('濝', (76, 25)),
+    ('濞', (76, 23)),
+    ('濟', (31, 119)),
+    ('濠', (31, 120)),
+    ('濡', (31, 127)),
+    ('濢', (76, 26)),
+    ('濣', (76, 18)),
+    ('濤', (31, 122)),
+    ('濦', (76, 22)),
+    ('濧', (76, 21)),
+    ('濨', (76, 27)),
+    ('濩', (31, 128)),
+    ('濫', (31, 123)),
+    ('濬', (31, 126)),
+    ('濭', (76, 20)),
+    ('濮', (31, 130)),
+    ('濯', (31, 124)),
+    ('濰', (31, 131)),
+    ('濱', (31, 118)),
+    ('濲', (76, 24)),
+    ('濴', (76, 16)),
+    ('濷', (78, 149)),
+    ('濺', (33, 49)),
+    ('濻', (78, 146)),
+    ('濼', (78, 148)),
+    ('濾', (33, 47)),
+    ('濿', (78, 144)),
+    ('瀀', (78, 145)),
+    ('瀁', (78, 140)),
+    ('瀄', (72, 148)),
+    ('瀅', (78, 141)),
+    ('瀆', (33, 48)),
+    ('瀇', (78, 137)),
+    ('瀉', (33, 45)),
+    ('瀊', (78, 150)),
+    ('瀋', (33, 46)),
+    ('瀌', (78, 138)),
+    ('瀍', (78, 139)),
+    ('瀎', (78, 143)),
+    ('瀏', (33, 51)),
+    ('瀑', (33, 50)),
+    ('瀔', (78, 142)),
+    ('瀕', (34, 56)),
+    ('瀖', (81, 31)),
+    ('瀗', (81, 37)),
+    ('瀘', (34, 57)),
+    ('瀙', (81, 28)),
+    ('瀚', (34, 54)),
+    ('瀛', (34, 51)),
+    ('瀜', (81, 39)),
+    ('瀝', (34, 55)),
+    ('瀟', (34, 52)),
+    ('瀠', (81, 30)),
+    ('瀡', (81, 33)),
+    ('瀢', (81, 34)),
+    ('瀣', (81, 35)),
+    ('瀤', (81, 38)),
+    ('瀦', (78, 147)),
+    ('瀧', (81, 29)),
+    ('瀨', (34, 53)),
+    ('瀩', (81, 36)),
+    ('瀪', (81, 40)),
+    ('瀫', (81, 32)),
+    ('瀯', (83, 29)),
+    ('瀰', (35, 42)),
+    ('瀱', (83, 32)),
+    ('瀲', (35, 43)),
+    ('瀳', (83, 40)),
+    ('瀴', (83, 31)),
+    ('瀵', (83, 28)),
+    ('瀷', (83, 30)),
+    ('瀸', (83, 34)),
+    ('瀹', (83, 37)),
+    ('瀺', (83, 36)),
+    ('瀻', (83, 39)),
+    ('瀼', (83, 27)),
+    ('瀾', (35, 41)),
+    ('瀿', (83, 35)),
+    ('灀', (83, 38)),
+    ('灁', (83, 41)),
+    ('灂', (83, 33)),
+    ('灃', (84, 113)),
+    ('灄', (84, 114)),
+    ('灅', (84, 118)),
+    ('灆', (84, 119)),
+    ('灈', (84, 116)),
+    ('灉', (84, 117)),
+    ('灊', (84, 115)),
+    ('灌', (35, 135)),
+    ('灑', (36, 56)),
+    ('灒', (85, 137)),
+    ('灕', (85, 134)),
+    ('灖', (85, 135)),
+    ('灗', (85, 136)),
+    ('灘', (36, 57)),
+    ('灚', (86, 131)),
+    ('灛', (86, 130)),
+    ('灝', (87, 77)),
+    ('灞', (36, 146)),
+    ('灟', (87, 75)),
+    ('灠', (87, 78)),
+    ('灡', (87, 76)),
+    ('灢', (87, 147)),
+    ('灣', (37, 23)),
+    ('灤', (37, 37)),
+    ('灥', (88, 65)),
+    ('灦', (88, 44)),
+    ('灨', (88, 64)),
+    ('灩', (88, 110)),
+    ('灪', (88, 111)),
+    ('火', (3, 147)),
+    ('灰', (5, 101)),
+    ('灱', (41, 1)),
+    ('灴', (42, 30)),
+    ('灶', (7, 31)),
+    ('灸', (7, 34)),
+    ('灺', (42, 31)),
+    ('灼', (7, 32)),
+    ('災', (7, 33)),
+    ('炂', (44, 24)),
+    ('炃', (44, 26)),
+    ('炄', (44, 21)),
+    ('炅', (44, 18)),
+    ('炆', (44, 20)),
+    ('炊', (9, 66)),
+    ('炎', (9, 64)),
+    ('炑', (44, 22)),
+    ('炒', (9, 65)),
+    ('炓', (44, 19)),
+    ('炔', (44, 16)),
+    ('炕', (9, 63)),
+    ('炖', (44, 23)),
+    ('炘', (44, 17)),
+    ('炙', (9, 67)),
+    ('炚', (44, 25)),
+    ('炟', (46, 77)),
+    ('炡', (46, 81)),
+    ('炤', (11, 85)),
+    ('炩', (46, 84)),
+    ('炫', (11, 77)),
+    ('炬', (11, 80)),
+    ('炭', (11, 82)),
+    ('炮', (11, 84)),
+    ('炯', (11, 81)),
+    ('炰', (46, 80)),
+    ('炱', (46, 79)),
+    ('炳', (11, 79)),
+    ('炴', (46, 82)),
+    ('炵', (46, 83)),
+    ('炷', (46, 76)),
+    ('炸', (11, 83)),
+    ('為', (11, 78)),
+    ('炾', (46, 78)),
+    ('烅', (49, 55)),
+    ('烆', (49, 56)),
+    ('烇', (49, 57)),
+    ('烈', (14, 16)),
+    ('烊', (14, 12)),
+    ('烋', (49, 46)),
+    ('烍', (49, 54)),
+    ('烎', (49, 59)),
+    ('烏', (14, 17)),
+    ('烑', (49, 44)),
+    ('烒', (49, 50)),
+    ('烓', (49, 43)),
+    ('烔', (49, 53)),
+    ('烗', (49, 49)),
+    ('烘', (14, 13)),
+    ('烙', (14, 15)),
+    ('烚', (49, 58)),
+    ('烜', (49, 42)),
+    ('烝', (49, 45)),
+    ('烞', (49, 51)),
+    ('烠', (49, 52)),
+    ('烡', (49, 60)),
+    ('烢', (49, 48)),
+    ('烤', (14, 14)),
+    ('烯', (17, 45)),
+    ('烰', (53, 14)),
+    ('烳', (53, 16)),
+    ('烴', (53, 12)),
+    ('烶', (53, 24)),
+    ('烷', (53, 10)),
+    ('烸', (53, 23)),
+    ('烹', (17, 41)),
+    ('烺', (53, 8)),
+    ('烻', (57, 27)),
+    ('烼', (53, 18)),
+    ('烽', (17, 44)),
+    ('烿', (53, 19)),
+    ('焀', (53, 22)),
+    ('焂', (53, 26)),
+    ('焄', (53, 15)),
+    ('焆', (53, 20)),
+    ('焉', (17, 42)),
+    ('焊', (17, 43)),
+    ('焋', (53, 25)),
+    ('焌', (53, 13)),
+    ('焍', (53, 9)),
+    ('焎', (53, 27)),
+    ('焐', (53, 17)),
+    ('焓', (53, 21)),
+    ('焗', (53, 11)),
+    ('焙', (20, 8)),
+    ('焚', (20, 9)),
+    ('焛', (57, 37)),
+    ('焜', (20, 15)),
+    ('焞', (57, 25)),
+    ('焟', (57, 34)),
+    ('焠', (57, 24)),
+    ('無', (20, 12)),
+    ('焢', (57, 32)),
+    ('焣', (57, 30)),
+    ('焥', (57, 31)),
+    ('焦', (20, 10)),
+    ('焨', (57, 35)),
+    ('焮', (57, 28)),
+    ('焯', (57, 26)),
+    ('焰', (20, 11)),
+    ('焱', (57, 29)),
+    ('焲', (57, 33)),
+    ('然', (20, 13)),
+    ('焺', (57, 36)),
+    ('煁', (61, 48)),
+    ('煂', (61, 55)),
+    ('煃', (61, 57)),
+    ('煄', (61, 63)),
+    ('煆', (22, 120)),
+    ('煇', (61, 43)),
+    ('煉', (22, 112)),
+    ('煋', (61, 58)),
+    ('煌', (22, 117)),
+    ('煍', (61, 64)),
+    ('煎', (22, 108)),
+    ('煐', (61, 61)),
+    ('煒', (61, 45)),
+    ('煓', (61, 62)),
+    ('煔', (61, 44)),
+    ('煖', (22, 122)),
+    ('煘', (61, 56)),
+    ('煙', (22, 109)),
+    ('煚', (61, 65)),
+    ('煜', (22, 114)),
+    ('煝', (61, 49)),
+    ('煞', (22, 119)),
+    ('煟', (61, 60)),
+    ('煠', (61, 47)),
+    ('煡', (61, 54)),
+    ('煢', (61, 50)),
+    ('煣', (61, 46)),
+    ('煤', (22, 111)),
+    ('煥', (22, 118)),
+    ('煦', (22, 116)),
+    ('照', (22, 113)),
+    ('煨', (22, 121)),
+    ('煩', (22, 110)),
+    ('煪', (61, 53)),
+    ('煬', (22, 115)),
+    ('煮', (20, 14)),
+    ('煰', (61, 59)),
+    ('煲', (61, 51)),
+    ('煸', (61, 52)),
+    ('煻', (65, 95)),
+    ('煽', (25, 82)),
+    ('熀', (65, 91)),
+    ('熁', (65, 97)),
+    ('熂', (65, 93)),
+    ('熄', (25, 84)),
+    ('熅', (65, 92)),
+    ('熆', (65, 96)),
+    ('熇', (65, 88)),
+    ('熉', (65, 90)),
+    ('熊', (25, 83)),
+    ('熏', (65, 94)),
+    ('熐', (65, 89)),
+    ('熒', (25, 85)),
+    ('熔', (25, 80)),
+    ('熗', (65, 98)),
+    ('熙', (25, 81)),
+    ('熚', (69, 39)),
+    ('熛', (69, 36)),
+    ('熜', (69, 48)),
+    ('熝', (69, 42)),
+    ('熞', (69, 44)),
+    ('熟', (27, 146)),
+    ('熠', (69, 38)),
+    ('熡', (69, 46)),
+    ('熤', (69, 45)),
+    ('熥', (69, 43)),
+    ('熧', (69, 49)),
+    ('熨', (27, 149)),
+    ('熩', (69, 40)),
+    ('熪', (69, 47)),
+    ('熬', (27, 147)),
+    ('熯', (69, 35)),
+    ('熰', (69, 37)),
+    ('熱', (27, 148)),
+    ('熲', (69, 34)),
+    ('熳', (69, 50)),
+    ('熵', (69, 41)),
+    ('熸', (73, 10)),
+    ('熹', (30, 17)),
+    ('熼', (73, 21)),
+    ('熽', (73, 19)),
+    ('熾', (30, 11)),
+    ('熿', (73, 9)),
+    ('燀', (73, 12)),
+    ('燁', (73, 13)),
+    ('燂', (73, 8)),
+    ('燃', (30, 21)),
+    ('燄', (30, 22)),
+    ('燅', (73, 7)),
+    ('燆', (73, 22)),
+    ('燇', (73, 17)),
+    ('燈', (30, 15)),
+    ('燉', (30, 12)),
+    ('燊', (73, 16)),
+    ('燋', (73, 14)),
+    ('燎', (30, 18)),
+    ('燏', (73, 18)),
+    ('燐', (30, 13)),
+    ('燒', (30, 14)),
+    ('燔', (73, 15)),
+    ('燕', (30, 16)),
+    ('燖', (73, 11)),
+    ('燘', (73, 20)),
+    ('燙', (30, 19)),
+    ('燚', (73, 23)),
+    ('燛', (73, 24)),
+    ('燜', (30, 20)),
+    ('營', (31, 133)),
+    ('燠', (31, 140)),
+    ('燡', (76, 28)),
+    ('燢', (76, 34)),
+    ('燤', (76, 32)),
+    ('燥', (31, 136)),
+    ('燦', (31, 135)),
+    ('燧', (31, 132)),
+    ('燨', (76, 30)),
+    ('燬', (31, 138)),
+    ('燭', (31, 137)),
+    ('燮', (31, 134)),
+    ('燰', (76, 33)),
+    ('燱', (76, 29)),
+    ('燲', (76, 31)),
+    ('燴', (31, 139)),
+    ('燸', (33, 55)),
+    ('燹', (78, 153)),
+    ('燻', (33, 52)),
+    ('燼', (33, 53)),
+    ('燽', (78, 155)),
+    ('燾', (33, 54)),
+    ('燿', (78, 152)),
+    ('爁', (78, 151)),
+    ('爂', (81, 44)),
+    ('爃', (78, 154)),
+    ('爅', (81, 45)),
+    ('爆', (34, 58)),
+    ('爇', (81, 43)),
+    ('爊', (81, 42)),
+    ('爌', (81, 41)),
+    ('爍', (34, 59)),
+    ('爐', (35, 44)),
+    ('爓', (83, 42)),
+    ('爔', (83, 43)),
+    ('爙', (84, 122)),
+    ('爚', (84, 121)),
+    ('爛', (35, 136)),
+    ('爝', (84, 120)),
+    ('爞', (85, 138)),
+    ('爟', (85, 139)),
+    ('爢', (86, 132)),
+    ('爣', (87, 79)),
+    ('爦', (87, 148)),
+    ('爧', (88, 90)),
+    ('爨', (37, 57)),
+    ('爩', (88, 100)),
+    ('爪', (3, 148)),
+    ('爬', (9, 68)),
+    ('爭', (9, 69)),
+    ('爰', (11, 86)),
+    ('爵', (31, 141)),
+    ('父', (3, 149)),
+    ('爸', (9, 70)),
+    ('爹', (14, 18)),
+    ('爺', (22, 123)),
+    ('爻', (3, 150)),
+    ('爽', (17, 46)),
+    ('爾', (25, 86)),
+    ('爿', (40, 34)),
+    ('牁', (46, 85)),
+    ('牂', (49, 61)),
+    ('牄', (65, 99)),
+    ('牆', (31, 142)),
+    ('片', (3, 151)),
+    ('版', (9, 71)),
+    ('牉', (46, 86)),
+    ('牊', (46, 87)),
+    ('牋', (57, 38)),
+    ('牌', (20, 16)),
+    ('牏', (61, 66)),
+    ('牒', (22, 124)),
+    ('牓', (65, 100)),
+    ('牖', (27, 150)),
+    ('牘', (34, 60)),
+    ('牙', (3, 152)),
+    ('牚', (57, 39)),
+    ('牛', (3, 153)),
+    ('牝', (5, 103)),
+    ('牞', (41, 2)),
+    ('牟', (5, 102)),
+    ('牠', (7, 37)),
+    ('牡', (7, 36)),
+    ('牢', (7, 35)),
+    ('牣', (42, 32)),
+    ('牧', (9, 72)),
+    ('物', (9, 73)),
+    ('牪', (44, 27)),
+    ('牬', (46, 88)),
+    ('牮', (46, 91)),
+    ('牯', (11, 88)),
+    ('牰', (46, 89)),
+    ('牲', (11, 87)),
+    ('牳', (46, 90)),
+    ('牴', (11, 89)),
+    ('牶', (49, 64)),
+    ('牷', (49, 63)),
+    ('牸', (49, 62)),
+    ('特', (14, 19)),
+    ('牻', (53, 29)),
+    ('牼', (53, 30)),
+    ('牽', (17, 47)),
+    ('牾', (53, 28)),
+    ('牿', (53, 31)),
+    ('犀', (20, 18)),
+    ('犁', (17, 48)),
+    ('犄', (20, 17)),
+    ('犅', (57, 43)),
+    ('犆', (57, 42)),
+    ('犈', (57, 40)),
+    ('犉', (57, 41)),
+    ('犋', (57, 44)),
+    ('犌', (61, 68)),
+    ('犍', (61, 67)),
+    ('犎', (61, 71)),
+    ('犐', (61, 70)),
+    ('犑', (61, 69)),
+    ('犒', (25, 87)),
+    ('犓', (65, 103)),
+    ('犕', (65, 102)),
+    ('犖', (25, 88)),
+    ('犗', (65, 101)),
+    ('犘', (69, 51)),
+    ('犚', (69, 52)),
+    ('犛', (27, 151)),
+    ('犝', (73, 25)),
+    ('犞', (73, 26)),
+    ('犡', (81, 50)),
+    ('犢', (34, 61)),
+    ('犣', (81, 49)),
+    ('犤', (81, 48)),
+    ('犥', (81, 46)),
+    ('犦', (81, 47)),
+    ('犧', (35, 137)),
+    ('犨', (83, 44)),
+    ('犩', (85, 140)),
+    ('犪', (87, 149)),
+    ('犬', (3, 154)),
+    ('犮', (40, 67)),
+    ('犯', (4, 101)),
+    ('犰', (40, 68)),
+    ('犴', (41, 3)),
+    ('犵', (41, 4)),
+    ('犺', (42, 38)),
+    ('犽', (42, 34)),
+    ('犿', (42, 33)),
+    ('狀', (9, 74)),
+    ('狁', (42, 37)),
+    ('狂', (7, 39)),
+    ('狃', (42, 35)),
+    ('狄', (7, 38)),
+    ('狅', (42, 39)),
+    ('狆', (42, 36)),
+    ('狉', (44, 31)),
+    ('狊', (46, 92)),
+    ('狋', (44, 29)),
+    ('狌', (44, 36)),
+    ('狎', (9, 75)),
+    ('狐', (9, 78)),
+    ('狑', (44, 37)),
+    ('狒', (44, 33)),
+    ('狔', (44, 34)),
+    ('狖', (44, 28)),
+    ('狗', (9, 77)),
+    ('狘', (44, 30)),
+    ('狙', (9, 76)),
+    ('狚', (44, 35)),
+    ('狜', (44, 32)),
+    ('狟', (46, 96)),
+    ('狠', (11, 91)),
+    ('狡', (11, 92)),
+    ('狣', (46, 99)),
+    ('狤', (46, 93)),
+    ('狦', (46, 98)),
+    ('狨', (46, 94)),
+    ('狩', (11, 90)),
+    ('狪', (46, 97)),
+    ('狫', (46, 95)),
+    ('狳', (49, 70)),
+    ('狴', (49, 67)),
+    ('狶', (49, 69)),
+    ('狷', (14, 24)),
+    ('狸', (14, 23)),
+    ('狹', (14, 21)),
+    ('狺', (49, 66)),
+    ('狻', (49, 71)),
+    ('狼', (14, 20)),
+    ('狽', (14, 22)),
+    ('狾', (49, 68)),
+    ('狿', (53, 39)),
+    ('猀', (49, 65)),
+    ('猁', (49, 72)),
+    ('猇', (53, 34)),
+    ('猈', (53, 38)),
+    ('猊', (53, 37)),
+    ('猋', (57, 46)),
+    ('猌', (57, 57)),
+    ('猏', (53, 40)),
+    ('猑', (53, 35)),
+    ('猒', (57, 45)),
+    ('猓', (17, 52)),
+    ('猖', (17, 51)),
+    ('猗', (53, 33)),
+    ('猘', (53, 36)),
+    ('猙', (17, 53)),
+    ('猛', (17, 50)),
+    ('猜', (17, 49)),
+    ('猝', (53, 32)),
+    ('猞', (53, 41)),
+    ('猢', (57, 48)),
+    ('猣', (57, 55)),
+    ('猥', (20, 20)),
+    ('猦', (57, 54)),
+    ('猧', (57, 51)),
+    ('猩', (20, 22)),
+    ('猭', (57, 53)),
+    ('猰', (57, 47)),
+    ('猱', (57, 49)),
+    ('猲', (57, 52)),
+    ('猳', (57, 50)),
+    ('猴', (20, 21)),
+    ('猵', (57, 56)),
+    ('猶', (20, 19)),
+    ('猷', (22, 125)),
+    ('猺', (61, 75)),
+    ('猻', (61, 74)),
+    ('猼', (61, 72)),
+    ('猾', (22, 128)),
+    ('猿', (22, 127)),
+    ('獀', (61, 76)),
+    ('獂', (61, 73)),
+    ('獃', (65, 104)),
+    ('獄', (25, 89)),
+    ('獅', (22, 126)),
+    ('獉', (61, 78)),
+    ('獊', (61, 77)),
+    ('獌', (65, 107)),
+    ('獍', (65, 105)),
+    ('獎', (27, 152)),
+    ('獐', (25, 90)),
+    ('獑', (65, 106)),
+    ('獒', (69, 54)),
+    ('獗', (27, 153)),
+    ('獘', (69, 53)),
+    ('獙', (69, 62)),
+    ('獚', (69, 61)),
+    ('獛', (69, 59)),
+    ('獝', (69, 58)),
+    ('獞', (69, 55)),
+    ('獟', (69, 56)),
+    ('獠', (69, 57)),
+    ('獡', (69, 60)),
+    ('獢', (69, 63)),
+    ('獥', (73, 31)),
+    ('獦', (73, 28)),
+    ('獧', (73, 29)),
+    ('獨', (30, 23)),
+    ('獩', (73, 27)),
+    ('獪', (73, 33)),
+    ('獫', (73, 32)),
+    ('獬', (73, 30)),
+    ('獮', (76, 36)),
+    ('獯', (76, 37)),
+    ('獰', (31, 143)),
+    ('獲', (31, 144)),
+    ('獳', (76, 35)),
+    ('獵', (33, 57)),
+    ('獶', (78, 156)),
+    ('獷', (33, 56)),
+    ('獸', (34, 62)),
+    ('獺', (34, 63)),
+    ('獻', (35, 45)),
+    ('獼', (83, 46)),
+    ('獽', (83, 45)),
+    ('獾', (84, 123)),
+    ('獿', (85, 141)),
+    ('玀', (36, 58)),
+    ('玁', (86, 134)),
+    ('玂', (86, 133)),
+    ('玃', (86, 135)),
+    ('玄', (4, 102)),
+    ('玅', (46, 100)),
+    ('玆', (14, 25)),
+    ('率', (17, 54)),
+    ('玈', (53, 42)),
+    ('玉', (4, 103)),
+    ('玊', (40, 69)),
+    ('王', (3, 155)),
+    ('玎', (41, 5)),
+    ('玒', (42, 44)),
+    ('玓', (42, 42)),
+    ('玔', (42, 43)),
+    ('玕', (42, 40)),
+    ('玖', (7, 40)),
+    ('玗', (42, 41)),
+    ('玝', (44, 45)),
+    ('玟', (9, 81)),
+    ('玠', (44, 43)),
+    ('玡', (44, 39)),
+    ('玢', (44, 42)),
+    ('玤', (44, 38)),
+    ('玥', (9, 83)),
+    ('玦', (44, 41)),
+    ('玨', (9, 80)),
+    ('玩', (9, 79)),
+    ('玫', (9, 82)),
+    ('玬', (44, 44)),
+    ('玭', (44, 40)),
+    ('玲', (11, 96)),
+    ('玳', (11, 99)),
+    ('玴', (46, 108)),
+    ('玵', (46, 107)),
+    ('玶', (46, 106)),
+    ('玷', (11, 93)),
+    ('玸', (46, 115)),
+    ('玹', (46, 105)),
+    ('玻', (11, 95)),
+    ('玼', (49, 77)),
+    ('玾', (46, 112)),
+    ('玿', (46, 110)),
+    ('珀', (11, 98)),
+    ('珂', (46, 102)),
+    ('珃', (46, 113)),
+    ('珅', (46, 104)),
+    ('珆', (46, 114)),
+    ('珇', (46, 111)),
+    ('珈', (46, 103)),
+    ('珊', (11, 94)),
+    ('珋', (46, 116)),
+    ('珌', (46, 101)),
+    ('珍', (11, 97)),
+    ('珒', (49, 82)),
+    ('珓', (49, 73)),
+    ('珔', (49, 84)),
+    ('珖', (49, 76)),
+    ('珗', (49, 87)),
+    ('珘', (49, 88)),
+    ('珙', (49, 74)),
+    ('珚', (49, 86)),
+    ('珛', (49, 83)),
+    ('珜', (49, 81)),
+    ('珝', (49, 85)),
+    ('珞', (14, 31)),
+    ('珠', (14, 29)),
+    ('珣', (49, 79)),
+    ('珥', (49, 75)),
+    ('珧', (49, 78)),
+    ('珨', (49, 89)),
+    ('珩', (49, 80)),
+    ('珪', (14, 30)),
+    ('珫', (46, 109)),
+    ('班', (14, 26)),
+    ('珮', (14, 28)),
+    ('珴', (53, 56)),
+    ('珵', (53, 45)),
+    ('珶', (53, 43)),
+    ('珸', (53, 44)),
+    ('珺', (53, 51)),
+    ('珼', (53, 52)),
+    ('珽', (53, 48)),
+    ('現', (17, 59)),
+    ('珿', (53, 53)),
+    ('琀', (53, 50)),
+    ('琁', (53, 47)),
+    ('球', (17, 57)),
+    ('琄', (53, 46)),
+    ('琅', (17, 55)),
+    ('理', (17, 58)),
+    ('琇', (53, 49)),
+    ('琈', (53, 57)),
+    ('琉', (14, 27)),
+    ('琊', (17, 56)),
+    ('琋', (53, 55)),
+    ('琌', (53, 54)),
+    ('琍', (17, 60)),
+    ('琖', (57, 62)),
+    ('琚', (57, 63)),
+    ('琛', (20, 32)),
+    ('琝', (57, 69)),
+    ('琠', (57, 71)),
+    ('琡', (57, 64)),
+    ('琢', (20, 26)),
+    ('琣', (57, 68)),
+    ('琤', (57, 67)),
+    ('琥', (20, 27)),
+    ('琦', (20, 33)),
+    ('琨', (20, 34)),
+    ('琩', (57, 70)),
+    ('琪', (20, 24)),
+    ('琫', (57, 61)),
+    ('琬', (57, 59)),
+    ('琭', (57, 65)),
+    ('琮', (57, 58)),
+    ('琯', (20, 31)),
+    ('琰', (57, 60)),
+    ('琱', (57, 66)),
+    ('琲', (57, 72)),
+    ('琳', (20, 25)),
+    ('琴', (20, 30)),
+    ('琵', (20, 28)),
+    ('琶', (20, 29)),
+    ('琺', (20, 23)),
+    ('琿', (22, 135)),
+    ('瑀', (61, 85)),
+    ('瑁', (22, 134)),
+    ('瑂', (61, 89)),
+    ('瑄', (61, 79)),
+    ('瑆', (61, 90)),
+    ('瑊', (61, 80)),
+    ('瑋', (61, 81)),
+    ('瑍', (61, 91)),
+    ('瑎', (61, 88)),
+    ('瑏', (61, 86)),
+    ('瑐', (61, 87)),
+    ('瑑', (61, 83)),
+    ('瑒', (61, 82)),
+    ('瑔', (61, 92)),
+    ('瑕', (22, 131)),
+    ('瑗', (61, 84)),
+    ('瑙', (22, 136)),
+    ('瑚', (22, 130)),
+    ('瑛', (22, 137)),
+    ('瑜', (22, 138)),
+    ('瑞', (22, 133)),
+    ('瑟', (22, 132)),
+    ('瑢', (65, 108)),
+    ('瑣', (25, 92)),
+    ('瑤', (25, 91)),
+    ('瑧', (65, 113)),
+    ('瑩', (27, 154)),
+    ('瑪', (25, 93)),
+    ('瑭', (25, 95)),
+    ('瑮', (65, 114)),
+    ('瑯', (22, 129)),
+    ('瑰', (25, 94)),
+    ('瑱', (65, 110)),
+    ('瑲', (65, 112)),
+    ('瑳', (65, 109)),
+    ('瑵', (65, 111)),
+    ('瑹', (69, 73)),
+    ('瑼', (69, 72)),
+    ('瑽', (69, 69)),
+    ('瑾', (28, 0)),
+    ('瑿', (73, 34)),
+    ('璀', (28, 1)),
+    ('璁', (69, 68)),
+    ('璃', (27, 156)),
+    ('璅', (69, 70)),
+    ('璆', (69, 67)),
+    ('璇', (69, 64)),
+    ('璈', (69, 71)),
+    ('璉', (69, 65)),
+    ('璊', (69, 66)),
+    ('璋', (27, 155)),
+    ('璐', (76, 41)),
+    ('璒', (73, 38)),
+    ('璔', (73, 37)),
+    ('璕', (73, 39)),
+    ('璗', (76, 38)),
+    ('璘', (30, 26)),
+    ('璚', (73, 35)),
+    ('璜', (30, 24)),
+    ('璞', (30, 28)),
+    ('璟', (30, 27)),
+    ('璠', (73, 36)),
+    ('璡', (73, 40)),
+    ('璣', (30, 25)),
+    ('璥', (76, 45)),
+    ('璦', (31, 147)),
+    ('璧', (33, 58)),
+    ('璨', (31, 148)),
+    ('璩', (31, 145)),
+    ('璪', (76, 42)),
+    ('璫', (76, 40)),
+    ('璭', (76, 43)),
+    ('璯', (76, 46)),
+    ('環', (31, 146)),
+    ('璱', (76, 44)),
+    ('璲', (76, 39)),
+    ('璵', (79, 2)),
+    ('璶', (79, 5)),
+    ('璷', (81, 53)),
+    ('璸', (79, 0)),
+    ('璺', (83, 47)),
+    ('璻', (79, 6)),
+    ('璽', (34, 64)),
+    ('璾', (79, 4)),
+    ('璿', (33, 59)),
+    ('瓀', (79, 1)),
+    ('瓁', (79, 3)),
+    ('瓂', (79, 7)),
+    ('瓃', (81, 54)),
+    ('瓅', (81, 52)),
+    ('瓊', (34, 65)),
+    ('瓋', (81, 51)),
+    ('瓏', (35, 46)),
+    ('瓔', (35, 139)),
+    ('瓕', (85, 143)),
+    ('瓖', (35, 138)),
+    ('瓗', (85, 145)),
+    ('瓘', (85, 142)),
+    ('瓙', (85, 144)),
+    ('瓚', (36, 110)),
+    ('瓛', (87, 80)),
+    ('瓜', (4, 104)),
+    ('瓝', (44, 46)),
+    ('瓞', (49, 90)),
+    ('瓟', (49, 91)),
+    ('瓠', (17, 61)),
+    ('瓡', (61, 93)),
+    ('瓢', (30, 29)),
+    ('瓣', (34, 66)),
+    ('瓤', (36, 59)),
+    ('瓥', (87, 81)),
+    ('瓦', (4, 105)),
+    ('瓨', (44, 47)),
+    ('瓩', (1, 32)),
+    ('瓬', (46, 117)),
+    ('瓮', (46, 118)),
+    ('瓴', (49, 92)),
+    ('瓵', (49, 93)),
+    ('瓶', (17, 62)),
+    ('瓷', (17, 63)),
+    ('瓻', (57, 73)),
+    ('瓽', (61, 96)),
+    ('瓾', (61, 95)),
+    ('瓿', (61, 94)),
+    ('甀', (65, 115)),
+    ('甂', (65, 116)),
+    ('甃', (65, 117)),
+    ('甄', (25, 96)),
+    ('甇', (69, 75)),
+    ('甈', (69, 74)),
+    ('甋', (73, 41)),
+    ('甌', (30, 30)),
+    ('甍', (30, 31)),
+    ('甏', (76, 50)),
+    ('甐', (76, 47)),
+    ('甑', (76, 48)),
+    ('甒', (76, 49)),
+    ('甓', (79, 9)),
+    ('甔', (79, 8)),
+    ('甕', (33, 60)),
+    ('甖', (81, 55)),
+    ('甗', (84, 124)),
+    ('甘', (4, 106)),
+    ('甚', (11, 100)),
+    ('甜', (17, 64)),
+    ('甝', (61, 97)),
+    ('生', (4, 107)),
+    ('甡', (49, 94)),
+    ('產', (17, 65)),
+    ('甥', (20, 35)),
+    ('甦', (20, 36)),
+    ('用', (4, 108)),
+    ('甩', (4, 109)),
+    ('甪', (41, 6)),
+    ('甫', (7, 42)),
+    ('甬', (7, 41)),
+    ('甭', (11, 101)),
+    ('甮', (46, 119)),
+    ('甯', (57, 74)),
+    ('田', (4, 110)),
+    ('由', (4, 111)),
+    ('甲', (4, 112)),
+    ('申', (4, 113)),
+    ('男', (7, 43)),
+    ('甸', (7, 44)),
+    ('甹', (42, 46)),
+    ('町', (42, 45)),
+    ('甽', (9, 84)),
+    ('甾', (44, 50)),
+    ('甿', (44, 48)),
+    ('畀', (44, 49)),
+    ('畇', (46, 120)),
+    ('畈', (46, 121)),
+    ('畋', (11, 105)),
+    ('界', (11, 103)),
+    ('畎', (11, 104)),
+    ('畏', (11, 102)),
+    ('畔', (14, 32)),
+    ('留', (14, 36)),
+    ('畚', (14, 35)),
+    ('畛', (49, 95)),
+    ('畜', (14, 34)),
+    ('畝', (14, 33)),
+    ('畟', (49, 96)),
+    ('畢', (17, 68)),
+    ('畣', (53, 59)),
+    ('畤', (53, 58)),
+    ('略', (17, 66)),
+    ('畦', (17, 67)),
+    ('番', (20, 38)),
+    ('畫', (20, 37)),
+    ('畬', (57, 76)),
+    ('畯', (57, 75)),
+    ('異', (17, 69)),
+    ('當', (22, 139)),
+    ('畷', (61, 99)),
+    ('畸', (22, 140)),
+    ('畹', (61, 98)),
+    ('畽', (65, 118)),
+    ('畾', (69, 76)),
+    ('畿', (28, 2)),
+    ('疀', (73, 42)),
+    ('疄', (76, 51)),
+    ('疆', (34, 68)),
+    ('疇', (34, 67)),
+    ('疊', (36, 60)),
+    ('疋', (4, 114)),
+    ('疌', (44, 51)),
+    ('疏', (17, 70)),
+    ('疐', (65, 119)),
+    ('疑', (25, 97)),
+    ('疔', (42, 47)),
+    ('疕', (42, 48)),
+    ('疘', (44, 52)),
+    ('疙', (9, 86)),
+    ('疚', (9, 87)),
+    ('疝', (9, 85)),
+    ('疢', (11, 109)),
+    ('疣', (11, 110)),
+    ('疤', (11, 107)),
+    ('疥', (11, 108)),
+    ('疧', (46, 122)),
+    ('疪', (46, 123)),
+    ('疫', (11, 106)),
+    ('疰', (49, 97)),
+    ('疲', (14, 40)),
+    ('疳', (14, 41)),
+    ('疵', (17, 73)),
+    ('疶', (49, 103)),
+    ('疸', (14, 46)),
+    ('疹', (14, 44)),
+    ('疺', (49, 104)),
+    ('疻', (49, 99)),
+    ('疼', (14, 43)),
+    ('疽', (14, 42)),
+    ('疾', (14, 37)),
+    ('疿', (49, 102)),
+    ('痀', (49, 101)),
+    ('痁', (49, 98)),
+    ('痂', (14, 45)),
+    ('痄', (49, 100)),
+    ('病', (14, 38)),
+    ('症', (14, 39)),
+    ('痊', (17, 74)),
+    ('痋', (53, 63)),
+    ('痌', (53, 64)),
+    ('痍', (17, 75)),
+    ('痎', (53, 60)),
+    ('痏', (53, 62)),
+    ('痐', (53, 66)),
+    ('痑', (53, 65)),
+    ('痒', (53, 61)),
+    ('痔', (17, 71)),
+    ('痕', (17, 72)),
+    ('痗', (57, 84)),
+    ('痘', (20, 43)),
+    ('痙', (20, 42)),
+    ('痚', (57, 78)),
+    ('痛', (20, 40)),
+    ('痝', (57, 81)),
+    ('痞', (20, 44)),
+    ('痟', (57, 82)),
+    ('痠', (20, 45)),
+    ('痡', (57, 79)),
+    ('痢', (20, 39)),
+    ('痣', (20, 41)),
+    ('痤', (57, 83)),
+    ('痦', (57, 80)),
+    ('痧', (57, 77)),
+    ('痭', (61, 112)),
+    ('痯', (61, 101)),
+    ('痰', (22, 142)),
+    ('痱', (22, 145)),
+    ('痲', (22, 144)),
+    ('痳', (22, 149)),
+    ('痴', (22, 148)),
+    ('痵', (61, 113)),
+    ('痶', (61, 111)),
+    ('痷', (61, 104)),
+    ('痸', (61, 108)),
+    ('痹', (61, 107)),
+    ('痺', (22, 146)),
+    ('痻', (61, 110)),
+    ('痼', (61, 106)),
+    ('痽', (61, 114)),
+    ('痾', (61, 105)),
+    ('痿', (22, 147)),
+    ('瘀', (22, 141)),
+    ('瘁', (22, 143)),
+    ('瘃', (61, 103)),
+    ('瘈', (65, 121)),
+    ('瘉', (25, 101)),
+    ('瘊', (65, 125)),
+    ('瘋', (25, 100)),
+    ('瘌', (65, 122)),
+    ('瘍', (25, 99)),
+    ('瘏', (61, 102)),
+    ('瘐', (61, 109)),
+    ('瘑', (65, 124)),
+    ('瘓', (25, 102)),
+    ('瘔', (65, 126)),
+    ('瘕', (65, 123)),
+    ('瘖', (65, 120)),
+    ('瘙', (69, 79)),
+    ('瘚', (69, 83)),
+    ('瘛', (69, 85)),
+    ('瘜', (69, 81)),
+    ('瘝', (69, 80)),
+    ('瘞', (69, 78)),
+    ('瘟', (28, 5)),
+    ('瘠', (28, 3)),
+    ('瘡', (28, 8)),
+    ('瘢', (28, 9)),
+    ('瘣', (69, 82)),
+    ('瘤', (28, 6)),
+    ('瘥', (69, 77)),
+    ('瘦', (28, 7)),
+    ('瘧', (25, 98)),
+    ('瘨', (69, 84)),
+    ('瘩', (28, 4)),
+    ('瘭', (73, 44)),
+    ('瘯', (73, 43)),
+    ('瘰', (73, 51)),
+    ('瘱', (73, 45)),
+    ('瘲', (73, 50)),
+    ('瘳', (73, 47)),
+    ('瘴', (30, 32)),
+    ('瘵', (73, 49)),
+    ('瘸', (30, 33)),
+    ('瘺', (30, 34)),
+    ('瘼', (73, 48)),
+    ('瘽', (73, 46)),
+    ('療', (31, 150)),
+    ('癃', (76, 52)),
+    ('癆', (31, 149)),
+    ('癇', (76, 55)),
+    ('癈', (76, 53)),
+    ('癉', (76, 54)),
+    ('癌', (31, 151)),
+    ('癐', (79, 13)),
+    ('癒', (33, 63)),
+    ('癓', (79, 14)),
+    ('癖', (33, 61)),
+    ('癗', (79, 15)),
+    ('癘', (33, 62)),
+    ('癙', (79, 12)),
+    ('癚', (79, 16)),
+    ('癜', (79, 10)),
+    ('癟', (34, 69)),
+    ('癠', (81, 56)),
+    ('癡', (34, 70)),
+    ('癢', (35, 47)),
+    ('癤', (79, 11)),
+    ('癥', (35, 48)),
+    ('癩', (35, 140)),
+    ('癪', (84, 125)),
+    ('癬', (36, 62)),
+    ('癭', (85, 146)),
+    ('癮', (36, 61)),
+    ('癰', (86, 136)),
+    ('癱', (36, 147)),
+    ('癲', (36, 148)),
+    ('癵', (88, 106)),
+    ('癸', (11, 111)),
+    ('癹', (46, 124)),
+    ('登', (20, 46)),
+    ('發', (20, 47)),
+    ('白', (4, 115)),
+    ('百', (5, 104)),
+    ('癿', (41, 7)),
+    ('皁', (42, 49)),
+    ('皂', (7, 45)),
+    ('的', (9, 88)),
+    ('皆', (11, 112)),
+    ('皇', (11, 113)),
+    ('皈', (11, 114)),
+    ('皉', (53, 68)),
+    ('皊', (49, 105)),
+    ('皋', (14, 47)),
+    ('皎', (17, 76)),
+    ('皏', (53, 67)),
+    ('皒', (57, 86)),
+    ('皓', (20, 49)),
+    ('皕', (57, 85)),
+    ('皖', (20, 48)),
+    ('皙', (61, 115)),
+    ('皚', (28, 10)),
+    ('皛', (69, 89)),
+    ('皜', (69, 86)),
+    ('皝', (69, 87)),
+    ('皞', (69, 88)),
+    ('皤', (76, 56)),
+    ('皦', (79, 17)),
+    ('皪', (83, 49)),
+    ('皫', (83, 48)),
+    ('皭', (85, 147)),
+    ('皮', (4, 116)),
+    ('皯', (44, 53)),
+    ('皰', (14, 48)),
+    ('皴', (20, 50)),
+    ('皵', (61, 116)),
+    ('皸', (65, 127)),
+    ('皺', (28, 11)),
+    ('皻', (73, 52)),
+    ('皽', (79, 18)),
+    ('皾', (83, 50)),
+    ('皿', (4, 117)),
+    ('盂', (9, 89)),
+    ('盃', (11, 117)),
+    ('盄', (46, 125)),
+    ('盅', (11, 118)),
+    ('盆', (11, 116)),
+    ('盈', (11, 115)),
+    ('盉', (49, 106)),
+    ('益', (14, 49)),
+    ('盍', (14, 50)),
+    ('盎', (14, 51)),
+    ('盒', (17, 78)),
+    ('盓', (53, 69)),
+    ('盔', (17, 77)),
+    ('盚', (57, 87)),
+    ('盛', (17, 79)),
+    ('盜', (20, 51)),
+    ('盝', (61, 117)),
+    ('盞', (22, 150)),
+    ('盟', (22, 151)),
+    ('盡', (25, 103)),
+    ('監', (25, 104)),
+    ('盤', (28, 12)),
+    ('盥', (30, 36)),
+    ('盦', (73, 53)),
+    ('盧', (30, 35)),
+    ('盩', (76, 57)),
+    ('盪', (31, 152)),
+    ('盬', (79, 19)),
+    ('盭', (83, 51)),
+    ('目', (4, 118)),
+    ('盯', (7, 46)),
+    ('盰', (44, 56)),
+    ('盱', (44, 55)),
+    ('盲', (9, 90)),
+    ('盳', (44, 54)),
+    ('直', (9, 91)),
+    ('盵', (44, 57)),
+    ('盷', (46, 131)),
+    ('相', (11, 121)),
+    ('盹', (11, 120)),
+    ('盺', (46, 133)),
+    ('盻', (46, 132)),
+    ('盼', (11, 125)),
+    ('盾', (11, 124)),
+    ('省', (11, 119)),
+    ('眃', (46, 127)),
+    ('眄', (46, 128)),
+    ('眅', (46, 129)),
+    ('眇', (11, 126)),
+    ('眈', (46, 126)),
+    ('眉', (11, 122)),
+    ('眊', (46, 130)),
+    ('看', (11, 123)),
+    ('眐', (49, 109)),
+    ('眑', (49, 113)),
+    ('眒', (49, 111)),
+    ('眓', (49, 110)),
+    ('眕', (49, 114)),
+    ('眙', (49, 115)),
+    ('眚', (49, 116)),
+    ('眛', (49, 108)),
+    ('眝', (49, 107)),
+    ('真', (14, 53)),
+    ('眠', (14, 54)),
+    ('眢', (49, 117)),
+    ('眣', (49, 112)),
+    ('眥', (53, 78)),
+    ('眧', (49, 118)),
+    ('眨', (14, 55)),
+    ('眩', (14, 52)),
+    ('眭', (53, 72)),
+    ('眯', (53, 71)),
+    ('眱', (53, 73)),
+    ('眲', (53, 74)),
+    ('眳', (53, 76)),
+    ('眴', (53, 75)),
+    ('眵', (53, 80)),
+    ('眶', (17, 83)),
+    ('眷', (17, 80)),
+    ('眸', (17, 84)),
+    ('眹', (53, 70)),
+    ('眺', (17, 85)),
+    ('眻', (53, 79)),
+    ('眼', (17, 82)),
+    ('眽', (53, 77)),
+    ('眾', (17, 81)),
+    ('睄', (57, 90)),
+    ('睅', (57, 92)),
+    ('睆', (57, 88)),
+    ('睇', (57, 89)),
+    ('睊', (57, 93)),
+    ('睋', (57, 95)),
+    ('睌', (57, 96)),
+    ('睍', (57, 91)),
+    ('睎', (57, 94)),
+    ('睏', (20, 52)),
+    ('睒', (61, 121)),
+    ('睔', (61, 126)),
+    ('睕', (61, 118)),
+    ('睖', (61, 122)),
+    ('睙', (61, 127)),
+    ('睚', (61, 123)),
+    ('睛', (22, 152)),
+    ('睜', (23, 3)),
+    ('睞', (22, 155)),
+    ('睟', (61, 119)),
+    ('睠', (61, 120)),
+    ('睡', (25, 108)),
+    ('睢', (23, 6)),
+    ('督', (22, 156)),
+    ('睥', (23, 4)),
+    ('睦', (22, 154)),
+    ('睧', (61, 125)),
+    ('睨', (23, 5)),
+    ('睩', (61, 124)),
+    ('睪', (23, 1)),
+    ('睫', (22, 153)),
+    ('睬', (23, 2)),
+    ('睭', (61, 128)),
+    ('睮', (65, 132)),
+    ('睯', (65, 134)),
+    ('睹', (23, 0)),
+    ('睼', (65, 129)),
+    ('睽', (25, 106)),
+    ('睾', (65, 135)),
+    ('睿', (25, 107)),
+    ('瞀', (65, 133)),
+    ('瞁', (65, 128)),
+    ('瞂', (65, 131)),
+    ('瞃', (65, 136)),
+    ('瞄', (25, 105)),
+    ('瞅', (65, 130)),
+    ('瞇', (28, 14)),
+    ('瞈', (69, 93)),
+    ('瞉', (69, 92)),
+    ('瞋', (28, 17)),
+    ('瞌', (28, 15)),
+    ('瞍', (69, 90)),
+    ('瞎', (28, 13)),
+    ('瞏', (69, 91)),
+    ('瞑', (28, 16)),
+    ('瞕', (73, 61)),
+    ('瞗', (73, 63)),
+    ('瞙', (73, 62)),
+    ('瞚', (73, 54)),
+    ('瞛', (73, 58)),
+    ('瞜', (73, 57)),
+    ('瞝', (73, 55)),
+    ('瞞', (30, 38)),
+    ('瞟', (30, 39)),
+    ('瞠', (30, 37)),
+    ('瞡', (73, 56)),
+    ('瞢', (73, 59)),
+    ('瞣', (73, 60)),
+    ('瞥', (30, 40)),
+    ('瞧', (32, 0)),
+    ('瞨', (76, 65)),
+    ('瞪', (31, 154)),
+    ('瞫', (76, 59)),
+    ('瞬', (31, 156)),
+    ('瞭', (32, 1)),
+    ('瞰', (31, 155)),
+    ('瞱', (76, 64)),
+    ('瞲', (76, 60)),
+    ('瞳', (31, 153)),
+    ('瞴', (76, 63)),
+    ('瞵', (76, 58)),
+    ('瞶', (76, 62)),
+    ('瞷', (76, 61)),
+    ('瞺', (79, 21)),
+    ('瞻', (33, 66)),
+    ('瞼', (33, 67)),
+    ('瞽', (33, 64)),
+    ('瞿', (33, 65)),
+    ('矂', (79, 20)),
+    ('矄', (81, 59)),
+    ('矇', (34, 71)),
+    ('矉', (81, 57)),
+    ('矊', (81, 58)),
+    ('矌', (83, 52)),
+    ('矍', (83, 55)),
+    ('矎', (83, 53)),
+    ('矏', (83, 54)),
+    ('矐', (84, 126)),
+    ('矓', (35, 141)),
+    ('矔', (86, 137)),
+    ('矕', (87, 82)),
+    ('矗', (36, 149)),
+    ('矘', (87, 150)),
+    ('矙', (87, 151)),
+    ('矚', (37, 38)),
+    ('矛', (4, 119)),
+    ('矜', (11, 127)),
+    ('矞', (57, 97)),
+    ('矠', (61, 129)),
+    ('矢', (4, 120)),
+    ('矣', (7, 47)),
+    ('知', (9, 92)),
+    ('矧', (46, 134)),
+    ('矨', (46, 135)),
+    ('矩', (14, 56)),
+    ('矬', (57, 98)),
+    ('短', (20, 53)),
+    ('矮', (23, 7)),
+    ('矯', (32, 2)),
+    ('矰', (76, 66)),
+    ('矱', (81, 60)),
+    ('矲', (83, 56)),
+    ('石', (4, 121)),
+    ('矷', (44, 63)),
+    ('矸', (44, 58)),
+    ('矹', (44, 60)),
+    ('矺', (44, 62)),
+    ('矻', (44, 61)),
+    ('矼', (44, 59)),
+    ('矽', (9, 93)),
+    ('砂', (11, 128)),
+    ('砃', (46, 144)),
+    ('砅', (46, 139)),
+    ('砆', (46, 136)),
+    ('砉', (46, 143)),
+    ('砌', (11, 130)),
+    ('砍', (11, 131)),
+    ('砎', (46, 142)),
+    ('砏', (46, 141)),
+    ('砐', (46, 140)),
+    ('砑', (46, 137)),
+    ('砒', (46, 138)),
+    ('砓', (46, 145)),
+    ('研', (11, 129)),
+    ('砝', (14, 60)),
+    ('砟', (14, 66)),
+    ('砠', (14, 65)),
+    ('砡', (49, 127)),
+    ('砢', (49, 121)),
+    ('砣', (49, 119)),
+    ('砥', (14, 63)),
+    ('砦', (53, 87)),
+    ('砧', (14, 58)),
+    ('砨', (49, 124)),
+    ('砩', (49, 128)),
+    ('砪', (49, 130)),
+    ('砫', (49, 126)),
+    ('砬', (49, 120)),
+    ('砭', (14, 64)),
+    ('砮', (49, 125)),
+    ('砯', (49, 123)),
+    ('砰', (14, 57)),
+    ('砱', (49, 131)),
+    ('砲', (14, 67)),
+    ('砳', (49, 129)),
+    ('破', (14, 61)),
+    ('砵', (49, 122)),
+    ('砷', (14, 62)),
+    ('砸', (14, 59)),
+    ('硃', (17, 87)),
+    ('硅', (53, 88)),
+    ('硈', (53, 81)),
+    ('硉', (53, 83)),
+    ('硊', (53, 85)),
+    ('硌', (53, 86)),
+    ('硍', (53, 84)),
+    ('硎', (17, 88)),
+    ('硐', (53, 89)),
+    ('硒', (53, 82)),
+    ('硜', (57, 102)),
+    ('硝', (20, 54)),
+    ('硞', (57, 110)),
+    ('硠', (57, 99)),
+    ('硢', (57, 111)),
+    ('硤', (57, 100)),
+    ('硥', (57, 101)),
+    ('硨', (57, 109)),
+    ('硩', (57, 108)),
+    ('硪', (57, 105)),
+    ('硫', (17, 86)),
+    ('硬', (20, 55)),
+    ('硭', (57, 103)),
+    ('确', (57, 106)),
+    ('硯', (20, 56)),
+    ('硰', (57, 107)),
+    ('硱', (57, 104)),
+    ('硹', (61, 140)),
+    ('硻', (61, 144)),
+    ('硼', (23, 14)),
+    ('硾', (65, 142)),
+    ('硿', (23, 17)),
+    ('碀', (61, 142)),
+    ('碃', (61, 139)),
+    ('碄', (61, 134)),
+    ('碅', (61, 136)),
+    ('碆', (61, 137)),
+    ('碇', (61, 130)),
+    ('碉', (23, 13)),
+    ('碌', (23, 12)),
+    ('碎', (23, 8)),
+    ('碏', (61, 133)),
+    ('碑', (23, 15)),
+    ('碓', (23, 16)),
+    ('碔', (61, 132)),
+    ('碕', (61, 135)),
+    ('碖', (61, 143)),
+    ('碗', (23, 10)),
+    ('碘', (23, 11)),
+    ('碙', (61, 141)),
+    ('碚', (61, 131)),
+    ('碞', (65, 144)),
+    ('碟', (25, 110)),
+    ('碠', (65, 146)),
+    ('碡', (61, 138)),
+    ('碢', (65, 148)),
+    ('碣', (25, 114)),
+    ('碤', (65, 149)),
+    ('碥', (65, 145)),
+    ('碧', (25, 111)),
+    ('碨', (65, 141)),
+    ('碩', (25, 113)),
+    ('碪', (65, 138)),
+    ('碫', (65, 143)),
+    ('碬', (65, 147)),
+    ('碭', (65, 140)),
+    ('碰', (23, 9)),
+    ('碲', (65, 137)),
+    ('碳', (25, 112)),
+    ('碴', (65, 139)),
+    ('確', (28, 20)),
+    ('碻', (69, 95)),
+    ('碼', (28, 24)),
+    ('碾', (28, 22)),
+    ('磁', (25, 109)),
+    ('磃', (69, 102)),
+    ('磄', (69, 103)),
+    ('磅', (28, 19)),
+    ('磈', (69, 101)),
+    ('磉', (69, 104)),
+    ('磊', (28, 21)),
+    ('磋', (28, 18)),
+    ('磌', (69, 97)),
+    ('磍', (69, 94)),
+    ('磎', (69, 99)),
+    ('磏', (69, 96)),
+    ('磐', (28, 25)),
+    ('磑', (69, 98)),
+    ('磔', (69, 100)),
+    ('磕', (28, 23)),
+    ('磚', (30, 42)),
+    ('磛', (73, 70)),
+    ('磝', (73, 64)),
+    ('磞', (73, 68)),
+    ('磟', (73, 74)),
+    ('磠', (73, 75)),
+    ('磡', (73, 71)),
+    ('磢', (73, 72)),
+    ('磣', (73, 69)),
+    ('磥', (73, 66)),
+    ('磧', (30, 44)),
+    ('磨', (30, 41)),
+    ('磩', (73, 65)),
+    ('磪', (73, 67)),
+    ('磬', (30, 43)),
+    ('磭', (73, 73)),
+    ('磯', (32, 6)),
+    ('磲', (76, 72)),
+    ('磳', (76, 67)),
+    ('磴', (32, 5)),
+    ('磷', (32, 3)),
+    ('磹', (76, 74)),
+    ('磺', (32, 4)),
+    ('磻', (76, 70)),
+    ('磼', (76, 71)),
+    ('磽', (76, 68)),
+    ('磾', (76, 75)),
+    ('磿', (79, 22)),
+    ('礁', (32, 7)),
+    ('礂', (76, 69)),
+    ('礄', (76, 76)),
+    ('礅', (76, 73)),
+    ('礉', (79, 26)),
+    ('礌', (79, 23)),
+    ('礎', (33, 68)),
+    ('礐', (79, 27)),
+    ('礑', (79, 29)),
+    ('礒', (79, 28)),
+    ('礓', (79, 24)),
+    ('礔', (79, 25)),
+    ('礗', (81, 65)),
+    ('礙', (34, 72)),
+    ('礛', (81, 62)),
+    ('礜', (81, 64)),
+    ('礝', (81, 61)),
+    ('礞', (81, 66)),
+    ('礡', (81, 63)),
+    ('礣', (83, 58)),
+    ('礤', (83, 61)),
+    ('礥', (83, 57)),
+    ('礦', (35, 49)),
+    ('礧', (83, 59)),
+    ('礨', (83, 60)),
+    ('礩', (83, 62)),
+    ('礪', (35, 50)),
+    ('礫', (35, 52)),
+    ('礬', (35, 51)),
+    ('礭', (84, 127)),
+    ('礯', (84, 129)),
+    ('礱', (84, 128)),
+    ('礵', (85, 148)),
+    ('礸', (87, 83)),
+    ('礹', (87, 152)),
+    ('示', (4, 122)),
+    ('礽', (42, 50)),
+    ('社', (9, 94)),
+    ('礿', (44, 65)),
+    ('祀', (9, 95)),
+    ('祁', (9, 96)),
+    ('祂', (44, 64)),
+    ('祄', (46, 150)),
+    ('祅', (46, 149)),
+    ('祆', (11, 132)),
+    ('祇', (11, 135)),
+    ('祈', (11, 134)),
+    ('祉', (11, 133)),
+    ('祊', (46, 146)),
+    ('祋', (46, 148)),
+    ('祌', (46, 147)),
+    ('祏', (49, 134)),
+    ('祐', (14, 69)),
+    ('祑', (49, 138)),
+    ('祒', (49, 137)),
+    ('祓', (49, 136)),
+    ('祔', (49, 132)),
+    ('祕', (14, 68)),
+    ('祖', (14, 72)),
+    ('祗', (14, 75)),
+    ('祚', (14, 76)),
+    ('祛', (49, 133)),
+    ('祜', (49, 135)),
+    ('祝', (14, 74)),
+    ('神', (14, 73)),
+    ('祟', (14, 71)),
+    ('祠', (14, 70)),
+    ('祡', (53, 96)),
+    ('祣', (53, 94)),
+    ('祤', (53, 90)),
+    ('祥', (17, 89)),
+    ('祧', (53, 91)),
+    ('票', (17, 90)),
+    ('祩', (53, 92)),
+    ('祪', (53, 93)),
+    ('祫', (53, 95)),
+    ('祭', (17, 91)),
+    ('祰', (57, 115)),
+    ('祲', (57, 114)),
+    ('祳', (57, 113)),
+    ('祴', (57, 112)),
+    ('祹', (61, 148)),
+    ('祺', (23, 18)),
+    ('祼', (61, 145)),
+    ('祽', (61, 147)),
+    ('祿', (23, 19)),
+    ('禁', (23, 20)),
+    ('禂', (61, 146)),
+    ('禈', (66, 1)),
+    ('禊', (65, 151)),
+    ('禋', (65, 152)),
+    ('禍', (25, 117)),
+    ('禎', (25, 115)),
+    ('福', (25, 116)),
+    ('禐', (66, 3)),
+    ('禒', (66, 2)),
+    ('禓', (65, 156)),
+    ('禔', (65, 155)),
+    ('禕', (65, 154)),
+    ('禖', (65, 153)),
+    ('禗', (66, 0)),
+    ('禘', (65, 150)),
+    ('禚', (69, 105)),
+    ('禛', (69, 110)),
+    ('禜', (69, 108)),
+    ('禠', (69, 107)),
+    ('禡', (69, 106)),
+    ('禢', (69, 109)),
+    ('禤', (73, 76)),
+    ('禦', (30, 45)),
+    ('禧', (32, 8)),
+    ('禨', (76, 78)),
+    ('禪', (32, 9)),
+    ('禫', (76, 77)),
+    ('禬', (79, 31)),
+    ('禭', (79, 30)),
+    ('禮', (33, 69)),
+    ('禰', (81, 67)),
+    ('禱', (34, 73)),
+    ('禲', (83, 63)),
+    ('禳', (36, 63)),
+    ('禴', (85, 149)),
+    ('禶', (87, 85)),
+    ('禷', (87, 84)),
+    ('禸', (40, 70)),
+    ('禹', (11, 136)),
+    ('禺', (11, 137)),
+    ('离', (53, 97)),
+    ('禽', (23, 22)),
+    ('禾', (4, 123)),
+    ('禿', (7, 50)),
+    ('秀', (7, 49)),
+    ('私', (7, 48)),
+    ('秅', (44, 66)),
+    ('秈', (9, 98)),
+    ('秉', (9, 97)),
+    ('秋', (11, 140)),
+    ('种', (46, 152)),
+    ('秎', (46, 155)),
+    ('秏', (46, 153)),
+    ('科', (11, 138)),
+    ('秒', (11, 139)),
+    ('秕', (46, 151)),
+    ('秖', (46, 154)),
+    ('秘', (14, 83)),
+    ('秜', (49, 145)),
+    ('秝', (49, 147)),
+    ('秞', (49, 146)),
+    ('租', (14, 80)),
+    ('秠', (49, 141)),
+    ('秣', (14, 78)),
+    ('秤', (14, 77)),
+    ('秦', (14, 81)),
+    ('秧', (14, 79)),
+    ('秩', (14, 82)),
+    ('秪', (49, 144)),
+    ('秫', (49, 139)),
+    ('秬', (49, 140)),
+    ('秭', (49, 143)),
+    ('秮', (49, 142)),
+    ('秶', (53, 100)),
+    ('秷', (53, 101)),
+    ('秸', (53, 99)),
+    ('秺', (53, 98)),
+    ('移', (17, 92)),
+    ('稀', (20, 61)),
+    ('稂', (57, 116)),
+    ('稃', (57, 118)),
+    ('稄', (57, 120)),
+    ('稅', (20, 60)),
+    ('稈', (20, 58)),
+    ('稊', (57, 117)),
+    ('程', (20, 59)),
+    ('稌', (57, 119)),
+    ('稍', (20, 57)),
+    ('稐', (62, 1)),
+    ('稑', (61, 149)),
+    ('稒', (61, 152)),
+    ('稓', (61, 156)),
+    ('稔', (23, 26)),
+    ('稕', (61, 154)),
+    ('稗', (61, 153)),
+    ('稘', (61, 150)),
+    ('稙', (61, 151)),
+    ('稚', (23, 24)),
+    ('稛', (62, 0)),
+    ('稜', (23, 23)),
+    ('稞', (23, 28)),
+    ('稟', (23, 27)),
+    ('稠', (23, 25)),
+    ('稢', (61, 155)),
+    ('稦', (66, 9)),
+    ('稨', (66, 8)),
+    ('稫', (66, 4)),
+    ('種', (25, 118)),
+    ('稯', (66, 7)),
+    ('稰', (66, 6)),
+    ('稱', (25, 119)),
+    ('稷', (28, 30)),
+    ('稹', (69, 112)),
+    ('稻', (28, 31)),
+    ('稼', (28, 27)),
+    ('稽', (28, 29)),
+    ('稿', (28, 26)),
+    ('穀', (28, 28)),
+    ('穄', (73, 77)),
+    ('穆', (30, 48)),
+    ('穇', (73, 79)),
+    ('穈', (73, 78)),
+    ('穊', (66, 5)),
+    ('穋', (30, 50)),
+    ('穌', (30, 49)),
+    ('積', (30, 46)),
+    ('穎', (30, 47)),
+    ('穔', (76, 83)),
+    ('穖', (76, 81)),
+    ('穗', (32, 10)),
+    ('穘', (76, 82)),
+    ('穚', (76, 84)),
+    ('穛', (76, 80)),
+    ('穜', (76, 79)),
+    ('穟', (79, 32)),
+    ('穠', (33, 72)),
+    ('穡', (33, 70)),
+    ('穢', (33, 71)),
+    ('穧', (81, 68)),
+    ('穨', (81, 69)),
+    ('穩', (34, 75)),
+    ('穫', (34, 74)),
+    ('穬', (83, 65)),
+    ('穭', (83, 66)),
+    ('穮', (83, 64)),
+    ('穰', (85, 150)),
+    ('穱', (85, 151)),
+    ('穴', (4, 124)),
+    ('穵', (41, 8)),
+    ('究', (7, 51)),
+    ('穸', (44, 67)),
+    ('穹', (9, 100)),
+    ('空', (9, 99)),
+    ('穻', (44, 68)),
+    ('穾', (47, 0)),
+    ('穿', (11, 141)),
+    ('窀', (46, 156)),
+    ('突', (11, 142)),
+    ('窄', (14, 84)),
+    ('窅', (49, 150)),
+    ('窆', (49, 148)),
+    ('窇', (49, 154)),
+    ('窈', (14, 85)),
+    ('窉', (49, 149)),
+    ('窊', (49, 153)),
+    ('窋', (49, 151)),
+    ('窌', (49, 152)),
+    ('窏', (53, 102)),
+    ('窐', (53, 104)),
+    ('窒', (17, 93)),
+    ('窔', (53, 103)),
+    ('窕', (17, 94)),
+    ('窖', (20, 64)),
+    ('窗', (20, 63)),
+    ('窘', (20, 62)),
+    ('窙', (57, 121)),
+    ('窞', (62, 4)),
+    ('窟', (23, 29)),
+    ('窠', (23, 30)),
+    ('窢', (62, 3)),
+    ('窣', (62, 2)),
+    ('窨', (66, 10)),
+    ('窩', (25, 121)),
+    ('窪', (25, 120)),
+    ('窫', (66, 11)),
+    ('窬', (66, 12)),
+    ('窮', (28, 33)),
+    ('窯', (28, 32)),
+    ('窱', (73, 83)),
+    ('窲', (69, 113)),
+    ('窳', (69, 115)),
+    ('窴', (69, 114)),
+    ('窵', (73, 82)),
+    ('窶', (73, 80)),
+    ('窷', (73, 84)),
+    ('窸', (73, 81)),
+    ('窺', (30, 51)),
+    ('窾', (76, 85)),
+    ('窿', (32, 11)),
+    ('竀', (76, 86)),
+    ('竁', (76, 87)),
+    ('竄', (33, 73)),
+    ('竅', (33, 74)),
+    ('竇', (35, 53)),
+    ('竊', (36, 111)),
+    ('立', (4, 125)),
+    ('竑', (47, 1)),
+    ('竘', (49, 155)),
+    ('站', (14, 86)),
+    ('竟', (18, 88)),
+    ('章', (18, 87)),
+    ('竣', (20, 66)),
+    ('竤', (57, 123)),
+    ('童', (20, 65)),
+    ('竦', (57, 122)),
+    ('竫', (62, 5)),
+    ('竭', (25, 122)),
+    ('竮', (66, 13)),
+    ('端', (25, 123)),
+    ('競', (35, 54)),
+    ('竷', (83, 67)),
+    ('竹', (5, 105)),
+    ('竺', (9, 101)),
+    ('竻', (44, 69)),
+    ('竽', (11, 144)),
+    ('竿', (11, 143)),
+    ('笀', (47, 2)),
+    ('笁', (47, 3)),
+    ('笄', (50, 0)),
+    ('笅', (50, 2)),
+    ('笆', (14, 87)),
+    ('笈', (50, 4)),
+    ('笉', (50, 7)),
+    ('笊', (50, 5)),
+    ('笎', (50, 6)),
+    ('笏', (50, 3)),
+    ('笐', (49, 156)),
+    ('笑', (14, 88)),
+    ('笒', (50, 8)),
+    ('笓', (50, 1)),
+    ('笘', (53, 113)),
+    ('笙', (17, 100)),
+    ('笚', (53, 122)),
+    ('笛', (17, 97)),
+    ('笝', (53, 115)),
+    ('笞', (17, 101)),
+    ('笠', (17, 95)),
+    ('笢', (53, 110)),
+    ('笣', (53, 123)),
+    ('笤', (53, 111)),
+    ('笥', (53, 108)),
+    ('符', (17, 99)),
+    ('笨', (17, 96)),
+    ('笪', (53, 114)),
+    ('笫', (53, 117)),
+    ('第', (17, 98)),
+    ('笭', (53, 118)),
+    ('笮', (17, 102)),
+    ('笯', (53, 119)),
+    ('笰', (53, 109)),
+    ('笱', (53, 116)),
+    ('笲', (53, 120)),
+    ('笳', (53, 112)),
+    ('笴', (53, 107)),
+    ('笵', (53, 105)),
+    ('笸', (53, 121)),
+    ('笻', (57, 125)),
+    ('筀', (57, 130)),
+    ('筄', (57, 126)),
+    ('筅', (57, 132)),
+    ('筆', (20, 69)),
+    ('筇', (53, 106)),
+    ('筈', (57, 127)),
+    ('等', (20, 67)),
+    ('筊', (57, 124)),
+    ('筋', (20, 74)),
+    ('筌', (57, 128)),
+    ('筍', (20, 73)),
+    ('筎', (57, 129)),
+    ('筏', (20, 75)),
+    ('筐', (20, 70)),
+    ('筑', (20, 76)),
+    ('筒', (20, 71)),
+    ('答', (20, 72)),
+    ('策', (20, 68)),
+    ('筘', (57, 131)),
+    ('筠', (23, 33)),
+    ('筡', (62, 16)),
+    ('筣', (62, 19)),
+    ('筤', (62, 7)),
+    ('筥', (62, 12)),
+    ('筦', (62, 6)),
+    ('筧', (23, 35)),
+    ('筩', (62, 10)),
+    ('筭', (62, 8)),
+    ('筮', (23, 34)),
+    ('筰', (62, 15)),
+    ('筱', (62, 14)),
+    ('筲', (62, 11)),
+    ('筳', (62, 13)),
+    ('筴', (62, 9)),
+    ('筵', (25, 127)),
+    ('筶', (62, 18)),
+    ('筷', (23, 31)),
+    ('筸', (62, 17)),
+    ('箂', (66, 29)),
+    ('箄', (25, 134)),
+    ('箅', (66, 24)),
+    ('箇', (25, 133)),
+    ('箈', (66, 14)),
+    ('箊', (66, 16)),
+    ('箋', (25, 126)),
+    ('箌', (66, 21)),
+    ('箍', (66, 20)),
+    ('箎', (66, 23)),
+    ('箏', (25, 131)),
+    ('箐', (66, 18)),
+    ('箑', (66, 17)),
+    ('箔', (25, 130)),
+    ('箕', (25, 125)),
+    ('箖', (66, 19)),
+    ('算', (25, 128)),
+    ('箘', (66, 25)),
+    ('箙', (66, 27)),
+    ('箛', (66, 22)),
+    ('箜', (66, 15)),
+    ('箝', (25, 129)),
+    ('箠', (28, 41)),
+    ('管', (25, 124)),
+    ('箤', (66, 28)),
+    ('箬', (69, 119)),
+    ('箭', (28, 34)),
+    ('箯', (69, 121)),
+    ('箱', (28, 35)),
+    ('箴', (28, 37)),
+    ('箵', (69, 124)),
+    ('箷', (69, 116)),
+    ('箸', (25, 132)),
+    ('箹', (69, 122)),
+    ('箾', (69, 118)),
+    ('節', (23, 32)),
+    ('篁', (28, 40)),
+    ('範', (28, 36)),
+    ('篆', (28, 38)),
+    ('篇', (28, 39)),
+    ('築', (30, 54)),
+    ('篊', (69, 123)),
+    ('篋', (69, 117)),
+    ('篌', (28, 42)),
+    ('篎', (69, 120)),
+    ('篔', (73, 94)),
+    ('篕', (73, 89)),
+    ('篘', (73, 99)),
+    ('篙', (30, 52)),
+    ('篚', (73, 91)),
+    ('篛', (30, 56)),
+    ('篜', (73, 97)),
+    ('篝', (73, 88)),
+    ('篞', (73, 85)),
+    ('篟', (73, 100)),
+    ('篠', (32, 17)),
+    ('篡', (30, 57)),
+    ('篢', (73, 96)),
+    ('篣', (73, 86)),
+    ('篤', (30, 55)),
+    ('篥', (73, 90)),
+    ('篦', (30, 59)),
+    ('篧', (73, 87)),
+    ('篨', (73, 92)),
+    ('篩', (30, 58)),
+    ('篪', (73, 95)),
+    ('篫', (73, 98)),
+    ('篰', (76, 105)),
+    ('篱', (76, 106)),
+    ('篲', (76, 90)),
+    ('篳', (76, 97)),
+    ('篴', (76, 95)),
+    ('篷', (32, 15)),
+    ('篸', (76, 102)),
+    ('篹', (73, 93)),
+    ('篻', (76, 93)),
+    ('篽', (76, 103)),
+    ('篾', (32, 14)),
+    ('篿', (76, 92)),
+    ('簀', (76, 91)),
+    ('簁', (76, 101)),
+    ('簂', (76, 98)),
+    ('簃', (76, 100)),
+    ('簅', (76, 88)),
+    ('簆', (76, 104)),
+    ('簇', (32, 12)),
+    ('簉', (76, 99)),
+    ('簊', (76, 108)),
+    ('簋', (76, 96)),
+    ('簌', (32, 16)),
+    ('簍', (32, 13)),
+    ('簎', (76, 94)),
+    ('簏', (76, 89)),
+    ('簐', (76, 107)),
+    ('簑', (30, 53)),
+    ('簙', (79, 35)),
+    ('簜', (79, 33)),
+    ('簝', (79, 39)),
+    ('簞', (33, 78)),
+    ('簟', (79, 37)),
+    ('簠', (79, 36)),
+    ('簡', (33, 80)),
+    ('簢', (79, 42)),
+    ('簣', (33, 79)),
+    ('簥', (79, 43)),
+    ('簦', (79, 40)),
+    ('簧', (33, 76)),
+    ('簨', (79, 41)),
+    ('簩', (79, 34)),
+    ('簪', (33, 77)),
+    ('簫', (33, 75)),
+    ('簬', (81, 73)),
+    ('簭', (79, 38)),
+    ('簰', (79, 44)),
+    ('簳', (81, 70)),
+    ('簷', (34, 80)),
+    ('簸', (34, 78)),
+    ('簹', (81, 72)),
+    ('簻', (81, 74)),
+    ('簼', (81, 71)),
+    ('簽', (34, 79)),
+    ('簾', (34, 76)),
+    ('簿', (34, 77)),
+    ('籀', (34, 81)),
+    ('籃', (35, 56)),
+    ('籅', (83, 72)),
+    ('籇', (83, 71)),
+    ('籈', (83, 69)),
+    ('籉', (83, 68)),
+    ('籊', (83, 70)),
+    ('籌', (35, 55)),
+    ('籍', (35, 57)),
+    ('籐', (35, 142)),
+    ('籓', (84, 131)),
+    ('籔', (84, 130)),
+    ('籗', (85, 152)),
+    ('籙', (85, 154)),
+    ('籚', (85, 156)),
+    ('籛', (85, 155)),
+    ('籜', (85, 153)),
+    ('籟', (36, 65)),
+    ('籠', (36, 64)),
+    ('籣', (36, 113)),
+    ('籤', (36, 112)),
+    ('籥', (36, 114)),
+    ('籦', (86, 139)),
+    ('籧', (86, 138)),
+    ('籩', (87, 153)),
+    ('籪', (87, 86)),
+    ('籫', (87, 154)),
+    ('籬', (37, 24)),
+    ('籮', (37, 25)),
+    ('籯', (88, 45)),
+    ('籲', (37, 62)),
+    ('米', (5, 106)),
+    ('籵', (44, 70)),
+    ('籸', (47, 5)),
+    ('籹', (47, 6)),
+    ('籺', (47, 4)),
+    ('籽', (11, 145)),
+    ('籿', (47, 7)),
+    ('粀', (47, 8)),
+    ('粁', (47, 9)),
+    ('粄', (50, 9)),
+    ('粅', (50, 15)),
+    ('粈', (50, 13)),
+    ('粉', (14, 89)),
+    ('粊', (50, 11)),
+    ('粌', (50, 12)),
+    ('粍', (50, 14)),
+    ('粑', (50, 10)),
+    ('粒', (17, 103)),
+    ('粔', (53, 124)),
+    ('粕', (17, 105)),
+    ('粖', (53, 126)),
+    ('粗', (17, 104)),
+    ('粘', (53, 125)),
+    ('粞', (57, 134)),
+    ('粟', (20, 77)),
+    ('粡', (57, 136)),
+    ('粢', (57, 133)),
+    ('粣', (53, 127)),
+    ('粥', (20, 78)),
+    ('粨', (57, 135)),
+    ('粯', (62, 22)),
+    ('粱', (23, 36)),
+    ('粲', (62, 20)),
+    ('粳', (23, 37)),
+    ('粴', (62, 21)),
+    ('粵', (23, 38)),
+    ('粹', (25, 135)),
+    ('粺', (66, 33)),
+    ('粻', (66, 30)),
+    ('粼', (66, 32)),
+    ('粽', (25, 136)),
+    ('精', (25, 137)),
+    ('粿', (66, 31)),
+    ('糅', (69, 125)),
+    ('糈', (69, 126)),
+    ('糊', (28, 43)),
+    ('糋', (69, 128)),
+    ('糌', (69, 127)),
+    ('糎', (1, 33)),
+    ('糐', (73, 104)),
+    ('糑', (73, 105)),
+    ('糒', (73, 101)),
+    ('糔', (73, 102)),
+    ('糕', (30, 60)),
+    ('糖', (30, 61)),
+    ('糗', (73, 103)),
+    ('糙', (32, 23)),
+    ('糜', (32, 19)),
+    ('糝', (32, 24)),
+    ('糞', (32, 20)),
+    ('糟', (32, 22)),
+    ('糠', (32, 18)),
+    ('糢', (32, 21)),
+    ('糧', (33, 81)),
+    ('糨', (76, 109)),
+    ('糪', (81, 76)),
+    ('糬', (81, 75)),
+    ('糮', (83, 73)),
+    ('糯', (35, 58)),
+    ('糰', (35, 59)),
+    ('糱', (86, 1)),
+    ('糲', (84, 132)),
+    ('糴', (86, 0)),
+    ('糶', (87, 155)),
+    ('糷', (88, 66)),
+    ('糸', (5, 107)),
+    ('系', (7, 52)),
+    ('糽', (44, 71)),
+    ('糾', (9, 102)),
+    ('紀', (11, 148)),
+    ('紁', (47, 12)),
+    ('紂', (11, 146)),
+    ('紃', (47, 10)),
+    ('約', (11, 151)),
+    ('紅', (11, 147)),
+    ('紆', (11, 152)),
+    ('紇', (11, 150)),
+    ('紈', (47, 11)),
+    ('紉', (11, 149)),
+    ('紊', (14, 93)),
+    ('紋', (14, 92)),
+    ('紌', (50, 26)),
+    ('納', (14, 101)),
+    ('紎', (50, 19)),
+    ('紏', (50, 25)),
+    ('紐', (14, 97)),
+    ('紑', (50, 18)),
+    ('紒', (50, 24)),
+    ('紓', (50, 22)),
+    ('純', (14, 96)),
+    ('紕', (14, 98)),
+    ('紖', (50, 21)),
+    ('紗', (14, 91)),
+    ('紘', (50, 20)),
+    ('紙', (14, 102)),
+    ('級', (14, 99)),
+    ('紛', (14, 103)),
+    ('紜', (14, 100)),
+    ('紝', (50, 17)),
+    ('紞', (50, 16)),
+    ('紟', (50, 23)),
+    ('素', (14, 94)),
+    ('紡', (14, 90)),
+    ('索', (14, 95)),
+    ('紨', (53, 142)),
+    ('紩', (53, 135)),
+    ('紫', (20, 83)),
+    ('紬', (53, 134)),
+    ('紮', (17, 109)),
+    ('累', (17, 116)),
+    ('細', (17, 113)),
+    ('紱', (17, 119)),
+    ('紲', (17, 118)),
+    ('紳', (17, 114)),
+    ('紵', (53, 128)),
+    ('紶', (53, 131)),
+    ('紸', (53, 130)),
+    ('紹', (17, 110)),
+    ('紺', (53, 132)),
+    ('紻', (53, 141)),
+    ('紼', (17, 111)),
+    ('紽', (53, 129)),
+    ('紾', (53, 138)),
+    ('紿', (53, 139)),
+    ('絀', (17, 112)),
+    ('絁', (53, 136)),
+    ('終', (17, 117)),
+    ('絃', (17, 107)),
+    ('組', (17, 115)),
+    ('絅', (53, 133)),
+    ('絆', (17, 106)),
+    ('絇', (53, 137)),
+    ('絊', (53, 140)),
+    ('絎', (57, 153)),
+    ('絏', (57, 144)),
+    ('結', (20, 80)),
+    ('絑', (57, 151)),
+    ('絒', (57, 148)),
+    ('絓', (57, 140)),
+    ('絔', (57, 149)),
+    ('絕', (20, 82)),
+    ('絖', (57, 141)),
+    ('絘', (57, 137)),
+    ('絛', (23, 44)),
+    ('絜', (57, 146)),
+    ('絞', (20, 79)),
+    ('絟', (57, 152)),
+    ('絡', (20, 86)),
+    ('絢', (20, 88)),
+    ('絣', (57, 139)),
+    ('給', (20, 87)),
+    ('絧', (57, 142)),
+    ('絨', (20, 81)),
+    ('絩', (57, 150)),
+    ('絪', (57, 143)),
+    ('絫', (57, 147)),
+    ('絭', (57, 145)),
+    ('絮', (20, 84)),
+    ('絯', (57, 138)),
+    ('絰', (20, 89)),
+    ('統', (17, 108)),
+    ('絲', (20, 85)),
+    ('絳', (20, 90)),
+    ('絹', (23, 40)),
+    ('絺', (62, 29)),
+    ('絻', (62, 31)),
+    ('絼', (62, 33)),
+    ('絽', (62, 37)),
+    ('絿', (62, 27)),
+    ('綀', (62, 25)),
+    ('綁', (23, 42)),
+    ('綃', (62, 32)),
+    ('綄', (62, 36)),
+    ('綅', (62, 28)),
+    ('綆', (62, 24)),
+    ('綈', (62, 23)),
+    ('綌', (62, 34)),
+    ('綍', (62, 26)),
+    ('綎', (62, 30)),
+    ('綏', (23, 43)),
+    ('綑', (23, 41)),
+    ('綒', (62, 38)),
+    ('經', (23, 39)),
+    ('綔', (62, 35)),
+    ('綖', (66, 50)),
+    ('綜', (25, 140)),
+    ('綝', (66, 42)),
+    ('綞', (28, 56)),
+    ('綟', (66, 52)),
+    ('綠', (25, 143)),
+    ('綡', (66, 56)),
+    ('綢', (25, 149)),
+    ('綣', (66, 37)),
+    ('綦', (66, 53)),
+    ('綧', (66, 34)),
+    ('綩', (66, 55)),
+    ('綪', (66, 38)),
+    ('綬', (25, 156)),
+    ('維', (25, 153)),
+    ('綮', (66, 54)),
+    ('綯', (66, 48)),
+    ('綰', (25, 139)),
+    ('綱', (25, 147)),
+    ('網', (25, 146)),
+    ('綴', (25, 145)),
+    ('綵', (25, 151)),
+    ('綷', (66, 35)),
+    ('綸', (25, 152)),
+    ('綹', (66, 49)),
+    ('綺', (25, 148)),
+    ('綻', (25, 138)),
+    ('綼', (66, 51)),
+    ('綽', (25, 141)),
+    ('綾', (25, 142)),
+    ('綿', (25, 150)),
+    ('緀', (66, 40)),
+    ('緁', (66, 39)),
+    ('緂', (66, 36)),
+    ('緄', (66, 44)),
+    ('緅', (66, 41)),
+    ('緆', (66, 45)),
+    ('緇', (25, 155)),
+    ('緉', (66, 57)),
+    ('緊', (25, 144)),
+    ('緋', (66, 46)),
+    ('緌', (66, 47)),
+    ('緎', (66, 43)),
+    ('緒', (25, 154)),
+    ('緗', (69, 133)),
+    ('緘', (28, 48)),
+    ('緙', (28, 57)),
+    ('線', (28, 53)),
+    ('緛', (69, 130)),
+    ('緝', (28, 50)),
+    ('緞', (28, 54)),
+    ('緟', (69, 142)),
+    ('締', (28, 44)),
+    ('緡', (69, 134)),
+    ('緣', (28, 52)),
+    ('緦', (69, 137)),
+    ('緧', (69, 132)),
+    ('編', (28, 51)),
+    ('緩', (28, 55)),
+    ('緪', (69, 131)),
+    ('緬', (28, 49)),
+    ('緮', (69, 141)),
+    ('緯', (28, 46)),
+    ('緰', (69, 140)),
+    ('緱', (69, 139)),
+    ('緲', (28, 58)),
+    ('緳', (68, 50)),
+    ('練', (28, 45)),
+    ('緶', (69, 138)),
+    ('緷', (69, 129)),
+    ('緹', (28, 59)),
+    ('緺', (69, 136)),
+    ('緻', (28, 47)),
+    ('縃', (69, 135)),
+    ('縈', (30, 64)),
+    ('縉', (30, 69)),
+    ('縊', (30, 62)),
+    ('縋', (73, 118)),
+    ('縌', (73, 109)),
+    ('縍', (73, 121)),
+    ('縎', (73, 113)),
+    ('縏', (73, 119)),
+    ('縐', (30, 70)),
+    ('縑', (30, 63)),
+    ('縒', (73, 106)),
+    ('縓', (73, 112)),
+    ('縔', (73, 122)),
+    ('縕', (73, 115)),
+    ('縖', (73, 120)),
+    ('縗', (73, 108)),
+    ('縚', (73, 116)),
+    ('縛', (30, 65)),
+    ('縜', (73, 114)),
+    ('縝', (30, 68)),
+    ('縞', (30, 67)),
+    ('縟', (73, 110)),
+    ('縠', (73, 111)),
+    ('縡', (73, 107)),
+    ('縢', (73, 117)),
+    ('縣', (30, 66)),
+    ('縤', (73, 124)),
+    ('縥', (73, 123)),
+    ('縩', (76, 120)),
+    ('縪', (76, 116)),
+    ('縫', (32, 31)),
+    ('縭', (76, 110)),
+    ('縮', (32, 25)),
+    ('縯', (32, 41)),
+    ('縰', (76, 122)),
+    ('縱', (32, 33)),
+    ('縲', (32, 29)),
+    ('縳', (76, 113)),
+    ('縴', (32, 36)),
+    ('縵', (32, 39)),
+    ('縶', (76, 124)),
+    ('縷', (32, 28)),
+    ('縸', (76, 115)),
+    ('縹', (32, 37)),
+    ('縺', (76, 126)),
+    ('縻', (76, 123)),
+    ('縼', (76, 111)),
+    ('總', (32, 32)),
+    ('績', (32, 26)),
+    ('縿', (32, 40)),
+    ('繀', (76, 118)),
+    ('繁', (32, 35)),
+    ('繂', (76, 112)),
+    ('繃', (32, 30)),
+    ('繄', (76, 125)),
+    ('繅', (32, 34)),
+    ('繆', (32, 27)),
+    ('繇', (76, 119)),
+    ('繈', (32, 38)),
+    ('繉', (76, 117)),
+    ('繌', (76, 121)),
+    ('繐', (79, 46)),
+    ('繑', (79, 52)),
+    ('繒', (33, 87)),
+    ('繓', (79, 55)),
+    ('織', (33, 82)),
+    ('繕', (33, 83)),
+    ('繖', (79, 47)),
+    ('繗', (79, 54)),
+    ('繘', (79, 49)),
+    ('繙', (33, 88)),
+    ('繚', (33, 85)),
+    ('繜', (79, 45)),
+    ('繞', (33, 84)),
+    ('繟', (79, 51)),
+    ('繠', (79, 53)),
+    ('繡', (33, 86)),
+    ('繢', (79, 50)),
+    ('繣', (79, 48)),
+    ('繨', (81, 86)),
+    ('繩', (34, 85)),
+    ('繪', (34, 86)),
+    ('繫', (34, 82)),
+    ('繭', (34, 83)),
+    ('繯', (81, 82)),
+    ('繰', (81, 80)),
+    ('繲', (81, 84)),
+    ('繳', (34, 88)),
+    ('繴', (81, 85)),
+    ('繵', (81, 78)),
+    ('繶', (81, 77)),
+    ('繷', (81, 81)),
+    ('繸', (81, 79)),
+    ('繹', (34, 84)),
+    ('繺', (81, 83)),
+    ('繻', (83, 74)),
+    ('繼', (35, 62)),
+    ('繽', (35, 61)),
+    ('繾', (83, 75)),
+    ('纀', (83, 77)),
+    ('纁', (83, 76)),
+    ('纂', (35, 63)),
+    ('纆', (84, 137)),
+    ('纇', (84, 134)),
+    ('纈', (84, 135)),
+    ('纊', (84, 133)),
+    ('纋', (84, 136)),
+    ('續', (35, 144)),
+    ('纍', (84, 138)),
+    ('纏', (35, 143)),
+    ('纑', (86, 2)),
+    ('纓', (36, 115)),
+    ('纔', (36, 117)),
+    ('纕', (86, 140)),
+    ('纖', (36, 116)),
+    ('纗', (87, 87)),
+    ('纘', (88, 0)),
+    ('纙', (88, 2)),
+    ('纚', (87, 156)),
+    ('纛', (88, 1)),
+    ('纜', (37, 44)),
+    ('缶', (5, 108)),
+    ('缸', (11, 153)),
+    ('缹', (49, 47)),
+    ('缺', (14, 104)),
+    ('缽', (17, 120)),
+    ('缾', (57, 154)),
+    ('缿', (57, 155)),
+    ('罃', (73, 125)),
+    ('罄', (32, 42)),
+    ('罅', (76, 127)),
+    ('罈', (33, 89)),
+    ('罊', (81, 88)),
+    ('罋', (81, 87)),
+    ('罌', (35, 64)),
+    ('罍', (84, 139)),
+    ('罏', (86, 3)),
+    ('罐', (36, 150)),
+    ('网', (41, 9)),
+    ('罔', (9, 103)),
+    ('罕', (7, 53)),
+    ('罘', (47, 13)),
+    ('罛', (50, 32)),
+    ('罜', (50, 27)),
+    ('罝', (50, 31)),
+    ('罞', (50, 29)),
+    ('罟', (14, 105)),
+    ('罠', (50, 30)),
+    ('罡', (50, 28)),
+    ('罣', (53, 143)),
+    ('罥', (57, 156)),
+    ('罦', (58, 0)),
+    ('罧', (62, 41)),
+    ('罨', (62, 42)),
+    ('罩', (23, 46)),
+    ('罪', (23, 47)),
+    ('罫', (62, 40)),
+    ('罬', (62, 43)),
+    ('罭', (62, 39)),
+    ('置', (23, 45)),
+    ('罰', (26, 0)),
+    ('署', (23, 48)),
+    ('罳', (66, 58)),
+    ('罵', (28, 60)),
+    ('罶', (69, 143)),
+    ('罷', (28, 61)),
+    ('罹', (30, 71)),
+    ('罺', (73, 128)),
+    ('罻', (73, 126)),
+    ('罼', (73, 127)),
+    ('罽', (76, 130)),
+    ('罾', (76, 129)),
+    ('罿', (76, 128)),
+    ('羃', (81, 89)),
+    ('羅', (34, 87)),
+    ('羆', (81, 90)),
+    ('羇', (86, 4)),
+    ('羈', (36, 151)),
+    ('羉', (87, 88)),
+    ('羊', (5, 109)),
+    ('羋', (9, 105)),
+    ('羌', (9, 104)),
+    ('羍', (47, 15)),
+    ('美', (11, 154)),
+    ('羑', (47, 14)),
+    ('羒', (50, 34)),
+    ('羔', (14, 106)),
+    ('羕', (53, 144)),
+    ('羖', (50, 33)),
+    ('羚', (17, 122)),
+    ('羛', (53, 147)),
+    ('羜', (53, 145)),
+    ('羝', (53, 146)),
+    ('羞', (17, 121)),
+    ('羠', (58, 2)),
+    ('羡', (58, 3)),
+    ('羢', (58, 1)),
+    ('群', (23, 51)),
+    ('羥', (62, 45)),
+    ('羦', (62, 44)),
+    ('羧', (62, 46)),
+    ('羨', (23, 50)),
+    ('義', (23, 49)),
+    ('羬', (69, 144)),
+    ('羭', (69, 146)),
+    ('羯', (28, 62)),
+    ('羰', (69, 145)),
+    ('羱', (73, 129)),
+    ('羲', (30, 72)),
+    ('羳', (79, 57)),
+    ('羵', (79, 56)),
+    ('羶', (34, 89)),
+    ('羷', (81, 91)),
+    ('羸', (34, 91)),
+    ('羹', (34, 90)),
+    ('羺', (83, 78)),
+    ('羻', (84, 140)),
+    ('羼', (35, 145)),
+    ('羽', (5, 110)),
+    ('羾', (47, 16)),
+    ('羿', (11, 155)),
+    ('翀', (50, 37)),
+    ('翁', (14, 108)),
+    ('翂', (50, 36)),
+    ('翃', (50, 35)),
+    ('翅', (14, 107)),
+    ('翇', (53, 153)),
+    ('翉', (53, 155)),
+    ('翊', (53, 148)),
+    ('翋', (53, 149)),
+    ('翌', (17, 123)),
+    ('翍', (53, 150)),
+    ('翎', (17, 124)),
+    ('翏', (53, 154)),
+    ('翐', (53, 151)),
+    ('翑', (53, 152)),
+    ('習', (17, 125)),
+    ('翔', (20, 92)),
+    ('翕', (20, 93)),
+    ('翗', (58, 4)),
+    ('翛', (62, 47)),
+    ('翜', (62, 48)),
+    ('翞', (66, 62)),
+    ('翟', (26, 3)),
+    ('翠', (26, 1)),
+    ('翡', (26, 2)),
+    ('翢', (66, 59)),
+    ('翣', (66, 60)),
+    ('翥', (66, 61)),
+    ('翦', (69, 151)),
+    ('翨', (69, 152)),
+    ('翩', (28, 63)),
+    ('翪', (69, 149)),
+    ('翫', (69, 148)),
+    ('翬', (69, 150)),
+    ('翭', (69, 147)),
+    ('翮', (30, 75)),
+    ('翯', (73, 130)),
+    ('翰', (30, 73)),
+    ('翱', (30, 74)),
+    ('翲', (76, 132)),
+    ('翳', (32, 43)),
+    ('翴', (76, 131)),
+    ('翵', (78, 70)),
+    ('翷', (79, 58)),
+    ('翸', (79, 59)),
+    ('翹', (33, 90)),
+    ('翻', (33, 91)),
+    ('翼', (32, 44)),
+    ('翽', (81, 92)),
+    ('翾', (81, 93)),
+    ('翿', (83, 79)),
+    ('耀', (35, 65)),
+    ('老', (5, 111)),
+    ('考', (5, 112)),
+    ('耄', (11, 156)),
+    ('者', (9, 106)),
+    ('耆', (14, 109)),
+    ('耇', (47, 17)),
+    ('耋', (20, 94)),
+    ('而', (5, 113)),
+    ('耍', (12, 1)),
+    ('耎', (47, 18)),
+    ('耏', (47, 19)),
+    ('耐', (12, 0)),
+    ('耑', (12, 2)),
+    ('耒', (5, 114)),
+    ('耔', (47, 20)),
+    ('耕', (14, 111)),
+    ('耖', (50, 38)),
+    ('耗', (14, 113)),
+    ('耘', (14, 110)),
+    ('耙', (14, 112)),
+    ('耛', (54, 1)),
+    ('耜', (17, 126)),
+    ('耞', (54, 0)),
+    ('耟', (53, 156)),
+    ('耡', (62, 49)),
+    ('耤', (66, 63)),
+    ('耦', (28, 64)),
+    ('耨', (30, 76)),
+    ('耩', (73, 132)),
+    ('耪', (73, 131)),
+    ('耬', (76, 133)),
+    ('耰', (84, 141)),
+    ('耳', (5, 115)),
+    ('耴', (42, 51)),
+    ('耵', (44, 72)),
+    ('耶', (12, 3)),
+    ('耷', (47, 21)),
+    ('耹', (50, 40)),
+    ('耽', (14, 114)),
+    ('耾', (50, 39)),
+    ('耿', (14, 115)),
+    ('聃', (54, 3)),
+    ('聆', (17, 128)),
+    ('聇', (54, 2)),
+    ('聈', (54, 4)),
+    ('聊', (17, 127)),
+    ('聏', (58, 6)),
+    ('聐', (58, 7)),
+    ('聑', (58, 5)),
+    ('聒', (20, 95)),
+    ('聖', (23, 52)),
+    ('聘', (23, 53)),
+    ('聚', (26, 5)),
+    ('聜', (66, 65)),
+    ('聝', (66, 64)),
+    ('聞', (26, 4)),
+    ('聤', (69, 153)),
+    ('聧', (69, 154)),
+    ('聬', (73, 133)),
+    ('聯', (32, 48)),
+    ('聰', (32, 47)),
+    ('聱', (32, 45)),
+    ('聲', (32, 46)),
+    ('聳', (32, 49)),
+    ('聵', (79, 60)),
+    ('聶', (33, 93)),
+    ('職', (33, 92)),
+    ('聸', (81, 94)),
+    ('聹', (83, 80)),
+    ('聽', (36, 67)),
+    ('聾', (36, 66)),
+    ('聿', (5, 116)),
+    ('肂', (48, 153)),
+    ('肄', (23, 55)),
+    ('肅', (20, 96)),
+    ('肆', (23, 54)),
+    ('肇', (26, 6)),
+    ('肉', (5, 117)),
+    ('肊', (40, 71)),
+    ('肋', (5, 118)),
+    ('肌', (5, 119)),
+    ('肏', (44, 73)),
+    ('肐', (42, 54)),
+    ('肒', (42, 55)),
+    ('肓', (7, 55)),
+    ('肕', (42, 52)),
+    ('肖', (7, 54)),
+    ('肘', (7, 57)),
+    ('肙', (42, 53)),
+    ('肚', (7, 59)),
+    ('肛', (7, 58)),
+    ('肜', (42, 56)),
+    ('肝', (7, 56)),
+    ('股', (9, 111)),
+    ('肢', (9, 109)),
+    ('肣', (44, 75)),
+    ('肥', (9, 108)),
+    ('肩', (9, 113)),
+    ('肪', (9, 115)),
+    ('肫', (9, 112)),
+    ('肭', (44, 78)),
+    ('肮', (44, 74)),
+    ('肯', (9, 116)),
+    ('肱', (9, 110)),
+    ('育', (7, 60)),
+    ('肴', (9, 114)),
+    ('肵', (44, 77)),
+    ('肸', (44, 76)),
+    ('肺', (9, 107)),
+    ('胂', (47, 27)),
+    ('胃', (12, 7)),
+    ('胄', (12, 8)),
+    ('胅', (47, 29)),
+    ('胇', (47, 23)),
+    ('胈', (47, 26)),
+    ('胉', (47, 35)),
+    ('胊', (47, 33)),
+    ('背', (12, 9)),
+    ('胍', (47, 39)),
+    ('胎', (12, 12)),
+    ('胏', (47, 36)),
+    ('胐', (47, 28)),
+    ('胑', (47, 25)),
+    ('胔', (58, 9)),
+    ('胕', (47, 34)),
+    ('胖', (12, 4)),
+    ('胗', (47, 37)),
+    ('胘', (47, 22)),
+    ('胙', (47, 31)),
+    ('胚', (12, 6)),
+    ('胛', (12, 11)),
+    ('胜', (47, 32)),
+    ('胝', (12, 15)),
+    ('胞', (12, 13)),
+    ('胠', (47, 24)),
+    ('胡', (12, 10)),
+    ('胣', (47, 30)),
+    ('胤', (12, 14)),
+    ('胥', (12, 5)),
+    ('胦', (47, 38)),
+    ('胭', (14, 120)),
+    ('胯', (14, 129)),
+    ('胰', (14, 118)),
+    ('胱', (14, 116)),
+    ('胲', (50, 42)),
+    ('胳', (14, 124)),
+    ('胴', (14, 121)),
+    ('胵', (50, 44)),
+    ('胸', (14, 123)),
+    ('胹', (50, 43)),
+    ('胺', (50, 41)),
+    ('胻', (50, 46)),
+    ('胼', (14, 128)),
+    ('能', (14, 126)),
+    ('胾', (58, 8)),
+    ('脀', (50, 47)),
+    ('脁', (50, 45)),
+    ('脂', (14, 117)),
+    ('脅', (14, 119)),
+    ('脆', (14, 122)),
+    ('脈', (14, 125)),
+    ('脊', (14, 127)),
+    ('脕', (54, 14)),
+    ('脖', (17, 130)),
+    ('脘', (54, 5)),
+    ('脙', (54, 7)),
+    ('脛', (54, 8)),
+    ('脝', (54, 16)),
+    ('脞', (54, 12)),
+    ('脟', (54, 10)),
+    ('脡', (54, 13)),
+    ('脢', (54, 17)),
+    ('脣', (17, 131)),
+    ('脤', (17, 135)),
+    ('脥', (54, 6)),
+    ('脧', (54, 15)),
+    ('脩', (17, 133)),
+    ('脫', (17, 132)),
+    ('脬', (54, 11)),
+    ('脭', (54, 9)),
+    ('脯', (17, 129)),
+    ('脰', (17, 134)),
+    ('脹', (20, 102)),
+    ('脺', (58, 17)),
+    ('脽', (58, 15)),
+    ('脾', (20, 104)),
+    ('腃', (58, 10)),
+    ('腄', (62, 63)),
+    ('腆', (20, 103)),
+    ('腇', (58, 14)),
+    ('腊', (58, 11)),
+    ('腋', (20, 99)),
+    ('腌', (20, 105)),
+    ('腍', (58, 16)),
+    ('腎', (20, 101)),
+    ('腏', (58, 13)),
+    ('腐', (26, 7)),
+    ('腑', (20, 100)),
+    ('腒', (58, 12)),
+    ('腓', (20, 106)),
+    ('腔', (20, 98)),
+    ('腕', (20, 97)),
+    ('腛', (62, 55)),
+    ('腜', (62, 53)),
+    ('腞', (62, 59)),
+    ('腠', (62, 51)),
+    ('腡', (62, 64)),
+    ('腢', (62, 56)),
+    ('腤', (62, 50)),
+    ('腥', (23, 59)),
+    ('腦', (23, 65)),
+    ('腧', (62, 61)),
+    ('腩', (62, 54)),
+    ('腫', (23, 62)),
+    ('腮', (23, 60)),
+    ('腯', (62, 62)),
+    ('腰', (23, 57)),
+    ('腱', (23, 56)),
+    ('腲', (62, 57)),
+    ('腳', (23, 61)),
+    ('腴', (20, 107)),
+    ('腶', (62, 60)),
+    ('腷', (62, 52)),
+    ('腸', (23, 58)),
+    ('腹', (23, 63)),
+    ('腺', (23, 64)),
+    ('腿', (26, 12)),
+    ('膀', (26, 8)),
+    ('膂', (26, 13)),
+    ('膃', (66, 68)),
+    ('膆', (66, 67)),
+    ('膇', (66, 69)),
+    ('膈', (26, 10)),
+    ('膉', (66, 66)),
+    ('膊', (26, 11)),
+    ('膋', (66, 72)),
+    ('膌', (66, 71)),
+    ('膍', (66, 70)),
+    ('膏', (26, 9)),
+    ('膕', (70, 1)),
+    ('膗', (70, 4)),
+    ('膘', (28, 70)),
+    ('膙', (70, 3)),
+    ('膚', (28, 69)),
+    ('膛', (28, 65)),
+    ('膜', (28, 66)),
+    ('膝', (28, 67)),
+    ('膞', (70, 0)),
+    ('膟', (69, 156)),
+    ('膠', (28, 68)),
+    ('膢', (70, 2)),
+    ('膣', (69, 155)),
+    ('膦', (73, 135)),
+    ('膧', (73, 145)),
+    ('膨', (30, 79)),
+    ('膩', (30, 78)),
+    ('膫', (73, 139)),
+    ('膬', (73, 141)),
+    ('膮', (73, 136)),
+    ('膰', (73, 140)),
+    ('膱', (73, 134)),
+    ('膲', (73, 143)),
+    ('膳', (30, 77)),
+    ('膴', (73, 142)),
+    ('膵', (73, 138)),
+    ('膷', (73, 144)),
+    ('膹', (73, 137)),
+    ('膺', (32, 52)),
+    ('膻', (76, 134)),
+    ('膼', (76, 140)),
+    ('膽', (32, 56)),
+    ('膾', (32, 58)),
+    ('膿', (32, 55)),
+    ('臀', (32, 54)),
+    ('臂', (32, 53)),
+    ('臃', (32, 51)),
+    ('臄', (76, 135)),
+    ('臅', (76, 138)),
+    ('臆', (32, 50)),
+    ('臇', (76, 139)),
+    ('臉', (32, 57)),
+    ('臊', (76, 137)),
+    ('臌', (76, 136)),
+    ('臍', (33, 94)),
+    ('臏', (33, 95)),
+    ('臐', (79, 63)),
+    ('臑', (79, 61)),
+    ('臒', (79, 62)),
+    ('臕', (81, 96)),
+    ('臗', (81, 95)),
+    ('臘', (34, 92)),
+    ('臙', (83, 82)),
+    ('臚', (35, 66)),
+    ('臛', (83, 81)),
+    ('臝', (84, 142)),
+    ('臞', (86, 5)),
+    ('臟', (36, 68)),
+    ('臠', (88, 3)),
+    ('臡', (88, 4)),
+    ('臢', (36, 118)),
+    ('臣', (5, 120)),
+    ('臥', (9, 117)),
+    ('臦', (58, 18)),
+    ('臧', (26, 14)),
+    ('臨', (32, 59)),
+    ('臩', (76, 141)),
+    ('自', (5, 121)),
+    ('臬', (14, 131)),
+    ('臭', (14, 130)),
+    ('臮', (58, 19)),
+    ('臲', (73, 146)),
+    ('至', (5, 122)),
+    ('致', (12, 16)),
+    ('臷', (58, 20)),
+    ('臸', (58, 21)),
+    ('臹', (58, 22)),
+    ('臺', (26, 15)),
+    ('臻', (30, 80)),
+    ('臼', (5, 123)),
+    ('臾', (9, 118)),
+    ('臿', (47, 40)),
+    ('舀', (14, 132)),
+    ('舁', (50, 48)),
+    ('舂', (17, 136)),
+    ('舄', (58, 23)),
+    ('舅', (23, 66)),
+    ('與', (26, 16)),
+    ('興', (30, 81)),
+    ('舉', (32, 60)),
+    ('舊', (33, 96)),
+    ('舋', (83, 83)),
+    ('舌', (5, 124)),
+    ('舍', (9, 119)),
+    ('舐', (14, 133)),
+    ('舑', (54, 18)),
+    ('舒', (20, 108)),
+    ('舔', (26, 17)),
+    ('舕', (66, 73)),
+    ('舖', (70, 5)),
+    ('舛', (5, 125)),
+    ('舜', (20, 109)),
+    ('舝', (62, 65)),
+    ('舞', (26, 18)),
+    ('舟', (5, 126)),
+    ('舠', (44, 79)),
+    ('舡', (47, 41)),
+    ('舢', (12, 17)),
+    ('舥', (50, 50)),
+    ('舨', (14, 136)),
+    ('航', (14, 134)),
+    ('舫', (14, 135)),
+    ('般', (14, 137)),
+    ('舯', (50, 49)),
+    ('舲', (54, 23)),
+    ('舳', (54, 20)),
+    ('舴', (54, 22)),
+    ('舵', (17, 137)),
+    ('舶', (17, 139)),
+    ('舷', (17, 138)),
+    ('舸', (54, 19)),
+    ('船', (17, 140)),
+    ('舺', (54, 21)),
+    ('舼', (58, 24)),
+    ('舽', (58, 25)),
+    ('舿', (58, 26)),
+    ('艀', (62, 68)),
+    ('艂', (62, 69)),
+    ('艄', (62, 67)),
+    ('艅', (62, 70)),
+    ('艇', (23, 67)),
+    ('艉', (62, 66)),
+    ('艋', (26, 19)),
+    ('艎', (70, 10)),
+    ('艏', (70, 6)),
+    ('艐', (70, 9)),
+    ('艑', (70, 11)),
+    ('艒', (70, 8)),
+    ('艓', (70, 7)),
+    ('艕', (73, 147)),
+    ('艖', (73, 148)),
+    ('艗', (73, 149)),
+    ('艘', (30, 82)),
+    ('艙', (30, 83)),
+    ('艚', (76, 143)),
+    ('艛', (76, 142)),
+    ('艜', (76, 144)),
+    ('艞', (79, 65)),
+    ('艟', (79, 64)),
+    ('艡', (81, 98)),
+    ('艣', (81, 99)),
+    ('艤', (81, 97)),
+    ('艦', (35, 67)),
+    ('艨', (83, 84)),
+    ('艩', (83, 85)),
+    ('艫', (86, 6)),
+    ('艬', (86, 141)),
+    ('艭', (87, 89)),
+    ('艮', (5, 127)),
+    ('良', (7, 61)),
+    ('艱', (32, 61)),
+    ('色', (5, 128)),
+    ('艴', (54, 24)),
+    ('艵', (58, 27)),
+    ('艷', (37, 1)),
+    ('艸', (41, 10)),
+    ('艼', (41, 11)),
+    ('艽', (41, 13)),
+    ('艾', (5, 129)),
+    ('艿', (41, 14)),
+    ('芀', (41, 12)),
+    ('芃', (42, 64)),
+    ('芄', (42, 65)),
+    ('芅', (42, 59)),
+    ('芊', (42, 63)),
+    ('芋', (7, 63)),
+    ('芍', (7, 64)),
+    ('芎', (42, 60)),
+    ('芏', (42, 58)),
+    ('芐', (42, 57)),
+    ('芑', (42, 61)),
+    ('芒', (7, 62)),
+    ('芓', (42, 62)),
+    ('芔', (47, 42)),
+    ('芘', (44, 84)),
+    ('芙', (9, 122)),
+    ('芚', (44, 83)),
+    ('芛', (44, 85)),
+    ('芝', (9, 121)),
+    ('芞', (44, 90)),
+    ('芟', (9, 125)),
+    ('芠', (44, 80)),
+    ('芡', (44, 94)),
+    ('芢', (44, 100)),
+    ('芣', (9, 132)),
+    ('芤', (44, 97)),
+    ('芥', (9, 129)),
+    ('芧', (44, 87)),
+    ('芨', (44, 93)),
+    ('芩', (44, 95)),
+    ('芫', (44, 82)),
+    ('芬', (9, 128)),
+    ('芭', (9, 123)),
+    ('芮', (44, 88)),
+    ('芯', (9, 130)),
+    ('芰', (9, 133)),
+    ('花', (9, 127)),
+    ('芳', (9, 120)),
+    ('芴', (44, 92)),
+    ('芵', (44, 86)),
+    ('芶', (44, 99)),
+    ('芷', (9, 135)),
+    ('芸', (9, 131)),
+    ('芹', (9, 126)),
+    ('芺', (44, 91)),
+    ('芻', (14, 138)),
+    ('芼', (44, 89)),
+    ('芽', (9, 124)),
+    ('芾', (9, 134)),
+    ('苀', (44, 81)),
+    ('苂', (44, 96)),
+    ('苃', (44, 98)),
+    ('苑', (12, 34)),
+    ('苒', (12, 28)),
+    ('苓', (12, 36)),
+    ('苔', (12, 33)),
+    ('苕', (47, 49)),
+    ('苖', (47, 52)),
+    ('苗', (12, 29)),
+    ('苙', (47, 43)),
+    ('苛', (12, 22)),
+    ('苜', (12, 32)),
+    ('苞', (12, 35)),
+    ('苟', (12, 37)),
+    ('苠', (47, 64)),
+    ('苡', (47, 55)),
+    ('苣', (12, 21)),
+    ('苤', (47, 63)),
+    ('若', (12, 25)),
+    ('苦', (12, 23)),
+    ('苧', (12, 18)),
+    ('苨', (47, 47)),
+    ('苪', (47, 62)),
+    ('苫', (47, 51)),
+    ('苬', (47, 54)),
+    ('苭', (47, 67)),
+    ('苯', (12, 38)),
+    ('苰', (47, 61)),
+    ('英', (12, 30)),
+    ('苲', (47, 56)),
+    ('苳', (47, 66)),
+    ('苴', (47, 53)),
+    ('苵', (47, 57)),
+    ('苶', (47, 60)),
+    ('苹', (47, 45)),
+    ('苺', (47, 65)),
+    ('苻', (47, 59)),
+    ('苾', (47, 44)),
+    ('茀', (47, 48)),
+    ('茁', (12, 31)),
+    ('茂', (12, 26)),
+    ('范', (12, 19)),
+    ('茄', (12, 24)),
+    ('茅', (12, 20)),
+    ('茆', (12, 39)),
+    ('茇', (47, 46)),
+    ('茈', (50, 67)),
+    ('茉', (12, 27)),
+    ('茌', (47, 58)),
+    ('茍', (45, 1)),
+    ('茖', (50, 70)),
+    ('茗', (14, 152)),
+    ('茙', (50, 54)),
+    ('茛', (50, 65)),
+    ('茜', (50, 61)),
+    ('茞', (50, 80)),
+    ('茠', (50, 72)),
+    ('茢', (50, 62)),
+    ('茤', (50, 71)),
+    ('茥', (50, 56)),
+    ('茦', (50, 60)),
+    ('茧', (50, 83)),
+    ('茨', (14, 155)),
+    ('茩', (50, 75)),
+    ('茪', (50, 66)),
+    ('茫', (14, 139)),
+    ('茬', (50, 81)),
+    ('茭', (50, 52)),
+    ('茯', (50, 74)),
+    ('茱', (14, 154)),
+    ('茲', (14, 149)),
+    ('茳', (50, 51)),
+    ('茴', (14, 147)),
+    ('茵', (14, 146)),
+    ('茶', (14, 151)),
+    ('茷', (50, 73)),
+    ('茸', (14, 143)),
+    ('茹', (14, 150)),
+    ('茺', (47, 50)),
+    ('茻', (58, 28)),
+    ('茼', (50, 68)),
+    ('茿', (50, 58)),
+    ('荀', (14, 153)),
+    ('荁', (50, 59)),
+    ('荂', (50, 63)),
+    ('荃', (14, 156)),
+    ('荄', (50, 53)),
+    ('荅', (50, 77)),
+    ('荇', (50, 76)),
+    ('荈', (50, 84)),
+    ('草', (14, 145)),
+    ('荊', (14, 142)),
+    ('荋', (50, 82)),
+    ('荌', (50, 78)),
+    ('荍', (50, 69)),
+    ('荎', (50, 64)),
+    ('荏', (14, 148)),
+    ('荐', (14, 144)),
+    ('荑', (50, 55)),
+    ('荒', (14, 140)),
+    ('荓', (50, 79)),
+    ('荔', (14, 141)),
+    ('荖', (50, 57)),
+    ('荳', (54, 30)),
+    ('荴', (54, 32)),
+    ('荵', (54, 37)),
+    ('荶', (54, 57)),
+    ('荷', (17, 154)),
+    ('荸', (17, 144)),
+    ('荺', (54, 29)),
+    ('荻', (17, 155)),
+    ('荼', (17, 156)),
+    ('荽', (54, 40)),
+    ('荾', (54, 47)),
+    ('荿', (54, 53)),
+    ('莁', (54, 34)),
+    ('莃', (54, 41)),
+    ('莆', (18, 0)),
+    ('莇', (54, 55)),
+    ('莈', (54, 50)),
+    ('莉', (17, 152)),
+    ('莊', (17, 150)),
+    ('莋', (54, 46)),
+    ('莌', (54, 42)),
+    ('莍', (54, 28)),
+    ('莎', (17, 141)),
+    ('莏', (54, 33)),
+    ('莐', (54, 25)),
+    ('莒', (17, 149)),
+    ('莓', (17, 151)),
+    ('莔', (54, 38)),
+    ('莕', (54, 35)),
+    ('莖', (17, 146)),
+    ('莗', (54, 51)),
+    ('莘', (17, 143)),
+    ('莙', (54, 36)),
+    ('莚', (54, 58)),
+    ('莛', (54, 44)),
+    ('莝', (54, 43)),
+    ('莞', (17, 142)),
+    ('莠', (17, 153)),
+    ('莢', (17, 145)),
+    ('莣', (54, 26)),
+    ('莤', (54, 31)),
+    ('莥', (54, 48)),
+    ('莦', (54, 54)),
+    ('莧', (18, 1)),
+    ('莨', (54, 27)),
+    ('莩', (54, 39)),
+    ('莪', (54, 45)),
+    ('莫', (17, 148)),
+    ('莮', (54, 56)),
+    ('莯', (54, 49)),
+    ('莰', (54, 52)),
+    ('莽', (17, 147)),
+    ('莿', (58, 44)),
+    ('菀', (58, 32)),
+    ('菁', (20, 117)),
+    ('菂', (58, 61)),
+    ('菃', (58, 69)),
+    ('菄', (58, 72)),
+    ('菅', (20, 115)),
+    ('菆', (58, 40)),
+    ('菇', (58, 65)),
+    ('菈', (58, 41)),
+    ('菉', (58, 55)),
+    ('菊', (20, 128)),
+    ('菋', (58, 51)),
+    ('菌', (20, 125)),
+    ('菎', (58, 52)),
+    ('菏', (58, 29)),
+    ('菑', (58, 66)),
+    ('菔', (20, 134)),
+    ('菕', (58, 63)),
+    ('菖', (58, 53)),
+    ('菗', (58, 74)),
+    ('菘', (58, 48)),
+    ('菙', (62, 102)),
+    ('菛', (58, 77)),
+    ('菜', (20, 132)),
+    ('菝', (58, 46)),
+    ('菞', (58, 58)),
+    ('菟', (20, 135)),
+    ('菠', (20, 114)),
+    ('菡', (58, 50)),
+    ('菢', (58, 75)),
+    ('菣', (58, 43)),
+    ('菤', (58, 36)),
+    ('菥', (58, 47)),
+    ('菧', (58, 35)),
+    ('菨', (58, 33)),
+    ('菩', (20, 110)),
+    ('菪', (58, 67)),
+    ('菫', (58, 42)),
+    ('菬', (58, 70)),
+    ('菮', (58, 71)),
+    ('華', (20, 118)),
+    ('菰', (20, 123)),
+    ('菱', (20, 119)),
+    ('菲', (20, 127)),
+    ('菳', (58, 62)),
+    ('菴', (20, 120)),
+    ('菵', (58, 54)),
+    ('菶', (58, 38)),
+    ('菸', (20, 112)),
+    ('菹', (58, 30)),
+    ('菺', (58, 64)),
+    ('菻', (58, 73)),
+    ('菼', (58, 37)),
+    ('菽', (20, 126)),
+    ('菾', (58, 78)),
+    ('菿', (58, 49)),
+    ('萁', (58, 45)),
+    ('萃', (20, 111)),
+    ('萄', (20, 131)),
+    ('萆', (58, 60)),
+    ('萇', (20, 133)),
+    ('萉', (58, 56)),
+    ('萊', (20, 122)),
+    ('萋', (20, 116)),
+    ('萌', (20, 124)),
+    ('萍', (20, 113)),
+    ('萎', (20, 130)),
+    ('萏', (58, 57)),
+    ('萐', (58, 39)),
+    ('萑', (58, 59)),
+    ('萒', (58, 34)),
+    ('萓', (58, 68)),
+    ('萛', (58, 76)),
+    ('萣', (58, 31)),
+    ('萩', (62, 101)),
+    ('萫', (62, 123)),
+    ('萬', (23, 21)),
+    ('萭', (62, 106)),
+    ('萯', (62, 104)),
+    ('萰', (62, 83)),
+    ('萱', (23, 71)),
+    ('萲', (62, 99)),
+    ('萳', (62, 119)),
+    ('萴', (62, 95)),
+    ('萵', (23, 79)),
+    ('萶', (62, 118)),
+    ('萷', (62, 93)),
+    ('萸', (20, 129)),
+    ('萹', (62, 109)),
+    ('萺', (62, 94)),
+    ('萻', (62, 116)),
+    ('萼', (23, 78)),
+    ('落', (23, 70)),
+    ('萿', (62, 72)),
+    ('葀', (62, 80)),
+    ('葂', (62, 105)),
+    ('葃', (62, 97)),
+    ('葄', (62, 122)),
+    ('葅', (62, 100)),
+    ('葆', (23, 84)),
+    ('葇', (62, 117)),
+    ('葉', (23, 75)),
+    ('葋', (62, 103)),
+    ('葌', (62, 111)),
+    ('葍', (62, 84)),
+    ('葎', (62, 110)),
+    ('葐', (62, 127)),
+    ('葑', (62, 79)),
+    ('葒', (62, 112)),
+    ('葔', (62, 125)),
+    ('葖', (62, 73)),
+    ('著', (20, 121)),
+    ('葙', (62, 87)),
+    ('葚', (62, 86)),
+    ('葛', (23, 77)),
+    ('葝', (62, 90)),
+    ('葞', (62, 92)),
+    ('葟', (62, 107)),
+    ('葠', (62, 124)),
+    ('葡', (23, 80)),
+    ('董', (23, 81)),
+    ('葥', (62, 78)),
+    ('葦', (23, 73)),
+    ('葧', (62, 82)),
+    ('葨', (62, 120)),
+    ('葩', (23, 82)),
+    ('葫', (23, 74)),
+    ('葬', (23, 76)),
+    ('葭', (23, 83)),
+    ('葮', (62, 126)),
+    ('葯', (62, 113)),
+    ('葰', (62, 108)),
+    ('葳', (62, 89)),
+    ('葴', (62, 88)),
+    ('葵', (23, 72)),
+    ('葶', (62, 74)),
+    ('葷', (23, 69)),
+    ('葸', (62, 98)),
+    ('葹', (62, 75)),
+    ('葺', (62, 96)),
+    ('葽', (62, 85)),
+    ('葾', (62, 121)),
+    ('蒂', (23, 68)),
+    ('蒆', (62, 81)),
+    ('蒍', (62, 77)),
+    ('蒎', (62, 115)),
+    ('蒏', (62, 76)),
+    ('蒐', (26, 32)),
+    ('蒑', (66, 113)),
+    ('蒔', (66, 96)),
+    ('蒗', (66, 74)),
+    ('蒘', (66, 104)),
+    ('蒙', (26, 24)),
+    ('蒚', (66, 89)),
+    ('蒛', (66, 99)),
+    ('蒜', (26, 27)),
+    ('蒝', (66, 92)),
+    ('蒞', (26, 25)),
+    ('蒟', (66, 77)),
+    ('蒠', (66, 107)),
+    ('蒡', (66, 76)),
+    ('蒢', (66, 95)),
+    ('蒤', (66, 75)),
+    ('蒧', (66, 93)),
+    ('蒨', (66, 102)),
+    ('蒩', (66, 100)),
+    ('蒪', (66, 88)),
+    ('蒫', (66, 83)),
+    ('蒬', (66, 81)),
+    ('蒮', (66, 82)),
+    ('蒯', (66, 101)),
+    ('蒰', (66, 112)),
+    ('蒱', (66, 90)),
+    ('蒲', (26, 26)),
+    ('蒴', (66, 85)),
+    ('蒶', (66, 105)),
+    ('蒸', (26, 29)),
+    ('蒹', (66, 84)),
+    ('蒺', (66, 78)),
+    ('蒻', (66, 94)),
+    ('蒼', (26, 33)),
+    ('蒿', (26, 21)),
+    ('蓀', (26, 30)),
+    ('蓁', (66, 86)),
+    ('蓂', (66, 80)),
+    ('蓄', (26, 23)),
+    ('蓅', (62, 114)),
+    ('蓆', (26, 22)),
+    ('蓇', (66, 97)),
+    ('蓉', (26, 20)),
+    ('蓊', (26, 35)),
+    ('蓋', (26, 28)),
+    ('蓌', (66, 98)),
+    ('蓍', (66, 87)),
+    ('蓎', (66, 79)),
+    ('蓏', (66, 106)),
+    ('蓐', (66, 91)),
+    ('蓑', (26, 34)),
+    ('蓒', (66, 110)),
+    ('蓓', (26, 31)),
+    ('蓔', (66, 109)),
+    ('蓖', (66, 103)),
+    ('蓗', (66, 108)),
+    ('蓛', (66, 111)),
+    ('蓧', (70, 51)),
+    ('蓨', (70, 52)),
+    ('蓩', (70, 39)),
+    ('蓪', (70, 38)),
+    ('蓫', (70, 34)),
+    ('蓬', (28, 82)),
+    ('蓮', (28, 74)),
+    ('蓯', (70, 54)),
+    ('蓰', (70, 53)),
+    ('蓱', (62, 71)),
+    ('蓲', (70, 31)),
+    ('蓳', (70, 35)),
+    ('蓴', (70, 29)),
+    ('蓶', (70, 48)),
+    ('蓷', (70, 33)),
+    ('蓹', (70, 55)),
+    ('蓺', (70, 26)),
+    ('蓻', (70, 24)),
+    ('蓼', (70, 36)),
+    ('蓽', (70, 46)),
+    ('蓾', (70, 41)),
+    ('蓿', (28, 84)),
+    ('蔀', (70, 15)),
+    ('蔂', (70, 45)),
+    ('蔆', (28, 85)),
+    ('蔇', (62, 91)),
+    ('蔈', (70, 27)),
+    ('蔉', (70, 18)),
+    ('蔊', (70, 21)),
+    ('蔋', (70, 59)),
+    ('蔌', (70, 28)),
+    ('蔍', (70, 19)),
+    ('蔎', (70, 17)),
+    ('蔏', (70, 14)),
+    ('蔑', (28, 78)),
+    ('蔒', (70, 37)),
+    ('蔓', (28, 77)),
+    ('蔔', (28, 81)),
+    ('蔕', (70, 32)),
+    ('蔖', (70, 40)),
+    ('蔗', (28, 71)),
+    ('蔘', (70, 56)),
+    ('蔙', (70, 60)),
+    ('蔚', (28, 73)),
+    ('蔜', (70, 23)),
+    ('蔝', (70, 43)),
+    ('蔞', (70, 47)),
+    ('蔟', (70, 20)),
+    ('蔠', (70, 57)),
+    ('蔡', (28, 80)),
+    ('蔣', (28, 79)),
+    ('蔤', (70, 12)),
+    ('蔥', (28, 83)),
+    ('蔦', (70, 50)),
+    ('蔧', (70, 22)),
+    ('蔨', (70, 42)),
+    ('蔩', (70, 16)),
+    ('蔪', (70, 30)),
+    ('蔫', (70, 25)),
+    ('蔬', (28, 75)),
+    ('蔭', (28, 76)),
+    ('蔮', (70, 44)),
+    ('蔯', (70, 61)),
+    ('蔰', (70, 58)),
+    ('蔱', (70, 49)),
+    ('蔻', (70, 13)),
+    ('蔽', (28, 72)),
+    ('蔾', (74, 9)),
+    ('蕀', (74, 0)),
+    ('蕁', (74, 3)),
+    ('蕃', (30, 89)),
+    ('蕄', (74, 5)),
+    ('蕅', (73, 151)),
+    ('蕆', (74, 1)),
+    ('蕇', (74, 7)),
+    ('蕈', (30, 86)),
+    ('蕉', (30, 90)),
+    ('蕊', (30, 84)),
+    ('蕍', (73, 153)),
+    ('蕎', (74, 12)),
+    ('蕑', (74, 6)),
+    ('蕓', (73, 154)),
+    ('蕔', (74, 21)),
+    ('蕕', (74, 15)),
+    ('蕖', (73, 150)),
+    ('蕗', (77, 6)),
+    ('蕘', (73, 156)),
+    ('蕙', (30, 85)),
+    ('蕛', (74, 10)),
+    ('蕝', (74, 20)),
+    ('蕞', (30, 93)),
+    ('蕠', (74, 17)),
+    ('蕡', (73, 155)),
+    ('蕢', (74, 4)),
+    ('蕣', (74, 8)),
+    ('蕤', (74, 2)),
+    ('蕥', (74, 22)),
+    ('蕦', (74, 19)),
+    ('蕧', (74, 16)),
+    ('蕨', (30, 87)),
+    ('蕩', (30, 88)),
+    ('蕪', (30, 92)),
+    ('蕫', (73, 152)),
+    ('蕬', (74, 23)),
+    ('蕭', (30, 91)),
+    ('蕮', (74, 13)),
+    ('蕱', (74, 11)),
+    ('蕵', (74, 14)),
+    ('蕶', (77, 19)),
+    ('蕷', (77, 0)),
+    ('蕸', (77, 5)),
+    ('蕹', (77, 18)),
+    ('蕺', (77, 4)),
+    ('蕻', (76, 153)),
+    ('蕼', (77, 1)),
+    ('蕾', (32, 64)),
+    ('薀', (76, 146)),
+    ('薁', (77, 13)),
+    ('薂', (77, 15)),
+    ('薃', (76, 145)),
+    ('薄', (32, 63)),
+    ('薅', (77, 17)),
+    ('薆', (77, 9)),
+    ('薇', (32, 70)),
+    ('薈', (77, 16)),
+    ('薉', (77, 2)),
+    ('薊', (32, 72)),
+    ('薋', (76, 151)),
+    ('薌', (74, 18)),
+    ('薍', (77, 10)),
+    ('薎', (77, 7)),
+    ('薏', (76, 147)),
+    ('薐', (77, 21)),
+    ('薑', (32, 66)),
+    ('薔', (32, 67)),
+    ('薕', (76, 149)),
+    ('薖', (77, 8)),
+    ('薘', (77, 20)),
+    ('薙', (77, 11)),
+    ('薚', (76, 155)),
+    ('薛', (32, 69)),
+    ('薜', (32, 65)),
+    ('薝', (77, 12)),
+    ('薞', (76, 156)),
+    ('薟', (77, 22)),
+    ('薠', (76, 150)),
+    ('薡', (77, 3)),
+    ('薢', (77, 14)),
+    ('薣', (76, 152)),
+    ('薤', (76, 154)),
+    ('薦', (33, 105)),
+    ('薧', (76, 148)),
+    ('薨', (32, 71)),
+    ('薩', (33, 98)),
+    ('薪', (32, 62)),
+    ('薯', (32, 68)),
+    ('薰', (33, 102)),
+    ('薱', (79, 81)),
+    ('薳', (79, 71)),
+    ('薴', (79, 66)),
+    ('薵', (79, 72)),
+    ('薶', (79, 82)),
+    ('薷', (79, 86)),
+    ('薸', (79, 85)),
+    ('薹', (33, 104)),
+    ('薺', (33, 103)),
+    ('薽', (79, 73)),
+    ('薾', (79, 87)),
+    ('薿', (79, 76)),
+    ('藀', (79, 68)),
+    ('藂', (79, 70)),
+    ('藃', (79, 69)),
+    ('藄', (79, 75)),
+    ('藅', (79, 80)),
+    ('藆', (79, 67)),
+    ('藇', (79, 74)),
+    ('藈', (79, 79)),
+    ('藉', (33, 101)),
+    ('藋', (79, 77)),
+    ('藍', (33, 99)),
+    ('藎', (79, 78)),
+    ('藏', (33, 97)),
+    ('藐', (33, 100)),
+    ('藑', (81, 115)),
+    ('藒', (79, 83)),
+    ('藕', (34, 96)),
+    ('藗', (81, 107)),
+    ('藘', (81, 111)),
+    ('藙', (81, 103)),
+    ('藚', (81, 106)),
+    ('藜', (81, 114)),
+    ('藝', (34, 94)),
+    ('藞', (81, 119)),
+    ('藟', (81, 112)),
+    ('藡', (81, 104)),
+    ('藢', (81, 120)),
+    ('藣', (81, 113)),
+    ('藤', (34, 97)),
+    ('藥', (34, 98)),
+    ('藦', (81, 117)),
+    ('藨', (81, 105)),
+    ('藩', (34, 93)),
+    ('藪', (34, 95)),
+    ('藫', (81, 100)),
+    ('藬', (81, 108)),
+    ('藭', (81, 102)),
+    ('藯', (81, 118)),
+    ('藰', (81, 116)),
+    ('藱', (81, 101)),
+    ('藲', (81, 109)),
+    ('藶', (83, 92)),
+    ('藷', (34, 99)),
+    ('藸', (81, 110)),
+    ('藹', (35, 69)),
+    ('藺', (35, 71)),
+    ('藻', (35, 68)),
+    ('藽', (83, 97)),
+    ('藾', (83, 89)),
+    ('藿', (83, 87)),
+    ('蘀', (83, 91)),
+    ('蘁', (83, 88)),
+    ('蘄', (83, 93)),
+    ('蘅', (83, 95)),
+    ('蘆', (35, 72)),
+    ('蘇', (35, 74)),
+    ('蘉', (83, 94)),
+    ('蘊', (35, 75)),
+    ('蘋', (35, 73)),
+    ('蘌', (83, 96)),
+    ('蘑', (35, 70)),
+    ('蘗', (35, 146)),
+    ('蘘', (84, 143)),
+    ('蘙', (84, 149)),
+    ('蘚', (35, 148)),
+    ('蘛', (83, 90)),
+    ('蘜', (84, 148)),
+    ('蘞', (84, 155)),
+    ('蘟', (84, 146)),
+    ('蘠', (84, 153)),
+    ('蘡', (84, 152)),
+    ('蘢', (83, 86)),
+    ('蘣', (84, 147)),
+    ('蘤', (79, 84)),
+    ('蘥', (84, 156)),
+    ('蘦', (84, 145)),
+    ('蘧', (84, 150)),
+    ('蘩', (84, 154)),
+    ('蘪', (84, 144)),
+    ('蘬', (86, 10)),
+    ('蘭', (35, 147)),
+    ('蘮', (84, 151)),
+    ('蘱', (86, 146)),
+    ('蘲', (86, 11)),
+    ('蘳', (86, 9)),
+    ('蘴', (86, 7)),
+    ('蘵', (86, 8)),
+    ('蘶', (86, 12)),
+    ('蘸', (36, 119)),
+    ('蘹', (86, 144)),
+    ('蘺', (86, 142)),
+    ('蘻', (86, 147)),
+    ('蘼', (86, 145)),
+    ('蘾', (86, 148)),
+    ('蘿', (36, 120)),
+    ('虀', (86, 143)),
+    ('虃', (87, 90)),
+    ('虆', (88, 5)),
+    ('虇', (88, 6)),
+    ('虈', (88, 7)),
+    ('虋', (88, 101)),
+    ('虌', (88, 91)),
+    ('虍', (41, 15)),
+    ('虎', (9, 136)),
+    ('虐', (12, 40)),
+    ('虒', (50, 86)),
+    ('虓', (50, 85)),
+    ('虔', (15, 0)),
+    ('處', (18, 2)),
+    ('虖', (54, 60)),
+    ('虙', (54, 59)),
+    ('虛', (20, 136)),
+    ('虜', (23, 86)),
+    ('虞', (23, 85)),
+    ('號', (23, 87)),
+    ('虡', (66, 114)),
+    ('虢', (70, 62)),
+    ('虣', (74, 24)),
+    ('虤', (74, 26)),
+    ('虥', (74, 25)),
+    ('虧', (32, 73)),
+    ('虨', (77, 23)),
+    ('虩', (79, 88)),
+    ('虪', (88, 67)),
+    ('虫', (5, 130)),
+    ('虭', (44, 103)),
+    ('虮', (44, 104)),
+    ('虯', (44, 102)),
+    ('虰', (44, 101)),
+    ('虱', (9, 137)),
+    ('虳', (47, 71)),
+    ('虴', (47, 69)),
+    ('虷', (47, 68)),
+    ('虹', (12, 41)),
+    ('虺', (12, 43)),
+    ('虻', (12, 42)),
+    ('虼', (47, 70)),
+    ('蚅', (50, 98)),
+    ('蚆', (50, 95)),
+    ('蚇', (50, 93)),
+    ('蚊', (15, 1)),
+    ('蚋', (50, 96)),
+    ('蚌', (15, 6)),
+    ('蚍', (50, 90)),
+    ('蚎', (50, 105)),
+    ('蚐', (50, 107)),
+    ('蚑', (50, 91)),
+    ('蚓', (15, 3)),
+    ('蚔', (50, 108)),
+    ('蚕', (50, 103)),
+    ('蚖', (50, 89)),
+    ('蚗', (50, 94)),
+    ('蚘', (50, 104)),
+    ('蚙', (50, 100)),
+    ('蚚', (50, 97)),
+    ('蚜', (15, 8)),
+    ('蚝', (50, 106)),
+    ('蚞', (50, 92)),
+    ('蚡', (50, 101)),
+    ('蚢', (50, 87)),
+    ('蚣', (15, 7)),
+    ('蚤', (15, 4)),
+    ('蚥', (50, 99)),
+    ('蚧', (50, 102)),
+    ('蚨', (50, 88)),
+    ('蚩', (15, 5)),
+    ('蚪', (15, 2)),
+    ('蚯', (18, 12)),
+    ('蚰', (54, 67)),
+    ('蚱', (18, 11)),
+    ('蚳', (54, 70)),
+    ('蚴', (54, 73)),
+    ('蚵', (18, 8)),
+    ('蚶', (18, 6)),
+    ('蚷', (54, 62)),
+    ('蚸', (54, 71)),
+    ('蚹', (54, 69)),
+    ('蚺', (54, 66)),
+    ('蚻', (54, 74)),
+    ('蚼', (54, 75)),
+    ('蚽', (54, 77)),
+    ('蚾', (54, 78)),
+    ('蚿', (54, 61)),
+    ('蛀', (18, 5)),
+    ('蛁', (54, 64)),
+    ('蛂', (54, 63)),
+    ('蛃', (54, 76)),
+    ('蛄', (18, 7)),
+    ('蛅', (54, 65)),
+    ('蛆', (18, 9)),
+    ('蛇', (18, 4)),
+    ('蛈', (54, 68)),
+    ('蛉', (18, 13)),
+    ('蛋', (18, 10)),
+    ('蛌', (54, 72)),
+    ('蛐', (20, 143)),
+    ('蛑', (58, 93)),
+    ('蛓', (58, 82)),
+    ('蛔', (20, 140)),
+    ('蛖', (62, 133)),
+    ('蛗', (58, 91)),
+    ('蛘', (58, 79)),
+    ('蛙', (20, 138)),
+    ('蛚', (58, 84)),
+    ('蛛', (20, 141)),
+    ('蛜', (58, 88)),
+    ('蛝', (58, 86)),
+    ('蛞', (20, 144)),
+    ('蛟', (20, 137)),
+    ('蛢', (58, 80)),
+    ('蛣', (58, 83)),
+    ('蛤', (20, 142)),
+    ('蛦', (58, 81)),
+    ('蛨', (58, 92)),
+    ('蛩', (58, 90)),
+    ('蛪', (58, 85)),
+    ('蛫', (58, 87)),
+    ('蛬', (58, 89)),
+    ('蛭', (20, 139)),
+    ('蛵', (62, 134)),
+    ('蛶', (62, 140)),
+    ('蛷', (62, 130)),
+    ('蛸', (62, 136)),
+    ('蛹', (23, 88)),
+    ('蛺', (62, 132)),
+    ('蛻', (23, 94)),
+    ('蛾', (23, 93)),
+    ('蜀', (23, 92)),
+    ('蜁', (62, 139)),
+    ('蜂', (23, 95)),
+    ('蜃', (23, 96)),
+    ('蜄', (62, 129)),
+    ('蜅', (62, 142)),
+    ('蜆', (23, 97)),
+    ('蜇', (23, 91)),
+    ('蜈', (23, 90)),
+    ('蜉', (62, 138)),
+    ('蜊', (23, 98)),
+    ('蜋', (62, 128)),
+    ('蜌', (62, 131)),
+    ('蜍', (62, 141)),
+    ('蜎', (62, 137)),
+    ('蜑', (66, 146)),
+    ('蜒', (66, 135)),
+    ('蜓', (23, 89)),
+    ('蜘', (26, 42)),
+    ('蜙', (66, 123)),
+    ('蜚', (66, 144)),
+    ('蜛', (66, 124)),
+    ('蜜', (26, 37)),
+    ('蜞', (66, 121)),
+    ('蜠', (66, 130)),
+    ('蜡', (66, 122)),
+    ('蜢', (26, 39)),
+    ('蜣', (66, 116)),
+    ('蜤', (66, 143)),
+    ('蜥', (26, 40)),
+    ('蜦', (66, 140)),
+    ('蜧', (66, 141)),
+    ('蜨', (66, 117)),
+    ('蜩', (26, 45)),
+    ('蜪', (66, 132)),
+    ('蜬', (66, 126)),
+    ('蜭', (66, 133)),
+    ('蜮', (66, 120)),
+    ('蜰', (66, 145)),
+    ('蜱', (66, 137)),
+    ('蜲', (66, 131)),
+    ('蜳', (66, 115)),
+    ('蜴', (26, 41)),
+    ('蜵', (66, 138)),
+    ('蜷', (26, 44)),
+    ('蜸', (66, 142)),
+    ('蜺', (66, 136)),
+    ('蜻', (26, 38)),
+    ('蜼', (66, 134)),
+    ('蜾', (66, 128)),
+    ('蜿', (26, 36)),
+    ('蝀', (66, 119)),
+    ('蝁', (66, 127)),
+    ('蝂', (66, 139)),
+    ('蝃', (66, 125)),
+    ('蝆', (66, 129)),
+    ('蝌', (28, 95)),
+    ('蝍', (62, 135)),
+    ('蝎', (70, 80)),
+    ('蝏', (70, 89)),
+    ('蝐', (70, 79)),
+    ('蝑', (70, 75)),
+    ('蝒', (70, 72)),
+    ('蝓', (28, 96)),
+    ('蝔', (70, 70)),
+    ('蝕', (26, 43)),
+    ('蝖', (70, 63)),
+    ('蝗', (28, 94)),
+    ('蝘', (70, 69)),
+    ('蝙', (28, 93)),
+    ('蝚', (70, 74)),
+    ('蝛', (70, 71)),
+    ('蝜', (70, 87)),
+    ('蝝', (70, 82)),
+    ('蝞', (70, 76)),
+    ('蝟', (70, 81)),
+    ('蝠', (28, 89)),
+    ('蝡', (70, 73)),
+    ('蝢', (70, 92)),
+    ('蝣', (70, 64)),
+    ('蝤', (70, 65)),
+    ('蝥', (70, 88)),
+    ('蝦', (28, 90)),
+    ('蝧', (70, 93)),
+    ('蝨', (28, 92)),
+    ('蝩', (70, 94)),
+    ('蝪', (70, 78)),
+    ('蝫', (66, 118)),
+    ('蝬', (70, 84)),
+    ('蝭', (70, 77)),
+    ('蝮', (70, 86)),
+    ('蝯', (70, 83)),
+    ('蝳', (70, 68)),
+    ('蝴', (28, 87)),
+    ('蝵', (70, 91)),
+    ('蝶', (28, 88)),
+    ('蝷', (70, 66)),
+    ('蝸', (28, 91)),
+    ('蝹', (74, 36)),
+    ('蝺', (70, 85)),
+    ('蝻', (70, 90)),
+    ('螁', (74, 33)),
+    ('螂', (28, 86)),
+    ('螃', (30, 94)),
+    ('螄', (74, 43)),
+    ('螅', (74, 39)),
+    ('螇', (74, 37)),
+    ('螈', (74, 32)),
+    ('螉', (74, 47)),
+    ('融', (30, 98)),
+    ('螏', (74, 28)),
+    ('螐', (74, 40)),
+    ('螑', (74, 41)),
+    ('螒', (74, 31)),
+    ('螓', (74, 30)),
+    ('螔', (74, 44)),
+    ('螖', (74, 34)),
+    ('螗', (74, 29)),
+    ('螘', (74, 35)),
+    ('螚', (74, 46)),
+    ('螛', (74, 27)),
+    ('螜', (74, 45)),
+    ('螝', (74, 42)),
+    ('螞', (30, 96)),
+    ('螟', (30, 95)),
+    ('螢', (30, 97)),
+    ('螣', (74, 38)),
+    ('螤', (79, 105)),
+    ('螪', (77, 25)),
+    ('螫', (32, 79)),
+    ('螬', (77, 29)),
+    ('螭', (77, 26)),
+    ('螮', (77, 33)),
+    ('螯', (77, 39)),
+    ('螰', (77, 28)),
+    ('螲', (77, 48)),
+    ('螳', (32, 76)),
+    ('螴', (77, 42)),
+    ('螵', (77, 31)),
+    ('螶', (77, 43)),
+    ('螷', (77, 38)),
+    ('螸', (77, 45)),
+    ('螹', (77, 30)),
+    ('螺', (32, 81)),
+    ('螻', (32, 80)),
+    ('螼', (77, 32)),
+    ('螽', (77, 46)),
+    ('螾', (77, 24)),
+    ('螿', (77, 44)),
+    ('蟀', (32, 74)),
+    ('蟂', (77, 36)),
+    ('蟃', (77, 35)),
+    ('蟄', (77, 40)),
+    ('蟅', (77, 27)),
+    ('蟆', (32, 78)),
+    ('蟈', (32, 82)),
+    ('蟉', (77, 34)),
+    ('蟊', (77, 41)),
+    ('蟋', (32, 83)),
+    ('蟌', (77, 37)),
+    ('蟑', (32, 75)),
+    ('蟒', (32, 77)),
+    ('蟓', (79, 101)),
+    ('蟔', (79, 99)),
+    ('蟗', (79, 106)),
+    ('蟘', (79, 103)),
+    ('蟙', (79, 107)),
+    ('蟛', (79, 92)),
+    ('蟜', (79, 100)),
+    ('蟝', (79, 111)),
+    ('蟞', (77, 47)),
+    ('蟟', (79, 96)),
+    ('蟠', (33, 109)),
+    ('蟡', (70, 67)),
+    ('蟢', (79, 91)),
+    ('蟣', (79, 104)),
+    ('蟤', (79, 98)),
+    ('蟥', (79, 95)),
+    ('蟦', (79, 90)),
+    ('蟧', (79, 89)),
+    ('蟨', (79, 110)),
+    ('蟪', (79, 94)),
+    ('蟫', (79, 93)),
+    ('蟬', (33, 107)),
+    ('蟭', (79, 102)),
+    ('蟯', (33, 106)),
+    ('蟲', (33, 108)),
+    ('蟳', (79, 97)),
+    ('蟴', (79, 109)),
+    ('蟶', (81, 124)),
+    ('蟷', (81, 125)),
+    ('蟹', (34, 103)),
+    ('蟺', (81, 122)),
+    ('蟻', (34, 100)),
+    ('蟼', (81, 130)),
+    ('蟾', (34, 104)),
+    ('蟿', (81, 132)),
+    ('蠀', (81, 121)),
+    ('蠁', (79, 108)),
+    ('蠂', (81, 134)),
+    ('蠃', (81, 123)),
+    ('蠅', (34, 101)),
+    ('蠆', (81, 129)),
+    ('蠈', (81, 131)),
+    ('蠉', (81, 126)),
+    ('蠊', (81, 133)),
+    ('蠋', (81, 128)),
+    ('蠌', (81, 127)),
+    ('蠍', (34, 102)),
+    ('蠐', (83, 99)),
+    ('蠑', (83, 100)),
+    ('蠓', (83, 102)),
+    ('蠔', (35, 76)),
+    ('蠕', (35, 77)),
+    ('蠖', (83, 103)),
+    ('蠗', (83, 101)),
+    ('蠙', (83, 98)),
+    ('蠛', (85, 2)),
+    ('蠜', (85, 5)),
+    ('蠝', (85, 1)),
+    ('蠟', (35, 152)),
+    ('蠠', (85, 3)),
+    ('蠡', (35, 151)),
+    ('蠢', (35, 150)),
+    ('蠣', (35, 149)),
+    ('蠤', (85, 4)),
+    ('蠥', (86, 17)),
+    ('蠦', (86, 15)),
+    ('蠨', (86, 14)),
+    ('蠩', (85, 0)),
+    ('蠪', (86, 16)),
+    ('蠫', (85, 6)),
+    ('蠬', (86, 13)),
+    ('蠮', (86, 151)),
+    ('蠯', (86, 117)),
+    ('蠰', (86, 149)),
+    ('蠱', (36, 121)),
+    ('蠲', (86, 150)),
+    ('蠳', (86, 152)),
+    ('蠵', (87, 93)),
+    ('蠶', (36, 152)),
+    ('蠷', (87, 92)),
+    ('蠸', (87, 91)),
+    ('蠹', (36, 153)),
+    ('蠻', (37, 26)),
+    ('蠼', (88, 46)),
+    ('蠽', (88, 69)),
+    ('蠾', (88, 68)),
+    ('蠿', (88, 70)),
+    ('血', (5, 131)),
+    ('衁', (47, 72)),
+    ('衃', (50, 109)),
+    ('衄', (50, 110)),
+    ('衈', (58, 94)),
+    ('衊', (85, 7)),
+    ('衋', (87, 94)),
+    ('行', (5, 132)),
+    ('衍', (12, 44)),
+    ('衎', (47, 73)),
+    ('衒', (54, 79)),
+    ('術', (18, 14)),
+    ('衕', (58, 96)),
+    ('衖', (58, 95)),
+    ('街', (20, 145)),
+    ('衙', (23, 99)),
+    ('衚', (70, 95)),
+    ('衛', (28, 97)),
+    ('衝', (28, 98)),
+    ('衡', (30, 99)),
+    ('衢', (36, 154)),
+    ('衣', (5, 133)),
+    ('衧', (47, 74)),
+    ('表', (9, 139)),
+    ('衩', (47, 76)),
+    ('衪', (47, 75)),
+    ('衫', (12, 45)),
+    ('衭', (50, 111)),
+    ('衯', (50, 118)),
+    ('衰', (15, 9)),
+    ('衱', (50, 116)),
+    ('衲', (50, 114)),
+    ('衴', (50, 121)),
+    ('衵', (50, 112)),
+    ('衶', (50, 113)),
+    ('衷', (15, 10)),
+    ('衹', (15, 14)),
+    ('衼', (50, 122)),
+    ('衽', (15, 13)),
+    ('衾', (50, 120)),
+    ('衿', (50, 117)),
+    ('袀', (50, 115)),
+    ('袁', (15, 11)),
+    ('袂', (15, 12)),
+    ('袃', (50, 119)),
+    ('袈', (18, 16)),
+    ('袉', (54, 80)),
+    ('袋', (18, 21)),
+    ('袌', (54, 96)),
+    ('袍', (18, 20)),
+    ('袎', (54, 98)),
+    ('袑', (54, 86)),
+    ('袒', (18, 18)),
+    ('袓', (54, 97)),
+    ('袕', (54, 81)),
+    ('袖', (18, 19)),
+    ('袗', (54, 93)),
+    ('袘', (54, 89)),
+    ('袙', (54, 91)),
+    ('袚', (54, 85)),
+    ('袛', (54, 92)),
+    ('袞', (18, 15)),
+    ('袟', (54, 88)),
+    ('袡', (54, 87)),
+    ('袢', (54, 83)),
+    ('袤', (54, 94)),
+    ('袧', (54, 90)),
+    ('袨', (54, 82)),
+    ('袪', (54, 84)),
+    ('被', (18, 17)),
+    ('袬', (54, 95)),
+    ('袱', (20, 148)),
+    ('袲', (58, 107)),
+    ('袶', (58, 103)),
+    ('袷', (58, 105)),
+    ('袸', (58, 100)),
+    ('袹', (58, 99)),
+    ('袺', (58, 97)),
+    ('袼', (58, 104)),
+    ('袽', (58, 106)),
+    ('袾', (58, 102)),
+    ('裀', (58, 101)),
+    ('裁', (20, 146)),
+    ('裂', (20, 147)),
+    ('裉', (58, 109)),
+    ('裊', (23, 107)),
+    ('裋', (62, 144)),
+    ('裌', (62, 150)),
+    ('裍', (62, 145)),
+    ('裎', (62, 146)),
+    ('裐', (62, 151)),
+    ('裒', (23, 109)),
+    ('裔', (23, 101)),
+    ('裕', (23, 108)),
+    ('裖', (62, 143)),
+    ('裗', (58, 98)),
+    ('裘', (23, 104)),
+    ('裙', (23, 102)),
+    ('裚', (62, 149)),
+    ('裛', (62, 148)),
+    ('補', (23, 103)),
+    ('裝', (23, 105)),
+    ('裞', (62, 147)),
+    ('裟', (23, 100)),
+    ('裡', (23, 106)),
+    ('裧', (66, 148)),
+    ('裨', (26, 52)),
+    ('裫', (67, 2)),
+    ('裬', (67, 1)),
+    ('裮', (66, 153)),
+    ('裯', (26, 54)),
+    ('裰', (67, 0)),
+    ('裱', (66, 149)),
+    ('裲', (66, 150)),
+    ('裳', (26, 46)),
+    ('裴', (26, 48)),
+    ('裶', (66, 155)),
+    ('裷', (66, 147)),
+    ('裸', (26, 50)),
+    ('裹', (26, 49)),
+    ('裺', (66, 151)),
+    ('裻', (66, 156)),
+    ('裼', (66, 154)),
+    ('製', (26, 51)),
+    ('裾', (66, 152)),
+    ('褁', (58, 108)),
+    ('褂', (26, 47)),
+    ('褅', (70, 96)),
+    ('褆', (70, 103)),
+    ('複', (28, 100)),
+    ('褉', (70, 107)),
+    ('褊', (28, 104)),
+    ('褋', (70, 99)),
+    ('褌', (70, 97)),
+    ('褎', (70, 106)),
+    ('褐', (28, 99)),
+    ('褑', (70, 105)),
+    ('褒', (28, 101)),
+    ('褓', (28, 102)),
+    ('褔', (70, 98)),
+    ('褕', (28, 103)),
+    ('褖', (70, 104)),
+    ('褗', (70, 100)),
+    ('褘', (70, 101)),
+    ('褙', (70, 102)),
+    ('褚', (26, 53)),
+    ('褞', (74, 48)),
+    ('褟', (74, 60)),
+    ('褡', (30, 104)),
+    ('褢', (74, 55)),
+    ('褣', (74, 57)),
+    ('褥', (30, 102)),
+    ('褦', (74, 49)),
+    ('褧', (74, 53)),
+    ('褩', (74, 56)),
+    ('褪', (30, 100)),
+    ('褫', (30, 103)),
+    ('褬', (74, 59)),
+    ('褭', (74, 51)),
+    ('褮', (74, 52)),
+    ('褯', (74, 58)),
+    ('褰', (74, 50)),
+    ('褱', (74, 54)),
+    ('褲', (30, 101)),
+    ('褳', (77, 50)),
+    ('褵', (77, 49)),
+    ('褶', (32, 85)),
+    ('褷', (77, 55)),
+    ('褸', (32, 87)),
+    ('褻', (32, 84)),
+    ('褼', (77, 51)),
+    ('褽', (32, 88)),
+    ('褾', (77, 52)),
+    ('襁', (77, 53)),
+    ('襂', (77, 56)),
+    ('襄', (32, 86)),
+    ('襆', (79, 116)),
+    ('襉', (79, 119)),
+    ('襋', (79, 113)),
+    ('襌', (79, 115)),
+    ('襏', (79, 114)),
+    ('襐', (79, 117)),
+    ('襑', (79, 118)),
+    ('襒', (77, 54)),
+    ('襓', (79, 112)),
+    ('襖', (34, 107)),
+    ('襗', (81, 138)),
+    ('襘', (81, 141)),
+    ('襙', (81, 143)),
+    ('襚', (81, 136)),
+    ('襛', (81, 137)),
+    ('襜', (81, 140)),
+    ('襝', (81, 142)),
+    ('襞', (34, 108)),
+    ('襟', (34, 106)),
+    ('襠', (34, 105)),
+    ('襡', (81, 139)),
+    ('襢', (81, 135)),
+    ('襣', (83, 104)),
+    ('襤', (35, 78)),
+    ('襦', (83, 105)),
+    ('襩', (85, 9)),
+    ('襪', (35, 153)),
+    ('襫', (85, 11)),
+    ('襬', (35, 154)),
+    ('襭', (85, 8)),
+    ('襮', (85, 10)),
+    ('襯', (36, 70)),
+    ('襱', (86, 18)),
+    ('襲', (36, 69)),
+    ('襳', (86, 155)),
+    ('襴', (86, 154)),
+    ('襶', (86, 153)),
+    ('襹', (88, 8)),
+    ('襺', (88, 9)),
+    ('襻', (88, 11)),
+    ('襼', (88, 10)),
+    ('襾', (41, 16)),
+    ('西', (5, 134)),
+    ('要', (12, 46)),
+    ('覂', (54, 99)),
+    ('覃', (20, 149)),
+    ('覅', (62, 152)),
+    ('覆', (33, 110)),
+    ('覈', (81, 144)),
+    ('見', (7, 65)),
+    ('規', (18, 23)),
+    ('覓', (18, 22)),
+    ('覕', (58, 110)),
+    ('視', (20, 150)),
+    ('覗', (58, 112)),
+    ('覘', (58, 111)),
+    ('覛', (62, 153)),
+    ('覜', (23, 110)),
+    ('覝', (67, 3)),
+    ('覞', (67, 6)),
+    ('覟', (67, 5)),
+    ('覡', (67, 4)),
+    ('覢', (70, 108)),
+    ('覣', (70, 110)),
+    ('覤', (70, 109)),
+    ('覦', (30, 106)),
+    ('親', (30, 105)),
+    ('覬', (32, 89)),
+    ('覭', (77, 57)),
+    ('覮', (77, 59)),
+    ('覯', (77, 58)),
+    ('覲', (33, 111)),
+    ('覶', (81, 146)),
+    ('覷', (81, 145)),
+    ('覹', (83, 106)),
+    ('覺', (35, 79)),
+    ('覽', (35, 155)),
+    ('覾', (86, 20)),
+    ('覿', (86, 19)),
+    ('觀', (37, 27)),
+    ('角', (7, 66)),
+    ('觓', (47, 77)),
+    ('觔', (12, 47)),
+    ('觕', (54, 102)),
+    ('觖', (54, 100)),
+    ('觙', (54, 101)),
+    ('觚', (58, 114)),
+    ('觛', (58, 115)),
+    ('觜', (63, 3)),
+    ('觝', (58, 113)),
+    ('觟', (62, 154)),
+    ('觠', (63, 1)),
+    ('觡', (63, 0)),
+    ('觢', (63, 2)),
+    ('解', (23, 111)),
+    ('觤', (62, 156)),
+    ('觥', (62, 155)),
+    ('触', (63, 4)),
+    ('觨', (67, 9)),
+    ('觩', (67, 7)),
+    ('觫', (67, 8)),
+    ('觬', (70, 113)),
+    ('觭', (70, 111)),
+    ('觰', (70, 112)),
+    ('觱', (74, 61)),
+    ('觲', (77, 60)),
+    ('觳', (77, 61)),
+    ('觴', (33, 112)),
+    ('觶', (81, 147)),
+    ('觷', (83, 107)),
+    ('觸', (35, 80)),
+    ('觺', (85, 12)),
+    ('觻', (86, 21)),
+    ('觼', (36, 71)),
+    ('觾', (86, 156)),
+    ('觿', (88, 12)),
+    ('言', (7, 67)),
+    ('訂', (12, 49)),
+    ('訃', (12, 50)),
+    ('訄', (47, 78)),
+    ('訇', (47, 79)),
+    ('計', (12, 48)),
+    ('訊', (15, 20)),
+    ('訌', (15, 18)),
+    ('討', (15, 17)),
+    ('訏', (15, 24)),
+    ('訐', (15, 16)),
+    ('訑', (15, 25)),
+    ('訒', (50, 123)),
+    ('訓', (15, 22)),
+    ('訕', (15, 19)),
+    ('訖', (15, 23)),
+    ('託', (15, 21)),
+    ('記', (15, 15)),
+    ('訛', (18, 31)),
+    ('訝', (18, 25)),
+    ('訞', (54, 106)),
+    ('訟', (18, 30)),
+    ('訢', (18, 32)),
+    ('訣', (18, 26)),
+    ('訥', (18, 27)),
+    ('訧', (54, 104)),
+    ('訪', (18, 24)),
+    ('訬', (54, 105)),
+    ('設', (18, 29)),
+    ('訰', (54, 103)),
+    ('許', (18, 28)),
+    ('訴', (21, 4)),
+    ('訶', (21, 6)),
+    ('訹', (58, 118)),
+    ('診', (21, 5)),
+    ('註', (20, 151)),
+    ('証', (20, 155)),
+    ('訾', (23, 130)),
+    ('訿', (63, 9)),
+    ('詀', (58, 120)),
+    ('詁', (20, 156)),
+    ('詄', (58, 123)),
+    ('詅', (58, 124)),
+    ('詆', (21, 3)),
+    ('詈', (58, 126)),
+    ('詊', (58, 128)),
+    ('詌', (58, 129)),
+    ('詍', (58, 117)),
+    ('詎', (58, 116)),
+    ('詏', (58, 130)),
+    ('詐', (21, 2)),
+    ('詑', (58, 127)),
+    ('詒', (58, 125)),
+    ('詔', (21, 0)),
+    ('評', (20, 153)),
+    ('詖', (21, 7)),
+    ('詗', (58, 121)),
+    ('詘', (58, 122)),
+    ('詙', (58, 119)),
+    ('詛', (21, 1)),
+    ('詞', (20, 154)),
+    ('詠', (20, 152)),
+    ('詡', (63, 8)),
+    ('詢', (23, 125)),
+    ('詣', (23, 120)),
+    ('試', (23, 115)),
+    ('詨', (23, 131)),
+    ('詩', (23, 116)),
+    ('詫', (23, 112)),
+    ('詬', (23, 127)),
+    ('詭', (23, 124)),
+    ('詮', (23, 126)),
+    ('詰', (23, 117)),
+    ('話', (23, 122)),
+    ('該', (23, 113)),
+    ('詳', (23, 114)),
+    ('詴', (63, 16)),
+    ('詵', (63, 13)),
+    ('詶', (63, 5)),
+    ('詷', (63, 10)),
+    ('詹', (23, 128)),
+    ('詺', (63, 17)),
+    ('詻', (23, 129)),
+    ('詼', (23, 119)),
+    ('詿', (63, 7)),
+    ('誁', (63, 15)),
+    ('誂', (63, 11)),
+    ('誃', (63, 14)),
+    ('誄', (63, 12)),
+    ('誅', (23, 123)),
+    ('誆', (63, 6)),
+    ('誇', (23, 118)),
+    ('誋', (67, 12)),
+    ('誌', (26, 56)),
+    ('認', (26, 59)),
+    ('誏', (67, 14)),
+    ('誑', (26, 67)),
+    ('誒', (67, 13)),
+    ('誓', (26, 61)),
+    ('誕', (28, 109)),
+    ('誖', (67, 15)),
+    ('誘', (26, 66)),
+    ('誙', (67, 11)),
+    ('誚', (26, 68)),
+    ('語', (26, 57)),
+    ('誠', (23, 121)),
+    ('誡', (26, 60)),
+    ('誣', (26, 58)),
+    ('誤', (26, 62)),
+    ('誥', (26, 64)),
+    ('誦', (26, 55)),
+    ('誧', (26, 69)),
+    ('誨', (26, 65)),
+    ('說', (26, 63)),
+    ('誫', (67, 10)),
+    ('誰', (28, 116)),
+    ('課', (28, 112)),
+    ('誶', (28, 119)),
+    ('誸', (70, 116)),
+    ('誹', (28, 120)),
+    ('誺', (70, 128)),
+    ('誻', (70, 121)),
+    ('誼', (28, 105)),
+    ('誽', (70, 129)),
+    ('誾', (70, 123)),
+    ('調', (28, 115)),
+    ('諀', (70, 124)),
+    ('諂', (28, 114)),
+    ('諃', (70, 127)),
+    ('諄', (28, 108)),
+    ('諅', (70, 125)),
+    ('諆', (70, 115)),
+    ('談', (28, 107)),
+    ('諈', (74, 72)),
+    ('諉', (28, 113)),
+    ('請', (28, 110)),
+    ('諍', (28, 118)),
+    ('諏', (70, 114)),
+    ('諑', (70, 118)),
+    ('諒', (28, 106)),
+    ('諓', (70, 117)),
+    ('諔', (70, 119)),
+    ('諕', (70, 120)),
+    ('論', (28, 117)),
+    ('諗', (70, 122)),
+    ('諘', (70, 126)),
+    ('諙', (70, 130)),
+    ('諛', (28, 121)),
+    ('諜', (30, 112)),
+    ('諝', (74, 67)),
+    ('諞', (74, 73)),
+    ('諟', (74, 70)),
+    ('諠', (74, 62)),
+    ('諡', (74, 74)),
+    ('諢', (74, 63)),
+    ('諤', (74, 69)),
+    ('諦', (30, 107)),
+    ('諧', (30, 113)),
+    ('諨', (74, 75)),
+    ('諫', (30, 109)),
+    ('諭', (30, 119)),
+    ('諮', (30, 114)),
+    ('諯', (74, 77)),
+    ('諰', (74, 71)),
+    ('諱', (30, 110)),
+    ('諲', (74, 64)),
+    ('諳', (30, 120)),
+    ('諴', (74, 65)),
+    ('諵', (74, 66)),
+    ('諶', (30, 121)),
+    ('諷', (30, 118)),
+    ('諸', (28, 111)),
+    ('諺', (30, 108)),
+    ('諻', (74, 78)),
+    ('諼', (30, 122)),
+    ('諾', (30, 115)),
+    ('諿', (74, 76)),
+    ('謀', (30, 111)),
+    ('謁', (30, 116)),
+    ('謂', (30, 117)),
+    ('謄', (32, 97)),
+    ('謅', (77, 66)),
+    ('謆', (77, 75)),
+    ('謇', (77, 72)),
+    ('謈', (77, 74)),
+    ('謊', (32, 94)),
+    ('謋', (77, 67)),
+    ('謍', (77, 73)),
+    ('謎', (32, 90)),
+    ('謏', (77, 69)),
+    ('謐', (32, 98)),
+    ('謑', (77, 65)),
+    ('謒', (77, 70)),
+    ('謓', (77, 77)),
+    ('謔', (74, 68)),
+    ('謕', (77, 71)),
+    ('謖', (77, 64)),
+    ('謗', (32, 91)),
+    ('謘', (77, 63)),
+    ('謙', (32, 92)),
+    ('謚', (77, 78)),
+    ('講', (32, 93)),
+    ('謜', (77, 76)),
+    ('謝', (32, 96)),
+    ('謞', (77, 62)),
+    ('謠', (32, 95)),
+    ('謢', (77, 68)),
+    ('謣', (79, 122)),
+    ('謤', (79, 136)),
+    ('謥', (79, 131)),
+    ('謦', (79, 133)),
+    ('謧', (79, 121)),
+    ('謨', (33, 113)),
+    ('謪', (79, 120)),
+    ('謫', (33, 116)),
+    ('謬', (33, 115)),
+    ('謮', (79, 135)),
+    ('謯', (79, 127)),
+    ('謰', (79, 124)),
+    ('謱', (79, 130)),
+    ('謳', (79, 123)),
+    ('謵', (79, 125)),
+    ('謶', (79, 134)),
+    ('謷', (79, 132)),
+    ('謹', (33, 114)),
+    ('謺', (79, 139)),
+    ('謻', (79, 137)),
+    ('謼', (79, 128)),
+    ('謽', (79, 138)),
+    ('謾', (79, 129)),
+    ('譀', (81, 151)),
+    ('譁', (34, 109)),
+    ('譂', (82, 1)),
+    ('譅', (85, 15)),
+    ('譆', (34, 116)),
+    ('譇', (79, 126)),
+    ('譈', (81, 149)),
+    ('證', (34, 112)),
+    ('譊', (81, 150)),
+    ('譋', (81, 155)),
+    ('譎', (34, 114)),
+    ('譏', (34, 115)),
+    ('譐', (81, 148)),
+    ('譑', (82, 0)),
+    ('譒', (82, 2)),
+    ('譓', (81, 152)),
+    ('譔', (81, 154)),
+    ('譕', (81, 156)),
+    ('譖', (81, 153)),
+    ('譗', (82, 3)),
+    ('識', (34, 111)),
+    ('譙', (34, 117)),
+    ('譚', (34, 113)),
+    ('譜', (34, 110)),
+    ('譝', (83, 110)),
+    ('譟', (35, 85)),
+    ('譠', (83, 108)),
+    ('譣', (83, 112)),
+    ('譥', (83, 113)),
+    ('警', (35, 83)),
+    ('譧', (83, 114)),
+    ('譨', (83, 111)),
+    ('譪', (83, 109)),
+    ('譫', (35, 86)),
+    ('譬', (35, 82)),
+    ('譭', (83, 115)),
+    ('譯', (35, 84)),
+    ('議', (35, 81)),
+    ('譴', (35, 156)),
+    ('護', (36, 0)),
+    ('譸', (85, 14)),
+    ('譹', (85, 13)),
+    ('譺', (85, 16)),
+    ('譻', (85, 17)),
+    ('譽', (36, 1)),
+    ('譾', (86, 22)),
+    ('譿', (86, 27)),
+    ('讀', (36, 72)),
+    ('讂', (86, 24)),
+    ('讄', (86, 23)),
+    ('讅', (86, 26)),
+    ('讆', (86, 25)),
+    ('讈', (87, 3)),
+    ('變', (36, 122)),
+    ('讋', (87, 2)),
+    ('讌', (87, 0)),
+    ('讎', (87, 1)),
+    ('讒', (36, 156)),
+    ('讓', (36, 155)),
+    ('讔', (87, 95)),
+    ('讕', (87, 96)),
+    ('讖', (37, 0)),
+    ('讘', (88, 13)),
+    ('讙', (88, 14)),
+    ('讚', (37, 39)),
+    ('讜', (37, 45)),
+    ('讞', (88, 71)),
+    ('讟', (88, 102)),
+    ('谷', (7, 68)),
+    ('谹', (54, 107)),
+    ('谻', (54, 108)),
+    ('谼', (63, 18)),
+    ('谽', (67, 16)),
+    ('谾', (70, 131)),
+    ('谿', (32, 100)),
+    ('豁', (32, 99)),
+    ('豂', (79, 140)),
+    ('豃', (82, 4)),
+    ('豅', (87, 4)),
+    ('豆', (7, 69)),
+    ('豇', (50, 124)),
+    ('豈', (15, 26)),
+    ('豉', (18, 33)),
+    ('豊', (63, 20)),
+    ('豋', (63, 19)),
+    ('豌', (28, 122)),
+    ('豍', (70, 132)),
+    ('豎', (28, 123)),
+    ('豏', (77, 79)),
+    ('豐', (33, 117)),
+    ('豔', (37, 54)),
+    ('豕', (7, 70)),
+    ('豖', (44, 105)),
+    ('豗', (50, 125)),
+    ('豚', (18, 34)),
+    ('豜', (54, 109)),
+    ('豝', (54, 110)),
+    ('豟', (58, 131)),
+    ('象', (21, 8)),
+    ('豢', (23, 132)),
+    ('豤', (63, 22)),
+    ('豥', (63, 21)),
+    ('豦', (63, 23)),
+    ('豨', (67, 17)),
+    ('豩', (67, 18)),
+    ('豪', (26, 70)),
+    ('豫', (30, 123)),
+    ('豬', (28, 124)),
+    ('豭', (30, 124)),
+    ('豯', (77, 83)),
+    ('豰', (77, 80)),
+    ('豱', (77, 82)),
+    ('豲', (77, 81)),
+    ('豳', (32, 101)),
+    ('豵', (79, 141)),
+    ('豶', (82, 6)),
+    ('豷', (82, 5)),
+    ('豸', (42, 66)),
+    ('豹', (15, 28)),
+    ('豺', (15, 27)),
+    ('豻', (50, 126)),
+    ('豽', (54, 111)),
+    ('貀', (58, 133)),
+    ('貁', (58, 132)),
+    ('貂', (21, 9)),
+    ('貄', (63, 25)),
+    ('貅', (63, 26)),
+    ('貆', (63, 24)),
+    ('貉', (23, 134)),
+    ('貊', (23, 133)),
+    ('貌', (26, 72)),
+    ('貍', (26, 71)),
+    ('貏', (70, 133)),
+    ('貐', (74, 81)),
+    ('貑', (74, 79)),
+    ('貒', (74, 80)),
+    ('貓', (30, 125)),
+    ('貔', (77, 85)),
+    ('貕', (77, 84)),
+    ('貗', (79, 144)),
+    ('貘', (79, 143)),
+    ('貙', (79, 142)),
+    ('貚', (82, 7)),
+    ('貜', (88, 72)),
+    ('貝', (7, 71)),
+    ('貞', (12, 51)),
+    ('負', (12, 52)),
+    ('財', (15, 29)),
+    ('貢', (15, 30)),
+    ('貣', (50, 128)),
+    ('貤', (50, 127)),
+    ('貥', (54, 112)),
+    ('貧', (18, 40)),
+    ('貨', (18, 38)),
+    ('販', (18, 35)),
+    ('貪', (18, 39)),
+    ('貫', (18, 37)),
+    ('責', (18, 36)),
+    ('貯', (21, 10)),
+    ('貰', (58, 136)),
+    ('貲', (23, 139)),
+    ('貳', (21, 12)),
+    ('貴', (21, 17)),
+    ('貵', (58, 138)),
+    ('貶', (21, 19)),
+    ('買', (21, 18)),
+    ('貸', (21, 21)),
+    ('貹', (58, 137)),
+    ('貺', (58, 134)),
+    ('費', (21, 15)),
+    ('貼', (21, 11)),
+    ('貽', (21, 13)),
+    ('貾', (58, 135)),
+    ('貿', (21, 20)),
+    ('賀', (21, 16)),
+    ('賁', (21, 14)),
+    ('賂', (23, 141)),
+    ('賃', (23, 140)),
+    ('賄', (23, 138)),
+    ('賅', (23, 142)),
+    ('資', (23, 136)),
+    ('賈', (23, 137)),
+    ('賊', (23, 135)),
+    ('賌', (63, 27)),
+    ('賏', (67, 20)),
+    ('賑', (26, 74)),
+    ('賒', (26, 75)),
+    ('賓', (26, 73)),
+    ('賕', (67, 19)),
+    ('賗', (67, 21)),
+    ('賙', (70, 136)),
+    ('賚', (70, 138)),
+    ('賜', (28, 133)),
+    ('賝', (70, 139)),
+    ('賞', (28, 126)),
+    ('賟', (70, 135)),
+    ('賠', (28, 125)),
+    ('賡', (28, 135)),
+    ('賢', (28, 131)),
+    ('賣', (28, 132)),
+    ('賤', (28, 128)),
+    ('賥', (70, 134)),
+    ('賦', (28, 127)),
+    ('賧', (70, 140)),
+    ('賨', (70, 137)),
+    ('質', (28, 134)),
+    ('賬', (28, 129)),
+    ('賭', (28, 130)),
+    ('賮', (74, 83)),
+    ('賰', (74, 85)),
+    ('賱', (74, 84)),
+    ('賳', (74, 86)),
+    ('賴', (30, 126)),
+    ('賵', (74, 82)),
+    ('賸', (32, 105)),
+    ('賹', (77, 86)),
+    ('賺', (32, 102)),
+    ('賻', (32, 106)),
+    ('購', (32, 104)),
+    ('賽', (32, 103)),
+    ('賾', (79, 145)),
+    ('贀', (79, 148)),
+    ('贂', (79, 147)),
+    ('贄', (79, 146)),
+    ('贅', (33, 118)),
+    ('贆', (82, 8)),
+    ('贇', (82, 9)),
+    ('贈', (34, 118)),
+    ('贉', (82, 10)),
+    ('贊', (34, 119)),
+    ('贍', (35, 88)),
+    ('贏', (35, 87)),
+    ('贐', (85, 18)),
+    ('贓', (36, 2)),
+    ('贔', (85, 19)),
+    ('贕', (86, 28)),
+    ('贖', (36, 73)),
+    ('贗', (36, 74)),
+    ('贙', (87, 5)),
+    ('贛', (37, 2)),
+    ('赤', (7, 72)),
+    ('赦', (18, 42)),
+    ('赧', (18, 41)),
+    ('赨', (63, 28)),
+    ('赩', (63, 29)),
+    ('赫', (26, 76)),
+    ('赬', (74, 87)),
+    ('赭', (28, 136)),
+    ('赮', (74, 88)),
+    ('赯', (77, 87)),
+    ('走', (7, 73)),
+    ('赲', (47, 80)),
+    ('赳', (12, 54)),
+    ('赴', (12, 53)),
+    ('赶', (50, 129)),
+    ('起', (15, 31)),
+    ('赸', (50, 130)),
+    ('赹', (54, 115)),
+    ('赻', (54, 114)),
+    ('赽', (54, 113)),
+    ('趀', (58, 140)),
+    ('趁', (21, 24)),
+    ('趄', (58, 139)),
+    ('超', (21, 23)),
+    ('趉', (58, 141)),
+    ('越', (21, 22)),
+    ('趌', (63, 31)),
+    ('趍', (63, 34)),
+    ('趎', (63, 32)),
+    ('趏', (63, 33)),
+    ('趐', (63, 37)),
+    ('趑', (63, 30)),
+    ('趒', (63, 38)),
+    ('趓', (63, 35)),
+    ('趔', (63, 36)),
+    ('趕', (26, 78)),
+    ('趖', (67, 22)),
+    ('趙', (26, 77)),
+    ('趛', (70, 144)),
+    ('趜', (70, 142)),
+    ('趟', (28, 137)),
+    ('趠', (70, 141)),
+    ('趡', (70, 143)),
+    ('趣', (28, 138)),
+    ('趥', (74, 89)),
+    ('趧', (74, 90)),
+    ('趨', (32, 107)),
+    ('趪', (82, 12)),
+    ('趫', (82, 14)),
+    ('趬', (82, 11)),
+    ('趭', (82, 13)),
+    ('趮', (83, 116)),
+    ('趯', (85, 20)),
+    ('趲', (88, 47)),
+    ('足', (7, 74)),
+    ('趴', (12, 55)),
+    ('趵', (50, 131)),
+    ('趶', (50, 133)),
+    ('趷', (50, 132)),
+    ('趹', (54, 118)),
+    ('趺', (18, 44)),
+    ('趼', (54, 116)),
+    ('趾', (18, 43)),
+    ('趿', (54, 119)),
+    ('跁', (54, 120)),
+    ('跂', (54, 117)),
+    ('跅', (58, 153)),
+    ('跆', (21, 32)),
+    ('跇', (58, 145)),
+    ('跈', (58, 151)),
+    ('跋', (21, 27)),
+    ('跌', (21, 30)),
+    ('跍', (58, 144)),
+    ('跎', (21, 25)),
+    ('跏', (58, 148)),
+    ('跐', (63, 44)),
+    ('跑', (21, 29)),
+    ('跓', (58, 143)),
+    ('跕', (58, 149)),
+    ('跖', (58, 146)),
+    ('跗', (58, 152)),
+    ('跘', (58, 142)),
+    ('跙', (58, 150)),
+    ('跚', (21, 28)),
+    ('跛', (21, 31)),
+    ('跜', (58, 147)),
+    ('距', (21, 26)),
+    ('跟', (23, 144)),
+    ('跠', (63, 40)),
+    ('跡', (23, 143)),
+    ('跢', (63, 47)),
+    ('跣', (63, 46)),
+    ('跤', (23, 150)),
+    ('跦', (23, 151)),
+    ('跧', (63, 48)),
+    ('跨', (23, 145)),
+    ('跩', (63, 45)),
+    ('跪', (23, 149)),
+    ('跫', (63, 50)),
+    ('跬', (63, 41)),
+    ('跮', (63, 43)),
+    ('路', (23, 146)),
+    ('跰', (63, 39)),
+    ('跱', (63, 42)),
+    ('跲', (63, 49)),
+    ('跳', (23, 147)),
+    ('跴', (63, 51)),
+    ('跺', (23, 148)),
+    ('跼', (26, 79)),
+    ('跽', (67, 27)),
+    ('跾', (67, 33)),
+    ('跿', (67, 25)),
+    ('踀', (67, 34)),
+    ('踂', (67, 24)),
+    ('踃', (67, 29)),
+    ('踄', (67, 35)),
+    ('踅', (67, 32)),
+    ('踆', (67, 31)),
+    ('踇', (67, 30)),
+    ('踉', (67, 23)),
+    ('踊', (67, 28)),
+    ('踍', (67, 26)),
+    ('踏', (28, 143)),
+    ('踐', (28, 140)),
+    ('踑', (70, 153)),
+    ('踒', (71, 1)),
+    ('踓', (71, 3)),
+    ('踔', (71, 0)),
+    ('踕', (70, 150)),
+    ('踖', (70, 152)),
+    ('踗', (71, 5)),
+    ('踘', (71, 2)),
+    ('踙', (70, 154)),
+    ('踚', (71, 6)),
+    ('踛', (70, 151)),
+    ('踜', (71, 4)),
+    ('踝', (28, 141)),
+    ('踞', (28, 147)),
+    ('踟', (28, 145)),
+    ('踠', (70, 145)),
+    ('踡', (28, 146)),
+    ('踢', (28, 142)),
+    ('踣', (70, 146)),
+    ('踤', (70, 148)),
+    ('踥', (70, 147)),
+    ('踦', (70, 155)),
+    ('踧', (70, 156)),
+    ('踩', (28, 144)),
+    ('踫', (28, 139)),
+    ('踮', (70, 149)),
+    ('踰', (74, 100)),
+    ('踱', (30, 128)),
+    ('踳', (74, 91)),
+    ('踴', (30, 129)),
+    ('踵', (30, 132)),
+    ('踶', (74, 96)),
+    ('踸', (74, 93)),
+    ('踹', (30, 131)),
+    ('踼', (74, 97)),
+    ('踽', (74, 98)),
+    ('踾', (74, 92)),
+    ('踿', (74, 101)),
+    ('蹀', (74, 94)),
+    ('蹁', (74, 99)),
+    ('蹂', (30, 130)),
+    ('蹄', (30, 127)),
+    ('蹅', (74, 95)),
+    ('蹇', (77, 93)),
+    ('蹈', (32, 110)),
+    ('蹉', (32, 108)),
+    ('蹊', (32, 111)),
+    ('蹋', (32, 109)),
+    ('蹌', (77, 92)),
+    ('蹍', (77, 89)),
+    ('蹎', (77, 88)),
+    ('蹐', (77, 91)),
+    ('蹓', (77, 90)),
+    ('蹔', (80, 5)),
+    ('蹕', (33, 124)),
+    ('蹖', (79, 153)),
+    ('蹗', (79, 152)),
+    ('蹙', (33, 119)),
+    ('蹚', (80, 1)),
+    ('蹛', (80, 0)),
+    ('蹜', (79, 149)),
+    ('蹝', (80, 3)),
+    ('蹞', (79, 154)),
+    ('蹟', (33, 123)),
+    ('蹠', (79, 151)),
+    ('蹡', (80, 2)),
+    ('蹢', (79, 150)),
+    ('蹣', (33, 120)),
+    ('蹤', (33, 122)),
+    ('蹥', (79, 155)),
+    ('蹦', (33, 121)),
+    ('蹧', (79, 156)),
+    ('蹩', (80, 4)),
+    ('蹪', (82, 18)),
+    ('蹬', (34, 124)),
+    ('蹭', (82, 15)),
+    ('蹯', (82, 19)),
+    ('蹲', (34, 121)),
+    ('蹳', (82, 17)),
+    ('蹴', (34, 126)),
+    ('蹶', (34, 123)),
+    ('蹸', (82, 16)),
+    ('蹺', (34, 125)),
+    ('蹻', (82, 20)),
+    ('蹼', (34, 120)),
+    ('躁', (35, 90)),
+    ('躂', (35, 92)),
+    ('躄', (83, 119)),
+    ('躅', (35, 91)),
+    ('躆', (83, 117)),
+    ('躇', (34, 122)),
+    ('躈', (83, 118)),
+    ('躉', (35, 89)),
+    ('躊', (36, 3)),
+    ('躋', (36, 5)),
+    ('躌', (85, 22)),
+    ('躍', (36, 4)),
+    ('躎', (85, 21)),
+    ('躐', (86, 33)),
+    ('躑', (36, 75)),
+    ('躒', (86, 32)),
+    ('躓', (36, 76)),
+    ('躔', (86, 30)),
+    ('躕', (86, 29)),
+    ('躖', (86, 34)),
+    ('躗', (86, 35)),
+    ('躘', (87, 6)),
+    ('躚', (86, 31)),
+    ('躝', (87, 100)),
+    ('躞', (87, 97)),
+    ('躟', (87, 98)),
+    ('躠', (87, 99)),
+    ('躡', (37, 28)),
+    ('躣', (88, 17)),
+    ('躤', (88, 16)),
+    ('躥', (88, 15)),
+    ('躦', (88, 48)),
+    ('躨', (88, 92)),
+    ('躩', (88, 73)),
+    ('躪', (37, 46)),
+    ('身', (7, 75)),
+    ('躬', (15, 32)),
+    ('躲', (23, 152)),
+    ('躺', (28, 148)),
+    ('躽', (74, 102)),
+    ('軀', (33, 125)),
+    ('軂', (82, 21)),
+    ('軉', (88, 74)),
+    ('車', (7, 76)),
+    ('軋', (9, 140)),
+    ('軌', (12, 57)),
+    ('軍', (12, 56)),
+    ('軏', (15, 35)),
+    ('軑', (50, 134)),
+    ('軒', (15, 33)),
+    ('軓', (50, 135)),
+    ('軔', (15, 34)),
+    ('軗', (54, 125)),
+    ('軘', (54, 121)),
+    ('軛', (18, 45)),
+    ('軜', (54, 124)),
+    ('軝', (54, 123)),
+    ('軞', (54, 122)),
+    ('軟', (18, 46)),
+    ('軠', (54, 126)),
+    ('軡', (54, 127)),
+    ('軥', (59, 3)),
+    ('軦', (59, 1)),
+    ('軧', (59, 5)),
+    ('軨', (59, 6)),
+    ('軩', (59, 12)),
+    ('軫', (59, 8)),
+    ('軬', (59, 10)),
+    ('軮', (59, 2)),
+    ('軯', (58, 154)),
+    ('軱', (59, 9)),
+    ('軴', (59, 11)),
+    ('軵', (59, 4)),
+    ('軶', (59, 7)),
+    ('軷', (58, 155)),
+    ('軸', (21, 34)),
+    ('軹', (59, 0)),
+    ('軺', (58, 156)),
+    ('軻', (21, 33)),
+    ('軼', (21, 35)),
+    ('軾', (23, 155)),
+    ('軿', (63, 53)),
+    ('輀', (63, 55)),
+    ('輁', (63, 54)),
+    ('輂', (63, 59)),
+    ('較', (23, 153)),
+    ('輅', (63, 56)),
+    ('輆', (63, 52)),
+    ('輇', (63, 57)),
+    ('輈', (63, 58)),
+    ('載', (23, 154)),
+    ('輊', (23, 156)),
+    ('輋', (63, 60)),
+    ('輍', (67, 39)),
+    ('輎', (67, 38)),
+    ('輐', (67, 36)),
+    ('輑', (67, 37)),
+    ('輒', (26, 81)),
+    ('輓', (26, 83)),
+    ('輔', (26, 80)),
+    ('輕', (26, 82)),
+    ('輖', (71, 13)),
+    ('輗', (71, 14)),
+    ('輘', (71, 9)),
+    ('輚', (71, 10)),
+    ('輛', (28, 150)),
+    ('輜', (28, 155)),
+    ('輝', (28, 149)),
+    ('輞', (28, 156)),
+    ('輟', (28, 151)),
+    ('輠', (71, 11)),
+    ('輣', (71, 12)),
+    ('輤', (71, 8)),
+    ('輥', (29, 0)),
+    ('輦', (28, 153)),
+    ('輩', (28, 152)),
+    ('輪', (28, 154)),
+    ('輬', (71, 7)),
+    ('輮', (74, 104)),
+    ('輯', (30, 134)),
+    ('輲', (74, 106)),
+    ('輳', (30, 136)),
+    ('輴', (74, 109)),
+    ('輵', (74, 105)),
+    ('輶', (74, 103)),
+    ('輷', (74, 108)),
+    ('輸', (30, 135)),
+    ('輹', (74, 107)),
+    ('輻', (30, 133)),
+    ('輾', (32, 113)),
+    ('輿', (32, 116)),
+    ('轀', (77, 95)),
+    ('轂', (32, 114)),
+    ('轃', (77, 94)),
+    ('轄', (32, 112)),
+    ('轅', (32, 115)),
+    ('轆', (80, 6)),
+    ('轇', (80, 7)),
+    ('轈', (80, 8)),
+    ('轉', (33, 126)),
+    ('轋', (80, 9)),
+    ('轍', (33, 127)),
+    ('轎', (34, 128)),
+    ('轏', (82, 24)),
+    ('轐', (82, 25)),
+    ('轑', (82, 23)),
+    ('轒', (82, 22)),
+    ('轓', (82, 26)),
+    ('轔', (34, 127)),
+    ('轕', (83, 123)),
+    ('轖', (83, 121)),
+    ('轗', (83, 122)),
+    ('轘', (83, 124)),
+    ('轙', (83, 120)),
+    ('轚', (83, 125)),
+    ('轛', (85, 24)),
+    ('轝', (85, 25)),
+    ('轞', (85, 23)),
+    ('轟', (36, 6)),
+    ('轠', (86, 36)),
+    ('轡', (36, 77)),
+    ('轢', (86, 37)),
+    ('轣', (87, 8)),
+    ('轤', (87, 7)),
+    ('辛', (7, 77)),
+    ('辜', (21, 36)),
+    ('辟', (24, 0)),
+    ('辣', (26, 84)),
+    ('辦', (30, 138)),
+    ('辨', (30, 137)),
+    ('辭', (34, 129)),
+    ('辮', (35, 60)),
+    ('辯', (36, 7)),
+    ('辰', (7, 78)),
+    ('辱', (15, 36)),
+    ('農', (24, 1)),
+    ('辴', (82, 27)),
+    ('辿', (42, 68)),
+    ('迂', (7, 79)),
+    ('迄', (7, 82)),
+    ('迅', (7, 81)),
+    ('迆', (7, 80)),
+    ('迉', (42, 67)),
+    ('迋', (44, 107)),
+    ('迍', (44, 109)),
+    ('迎', (9, 141)),
+    ('近', (9, 143)),
+    ('迒', (44, 106)),
+    ('迓', (44, 108)),
+    ('返', (9, 142)),
+    ('迕', (44, 111)),
+    ('迖', (44, 110)),
+    ('迗', (44, 112)),
+    ('迠', (47, 84)),
+    ('迡', (47, 82)),
+    ('迢', (12, 60)),
+    ('迣', (47, 81)),
+    ('迤', (12, 65)),
+    ('迥', (12, 62)),
+    ('迦', (12, 59)),
+    ('迨', (12, 66)),
+    ('迪', (12, 61)),
+    ('迫', (12, 64)),
+    ('迭', (12, 63)),
+    ('迮', (47, 83)),
+    ('述', (12, 58)),
+    ('迴', (15, 42)),
+    ('迵', (50, 137)),
+    ('迶', (50, 143)),
+    ('迷', (15, 39)),
+    ('迸', (15, 46)),
+    ('迺', (15, 41)),
+    ('迻', (50, 140)),
+    ('迼', (50, 142)),
+    ('追', (15, 44)),
+    ('迾', (50, 136)),
+    ('迿', (50, 139)),
+    ('退', (15, 40)),
+    ('送', (15, 37)),
+    ('适', (50, 138)),
+    ('逃', (15, 43)),
+    ('逄', (50, 141)),
+    ('逅', (15, 45)),
+    ('逆', (15, 38)),
+    ('逋', (54, 129)),
+    ('逌', (54, 132)),
+    ('逍', (18, 48)),
+    ('透', (18, 58)),
+    ('逐', (18, 54)),
+    ('逑', (54, 130)),
+    ('途', (18, 62)),
+    ('逕', (18, 55)),
+    ('逖', (18, 60)),
+    ('逗', (18, 50)),
+    ('這', (18, 47)),
+    ('通', (18, 49)),
+    ('逛', (18, 61)),
+    ('逜', (54, 131)),
+    ('逝', (18, 53)),
+    ('逞', (18, 56)),
+    ('速', (18, 52)),
+    ('造', (18, 57)),
+    ('逡', (54, 133)),
+    ('逢', (18, 59)),
+    ('連', (18, 51)),
+    ('逤', (54, 128)),
+    ('逭', (59, 13)),
+    ('逮', (21, 37)),
+    ('逯', (59, 15)),
+    ('週', (21, 39)),
+    ('進', (21, 41)),
+    ('逴', (59, 14)),
+    ('逵', (21, 38)),
+    ('逶', (21, 42)),
+    ('逸', (21, 40)),
+    ('逼', (24, 7)),
+    ('逽', (63, 65)),
+    ('逾', (24, 15)),
+    ('逿', (63, 62)),
+    ('遁', (24, 16)),
+    ('遂', (24, 5)),
+    ('遄', (63, 63)),
+    ('遇', (24, 10)),
+    ('遉', (63, 64)),
+    ('遊', (24, 3)),
+    ('運', (24, 2)),
+    ('遍', (24, 13)),
+    ('過', (24, 12)),
+    ('遏', (24, 11)),
+    ('遐', (24, 9)),
+    ('遑', (24, 14)),
+    ('遒', (63, 61)),
+    ('道', (24, 4)),
+    ('達', (24, 6)),
+    ('違', (24, 8)),
+    ('遘', (26, 86)),
+    ('遙', (26, 89)),
+    ('遛', (26, 93)),
+    ('遜', (26, 87)),
+    ('遝', (26, 92)),
+    ('遞', (26, 90)),
+    ('遠', (26, 85)),
+    ('遢', (26, 91)),
+    ('遣', (26, 88)),
+    ('遧', (71, 18)),
+    ('遨', (29, 3)),
+    ('適', (29, 1)),
+    ('遫', (71, 19)),
+    ('遭', (29, 4)),
+    ('遮', (29, 2)),
+    ('遯', (71, 17)),
+    ('遰', (71, 16)),
+    ('遲', (30, 142)),
+    ('遳', (71, 15)),
+    ('遴', (30, 140)),
+    ('遵', (30, 139)),
+    ('遶', (74, 110)),
+    ('遷', (29, 5)),
+    ('選', (30, 141)),
+    ('遹', (74, 111)),
+    ('遺', (30, 144)),
+    ('遻', (74, 112)),
+    ('遼', (30, 143)),
+    ('遽', (32, 118)),
+    ('遾', (77, 97)),
+    ('避', (32, 117)),
+    ('邀', (32, 122)),
+    ('邁', (32, 120)),
+    ('邂', (32, 121)),
+    ('邃', (33, 129)),
+    ('還', (32, 119)),
+    ('邅', (77, 96)),
+    ('邆', (74, 113)),
+    ('邇', (33, 128)),
+    ('邈', (33, 130)),
+    ('邊', (34, 130)),
+    ('邋', (34, 131)),
+    ('邍', (83, 126)),
+    ('邏', (36, 124)),
+    ('邐', (36, 123)),
+    ('邑', (7, 84)),
+    ('邔', (41, 21)),
+    ('邕', (15, 47)),
+    ('邗', (41, 18)),
+    ('邘', (41, 19)),
+    ('邙', (41, 17)),
+    ('邛', (41, 20)),
+    ('邞', (42, 72)),
+    ('邟', (42, 69)),
+    ('邠', (42, 74)),
+    ('邡', (42, 70)),
+    ('邢', (7, 85)),
+    ('那', (7, 88)),
+    ('邥', (42, 71)),
+    ('邦', (7, 87)),
+    ('邧', (42, 73)),
+    ('邪', (7, 86)),
+    ('邯', (44, 115)),
+    ('邰', (44, 117)),
+    ('邱', (9, 146)),
+    ('邲', (44, 113)),
+    ('邳', (44, 116)),
+    ('邴', (44, 114)),
+    ('邵', (9, 144)),
+    ('邶', (9, 147)),
+    ('邸', (9, 145)),
+    ('邽', (47, 86)),
+    ('邾', (47, 90)),
+    ('邿', (47, 87)),
+    ('郁', (12, 69)),
+    ('郃', (12, 70)),
+    ('郅', (47, 89)),
+    ('郇', (47, 91)),
+    ('郈', (47, 93)),
+    ('郊', (12, 67)),
+    ('郋', (47, 92)),
+    ('郎', (12, 68)),
+    ('郔', (54, 140)),
+    ('郕', (47, 88)),
+    ('郖', (50, 144)),
+    ('郗', (50, 153)),
+    ('郘', (50, 151)),
+    ('郙', (50, 146)),
+    ('郚', (50, 147)),
+    ('郛', (50, 152)),
+    ('郜', (50, 154)),
+    ('郝', (15, 49)),
+    ('郟', (50, 149)),
+    ('郠', (50, 145)),
+    ('郡', (15, 48)),
+    ('郢', (15, 50)),
+    ('郣', (50, 148)),
+    ('郤', (50, 155)),
+    ('郥', (50, 150)),
+    ('部', (18, 63)),
+    ('郩', (54, 143)),
+    ('郪', (54, 135)),
+    ('郫', (54, 141)),
+    ('郬', (54, 142)),
+    ('郭', (18, 64)),
+    ('郯', (54, 134)),
+    ('郰', (54, 136)),
+    ('郱', (47, 85)),
+    ('郲', (54, 138)),
+    ('郳', (54, 139)),
+    ('郴', (54, 137)),
+    ('郵', (21, 44)),
+    ('郹', (59, 22)),
+    ('郺', (74, 114)),
+    ('郻', (59, 23)),
+    ('郼', (59, 20)),
+    ('都', (18, 65)),
+    ('郾', (21, 46)),
+    ('郿', (59, 19)),
+    ('鄀', (59, 25)),
+    ('鄁', (59, 24)),
+    ('鄂', (21, 43)),
+    ('鄃', (59, 28)),
+    ('鄄', (59, 18)),
+    ('鄅', (59, 27)),
+    ('鄆', (59, 16)),
+    ('鄇', (59, 26)),
+    ('鄈', (59, 21)),
+    ('鄉', (21, 45)),
+    ('鄋', (63, 72)),
+    ('鄍', (63, 67)),
+    ('鄎', (63, 73)),
+    ('鄏', (63, 68)),
+    ('鄐', (63, 66)),
+    ('鄑', (63, 69)),
+    ('鄒', (24, 17)),
+    ('鄔', (63, 71)),
+    ('鄖', (63, 70)),
+    ('鄗', (24, 18)),
+    ('鄘', (26, 95)),
+    ('鄙', (26, 94)),
+    ('鄚', (67, 46)),
+    ('鄛', (67, 49)),
+    ('鄜', (67, 41)),
+    ('鄝', (67, 45)),
+    ('鄞', (26, 96)),
+    ('鄟', (67, 44)),
+    ('鄠', (67, 42)),
+    ('鄡', (67, 48)),
+    ('鄢', (67, 43)),
+    ('鄣', (67, 40)),
+    ('鄤', (67, 47)),
+    ('鄦', (71, 25)),
+    ('鄧', (29, 8)),
+    ('鄨', (80, 10)),
+    ('鄩', (71, 22)),
+    ('鄪', (71, 23)),
+    ('鄫', (71, 21)),
+    ('鄬', (59, 17)),
+    ('鄭', (29, 7)),
+    ('鄮', (71, 26)),
+    ('鄯', (71, 20)),
+    ('鄰', (29, 6)),
+    ('鄱', (29, 9)),
+    ('鄲', (71, 24)),
+    ('鄳', (74, 115)),
+    ('鄴', (30, 145)),
+    ('鄵', (74, 116)),
+    ('鄶', (74, 117)),
+    ('鄸', (77, 98)),
+    ('鄹', (32, 123)),
+    ('鄺', (80, 11)),
+    ('鄻', (80, 12)),
+    ('鄾', (80, 13)),
+    ('鄿', (82, 29)),
+    ('酀', (82, 28)),
+    ('酁', (83, 128)),
+    ('酃', (83, 127)),
+    ('酄', (85, 27)),
+    ('酅', (85, 28)),
+    ('酆', (85, 26)),
+    ('酇', (86, 38)),
+    ('酈', (36, 78)),
+    ('酉', (7, 89)),
+    ('酊', (12, 72)),
+    ('酋', (12, 71)),
+    ('酌', (15, 53)),
+    ('配', (15, 52)),
+    ('酎', (51, 0)),
+    ('酏', (51, 1)),
+    ('酐', (50, 156)),
+    ('酒', (15, 51)),
+    ('酓', (54, 147)),
+    ('酕', (54, 148)),
+    ('酖', (54, 144)),
+    ('酗', (18, 66)),
+    ('酘', (54, 145)),
+    ('酚', (54, 146)),
+    ('酟', (59, 31)),
+    ('酠', (59, 33)),
+    ('酡', (59, 29)),
+    ('酢', (59, 32)),
+    ('酣', (21, 47)),
+    ('酤', (59, 30)),
+    ('酥', (21, 48)),
+    ('酨', (60, 51)),
+    ('酩', (24, 21)),
+    ('酪', (24, 20)),
+    ('酬', (24, 19)),
+    ('酮', (63, 74)),
+    ('酯', (63, 75)),
+    ('酲', (67, 51)),
+    ('酳', (67, 53)),
+    ('酴', (26, 100)),
+    ('酵', (26, 97)),
+    ('酷', (26, 99)),
+    ('酸', (26, 98)),
+    ('酹', (67, 52)),
+    ('酺', (67, 50)),
+    ('醀', (71, 33)),
+    ('醁', (71, 30)),
+    ('醂', (71, 31)),
+    ('醃', (29, 13)),
+    ('醄', (71, 32)),
+    ('醅', (71, 27)),
+    ('醆', (71, 28)),
+    ('醇', (29, 10)),
+    ('醉', (29, 11)),
+    ('醊', (71, 29)),
+    ('醋', (29, 12)),
+    ('醍', (74, 121)),
+    ('醏', (74, 122)),
+    ('醐', (74, 119)),
+    ('醑', (74, 120)),
+    ('醒', (30, 146)),
+    ('醓', (74, 118)),
+    ('醙', (77, 102)),
+    ('醚', (77, 99)),
+    ('醛', (77, 101)),
+    ('醜', (32, 126)),
+    ('醝', (77, 105)),
+    ('醞', (32, 125)),
+    ('醟', (77, 103)),
+    ('醠', (77, 106)),
+    ('醡', (77, 104)),
+    ('醢', (77, 100)),
+    ('醣', (32, 124)),
+    ('醥', (80, 15)),
+    ('醧', (80, 16)),
+    ('醨', (80, 14)),
+    ('醪', (80, 18)),
+    ('醫', (33, 131)),
+    ('醬', (33, 132)),
+    ('醭', (82, 31)),
+    ('醮', (34, 133)),
+    ('醯', (80, 17)),
+    ('醰', (82, 30)),
+    ('醱', (34, 132)),
+    ('醲', (83, 131)),
+    ('醳', (83, 132)),
+    ('醴', (35, 93)),
+    ('醵', (83, 130)),
+    ('醷', (83, 129)),
+    ('醹', (85, 29)),
+    ('醺', (36, 8)),
+    ('醼', (87, 9)),
+    ('醽', (87, 102)),
+    ('醾', (87, 101)),
+    ('釀', (37, 3)),
+    ('釁', (37, 29)),
+    ('釂', (87, 103)),
+    ('釃', (88, 49)),
+    ('釅', (37, 47)),
+    ('釆', (7, 90)),
+    ('采', (9, 148)),
+    ('釉', (24, 22)),
+    ('釋', (35, 94)),
+    ('里', (7, 91)),
+    ('重', (12, 73)),
+    ('野', (18, 67)),
+    ('量', (21, 49)),
+    ('釐', (33, 133)),
+    ('金', (9, 149)),
+    ('釓', (47, 95)),
+    ('釔', (47, 94)),
+    ('釕', (51, 2)),
+    ('釗', (15, 56)),
+    ('釘', (15, 54)),
+    ('釙', (15, 58)),
+    ('釚', (51, 4)),
+    ('釜', (15, 57)),
+    ('針', (15, 55)),
+    ('釢', (51, 3)),
+    ('釣', (18, 70)),
+    ('釤', (54, 154)),
+    ('釦', (18, 69)),
+    ('釧', (18, 71)),
+    ('釨', (55, 2)),
+    ('釩', (18, 73)),
+    ('釪', (54, 156)),
+    ('釫', (55, 0)),
+    ('釬', (54, 149)),
+    ('釭', (18, 72)),
+    ('釮', (55, 3)),
+    ('釱', (54, 151)),
+    ('釳', (54, 152)),
+    ('釴', (54, 150)),
+    ('釵', (18, 68)),
+    ('釷', (55, 1)),
+    ('釸', (54, 153)),
+    ('釹', (54, 155)),
+    ('釽', (59, 45)),
+    ('釿', (59, 44)),
+    ('鈀', (59, 42)),
+    ('鈁', (59, 34)),
+    ('鈂', (59, 49)),
+    ('鈃', (59, 37)),
+    ('鈄', (59, 47)),
+    ('鈅', (59, 54)),
+    ('鈆', (59, 46)),
+    ('鈇', (21, 57)),
+    ('鈉', (21, 53)),
+    ('鈊', (59, 35)),
+    ('鈌', (59, 41)),
+    ('鈍', (21, 55)),
+    ('鈏', (59, 40)),
+    ('鈐', (21, 56)),
+    ('鈑', (21, 58)),
+    ('鈒', (59, 43)),
+    ('鈔', (21, 50)),
+    ('鈕', (21, 51)),
+    ('鈖', (59, 55)),
+    ('鈗', (59, 53)),
+    ('鈙', (59, 52)),
+    ('鈚', (59, 38)),
+    ('鈜', (59, 50)),
+    ('鈞', (21, 54)),
+    ('鈣', (21, 52)),
+    ('鈤', (59, 51)),
+    ('鈥', (59, 36)),
+    ('鈦', (59, 39)),
+    ('鈧', (59, 48)),
+    ('鈭', (67, 88)),
+    ('鈮', (63, 85)),
+    ('鈰', (63, 78)),
+    ('鈱', (63, 97)),
+    ('鈲', (63, 106)),
+    ('鈳', (63, 81)),
+    ('鈴', (24, 33)),
+    ('鈶', (63, 94)),
+    ('鈷', (24, 23)),
+    ('鈸', (24, 25)),
+    ('鈹', (24, 37)),
+    ('鈺', (63, 79)),
+    ('鈽', (24, 26)),
+    ('鈾', (24, 28)),
+    ('鈿', (24, 38)),
+    ('鉀', (24, 27)),
+    ('鉅', (24, 36)),
+    ('鉆', (63, 87)),
+    ('鉈', (63, 76)),
+    ('鉉', (24, 34)),
+    ('鉊', (63, 86)),
+    ('鉋', (24, 30)),
+    ('鉌', (63, 104)),
+    ('鉍', (24, 35)),
+    ('鉎', (63, 102)),
+    ('鉏', (63, 90)),
+    ('鉐', (63, 100)),
+    ('鉑', (24, 32)),
+    ('鉒', (63, 77)),
+    ('鉓', (63, 103)),
+    ('鉔', (63, 98)),
+    ('鉖', (63, 105)),
+    ('鉗', (24, 24)),
+    ('鉚', (24, 39)),
+    ('鉛', (24, 29)),
+    ('鉞', (63, 83)),
+    ('鉠', (63, 91)),
+    ('鉡', (63, 95)),
+    ('鉣', (63, 99)),
+    ('鉤', (24, 31)),
+    ('鉥', (63, 82)),
+    ('鉦', (63, 80)),
+    ('鉧', (63, 92)),
+    ('鉬', (63, 89)),
+    ('鉭', (63, 88)),
+    ('鉯', (63, 93)),
+    ('鉰', (63, 96)),
+    ('鉲', (63, 101)),
+    ('鉶', (67, 56)),
+    ('鉸', (26, 101)),
+    ('鉹', (67, 66)),
+    ('鉺', (67, 58)),
+    ('鉻', (26, 106)),
+    ('鉼', (26, 110)),
+    ('鉽', (67, 75)),
+    ('鉾', (67, 83)),
+    ('鉿', (67, 68)),
+    ('銀', (26, 102)),
+    ('銂', (67, 72)),
+    ('銃', (63, 84)),
+    ('銅', (26, 103)),
+    ('銆', (67, 79)),
+    ('銇', (67, 84)),
+    ('銈', (67, 76)),
+    ('銊', (67, 78)),
+    ('銋', (67, 87)),
+    ('銌', (67, 80)),
+    ('銍', (67, 62)),
+    ('銎', (67, 71)),
+    ('銑', (26, 111)),
+    ('銓', (26, 107)),
+    ('銔', (67, 60)),
+    ('銕', (67, 73)),
+    ('銖', (26, 105)),
+    ('銗', (67, 67)),
+    ('銘', (26, 104)),
+    ('銙', (67, 81)),
+    ('銚', (67, 64)),
+    ('銛', (67, 57)),
+    ('銜', (26, 108)),
+    ('銝', (67, 86)),
+    ('銠', (67, 59)),
+    ('銡', (67, 77)),
+    ('銢', (67, 74)),
+    ('銣', (67, 69)),
+    ('銤', (67, 55)),
+    ('銥', (67, 54)),
+    ('銦', (67, 63)),
+    ('銧', (67, 82)),
+    ('銨', (26, 109)),
+    ('銩', (67, 85)),
+    ('銪', (67, 61)),
+    ('銫', (67, 65)),
+    ('銬', (29, 18)),
+    ('銲', (29, 26)),
+    ('銳', (29, 21)),
+    ('銴', (71, 66)),
+    ('銵', (71, 63)),
+    ('銶', (71, 39)),
+    ('銷', (29, 16)),
+    ('銻', (29, 15)),
+    ('銼', (29, 22)),
+    ('鋀', (71, 37)),
+    ('鋁', (29, 20)),
+    ('鋂', (71, 49)),
+    ('鋃', (71, 35)),
+    ('鋄', (71, 36)),
+    ('鋅', (29, 14)),
+    ('鋆', (71, 65)),
+    ('鋇', (29, 24)),
+    ('鋈', (71, 52)),
+    ('鋉', (71, 57)),
+    ('鋊', (71, 51)),
+    ('鋋', (74, 140)),
+    ('鋌', (71, 47)),
+    ('鋍', (71, 55)),
+    ('鋎', (71, 53)),
+    ('鋏', (71, 40)),
+    ('鋐', (71, 34)),
+    ('鋑', (71, 61)),
+    ('鋒', (29, 23)),
+    ('鋓', (71, 62)),
+    ('鋕', (71, 56)),
+    ('鋗', (71, 45)),
+    ('鋘', (71, 43)),
+    ('鋙', (71, 38)),
+    ('鋝', (71, 46)),
+    ('鋞', (71, 59)),
+    ('鋟', (71, 42)),
+    ('鋠', (71, 58)),
+    ('鋡', (71, 64)),
+    ('鋤', (29, 19)),
+    ('鋦', (71, 54)),
+    ('鋧', (71, 60)),
+    ('鋨', (71, 50)),
+    ('鋩', (71, 44)),
+    ('鋪', (29, 17)),
+    ('鋮', (67, 70)),
+    ('鋯', (71, 48)),
+    ('鋰', (29, 25)),
+    ('鋱', (71, 41)),
+    ('鋷', (74, 146)),
+    ('鋸', (30, 149)),
+    ('鋹', (74, 145)),
+    ('鋺', (74, 142)),
+    ('鋻', (75, 4)),
+    ('鋼', (30, 153)),
+    ('鋾', (75, 1)),
+    ('鋿', (74, 150)),
+    ('錀', (75, 3)),
+    ('錁', (74, 135)),
+    ('錂', (74, 148)),
+    ('錄', (30, 155)),
+    ('錆', (74, 127)),
+    ('錈', (74, 125)),
+    ('錉', (75, 2)),
+    ('錋', (75, 0)),
+    ('錌', (74, 156)),
+    ('錍', (74, 139)),
+    ('錎', (74, 138)),
+    ('錏', (74, 128)),
+    ('錐', (31, 0)),
+    ('錒', (74, 134)),
+    ('錓', (74, 144)),
+    ('錔', (74, 155)),
+    ('錕', (31, 3)),
+    ('錖', (75, 5)),
+    ('錘', (32, 134)),
+    ('錙', (31, 5)),
+    ('錚', (30, 156)),
+    ('錛', (74, 132)),
+    ('錝', (74, 141)),
+    ('錞', (74, 124)),
+    ('錟', (74, 126)),
+    ('錠', (30, 147)),
+    ('錡', (31, 2)),
+    ('錢', (30, 152)),
+    ('錣', (74, 133)),
+    ('錤', (74, 149)),
+    ('錥', (74, 143)),
+    ('錦', (31, 1)),
+    ('錧', (74, 123)),
+    ('錨', (32, 129)),
+    ('錩', (74, 151)),
+    ('錪', (74, 154)),
+    ('錫', (30, 154)),
+    ('錭', (74, 137)),
+    ('錮', (31, 4)),
+    ('錯', (30, 151)),
+    ('錳', (30, 150)),
+    ('錴', (74, 147)),
+    ('錵', (74, 153)),
+    ('錶', (30, 148)),
+    ('錸', (74, 130)),
+    ('錹', (74, 152)),
+    ('錼', (74, 131)),
+    ('鍆', (74, 136)),
+    ('鍇', (77, 112)),
+    ('鍉', (77, 117)),
+    ('鍊', (32, 131)),
+    ('鍋', (32, 133)),
+    ('鍌', (77, 123)),
+    ('鍍', (32, 127)),
+    ('鍎', (77, 138)),
+    ('鍏', (77, 129)),
+    ('鍐', (77, 118)),
+    ('鍑', (77, 119)),
+    ('鍒', (77, 128)),
+    ('鍔', (32, 140)),
+    ('鍕', (77, 127)),
+    ('鍖', (77, 111)),
+    ('鍗', (77, 126)),
+    ('鍘', (77, 114)),
+    ('鍙', (77, 139)),
+    ('鍚', (32, 139)),
+    ('鍛', (32, 137)),
+    ('鍜', (77, 115)),
+    ('鍞', (77, 134)),
+    ('鍠', (77, 120)),
+    ('鍡', (77, 133)),
+    ('鍣', (77, 135)),
+    ('鍤', (77, 110)),
+    ('鍥', (32, 132)),
+    ('鍧', (77, 136)),
+    ('鍪', (77, 124)),
+    ('鍬', (32, 136)),
+    ('鍭', (77, 121)),
+    ('鍰', (32, 138)),
+    ('鍱', (77, 130)),
+    ('鍵', (32, 130)),
+    ('鍶', (77, 116)),
+    ('鍷', (77, 131)),
+    ('鍹', (77, 125)),
+    ('鍺', (74, 129)),
+    ('鍻', (77, 132)),
+    ('鍼', (77, 113)),
+    ('鍾', (32, 135)),
+    ('鎀', (77, 137)),
+    ('鎂', (32, 128)),
+    ('鎃', (77, 108)),
+    ('鎈', (80, 32)),
+    ('鎉', (80, 25)),
+    ('鎊', (33, 135)),
+    ('鎌', (80, 20)),
+    ('鎍', (80, 35)),
+    ('鎎', (80, 27)),
+    ('鎏', (77, 122)),
+    ('鎑', (80, 37)),
+    ('鎒', (80, 21)),
+    ('鎔', (33, 134)),
+    ('鎕', (80, 31)),
+    ('鎖', (33, 136)),
+    ('鎗', (33, 144)),
+    ('鎘', (33, 142)),
+    ('鎙', (80, 33)),
+    ('鎚', (33, 143)),
+    ('鎛', (80, 23)),
+    ('鎝', (80, 24)),
+    ('鎞', (80, 29)),
+    ('鎟', (80, 34)),
+    ('鎡', (77, 107)),
+    ('鎢', (33, 137)),
+    ('鎣', (80, 42)),
+    ('鎤', (80, 39)),
+    ('鎥', (80, 43)),
+    ('鎦', (80, 30)),
+    ('鎧', (80, 26)),
+    ('鎨', (80, 40)),
+    ('鎩', (82, 42)),
+    ('鎪', (80, 28)),
+    ('鎬', (33, 140)),
+    ('鎮', (33, 139)),
+    ('鎯', (77, 109)),
+    ('鎰', (33, 141)),
+    ('鎱', (80, 36)),
+    ('鎲', (80, 38)),
+    ('鎳', (33, 138)),
+    ('鎴', (80, 41)),
+    ('鎵', (80, 19)),
+    ('鎷', (80, 22)),
+    ('鏀', (82, 51)),
+    ('鏂', (82, 35)),
+    ('鏃', (34, 137)),
+    ('鏄', (82, 49)),
+    ('鏇', (82, 33)),
+    ('鏈', (34, 138)),
+    ('鏊', (82, 44)),
+    ('鏌', (82, 40)),
+    ('鏍', (34, 143)),
+    ('鏎', (82, 50)),
+    ('鏏', (82, 34)),
+    ('鏐', (82, 37)),
+    ('鏑', (34, 135)),
+    ('鏒', (82, 52)),
+    ('鏔', (82, 45)),
+    ('鏕', (82, 48)),
+    ('鏖', (34, 141)),
+    ('鏗', (34, 146)),
+    ('鏘', (34, 144)),
+    ('鏙', (82, 41)),
+    ('鏚', (82, 36)),
+    ('鏜', (34, 139)),
+    ('鏝', (34, 140)),
+    ('鏞', (82, 32)),
+    ('鏟', (34, 136)),
+    ('鏡', (34, 134)),
+    ('鏢', (34, 142)),
+    ('鏣', (82, 47)),
+    ('鏤', (34, 145)),
+    ('鏦', (82, 43)),
+    ('鏧', (82, 53)),
+    ('鏨', (34, 147)),
+    ('鏬', (82, 39)),
+    ('鏮', (82, 46)),
+    ('鏵', (83, 145)),
+    ('鏶', (84, 2)),
+    ('鏷', (83, 147)),
+    ('鏸', (83, 154)),
+    ('鏹', (82, 38)),
+    ('鏺', (83, 152)),
+    ('鏻', (83, 135)),
+    ('鏼', (84, 0)),
+    ('鏽', (35, 97)),
+    ('鏾', (83, 139)),
+    ('鏿', (83, 156)),
+    ('鐀', (83, 146)),
+    ('鐃', (35, 96)),
+    ('鐆', (84, 4)),
+    ('鐇', (83, 148)),
+    ('鐉', (83, 153)),
+    ('鐊', (83, 155)),
+    ('鐋', (83, 133)),
+    ('鐌', (84, 1)),
+    ('鐍', (83, 144)),
+    ('鐎', (83, 149)),
+    ('鐏', (83, 137)),
+    ('鐐', (83, 141)),
+    ('鐑', (84, 3)),
+    ('鐒', (83, 151)),
+    ('鐓', (83, 134)),
+    ('鐔', (83, 138)),
+    ('鐕', (83, 140)),
+    ('鐖', (83, 150)),
+    ('鐘', (35, 95)),
+    ('鐙', (83, 143)),
+    ('鐠', (83, 136)),
+    ('鐨', (83, 142)),
+    ('鐩', (85, 33)),
+    ('鐪', (85, 38)),
+    ('鐫', (36, 15)),
+    ('鐬', (85, 40)),
+    ('鐮', (36, 9)),
+    ('鐰', (85, 36)),
+    ('鐱', (85, 42)),
+    ('鐲', (36, 14)),
+    ('鐳', (36, 10)),
+    ('鐵', (36, 11)),
+    ('鐶', (85, 32)),
+    ('鐷', (85, 39)),
+    ('鐸', (36, 13)),
+    ('鐹', (85, 37)),
+    ('鐺', (36, 12)),
+    ('鐻', (85, 31)),
+    ('鐼', (85, 35)),
+    ('鐽', (85, 34)),
+    ('鐿', (85, 30)),
+    ('鑀', (85, 41)),
+    ('鑄', (36, 79)),
+    ('鑅', (86, 45)),
+    ('鑆', (86, 48)),
+    ('鑇', (86, 44)),
+    ('鑈', (86, 46)),
+    ('鑉', (86, 47)),
+    ('鑊', (86, 41)),
+    ('鑋', (86, 42)),
+    ('鑌', (86, 39)),
+    ('鑏', (86, 43)),
+    ('鑐', (86, 40)),
+    ('鑑', (36, 80)),
+    ('鑒', (36, 81)),
+    ('鑕', (87, 11)),
+    ('鑗', (87, 13)),
+    ('鑝', (87, 12)),
+    ('鑞', (87, 14)),
+    ('鑠', (36, 126)),
+    ('鑢', (87, 10)),
+    ('鑣', (36, 125)),
+    ('鑤', (36, 127)),
+    ('鑨', (87, 105)),
+    ('鑩', (87, 106)),
+    ('鑪', (37, 4)),
+    ('鑫', (87, 104)),
+    ('鑭', (88, 19)),
+    ('鑮', (88, 18)),
+    ('鑯', (88, 20)),
+    ('鑰', (37, 31)),
+    ('鑱', (88, 21)),
+    ('鑲', (37, 30)),
+    ('鑳', (88, 22)),
+    ('鑴', (88, 50)),
+    ('鑵', (88, 53)),
+    ('鑶', (88, 52)),
+    ('鑷', (37, 40)),
+    ('鑸', (88, 51)),
+    ('鑼', (37, 50)),
+    ('鑽', (37, 48)),
+    ('鑾', (37, 49)),
+    ('鑿', (37, 55)),
+    ('钀', (88, 94)),
+    ('钁', (88, 95)),
+    ('钂', (88, 93)),
+    ('钃', (88, 103)),
+    ('長', (9, 150)),
+    ('镺', (55, 4)),
+    ('镻', (59, 56)),
+    ('镼', (71, 67)),
+    ('镽', (82, 54)),
+    ('門', (9, 151)),
+    ('閂', (12, 74)),
+    ('閃', (15, 59)),
+    ('閆', (55, 5)),
+    ('閈', (55, 6)),
+    ('閉', (18, 74)),
+    ('開', (21, 61)),
+    ('閌', (59, 58)),
+    ('閍', (59, 57)),
+    ('閎', (21, 65)),
+    ('閏', (21, 60)),
+    ('閐', (59, 59)),
+    ('閑', (21, 62)),
+    ('閒', (21, 64)),
+    ('間', (21, 63)),
+    ('閔', (21, 59)),
+    ('閘', (24, 40)),
+    ('閛', (63, 110)),
+    ('閜', (63, 108)),
+    ('閞', (63, 109)),
+    ('閟', (63, 107)),
+    ('閡', (26, 112)),
+    ('閣', (26, 115)),
+    ('閤', (26, 117)),
+    ('閥', (26, 116)),
+    ('閨', (26, 113)),
+    ('閩', (26, 114)),
+    ('閫', (71, 69)),
+    ('閬', (71, 68)),
+    ('閭', (29, 27)),
+    ('閮', (71, 70)),
+    ('閰', (71, 71)),
+    ('閱', (29, 28)),
+    ('閵', (75, 13)),
+    ('閶', (75, 11)),
+    ('閷', (77, 145)),
+    ('閹', (75, 9)),
+    ('閺', (75, 10)),
+    ('閻', (31, 6)),
+    ('閼', (75, 6)),
+    ('閽', (75, 14)),
+    ('閾', (75, 8)),
+    ('閿', (75, 12)),
+    ('闀', (77, 141)),
+    ('闃', (77, 143)),
+    ('闅', (77, 144)),
+    ('闆', (32, 145)),
+    ('闇', (77, 140)),
+    ('闈', (32, 144)),
+    ('闉', (77, 142)),
+    ('闊', (32, 141)),
+    ('闋', (32, 142)),
+    ('闌', (32, 143)),
+    ('闍', (75, 7)),
+    ('闐', (33, 147)),
+    ('闑', (80, 46)),
+    ('闒', (80, 44)),
+    ('闓', (80, 45)),
+    ('闔', (33, 145)),
+    ('闕', (33, 148)),
+    ('闖', (33, 146)),
+    ('闚', (82, 55)),
+    ('闛', (82, 56)),
+    ('關', (34, 148)),
+    ('闞', (84, 5)),
+    ('闟', (84, 7)),
+    ('闠', (84, 6)),
+    ('闡', (35, 98)),
+    ('闢', (36, 16)),
+    ('闣', (85, 45)),
+    ('闤', (85, 44)),
+    ('闥', (85, 43)),
+    ('阜', (9, 152)),
+    ('阞', (40, 72)),
+    ('阠', (41, 24)),
+    ('阡', (5, 135)),
+    ('阢', (41, 22)),
+    ('阣', (41, 25)),
+    ('阤', (41, 23)),
+    ('阨', (42, 76)),
+    ('阪', (7, 95)),
+    ('阬', (7, 96)),
+    ('阭', (42, 78)),
+    ('阮', (7, 93)),
+    ('阯', (42, 77)),
+    ('阰', (42, 75)),
+    ('阱', (7, 94)),
+    ('防', (7, 92)),
+    ('阹', (44, 118)),
+    ('阺', (44, 121)),
+    ('阻', (9, 155)),
+    ('阼', (44, 120)),
+    ('阽', (44, 119)),
+    ('阿', (9, 154)),
+    ('陀', (9, 153)),
+    ('陂', (10, 0)),
+    ('陃', (44, 122)),
+    ('附', (9, 156)),
+    ('陊', (47, 100)),
+    ('陋', (12, 76)),
+    ('陌', (12, 77)),
+    ('降', (12, 78)),
+    ('陎', (47, 101)),
+    ('陏', (47, 97)),
+    ('限', (12, 75)),
+    ('陑', (47, 98)),
+    ('陓', (47, 99)),
+    ('陔', (47, 96)),
+    ('陘', (15, 66)),
+    ('陛', (15, 63)),
+    ('陜', (51, 5)),
+    ('陝', (15, 64)),
+    ('陞', (15, 67)),
+    ('陟', (51, 6)),
+    ('陡', (15, 62)),
+    ('院', (15, 60)),
+    ('陣', (15, 61)),
+    ('除', (15, 65)),
+    ('陪', (18, 75)),
+    ('陫', (55, 9)),
+    ('陬', (18, 83)),
+    ('陭', (55, 8)),
+    ('陯', (55, 11)),
+    ('陰', (18, 79)),
+    ('陱', (55, 10)),
+    ('陲', (21, 73)),
+    ('陳', (18, 77)),
+    ('陴', (18, 80)),
+    ('陵', (18, 76)),
+    ('陶', (18, 81)),
+    ('陷', (18, 82)),
+    ('陸', (18, 78)),
+    ('陼', (55, 7)),
+    ('陽', (21, 69)),
+    ('陾', (59, 61)),
+    ('隀', (59, 65)),
+    ('隃', (59, 64)),
+    ('隄', (21, 74)),
+    ('隅', (21, 70)),
+    ('隆', (21, 71)),
+    ('隇', (59, 60)),
+    ('隈', (59, 62)),
+    ('隉', (59, 63)),
+    ('隊', (21, 66)),
+    ('隋', (21, 68)),
+    ('隍', (21, 72)),
+    ('階', (21, 67)),
+    ('隑', (63, 113)),
+    ('隒', (63, 111)),
+    ('隓', (63, 112)),
+    ('隔', (24, 42)),
+    ('隕', (24, 43)),
+    ('隗', (63, 114)),
+    ('隘', (24, 41)),
+    ('隙', (26, 118)),
+    ('際', (26, 120)),
+    ('障', (26, 119)),
+    ('隞', (67, 89)),
+    ('隡', (67, 90)),
+    ('隢', (71, 73)),
+    ('隤', (71, 72)),
+    ('隧', (31, 7)),
+    ('隨', (31, 8)),
+    ('隩', (75, 15)),
+    ('險', (31, 9)),
+    ('隬', (77, 148)),
+    ('隮', (77, 146)),
+    ('隰', (77, 147)),
+    ('隱', (32, 146)),
+    ('隳', (80, 47)),
+    ('隴', (34, 149)),
+    ('隸', (32, 147)),
+    ('隹', (10, 1)),
+    ('隻', (15, 68)),
+    ('隼', (51, 7)),
+    ('隿', (55, 12)),
+    ('雀', (18, 84)),
+    ('雁', (21, 75)),
+    ('雂', (59, 66)),
+    ('雃', (59, 68)),
+    ('雄', (21, 77)),
+    ('雅', (21, 76)),
+    ('集', (21, 78)),
+    ('雇', (21, 79)),
+    ('雈', (59, 67)),
+    ('雉', (24, 46)),
+    ('雊', (24, 47)),
+    ('雋', (24, 45)),
+    ('雌', (26, 121)),
+    ('雍', (24, 44)),
+    ('雎', (63, 115)),
+    ('雒', (26, 122)),
+    ('雓', (71, 74)),
+    ('雔', (75, 16)),
+    ('雕', (31, 10)),
+    ('雖', (32, 148)),
+    ('雗', (80, 48)),
+    ('雘', (80, 52)),
+    ('雙', (33, 151)),
+    ('雚', (80, 49)),
+    ('雛', (33, 152)),
+    ('雜', (33, 150)),
+    ('雝', (80, 53)),
+    ('雞', (33, 153)),
+    ('雟', (80, 51)),
+    ('雡', (82, 57)),
+    ('離', (33, 149)),
+    ('難', (34, 150)),
+    ('雥', (87, 107)),
+    ('雨', (10, 2)),
+    ('雩', (18, 86)),
+    ('雪', (18, 85)),
+    ('雯', (21, 80)),
+    ('雰', (59, 70)),
+    ('雱', (59, 69)),
+    ('雲', (21, 81)),
+    ('雵', (63, 119)),
+    ('零', (24, 51)),
+    ('雷', (24, 48)),
+    ('雸', (63, 118)),
+    ('雹', (24, 50)),
+    ('雺', (63, 116)),
+    ('電', (24, 49)),
+    ('雽', (63, 117)),
+    ('雿', (67, 91)),
+    ('需', (26, 123)),
+    ('霂', (71, 77)),
+    ('霄', (29, 29)),
+    ('霅', (71, 75)),
+    ('霆', (29, 30)),
+    ('震', (29, 31)),
+    ('霈', (71, 76)),
+    ('霉', (29, 32)),
+    ('霋', (75, 17)),
+    ('霍', (31, 14)),
+    ('霎', (31, 11)),
+    ('霏', (31, 16)),
+    ('霐', (75, 19)),
+    ('霑', (31, 12)),
+    ('霒', (75, 18)),
+    ('霓', (31, 15)),
+    ('霖', (31, 13)),
+    ('霘', (77, 151)),
+    ('霙', (77, 153)),
+    ('霜', (32, 149)),
+    ('霝', (77, 152)),
+    ('霞', (32, 150)),
+    ('霟', (77, 150)),
+    ('霠', (77, 149)),
+    ('霢', (80, 55)),
+    ('霣', (80, 54)),
+    ('霤', (33, 154)),
+    ('霥', (80, 56)),
+    ('霦', (82, 62)),
+    ('霧', (34, 152)),
+    ('霨', (82, 61)),
+    ('霩', (82, 58)),
+    ('霪', (34, 151)),
+    ('霫', (82, 59)),
+    ('霬', (82, 60)),
+    ('霮', (84, 8)),
+    ('霯', (84, 9)),
+    ('霰', (35, 99)),
+    ('露', (36, 19)),
+    ('霵', (85, 46)),
+    ('霸', (36, 17)),
+    ('霹', (36, 18)),
+    ('霺', (85, 47)),
+    ('霽', (36, 82)),
+    ('霾', (36, 83)),
+    ('霿', (86, 49)),
+    ('靂', (37, 5)),
+    ('靃', (87, 109)),
+    ('靄', (37, 7)),
+    ('靆', (87, 108)),
+    ('靇', (87, 110)),
+    ('靈', (37, 6)),
+    ('靉', (88, 23)),
+    ('靋', (88, 75)),
+    ('青', (10, 3)),
+    ('靖', (24, 52)),
+    ('靘', (67, 92)),
+    ('靚', (71, 78)),
+    ('靛', (31, 17)),
+    ('靜', (31, 18)),
+    ('非', (10, 4)),
+    ('靠', (29, 33)),
+    ('靡', (34, 153)),
+    ('面', (12, 79)),
+    ('靦', (31, 19)),
+    ('靨', (36, 128)),
+    ('革', (12, 80)),
+    ('靪', (55, 13)),
+    ('靬', (59, 71)),
+    ('靮', (59, 73)),
+    ('靰', (59, 72)),
+    ('靲', (63, 123)),
+    ('靳', (63, 120)),
+    ('靴', (24, 53)),
+    ('靶', (24, 54)),
+    ('靷', (63, 121)),
+    ('靸', (63, 122)),
+    ('靺', (67, 94)),
+    ('靻', (67, 99)),
+    ('靼', (26, 124)),
+    ('靽', (67, 93)),
+    ('靾', (67, 95)),
+    ('靿', (67, 102)),
+    ('鞀', (67, 97)),
+    ('鞁', (67, 101)),
+    ('鞂', (67, 98)),
+    ('鞃', (67, 96)),
+    ('鞄', (67, 100)),
+    ('鞅', (26, 125)),
+    ('鞈', (71, 81)),
+    ('鞊', (71, 79)),
+    ('鞋', (29, 35)),
+    ('鞍', (29, 34)),
+    ('鞎', (71, 80)),
+    ('鞏', (29, 36)),
+    ('鞔', (75, 22)),
+    ('鞗', (75, 21)),
+    ('鞘', (31, 20)),
+    ('鞙', (75, 20)),
+    ('鞚', (77, 154)),
+    ('鞜', (77, 156)),
+    ('鞝', (78, 1)),
+    ('鞞', (78, 0)),
+    ('鞠', (32, 151)),
+    ('鞡', (77, 155)),
+    ('鞢', (80, 63)),
+    ('鞣', (33, 155)),
+    ('鞤', (80, 61)),
+    ('鞥', (80, 64)),
+    ('鞦', (33, 156)),
+    ('鞨', (80, 59)),
+    ('鞪', (80, 62)),
+    ('鞫', (80, 60)),
+    ('鞬', (80, 57)),
+    ('鞭', (34, 0)),
+    ('鞮', (80, 58)),
+    ('鞳', (82, 63)),
+    ('鞶', (82, 65)),
+    ('鞷', (82, 64)),
+    ('鞹', (84, 10)),
+    ('鞻', (84, 11)),
+    ('鞿', (85, 48)),
+    ('韁', (36, 85)),
+    ('韃', (36, 84)),
+    ('韄', (87, 15)),
+    ('韅', (87, 16)),
+    ('韆', (37, 8)),
+    ('韇', (87, 111)),
+    ('韉', (37, 41)),
+    ('韋', (12, 81)),
+    ('韌', (21, 82)),
+    ('韍', (67, 104)),
+    ('韎', (67, 103)),
+    ('韏', (71, 83)),
+    ('韐', (71, 82)),
+    ('韓', (32, 152)),
+    ('韔', (78, 3)),
+    ('韕', (78, 2)),
+    ('韖', (80, 67)),
+    ('韗', (80, 65)),
+    ('韘', (80, 68)),
+    ('韙', (80, 66)),
+    ('韜', (34, 154)),
+    ('韝', (82, 66)),
+    ('韞', (82, 67)),
+    ('韟', (82, 68)),
+    ('韡', (85, 49)),
+    ('韣', (86, 50)),
+    ('韥', (87, 112)),
+    ('韭', (12, 82)),
+    ('韰', (75, 23)),
+    ('韱', (78, 4)),
+    ('音', (12, 83)),
+    ('韶', (26, 126)),
+    ('韸', (75, 24)),
+    ('韹', (34, 1)),
+    ('韺', (80, 69)),
+    ('韻', (34, 155)),
+    ('韽', (84, 12)),
+    ('韾', (84, 13)),
+    ('響', (36, 20)),
+    ('頀', (87, 17)),
+    ('頁', (12, 84)),
+    ('頂', (18, 89)),
+    ('頃', (18, 90)),
+    ('頄', (55, 14)),
+    ('項', (21, 83)),
+    ('順', (21, 84)),
+    ('頇', (59, 74)),
+    ('須', (21, 85)),
+    ('頊', (24, 58)),
+    ('頌', (24, 60)),
+    ('頍', (63, 125)),
+    ('頎', (63, 126)),
+    ('頏', (63, 124)),
+    ('預', (24, 55)),
+    ('頑', (24, 56)),
+    ('頒', (24, 59)),
+    ('頓', (24, 57)),
+    ('頖', (67, 105)),
+    ('頗', (26, 127)),
+    ('領', (26, 128)),
+    ('頛', (71, 90)),
+    ('頜', (29, 39)),
+    ('頝', (71, 85)),
+    ('頞', (71, 84)),
+    ('頠', (71, 89)),
+    ('頡', (29, 37)),
+    ('頤', (31, 27)),
+    ('頦', (71, 86)),
+    ('頧', (71, 91)),
+    ('頨', (71, 88)),
+    ('頩', (71, 87)),
+    ('頫', (29, 38)),
+    ('頭', (31, 25)),
+    ('頯', (75, 26)),
+    ('頰', (31, 21)),
+    ('頲', (75, 27)),
+    ('頵', (75, 25)),
+    ('頷', (31, 24)),
+    ('頸', (31, 22)),
+    ('頹', (31, 26)),
+    ('頻', (31, 23)),
+    ('顁', (78, 5)),
+    ('顃', (78, 10)),
+    ('顄', (78, 6)),
+    ('顅', (78, 9)),
+    ('顆', (32, 153)),
+    ('顈', (76, 114)),
+    ('顉', (78, 8)),
+    ('顊', (78, 7)),
+    ('題', (34, 4)),
+    ('額', (34, 2)),
+    ('顎', (34, 5)),
+    ('顏', (34, 3)),
+    ('顐', (80, 70)),
+    ('顑', (80, 71)),
+    ('顒', (80, 72)),
+    ('顓', (34, 6)),
+    ('顗', (82, 72)),
+    ('願', (35, 0)),
+    ('顙', (82, 70)),
+    ('顛', (35, 1)),
+    ('顜', (82, 69)),
+    ('顝', (82, 71)),
+    ('類', (34, 156)),
+    ('顟', (84, 17)),
+    ('顠', (84, 14)),
+    ('顢', (84, 15)),
+    ('顣', (84, 16)),
+    ('顤', (85, 50)),
+    ('顥', (36, 22)),
+    ('顧', (36, 21)),
+    ('顩', (86, 52)),
+    ('顪', (86, 51)),
+    ('顫', (36, 86)),
+    ('顯', (36, 129)),
+    ('顰', (37, 9)),
+    ('顱', (37, 32)),
+    ('顲', (88, 24)),
+    ('顳', (88, 76)),
+    ('顴', (88, 77)),
+    ('風', (12, 85)),
+    ('颩', (59, 75)),
+    ('颬', (63, 127)),
+    ('颭', (67, 106)),
+    ('颮', (67, 107)),
+    ('颯', (26, 129)),
+    ('颱', (26, 130)),
+    ('颲', (71, 92)),
+    ('颳', (29, 40)),
+    ('颶', (32, 154)),
+    ('颸', (80, 73)),
+    ('颺', (34, 7)),
+    ('颻', (82, 75)),
+    ('颼', (35, 2)),
+    ('颽', (82, 74)),
+    ('颾', (82, 76)),
+    ('颿', (82, 73)),
+    ('飀', (85, 53)),
+    ('飁', (84, 18)),
+    ('飂', (84, 19)),
+    ('飄', (35, 100)),
+    ('飆', (85, 52)),
+    ('飉', (85, 51)),
+    ('飋', (86, 53)),
+    ('飌', (88, 78)),
+    ('飛', (12, 86)),
+    ('食', (12, 87)),
+    ('飢', (15, 69)),
+    ('飣', (51, 8)),
+    ('飥', (55, 15)),
+    ('飧', (21, 86)),
+    ('飩', (21, 89)),
+    ('飪', (21, 87)),
+    ('飫', (59, 76)),
+    ('飭', (21, 91)),
+    ('飯', (21, 88)),
+    ('飲', (21, 90)),
+    ('飴', (24, 62)),
+    ('飶', (63, 128)),
+    ('飹', (63, 129)),
+    ('飺', (71, 94)),
+    ('飼', (24, 61)),
+    ('飽', (24, 63)),
+    ('飾', (24, 64)),
+    ('餀', (67, 109)),
+    ('餂', (67, 108)),
+    ('餃', (26, 131)),
+    ('餅', (26, 132)),
+    ('餇', (67, 110)),
+    ('餈', (71, 93)),
+    ('餉', (26, 134)),
+    ('養', (29, 41)),
+    ('餌', (26, 133)),
+    ('餐', (31, 28)),
+    ('餑', (71, 95)),
+    ('餒', (29, 43)),
+    ('餓', (29, 42)),
+    ('餔', (71, 96)),
+    ('餕', (71, 99)),
+    ('餖', (71, 97)),
+    ('餗', (71, 98)),
+    ('餘', (29, 44)),
+    ('餚', (31, 33)),
+    ('餛', (31, 31)),
+    ('餞', (31, 30)),
+    ('餟', (75, 29)),
+    ('餡', (31, 32)),
+    ('餤', (75, 28)),
+    ('餥', (78, 11)),
+    ('餧', (75, 30)),
+    ('館', (31, 29)),
+    ('餩', (75, 31)),
+    ('餪', (78, 14)),
+    ('餫', (78, 12)),
+    ('餬', (78, 13)),
+    ('餭', (78, 18)),
+    ('餮', (34, 11)),
+    ('餯', (78, 17)),
+    ('餰', (78, 20)),
+    ('餱', (78, 19)),
+    ('餲', (78, 16)),
+    ('餳', (78, 15)),
+    ('餵', (32, 155)),
+    ('餺', (80, 76)),
+    ('餼', (80, 75)),
+    ('餽', (34, 10)),
+    ('餾', (34, 8)),
+    ('餿', (34, 9)),
+    ('饁', (80, 74)),
+    ('饃', (82, 79)),
+    ('饅', (35, 3)),
+    ('饇', (82, 78)),
+    ('饈', (82, 77)),
+    ('饉', (35, 4)),
+    ('饋', (84, 24)),
+    ('饌', (84, 23)),
+    ('饎', (84, 21)),
+    ('饐', (84, 20)),
+    ('饑', (35, 102)),
+    ('饒', (35, 101)),
+    ('饓', (84, 25)),
+    ('饔', (86, 54)),
+    ('饕', (36, 87)),
+    ('饖', (85, 55)),
+    ('饗', (36, 23)),
+    ('饘', (85, 54)),
+    ('饙', (84, 22)),
+    ('饛', (86, 55)),
+    ('饜', (36, 130)),
+    ('饞', (37, 33)),
+    ('饟', (88, 25)),
+    ('饡', (88, 79)),
+    ('首', (12, 88)),
+    ('馗', (55, 16)),
+    ('馘', (78, 21)),
+    ('香', (12, 89)),
+    ('馜', (67, 112)),
+    ('馝', (67, 111)),
+    ('馞', (75, 32)),
+    ('馡', (78, 23)),
+    ('馣', (78, 22)),
+    ('馥', (34, 12)),
+    ('馦', (82, 80)),
+    ('馧', (82, 81)),
+    ('馨', (35, 103)),
+    ('馫', (88, 80)),
+    ('馬', (15, 70)),
+    ('馭', (21, 93)),
+    ('馮', (21, 92)),
+    ('馯', (63, 130)),
+    ('馰', (63, 132)),
+    ('馱', (24, 66)),
+    ('馲', (63, 131)),
+    ('馳', (24, 65)),
+    ('馴', (24, 67)),
+    ('馵', (63, 133)),
+    ('馹', (67, 114)),
+    ('馺', (67, 116)),
+    ('馻', (67, 115)),
+    ('馽', (67, 118)),
+    ('駁', (26, 135)),
+    ('駂', (67, 117)),
+    ('駃', (67, 113)),
+    ('駇', (67, 119)),
+    ('駉', (71, 106)),
+    ('駋', (71, 109)),
+    ('駌', (71, 111)),
+    ('駍', (71, 101)),
+    ('駎', (71, 105)),
+    ('駏', (71, 102)),
+    ('駐', (29, 46)),
+    ('駑', (29, 49)),
+    ('駒', (29, 51)),
+    ('駓', (71, 103)),
+    ('駔', (71, 104)),
+    ('駕', (29, 50)),
+    ('駖', (71, 107)),
+    ('駗', (71, 110)),
+    ('駘', (71, 108)),
+    ('駙', (29, 52)),
+    ('駛', (29, 48)),
+    ('駜', (71, 100)),
+    ('駝', (29, 45)),
+    ('駟', (29, 47)),
+    ('駢', (31, 35)),
+    ('駣', (75, 38)),
+    ('駤', (75, 36)),
+    ('駥', (75, 35)),
+    ('駧', (75, 41)),
+    ('駩', (75, 40)),
+    ('駪', (75, 39)),
+    ('駬', (75, 34)),
+    ('駭', (31, 34)),
+    ('駮', (75, 33)),
+    ('駰', (75, 37)),
+    ('駱', (31, 36)),
+    ('駴', (78, 26)),
+    ('駶', (78, 30)),
+    ('駷', (78, 27)),
+    ('駸', (78, 29)),
+    ('駹', (78, 28)),
+    ('駺', (78, 25)),
+    ('駻', (78, 31)),
+    ('駼', (78, 34)),
+    ('駽', (78, 32)),
+    ('駾', (78, 33)),
+    ('駿', (33, 0)),
+    ('騁', (32, 156)),
+    ('騂', (78, 24)),
+    ('騃', (78, 35)),
+    ('騄', (80, 81)),
+    ('騅', (80, 84)),
+    ('騆', (80, 86)),
+    ('騇', (80, 85)),
+    ('騉', (80, 79)),
+    ('騊', (80, 83)),
+    ('騋', (80, 78)),
+    ('騍', (80, 80)),
+    ('騎', (34, 13)),
+    ('騏', (80, 77)),
+    ('騑', (80, 82)),
+    ('騔', (82, 94)),
+    ('騕', (82, 83)),
+    ('騖', (35, 5)),
+    ('騙', (35, 6)),
+    ('騚', (82, 82)),
+    ('騛', (82, 87)),
+    ('騜', (82, 93)),
+    ('騝', (82, 85)),
+    ('騞', (82, 92)),
+    ('騠', (82, 89)),
+    ('騢', (82, 88)),
+    ('騣', (82, 91)),
+    ('騤', (82, 86)),
+    ('騥', (82, 84)),
+    ('騧', (82, 90)),
+    ('騩', (84, 32)),
+    ('騪', (84, 30)),
+    ('騫', (35, 104)),
+    ('騬', (84, 29)),
+    ('騭', (84, 35)),
+    ('騮', (84, 33)),
+    ('騰', (35, 105)),
+    ('騱', (84, 28)),
+    ('騲', (84, 26)),
+    ('騴', (84, 27)),
+    ('騵', (35, 107)),
+    ('騶', (84, 31)),
+    ('騷', (35, 106)),
+    ('騸', (84, 34)),
+    ('騹', (85, 56)),
+    ('騺', (85, 62)),
+    ('騽', (85, 57)),
+    ('騾', (36, 27)),
+    ('騿', (85, 63)),
+    ('驀', (36, 26)),
+    ('驁', (85, 61)),
+    ('驂', (85, 60)),
+    ('驃', (36, 25)),
+    ('驄', (85, 59)),
+    ('驅', (36, 24)),
+    ('驆', (85, 58)),
+    ('驈', (86, 61)),
+    ('驉', (86, 63)),
+    ('驊', (86, 62)),
+    ('驌', (86, 59)),
+    ('驍', (36, 89)),
+    ('驎', (86, 56)),
+    ('驏', (86, 60)),
+    ('驐', (86, 65)),
+    ('驒', (86, 64)),
+    ('驓', (86, 57)),
+    ('驔', (86, 58)),
+    ('驕', (36, 88)),
+    ('驖', (87, 18)),
+    ('驗', (36, 133)),
+    ('驙', (87, 19)),
+    ('驚', (36, 131)),
+    ('驛', (36, 132)),
+    ('驞', (87, 113)),
+    ('驟', (37, 10)),
+    ('驠', (88, 54)),
+    ('驢', (37, 42)),
+    ('驤', (88, 81)),
+    ('驥', (37, 43)),
+    ('驦', (88, 82)),
+    ('驧', (88, 83)),
+    ('驨', (88, 97)),
+    ('驩', (88, 96)),
+    ('驪', (37, 58)),
+    ('驫', (88, 107)),
+    ('骨', (15, 71)),
+    ('骫', (63, 135)),
+    ('骭', (63, 134)),
+    ('骯', (26, 136)),
+    ('骰', (26, 137)),
+    ('骱', (67, 120)),
+    ('骳', (71, 112)),
+    ('骴', (75, 44)),
+    ('骷', (29, 53)),
+    ('骸', (31, 37)),
+    ('骹', (75, 42)),
+    ('骻', (75, 45)),
+    ('骼', (31, 38)),
+    ('骾', (78, 36)),
+    ('骿', (75, 43)),
+    ('髀', (80, 87)),
+    ('髁', (34, 14)),
+    ('髂', (82, 95)),
+    ('髆', (84, 38)),
+    ('髇', (84, 36)),
+    ('髊', (84, 37)),
+    ('髍', (85, 64)),
+    ('髏', (36, 28)),
+    ('髐', (86, 66)),
+    ('髑', (36, 136)),
+    ('髒', (36, 90)),
+    ('髓', (36, 134)),
+    ('體', (36, 135)),
+    ('髕', (87, 114)),
+    ('髖', (37, 34)),
+    ('高', (15, 72)),
+    ('髜', (80, 88)),
+    ('髟', (51, 9)),
+    ('髡', (24, 68)),
+    ('髣', (67, 121)),
+    ('髦', (26, 138)),
+    ('髧', (67, 122)),
+    ('髫', (71, 114)),
+    ('髬', (71, 113)),
+    ('髭', (31, 40)),
+    ('髮', (29, 54)),
+    ('髯', (29, 55)),
+    ('髱', (71, 117)),
+    ('髲', (71, 116)),
+    ('髳', (71, 115)),
+    ('髶', (75, 46)),
+    ('髷', (75, 49)),
+    ('髹', (75, 48)),
+    ('髺', (75, 47)),
+    ('髻', (31, 39)),
+    ('髼', (78, 40)),
+    ('髽', (78, 38)),
+    ('髾', (78, 37)),
+    ('鬁', (78, 39)),
+    ('鬃', (34, 15)),
+    ('鬄', (80, 90)),
+    ('鬅', (80, 91)),
+    ('鬆', (34, 16)),
+    ('鬈', (80, 89)),
+    ('鬊', (82, 97)),
+    ('鬋', (82, 96)),
+    ('鬌', (82, 99)),
+    ('鬍', (35, 7)),
+    ('鬎', (82, 98)),
+    ('鬐', (84, 39)),
+    ('鬑', (84, 41)),
+    ('鬒', (84, 40)),
+    ('鬕', (85, 65)),
+    ('鬖', (85, 68)),
+    ('鬗', (85, 66)),
+    ('鬘', (85, 67)),
+    ('鬙', (86, 67)),
+    ('鬚', (36, 91)),
+    ('鬞', (87, 20)),
+    ('鬟', (87, 21)),
+    ('鬠', (87, 22)),
+    ('鬢', (37, 11)),
+    ('鬣', (37, 35)),
+    ('鬤', (88, 84)),
+    ('鬥', (15, 73)),
+    ('鬧', (29, 56)),
+    ('鬨', (31, 41)),
+    ('鬩', (80, 92)),
+    ('鬫', (86, 68)),
+    ('鬮', (88, 98)),
+    ('鬯', (51, 10)),
+    ('鬱', (37, 59)),
+    ('鬲', (15, 74)),
+    ('鬳', (75, 50)),
+    ('鬵', (80, 93)),
+    ('鬷', (82, 100)),
+    ('鬺', (85, 69)),
+    ('鬻', (86, 69)),
+    ('鬼', (15, 75)),
+    ('鬾', (67, 123)),
+    ('鬿', (67, 124)),
+    ('魁', (26, 139)),
+    ('魂', (26, 140)),
+    ('魃', (71, 119)),
+    ('魄', (29, 58)),
+    ('魅', (29, 57)),
+    ('魆', (71, 118)),
+    ('魈', (78, 41)),
+    ('魊', (80, 94)),
+    ('魋', (80, 96)),
+    ('魌', (80, 95)),
+    ('魍', (34, 19)),
+    ('魎', (34, 18)),
+    ('魏', (34, 17)),
+    ('魑', (36, 30)),
+    ('魒', (85, 70)),
+    ('魔', (36, 29)),
+    ('魕', (86, 71)),
+    ('魖', (86, 70)),
+    ('魘', (37, 12)),
+    ('魙', (87, 115)),
+    ('魚', (18, 91)),
+    ('魛', (63, 136)),
+    ('魟', (67, 127)),
+    ('魠', (67, 125)),
+    ('魡', (67, 126)),
+    ('魤', (71, 128)),
+    ('魦', (71, 123)),
+    ('魧', (71, 120)),
+    ('魨', (71, 127)),
+    ('魬', (71, 129)),
+    ('魯', (29, 60)),
+    ('魰', (71, 126)),
+    ('魱', (71, 122)),
+    ('魴', (71, 121)),
+    ('魵', (71, 125)),
+    ('魶', (71, 124)),
+    ('魷', (29, 59)),
+    ('魺', (75, 61)),
+    ('魻', (75, 56)),
+    ('魼', (75, 54)),
+    ('魽', (75, 63)),
+    ('魾', (75, 55)),
+    ('鮀', (75, 51)),
+    ('鮂', (75, 57)),
+    ('鮅', (75, 52)),
+    ('鮆', (78, 50)),
+    ('鮇', (75, 53)),
+    ('鮈', (75, 64)),
+    ('鮐', (75, 60)),
+    ('鮑', (31, 42)),
+    ('鮒', (75, 59)),
+    ('鮓', (75, 58)),
+    ('鮕', (75, 62)),
+    ('鮚', (78, 42)),
+    ('鮛', (78, 45)),
+    ('鮞', (78, 44)),
+    ('鮠', (78, 52)),
+    ('鮡', (78, 47)),
+    ('鮢', (78, 51)),
+    ('鮤', (78, 49)),
+    ('鮥', (78, 48)),
+    ('鮦', (78, 46)),
+    ('鮨', (78, 43)),
+    ('鮪', (33, 3)),
+    ('鮫', (33, 2)),
+    ('鮭', (33, 4)),
+    ('鮮', (33, 1)),
+    ('鮯', (78, 53)),
+    ('鮵', (80, 102)),
+    ('鮶', (80, 105)),
+    ('鮸', (80, 103)),
+    ('鮹', (80, 107)),
+    ('鮽', (80, 108)),
+    ('鮿', (80, 100)),
+    ('鯀', (34, 24)),
+    ('鯁', (80, 101)),
+    ('鯃', (80, 99)),
+    ('鯄', (80, 106)),
+    ('鯆', (80, 98)),
+    ('鯇', (80, 97)),
+    ('鯈', (34, 23)),
+    ('鯉', (34, 21)),
+    ('鯊', (34, 20)),
+    ('鯓', (80, 104)),
+    ('鯔', (82, 109)),
+    ('鯕', (82, 115)),
+    ('鯖', (35, 10)),
+    ('鯗', (82, 110)),
+    ('鯙', (82, 113)),
+    ('鯚', (82, 117)),
+    ('鯛', (35, 11)),
+    ('鯜', (82, 112)),
+    ('鯞', (82, 104)),
+    ('鯠', (82, 103)),
+    ('鯡', (82, 116)),
+    ('鯢', (82, 107)),
+    ('鯤', (82, 105)),
+    ('鯥', (82, 114)),
+    ('鯦', (82, 106)),
+    ('鯧', (35, 9)),
+    ('鯨', (35, 8)),
+    ('鯪', (82, 101)),
+    ('鯫', (82, 102)),
+    ('鯬', (82, 111)),
+    ('鯰', (82, 108)),
+    ('鯷', (84, 44)),
+    ('鯸', (84, 47)),
+    ('鯽', (34, 22)),
+    ('鰅', (84, 45)),
+    ('鰆', (84, 51)),
+    ('鰇', (84, 49)),
+    ('鰈', (84, 43)),
+    ('鰉', (84, 54)),
+    ('鰋', (84, 42)),
+    ('鰍', (35, 109)),
+    ('鰎', (84, 50)),
+    ('鰒', (84, 46)),
+    ('鰓', (35, 108)),
+    ('鰔', (84, 53)),
+    ('鰗', (84, 52)),
+    ('鰜', (85, 73)),
+    ('鰝', (85, 72)),
+    ('鰡', (85, 79)),
+    ('鰣', (85, 75)),
+    ('鰤', (85, 78)),
+    ('鰥', (36, 32)),
+    ('鰨', (85, 76)),
+    ('鰩', (85, 77)),
+    ('鰫', (85, 71)),
+    ('鰬', (85, 74)),
+    ('鰭', (36, 31)),
+    ('鰱', (36, 93)),
+    ('鰲', (86, 82)),
+    ('鰳', (86, 77)),
+    ('鰴', (86, 81)),
+    ('鰶', (86, 84)),
+    ('鰷', (86, 80)),
+    ('鰹', (86, 76)),
+    ('鰻', (36, 95)),
+    ('鰼', (86, 79)),
+    ('鰽', (86, 83)),
+    ('鰾', (36, 94)),
+    ('鰿', (86, 74)),
+    ('鱀', (84, 48)),
+    ('鱁', (86, 78)),
+    ('鱄', (86, 75)),
+    ('鱆', (86, 72)),
+    ('鱈', (86, 73)),
+    ('鱉', (36, 92)),
+    ('鱊', (87, 26)),
+    ('鱋', (87, 28)),
+    ('鱌', (87, 31)),
+    ('鱍', (87, 27)),
+    ('鱎', (87, 32)),
+    ('鱐', (87, 25)),
+    ('鱒', (87, 23)),
+    ('鱔', (36, 137)),
+    ('鱕', (87, 29)),
+    ('鱖', (36, 139)),
+    ('鱗', (36, 138)),
+    ('鱘', (87, 24)),
+    ('鱙', (87, 30)),
+    ('鱞', (87, 120)),
+    ('鱟', (37, 13)),
+    ('鱠', (87, 121)),
+    ('鱢', (87, 119)),
+    ('鱣', (87, 116)),
+    ('鱦', (87, 118)),
+    ('鱧', (87, 117)),
+    ('鱨', (88, 26)),
+    ('鱭', (88, 28)),
+    ('鱮', (88, 27)),
+    ('鱱', (88, 57)),
+    ('鱳', (88, 56)),
+    ('鱴', (88, 55)),
+    ('鱵', (88, 58)),
+    ('鱷', (37, 51)),
+    ('鱸', (37, 52)),
+    ('鱹', (88, 104)),
+    ('鱺', (88, 108)),
+    ('鳥', (18, 92)),
+    ('鳦', (59, 77)),
+    ('鳧', (63, 139)),
+    ('鳩', (24, 69)),
+    ('鳪', (63, 137)),
+    ('鳭', (63, 138)),
+    ('鳱', (67, 128)),
+    ('鳲', (67, 129)),
+    ('鳳', (26, 143)),
+    ('鳴', (26, 141)),
+    ('鳵', (67, 130)),
+    ('鳶', (26, 142)),
+    ('鳷', (71, 134)),
+    ('鳹', (71, 137)),
+    ('鳺', (71, 131)),
+    ('鳻', (71, 138)),
+    ('鳼', (71, 130)),
+    ('鳽', (71, 132)),
+    ('鳿', (71, 133)),
+    ('鴀', (71, 136)),
+    ('鴃', (29, 63)),
+    ('鴄', (71, 141)),
+    ('鴅', (71, 140)),
+    ('鴆', (29, 61)),
+    ('鴇', (71, 135)),
+    ('鴈', (71, 139)),
+    ('鴉', (29, 62)),
+    ('鴐', (75, 74)),
+    ('鴒', (31, 47)),
+    ('鴔', (75, 69)),
+    ('鴕', (31, 43)),
+    ('鴗', (75, 66)),
+    ('鴘', (75, 72)),
+    ('鴙', (75, 75)),
+    ('鴛', (31, 48)),
+    ('鴝', (75, 71)),
+    ('鴞', (75, 68)),
+    ('鴟', (75, 76)),
+    ('鴠', (75, 67)),
+    ('鴢', (75, 73)),
+    ('鴣', (31, 44)),
+    ('鴥', (75, 65)),
+    ('鴦', (31, 45)),
+    ('鴨', (31, 46)),
+    ('鴩', (75, 70)),
+    ('鴭', (78, 71)),
+    ('鴮', (78, 58)),
+    ('鴯', (78, 59)),
+    ('鴰', (78, 62)),
+    ('鴱', (78, 60)),
+    ('鴳', (78, 54)),
+    ('鴶', (78, 57)),
+    ('鴷', (78, 67)),
+    ('鴸', (78, 61)),
+    ('鴻', (33, 5)),
+    ('鴽', (78, 69)),
+    ('鴾', (78, 66)),
+    ('鴿', (33, 6)),
+    ('鵀', (78, 68)),
+    ('鵁', (78, 55)),
+    ('鵂', (78, 64)),
+    ('鵃', (78, 65)),
+    ('鵅', (78, 63)),
+    ('鵊', (80, 112)),
+    ('鵋', (80, 114)),
+    ('鵌', (80, 117)),
+    ('鵏', (80, 111)),
+    ('鵑', (34, 25)),
+    ('鵒', (80, 119)),
+    ('鵓', (80, 110)),
+    ('鵔', (80, 120)),
+    ('鵖', (80, 116)),
+    ('鵗', (80, 118)),
+    ('鵘', (80, 122)),
+    ('鵙', (80, 115)),
+    ('鵚', (80, 123)),
+    ('鵛', (80, 113)),
+    ('鵜', (80, 109)),
+    ('鵝', (34, 26)),
+    ('鵟', (80, 121)),
+    ('鵠', (34, 27)),
+    ('鵡', (35, 13)),
+    ('鵧', (78, 56)),
+    ('鵨', (82, 143)),
+    ('鵩', (82, 134)),
+    ('鵪', (35, 15)),
+    ('鵫', (82, 130)),
+    ('鵬', (35, 16)),
+    ('鵯', (82, 139)),
+    ('鵰', (82, 133)),
+    ('鵱', (82, 123)),
+    ('鵲', (35, 14)),
+    ('鵳', (82, 136)),
+    ('鵴', (82, 131)),
+    ('鵵', (82, 132)),
+    ('鵷', (82, 118)),
+    ('鵸', (82, 125)),
+    ('鵹', (82, 140)),
+    ('鵻', (82, 137)),
+    ('鵽', (82, 129)),
+    ('鵿', (82, 141)),
+    ('鶀', (82, 124)),
+    ('鶁', (82, 119)),
+    ('鶂', (82, 138)),
+    ('鶄', (82, 121)),
+    ('鶅', (82, 135)),
+    ('鶆', (82, 126)),
+    ('鶇', (82, 142)),
+    ('鶈', (82, 122)),
+    ('鶉', (35, 12)),
+    ('鶊', (82, 120)),
+    ('鶋', (82, 127)),
+    ('鶌', (82, 128)),
+    ('鶐', (84, 61)),
+    ('鶒', (84, 59)),
+    ('鶔', (84, 64)),
+    ('鶖', (84, 76)),
+    ('鶗', (84, 67)),
+    ('鶘', (84, 60)),
+    ('鶙', (84, 56)),
+    ('鶚', (84, 69)),
+    ('鶛', (84, 62)),
+    ('鶜', (84, 65)),
+    ('鶝', (84, 58)),
+    ('鶞', (84, 72)),
+    ('鶟', (84, 55)),
+    ('鶠', (84, 63)),
+    ('鶡', (84, 68)),
+    ('鶢', (84, 70)),
+    ('鶣', (84, 73)),
+    ('鶤', (84, 57)),
+    ('鶦', (84, 77)),
+    ('鶧', (84, 78)),
+    ('鶨', (84, 71)),
+    ('鶩', (84, 75)),
+    ('鶪', (84, 66)),
+    ('鶬', (85, 95)),
+    ('鶭', (85, 98)),
+    ('鶯', (36, 33)),
+    ('鶱', (85, 97)),
+    ('鶲', (85, 102)),
+    ('鶳', (85, 100)),
+    ('鶴', (36, 34)),
+    ('鶵', (85, 91)),
+    ('鶶', (85, 81)),
+    ('鶷', (85, 80)),
+    ('鶸', (36, 36)),
+    ('鶹', (85, 93)),
+    ('鶺', (85, 94)),
+    ('鶻', (85, 90)),
+    ('鶼', (85, 82)),
+    ('鶾', (85, 87)),
+    ('鶿', (84, 74)),
+    ('鷁', (85, 83)),
+    ('鷂', (36, 35)),
+    ('鷃', (85, 89)),
+    ('鷅', (85, 88)),
+    ('鷇', (85, 84)),
+    ('鷈', (85, 96)),
+    ('鷊', (85, 85)),
+    ('鷋', (86, 89)),
+    ('鷌', (85, 99)),
+    ('鷍', (85, 101)),
+    ('鷎', (85, 92)),
+    ('鷏', (85, 86)),
+    ('鷐', (86, 90)),
+    ('鷑', (86, 92)),
+    ('鷒', (86, 86)),
+    ('鷓', (36, 96)),
+    ('鷕', (86, 99)),
+    ('鷖', (86, 97)),
+    ('鷗', (36, 97)),
+    ('鷘', (86, 96)),
+    ('鷙', (86, 95)),
+    ('鷚', (86, 88)),
+    ('鷛', (86, 85)),
+    ('鷜', (86, 91)),
+    ('鷝', (86, 100)),
+    ('鷞', (86, 87)),
+    ('鷟', (86, 93)),
+    ('鷡', (87, 41)),
+    ('鷢', (87, 46)),
+    ('鷣', (87, 36)),
+    ('鷤', (87, 39)),
+    ('鷥', (36, 140)),
+    ('鷦', (87, 43)),
+    ('鷨', (87, 50)),
+    ('鷩', (86, 94)),
+    ('鷫', (87, 37)),
+    ('鷬', (87, 47)),
+    ('鷭', (87, 51)),
+    ('鷮', (87, 42)),
+    ('鷯', (87, 35)),
+    ('鷰', (87, 45)),
+    ('鷲', (87, 44)),
+    ('鷳', (87, 49)),
+    ('鷴', (87, 48)),
+    ('鷵', (86, 98)),
+    ('鷶', (87, 40)),
+    ('鷷', (87, 34)),
+    ('鷸', (87, 38)),
+    ('鷹', (37, 14)),
+    ('鷺', (37, 15)),
+    ('鷻', (87, 33)),
+    ('鷽', (87, 132)),
+    ('鷾', (87, 123)),
+    ('鷿', (87, 131)),
+    ('鸀', (87, 128)),
+    ('鸁', (87, 129)),
+    ('鸂', (87, 122)),
+    ('鸃', (87, 125)),
+    ('鸄', (87, 133)),
+    ('鸅', (87, 127)),
+    ('鸆', (87, 126)),
+    ('鸇', (87, 124)),
+    ('鸉', (87, 130)),
+    ('鸋', (88, 29)),
+    ('鸍', (88, 30)),
+    ('鸏', (88, 32)),
+    ('鸐', (88, 31)),
+    ('鸑', (88, 34)),
+    ('鸒', (88, 33)),
+    ('鸓', (88, 60)),
+    ('鸔', (88, 59)),
+    ('鸕', (88, 85)),
+    ('鸗', (88, 86)),
+    ('鸙', (88, 99)),
+    ('鸚', (37, 56)),
+    ('鸛', (37, 60)),
+    ('鸝', (88, 109)),
+    ('鸞', (37, 61)),
+    ('鹵', (18, 93)),
+    ('鹹', (35, 110)),
+    ('鹺', (85, 103)),
+    ('鹼', (37, 16)),
+    ('鹽', (37, 17)),
+    ('鹿', (18, 94)),
+    ('麀', (63, 140)),
+    ('麂', (24, 70)),
+    ('麃', (71, 142)),
+    ('麆', (75, 78)),
+    ('麇', (75, 79)),
+    ('麈', (75, 77)),
+    ('麉', (78, 73)),
+    ('麊', (78, 72)),
+    ('麋', (33, 7)),
+    ('麌', (80, 125)),
+    ('麍', (78, 74)),
+    ('麎', (80, 124)),
+    ('麑', (82, 145)),
+    ('麒', (35, 17)),
+    ('麓', (35, 19)),
+    ('麔', (82, 144)),
+    ('麗', (35, 18)),
+    ('麙', (84, 79)),
+    ('麚', (84, 81)),
+    ('麛', (84, 80)),
+    ('麜', (85, 104)),
+    ('麝', (36, 37)),
+    ('麟', (36, 141)),
+    ('麠', (87, 134)),
+    ('麡', (88, 35)),
+    ('麤', (88, 112)),
+    ('麥', (18, 95)),
+    ('麧', (67, 131)),
+    ('麩', (29, 64)),
+    ('麭', (75, 81)),
+    ('麮', (75, 80)),
+    ('麰', (78, 75)),
+    ('麴', (35, 20)),
+    ('麵', (35, 111)),
+    ('麶', (86, 101)),
+    ('麷', (88, 105)),
+    ('麻', (18, 96)),
+    ('麼', (26, 144)),
+    ('麾', (29, 65)),
+    ('黀', (82, 146)),
+    ('黂', (87, 52)),
+    ('黃', (21, 94)),
+    ('黈', (78, 76)),
+    ('黌', (37, 36)),
+    ('黍', (21, 95)),
+    ('黎', (29, 66)),
+    ('黏', (33, 8)),
+    ('黐', (87, 53)),
+    ('黑', (21, 96)),
+    ('黓', (71, 143)),
+    ('黔', (31, 50)),
+    ('黕', (75, 82)),
+    ('黖', (75, 83)),
+    ('默', (31, 49)),
+    ('黚', (78, 77)),
+    ('黛', (33, 12)),
+    ('黜', (33, 10)),
+    ('黝', (33, 11)),
+    ('點', (33, 9)),
+    ('黟', (80, 126)),
+    ('黠', (34, 28)),
+    ('黤', (84, 83)),
+    ('黥', (84, 82)),
+    ('黦', (84, 85)),
+    ('黧', (84, 84)),
+    ('黨', (35, 112)),
+    ('黫', (85, 105)),
+    ('黭', (85, 107)),
+    ('黮', (85, 106)),
+    ('黯', (36, 38)),
+    ('黰', (86, 102)),
+    ('黲', (87, 54)),
+    ('黳', (87, 55)),
+    ('黴', (36, 142)),
+    ('黵', (88, 36)),
+    ('黶', (88, 61)),
+    ('黷', (37, 53)),
+    ('黹', (59, 78)),
+    ('黺', (75, 84)),
+    ('黻', (78, 78)),
+    ('黼', (82, 147)),
+    ('黽', (63, 141)),
+    ('黿', (78, 79)),
+    ('鼀', (80, 128)),
+    ('鼁', (80, 127)),
+    ('鼆', (87, 56)),
+    ('鼇', (37, 18)),
+    ('鼉', (88, 37)),
+    ('鼊', (88, 62)),
+    ('鼎', (24, 71)),
+    ('鼏', (71, 144)),
+    ('鼐', (71, 145)),
+    ('鼒', (75, 85)),
+    ('鼓', (24, 72)),
+    ('鼕', (34, 29)),
+    ('鼖', (80, 129)),
+    ('鼘', (85, 109)),
+    ('鼙', (36, 39)),
+    ('鼚', (85, 110)),
+    ('鼛', (85, 108)),
+    ('鼜', (87, 57)),
+    ('鼞', (87, 135)),
+    ('鼠', (24, 73)),
+    ('鼢', (78, 82)),
+    ('鼣', (78, 81)),
+    ('鼤', (78, 80)),
+    ('鼥', (80, 130)),
+    ('鼨', (80, 134)),
+    ('鼩', (80, 133)),
+    ('鼪', (80, 132)),
+    ('鼫', (80, 131)),
+    ('鼬', (34, 30)),
+    ('鼭', (82, 148)),
+    ('鼮', (84, 87)),
+    ('鼯', (35, 113)),
+    ('鼰', (84, 86)),
+    ('鼱', (85, 111)),
+    ('鼲', (86, 105)),
+    ('鼳', (86, 104)),
+    ('鼴', (36, 98)),
+    ('鼵', (86, 103)),
+    ('鼶', (87, 60)),
+    ('鼷', (87, 59)),
+    ('鼸', (87, 58)),
+    ('鼻', (26, 145)),
+    ('鼽', (75, 86)),
+    ('鼾', (33, 13)),
+    ('齀', (82, 149)),
+    ('齁', (82, 150)),
+    ('齂', (86, 106)),
+    ('齃', (87, 61)),
+    ('齆', (87, 136)),
+    ('齇', (88, 38)),
+    ('齈', (88, 87)),
+    ('齉', (88, 114)),
+    ('齊', (26, 146)),
+    ('齋', (33, 14)),
+    ('齌', (80, 135)),
+    ('齍', (82, 151)),
+    ('齎', (85, 112)),
+    ('齏', (87, 62)),
+    ('齒', (29, 68)),
+    ('齔', (78, 83)),
+    ('齕', (80, 136)),
+    ('齖', (82, 152)),
+    ('齗', (82, 153)),
+    ('齘', (82, 154)),
+    ('齙', (84, 92)),
+    ('齛', (84, 88)),
+    ('齜', (36, 40)),
+    ('齝', (84, 91)),
+    ('齞', (84, 90)),
+    ('齟', (35, 114)),
+    ('齠', (84, 89)),
+    ('齡', (35, 116)),
+    ('齣', (35, 115)),
+    ('齤', (85, 114)),
+    ('齥', (85, 113)),
+    ('齦', (36, 41)),
+    ('齧', (36, 42)),
+    ('齪', (36, 100)),
+    ('齫', (86, 107)),
+    ('齬', (36, 99)),
+    ('齮', (87, 65)),
+    ('齯', (87, 66)),
+    ('齰', (87, 64)),
+    ('齱', (87, 63)),
+    ('齲', (37, 20)),
+    ('齴', (87, 137)),
+    ('齵', (87, 138)),
+    ('齶', (87, 139)),
+    ('齷', (37, 19)),
+    ('齸', (88, 39)),
+    ('齹', (88, 42)),
+    ('齺', (88, 41)),
+    ('齻', (88, 40)),
+    ('齾', (88, 113)),
+    ('龍', (31, 51)),
+    ('龐', (34, 37)),
+    ('龑', (84, 93)),
+    ('龒', (85, 115)),
+    ('龔', (36, 101)),
+    ('龕', (86, 108)),
+    ('龘', (88, 115)),
+    ('龜', (31, 52)),
+    ('龠', (78, 84)),
+    ('龢', (86, 109)),
+    ('龤', (88, 63)),
+    ('兀', (40, 10)),
+    ('嗀', (60, 154)),
+    ('︰', (0, 10)),
+    ('︱', (0, 23)),
+    ('︳', (0, 25)),
+    ('︴', (0, 27)),
+    ('︵', (0, 31)),
+    ('︶', (0, 32)),
+    ('︷', (0, 35)),
+    ('︸', (0, 36)),
+    ('︹', (0, 39)),
+    ('︺', (0, 40)),
+    ('︻', (0, 43)),
+    ('︼', (0, 44)),
+    ('︽', (0, 47)),
+    ('︾', (0, 48)),
+    ('︿', (0, 51)),
+    ('﹀', (0, 52)),
+    ('﹁', (0, 55)),
+    ('﹂', (0, 56)),
+    ('﹃', (0, 59)),
+    ('﹄', (0, 60)),
+    ('﹉', (0, 100)),
+    ('﹊', (0, 101)),
+    ('﹋', (0, 104)),
+    ('﹌', (0, 105)),
+    ('﹍', (0, 102)),
+    ('﹎', (0, 103)),
+    ('﹏', (0, 28)),
+    ('﹐', (0, 13)),
+    ('﹒', (0, 15)),
+    ('﹔', (0, 17)),
+    ('﹕', (0, 18)),
+    ('﹖', (0, 19)),
+    ('﹗', (0, 20)),
+    ('﹙', (0, 61)),
+    ('﹚', (0, 62)),
+    ('﹛', (0, 63)),
+    ('﹜', (0, 64)),
+    ('﹝', (0, 65)),
+    ('﹞', (0, 66)),
+    ('﹟', (0, 106)),
+    ('﹠', (0, 107)),
+    ('﹡', (0, 108)),
+    ('﹢', (0, 124)),
+    ('﹣', (0, 125)),
+    ('﹤', (0, 126)),
+    ('﹥', (0, 127)),
+    ('﹦', (0, 128)),
+    ('﹩', (1, 12)),
+    ('﹪', (1, 13)),
+    ('﹫', (1, 14)),
+    ('！', (0, 9)),
+    ('＃', (0, 75)),
+    ('＄', (1, 3)),
+    ('％', (1, 8)),
+    ('＆', (0, 76)),
+    ('（', (0, 29)),
+    ('）', (0, 30)),
+    ('＊', (0, 77)),
+    ('＋', (0, 109)),
+    ('，', (0, 1)),
+    ('－', (0, 110)),
+    ('．', (0, 4)),
+    ('／', (1, 1)),
+    ('０', (1, 77)),
+    ('１', (1, 78)),
+    ('２', (1, 79)),
+    ('３', (1, 80)),
+    ('４', (1, 81)),
+    ('５', (1, 82)),
+    ('６', (1, 83)),
+    ('７', (1, 84)),
+    ('８', (1, 85)),
+    ('９', (1, 86)),
+    ('：', (0, 7)),
+    ('；', (0, 6)),
+    ('＜', (0, 115)),
+    ('＝', (0, 117)),
+    ('＞', (0, 116)),
+    ('？', (0, 8)),
+    ('＠', (1, 9)),
+    ('Ａ', (1, 109)),
+    ('Ｂ', (1, 110)),
+    ('Ｃ', (1, 111)),
+    ('Ｄ', (1, 112)),
+    ('Ｅ', (1, 113)),
+    ('Ｆ', (1, 114)),
+    ('Ｇ', (1, 115)),
+    ('Ｈ', (1, 116)),
+    ('Ｉ', (1, 117)),
+    ('Ｊ', (1, 118)),
+    ('Ｋ', (1, 119)),
+    ('Ｌ', (1, 120)),
+    ('Ｍ', (1, 121)),
+    ('Ｎ', (1, 122)),
+    ('Ｏ', (1, 123)),
+    ('Ｐ', (1, 124)),
+    ('Ｑ', (1, 125)),
+    ('Ｒ', (1, 126)),
+    ('Ｓ', (1, 127)),
+    ('Ｔ', (1, 128)),
+    ('Ｕ', (1, 129)),
+    ('Ｖ', (1, 130)),
+    ('Ｗ', (1, 131)),
+    ('Ｘ', (1, 132)),
+    ('Ｙ', (1, 133)),
+    ('Ｚ', (1, 134)),
+    ('＼', (1, 2)),
+    ('＿', (0, 98)),
+    ('ａ', (1, 135)),
+    ('ｂ', (1, 136)),
+    ('ｃ', (1, 137)),
+    ('ｄ', (1, 138)),
+    ('ｅ', (1, 139)),
+    ('ｆ', (1, 140)),
+    ('ｇ', (1, 141)),
+    ('ｈ', (1, 142)),
+    ('ｉ', (1, 143)),
+    ('ｊ', (1, 144)),
+    ('ｋ', (1, 145)),
+    ('ｌ', (1, 146)),
+    ('ｍ', (1, 147)),
+    ('ｎ', (1, 148)),
+    ('ｏ', (1, 149)),
+    ('ｐ', (1, 150)),
+    ('ｑ', (1, 151)),
+    ('ｒ', (1, 152)),
+    ('ｓ', (1, 153)),
+    ('ｔ', (1, 154)),
+    ('ｕ', (1, 155)),
+    ('ｖ', (1, 156)),
+    ('ｗ', (2, 0)),
+    ('ｘ', (2, 1)),
+    ('ｙ', (2, 2)),
+    ('ｚ', (2, 3)),
+    ('｛', (0, 33)),
+    ('｜', (0, 21)),
+    ('｝', (0, 34)),
+    ('､', (0, 14)),
+    ('￣', (0, 97)),
+];
+
+const fn is_strictly_sorted(map: &[(char, (usize, usize))]) -> bool {
+    let mut idx = 1;
+    while idx < map.len() {
+        if map[idx - 1].0 as u32 >= map[idx].0 as u32 {
+            return false;
+        }
+        idx += 1;
+    }
+    true
+}
+
+// `Big5::encode_char` binary searches this map, so it must remain sorted
+const _: () = assert!(is_strictly_sorted(ENCODE_MAP_BIG5));
+
+/// `DECODE_MAP_BIG5` is the source of truth for this file - the encode map is derived from it.
+/// Run this test with `--ignored --nocapture` to regenerate it after editing the decode map.
+#[cfg(feature = "std")]
+#[ignore = "For generating the ENCODE_MAP"]
+#[test]
+fn gen_derived_maps() {
+    extern crate std;
+    let mut encode = alloc::vec::Vec::new();
+    for (l_idx, row) in DECODE_MAP_BIG5.iter().enumerate() {
+        for (t_idx, c) in row.iter().copied().enumerate() {
+            if c == '�' {
+                continue;
+            }
+            encode.push((c, (l_idx, t_idx)));
+        }
+    }
+    // A few characters are mapped twice, prefer the later encoding for them, matching other
+    // Big5 encoders
+    encode.sort_by(|(c1, pos1), (c2, pos2)| c1.cmp(c2).then(pos2.cmp(pos1)));
+    encode.dedup_by_key(|(c, _)| *c);
+
+    std::println!(
+        "pub const ENCODE_MAP_BIG5: &[(char, (usize, usize)); {}] = &{:?};",
+        encode.len(),
+        encode,
+    );
+}
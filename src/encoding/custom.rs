@@ -0,0 +1,252 @@
+use crate::encoding::sealed::Sealed;
+use crate::encoding::{Encoding, EncodingKind, NullTerminable, SingleByte, ValidateError};
+use crate::str::Str;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+#[cfg(feature = "rand")]
+use rand::{distributions::Distribution, Rng};
+
+/// A user-defined single-byte encoding, described by a table mapping every byte to a character.
+/// Implement this on your own type, then use [`CustomSingleByte<T>`] as the encoding.
+///
+/// ```
+/// # use enrede::encoding::{CustomSingleByte, SingleByteTable};
+/// # use enrede::Str;
+/// struct Upper;
+///
+/// impl SingleByteTable for Upper {
+///     const NAME: &'static str = "upper";
+///     const TABLE: [char; 256] = {
+///         let mut table = ['\u{FFFD}'; 256];
+///         let mut idx = 0;
+///         while idx < 26 {
+///             table[idx] = (b'A' + idx as u8) as char;
+///             idx += 1;
+///         }
+///         table[26] = '?';
+///         table
+///     };
+/// }
+///
+/// let str = Str::<CustomSingleByte<Upper>>::from_bytes(&[7, 4, 11, 11, 14]).unwrap();
+/// assert!(str.chars().eq("HELLO".chars()));
+/// ```
+pub trait SingleByteTable {
+    /// A short name for the encoding, used in error messages.
+    const NAME: &'static str;
+
+    /// The character each byte decodes to. Bytes mapped to `'\u{FFFD}'` are invalid in this
+    /// encoding, so `'\u{FFFD}'` itself can't be encoded.
+    ///
+    /// If a character appears multiple times, it will be encoded as the first byte mapping to it.
+    const TABLE: [char; 256];
+
+    /// The character used to replace invalid data in lossy conversions. This must be mapped by
+    /// [`TABLE`](Self::TABLE).
+    const REPLACEMENT: char = '?';
+}
+
+/// Marker for [`SingleByteTable`]s that may be used in C-strings. See [`NullTerminable`] for the
+/// requirements - for a single-byte table, this means byte `0` is either invalid or mapped to
+/// `'\0'`.
+pub trait NullTerminableTable: SingleByteTable {}
+
+/// A single-byte encoding defined by the [`SingleByteTable`] `T`. This gets all the functionality
+/// of built-in encodings, allowing use with [`Str`], [`String`](crate::String), and the rest of
+/// the crate.
+pub struct CustomSingleByte<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for CustomSingleByte<T> {
+    fn default() -> Self {
+        CustomSingleByte(PhantomData)
+    }
+}
+
+impl<T> Clone for CustomSingleByte<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for CustomSingleByte<T> {}
+
+impl<T> PartialEq for CustomSingleByte<T> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> Eq for CustomSingleByte<T> {}
+
+impl<T> Hash for CustomSingleByte<T> {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl<T: SingleByteTable> fmt::Debug for CustomSingleByte<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CustomSingleByte").field(&T::NAME).finish()
+    }
+}
+
+impl<T: SingleByteTable> Sealed for CustomSingleByte<T> {}
+
+impl<T: SingleByteTable> Encoding for CustomSingleByte<T> {
+    const REPLACEMENT: char = T::REPLACEMENT;
+    const MAX_LEN: usize = 1;
+    type Bytes = u8;
+    const KIND: EncodingKind = EncodingKind::Custom;
+    const ASCII_COMPATIBLE: bool = {
+        let mut idx = 0;
+        while idx < 0x80 {
+            if T::TABLE[idx] as u32 != idx as u32 {
+                break;
+            }
+            idx += 1;
+        }
+        idx == 0x80
+    };
+
+    fn shorthand() -> &'static str {
+        T::NAME
+    }
+
+    fn name() -> &'static str {
+        T::NAME
+    }
+
+    fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
+        bytes.iter().enumerate().try_for_each(|(idx, b)| {
+            if T::TABLE[*b as usize] == '\u{FFFD}' {
+                Err(ValidateError {
+                    valid_up_to: idx,
+                    error_len: Some(1),
+                })
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    fn encode_char(c: char) -> Option<Self::Bytes> {
+        if c == '\u{FFFD}' {
+            return None;
+        }
+        T::TABLE.iter().position(|c2| *c2 == c).map(|b| b as u8)
+    }
+
+    fn decode_char(str: &Str<Self>) -> (char, &Str<Self>) {
+        (T::TABLE[str.as_bytes()[0] as usize], &str[1..])
+    }
+
+    fn char_bound(_: &Str<Self>, _: usize) -> bool {
+        true
+    }
+
+    fn char_len(c: char) -> Option<usize> {
+        Self::encode_char(c).map(|_| 1)
+    }
+}
+
+impl<T: NullTerminableTable> NullTerminable for CustomSingleByte<T> {}
+
+impl<T: SingleByteTable> SingleByte for CustomSingleByte<T> {}
+
+#[cfg(feature = "rand")]
+impl<T: SingleByteTable> Distribution<char> for CustomSingleByte<T> {
+    /// Generate a random character mapped by the table. This will loop forever if the table
+    /// doesn't map any characters.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        loop {
+            let c = T::TABLE[rng.gen::<u8>() as usize];
+            if c != '\u{FFFD}' {
+                return c;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cstr::CStr;
+    use crate::encoding::Utf8;
+    #[cfg(feature = "alloc")]
+    use crate::string::String;
+
+    /// ASCII, with `0x80..0xA0` invalid and the rest of the high half mapped to the private use
+    /// area, like a gaiji extension.
+    struct Gaiji;
+
+    impl SingleByteTable for Gaiji {
+        const NAME: &'static str = "gaiji";
+        const TABLE: [char; 256] = {
+            let mut table = ['\u{FFFD}'; 256];
+            let mut idx = 0;
+            while idx < 256 {
+                if idx < 0x80 {
+                    table[idx] = idx as u8 as char;
+                } else if idx >= 0xA0 {
+                    table[idx] = match char::from_u32(0xE000 + idx as u32 - 0xA0) {
+                        Some(c) => c,
+                        None => unreachable!(),
+                    };
+                }
+                idx += 1;
+            }
+            table
+        };
+    }
+
+    impl NullTerminableTable for Gaiji {}
+
+    type GaijiEnc = CustomSingleByte<Gaiji>;
+
+    #[test]
+    fn test_str() {
+        let str = Str::<GaijiEnc>::from_bytes(b"Ab\xA0\xA1c").unwrap();
+        assert!(str.chars().eq(['A', 'b', '\u{E000}', '\u{E001}', 'c']));
+        assert_eq!(&str[2..3], Str::from_bytes(b"\xA0").unwrap());
+        const { assert!(GaijiEnc::ASCII_COMPATIBLE) };
+        const { assert!(GaijiEnc::MAX_LEN == <u8 as crate::encoding::ArrayLike>::CAPACITY) };
+        assert_eq!(GaijiEnc::encode_char(GaijiEnc::REPLACEMENT), Some(b'?'));
+        assert!(!str.is_ascii());
+        assert_eq!(str[..2].as_ascii_str().unwrap().as_bytes(), b"Ab");
+        assert_eq!(
+            Str::<GaijiEnc>::from_bytes(b"Ab\x90"),
+            Err(ValidateError {
+                valid_up_to: 2,
+                error_len: Some(1),
+            })
+        );
+
+        let mut out = [0; 16];
+        let len = Utf8::recode(str, &mut out).unwrap();
+        assert_eq!(&out[..len], "Ab\u{E000}\u{E001}c".as_bytes());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_string() {
+        let mut string = String::<GaijiEnc>::new();
+        string.push('A');
+        string.push('\u{E05F}');
+        assert_eq!(string.as_bytes(), b"A\xFF");
+        assert!(string.try_push('\u{E060}').is_err());
+        assert!(string.try_push('\u{FFFD}').is_err());
+
+        let lossy = String::<GaijiEnc>::from_bytes_lossy(b"A\x80B");
+        assert_eq!(lossy.as_bytes(), b"A?B");
+        assert_eq!(
+            alloc::format!("{:?}", GaijiEnc::default()),
+            "CustomSingleByte(\"gaiji\")"
+        );
+    }
+
+    #[test]
+    fn test_cstr() {
+        let cstr = CStr::<GaijiEnc>::from_bytes_with_nul(b"A\xA0\0").unwrap();
+        assert!(cstr.chars().eq(['A', '\u{E000}']));
+        assert!(CStr::<GaijiEnc>::from_bytes_with_nul(b"A\x80\0").is_err());
+    }
+}
@@ -1,30 +1,34 @@
+#[cfg(feature = "rand")]
+use crate::encoding::sample_single_byte;
 use crate::encoding::sealed::Sealed;
-use crate::encoding::{NullTerminable, ValidateError};
+use crate::encoding::{
+    impl_ascii_compatible, EncodingKind, NullChar, NullTerminable, SingleByte, ValidateError,
+};
 use crate::{Encoding, Str};
 #[cfg(feature = "rand")]
 use rand::{distributions::Distribution, Rng};
 
 const DECODE_MAP_8859_2: [char; 96] = [
-    ' ', 'Ą', '˘', 'Ł', '¤', 'Ľ', 'Ś', '§', '¨', 'Š', 'Ş', 'Ť', 'Ź', '\u{AD}', 'Ž', 'Ż', '°', 'ą',
-    '˛', 'ł', '´', 'ľ', 'ś', 'ˇ', '¸', 'š', 'ş', 'ť', 'ź', '˝', 'ž', 'ż', 'Ŕ', 'Á', 'Â', 'Ă', 'Ä',
-    'Ĺ', 'Ć', 'Ç', 'Č', 'É', 'Ę', 'Ë', 'Ě', 'Í', 'Î', 'Ď', 'Đ', 'Ń', 'Ň', 'Ó', 'Ô', 'Ő', 'Ö', '×',
-    'Ř', 'Ů', 'Ú', 'Ű', 'Ü', 'Ý', 'Ţ', 'ß', 'ŕ', 'á', 'â', 'ă', 'ä', 'ĺ', 'ć', 'ç', 'č', 'é', 'ę',
-    'ë', 'ě', 'í', 'î', 'ď', 'đ', 'ń', 'ň', 'ó', 'ô', 'ő', 'ö', '÷', 'ř', 'ů', 'ú', 'ű', 'ü', 'ý',
-    'ţ', '˙',
+    '\u{A0}', 'Ą', '˘', 'Ł', '¤', 'Ľ', 'Ś', '§', '¨', 'Š', 'Ş', 'Ť', 'Ź', '\u{AD}', 'Ž', 'Ż', '°',
+    'ą', '˛', 'ł', '´', 'ľ', 'ś', 'ˇ', '¸', 'š', 'ş', 'ť', 'ź', '˝', 'ž', 'ż', 'Ŕ', 'Á', 'Â', 'Ă',
+    'Ä', 'Ĺ', 'Ć', 'Ç', 'Č', 'É', 'Ę', 'Ë', 'Ě', 'Í', 'Î', 'Ď', 'Đ', 'Ń', 'Ň', 'Ó', 'Ô', 'Ő', 'Ö',
+    '×', 'Ř', 'Ů', 'Ú', 'Ű', 'Ü', 'Ý', 'Ţ', 'ß', 'ŕ', 'á', 'â', 'ă', 'ä', 'ĺ', 'ć', 'ç', 'č', 'é',
+    'ę', 'ë', 'ě', 'í', 'î', 'ď', 'đ', 'ń', 'ň', 'ó', 'ô', 'ő', 'ö', '÷', 'ř', 'ů', 'ú', 'ű', 'ü',
+    'ý', 'ţ', '˙',
 ];
 
 const DECODE_MAP_8859_15: [char; 96] = [
-    ' ', '¡', '¢', '£', '€', '¥', 'Š', '§', 'š', '©', 'ª', '«', '¬', '\u{AD}', '®', '¯', '°', '±',
-    '²', '³', 'Ž', 'µ', '¶', '·', 'ž', '¹', 'º', '»', 'Œ', 'œ', 'Ÿ', '¿', 'À', 'Á', 'Â', 'Ã', 'Ä',
-    'Å', 'Æ', 'Ç', 'È', 'É', 'Ê', 'Ë', 'Ì', 'Í', 'Î', 'Ï', 'Ð', 'Ñ', 'Ò', 'Ó', 'Ô', 'Õ', 'Ö', '×',
-    'Ø', 'Ù', 'Ú', 'Û', 'Ü', 'Ý', 'Þ', 'ß', 'à', 'á', 'â', 'ã', 'ä', 'å', 'æ', 'ç', 'è', 'é', 'ê',
-    'ë', 'ì', 'í', 'î', 'ï', 'ð', 'ñ', 'ò', 'ó', 'ô', 'õ', 'ö', '÷', 'ø', 'ù', 'ú', 'û', 'ü', 'ý',
-    'þ', 'ÿ',
+    '\u{A0}', '¡', '¢', '£', '€', '¥', 'Š', '§', 'š', '©', 'ª', '«', '¬', '\u{AD}', '®', '¯', '°',
+    '±', '²', '³', 'Ž', 'µ', '¶', '·', 'ž', '¹', 'º', '»', 'Œ', 'œ', 'Ÿ', '¿', 'À', 'Á', 'Â', 'Ã',
+    'Ä', 'Å', 'Æ', 'Ç', 'È', 'É', 'Ê', 'Ë', 'Ì', 'Í', 'Î', 'Ï', 'Ð', 'Ñ', 'Ò', 'Ó', 'Ô', 'Õ', 'Ö',
+    '×', 'Ø', 'Ù', 'Ú', 'Û', 'Ü', 'Ý', 'Þ', 'ß', 'à', 'á', 'â', 'ã', 'ä', 'å', 'æ', 'ç', 'è', 'é',
+    'ê', 'ë', 'ì', 'í', 'î', 'ï', 'ð', 'ñ', 'ò', 'ó', 'ô', 'õ', 'ö', '÷', 'ø', 'ù', 'ú', 'û', 'ü',
+    'ý', 'þ', 'ÿ',
 ];
 
 /// The [ISO/IEC 8859-2](https://en.wikipedia.org/wiki/ISO/IEC_8859-2) encoding.
 #[non_exhaustive]
-#[derive(Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Iso8859_2;
 
 impl Sealed for Iso8859_2 {}
@@ -33,14 +37,30 @@ impl Encoding for Iso8859_2 {
     const REPLACEMENT: char = '?';
     const MAX_LEN: usize = 1;
     type Bytes = u8;
+    const KIND: EncodingKind = EncodingKind::Iso8859_2;
+    const ASCII_COMPATIBLE: bool = true;
 
     fn shorthand() -> &'static str {
         "iso5889_2"
     }
 
+    fn aliases() -> &'static [&'static str] {
+        &[
+            "csisolatin2",
+            "iso-8859-2",
+            "iso-ir-101",
+            "iso8859-2",
+            "iso88592",
+            "iso_8859-2",
+            "iso_8859-2:1987",
+            "l2",
+            "latin2",
+        ]
+    }
+
     fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
         bytes.iter().enumerate().try_for_each(|(idx, c)| {
-            if (0x20..0x7F).contains(c) || (0xA0..).contains(c) {
+            if (..0x80).contains(c) || (0xA0..).contains(c) {
                 Ok(())
             } else {
                 Err(ValidateError {
@@ -52,7 +72,7 @@ impl Encoding for Iso8859_2 {
     }
 
     fn encode_char(c: char) -> Option<Self::Bytes> {
-        if (0x20..0x7F).contains(&(c as u32)) {
+        if (..0x80).contains(&(c as u32)) {
             Some(c as u8)
         } else {
             let pos = DECODE_MAP_8859_2.iter().position(|v| *v == c)? as u8;
@@ -73,33 +93,29 @@ impl Encoding for Iso8859_2 {
         true
     }
 
-    fn char_len(c: char) -> usize {
-        if (0x20..0x7F).contains(&(c as u32)) || DECODE_MAP_8859_2.contains(&c) {
-            1
-        } else {
-            0
-        }
+    fn char_len(c: char) -> Option<usize> {
+        Self::encode_char(c).map(|_| 1)
     }
 }
 
 impl NullTerminable for Iso8859_2 {}
 
+impl NullChar for Iso8859_2 {}
+
+impl_ascii_compatible!(Iso8859_2);
+
+impl SingleByte for Iso8859_2 {}
+
 #[cfg(feature = "rand")]
 impl Distribution<char> for Iso8859_2 {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
-        // Total number of characters in encoding
-        let c = rng.gen_range(0u8..112);
-        if c < 95 {
-            char::from(c + 0x20)
-        } else {
-            DECODE_MAP_8859_2[(c - 95) as usize]
-        }
+        sample_single_byte::<Self, R>(rng)
     }
 }
 
 /// The [ISO/IEC 8859-15](https://en.wikipedia.org/wiki/ISO/IEC_8859-15) encoding.
 #[non_exhaustive]
-#[derive(Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Iso8859_15;
 
 impl Sealed for Iso8859_15 {}
@@ -108,14 +124,27 @@ impl Encoding for Iso8859_15 {
     const REPLACEMENT: char = '?';
     const MAX_LEN: usize = 1;
     type Bytes = u8;
+    const KIND: EncodingKind = EncodingKind::Iso8859_15;
+    const ASCII_COMPATIBLE: bool = true;
 
     fn shorthand() -> &'static str {
         "iso5889_15"
     }
 
+    fn aliases() -> &'static [&'static str] {
+        &[
+            "csisolatin9",
+            "iso-8859-15",
+            "iso8859-15",
+            "iso885915",
+            "iso_8859-15",
+            "l9",
+        ]
+    }
+
     fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
         bytes.iter().enumerate().try_for_each(|(idx, c)| {
-            if (0x20..0x7F).contains(c) || (0xA0..).contains(c) {
+            if (..0x80).contains(c) || (0xA0..).contains(c) {
                 Ok(())
             } else {
                 Err(ValidateError {
@@ -127,7 +156,7 @@ impl Encoding for Iso8859_15 {
     }
 
     fn encode_char(c: char) -> Option<Self::Bytes> {
-        if (0x20..0x7F).contains(&(c as u32)) {
+        if (..0x80).contains(&(c as u32)) {
             Some(c as u8)
         } else {
             let pos = DECODE_MAP_8859_15.iter().position(|v| *v == c)? as u8;
@@ -148,26 +177,22 @@ impl Encoding for Iso8859_15 {
         true
     }
 
-    fn char_len(c: char) -> usize {
-        if (0x20..0x7F).contains(&(c as u32)) || DECODE_MAP_8859_15.contains(&c) {
-            1
-        } else {
-            0
-        }
+    fn char_len(c: char) -> Option<usize> {
+        Self::encode_char(c).map(|_| 1)
     }
 }
 
 impl NullTerminable for Iso8859_15 {}
 
+impl NullChar for Iso8859_15 {}
+
+impl_ascii_compatible!(Iso8859_15);
+
+impl SingleByte for Iso8859_15 {}
+
 #[cfg(feature = "rand")]
 impl Distribution<char> for Iso8859_15 {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
-        // Total number of characters in encoding
-        let c = rng.gen_range(0u8..112);
-        if c < 95 {
-            char::from(c + 0x20)
-        } else {
-            DECODE_MAP_8859_15[(c - 95) as usize]
-        }
+        sample_single_byte::<Self, R>(rng)
     }
 }
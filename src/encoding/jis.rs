@@ -1,12 +1,19 @@
+#[cfg(feature = "rand")]
+use crate::encoding::sample_single_byte;
 use crate::encoding::sealed::Sealed;
-use crate::encoding::ValidateError;
+use crate::encoding::{
+    impl_ascii_compatible, EncodingKind, NullChar, NullTerminable, SingleByte, ValidateError,
+};
 use crate::{Encoding, Str};
 use arrayvec::ArrayVec;
 #[cfg(feature = "rand")]
 use rand::{distributions::Distribution, Rng};
 
+mod iso2022;
 mod x0208_tables;
 
+pub use iso2022::*;
+
 const DECODE_MAP_0201: [char; 63] = [
     '｡', '｢', '｣', '､', '･', 'ｦ', 'ｧ', 'ｨ', 'ｩ', 'ｪ', 'ｫ', 'ｬ', 'ｭ', 'ｮ', 'ｯ', 'ｰ', 'ｱ', 'ｲ', 'ｳ',
     'ｴ', 'ｵ', 'ｶ', 'ｷ', 'ｸ', 'ｹ', 'ｺ', 'ｻ', 'ｼ', 'ｽ', 'ｾ', 'ｿ', 'ﾀ', 'ﾁ', 'ﾂ', 'ﾃ', 'ﾄ', 'ﾅ', 'ﾆ',
@@ -15,7 +22,7 @@ const DECODE_MAP_0201: [char; 63] = [
 ];
 
 /// The [JIS X 0201](https://en.wikipedia.org/wiki/JIS_X_0201) encoding.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub struct JisX0201;
 
@@ -25,14 +32,19 @@ impl Encoding for JisX0201 {
     const REPLACEMENT: char = '?';
     const MAX_LEN: usize = 1;
     type Bytes = u8;
+    const KIND: EncodingKind = EncodingKind::JisX0201;
 
     fn shorthand() -> &'static str {
         "jisx0201"
     }
 
+    fn aliases() -> &'static [&'static str] {
+        &["jis_x0201", "x0201", "cshalfwidthkatakana"]
+    }
+
     fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
         bytes.iter().enumerate().try_for_each(|(idx, c)| {
-            if (..0x20).contains(c) || (0x80..0xA1).contains(c) || (0xE0..).contains(c) {
+            if (0x80..0xA1).contains(c) || (0xE0..).contains(c) {
                 Err(ValidateError {
                     valid_up_to: idx,
                     error_len: Some(1),
@@ -48,7 +60,10 @@ impl Encoding for JisX0201 {
             Some(0x5C)
         } else if c == '‾' {
             Some(0x7E)
-        } else if (0x20..0x80).contains(&(c as u32)) {
+        } else if c == '\\' || c == '~' {
+            // These bytes are used for '¥' and '‾' instead
+            None
+        } else if (..0x80).contains(&(c as u32)) {
             Some(c as u8)
         } else {
             let pos = DECODE_MAP_0201.iter().position(|v| *v == c)? as u8;
@@ -73,27 +88,31 @@ impl Encoding for JisX0201 {
         true
     }
 
-    fn char_len(c: char) -> usize {
-        if (0x20..0x80).contains(&(c as u32)) || DECODE_MAP_0201.contains(&c) {
-            1
-        } else {
-            0
-        }
+    fn char_len(c: char) -> Option<usize> {
+        Self::encode_char(c).map(|_| 1)
     }
 }
 
+impl NullTerminable for JisX0201 {}
+
+impl NullChar for JisX0201 {}
+
+impl SingleByte for JisX0201 {}
+
 #[cfg(feature = "rand")]
 impl Distribution<char> for JisX0201 {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
-        // Number of JIS 0201 characters
-        let c = rng.gen_range(0..159);
-        let c = if c < 0x60 { c + 0x20 } else { c + 0x41 };
-        Self::decode_char(unsafe { Str::from_bytes_unchecked(&[c]) }).0
+        sample_single_byte::<Self, R>(rng)
     }
 }
 
 /// The [JIS X 0208](https://en.wikipedia.org/wiki/JIS_X_0208) encoding.
-#[derive(Debug, Default)]
+///
+/// Both bytes of a character use the same range, so a character boundary can only be found by
+/// counting bytes back to the nearest single-byte character. Slicing and other boundary checks
+/// are linear in that distance, which is the whole string for text with no single-byte
+/// characters.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub struct JisX0208;
 
@@ -103,42 +122,60 @@ impl Encoding for JisX0208 {
     const REPLACEMENT: char = '?';
     const MAX_LEN: usize = 2;
     type Bytes = ArrayVec<u8, 2>;
+    const KIND: EncodingKind = EncodingKind::JisX0208;
 
     fn shorthand() -> &'static str {
         "jisx0208"
     }
 
+    fn aliases() -> &'static [&'static str] {
+        &[
+            "jis_c6226-1983",
+            "jis_x0208-1983",
+            "iso-ir-87",
+            "x0208",
+            "csiso87jisx0208",
+        ]
+    }
+
     fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
-        let mut row = 0;
-        for (idx, b) in bytes.iter().enumerate() {
-            if *b >= 0x80 {
+        let mut idx = 0;
+        while idx < bytes.len() {
+            let first = bytes[idx];
+            // Characters in range 0..0x21 and 0x7F are single-byte ASCII control codes
+            if (..0x21).contains(&first) || first == 0x7F {
+                idx += 1;
+                continue;
+            } else if first >= 0x80 {
                 return Err(ValidateError {
                     valid_up_to: idx,
                     error_len: Some(1),
                 });
-            } else if row == 0 {
-                // Tables with no valid characters - fast path
-                if ((0x29..0x30).contains(b) && *b != 0x2D) || (0x75..0x7F).contains(b) {
-                    return Err(ValidateError {
-                        valid_up_to: idx,
-                        error_len: Some(2),
-                    });
-                } else if (0x21..0x7F).contains(b) {
-                    row = *b - 0x20;
-                }
-                // Characters in range 0..0x20 are ASCII control codes
-            } else if row != 0 {
-                if !(0x21..0x7F).contains(b)
-                    || x0208_tables::DECODE_MAP_0208[(row - 1) as usize][(*b - 0x21) as usize]
-                        == '�'
-                {
-                    return Err(ValidateError {
-                        valid_up_to: idx - 1,
-                        error_len: Some(2),
-                    });
-                }
-                row = 0;
             }
+
+            let Some(&second) = bytes.get(idx + 1) else {
+                // Rows with no valid characters can never be completed
+                let empty_row = ((0x29..0x30).contains(&first) && first != 0x2D) || first >= 0x75;
+                return Err(ValidateError {
+                    valid_up_to: idx,
+                    error_len: empty_row.then_some(1),
+                });
+            };
+            if !(0x21..0x7F).contains(&second) {
+                // The second byte isn't part of this character, so the first is invalid alone
+                return Err(ValidateError {
+                    valid_up_to: idx,
+                    error_len: Some(1),
+                });
+            }
+            let row = x0208_tables::DECODE_MAP_0208.get((first - 0x21) as usize);
+            if row.is_none_or(|row| row[(second - 0x21) as usize] == '�') {
+                return Err(ValidateError {
+                    valid_up_to: idx,
+                    error_len: Some(2),
+                });
+            }
+            idx += 2;
         }
         Ok(())
     }
@@ -169,86 +206,634 @@ impl Encoding for JisX0208 {
     }
 
     fn char_bound(str: &Str<Self>, idx: usize) -> bool {
-        let bytes = str.as_bytes();
-        let first = bytes[0];
-        // Control code bytes, space, and del - always single-byte, never used as a second byte
-        if (..0x21).contains(&first) || first == 0x7F {
-            true
-        } else {
-            // Otherwise, first and second bytes look the same - iterate to here
-            for (idx2, _) in str.char_indices() {
-                if idx == idx2 {
-                    return true;
-                } else if idx < idx2 {
-                    return false;
-                }
+        // Control code bytes, space, and del are always single-byte, and never used as a second
+        // byte, so are sync points. Otherwise, first and second bytes look the same - count the
+        // two-byte run back to the last sync point, we're on a boundary if it's even.
+        let run = str.as_bytes()[..idx]
+            .iter()
+            .rev()
+            .take_while(|b| (0x21..0x7F).contains(*b))
+            .count();
+        run.is_multiple_of(2)
+    }
+
+    fn char_len(c: char) -> Option<usize> {
+        Self::encode_char(c).map(|bytes| bytes.len())
+    }
+}
+
+// Second bytes are always in `0x21..0x7F`, so the null byte is only ever the null character
+impl NullTerminable for JisX0208 {}
+
+impl NullChar for JisX0208 {}
+
+#[cfg(feature = "rand")]
+impl Distribution<char> for JisX0208 {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        // Single-byte control codes and space, then delete, then every double-byte character
+        let idx = rng.gen_range(0..0x22 + x0208_tables::ENCODE_MAP_0208.len());
+        match idx {
+            ..0x21 => char::from(idx as u8),
+            0x21 => '\x7F',
+            _ => x0208_tables::ENCODE_MAP_0208[idx - 0x22].0,
+        }
+    }
+}
+
+/// Whether a byte starts a double-byte Shift-JIS character
+const fn sjis_is_lead(b: u8) -> bool {
+    matches!(b, 0x81..=0x9F | 0xE0..=0xFC)
+}
+
+/// Look up the character for a Shift-JIS lead and trail byte, returning [`None`] if the pair isn't
+/// mapped.
+fn sjis_lookup(lead: u8, trail: u8) -> Option<char> {
+    let lead_offset = if lead < 0xA0 { 0x81 } else { 0xC1 };
+    let trail_offset = match trail {
+        0x40..=0x7E => 0x40,
+        0x80..=0xFC => 0x41,
+        _ => return None,
+    };
+    // Each lead byte covers two JIS X 0208 rows
+    let pointer = (lead - lead_offset) as usize * 188 + (trail - trail_offset) as usize;
+    let c = x0208_tables::DECODE_MAP_0208.get(pointer / 94)?[pointer % 94];
+    (c != '�').then_some(c)
+}
+
+/// Decode a single-byte Shift-JIS character. `ascii` selects whether `0x5C` and `0x7E` are ASCII
+/// or JIS X 0201.
+fn sjis_decode_single(b: u8, ascii: bool) -> Option<char> {
+    match b {
+        0x5C if !ascii => Some('¥'),
+        0x7E if !ascii => Some('‾'),
+        ..0x80 => Some(char::from(b)),
+        0xA1..0xE0 => Some(DECODE_MAP_0201[b as usize - 0xA1]),
+        _ => None,
+    }
+}
+
+fn sjis_validate(bytes: &[u8], ascii: bool) -> Result<(), ValidateError> {
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let lead = bytes[idx];
+        if !sjis_is_lead(lead) {
+            if sjis_decode_single(lead, ascii).is_none() {
+                return Err(ValidateError {
+                    valid_up_to: idx,
+                    error_len: Some(1),
+                });
             }
-            false
+            idx += 1;
+            continue;
         }
+
+        let Some(&trail) = bytes.get(idx + 1) else {
+            // Lead bytes for rows with no valid characters can never be completed
+            let empty_row = (0x40..=0xFC).all(|trail| sjis_lookup(lead, trail).is_none());
+            return Err(ValidateError {
+                valid_up_to: idx,
+                error_len: empty_row.then_some(1),
+            });
+        };
+        if sjis_lookup(lead, trail).is_none() {
+            // An ASCII byte following the lead isn't part of the error, it starts the next
+            // character
+            let error_len = if trail < 0x80 { 1 } else { 2 };
+            return Err(ValidateError {
+                valid_up_to: idx,
+                error_len: Some(error_len),
+            });
+        }
+        idx += 2;
     }
+    Ok(())
+}
 
-    fn char_len(c: char) -> usize {
-        if (..0x21).contains(&(c as u32)) || c as u32 == 0x7F {
-            1
-        } else if x0208_tables::DECODE_MAP_0208
-            .iter()
-            .any(|row| row.iter().any(|v| *v == c))
-        {
-            2
-        } else {
-            0
+fn sjis_encode(c: char, ascii: bool) -> Option<ArrayVec<u8, 2>> {
+    let single = match c {
+        '¥' if !ascii => Some(0x5C),
+        '‾' if !ascii => Some(0x7E),
+        // In JIS X 0201 these bytes are '¥' and '‾', so these fall back to their double-byte form
+        '\\' | '~' if !ascii => None,
+        ..'\u{80}' => Some(c as u8),
+        '｡'..='ﾟ' => Some((c as u32 - '｡' as u32) as u8 + 0xA1),
+        _ => None,
+    };
+    if let Some(b) = single {
+        return Some(ArrayVec::from_iter([b]));
+    }
+
+    let idx = x0208_tables::ENCODE_MAP_0208
+        .binary_search_by(|(c2, _)| c2.cmp(&c))
+        .ok()?;
+    let (_, (row, col)) = x0208_tables::ENCODE_MAP_0208[idx];
+    let pointer = row * 94 + col;
+    let (lead, trail) = (pointer / 188, pointer % 188);
+    let lead = if lead < 0x1F {
+        lead + 0x81
+    } else {
+        lead + 0xC1
+    };
+    let trail = if trail < 0x3F {
+        trail + 0x40
+    } else {
+        trail + 0x41
+    };
+    Some(ArrayVec::from([lead as u8, trail as u8]))
+}
+
+fn sjis_decode<E: Encoding>(str: &Str<E>, ascii: bool) -> (char, &Str<E>) {
+    let bytes = str.as_bytes();
+    let first = bytes[0];
+    if sjis_is_lead(first) {
+        let c =
+            sjis_lookup(first, bytes[1]).expect("Shift-JIS string contained an unmapped byte pair");
+        (c, &str[2..])
+    } else {
+        let c =
+            sjis_decode_single(first, ascii).expect("Shift-JIS string contained an invalid byte");
+        (c, &str[1..])
+    }
+}
+
+fn sjis_char_bound(bytes: &[u8], idx: usize) -> bool {
+    // Bytes which can't be a lead byte always end a character, either on their own or as a trail
+    // byte, so are sync points. Bytes after the last sync point must be lead/trail pairs, so we're
+    // on a boundary if that run is even.
+    let run = bytes[..idx]
+        .iter()
+        .rev()
+        .take_while(|b| sjis_is_lead(**b))
+        .count();
+    run.is_multiple_of(2)
+}
+
+#[cfg(feature = "rand")]
+fn sjis_sample<R: Rng + ?Sized>(rng: &mut R, ascii: bool) -> char {
+    // Single bytes, then half-width katakana, then every double-byte character. Characters with
+    // both a single and double-byte form are only picked as single bytes, to keep them uniform.
+    loop {
+        let idx = rng.gen_range(0..0x80 + 63 + x0208_tables::ENCODE_MAP_0208.len());
+        if idx < 0x80 {
+            return sjis_decode_single(idx as u8, ascii).unwrap();
+        } else if idx < 0xBF {
+            return DECODE_MAP_0201[idx - 0x80];
+        }
+        let c = x0208_tables::ENCODE_MAP_0208[idx - 0xBF].0;
+        if sjis_encode(c, ascii).is_some_and(|bytes| bytes.len() == 2) {
+            return c;
         }
     }
 }
 
+/// The [Shift-JIS](https://en.wikipedia.org/wiki/Shift_JIS) encoding. Single bytes are
+/// [JIS X 0201](JisX0201), and double-byte characters are [JIS X 0208](JisX0208), extended with
+/// the NEC row 13 characters such as `'①'`.
+///
+/// Many bytes can be either the first or second byte of a character, so a character boundary can
+/// only be found by counting back to the nearest byte which can't start a character. Slicing and
+/// other boundary checks are linear in that distance, which may be the whole string for text made
+/// up of kanji such as `'亜'`.
+///
+/// As in JIS X 0201, the bytes `0x5C` and `0x7E` are `'¥'` and `'‾'`. `'\\'` and `'~'` are only
+/// encodable as their double-byte forms, which most software won't read back as a path separator
+/// or tilde. For text as written by Windows and web browsers, use [`ShiftJisAscii`] instead.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ShiftJis;
+
+impl Sealed for ShiftJis {}
+
+impl Encoding for ShiftJis {
+    const REPLACEMENT: char = '?';
+    const MAX_LEN: usize = 2;
+    type Bytes = ArrayVec<u8, 2>;
+    const KIND: EncodingKind = EncodingKind::ShiftJis;
+
+    fn shorthand() -> &'static str {
+        "shiftjis"
+    }
+
+    fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
+        sjis_validate(bytes, false)
+    }
+
+    fn encode_char(c: char) -> Option<Self::Bytes> {
+        sjis_encode(c, false)
+    }
+
+    fn decode_char(str: &Str<Self>) -> (char, &Str<Self>) {
+        sjis_decode(str, false)
+    }
+
+    fn char_bound(str: &Str<Self>, idx: usize) -> bool {
+        sjis_char_bound(str.as_bytes(), idx)
+    }
+
+    fn char_len(c: char) -> Option<usize> {
+        Self::encode_char(c).map(|bytes| bytes.len())
+    }
+}
+
+// Trail bytes are always at least `0x40`, so the null byte is only ever the null character
+impl NullTerminable for ShiftJis {}
+
+impl NullChar for ShiftJis {}
+
 #[cfg(feature = "rand")]
-impl Distribution<char> for JisX0208 {
+impl Distribution<char> for ShiftJis {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
-        let c = rng.gen_range(0..x0208_tables::RAND_MAP_0208.len() + 22);
-        if c <= 21 {
-            if c == 21 {
-                '\x7F'
-            } else {
-                char::from(c as u8)
+        sjis_sample(rng, false)
+    }
+}
+
+/// The [Shift-JIS](https://en.wikipedia.org/wiki/Shift_JIS) encoding, with single bytes below
+/// `0x80` decoded as ASCII. This matches Windows code page 932 and the
+/// [WHATWG encoding standard](https://encoding.spec.whatwg.org/#shift_jis), and is what most
+/// Shift-JIS text in practice uses - `0x5C` is `'\\'`, so Windows paths can be encoded.
+///
+/// Unlike [`ShiftJis`], `'¥'` and `'‾'` are only encodable as their double-byte forms. The NEC
+/// row 13 extension characters are supported, but not the IBM extension rows from code page 932.
+/// Character boundaries are found the same way as for [`ShiftJis`], so have the same cost.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ShiftJisAscii;
+
+impl Sealed for ShiftJisAscii {}
+
+impl Encoding for ShiftJisAscii {
+    const REPLACEMENT: char = '?';
+    const MAX_LEN: usize = 2;
+    type Bytes = ArrayVec<u8, 2>;
+    const KIND: EncodingKind = EncodingKind::ShiftJisAscii;
+    const ASCII_COMPATIBLE: bool = true;
+
+    fn shorthand() -> &'static str {
+        "shiftjis_ascii"
+    }
+
+    fn aliases() -> &'static [&'static str] {
+        &[
+            "csshiftjis",
+            "ms932",
+            "ms_kanji",
+            "shift-jis",
+            "shift_jis",
+            "sjis",
+            "windows-31j",
+            "x-sjis",
+        ]
+    }
+
+    fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
+        sjis_validate(bytes, true)
+    }
+
+    fn encode_char(c: char) -> Option<Self::Bytes> {
+        sjis_encode(c, true)
+    }
+
+    fn decode_char(str: &Str<Self>) -> (char, &Str<Self>) {
+        sjis_decode(str, true)
+    }
+
+    fn char_bound(str: &Str<Self>, idx: usize) -> bool {
+        sjis_char_bound(str.as_bytes(), idx)
+    }
+
+    fn char_len(c: char) -> Option<usize> {
+        Self::encode_char(c).map(|bytes| bytes.len())
+    }
+}
+
+impl NullTerminable for ShiftJisAscii {}
+
+impl NullChar for ShiftJisAscii {}
+
+impl_ascii_compatible!(ShiftJisAscii);
+
+#[cfg(feature = "rand")]
+impl Distribution<char> for ShiftJisAscii {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        sjis_sample(rng, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_char_bound_jisx0208() {
+        let str = Str::<JisX0208>::from_bytes(b"\x30\x21 \x30\x22").unwrap();
+        let bounds = (0..=str.len())
+            .filter(|&idx| str.is_char_boundary(idx))
+            .collect::<alloc::vec::Vec<_>>();
+        assert_eq!(bounds, [0, 2, 3, 5]);
+        assert_eq!(str[2..].as_bytes(), b" \x30\x22");
+        assert!(str.get(1..).is_none());
+    }
+
+    #[test]
+    fn test_char_bound_jisx0208_agrees() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        // Reference implementation, walking forwards from the start of the string
+        fn char_bound_forward(bytes: &[u8], idx: usize) -> bool {
+            let mut pos = 0;
+            while pos < idx {
+                let b = bytes[pos];
+                pos += if (..0x21).contains(&b) || b == 0x7F {
+                    1
+                } else {
+                    2
+                };
             }
-        } else {
-            x0208_tables::RAND_MAP_0208[c - 22]
-        }
-    }
-}
-
-// #[derive(Debug, Default)]
-// #[non_exhaustive]
-// pub struct ShiftJIS;
-//
-// impl Sealed for ShiftJIS {}
-//
-// impl Encoding for ShiftJIS {
-//     const REPLACEMENT: char = '?';
-//     const MAX_LEN: usize = 0;
-//     type Bytes = ArrayVec<u8, 2>;
-//
-//     fn shorthand() -> &'static str {
-//         "shiftjis"
-//     }
-//
-//     fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
-//         todo!()
-//     }
-//
-//     fn encode_char(c: char) -> Option<Self::Bytes> {
-//         todo!()
-//     }
-//
-//     fn decode_char(str: &Str<Self>) -> (char, &Str<Self>) {
-//         todo!()
-//     }
-//
-//     fn char_bound(str: &Str<Self>, idx: usize) -> bool {
-//         todo!()
-//     }
-//
-//     fn char_len(c: char) -> usize {
-//         todo!()
-//     }
-// }
+            pos == idx
+        }
+
+        let mut rng = StdRng::seed_from_u64(0x0208);
+        for _ in 0..500 {
+            let mut bytes = alloc::vec::Vec::new();
+            for _ in 0..rng.gen_range(0..32) {
+                // Bias towards long runs of two-byte characters
+                if rng.gen_bool(0.2) {
+                    bytes.push(rng.gen_range(0..0x21));
+                } else {
+                    let rows = &x0208_tables::DECODE_MAP_0208;
+                    let row = &rows[rng.gen_range(0..rows.len())];
+                    let c = row[rng.gen_range(0..row.len())];
+                    if let Some(enc) = JisX0208::encode_char(c) {
+                        bytes.extend(enc);
+                    }
+                }
+            }
+            let str = Str::<JisX0208>::from_bytes(&bytes).unwrap();
+            for idx in 0..bytes.len() {
+                assert_eq!(
+                    JisX0208::char_bound(str, idx),
+                    char_bound_forward(&bytes, idx),
+                    "{:?} at {}",
+                    bytes,
+                    idx,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_tables_jisx0208() {
+        let mut count = 0;
+        for (row, table) in x0208_tables::DECODE_MAP_0208.iter().enumerate() {
+            for (col, c) in table.iter().copied().enumerate() {
+                if c == '�' {
+                    continue;
+                }
+                count += 1;
+                let bytes = [row as u8 + 0x21, col as u8 + 0x21];
+                assert!(JisX0208::validate(&bytes).is_ok());
+                let str = Str::<JisX0208>::from_bytes(&bytes).unwrap();
+                assert_eq!(JisX0208::decode_char(str).0, c);
+                // Characters in the row 13 extension may encode to their standard position instead
+                let encoded = JisX0208::encode_char(c).unwrap();
+                let str = Str::<JisX0208>::from_bytes(&encoded).unwrap();
+                assert_eq!(JisX0208::decode_char(str).0, c, "{:?} didn't round-trip", c);
+                if row != 12 {
+                    assert_eq!(&*encoded, &bytes);
+                }
+                assert_eq!(JisX0208::char_len(c), Some(2));
+            }
+        }
+        assert!(count >= x0208_tables::ENCODE_MAP_0208.len());
+
+        for (c, (row, col)) in x0208_tables::ENCODE_MAP_0208.iter().copied() {
+            assert_eq!(x0208_tables::DECODE_MAP_0208[row][col], c);
+        }
+    }
+
+    /// Check the decode table against the Unicode Consortium's JIS0208.TXT mapping, in the format
+    /// Tcl distributes it as (`jis0208.enc`). Tcl maps `0x2140` to U+FF3C rather than U+005C, which
+    /// makes no difference here.
+    #[test]
+    fn test_tables_jisx0208_reference() {
+        // This crate decodes the fullwidth forms which have an ASCII or JIS X 0201 equivalent as
+        // that equivalent
+        fn narrow(c: char) -> char {
+            match c {
+                '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap(),
+                '\u{FFE3}' => '‾',
+                '\u{FFE5}' => '¥',
+                _ => c,
+            }
+        }
+
+        let mut reference = [['�'; 94]; 84];
+        let mut lines = include_str!("../../tests/fixtures/jis0208.enc")
+            .lines()
+            .skip(3);
+        // Each page is a row number, then 16 lines of 16 hex code points, ending at the reverse
+        // mappings
+        while let Some(page) = lines.next().filter(|&line| line != "R") {
+            let row = usize::from_str_radix(page, 16).unwrap() - 0x21;
+            for high in 0..16 {
+                let line = lines.next().unwrap();
+                for low in 0..16 {
+                    let code = u32::from_str_radix(&line[low * 4..low * 4 + 4], 16).unwrap();
+                    let col = high * 16 + low;
+                    if code != 0 {
+                        reference[row][col - 0x21] = narrow(char::from_u32(code).unwrap());
+                    }
+                }
+            }
+        }
+
+        for (row, (table, reference)) in x0208_tables::DECODE_MAP_0208
+            .iter()
+            .zip(&reference)
+            .enumerate()
+        {
+            // Row 13 is an NEC extension, so isn't in the standard
+            if row == 12 {
+                assert!(reference.iter().all(|&c| c == '�'));
+                continue;
+            }
+            for (col, (&c, &expected)) in table.iter().zip(reference).enumerate() {
+                assert_eq!(c, expected, "0x{:02X}{:02X}", row + 0x21, col + 0x21);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tables_jisx0201() {
+        for b in (0..0x80).chain(0xA1..0xE0) {
+            let bytes = [b];
+            let str = Str::<JisX0201>::from_bytes(&bytes).unwrap();
+            let (c, _) = JisX0201::decode_char(str);
+            assert_eq!(
+                JisX0201::encode_char(c),
+                Some(b),
+                "{:?} didn't round-trip",
+                c
+            );
+        }
+
+        assert_eq!(JisX0201::encode_char('\\'), None);
+        assert_eq!(JisX0201::encode_char('~'), None);
+        assert_eq!(JisX0201::char_len('\\'), None);
+        assert_eq!(JisX0201::char_len('¥'), Some(1));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_cstring() {
+        use crate::{CStr, CString};
+
+        let str = CString::<JisX0208>::new(b"\x25\x4F\x25\x6D\x21\x3C".to_vec()).unwrap();
+        let mut bytes = str.into_bytes_with_nul();
+        bytes.extend(b"trailing");
+        let str = CStr::<JisX0208>::from_bytes_until_nul(&bytes).unwrap();
+        assert!(str.chars().eq("ハロー".chars()));
+
+        let str = CString::<JisX0201>::new(b"\xCA\xDB\xB0".to_vec()).unwrap();
+        let mut bytes = str.into_bytes_with_nul();
+        bytes.extend(b"trailing");
+        let str = CStr::<JisX0201>::from_bytes_until_nul(&bytes).unwrap();
+        assert!(str.chars().eq("ﾊﾛｰ".chars()));
+    }
+
+    #[test]
+    fn test_validate_jisx0208() {
+        assert_eq!(JisX0208::validate(b"\x30\x21 \x30\x22"), Ok(()));
+        let cases: [(&[u8], usize, Option<u8>); 6] = [
+            // Truncated character
+            (b"\x30\x21\x30", 2, None),
+            // Truncated character in a row with no characters
+            (b"\x30\x21\x2B", 2, Some(1)),
+            // High byte following a first byte
+            (b"\x30\x21\x30\xA1", 2, Some(1)),
+            // Control code following a first byte
+            (b"\x30\x21\x30\n", 2, Some(1)),
+            // Unmapped character
+            (b"\x30\x21\x2B\x21", 2, Some(2)),
+            (b"\x30\x21\xA1", 2, Some(1)),
+        ];
+        for (bytes, valid_up_to, error_len) in cases {
+            assert_eq!(
+                JisX0208::validate(bytes),
+                Err(ValidateError {
+                    valid_up_to,
+                    error_len
+                }),
+                "{:x?}",
+                bytes
+            );
+        }
+    }
+
+    #[test]
+    fn test_tables_shift_jis() {
+        fn check<E: Encoding<Bytes = ArrayVec<u8, 2>>>() {
+            for table in x0208_tables::DECODE_MAP_0208 {
+                for c in table.iter().copied().filter(|c| *c != '�') {
+                    let bytes = E::encode_char(c).unwrap();
+                    let str = Str::<E>::from_bytes(&bytes).unwrap();
+                    assert_eq!(str.chars().collect::<alloc::vec::Vec<_>>(), [c]);
+                    assert_eq!(E::char_len(c), Some(bytes.len()));
+                    // Only characters also representable as a single byte can be shorter
+                    if bytes.len() == 1 {
+                        assert!(c.is_ascii() || c == '¥' || c == '‾', "{:?}", c);
+                    }
+                }
+            }
+            for b in (0..0x80).chain(0xA1..0xE0) {
+                let bytes = [b];
+                let str = Str::<E>::from_bytes(&bytes).unwrap();
+                let c = str.chars().next().unwrap();
+                assert_eq!(E::encode_char(c).unwrap().as_slice(), [b]);
+            }
+        }
+        check::<ShiftJis>();
+        check::<ShiftJisAscii>();
+
+        let str = Str::<ShiftJis>::from_bytes(b"\x83\x6E\x83\x8D\x81\x5B\xCA").unwrap();
+        assert!(str.chars().eq("ハロー\u{FF8A}".chars()));
+        // Extension characters from NEC row 13, and the first row after the lead byte gap
+        assert_eq!(ShiftJis::encode_char('①').unwrap().as_slice(), b"\x87\x40");
+        assert_eq!(
+            ShiftJisAscii::encode_char('①').unwrap().as_slice(),
+            b"\x87\x40"
+        );
+        assert_eq!(ShiftJis::encode_char('漾').unwrap().as_slice(), b"\xE0\x40");
+        assert_eq!(
+            Str::<ShiftJisAscii>::from_bytes(b"\x87\x40\xE0\x40")
+                .unwrap()
+                .chars()
+                .collect::<alloc::vec::Vec<_>>(),
+            ['①', '漾'],
+        );
+    }
+
+    #[test]
+    fn test_shift_jis_backslash() {
+        assert_eq!(
+            ShiftJisAscii::encode_char('\\').unwrap().as_slice(),
+            b"\x5C"
+        );
+        assert_eq!(ShiftJisAscii::encode_char('~').unwrap().as_slice(), b"\x7E");
+        assert_eq!(
+            ShiftJisAscii::encode_char('¥').unwrap().as_slice(),
+            b"\x81\x8F"
+        );
+        assert_eq!(ShiftJisAscii::char_len('\\'), Some(1));
+
+        assert_eq!(ShiftJis::encode_char('¥').unwrap().as_slice(), b"\x5C");
+        assert_eq!(ShiftJis::encode_char('‾').unwrap().as_slice(), b"\x7E");
+        assert_eq!(ShiftJis::encode_char('\\').unwrap().as_slice(), b"\x81\x5F");
+        assert_eq!(ShiftJis::char_len('\\'), Some(2));
+
+        let bytes = b"C:\x5C\x83\x74\x83\x40\x83\x43\x83\x8B";
+        let str = Str::<ShiftJisAscii>::from_bytes(bytes).unwrap();
+        assert!(str.chars().eq("C:\\ファイル".chars()));
+        let str = Str::<ShiftJis>::from_bytes(bytes).unwrap();
+        assert!(str.chars().eq("C:¥ファイル".chars()));
+    }
+
+    #[test]
+    fn test_validate_shift_jis() {
+        assert_eq!(ShiftJis::validate(b"a\x88\x9F\xB1"), Ok(()));
+        let cases: [(&[u8], usize, Option<u8>); 6] = [
+            // Truncated character
+            (b"a\x88", 1, None),
+            // Truncated character with a lead byte for rows with no characters
+            (b"a\x85", 1, Some(1)),
+            (b"a\xFC", 1, Some(1)),
+            // Unmapped pair with an ASCII byte following the lead
+            (b"a\x88\x20", 1, Some(1)),
+            // Unmapped pair
+            (b"a\x88\x80", 1, Some(2)),
+            // Bytes which are never valid
+            (b"a\xA0", 1, Some(1)),
+        ];
+        for (bytes, valid_up_to, error_len) in cases {
+            let err = ValidateError {
+                valid_up_to,
+                error_len,
+            };
+            assert_eq!(ShiftJis::validate(bytes), Err(err), "{:x?}", bytes);
+            assert_eq!(ShiftJisAscii::validate(bytes), Err(err), "{:x?}", bytes);
+        }
+    }
+
+    #[test]
+    fn test_char_bound_shift_jis() {
+        // The trail bytes of '①' and 'ァ' look like '@', and the trail byte of the last character
+        // looks like a lead byte
+        let str = Str::<ShiftJisAscii>::from_bytes(b"\x87\x40@\x81\x5B\x83\x40\x81\x81").unwrap();
+        let bounds = (0..=str.len())
+            .filter(|&idx| str.is_char_boundary(idx))
+            .collect::<alloc::vec::Vec<_>>();
+        assert_eq!(bounds, [0, 2, 3, 5, 7, 9]);
+        assert!(str.get(1..).is_none());
+    }
+}
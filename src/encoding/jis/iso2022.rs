@@ -0,0 +1,353 @@
+use super::x0208_tables;
+use crate::encoding::ValidateError;
+#[cfg(feature = "alloc")]
+use crate::{encoding::JisX0208, errors::RecodeError, Encoding, Str};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use arrayvec::ArrayVec;
+use core::iter::FusedIterator;
+
+const ESC: u8 = 0x1B;
+
+/// The character set currently selected by escape sequences
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Mode {
+    Ascii,
+    Roman,
+    Jis0208,
+}
+
+impl Mode {
+    /// Parse the escape sequence at the start of `bytes`, which must start with `ESC`. On failure,
+    /// returns the error length for a [`ValidateError`].
+    fn from_escape(bytes: &[u8]) -> Result<Mode, Option<u8>> {
+        const ESCAPES: [(&[u8], Mode); 4] = [
+            (b"\x1B(B", Mode::Ascii),
+            (b"\x1B(J", Mode::Roman),
+            // JIS C 6226-1978, which is decoded as its successor JIS X 0208
+            (b"\x1B$@", Mode::Jis0208),
+            (b"\x1B$B", Mode::Jis0208),
+        ];
+        for (escape, mode) in ESCAPES {
+            if bytes.starts_with(escape) {
+                return Ok(mode);
+            } else if escape.starts_with(bytes) {
+                // The input ends part-way through a valid escape sequence
+                return Err(None);
+            }
+        }
+        Err(Some(1))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn escape(self) -> &'static [u8; 3] {
+        match self {
+            Mode::Ascii => b"\x1B(B",
+            Mode::Roman => b"\x1B(J",
+            Mode::Jis0208 => b"\x1B$B",
+        }
+    }
+}
+
+fn decode_single(mode: Mode, b: u8) -> char {
+    match (mode, b) {
+        (Mode::Roman, 0x5C) => '¥',
+        (Mode::Roman, 0x7E) => '‾',
+        _ => char::from(b),
+    }
+}
+
+fn decode_pair(first: u8, second: u8) -> Option<char> {
+    let row = x0208_tables::DECODE_MAP_0208.get(first.checked_sub(0x21)? as usize)?;
+    let c = *row.get(second.checked_sub(0x21)? as usize)?;
+    (c != '�').then_some(c)
+}
+
+/// Find the mode and bytes which encode a character, preferring to stay in the current mode
+#[cfg(feature = "alloc")]
+fn encode_char(mode: Mode, c: char) -> Option<(Mode, ArrayVec<u8, 2>)> {
+    let single = |mode, b| Some((mode, ArrayVec::from_iter([b])));
+    match c {
+        // Shift and escape bytes are reserved, and can't be used as characters
+        '\x0E' | '\x0F' | '\x1B' => None,
+        // JIS X 0201 Roman only differs from ASCII in these two characters, so either will do
+        '\\' | '~' => single(Mode::Ascii, c as u8),
+        '\0'..='\x7F' if mode == Mode::Roman => single(Mode::Roman, c as u8),
+        '\0'..='\x7F' => single(Mode::Ascii, c as u8),
+        '¥' => single(Mode::Roman, 0x5C),
+        '‾' => single(Mode::Roman, 0x7E),
+        _ => JisX0208::encode_char(c)
+            .filter(|bytes| bytes.len() == 2)
+            .map(|bytes| (Mode::Jis0208, bytes)),
+    }
+}
+
+/// The [ISO-2022-JP](https://en.wikipedia.org/wiki/ISO/IEC_2022#ISO-2022-JP) encoding, as used for
+/// Japanese email ([RFC 1468](https://www.rfc-editor.org/rfc/rfc1468)).
+///
+/// ISO-2022-JP is stateful - escape sequences switch between ASCII, JIS X 0201 Roman, and
+/// JIS X 0208, and the meaning of every other byte depends on the last escape sequence before it.
+/// Because of this, it doesn't implement [`Encoding`](crate::Encoding), and can't be used with
+/// [`Str`](crate::Str). Every other encoding guarantees that a string split at any character
+/// boundary leaves two valid strings which decode independently, which is what allows slicing,
+/// searching, and pushing characters without looking at the rest of the string. Data in the middle
+/// of a JIS X 0208 run can't be decoded without its escape sequence, so instead this type
+/// validates, decodes, and encodes whole buffers at a time. Decode into another encoding to
+/// manipulate the text.
+///
+/// Data must start and end in ASCII mode, and may contain ASCII control characters other than the
+/// `SO`, `SI`, and `ESC` bytes. The JIS C 6226-1978 escape sequence (`ESC $ @`) is accepted when
+/// decoding, and treated as JIS X 0208. Encoding only ever produces JIS X 0208-1983 escape
+/// sequences, and only switches mode when the next character requires it.
+///
+/// Only the character sets of plain ISO-2022-JP are supported. The JIS X 0212 supplementary
+/// kanji of ISO-2022-JP-1 (`ESC $ ( D`), and the other character sets of ISO-2022-JP-2, are
+/// rejected by validation.
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Iso2022Jp;
+
+impl Iso2022Jp {
+    /// Given a byte slice, determine whether it is valid ISO-2022-JP.
+    ///
+    /// On failure, the error's [`valid_up_to`](ValidateError::valid_up_to) is the index of the
+    /// first byte which isn't part of a valid character or escape sequence. As the encoding is
+    /// stateful, the data before this point may not switch back to ASCII mode, so may not be valid
+    /// on its own. Data which doesn't end in ASCII mode reports its length as `valid_up_to`, and no
+    /// error length, as it needs an escape sequence to be appended.
+    pub fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
+        let err = |valid_up_to, error_len| {
+            Err(ValidateError {
+                valid_up_to,
+                error_len,
+            })
+        };
+
+        let mut mode = Mode::Ascii;
+        let mut idx = 0;
+        while idx < bytes.len() {
+            let b = bytes[idx];
+            if b == ESC {
+                match Mode::from_escape(&bytes[idx..]) {
+                    Ok(new_mode) => mode = new_mode,
+                    Err(error_len) => return err(idx, error_len),
+                }
+                idx += 3;
+            } else if mode == Mode::Jis0208 {
+                if !(0x21..0x7F).contains(&b) {
+                    return err(idx, Some(1));
+                }
+                let Some(&second) = bytes.get(idx + 1) else {
+                    return err(idx, None);
+                };
+                if !(0x21..0x7F).contains(&second) {
+                    // The second byte isn't part of this character, so the first is invalid alone
+                    return err(idx, Some(1));
+                }
+                if decode_pair(b, second).is_none() {
+                    return err(idx, Some(2));
+                }
+                idx += 2;
+            } else if b >= 0x80 || b == 0x0E || b == 0x0F {
+                return err(idx, Some(1));
+            } else {
+                idx += 1;
+            }
+        }
+
+        if mode == Mode::Ascii {
+            Ok(())
+        } else {
+            err(bytes.len(), None)
+        }
+    }
+
+    /// Validate a byte slice, then iterate the characters it contains.
+    pub fn decode(bytes: &[u8]) -> Result<Iso2022JpChars<'_>, ValidateError> {
+        Self::validate(bytes)?;
+        Ok(Iso2022JpChars {
+            bytes,
+            mode: Mode::Ascii,
+        })
+    }
+
+    /// Encode a string in another encoding into ISO-2022-JP, switching modes only where needed and
+    /// returning to ASCII mode at the end.
+    ///
+    /// Fails if the string contains a character which can't be represented, such as half-width
+    /// katakana, or the `SO`, `SI`, and `ESC` control characters.
+    #[cfg(feature = "alloc")]
+    pub fn encode<E: Encoding>(str: &Str<E>) -> Result<Vec<u8>, RecodeError> {
+        let mut out = Vec::with_capacity(str.len());
+        let mut mode = Mode::Ascii;
+        let mut rest = str;
+        while !rest.is_empty() {
+            let idx = str.len() - rest.len();
+            let (c, next) = E::decode_char(rest);
+            let Some((new_mode, bytes)) = encode_char(mode, c) else {
+                return Err(RecodeError {
+                    valid_up_to: idx,
+                    char: c,
+                    char_len: (rest.len() - next.len()) as u8,
+                });
+            };
+            rest = next;
+            if new_mode != mode {
+                out.extend_from_slice(new_mode.escape());
+                mode = new_mode;
+            }
+            out.extend_from_slice(&bytes);
+        }
+        if mode != Mode::Ascii {
+            out.extend_from_slice(Mode::Ascii.escape());
+        }
+        Ok(out)
+    }
+}
+
+/// Iterator over the characters of ISO-2022-JP data. See [`Iso2022Jp::decode`].
+#[derive(Clone, Debug)]
+pub struct Iso2022JpChars<'a> {
+    bytes: &'a [u8],
+    mode: Mode,
+}
+
+impl Iso2022JpChars<'_> {
+    /// Get the remaining undecoded data. Unlike the original data, this may not start in ASCII
+    /// mode.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes
+    }
+}
+
+impl Iterator for Iso2022JpChars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.bytes.first() == Some(&ESC) {
+            // The data was validated, so this is always a complete escape sequence
+            self.mode = Mode::from_escape(self.bytes).unwrap_or(self.mode);
+            self.bytes = &self.bytes[3..];
+        }
+
+        match (self.mode, self.bytes) {
+            (_, []) => None,
+            (Mode::Jis0208, [first, second, rest @ ..]) => {
+                self.bytes = rest;
+                decode_pair(*first, *second)
+            }
+            (mode, [b, rest @ ..]) => {
+                self.bytes = rest;
+                Some(decode_single(mode, *b))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.bytes.len()))
+    }
+}
+
+impl FusedIterator for Iso2022JpChars<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_roundtrip() {
+        let text = "Hello, 世界!\n日本語のテキスト、¥100‾\\~\n";
+        let bytes = Iso2022Jp::encode(Str::from_std(text)).unwrap();
+        assert_eq!(Iso2022Jp::validate(&bytes), Ok(()));
+        let decoded = Iso2022Jp::decode(&bytes)
+            .unwrap()
+            .collect::<alloc::string::String>();
+        assert_eq!(decoded, text);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_encode_minimal() {
+        let encode = |text| Iso2022Jp::encode(Str::from_std(text)).unwrap();
+
+        assert_eq!(encode("abc"), b"abc");
+        assert_eq!(encode("漢字"), b"\x1B$B\x34\x41\x3B\x7A\x1B(B");
+        assert_eq!(encode("a漢字b"), b"a\x1B$B\x34\x41\x3B\x7A\x1B(Bb");
+        // Stays in Roman mode for characters shared with ASCII
+        assert_eq!(encode("¥1 ¥2"), b"\x1B(J\x5C1 \x5C2\x1B(B");
+        assert_eq!(encode("¥\\"), b"\x1B(J\x5C\x1B(B\\");
+        assert_eq!(encode(""), b"");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_encode_invalid() {
+        let err = Iso2022Jp::encode(Str::from_std("漢字ｱ")).unwrap_err();
+        assert_eq!(err.valid_up_to(), 6);
+        assert_eq!(err.char(), 'ｱ');
+        assert_eq!(err.char_len(), 3);
+
+        let err = Iso2022Jp::encode(Str::from_std("a\x1Bb")).unwrap_err();
+        assert_eq!(err.valid_up_to(), 1);
+        assert_eq!(err.char(), '\x1B');
+    }
+
+    #[test]
+    fn test_decode() {
+        let bytes = b"\x1B$@\x34\x41\x3B\x7A\x1B(J\x5C\x7E\x1B(B\x5C\x7E";
+        assert!(Iso2022Jp::decode(bytes).unwrap().eq("漢字¥‾\\~".chars()));
+
+        // Redundant escape sequences are allowed
+        let bytes = b"\x1B$B\x1B(Ba\x1B(B";
+        assert!(Iso2022Jp::decode(bytes).unwrap().eq(['a']));
+
+        let mut chars = Iso2022Jp::decode(b"a\x1B$B\x34\x41\x1B(B").unwrap();
+        assert_eq!(chars.next(), Some('a'));
+        assert_eq!(chars.as_bytes(), b"\x1B$B\x34\x41\x1B(B");
+        assert_eq!(chars.next(), Some('漢'));
+        assert_eq!(chars.next(), None);
+        assert_eq!(chars.as_bytes(), b"");
+    }
+
+    #[test]
+    fn test_validate() {
+        let cases: [(&[u8], usize, Option<u8>); 12] = [
+            // Doesn't end in ASCII mode
+            (b"a\x1B$B\x34\x41", 6, None),
+            (b"\x1B(J\x5C", 4, None),
+            // Truncated escape sequence
+            (b"a\x1B$", 1, None),
+            // Unknown escape sequence
+            (b"a\x1B$C\x1B(B", 1, Some(1)),
+            (b"a\x1Bb", 1, Some(1)),
+            // JIS X 0212, from ISO-2022-JP-1
+            (b"a\x1B$(D\x30\x21\x1B(B", 1, Some(1)),
+            // High bytes and shifts
+            (b"a\x80", 1, Some(1)),
+            (b"a\x0E", 1, Some(1)),
+            // Truncated character
+            (b"\x1B$B\x34", 3, None),
+            // Control code in JIS X 0208 mode
+            (b"\x1B$B\x34\x41\n\x1B(B", 5, Some(1)),
+            (b"\x1B$B\x34\n\x1B(B", 3, Some(1)),
+            // Unmapped character
+            (b"\x1B$B\x34\x41\x2B\x21\x1B(B", 5, Some(2)),
+        ];
+        for (bytes, valid_up_to, error_len) in cases {
+            assert_eq!(
+                Iso2022Jp::validate(bytes),
+                Err(ValidateError {
+                    valid_up_to,
+                    error_len
+                }),
+                "{:x?}",
+                bytes
+            );
+        }
+
+        assert_eq!(Iso2022Jp::validate(b""), Ok(()));
+        assert_eq!(Iso2022Jp::validate(b"line1\r\nline2\t\0"), Ok(()));
+    }
+}
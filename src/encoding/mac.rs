@@ -1,5 +1,10 @@
+#[cfg(feature = "rand")]
+use crate::encoding::sample_single_byte;
 use crate::encoding::sealed::Sealed;
-use crate::encoding::{AlwaysValid, NullTerminable, ValidateError};
+use crate::encoding::{
+    impl_ascii_compatible, AlwaysValid, EncodingKind, NullChar, NullTerminable, SingleByte,
+    ValidateError,
+};
 use crate::{Encoding, Str};
 #[cfg(feature = "rand")]
 use rand::distributions::Distribution;
@@ -19,7 +24,7 @@ const DECODE_MAP_ROMAN: [char; 128] = [
 
 /// The [macOS Roman](https://en.wikipedia.org/wiki/Mac_OS_Roman) encoding.
 #[non_exhaustive]
-#[derive(Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct MacRoman;
 
 impl Sealed for MacRoman {}
@@ -28,11 +33,17 @@ impl Encoding for MacRoman {
     const REPLACEMENT: char = '?';
     const MAX_LEN: usize = 1;
     type Bytes = u8;
+    const KIND: EncodingKind = EncodingKind::MacRoman;
+    const ASCII_COMPATIBLE: bool = true;
 
     fn shorthand() -> &'static str {
         "mac_roman"
     }
 
+    fn aliases() -> &'static [&'static str] {
+        &["csmacintosh", "mac", "macintosh", "x-mac-roman"]
+    }
+
     fn validate(_: &[u8]) -> Result<(), ValidateError> {
         Ok(())
     }
@@ -59,28 +70,24 @@ impl Encoding for MacRoman {
         true
     }
 
-    fn char_len(c: char) -> usize {
-        if (c as u32) < 0x80 || DECODE_MAP_ROMAN.contains(&c) {
-            1
-        } else {
-            0
-        }
+    fn char_len(c: char) -> Option<usize> {
+        ((c as u32) < 0x80 || DECODE_MAP_ROMAN.contains(&c)).then_some(1)
     }
 }
 
 impl NullTerminable for MacRoman {}
 
+impl NullChar for MacRoman {}
+
+impl_ascii_compatible!(MacRoman);
+
 impl AlwaysValid for MacRoman {}
 
+impl SingleByte for MacRoman {}
+
 #[cfg(feature = "rand")]
 impl Distribution<char> for MacRoman {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
-        // Number of characters
-        let c = rng.gen::<u8>();
-        if c <= 0x7F {
-            char::from(c)
-        } else {
-            DECODE_MAP_ROMAN[(c - 0x80) as usize]
-        }
+        sample_single_byte::<Self, R>(rng)
     }
 }
@@ -0,0 +1,612 @@
+use crate::encoding::Encoding;
+use core::fmt;
+use core::str::FromStr;
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Invoke a macro once for every built-in encoding, passing the path to the encoding type and its
+/// shorthand name. This allows code to handle every encoding supported by the crate without
+/// listing them manually.
+///
+/// ```
+/// macro_rules! print_name {
+///     ($enc:ty, $name:literal) => {
+///         println!("{}: {}", $name, core::any::type_name::<$enc>());
+///     };
+/// }
+///
+/// enrede::for_each_encoding!(print_name);
+/// ```
+#[macro_export]
+macro_rules! for_each_encoding {
+    ($mac:ident) => {
+        $mac!($crate::encoding::Ascii, "ascii");
+        $mac!($crate::encoding::ExtendedAscii, "ascii_ext");
+        $mac!($crate::encoding::Big5, "big5");
+        $mac!($crate::encoding::Iso8859_2, "iso5889_2");
+        $mac!($crate::encoding::Iso8859_15, "iso5889_15");
+        $mac!($crate::encoding::JisX0201, "jisx0201");
+        $mac!($crate::encoding::JisX0208, "jisx0208");
+        $mac!($crate::encoding::MacRoman, "mac_roman");
+        $mac!($crate::encoding::ShiftJis, "shiftjis");
+        $mac!($crate::encoding::ShiftJisAscii, "shiftjis_ascii");
+        $mac!($crate::encoding::Utf8, "utf8");
+        $mac!($crate::encoding::Utf16LE, "utf16le");
+        $mac!($crate::encoding::Utf16BE, "utf16be");
+        $mac!($crate::encoding::Utf32LE, "utf32le");
+        $mac!($crate::encoding::Utf32BE, "utf32be");
+        $mac!($crate::encoding::Win1251, "win1251");
+        $mac!($crate::encoding::Win1252, "win1252");
+        $mac!($crate::encoding::Win1252Loose, "win1252_loose");
+    };
+}
+
+/// The shorthand names of every built-in encoding, in the same order as [`for_each_encoding`].
+pub const ALL_SHORTHANDS: &[&str] = &[
+    "ascii",
+    "ascii_ext",
+    "big5",
+    "iso5889_2",
+    "iso5889_15",
+    "jisx0201",
+    "jisx0208",
+    "mac_roman",
+    "shiftjis",
+    "shiftjis_ascii",
+    "utf8",
+    "utf16le",
+    "utf16be",
+    "utf32le",
+    "utf32be",
+    "win1251",
+    "win1252",
+    "win1252_loose",
+];
+
+/// A value identifying an encoding, for storing in configuration or reporting in messages. Every
+/// encoding has one, available as [`Encoding::KIND`].
+///
+/// Kinds are written out and parsed by their shorthand name, and parsing also accepts any label
+/// recognized by [`from_label`]. With the `serde` feature, they serialize as their shorthand name.
+///
+/// ```
+/// # use enrede::encoding::{EncodingKind, Utf16LE};
+/// # use enrede::Encoding;
+/// let kind: EncodingKind = "windows-1251".parse().unwrap();
+/// assert_eq!(kind, EncodingKind::Win1251);
+/// assert_eq!(kind.to_string(), "win1251");
+/// assert_eq!(Utf16LE::KIND.name(), "UTF-16 (little endian)");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EncodingKind {
+    /// [`Ascii`](super::Ascii)
+    Ascii,
+    /// [`ExtendedAscii`](super::ExtendedAscii)
+    ExtendedAscii,
+    /// [`Big5`](super::Big5)
+    Big5,
+    /// [`Iso8859_2`](super::Iso8859_2)
+    Iso8859_2,
+    /// [`Iso8859_15`](super::Iso8859_15)
+    Iso8859_15,
+    /// [`JisX0201`](super::JisX0201)
+    JisX0201,
+    /// [`JisX0208`](super::JisX0208)
+    JisX0208,
+    /// [`MacRoman`](super::MacRoman)
+    MacRoman,
+    /// [`ShiftJis`](super::ShiftJis)
+    ShiftJis,
+    /// [`ShiftJisAscii`](super::ShiftJisAscii)
+    ShiftJisAscii,
+    /// [`Utf8`](super::Utf8)
+    Utf8,
+    /// [`Utf16LE`](super::Utf16LE)
+    Utf16LE,
+    /// [`Utf16BE`](super::Utf16BE)
+    Utf16BE,
+    /// [`Utf32LE`](super::Utf32LE)
+    Utf32LE,
+    /// [`Utf32BE`](super::Utf32BE)
+    Utf32BE,
+    /// [`Win1251`](super::Win1251)
+    Win1251,
+    /// [`Win1252`](super::Win1252)
+    Win1252,
+    /// [`Win1252Loose`](super::Win1252Loose)
+    Win1252Loose,
+    /// Any [`CustomSingleByte`](super::CustomSingleByte) encoding, or a test encoding from
+    /// `test_util`. This can't be parsed, as it doesn't identify a specific encoding.
+    Custom,
+}
+
+impl EncodingKind {
+    /// Every built-in encoding kind, in the same order as [`for_each_encoding`]. This excludes
+    /// [`EncodingKind::Custom`].
+    pub const ALL: &'static [EncodingKind] = &[
+        EncodingKind::Ascii,
+        EncodingKind::ExtendedAscii,
+        EncodingKind::Big5,
+        EncodingKind::Iso8859_2,
+        EncodingKind::Iso8859_15,
+        EncodingKind::JisX0201,
+        EncodingKind::JisX0208,
+        EncodingKind::MacRoman,
+        EncodingKind::ShiftJis,
+        EncodingKind::ShiftJisAscii,
+        EncodingKind::Utf8,
+        EncodingKind::Utf16LE,
+        EncodingKind::Utf16BE,
+        EncodingKind::Utf32LE,
+        EncodingKind::Utf32BE,
+        EncodingKind::Win1251,
+        EncodingKind::Win1252,
+        EncodingKind::Win1252Loose,
+    ];
+
+    /// A human-readable name for the encoding, such as `"Shift JIS"` or `"Windows-1252"`
+    pub const fn name(self) -> &'static str {
+        match self {
+            EncodingKind::Ascii => "ASCII",
+            EncodingKind::ExtendedAscii => "Extended ASCII",
+            EncodingKind::Big5 => "Big5",
+            EncodingKind::Iso8859_2 => "ISO-8859-2",
+            EncodingKind::Iso8859_15 => "ISO-8859-15",
+            EncodingKind::JisX0201 => "JIS X 0201",
+            EncodingKind::JisX0208 => "JIS X 0208",
+            EncodingKind::MacRoman => "Mac OS Roman",
+            EncodingKind::ShiftJis => "Shift JIS",
+            EncodingKind::ShiftJisAscii => "Shift JIS (ASCII)",
+            EncodingKind::Utf8 => "UTF-8",
+            EncodingKind::Utf16LE => "UTF-16 (little endian)",
+            EncodingKind::Utf16BE => "UTF-16 (big endian)",
+            EncodingKind::Utf32LE => "UTF-32 (little endian)",
+            EncodingKind::Utf32BE => "UTF-32 (big endian)",
+            EncodingKind::Win1251 => "Windows-1251",
+            EncodingKind::Win1252 => "Windows-1252",
+            EncodingKind::Win1252Loose => "Windows-1252 (loose)",
+            EncodingKind::Custom => "Custom single-byte",
+        }
+    }
+
+    /// The shorthand name for the encoding, as used by [`visit_shorthand`]
+    pub const fn shorthand(self) -> &'static str {
+        match self {
+            EncodingKind::Ascii => "ascii",
+            EncodingKind::ExtendedAscii => "ascii_ext",
+            EncodingKind::Big5 => "big5",
+            EncodingKind::Iso8859_2 => "iso5889_2",
+            EncodingKind::Iso8859_15 => "iso5889_15",
+            EncodingKind::JisX0201 => "jisx0201",
+            EncodingKind::JisX0208 => "jisx0208",
+            EncodingKind::MacRoman => "mac_roman",
+            EncodingKind::ShiftJis => "shiftjis",
+            EncodingKind::ShiftJisAscii => "shiftjis_ascii",
+            EncodingKind::Utf8 => "utf8",
+            EncodingKind::Utf16LE => "utf16le",
+            EncodingKind::Utf16BE => "utf16be",
+            EncodingKind::Utf32LE => "utf32le",
+            EncodingKind::Utf32BE => "utf32be",
+            EncodingKind::Win1251 => "win1251",
+            EncodingKind::Win1252 => "win1252",
+            EncodingKind::Win1252Loose => "win1252_loose",
+            EncodingKind::Custom => "custom",
+        }
+    }
+}
+
+impl fmt::Display for EncodingKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.shorthand())
+    }
+}
+
+impl FromStr for EncodingKind {
+    type Err = UnknownEncoding;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let shorthand = from_label(s).ok_or(UnknownEncoding)?;
+        EncodingKind::ALL
+            .iter()
+            .copied()
+            .find(|kind| kind.shorthand() == shorthand)
+            .ok_or(UnknownEncoding)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for EncodingKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.shorthand())
+    }
+}
+
+#[cfg(feature = "serde")]
+struct EncodingKindVisitor;
+
+#[cfg(feature = "serde")]
+impl de::Visitor<'_> for EncodingKindVisitor {
+    type Value = EncodingKind;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an encoding name")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse()
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for EncodingKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(EncodingKindVisitor)
+    }
+}
+
+/// Error encountered while parsing an [`EncodingKind`] from a name which doesn't identify any
+/// built-in encoding.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnknownEncoding;
+
+impl fmt::Display for UnknownEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown encoding name")
+    }
+}
+
+impl core::error::Error for UnknownEncoding {}
+
+/// An operation generic over an encoding, selected at runtime by [`visit_shorthand`].
+pub trait EncodingVisitor {
+    /// The result of the operation
+    type Output;
+
+    /// Perform the operation for encoding `E`
+    fn visit<E: Encoding>(self) -> Self::Output;
+}
+
+/// Find the built-in encoding with the given shorthand name, and call `visitor` with it. Returns
+/// [`None`] if no encoding has that name.
+///
+/// ```
+/// # use enrede::encoding::{visit_shorthand, EncodingVisitor};
+/// # use enrede::{Encoding, Str};
+/// struct IsValid<'a>(&'a [u8]);
+///
+/// impl EncodingVisitor for IsValid<'_> {
+///     type Output = bool;
+///
+///     fn visit<E: Encoding>(self) -> bool {
+///         Str::<E>::from_bytes(self.0).is_ok()
+///     }
+/// }
+///
+/// assert_eq!(visit_shorthand("ascii", IsValid(b"\xFF")), Some(false));
+/// assert_eq!(visit_shorthand("win1252", IsValid(b"\xFF")), Some(true));
+/// assert_eq!(visit_shorthand("unknown", IsValid(b"\xFF")), None);
+/// ```
+pub fn visit_shorthand<V: EncodingVisitor>(name: &str, visitor: V) -> Option<V::Output> {
+    macro_rules! visit {
+        ($enc:ty, $name:literal) => {
+            if name == $name {
+                return Some(visitor.visit::<$enc>());
+            }
+        };
+    }
+
+    crate::for_each_encoding!(visit);
+    None
+}
+
+/// Find the built-in encoding named by a charset label, such as one from an HTTP `Content-Type`
+/// header or an HTML `<meta charset>` tag, returning its shorthand name for use with
+/// [`visit_shorthand`]. Returns [`None`] if the label isn't recognized.
+///
+/// As in the [WHATWG Encoding Standard](https://encoding.spec.whatwg.org/#concept-encoding-get),
+/// leading and trailing ASCII whitespace is ignored and labels are matched ASCII
+/// case-insensitively. Each encoding's [`aliases`](Encoding::aliases) are recognized, as well as
+/// its shorthand name. The standard's mappings are followed, with some notable results:
+///
+/// - `latin1`, `iso-8859-1`, and `windows-1252` all name [`Win1252Loose`](super::Win1252Loose),
+///   which matches the standard's decoder for those labels
+/// - `shift_jis` and its aliases name [`ShiftJisAscii`](super::ShiftJisAscii), decoding `0x5C`
+///   as a backslash
+/// - `utf-16`, `unicode`, and `ucs-2` name [`Utf16LE`](super::Utf16LE)
+///
+/// The standard also maps `ascii` and `us-ascii` to its windows-1252 decoder. This crate deviates
+/// from that, as it has a distinct [`Ascii`](super::Ascii) encoding which those labels name
+/// instead.
+///
+/// ```
+/// # use enrede::encoding::from_label;
+/// assert_eq!(from_label("UTF-16LE"), Some("utf16le"));
+/// assert_eq!(from_label(" latin1\n"), Some("win1252_loose"));
+/// assert_eq!(from_label("csShiftJIS"), Some("shiftjis_ascii"));
+/// assert_eq!(from_label("ebcdic"), None);
+/// ```
+pub fn from_label(label: &str) -> Option<&'static str> {
+    let label = label.trim_matches(|c| matches!(c, '\t' | '\n' | '\x0C' | '\r' | ' '));
+    macro_rules! check {
+        ($enc:ty, $name:literal) => {
+            if label.eq_ignore_ascii_case($name)
+                || <$enc>::aliases()
+                    .iter()
+                    .any(|alias| label.eq_ignore_ascii_case(alias))
+            {
+                return Some($name);
+            }
+        };
+    }
+
+    crate::for_each_encoding!(check);
+    None
+}
+
+/// Detect a Unicode byte order mark at the start of `bytes`, returning the shorthand name of the
+/// encoding it marks and the length of the mark in bytes. Returns [`None`] if `bytes` doesn't
+/// start with a byte order mark.
+///
+/// The UTF-32LE mark begins with the UTF-16LE mark, so is preferred where both match. Data which
+/// starts with the UTF-16LE mark followed by a null character is indistinguishable from UTF-32LE.
+///
+/// ```
+/// # use enrede::encoding::detect_bom;
+/// assert_eq!(detect_bom(b"\xEF\xBB\xBFHello"), Some(("utf8", 3)));
+/// assert_eq!(detect_bom(b"\xFE\xFF\0H"), Some(("utf16be", 2)));
+/// assert_eq!(detect_bom(b"Hello"), None);
+/// ```
+pub fn detect_bom(bytes: &[u8]) -> Option<(&'static str, usize)> {
+    const BOMS: &[(&[u8], &str)] = &[
+        (b"\xEF\xBB\xBF", "utf8"),
+        (b"\xFF\xFE\0\0", "utf32le"),
+        (b"\0\0\xFE\xFF", "utf32be"),
+        (b"\xFF\xFE", "utf16le"),
+        (b"\xFE\xFF", "utf16be"),
+    ];
+
+    BOMS.iter()
+        .find(|(bom, _)| bytes.starts_with(bom))
+        .map(|(bom, name)| (*name, bom.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::ArrayLike;
+    use alloc::vec::Vec;
+    use core::fmt;
+    use core::hash::Hash;
+
+    #[test]
+    fn test_shorthands() {
+        let mut idx = 0;
+        macro_rules! check {
+            ($enc:ty, $name:literal) => {
+                assert_eq!(<$enc>::shorthand(), $name);
+                assert_eq!(ALL_SHORTHANDS[idx], $name);
+                idx += 1;
+            };
+        }
+        crate::for_each_encoding!(check);
+        assert_eq!(idx, ALL_SHORTHANDS.len());
+
+        struct Name;
+
+        impl EncodingVisitor for Name {
+            type Output = &'static str;
+
+            fn visit<E: Encoding>(self) -> Self::Output {
+                E::shorthand()
+            }
+        }
+
+        for name in ALL_SHORTHANDS {
+            assert_eq!(visit_shorthand(name, Name), Some(*name));
+        }
+    }
+
+    #[test]
+    fn test_from_label() {
+        let cases = [
+            ("utf-8", Some("utf8")),
+            ("UTF8", Some("utf8")),
+            ("unicode-1-1-utf-8", Some("utf8")),
+            ("  utf-8\t", Some("utf8")),
+            ("UTF-16LE", Some("utf16le")),
+            ("utf-16", Some("utf16le")),
+            ("ucs-2", Some("utf16le")),
+            ("UTF-16BE", Some("utf16be")),
+            ("unicodeFFFE", Some("utf16be")),
+            ("utf-32le", Some("utf32le")),
+            ("utf-32be", Some("utf32be")),
+            ("windows-1252", Some("win1252_loose")),
+            ("latin1", Some("win1252_loose")),
+            ("ISO-8859-1", Some("win1252_loose")),
+            ("iso_8859-1:1987", Some("win1252_loose")),
+            ("cp819", Some("win1252_loose")),
+            ("win1252", Some("win1252")),
+            ("us-ascii", Some("ascii")),
+            ("ASCII", Some("ascii")),
+            ("ANSI_X3.4-1968", Some("ascii")),
+            ("windows-1251", Some("win1251")),
+            ("x-cp1251", Some("win1251")),
+            ("latin2", Some("iso5889_2")),
+            ("iso-8859-2", Some("iso5889_2")),
+            ("ISO-8859-15", Some("iso5889_15")),
+            ("l9", Some("iso5889_15")),
+            ("macintosh", Some("mac_roman")),
+            ("x-mac-roman", Some("mac_roman")),
+            ("Big5", Some("big5")),
+            ("csbig5", Some("big5")),
+            ("Shift_JIS", Some("shiftjis_ascii")),
+            ("csshiftjis", Some("shiftjis_ascii")),
+            ("windows-31j", Some("shiftjis_ascii")),
+            ("shiftjis", Some("shiftjis")),
+            ("x0201", Some("jisx0201")),
+            ("JIS_C6226-1983", Some("jisx0208")),
+            ("", None),
+            ("utf-7", None),
+            ("big5-hkscs", None),
+            ("latin-1", None),
+            ("utf-8x", None),
+            ("u\u{0}tf-8", None),
+            ("\u{A0}utf-8", None),
+        ];
+        for (label, expected) in cases {
+            assert_eq!(from_label(label), expected, "label {label:?}");
+        }
+    }
+
+    #[test]
+    fn test_aliases() {
+        let mut seen = Vec::new();
+        macro_rules! check {
+            ($enc:ty, $name:literal) => {
+                for alias in <$enc>::aliases() {
+                    assert_eq!(alias.to_ascii_lowercase(), *alias);
+                    assert_eq!(from_label(alias), Some($name), "alias {alias:?}");
+                    assert!(!seen.contains(alias), "duplicate alias {alias:?}");
+                    seen.push(*alias);
+                }
+            };
+        }
+        crate::for_each_encoding!(check);
+    }
+
+    #[test]
+    fn test_encoding_kind() {
+        use alloc::string::ToString;
+
+        let mut idx = 0;
+        macro_rules! check {
+            ($enc:ty, $name:literal) => {
+                let kind = <$enc>::KIND;
+                assert_eq!(EncodingKind::ALL[idx], kind);
+                assert_eq!(kind.shorthand(), $name);
+                assert_eq!(kind.to_string(), $name);
+                assert_eq!(kind.to_string().parse(), Ok(kind));
+                assert_eq!(<$enc>::name(), kind.name());
+                idx += 1;
+            };
+        }
+        crate::for_each_encoding!(check);
+        assert_eq!(idx, EncodingKind::ALL.len());
+
+        // Fails to compile if a kind is added without being handled here, which should be followed
+        // by adding it to `ALL` and the registry
+        for kind in EncodingKind::ALL
+            .iter()
+            .copied()
+            .chain([EncodingKind::Custom])
+        {
+            match kind {
+                EncodingKind::Ascii
+                | EncodingKind::ExtendedAscii
+                | EncodingKind::Big5
+                | EncodingKind::Iso8859_2
+                | EncodingKind::Iso8859_15
+                | EncodingKind::JisX0201
+                | EncodingKind::JisX0208
+                | EncodingKind::MacRoman
+                | EncodingKind::ShiftJis
+                | EncodingKind::ShiftJisAscii
+                | EncodingKind::Utf8
+                | EncodingKind::Utf16LE
+                | EncodingKind::Utf16BE
+                | EncodingKind::Utf32LE
+                | EncodingKind::Utf32BE
+                | EncodingKind::Win1251
+                | EncodingKind::Win1252
+                | EncodingKind::Win1252Loose
+                | EncodingKind::Custom => (),
+            }
+        }
+
+        assert_eq!("UTF-16LE".parse(), Ok(EncodingKind::Utf16LE));
+        assert_eq!(" Shift_JIS ".parse(), Ok(EncodingKind::ShiftJisAscii));
+        assert_eq!("custom".parse::<EncodingKind>(), Err(UnknownEncoding));
+        assert_eq!("".parse::<EncodingKind>(), Err(UnknownEncoding));
+        assert_eq!(EncodingKind::Custom.to_string(), "custom");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_encoding_kind_serde() {
+        for kind in EncodingKind::ALL {
+            let json = serde_json::to_string(kind).unwrap();
+            assert_eq!(json, alloc::format!("\"{}\"", kind.shorthand()));
+            assert_eq!(serde_json::from_str::<EncodingKind>(&json).unwrap(), *kind);
+        }
+        assert_eq!(
+            serde_json::from_str::<EncodingKind>("\"latin1\"").unwrap(),
+            EncodingKind::Win1252Loose
+        );
+        assert!(serde_json::from_str::<EncodingKind>("\"ebcdic\"").is_err());
+    }
+
+    #[test]
+    fn test_detect_bom() {
+        struct Bom;
+
+        impl EncodingVisitor for Bom {
+            type Output = bool;
+
+            fn visit<E: Encoding>(self) -> Self::Output {
+                let bom = E::encode_char('\u{FEFF}').unwrap();
+                let bom = bom.slice();
+                detect_bom(bom) == Some((E::shorthand(), bom.len()))
+            }
+        }
+
+        for name in ["utf8", "utf16le", "utf16be", "utf32le", "utf32be"] {
+            assert_eq!(visit_shorthand(name, Bom), Some(true));
+        }
+
+        assert_eq!(detect_bom(b"\xFF\xFEA\0"), Some(("utf16le", 2)));
+        assert_eq!(detect_bom(b"\xEF\xBB"), None);
+        assert_eq!(detect_bom(b""), None);
+    }
+
+    #[test]
+    fn test_derives() {
+        fn assert_derives<E>()
+        where
+            E: fmt::Debug + Default + Clone + Copy + PartialEq + Eq + Hash,
+        {
+            let enc = E::default();
+            let copy = enc;
+            assert_eq!(enc.clone(), copy);
+        }
+
+        macro_rules! check {
+            ($enc:ty, $name:literal) => {
+                assert_derives::<$enc>();
+            };
+        }
+        crate::for_each_encoding!(check);
+    }
+
+    #[test]
+    fn test_registry_complete() {
+        // Every encoding has a kind, and `test_encoding_kind` can't compile until a new kind is
+        // handled, so the registry is complete if it visits each built-in kind exactly once
+        let mut kinds = Vec::new();
+        macro_rules! check {
+            ($enc:ty, $name:literal) => {
+                let kind = <$enc>::KIND;
+                assert_ne!(kind, EncodingKind::Custom, "{}", $name);
+                assert!(!kinds.contains(&kind), "duplicate kind {kind:?}");
+                kinds.push(kind);
+            };
+        }
+        crate::for_each_encoding!(check);
+
+        for kind in EncodingKind::ALL {
+            assert!(kinds.contains(kind), "missing kind {kind:?}");
+        }
+        assert_eq!(kinds.len(), EncodingKind::ALL.len());
+    }
+}
@@ -1,5 +1,8 @@
-use crate::encoding::sealed::Sealed;
-use crate::encoding::{Encoding, NullTerminable, ValidateError};
+use crate::encoding::sealed::{Sealed, UnicodeForm};
+use crate::encoding::{
+    impl_ascii_compatible, recode_char, EncodeError, Encoding, EncodingKind, NullChar,
+    NullTerminable, RecodeStepError, UnicodeComplete, ValidateError, WideNullTerminable,
+};
 use crate::str::Str;
 use arrayvec::ArrayVec;
 #[cfg(feature = "rand")]
@@ -7,7 +10,7 @@ use rand::{distributions::Distribution, Rng};
 
 /// The [UTF-8](https://en.wikipedia.org/wiki/UTF-8) encoding
 #[non_exhaustive]
-#[derive(Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Utf8;
 
 impl Sealed for Utf8 {}
@@ -16,11 +19,25 @@ impl Encoding for Utf8 {
     const REPLACEMENT: char = '\u{FFFD}';
     const MAX_LEN: usize = 4;
     type Bytes = ArrayVec<u8, 4>;
+    const KIND: EncodingKind = EncodingKind::Utf8;
+    const ASCII_COMPATIBLE: bool = true;
+    const UNICODE: Option<UnicodeForm> = Some(UnicodeForm::Utf8);
 
     fn shorthand() -> &'static str {
         "utf8"
     }
 
+    fn aliases() -> &'static [&'static str] {
+        &[
+            "unicode-1-1-utf-8",
+            "unicode11utf8",
+            "unicode20utf8",
+            "utf-8",
+            "utf8",
+            "x-unicode20utf8",
+        ]
+    }
+
     fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
         core::str::from_utf8(bytes)
             .map(|_| ())
@@ -30,11 +47,21 @@ impl Encoding for Utf8 {
             })
     }
 
+    fn encode(char: char, out: &mut [u8]) -> Result<usize, EncodeError> {
+        let len = char.len_utf8();
+        match out.get_mut(..len) {
+            Some(out) => {
+                char.encode_utf8(out);
+                Ok(len)
+            }
+            None => Err(EncodeError::NeedSpace { len }),
+        }
+    }
+
     fn encode_char(c: char) -> Option<Self::Bytes> {
-        let mut out = [0; 4];
-        let res = c.encode_utf8(&mut out);
-        let mut out = ArrayVec::new();
-        out.extend(res.as_bytes().iter().copied());
+        let mut out = ArrayVec::from([0; 4]);
+        let len = c.encode_utf8(&mut out).len();
+        out.truncate(len);
         Some(out)
     }
 
@@ -47,13 +74,19 @@ impl Encoding for Utf8 {
         str.as_std().is_char_boundary(idx)
     }
 
-    fn char_len(c: char) -> usize {
-        c.len_utf8()
+    fn char_len(c: char) -> Option<usize> {
+        Some(c.len_utf8())
     }
 }
 
 impl NullTerminable for Utf8 {}
 
+impl NullChar for Utf8 {}
+
+impl_ascii_compatible!(Utf8);
+
+impl UnicodeComplete for Utf8 {}
+
 #[cfg(feature = "rand")]
 impl Distribution<char> for Utf8 {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
@@ -86,6 +119,7 @@ macro_rules! utf16_impl {
     (
         $name:ident,
         $shorthand:literal,
+        [$($alias:literal),* $(,)?],
         $method_from:ident,
         $method_to:ident,
         $idx_add:literal,
@@ -95,7 +129,7 @@ macro_rules! utf16_impl {
         #[doc = $docname]
         #[doc = "](https://en.wikipedia.org/wiki/UTF-16#Byte-order_encoding_schemes) encoding"]
         #[non_exhaustive]
-        #[derive(Default)]
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
         pub struct $name;
 
         impl Sealed for $name {}
@@ -104,11 +138,17 @@ macro_rules! utf16_impl {
             const REPLACEMENT: char = '\u{FFFD}';
             const MAX_LEN: usize = 4;
             type Bytes = ArrayVec<u8, 4>;
+            const KIND: EncodingKind = EncodingKind::$name;
+            const UNICODE: Option<UnicodeForm> = Some(UnicodeForm::$name);
 
             fn shorthand() -> &'static str {
                 $shorthand
             }
 
+            fn aliases() -> &'static [&'static str] {
+                &[$($alias),*]
+            }
+
             fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
                 let chunks = bytes.chunks_exact(2);
 
@@ -147,8 +187,9 @@ macro_rules! utf16_impl {
                 }
 
                 if surrogate {
+                    // Ignore any odd trailing byte, the error starts at the unpaired surrogate
                     return Err(ValidateError {
-                        valid_up_to: bytes.len() - 2,
+                        valid_up_to: (bytes.len() & !1) - 2,
                         error_len: None,
                     });
                 }
@@ -159,14 +200,23 @@ macro_rules! utf16_impl {
                 }
             }
 
-            fn encode_char(c: char) -> Option<Self::Bytes> {
-                let mut out = [0; 2];
-                let res = c.encode_utf16(&mut out);
-                let mut out = ArrayVec::new();
-                out.extend(res[0].$method_to());
-                if res.len() > 1 {
-                    out.extend(res[1].$method_to());
+            fn encode(char: char, out: &mut [u8]) -> Result<usize, EncodeError> {
+                let len = char.len_utf16() * 2;
+                let Some(out) = out.get_mut(..len) else {
+                    return Err(EncodeError::NeedSpace { len });
+                };
+                let mut units = [0; 2];
+                let units = char.encode_utf16(&mut units);
+                for (unit, bytes) in units.iter().zip(out.chunks_exact_mut(2)) {
+                    bytes.copy_from_slice(&unit.$method_to());
                 }
+                Ok(len)
+            }
+
+            fn encode_char(c: char) -> Option<Self::Bytes> {
+                let mut out = ArrayVec::from([0; 4]);
+                let len = Self::encode(c, &mut out).ok()?;
+                out.truncate(len);
                 Some(out)
             }
 
@@ -190,14 +240,59 @@ macro_rules! utf16_impl {
             }
 
             fn char_bound(str: &Str<Self>, idx: usize) -> bool {
-                idx % 2 == 0 && !(0xD8..0xE0).contains(&str.as_bytes()[idx + $idx_add])
+                idx % 2 == 0 && !(0xDC..0xE0).contains(&str.as_bytes()[idx + $idx_add])
             }
 
-            fn char_len(c: char) -> usize {
-                c.len_utf16()
+            fn char_len(c: char) -> Option<usize> {
+                Some(c.len_utf16() * 2)
             }
         }
 
+        impl $name {
+            /// Convert UTF-16 code units into bytes for this encoding, checking that surrogates
+            /// are correctly paired. Errors are reported the same way as [`Encoding::validate`].
+            #[cfg(feature = "alloc")]
+            pub(crate) fn units_to_bytes(
+                units: impl IntoIterator<Item = u16>,
+                mut out: impl FnMut([u8; 2]),
+            ) -> Result<(), ValidateError> {
+                let mut units = units.into_iter();
+                let mut len = 0;
+                while let Some(unit) = units.next() {
+                    let err_len = match Kind::of(unit) {
+                        Kind::Char => {
+                            out(unit.$method_to());
+                            len += 2;
+                            continue;
+                        }
+                        Kind::High => match units.next() {
+                            Some(low) if Kind::of(low) == Kind::Low => {
+                                out(unit.$method_to());
+                                out(low.$method_to());
+                                len += 4;
+                                continue;
+                            }
+                            Some(next) if Kind::of(next) == Kind::Char => Some(2),
+                            Some(_) => Some(4),
+                            None => None,
+                        },
+                        Kind::Low => Some(2),
+                    };
+                    return Err(ValidateError {
+                        valid_up_to: len,
+                        error_len: err_len,
+                    });
+                }
+                Ok(())
+            }
+        }
+
+        impl UnicodeComplete for $name {}
+
+        impl WideNullTerminable for $name {
+            const UNIT_LEN: usize = 2;
+        }
+
         #[cfg(feature = "rand")]
         impl Distribution<char> for $name {
             fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
@@ -210,89 +305,248 @@ macro_rules! utf16_impl {
 utf16_impl!(
     Utf16BE,
     "utf16be",
+    ["unicodefffe", "utf-16be"],
     from_be_bytes,
     to_be_bytes,
-    1,
+    0,
     "UTF-16BE",
 );
 
 utf16_impl!(
     Utf16LE,
     "utf16le",
+    [
+        "csunicode",
+        "iso-10646-ucs-2",
+        "ucs-2",
+        "unicode",
+        "unicodefeff",
+        "utf-16",
+        "utf-16le",
+    ],
     from_le_bytes,
     to_le_bytes,
-    0,
+    1,
     "UTF-16LE",
 );
 
-/// The [UTF-32](https://en.wikipedia.org/wiki/UTF-32) encoding
-#[non_exhaustive]
-#[derive(Default)]
-pub struct Utf32;
+/// The [UTF-32](https://en.wikipedia.org/wiki/UTF-32) encoding. Like [`Utf16`], this is always
+/// little-endian, see [`Utf32Native`] for the encoding matching the target's `char` layout.
+pub type Utf32 = Utf32LE;
+
+/// The UTF-16 encoding matching the byte order of the target, and thus the in-memory layout of
+/// `[u16]`
+#[cfg(target_endian = "little")]
+pub type Utf16Native = Utf16LE;
+/// The UTF-16 encoding matching the byte order of the target, and thus the in-memory layout of
+/// `[u16]`
+#[cfg(target_endian = "big")]
+pub type Utf16Native = Utf16BE;
+
+/// The UTF-32 encoding matching the byte order of the target, and thus the in-memory layout of
+/// `[u32]` and `[char]`
+#[cfg(target_endian = "little")]
+pub type Utf32Native = Utf32LE;
+/// The UTF-32 encoding matching the byte order of the target, and thus the in-memory layout of
+/// `[u32]` and `[char]`
+#[cfg(target_endian = "big")]
+pub type Utf32Native = Utf32BE;
+
+macro_rules! utf32_impl {
+    (
+        $name:ident,
+        $shorthand:literal,
+        [$($alias:literal),* $(,)?],
+        $method_from:ident,
+        $method_to:ident,
+        $docname:literal $(,)?
+    ) => {
+        #[doc = "The ["]
+        #[doc = $docname]
+        #[doc = "](https://en.wikipedia.org/wiki/UTF-32) encoding"]
+        #[non_exhaustive]
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name;
 
-impl Sealed for Utf32 {}
+        impl Sealed for $name {}
 
-impl Encoding for Utf32 {
-    const REPLACEMENT: char = '\u{FFFD}';
-    const MAX_LEN: usize = 4;
-    type Bytes = [u8; 4];
+        impl Encoding for $name {
+            const REPLACEMENT: char = '\u{FFFD}';
+            const MAX_LEN: usize = 4;
+            type Bytes = [u8; 4];
+            const KIND: EncodingKind = EncodingKind::$name;
+            const UNICODE: Option<UnicodeForm> = Some(UnicodeForm::$name);
+            const FIXED_LEN: bool = true;
 
-    fn shorthand() -> &'static str {
-        "utf32"
-    }
+            fn shorthand() -> &'static str {
+                $shorthand
+            }
 
-    fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
-        for (idx, chunk) in bytes.chunks(4).enumerate() {
-            if chunk.len() != 4 {
-                return Err(ValidateError {
-                    valid_up_to: idx * 4,
-                    error_len: None,
-                });
+            fn aliases() -> &'static [&'static str] {
+                &[$($alias),*]
+            }
+
+            fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
+                for (idx, chunk) in bytes.chunks(4).enumerate() {
+                    if chunk.len() != 4 {
+                        return Err(ValidateError {
+                            valid_up_to: idx * 4,
+                            error_len: None,
+                        });
+                    }
+
+                    let c = u32::$method_from([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                    if char::from_u32(c).is_none() {
+                        return Err(ValidateError {
+                            valid_up_to: idx * 4,
+                            error_len: Some(4),
+                        });
+                    }
+                }
+
+                Ok(())
+            }
+
+            fn encode_char(c: char) -> Option<Self::Bytes> {
+                Some((c as u32).$method_to())
             }
 
-            let c = u32::from_ne_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
-            if (0xD800..0xE000).contains(&c) || (0x0011_0000..).contains(&c) {
-                return Err(ValidateError {
-                    valid_up_to: idx * 4,
-                    error_len: Some(4),
-                });
+            fn decode_char(str: &Str<Self>) -> (char, &Str<Self>) {
+                let bytes = str.as_bytes();
+                let c = u32::$method_from([bytes[0], bytes[1], bytes[2], bytes[3]]);
+                // SAFETY: Str is valid UTF-32, and thus guaranteed to contain valid `char` values
+                let c = unsafe { char::from_u32_unchecked(c) };
+                (c, &str[4..])
+            }
+
+            fn char_bound(_: &Str<Self>, idx: usize) -> bool {
+                idx.is_multiple_of(4)
+            }
+
+            fn char_len(_: char) -> Option<usize> {
+                Some(4)
             }
         }
 
-        Ok(())
-    }
+        impl UnicodeComplete for $name {}
 
-    fn encode_char(c: char) -> Option<Self::Bytes> {
-        Some((c as u32).to_le_bytes())
-    }
+        impl WideNullTerminable for $name {
+            const UNIT_LEN: usize = 4;
+        }
 
-    fn decode_char(str: &Str<Self>) -> (char, &Str<Self>) {
-        let bytes = str.as_bytes();
-        let c = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
-        // SAFETY: Str<Utf32> is guaranteed to contain valid `char` values
-        let c = unsafe { char::from_u32_unchecked(c) };
-        (c, &str[4..])
+        #[cfg(feature = "rand")]
+        impl Distribution<char> for $name {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+                rng.gen::<char>()
+            }
+        }
+    };
+}
+
+utf32_impl!(
+    Utf32LE,
+    "utf32le",
+    ["utf-32le", "csutf32le"],
+    from_le_bytes,
+    to_le_bytes,
+    "UTF-32LE"
+);
+
+utf32_impl!(
+    Utf32BE,
+    "utf32be",
+    ["utf-32be", "csutf32be"],
+    from_be_bytes,
+    to_be_bytes,
+    "UTF-32BE"
+);
+
+impl UnicodeForm {
+    fn unit_len(self) -> usize {
+        match self {
+            UnicodeForm::Utf8 => 1,
+            UnicodeForm::Utf16LE | UnicodeForm::Utf16BE => 2,
+            UnicodeForm::Utf32LE | UnicodeForm::Utf32BE => 4,
+        }
     }
 
-    fn char_bound(_: &Str<Self>, idx: usize) -> bool {
-        idx % 4 == 0
+    /// Read a single code unit, returning it if it's an ASCII character
+    fn read_ascii(self, unit: &[u8]) -> Option<u8> {
+        let c = match self {
+            UnicodeForm::Utf8 => unit[0] as u32,
+            UnicodeForm::Utf16LE => u16::from_le_bytes([unit[0], unit[1]]) as u32,
+            UnicodeForm::Utf16BE => u16::from_be_bytes([unit[0], unit[1]]) as u32,
+            UnicodeForm::Utf32LE => u32::from_le_bytes([unit[0], unit[1], unit[2], unit[3]]),
+            UnicodeForm::Utf32BE => u32::from_be_bytes([unit[0], unit[1], unit[2], unit[3]]),
+        };
+        if c < 0x80 {
+            Some(c as u8)
+        } else {
+            None
+        }
     }
 
-    fn char_len(_: char) -> usize {
-        4
+    /// Write an ASCII character as a single code unit
+    fn write_ascii(self, c: u8, unit: &mut [u8]) {
+        match self {
+            UnicodeForm::Utf8 => unit[0] = c,
+            UnicodeForm::Utf16LE => unit.copy_from_slice(&u16::from(c).to_le_bytes()),
+            UnicodeForm::Utf16BE => unit.copy_from_slice(&u16::from(c).to_be_bytes()),
+            UnicodeForm::Utf32LE => unit.copy_from_slice(&u32::from(c).to_le_bytes()),
+            UnicodeForm::Utf32BE => unit.copy_from_slice(&u32::from(c).to_be_bytes()),
+        }
     }
 }
 
-#[cfg(feature = "rand")]
-impl Distribution<char> for Utf32 {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
-        rng.gen::<char>()
+/// Recode between two Unicode encodings. Runs of ASCII are copied directly between code units,
+/// falling back to per-character recoding for everything else. Results, including errors, are
+/// identical to recoding character by character.
+pub(super) fn recode_unicode<E: Encoding, E2: Encoding>(
+    from: UnicodeForm,
+    to: UnicodeForm,
+    str: &Str<E>,
+    out: &mut [u8],
+) -> Result<usize, RecodeStepError> {
+    let input = str.as_bytes();
+    let (in_unit, out_unit) = (from.unit_len(), to.unit_len());
+    let mut in_pos = 0;
+    let mut out_pos = 0;
+    while in_pos < input.len() {
+        let run = usize::min(
+            (input.len() - in_pos) / in_unit,
+            (out.len() - out_pos) / out_unit,
+        );
+        let units_in = input[in_pos..in_pos + run * in_unit].chunks_exact(in_unit);
+        let units_out = out[out_pos..out_pos + run * out_unit].chunks_exact_mut(out_unit);
+        let mut copied = 0;
+        for (unit_in, unit_out) in units_in.zip(units_out) {
+            let Some(c) = from.read_ascii(unit_in) else {
+                break;
+            };
+            to.write_ascii(c, unit_out);
+            copied += 1;
+        }
+        in_pos += copied * in_unit;
+        out_pos += copied * out_unit;
+
+        // Either the input is done, we hit a non-ASCII character, or the output is full. The
+        // latter two are handled by the normal path.
+        if in_pos < input.len() {
+            // SAFETY: `in_pos` is always after a copied or decoded character, so is a boundary.
+            // Indexing would check that by scanning back from it, for every non-ASCII character.
+            let (c, rest) = E::decode_char(unsafe { str.slice_unchecked(in_pos..input.len()) });
+            let len = input.len() - rest.len() - in_pos;
+            out_pos = recode_char::<E2>(c, len, in_pos, out, out_pos)?;
+            in_pos += len;
+        }
     }
+    Ok(out_pos)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec::Vec;
     use bytemuck::must_cast_slice as cast_slice;
 
     #[allow(clippy::octal_escapes)]
@@ -332,6 +586,14 @@ mod tests {
                 error_len: None,
             })
         );
+        // dangling surrogate followed by an odd byte
+        assert_eq!(
+            Utf16LE::validate(b"a\0b\0\x01\xD8\x37"),
+            Err(ValidateError {
+                valid_up_to: 4,
+                error_len: None,
+            })
+        );
     }
 
     #[test]
@@ -357,6 +619,25 @@ mod tests {
         assert_eq!(c, 'b');
     }
 
+    #[test]
+    fn test_char_bound_utf16_le() {
+        // SAFETY: This test data is guaranteed valid
+        let str = unsafe { Str::from_bytes_unchecked(b"A\0\x01\xD8\x37\xDCb\0") };
+        let bounds = (0..str.len())
+            .filter(|&idx| Utf16LE::char_bound(str, idx))
+            .collect::<Vec<_>>();
+        assert_eq!(bounds, [0, 2, 6]);
+    }
+
+    #[test]
+    fn test_char_bound_utf16_low_byte() {
+        // 'Ü' is U+00DC, so has a byte in the low surrogate range which must not be checked
+        let str = Str::<Utf16LE>::from_bytes(b"\xDC\0\xDC\0").unwrap();
+        assert!(Utf16LE::char_bound(str, 2));
+        let str = Str::<Utf16BE>::from_bytes(b"\0\xDC\0\xDC").unwrap();
+        assert!(Utf16BE::char_bound(str, 2));
+    }
+
     #[allow(clippy::octal_escapes)]
     #[test]
     fn test_validate_utf16_be() {
@@ -420,19 +701,86 @@ mod tests {
     }
 
     #[test]
-    fn test_validate_utf32() {
-        assert!(Utf32::validate(cast_slice(&['a', 'b', 'c', '1', '2', '3'])).is_ok());
-        assert!(Utf32::validate(cast_slice(&['A', ' ', 'y', 'e', 'e', ':', ' ', '𐐷'])).is_ok());
+    fn test_char_bound_utf16_be() {
+        // SAFETY: This test data is guaranteed valid
+        let str = unsafe { Str::from_bytes_unchecked(b"\0A\xD8\x01\xDC\x37\0b") };
+        let bounds = (0..str.len())
+            .filter(|&idx| Utf16BE::char_bound(str, idx))
+            .collect::<Vec<_>>();
+        assert_eq!(bounds, [0, 2, 6]);
+    }
+
+    #[test]
+    fn test_validate_utf32_le() {
+        assert!(Utf32LE::validate(b"a\0\0\0b\0\0\0").is_ok());
+        assert!(Utf32LE::validate(b"A\0\0\0\x37\x04\x01\0").is_ok());
         // Invalid (surrogate)
         assert_eq!(
-            Utf32::validate(cast_slice(&['a' as u32, 0xD800, 'b' as u32,])),
+            Utf32LE::validate(b"a\0\0\0\0\xD8\0\0b\0\0\0"),
             Err(ValidateError {
                 valid_up_to: 4,
                 error_len: Some(4),
             })
         );
+        // Invalid (out of range)
         assert_eq!(
-            Utf32::validate(cast_slice(&[0x0011_0000])),
+            Utf32LE::validate(b"\0\0\x11\0"),
+            Err(ValidateError {
+                valid_up_to: 0,
+                error_len: Some(4),
+            })
+        );
+        // Big-endian data is out of range
+        assert!(Utf32LE::validate(b"\0\0\0a").is_err());
+        // Truncated
+        assert_eq!(
+            Utf32LE::validate(b"a\0\0\0b\0"),
+            Err(ValidateError {
+                valid_up_to: 4,
+                error_len: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_utf32_be() {
+        assert!(Utf32BE::validate(b"\0\0\0a\0\0\0b").is_ok());
+        assert!(Utf32BE::validate(b"\0\0\0A\0\x01\x04\x37").is_ok());
+        // Invalid (surrogate)
+        assert_eq!(
+            Utf32BE::validate(b"\0\0\0a\0\0\xD8\0\0\0\0b"),
+            Err(ValidateError {
+                valid_up_to: 4,
+                error_len: Some(4),
+            })
+        );
+        // Invalid (out of range)
+        assert_eq!(
+            Utf32BE::validate(b"\0\x11\0\0"),
+            Err(ValidateError {
+                valid_up_to: 0,
+                error_len: Some(4),
+            })
+        );
+        // Little-endian data is out of range
+        assert!(Utf32BE::validate(b"a\0\0\0").is_err());
+    }
+
+    #[test]
+    fn test_validate_utf32_native() {
+        assert!(Utf32Native::validate(cast_slice(&['a', 'b', 'c', '1', '2', '3'])).is_ok());
+        assert!(
+            Utf32Native::validate(cast_slice(&['A', ' ', 'y', 'e', 'e', ':', ' ', '𐐷'])).is_ok()
+        );
+        assert_eq!(
+            Utf32Native::validate(cast_slice(&['a' as u32, 0xD800, 'b' as u32])),
+            Err(ValidateError {
+                valid_up_to: 4,
+                error_len: Some(4),
+            })
+        );
+        assert_eq!(
+            Utf32Native::validate(cast_slice(&[0x0011_0000])),
             Err(ValidateError {
                 valid_up_to: 0,
                 error_len: Some(4),
@@ -442,18 +790,89 @@ mod tests {
 
     #[test]
     fn test_encode_utf32() {
-        assert_eq!(Utf32::encode_char('A'), Some([b'A', 0, 0, 0]));
-        assert_eq!(Utf32::encode_char('𐐷'), Some([0x37, 0x04, 0x01, 0x00]));
+        assert_eq!(Utf32LE::encode_char('A'), Some([b'A', 0, 0, 0]));
+        assert_eq!(Utf32LE::encode_char('𐐷'), Some([0x37, 0x04, 0x01, 0x00]));
+        assert_eq!(Utf32BE::encode_char('A'), Some([0, 0, 0, b'A']));
+        assert_eq!(Utf32BE::encode_char('𐐷'), Some([0x00, 0x01, 0x04, 0x37]));
+        assert_eq!(
+            Utf32Native::encode_char('𐐷'),
+            Some(('𐐷' as u32).to_ne_bytes())
+        );
     }
 
     #[test]
     fn test_decode_utf32() {
-        let str = Str::from_chars(&['A', '𐐷', 'b']);
-        let (c, str) = Utf32::decode_char(str);
+        let str = Str::<Utf32Native>::from_chars(&['A', '𐐷', 'b']);
+        let (c, str) = Utf32Native::decode_char(str);
         assert_eq!(c, 'A');
-        let (c, str) = Utf32::decode_char(str);
+        let (c, str) = Utf32Native::decode_char(str);
         assert_eq!(c, '𐐷');
-        let (c, _) = Utf32::decode_char(str);
+        let (c, _) = Utf32Native::decode_char(str);
         assert_eq!(c, 'b');
+
+        let str = Str::<Utf32BE>::from_bytes(b"\0\0\0A\0\x01\x04\x37").unwrap();
+        assert!(str.chars().eq(['A', '𐐷']));
+        let str = Str::<Utf32LE>::from_bytes(b"A\0\0\0\x37\x04\x01\0").unwrap();
+        assert!(str.chars().eq(['A', '𐐷']));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_recode_utf32_endian() {
+        let le = Str::<Utf32LE>::from_bytes(b"A\0\0\0\x37\x04\x01\0").unwrap();
+        let be = le.recode::<Utf32BE>().unwrap();
+        assert_eq!(be.as_bytes(), b"\0\0\0A\0\x01\x04\x37");
+        assert_eq!(&*be.recode::<Utf32LE>().unwrap(), le);
+    }
+
+    fn recode_differential<E: Encoding, E2: Encoding>() {
+        use crate::encoding::{recode_chars, ArrayLike};
+        use alloc::vec;
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0x1837);
+        for _ in 0..50 {
+            let mut bytes = Vec::new();
+            for _ in 0..rng.gen_range(0..24) {
+                let c = if rng.gen_bool(0.7) {
+                    rng.gen_range('\0'..'\u{80}')
+                } else {
+                    rng.gen::<char>()
+                };
+                bytes.extend(E::encode_char(c).unwrap().slice());
+            }
+            let str = Str::<E>::from_bytes(&bytes).unwrap();
+
+            for out_len in 0..=bytes.len() * 4 + 4 {
+                let mut fast = vec![0; out_len];
+                let mut slow = vec![0; out_len];
+                let from = E::UNICODE.unwrap();
+                let to = E2::UNICODE.unwrap();
+                let fast_res = recode_unicode::<E, E2>(from, to, str, &mut fast);
+                let slow_res = recode_chars::<E, E2>(str, 0, &mut slow, 0);
+                assert_eq!(fast_res, slow_res);
+                let valid = match &fast_res {
+                    Ok(len) => *len,
+                    Err(e) => e.output_valid(),
+                };
+                assert_eq!(fast[..valid], slow[..valid]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_recode_fast_path() {
+        recode_differential::<Utf8, Utf16LE>();
+        recode_differential::<Utf8, Utf16BE>();
+        recode_differential::<Utf8, Utf32LE>();
+        recode_differential::<Utf8, Utf32BE>();
+        recode_differential::<Utf16LE, Utf8>();
+        recode_differential::<Utf16BE, Utf8>();
+        recode_differential::<Utf32LE, Utf8>();
+        recode_differential::<Utf32BE, Utf8>();
+        recode_differential::<Utf32LE, Utf32BE>();
+        recode_differential::<Utf16LE, Utf16BE>();
+        recode_differential::<Utf8, Utf8>();
     }
 }
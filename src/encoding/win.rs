@@ -1,17 +1,22 @@
+#[cfg(feature = "rand")]
+use crate::encoding::sample_single_byte;
 use crate::encoding::sealed::Sealed;
-use crate::encoding::{AlwaysValid, Encoding, NullTerminable, ValidateError};
+use crate::encoding::{
+    impl_ascii_compatible, AlwaysValid, Encoding, EncodingKind, NullChar, NullTerminable,
+    SingleByte, ValidateError,
+};
 use crate::str::Str;
 #[cfg(feature = "rand")]
 use rand::{distributions::Distribution, Rng};
 
 const DECODE_MAP_1251: [char; 128] = [
     'Ђ', 'Ѓ', '‚', 'ѓ', '„', '…', '†', '‡', '€', '‰', 'Љ', '‹', 'Њ', 'Ќ', 'Ћ', 'Џ', 'ђ', '‘', '’',
-    '“', '”', '•', '–', '—', '␚', '™', 'љ', '›', 'њ', 'ќ', 'ћ', 'џ', ' ', 'Ў', 'ў', 'Ј', '¤', 'Ґ',
-    '¦', '§', 'Ё', '©', 'Є', '«', '¬', '\u{AD}', '®', 'Ї', '°', '±', 'І', 'і', 'ґ', 'µ', '¶', '·',
-    'ё', '№', 'є', '»', 'ј', 'Ѕ', 'ѕ', 'ї', 'А', 'Б', 'В', 'Г', 'Д', 'Е', 'Ж', 'З', 'И', 'Й', 'К',
-    'Л', 'М', 'Н', 'О', 'П', 'Р', 'С', 'Т', 'У', 'Ф', 'Х', 'Ц', 'Ч', 'Ш', 'Щ', 'Ъ', 'Ы', 'Ь', 'Э',
-    'Ю', 'Я', 'а', 'б', 'в', 'г', 'д', 'е', 'ж', 'з', 'и', 'й', 'к', 'л', 'м', 'н', 'о', 'п', 'р',
-    'с', 'т', 'у', 'ф', 'х', 'ц', 'ч', 'ш', 'щ', 'ъ', 'ы', 'ь', 'э', 'ю', 'я',
+    '“', '”', '•', '–', '—', '␚', '™', 'љ', '›', 'њ', 'ќ', 'ћ', 'џ', '\u{A0}', 'Ў', 'ў', 'Ј', '¤',
+    'Ґ', '¦', '§', 'Ё', '©', 'Є', '«', '¬', '\u{AD}', '®', 'Ї', '°', '±', 'І', 'і', 'ґ', 'µ', '¶',
+    '·', 'ё', '№', 'є', '»', 'ј', 'Ѕ', 'ѕ', 'ї', 'А', 'Б', 'В', 'Г', 'Д', 'Е', 'Ж', 'З', 'И', 'Й',
+    'К', 'Л', 'М', 'Н', 'О', 'П', 'Р', 'С', 'Т', 'У', 'Ф', 'Х', 'Ц', 'Ч', 'Ш', 'Щ', 'Ъ', 'Ы', 'Ь',
+    'Э', 'Ю', 'Я', 'а', 'б', 'в', 'г', 'д', 'е', 'ж', 'з', 'и', 'й', 'к', 'л', 'м', 'н', 'о', 'п',
+    'р', 'с', 'т', 'у', 'ф', 'х', 'ц', 'ч', 'ш', 'щ', 'ъ', 'ы', 'ь', 'э', 'ю', 'я',
 ];
 
 const DECODE_MAP_1252: [char; 32] = [
@@ -21,7 +26,7 @@ const DECODE_MAP_1252: [char; 32] = [
 
 /// The [Windows-1251](https://en.wikipedia.org/wiki/Windows-1251) encoding.
 #[non_exhaustive]
-#[derive(Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Win1251;
 
 impl Sealed for Win1251 {}
@@ -30,11 +35,17 @@ impl Encoding for Win1251 {
     const REPLACEMENT: char = '\x1A';
     const MAX_LEN: usize = 1;
     type Bytes = u8;
+    const KIND: EncodingKind = EncodingKind::Win1251;
+    const ASCII_COMPATIBLE: bool = true;
 
     fn shorthand() -> &'static str {
         "win1251"
     }
 
+    fn aliases() -> &'static [&'static str] {
+        &["cp1251", "windows-1251", "x-cp1251"]
+    }
+
     fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
         bytes.iter().enumerate().try_for_each(|(idx, b)| {
             if *b == 0x98 {
@@ -53,7 +64,12 @@ impl Encoding for Win1251 {
             Some(c as u8)
         } else {
             let pos = DECODE_MAP_1251.iter().position(|v| *v == c)? as u8;
-            Some(pos + 0x80)
+            // 0x98 is unmapped, its entry in the decode map is only a placeholder
+            if pos == 0x18 {
+                None
+            } else {
+                Some(pos + 0x80)
+            }
         }
     }
 
@@ -70,35 +86,32 @@ impl Encoding for Win1251 {
         true
     }
 
-    fn char_len(c: char) -> usize {
-        if (c as u32) < 0x80 || DECODE_MAP_1251.contains(&c) {
-            1
-        } else {
-            0
-        }
+    fn char_len(c: char) -> Option<usize> {
+        Self::encode_char(c).map(|_| 1)
     }
 }
 
 impl NullTerminable for Win1251 {}
 
+impl NullChar for Win1251 {}
+
+impl_ascii_compatible!(Win1251);
+
+impl SingleByte for Win1251 {}
+
 #[cfg(feature = "rand")]
 impl Distribution<char> for Win1251 {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
-        // Number of characters
-        let c = rng.gen_range(0u8..255);
-        if c <= 0x7F {
-            char::from(c)
-        } else if c < 0x98 {
-            DECODE_MAP_1251[(c - 0x80) as usize]
-        } else {
-            DECODE_MAP_1251[(c + 1 - 0x80) as usize]
-        }
+        sample_single_byte::<Self, R>(rng)
     }
 }
 
+/// Bytes with no assigned character in Windows-1252
+const UNDEFINED_1252: [u8; 5] = [0x81, 0x8D, 0x8F, 0x90, 0x9D];
+
 /// The [Windows-1252](https://en.wikipedia.org/wiki/Windows-1252) encoding.
 #[non_exhaustive]
-#[derive(Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Win1252;
 
 impl Sealed for Win1252 {}
@@ -107,6 +120,8 @@ impl Encoding for Win1252 {
     const REPLACEMENT: char = '\x1A';
     const MAX_LEN: usize = 1;
     type Bytes = u8;
+    const KIND: EncodingKind = EncodingKind::Win1252;
+    const ASCII_COMPATIBLE: bool = true;
 
     fn shorthand() -> &'static str {
         "win1252"
@@ -114,7 +129,7 @@ impl Encoding for Win1252 {
 
     fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
         bytes.iter().enumerate().try_for_each(|(idx, b)| {
-            if [0x81, 0x8D, 0x8F, 0x90, 0x9D].contains(b) {
+            if UNDEFINED_1252.contains(b) {
                 Err(ValidateError {
                     valid_up_to: idx,
                     error_len: Some(1),
@@ -129,8 +144,9 @@ impl Encoding for Win1252 {
         if (..0x80).contains(&(c as u32)) || (0xA0..0x100).contains(&(c as u32)) {
             Some(c as u8)
         } else {
-            let pos = DECODE_MAP_1252.iter().position(|v| *v == c)? as u8;
-            Some(pos + 0x80)
+            let b = DECODE_MAP_1252.iter().position(|v| *v == c)? as u8 + 0x80;
+            // The map contains the C1 control codes for undefined bytes, for `Win1252Loose`
+            (!UNDEFINED_1252.contains(&b)).then_some(b)
         }
     }
 
@@ -147,43 +163,30 @@ impl Encoding for Win1252 {
         true
     }
 
-    fn char_len(c: char) -> usize {
-        if (c as u32) < 0x80 || DECODE_MAP_1252.contains(&c) {
-            1
-        } else {
-            0
-        }
+    fn char_len(c: char) -> Option<usize> {
+        Self::encode_char(c).map(|_| 1)
     }
 }
 
 impl NullTerminable for Win1252 {}
 
+impl NullChar for Win1252 {}
+
+impl_ascii_compatible!(Win1252);
+
+impl SingleByte for Win1252 {}
+
 #[cfg(feature = "rand")]
 impl Distribution<char> for Win1252 {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
-        // Number of characters
-        let c = rng.gen_range(0u8..251);
-        if c <= 0x7F {
-            char::from(c)
-        } else if c >= (0xA0 - 5) {
-            char::from(c + 5)
-        } else {
-            let offset = match c {
-                0x80 => 0,
-                ..=0x8B => 1,
-                ..=0x8C => 2,
-                ..=0x98 => 4,
-                _ => 5,
-            };
-            DECODE_MAP_1252[(c + offset - 0x80) as usize]
-        }
+        sample_single_byte::<Self, R>(rng)
     }
 }
 
 /// The [Windows-1252](https://en.wikipedia.org/wiki/Windows-1252) encoding, with empty spots
 /// replaced by the corresponding C1 control codes.
 #[non_exhaustive]
-#[derive(Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Win1252Loose;
 
 impl Sealed for Win1252Loose {}
@@ -192,11 +195,32 @@ impl Encoding for Win1252Loose {
     const REPLACEMENT: char = '\x1A';
     const MAX_LEN: usize = 1;
     type Bytes = u8;
+    const KIND: EncodingKind = EncodingKind::Win1252Loose;
+    const ASCII_COMPATIBLE: bool = true;
 
     fn shorthand() -> &'static str {
         "win1252_loose"
     }
 
+    fn aliases() -> &'static [&'static str] {
+        &[
+            "cp1252",
+            "cp819",
+            "csisolatin1",
+            "ibm819",
+            "iso-8859-1",
+            "iso-ir-100",
+            "iso8859-1",
+            "iso88591",
+            "iso_8859-1",
+            "iso_8859-1:1987",
+            "l1",
+            "latin1",
+            "windows-1252",
+            "x-cp1252",
+        ]
+    }
+
     fn validate(_: &[u8]) -> Result<(), ValidateError> {
         // All bytes are valid in this variant of Win1252, we just leave the invalid bytes alone
         Ok(())
@@ -224,29 +248,25 @@ impl Encoding for Win1252Loose {
         true
     }
 
-    fn char_len(c: char) -> usize {
-        if (c as u32) > 255 {
-            0
-        } else {
-            1
-        }
+    fn char_len(c: char) -> Option<usize> {
+        Self::encode_char(c).map(|_| 1)
     }
 }
 
 impl NullTerminable for Win1252Loose {}
 
+impl NullChar for Win1252Loose {}
+
+impl_ascii_compatible!(Win1252Loose);
+
 impl AlwaysValid for Win1252Loose {}
 
+impl SingleByte for Win1252Loose {}
+
 #[cfg(feature = "rand")]
 impl Distribution<char> for Win1252Loose {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
-        // Number of characters
-        let c = rng.gen::<u8>();
-        if c <= 0x7F || c >= 0xA0 {
-            char::from(c)
-        } else {
-            DECODE_MAP_1252[(c - 0x80) as usize]
-        }
+        sample_single_byte::<Self, R>(rng)
     }
 }
 
@@ -291,9 +311,31 @@ mod tests {
     #[test]
     fn test_char_len_win1251() {
         let c = 'ф';
-        assert_eq!(Win1251::char_len(c), 1);
+        assert_eq!(Win1251::char_len(c), Some(1));
         let c = '𐐷';
-        assert_eq!(Win1251::char_len(c), 0);
+        assert_eq!(Win1251::char_len(c), None);
+    }
+
+    #[test]
+    fn test_tables_win1251() {
+        for b in 0x80..=0xFF {
+            let bytes = [b];
+            let res = Str::<Win1251>::from_bytes(&bytes);
+            if b == 0x98 {
+                assert!(res.is_err());
+                continue;
+            }
+            let (c, _) = Win1251::decode_char(res.unwrap());
+            assert_eq!(
+                Win1251::encode_char(c),
+                Some(b),
+                "{:?} didn't round-trip",
+                c
+            );
+            assert_eq!(Win1251::char_len(c), Some(1));
+        }
+        assert_eq!(Win1251::encode_char('␚'), None);
+        assert_eq!(Win1251::char_len('␚'), None);
     }
 
     #[test]
@@ -314,6 +356,9 @@ mod tests {
         assert_eq!(Win1252::encode_char('€'), Some(0x80));
         assert_eq!(Win1252::encode_char('ÿ'), Some(0xFF));
         assert_eq!(Win1252::encode_char('𐐷'), None,);
+        // Placeholders for undefined bytes
+        assert_eq!(Win1252::encode_char('\u{81}'), None);
+        assert_eq!(Win1252Loose::encode_char('\u{81}'), Some(0x81));
     }
 
     #[test]
@@ -333,8 +378,13 @@ mod tests {
     #[test]
     fn test_char_len_win1252() {
         let c = '€';
-        assert_eq!(Win1252::char_len(c), 1);
+        assert_eq!(Win1252::char_len(c), Some(1));
         let c = '𐐷';
-        assert_eq!(Win1252::char_len(c), 0);
+        assert_eq!(Win1252::char_len(c), None);
+        assert_eq!(Win1252::char_len('é'), Some(1));
+        assert_eq!(Win1252::char_len('\u{81}'), None);
+        // Encoded as `0x80`, which decodes to '€'
+        assert_eq!(Win1252Loose::char_len('\u{80}'), None);
+        assert_eq!(Win1252Loose::char_len('\u{81}'), Some(1));
     }
 }
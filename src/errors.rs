@@ -0,0 +1,828 @@
+//! Every public error type in the crate, gathered in one place.
+//!
+//! The errors defined here are also re-exported from the modules which use them, such as
+//! [`str`](crate::str).
+//!
+//! Errors which don't borrow from their input are `Send + Sync + 'static`, so they can be mixed
+//! with `?` in a function returning `Box<dyn Error + Send + Sync>`:
+//!
+//! ```
+//! # use enrede::{CString, Str, encoding::{Utf8, Win1252}};
+//! use std::error::Error;
+//!
+//! fn to_c_string(bytes: &[u8]) -> Result<CString<Win1252>, Box<dyn Error + Send + Sync>> {
+//!     let str = Str::<Utf8>::from_bytes(bytes)?;
+//!     let recoded = str.recode::<Win1252>()?;
+//!     Ok(CString::new(recoded)?)
+//! }
+//!
+//! assert_eq!(to_c_string("café".as_bytes()).unwrap().as_bytes(), b"caf\xE9");
+//! assert!(to_c_string(b"caf\xFF").is_err());
+//! assert!(to_c_string("日本".as_bytes()).is_err());
+//! assert!(to_c_string(b"a\0b").is_err());
+//! ```
+
+pub use crate::arraystring::{CapacityError, PushError};
+pub use crate::cstr::{
+    FromBytesUntilNulError, FromBytesWithNulError, FromBytesWithNulValidError, MissingNull,
+};
+#[cfg(feature = "alloc")]
+pub use crate::cstring::{CStringError, CStringErrorCause, FromVecWithNulError, NulError};
+use crate::encoding::Encoding;
+pub use crate::encoding::{
+    EncodeError, RecodeCause, RecodeStepError, UnknownEncoding, ValidateError,
+};
+use crate::str::Str;
+#[cfg(feature = "alloc")]
+pub use crate::string::{InvalidChar, OwnValidateError};
+#[cfg(feature = "alloc")]
+use alloc::collections::TryReserveError;
+use core::fmt;
+
+/// Error encountered while re-encoding a [`Str`](crate::Str) or [`CStr`](crate::CStr) into another
+/// format
+///
+/// This is returned by conversions which allocate their output, such as
+/// [`Str::recode`](crate::Str::recode). Recoding into a caller-provided buffer with
+/// [`Encoding::recode`](crate::Encoding::recode) returns [`RecodeStepError`] instead, which also
+/// reports how much output was written.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecodeError {
+    pub(crate) valid_up_to: usize,
+    pub(crate) char: char,
+    pub(crate) char_len: u8,
+}
+
+impl RecodeError {
+    /// The length of valid data in the input before the error was encountered. Calling
+    /// [`recode`](crate::Str::recode) again on the input sliced down to this length will succeed.
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+
+    /// The character encountered that caused re-encoding to fail. This character most likely isn't
+    /// supported by the new encoding.
+    pub fn char(&self) -> char {
+        self.char
+    }
+
+    /// The length of the character in the input encoding. Skipping this many bytes forwards from
+    /// [`valid_up_to`](Self::valid_up_to) and trying again will avoid this particular error
+    /// character (though recoding may fail again immediately due to another invalid character).
+    pub fn char_len(&self) -> usize {
+        self.char_len as usize
+    }
+}
+
+impl fmt::Display for RecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "character {:?} at index {} can't be represented in the output encoding",
+            self.char, self.valid_up_to,
+        )
+    }
+}
+
+impl core::error::Error for RecodeError {}
+
+/// Error encountered while re-encoding a [`Str`](crate::Str) with
+/// [`Str::try_recode`](crate::Str::try_recode), which reports allocation failure instead of
+/// aborting.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum TryRecodeError {
+    /// A character couldn't be represented in the output encoding
+    Recode(RecodeError),
+    /// Allocating the output failed
+    Alloc(TryReserveError),
+}
+
+#[cfg(feature = "alloc")]
+impl From<RecodeError> for TryRecodeError {
+    fn from(err: RecodeError) -> Self {
+        TryRecodeError::Recode(err)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<TryReserveError> for TryRecodeError {
+    fn from(err: TryReserveError) -> Self {
+        TryRecodeError::Alloc(err)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for TryRecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryRecodeError::Recode(e) => fmt::Display::fmt(e, f),
+            TryRecodeError::Alloc(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for TryRecodeError {}
+
+/// The number of characters of context shown on either side of an invalid byte sequence
+const CONTEXT_CHARS: usize = 8;
+
+/// The input surrounding a [`ValidateError`], for reporting where in a large input the error
+/// occurred. Created by [`ValidateError::context`].
+///
+/// The [`Display`](fmt::Display) implementation writes the invalid bytes in hex, surrounded by up
+/// to 8 characters of valid text on either side:
+///
+/// ```
+/// # use enrede::encoding::{Encoding, ShiftJis};
+/// let bytes = b"\x83\x6E\x83\x8D\xFF\x81\x5B\x83\x8F";
+/// let err = ShiftJis::validate(bytes).unwrap_err();
+/// assert_eq!(
+///     err.context::<ShiftJis>(bytes).to_string(),
+///     r#""ハロ" [0xFF] "ーワ" at byte 4"#,
+/// );
+/// ```
+pub struct ErrorContext<'a, E> {
+    before: &'a Str<E>,
+    invalid: &'a [u8],
+    after: &'a Str<E>,
+    error: ValidateError,
+    truncated_before: bool,
+    truncated_after: bool,
+}
+
+impl<'a, E: Encoding> ErrorContext<'a, E> {
+    pub(crate) fn new(error: ValidateError, bytes: &'a [u8]) -> ErrorContext<'a, E> {
+        // The error may not have come from validating `bytes` for `E`, as errors can be created
+        // with `ValidateError::new`, so the data before it needs checking. If it's invalid, the
+        // context starts from the real error instead.
+        let start = match E::validate(&bytes[..error.valid_up_to()]) {
+            Ok(()) => error.valid_up_to(),
+            Err(err) => err.valid_up_to(),
+        };
+        // SAFETY: Data up to `start` has just been validated
+        let valid = unsafe { Str::<E>::from_bytes_unchecked(&bytes[..start]) };
+        let mut before = start;
+        for _ in 0..CONTEXT_CHARS {
+            if before == 0 {
+                break;
+            }
+            before = valid.floor_char_boundary(before - 1);
+        }
+
+        let end = error.error_len().map_or(bytes.len(), |len| {
+            usize::min(error.valid_up_to() + len, bytes.len())
+        });
+        // Only look at enough bytes to hold the context, so large inputs aren't validated to
+        // the end
+        let rest = &bytes[end..];
+        let window = &rest[..usize::min(rest.len(), CONTEXT_CHARS * E::MAX_LEN)];
+        let after = match E::validate(window) {
+            Ok(()) => window,
+            Err(err) => &window[..err.valid_up_to()],
+        };
+        // SAFETY: `after` has been validated, or cut to the valid prefix
+        let after = unsafe { Str::<E>::from_bytes_unchecked(after) };
+        let after_len = after
+            .char_indices()
+            .nth(CONTEXT_CHARS)
+            .map_or(after.len(), |(idx, _)| idx);
+
+        ErrorContext {
+            before: &valid[before..],
+            invalid: &bytes[start..end],
+            after: &after[..after_len],
+            error,
+            truncated_before: before > 0,
+            truncated_after: after_len < rest.len(),
+        }
+    }
+
+    /// The valid text immediately before the invalid bytes, up to 8 characters
+    pub fn before(&self) -> &'a Str<E> {
+        self.before
+    }
+
+    /// The invalid byte sequence. If the error occurred at the end of the input, this is all of
+    /// the remaining input.
+    pub fn invalid(&self) -> &'a [u8] {
+        self.invalid
+    }
+
+    /// The valid text immediately after the invalid bytes, up to 8 characters. This stops early if
+    /// it reaches another invalid byte sequence.
+    pub fn after(&self) -> &'a Str<E> {
+        self.after
+    }
+
+    /// The error this context is for
+    pub fn error(&self) -> ValidateError {
+        self.error
+    }
+}
+
+impl<E> Clone for ErrorContext<'_, E> {
+    fn clone(&self) -> Self {
+        ErrorContext {
+            before: self.before,
+            invalid: self.invalid,
+            after: self.after,
+            error: self.error,
+            truncated_before: self.truncated_before,
+            truncated_after: self.truncated_after,
+        }
+    }
+}
+
+impl<E: Encoding> fmt::Debug for ErrorContext<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ErrorContext")
+            .field("before", &self.before)
+            .field("invalid", &self.invalid)
+            .field("after", &self.after)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl<E: Encoding> fmt::Display for ErrorContext<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.truncated_before || !self.before.is_empty() {
+            let ellipsis = if self.truncated_before { "…" } else { "" };
+            write!(f, "\"{}{}\" ", ellipsis, self.before.escape_debug())?;
+        }
+        write!(f, "[")?;
+        for (idx, byte) in self.invalid.iter().enumerate() {
+            if idx != 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{:#04X}", byte)?;
+        }
+        write!(f, "]")?;
+        if self.truncated_after || !self.after.is_empty() {
+            let ellipsis = if self.truncated_after { "…" } else { "" };
+            write!(f, " \"{}{}\"", self.after.escape_debug(), ellipsis)?;
+        }
+        write!(f, " at byte {}", self.error.valid_up_to())
+    }
+}
+
+/// Error encountered while copying between two strings of different lengths, returned by
+/// [`Str::try_copy_from`](crate::Str::try_copy_from) and
+/// [`CStr::try_copy_from`](crate::CStr::try_copy_from).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LengthMismatch {
+    pub(crate) source_len: usize,
+    pub(crate) dest_len: usize,
+}
+
+impl LengthMismatch {
+    /// The length of the string being copied from, in bytes
+    pub fn source_len(&self) -> usize {
+        self.source_len
+    }
+
+    /// The length of the string being copied into, in bytes. For a C string, this excludes the
+    /// terminating null byte.
+    pub fn dest_len(&self) -> usize {
+        self.dest_len
+    }
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "source string length ({}) doesn't match destination string length ({})",
+            self.source_len, self.dest_len,
+        )
+    }
+}
+
+impl core::error::Error for LengthMismatch {}
+
+/// Error encountered while filling a string with a character, returned by
+/// [`Str::fill`](crate::Str::fill).
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum FillError {
+    /// The character can't be represented in the string's encoding
+    InvalidChar,
+    /// The string's length isn't a multiple of the character's encoded length
+    Length {
+        /// The length of the character in the string's encoding
+        char_len: usize,
+    },
+}
+
+impl fmt::Display for FillError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FillError::InvalidChar => write!(f, "character isn't valid for the encoding"),
+            FillError::Length { char_len } => write!(
+                f,
+                "string length isn't a multiple of the character length ({})",
+                char_len
+            ),
+        }
+    }
+}
+
+impl core::error::Error for FillError {}
+
+/// Error encountered while parsing a [`Str`](crate::Str) into another type with
+/// [`Str::parse`](crate::Str::parse).
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ParseError<E> {
+    /// The string couldn't be represented as a UTF-8 [`str`] to parse. This can only occur when the
+    /// `alloc` feature is disabled, and the string contains non-ASCII characters.
+    Unrepresentable,
+    /// The type being parsed failed to parse from the string.
+    Parse(E),
+}
+
+impl<E> fmt::Display for ParseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Unrepresentable => {
+                write!(f, "string couldn't be represented as UTF-8 to parse")
+            }
+            ParseError::Parse(_) => write!(f, "failed to parse string"),
+        }
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for ParseError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseError::Unrepresentable => None,
+            ParseError::Parse(e) => Some(e),
+        }
+    }
+}
+
+/// Error encountered while slicing a [`Str`](crate::Str) with an invalid range, returned by
+/// [`Str::get_checked`](crate::Str::get_checked).
+#[derive(Clone, Debug, PartialEq)]
+pub struct SliceError {
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    pub(crate) kind: SliceErrorKind,
+    pub(crate) encoding: &'static str,
+}
+
+impl SliceError {
+    /// The start of the attempted range, as a byte index
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// The end of the attempted range, as an exclusive byte index
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Why the range was invalid
+    pub fn kind(&self) -> &SliceErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for SliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            SliceErrorKind::OutOfBounds { len } => write!(
+                f,
+                "range end {} is out of bounds of {} string of length {}",
+                self.end, self.encoding, len,
+            ),
+            SliceErrorKind::StartAfterEnd => write!(
+                f,
+                "range start {} is greater than range end {} slicing {} string",
+                self.start, self.end, self.encoding,
+            ),
+            SliceErrorKind::NotCharBoundary {
+                idx,
+                prev,
+                next,
+                char,
+            } => write!(
+                f,
+                "byte index {} is not a char boundary in {} string; it is inside {:?}, between \
+                 boundaries {} and {}",
+                idx, self.encoding, char, prev, next,
+            ),
+        }
+    }
+}
+
+impl core::error::Error for SliceError {}
+
+/// The reason a [`SliceError`] occurred
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum SliceErrorKind {
+    /// The end of the range was past the end of the string
+    OutOfBounds {
+        /// The length of the string
+        len: usize,
+    },
+    /// The start of the range was after the end
+    StartAfterEnd,
+    /// An index of the range didn't lie on a character boundary
+    NotCharBoundary {
+        /// The offending index
+        idx: usize,
+        /// The nearest character boundary before the index
+        prev: usize,
+        /// The nearest character boundary after the index
+        next: usize,
+        /// The character the index lies inside of
+        char: char,
+    },
+}
+
+/// Error encountered while reading a tagged string with
+/// [`from_tagged_bytes`](crate::tagged::from_tagged_bytes).
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum TaggedError {
+    /// The input ended before the end of the header
+    Truncated,
+    /// The input was written in a format version this crate doesn't support
+    UnsupportedVersion(u8),
+    /// The encoding tag doesn't name a built-in encoding
+    UnknownEncoding,
+    /// The string data isn't valid for its encoding
+    Invalid(ValidateError),
+}
+
+impl From<ValidateError> for TaggedError {
+    fn from(err: ValidateError) -> Self {
+        TaggedError::Invalid(err)
+    }
+}
+
+impl fmt::Display for TaggedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaggedError::Truncated => write!(f, "tagged string header is truncated"),
+            TaggedError::UnsupportedVersion(version) => {
+                write!(f, "unsupported tagged string version {}", version)
+            }
+            TaggedError::UnknownEncoding => write!(f, "tagged string has an unknown encoding"),
+            TaggedError::Invalid(_) => write!(f, "tagged string data isn't valid for its encoding"),
+        }
+    }
+}
+
+impl core::error::Error for TaggedError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            TaggedError::Invalid(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arraystring::{CapacityError, PushError};
+    use crate::cstr::{CStr, MissingNull};
+    use crate::cstr::{FromBytesUntilNulError, FromBytesWithNulError, FromBytesWithNulValidError};
+    use crate::encoding::{Ascii, EncodeError, Encoding, Utf8, ValidateError};
+    use crate::str::Str;
+    use alloc::string::ToString;
+    use core::error::Error;
+
+    fn check_display(err: &dyn Error, msg: &str) {
+        assert_eq!(err.to_string(), msg);
+    }
+
+    fn check_source<E: Error + PartialEq + 'static>(err: &dyn Error, expected: &E) {
+        let source = err.source().and_then(|s| s.downcast_ref::<E>());
+        assert_eq!(source, Some(expected));
+    }
+
+    #[test]
+    fn test_display() {
+        let validate = ValidateError::new(1, Some(1));
+        check_display(&validate, "invalid byte sequence of 1 bytes from index 1");
+        check_display(
+            &EncodeError::NeedSpace { len: 2 },
+            "output too small, 2 bytes required",
+        );
+        check_display(
+            &EncodeError::InvalidChar,
+            "character isn't valid for the encoding",
+        );
+        check_display(
+            &Ascii::recode(Str::from_std("aé"), &mut [0; 4]).unwrap_err(),
+            "character 'é' at index 1 isn't valid for the output encoding",
+        );
+        check_display(
+            &Ascii::recode(Str::from_std("ab"), &mut [0; 1]).unwrap_err(),
+            "output too small after 1 bytes of input, 1 more bytes required",
+        );
+        check_display(
+            &RecodeError {
+                valid_up_to: 1,
+                char: 'é',
+                char_len: 2,
+            },
+            "character 'é' at index 1 can't be represented in the output encoding",
+        );
+        check_display(
+            &Str::from_std("a").get_checked(..2).unwrap_err(),
+            "range end 2 is out of bounds of utf8 string of length 1",
+        );
+        check_display(
+            &ParseError::<core::num::ParseIntError>::Unrepresentable,
+            "string couldn't be represented as UTF-8 to parse",
+        );
+        check_display(
+            &ParseError::Parse("a".parse::<u8>().unwrap_err()),
+            "failed to parse string",
+        );
+        check_display(&MissingNull, "data doesn't contain a null byte");
+        check_display(
+            &FromBytesUntilNulError::Invalid(validate),
+            "data isn't valid for the encoding",
+        );
+        check_display(
+            &FromBytesUntilNulError::MissingNull,
+            "data doesn't contain a null byte",
+        );
+        check_display(
+            &FromBytesWithNulError::Invalid(validate),
+            "data isn't valid for the encoding",
+        );
+        check_display(
+            &FromBytesWithNulError::HasNull { idx: 3 },
+            "data contains an interior null byte at index 3",
+        );
+        check_display(
+            &FromBytesWithNulError::MissingNull,
+            "data doesn't contain a null byte",
+        );
+        check_display(
+            &FromBytesWithNulValidError::HasNull { idx: 3 },
+            "data contains an interior null byte at index 3",
+        );
+        check_display(
+            &FromBytesWithNulValidError::MissingNull,
+            "data doesn't contain a null byte",
+        );
+        check_display(
+            &FillError::InvalidChar,
+            "character isn't valid for the encoding",
+        );
+        check_display(
+            &FillError::Length { char_len: 2 },
+            "string length isn't a multiple of the character length (2)",
+        );
+        check_display(&CapacityError, "not enough remaining capacity in string");
+        check_display(
+            &PushError::InvalidChar,
+            "character isn't valid for the encoding",
+        );
+        check_display(
+            &PushError::Capacity,
+            "not enough remaining capacity in string",
+        );
+        check_display(&TaggedError::Truncated, "tagged string header is truncated");
+        check_display(
+            &TaggedError::UnsupportedVersion(2),
+            "unsupported tagged string version 2",
+        );
+        check_display(
+            &TaggedError::UnknownEncoding,
+            "tagged string has an unknown encoding",
+        );
+        let err = TaggedError::Invalid(ValidateError::new(0, Some(1)));
+        check_display(&err, "tagged string data isn't valid for its encoding");
+        check_source(&err, &ValidateError::new(0, Some(1)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_display_alloc() {
+        use crate::cstring::{CString, CStringErrorCause};
+        use crate::string::String;
+
+        check_display(
+            &String::<Ascii>::new().try_push('é').unwrap_err(),
+            "character 'é' isn't valid for the encoding",
+        );
+        check_display(
+            &CString::<Utf8>::new(b"a\0b".to_vec()).unwrap_err(),
+            "data contains an interior null byte at index 1",
+        );
+        assert_eq!(
+            CStringErrorCause::Invalid(ValidateError::new(0, Some(1))).to_string(),
+            "data isn't valid for the encoding",
+        );
+        check_display(
+            &CString::<Utf8>::from_vec_with_nul(b"ab".to_vec()).unwrap_err(),
+            "data doesn't contain a null byte",
+        );
+        check_display(
+            &CString::try_from(String::<Utf8>::from_std("ab\0".into())).unwrap_err(),
+            "data contains an interior null byte at index 2",
+        );
+        check_display(
+            &Str::from_std("aé").recode::<Ascii>().unwrap_err(),
+            "character 'é' at index 1 can't be represented in the output encoding",
+        );
+        check_display(
+            &Str::from_std("aé").try_recode::<Ascii>().unwrap_err(),
+            "character 'é' at index 1 can't be represented in the output encoding",
+        );
+
+        let alloc_err = alloc::vec::Vec::<u8>::new()
+            .try_reserve(usize::MAX)
+            .unwrap_err();
+        check_display(
+            &TryRecodeError::Alloc(alloc_err.clone()),
+            &alloc_err.to_string(),
+        );
+        assert_eq!(
+            CStringErrorCause::Alloc(alloc_err).to_string(),
+            "failed to allocate C string",
+        );
+    }
+
+    #[test]
+    fn test_error_context() {
+        use crate::encoding::{ArrayLike, Big5, JisX0208, ShiftJis, Utf16BE, Utf16LE, Utf32LE};
+        use alloc::vec::Vec;
+
+        const TEXT: &str = "日本人大中小山川田";
+
+        fn encode<E: Encoding>(text: &str) -> Vec<u8> {
+            text.chars()
+                .flat_map(|c| E::encode_char(c).unwrap().slice().to_vec())
+                .collect()
+        }
+
+        fn check<E: Encoding>(invalid: &[u8]) {
+            let name = E::shorthand();
+            let last_8 = encode::<E>(&TEXT[3..]);
+            let first_8 = encode::<E>(&TEXT[..TEXT.len() - 3]);
+            for (before, after) in [("", TEXT), (TEXT, TEXT), (TEXT, ""), ("", "")] {
+                let bytes = [encode::<E>(before), invalid.to_vec(), encode::<E>(after)].concat();
+                let err = E::validate(&bytes).unwrap_err();
+                let context = err.context::<E>(&bytes);
+                assert_eq!(context.error(), err);
+                assert_eq!(context.invalid(), invalid, "{name}");
+                let expected = if before.is_empty() { &[][..] } else { &last_8 };
+                assert_eq!(context.before().as_bytes(), expected, "{name}");
+                let expected = if after.is_empty() { &[][..] } else { &first_8 };
+                assert_eq!(context.after().as_bytes(), expected, "{name}");
+            }
+
+            // A character cut off at the end of the input
+            let mut bytes = encode::<E>(TEXT);
+            bytes.pop();
+            let err = E::validate(&bytes).unwrap_err();
+            let context = err.context::<E>(&bytes);
+            assert_eq!(err.error_len(), None, "{name}");
+            assert_eq!(context.invalid(), &bytes[err.valid_up_to()..], "{name}");
+            assert!(context.after().is_empty(), "{name}");
+        }
+
+        check::<Utf8>(b"\xFF");
+        check::<Utf16LE>(b"\x00\xDC");
+        check::<Utf16BE>(b"\xDC\x00");
+        check::<Utf32LE>(b"\x00\x00\x11\x00");
+        check::<ShiftJis>(b"\xFF");
+        check::<Big5>(b"\xFF");
+        check::<JisX0208>(b"\xFF");
+    }
+
+    #[test]
+    fn test_error_context_display() {
+        let check = |bytes: &[u8], msg: &str| {
+            let err = Utf8::validate(bytes).unwrap_err();
+            assert_eq!(err.context::<Utf8>(bytes).to_string(), msg);
+        };
+        let text = "日本人大中小山川田".as_bytes();
+        check(
+            &[text, b"\xFF", text].concat(),
+            "\"…本人大中小山川田\" [0xFF] \"日本人大中小山川…\" at byte 27",
+        );
+        check(b"\xFFabc", "[0xFF] \"abc\" at byte 0");
+        check(b"abc\xE6\x97", "\"abc\" [0xE6 0x97] at byte 3");
+        check(b"a\nb\xFF\xFFc", "\"a\\nb\" [0xFF] \"…\" at byte 3");
+    }
+
+    #[test]
+    fn test_error_context_mismatched() {
+        use crate::encoding::Win1252;
+
+        // Errors which didn't come from validating the input never expose invalid text
+        let bytes = b"\xFF\xFE\xFD\xFC";
+        let context = ValidateError::new(3, Some(1)).context::<Utf8>(bytes);
+        assert!(context.before().is_empty());
+        assert_eq!(context.invalid(), bytes);
+
+        let bytes = b"caf\xE9\x81!";
+        let err = Win1252::validate(bytes).unwrap_err();
+        let context = err.context::<Utf8>(bytes);
+        assert_eq!(context.before(), "caf");
+        assert_eq!(context.invalid(), b"\xE9\x81");
+        assert_eq!(context.after(), "!");
+    }
+
+    #[test]
+    fn test_source() {
+        let err = CStr::<Utf8>::from_bytes_with_nul(b"\xFF\0").unwrap_err();
+        check_source(&err, &Utf8::validate(b"\xFF").unwrap_err());
+        let err = CStr::<Utf8>::from_bytes_until_nul(b"\xFF\0").unwrap_err();
+        check_source(&err, &Utf8::validate(b"\xFF").unwrap_err());
+        assert!(FromBytesWithNulError::MissingNull.source().is_none());
+
+        let err = ParseError::Parse("a".parse::<u8>().unwrap_err());
+        check_source(&err, &"a".parse::<u8>().unwrap_err());
+        assert!(ParseError::<core::num::ParseIntError>::Unrepresentable
+            .source()
+            .is_none());
+
+        #[cfg(feature = "alloc")]
+        {
+            use crate::cstring::CString;
+
+            let err = CString::<Utf8>::new(b"\xFF".to_vec()).unwrap_err();
+            check_source(&err, &Utf8::validate(b"\xFF").unwrap_err());
+            let err = CString::<Utf8>::new(b"\0".to_vec()).unwrap_err();
+            assert!(err.source().is_none());
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        let validate = ValidateError::new(0, None);
+        assert_eq!(
+            FromBytesUntilNulError::from(validate),
+            FromBytesUntilNulError::Invalid(validate)
+        );
+        assert_eq!(
+            FromBytesUntilNulError::from(MissingNull),
+            FromBytesUntilNulError::MissingNull
+        );
+        assert_eq!(
+            FromBytesWithNulError::from(validate),
+            FromBytesWithNulError::Invalid(validate)
+        );
+        assert_eq!(
+            FromBytesWithNulError::from(FromBytesWithNulValidError::HasNull { idx: 1 }),
+            FromBytesWithNulError::HasNull { idx: 1 }
+        );
+        assert_eq!(
+            FromBytesWithNulValidError::from(MissingNull),
+            FromBytesWithNulValidError::MissingNull
+        );
+    }
+
+    fn assert_boxable<E: Error + Send + Sync + 'static>() {}
+
+    #[test]
+    fn test_boxable() {
+        assert_boxable::<RecodeError>();
+        assert_boxable::<LengthMismatch>();
+        assert_boxable::<FillError>();
+        assert_boxable::<ParseError<core::num::ParseIntError>>();
+        assert_boxable::<SliceError>();
+        assert_boxable::<TaggedError>();
+        assert_boxable::<ValidateError>();
+        assert_boxable::<EncodeError>();
+        assert_boxable::<RecodeStepError>();
+        assert_boxable::<UnknownEncoding>();
+        assert_boxable::<MissingNull>();
+        assert_boxable::<FromBytesUntilNulError>();
+        assert_boxable::<FromBytesWithNulError>();
+        assert_boxable::<FromBytesWithNulValidError>();
+        assert_boxable::<CapacityError>();
+        assert_boxable::<PushError>();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_boxable_alloc() {
+        use alloc::boxed::Box;
+
+        assert_boxable::<TryRecodeError>();
+        assert_boxable::<InvalidChar>();
+        assert_boxable::<OwnValidateError>();
+        assert_boxable::<CStringError>();
+        assert_boxable::<NulError>();
+        assert_boxable::<FromVecWithNulError>();
+
+        let err: Box<dyn Error + Send + Sync> = Utf8::validate(b"\xFF").unwrap_err().into();
+        assert!(err.downcast_ref::<ValidateError>().is_some());
+    }
+}
@@ -0,0 +1,511 @@
+//! Streaming recoding through [`std::io`] readers and writers.
+//!
+//! [`RecodeWriter`] encodes text into an encoding as it is written, and [`RecodeReader`] decodes
+//! text from an encoding as it is read. Both only buffer a fixed amount of data, allowing
+//! conversion of arbitrarily large inputs.
+
+use crate::encoding::{ArrayLike, Encoding, ValidateError};
+use crate::str::Str;
+use crate::string::InvalidChar;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use std::io;
+
+const DEFAULT_CAPACITY: usize = 8 * 1024;
+
+/// How to handle characters which can't be recoded.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OnInvalid {
+    /// Return an error with [`io::ErrorKind::InvalidData`]
+    #[default]
+    Error,
+    /// Replace the character with a replacement character, and continue
+    Replace,
+}
+
+/// A writer which encodes text into the encoding `E`, and writes the encoded bytes to an inner
+/// [`io::Write`].
+///
+/// Encoded output is buffered, and only written to the inner writer when the buffer fills, or on
+/// [`flush`](RecodeWriter::flush). The buffer is also flushed when the writer is dropped, though
+/// any errors are ignored in that case.
+///
+/// ```
+/// # use enrede::encoding::Win1252;
+/// # use enrede::io::RecodeWriter;
+/// # use enrede::Str;
+/// let mut writer = RecodeWriter::<_, Win1252>::new(Vec::new());
+/// writer.write_str(Str::from_std("Hello, €")).unwrap();
+/// assert_eq!(writer.into_inner().unwrap(), b"Hello, \x80");
+/// ```
+pub struct RecodeWriter<W: io::Write, E> {
+    // Only `None` after `into_inner`
+    inner: Option<W>,
+    buf: Vec<u8>,
+    on_invalid: OnInvalid,
+    _phantom: PhantomData<E>,
+}
+
+impl<W: io::Write, E: Encoding> RecodeWriter<W, E> {
+    /// Create a new writer with a default buffer capacity, which errors on unencodable characters.
+    pub fn new(inner: W) -> RecodeWriter<W, E> {
+        RecodeWriter::with_capacity(DEFAULT_CAPACITY, inner)
+    }
+
+    /// Create a new writer with a buffer of at least `capacity` bytes, which errors on unencodable
+    /// characters. The buffer will always be large enough to hold at least one character.
+    pub fn with_capacity(capacity: usize, inner: W) -> RecodeWriter<W, E> {
+        RecodeWriter {
+            inner: Some(inner),
+            buf: Vec::with_capacity(usize::max(capacity, E::MAX_LEN)),
+            on_invalid: OnInvalid::Error,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Set how characters which can't be encoded in `E` are handled.
+    pub fn on_invalid(mut self, on_invalid: OnInvalid) -> Self {
+        self.on_invalid = on_invalid;
+        self
+    }
+
+    /// Get a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        self.inner.as_ref().unwrap()
+    }
+
+    /// Get a mutable reference to the inner writer. Writing to it directly may interleave data
+    /// with unflushed output.
+    pub fn get_mut(&mut self) -> &mut W {
+        self.inner.as_mut().unwrap()
+    }
+
+    /// Flush any buffered output, and return the inner writer.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.flush_buf()?;
+        Ok(self.inner.take().unwrap())
+    }
+
+    /// Encode a single character and write it.
+    ///
+    /// If the character can't be encoded and this writer is set to [`OnInvalid::Error`], this
+    /// returns an error of kind [`io::ErrorKind::InvalidData`] containing an [`InvalidChar`].
+    pub fn write_char(&mut self, c: char) -> io::Result<()> {
+        let bytes = match (E::encode_char(c), self.on_invalid) {
+            (Some(bytes), _) => bytes,
+            (None, OnInvalid::Replace) => E::encode_char(E::REPLACEMENT)
+                .expect("Encoding replacement character should always be encodable"),
+            (None, OnInvalid::Error) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    InvalidChar { char: c },
+                ))
+            }
+        };
+        let bytes = bytes.slice();
+        if self.buf.len() + bytes.len() > self.buf.capacity() {
+            self.flush_buf()?;
+        }
+        self.buf.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Recode a string from any encoding and write it. If an error occurs, all characters before
+    /// the one which caused it will have been written.
+    pub fn write_str<E2: Encoding>(&mut self, str: &Str<E2>) -> io::Result<()> {
+        str.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    /// Flush any buffered output to the inner writer, then flush the inner writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.flush_buf()?;
+        self.get_mut().flush()
+    }
+
+    fn flush_buf(&mut self) -> io::Result<()> {
+        if let Some(inner) = &mut self.inner {
+            inner.write_all(&self.buf)?;
+        }
+        self.buf.clear();
+        Ok(())
+    }
+}
+
+/// Writing through [`fmt::Write`] recodes from UTF-8. As [`fmt::Error`] can't carry any
+/// information, prefer [`RecodeWriter::write_str`] where the cause of errors matters.
+impl<W: io::Write, E: Encoding> fmt::Write for RecodeWriter<W, E> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        RecodeWriter::write_str(self, Str::from_std(s)).map_err(|_| fmt::Error)
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        RecodeWriter::write_char(self, c).map_err(|_| fmt::Error)
+    }
+}
+
+impl<W: io::Write + fmt::Debug, E: Encoding> fmt::Debug for RecodeWriter<W, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecodeWriter")
+            .field("inner", &self.inner)
+            .field("encoding", &E::shorthand())
+            .field("buffered", &self.buf.len())
+            .field("on_invalid", &self.on_invalid)
+            .finish()
+    }
+}
+
+impl<W: io::Write, E> Drop for RecodeWriter<W, E> {
+    fn drop(&mut self) {
+        if let Some(inner) = &mut self.inner {
+            let _ = inner.write_all(&self.buf);
+        }
+    }
+}
+
+/// A reader which decodes text in the encoding `E` from an inner [`io::Read`].
+///
+/// Decoded characters can be read either through [`RecodeReader::read_char`] and the [`Iterator`]
+/// implementation, or as UTF-8 bytes through [`io::Read`].
+///
+/// ```
+/// # use enrede::encoding::Win1252;
+/// # use enrede::io::RecodeReader;
+/// # use std::io::Read;
+/// let mut reader = RecodeReader::<_, Win1252>::new(&b"Hello, \x80"[..]);
+/// let mut out = String::new();
+/// reader.read_to_string(&mut out).unwrap();
+/// assert_eq!(out, "Hello, €");
+/// ```
+pub struct RecodeReader<R: io::Read, E> {
+    inner: R,
+    buf: Vec<u8>,
+    // Bytes in `buf[start..valid]` are known valid, `buf[valid..end]` haven't been checked yet
+    start: usize,
+    valid: usize,
+    end: usize,
+    // Number of bytes consumed before the start of the buffer, for error positions
+    consumed: usize,
+    // UTF-8 bytes of a character partially returned by `io::Read`
+    pending: [u8; 4],
+    pending_start: usize,
+    pending_end: usize,
+    // An error to return on the next read, after returning the data before it
+    pending_err: Option<io::Error>,
+    on_invalid: OnInvalid,
+    _phantom: PhantomData<E>,
+}
+
+impl<R: io::Read, E: Encoding> RecodeReader<R, E> {
+    /// Create a new reader with a default buffer capacity, which errors on invalid input.
+    pub fn new(inner: R) -> RecodeReader<R, E> {
+        RecodeReader::with_capacity(DEFAULT_CAPACITY, inner)
+    }
+
+    /// Create a new reader with a buffer of at least `capacity` bytes, which errors on invalid
+    /// input. The buffer will always be large enough to hold at least one character.
+    pub fn with_capacity(capacity: usize, inner: R) -> RecodeReader<R, E> {
+        RecodeReader {
+            inner,
+            buf: vec![0; usize::max(capacity, E::MAX_LEN)],
+            start: 0,
+            valid: 0,
+            end: 0,
+            consumed: 0,
+            pending: [0; 4],
+            pending_start: 0,
+            pending_end: 0,
+            pending_err: None,
+            on_invalid: OnInvalid::Error,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Set how invalid input is handled. Invalid input is replaced with `'\u{FFFD}'`.
+    pub fn on_invalid(mut self, on_invalid: OnInvalid) -> Self {
+        self.on_invalid = on_invalid;
+        self
+    }
+
+    /// Get a reference to the inner reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner reader. Reading from it directly will skip data
+    /// without decoding it.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Read and decode a single character, returning [`None`] at the end of the input.
+    ///
+    /// If the input is invalid and this reader is set to [`OnInvalid::Error`], this returns an
+    /// error of kind [`io::ErrorKind::InvalidData`] containing a [`ValidateError`]. Its position is
+    /// relative to the start of the input.
+    pub fn read_char(&mut self) -> io::Result<Option<char>> {
+        loop {
+            if self.start < self.valid {
+                // SAFETY: Bytes in `start..valid` have been validated, and `start` is always on a
+                //         character boundary
+                let str =
+                    unsafe { Str::<E>::from_bytes_unchecked(&self.buf[self.start..self.valid]) };
+                let (c, rest) = E::decode_char(str);
+                self.start = self.valid - rest.len();
+                return Ok(Some(c));
+            }
+
+            match E::validate(&self.buf[self.start..self.end]) {
+                Ok(()) if self.start < self.end => self.valid = self.end,
+                Err(err) if err.valid_up_to() > 0 => self.valid = self.start + err.valid_up_to(),
+                Err(err) if err.error_len().is_some() => {
+                    let len = err.error_len().unwrap();
+                    let err = ValidateError::new(self.pos(), err.error_len());
+                    self.start += len;
+                    self.valid = self.start;
+                    return self.invalid(err);
+                }
+                // Either the buffer is empty, or it ends with an incomplete character
+                _ => {
+                    if !self.fill_buf()? {
+                        if self.start == self.end {
+                            return Ok(None);
+                        }
+                        // Input ended part-way through a character
+                        let err = ValidateError::new(self.pos(), None);
+                        self.start = self.end;
+                        self.valid = self.end;
+                        return self.invalid(err);
+                    }
+                }
+            }
+        }
+    }
+
+    fn invalid(&self, err: ValidateError) -> io::Result<Option<char>> {
+        match self.on_invalid {
+            OnInvalid::Error => Err(io::Error::new(io::ErrorKind::InvalidData, err)),
+            OnInvalid::Replace => Ok(Some('\u{FFFD}')),
+        }
+    }
+
+    /// Position of the start of the buffer in the input
+    fn pos(&self) -> usize {
+        self.consumed + self.start
+    }
+
+    /// Move unread data to the front of the buffer, and read more after it. Returns `false` if
+    /// the inner reader has no more data.
+    fn fill_buf(&mut self) -> io::Result<bool> {
+        self.buf.copy_within(self.start..self.end, 0);
+        self.consumed += self.start;
+        self.end -= self.start;
+        self.valid -= self.start;
+        self.start = 0;
+        loop {
+            match self.inner.read(&mut self.buf[self.end..]) {
+                Ok(len) => {
+                    self.end += len;
+                    return Ok(len != 0);
+                }
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+impl<R: io::Read, E: Encoding> Iterator for RecodeReader<R, E> {
+    type Item = io::Result<char>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_char().transpose()
+    }
+}
+
+/// Reading through [`io::Read`] produces the decoded text as UTF-8.
+impl<R: io::Read, E: Encoding> io::Read for RecodeReader<R, E> {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        if let Some(err) = self.pending_err.take() {
+            return Err(err);
+        }
+        let mut written = 0;
+        loop {
+            // Finish any character left over from the last call first
+            let pending = &self.pending[self.pending_start..self.pending_end];
+            let len = usize::min(pending.len(), out.len() - written);
+            out[written..written + len].copy_from_slice(&pending[..len]);
+            self.pending_start += len;
+            written += len;
+            if written == out.len() {
+                return Ok(written);
+            }
+
+            // Don't block waiting on more input if we already have something to return
+            if written > 0 && self.start == self.valid {
+                return Ok(written);
+            }
+
+            match self.read_char() {
+                Ok(Some(c)) => {
+                    self.pending_end = c.encode_utf8(&mut self.pending).len();
+                    self.pending_start = 0;
+                }
+                Ok(None) => return Ok(written),
+                Err(e) if written > 0 => {
+                    // Report the error on the next call, after the valid data
+                    self.pending_err = Some(e);
+                    return Ok(written);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::{Big5, Utf16LE, Utf8, Win1252};
+    use alloc::string::String as StdString;
+    use io::Read;
+    #[cfg(feature = "rand")]
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    /// Reader which returns at most `max` bytes at a time, to split characters across reads
+    #[cfg(feature = "rand")]
+    struct Trickle<'a> {
+        data: &'a [u8],
+        max: usize,
+    }
+
+    #[cfg(feature = "rand")]
+    impl io::Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = usize::min(usize::min(self.max, buf.len()), self.data.len());
+            buf[..len].copy_from_slice(&self.data[..len]);
+            self.data = &self.data[len..];
+            Ok(len)
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    fn check_roundtrip<E>(rng: &mut StdRng)
+    where
+        E: Encoding + rand::distributions::Distribution<char>,
+    {
+        // Build the expected output of an in-memory recode directly, as iterating characters of a
+        // string this size is slow with debug assertions
+        let mut encoded = Vec::new();
+        let mut text = StdString::new();
+        while encoded.len() < 2 * 1024 * 1024 {
+            let c = E::default().sample(rng);
+            encoded.extend_from_slice(E::encode_char(c).unwrap().slice());
+            text.push(c);
+        }
+
+        let mut writer = RecodeWriter::<_, E>::with_capacity(64, Vec::new());
+        text.chars().try_for_each(|c| writer.write_char(c)).unwrap();
+        assert_eq!(writer.into_inner().unwrap(), encoded);
+
+        let trickle = Trickle {
+            data: &encoded,
+            max: rng.gen_range(1..100),
+        };
+        let mut reader = RecodeReader::<_, E>::with_capacity(64, trickle);
+        let mut out = StdString::new();
+        reader.read_to_string(&mut out).unwrap();
+        assert_eq!(out, text);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_roundtrip() {
+        let mut rng = StdRng::seed_from_u64(0x10553);
+        check_roundtrip::<Utf8>(&mut rng);
+        check_roundtrip::<Utf16LE>(&mut rng);
+        check_roundtrip::<Win1252>(&mut rng);
+        check_roundtrip::<Big5>(&mut rng);
+    }
+
+    #[test]
+    fn test_writer_invalid() {
+        let mut writer = RecodeWriter::<_, Win1252>::new(Vec::new());
+        writer.write_str(Str::from_std("ab")).unwrap();
+        let err = writer.write_char('\u{3042}').unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let inner = err.into_inner().unwrap();
+        assert_eq!(
+            inner.downcast_ref::<InvalidChar>().unwrap().char(),
+            '\u{3042}'
+        );
+        assert_eq!(writer.into_inner().unwrap(), b"ab");
+
+        let mut writer = RecodeWriter::<_, Win1252>::new(Vec::new()).on_invalid(OnInvalid::Replace);
+        fmt::Write::write_str(&mut writer, "a\u{3042}b").unwrap();
+        assert_eq!(writer.into_inner().unwrap(), b"a\x1Ab");
+    }
+
+    #[test]
+    fn test_writer_drop_flushes() {
+        let mut out = Vec::new();
+        {
+            let mut writer = RecodeWriter::<_, Utf16LE>::new(&mut out);
+            writer.write_char('a').unwrap();
+        }
+        assert_eq!(out, b"a\0");
+    }
+
+    #[test]
+    fn test_reader_invalid() {
+        let data = b"ab\xA4\x40c\x80d\xA4";
+        let mut reader = RecodeReader::<_, Big5>::with_capacity(2, &data[..]);
+        assert_eq!(reader.read_char().unwrap(), Some('a'));
+        assert_eq!(reader.read_char().unwrap(), Some('b'));
+        assert_eq!(reader.read_char().unwrap(), Some('\u{4E00}'));
+        assert_eq!(reader.read_char().unwrap(), Some('c'));
+        let err = reader.read_char().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            *err.into_inner()
+                .unwrap()
+                .downcast::<ValidateError>()
+                .unwrap(),
+            ValidateError::new(5, Some(1))
+        );
+        assert_eq!(reader.read_char().unwrap(), Some('d'));
+        let err = reader.read_char().unwrap_err();
+        assert_eq!(
+            *err.into_inner()
+                .unwrap()
+                .downcast::<ValidateError>()
+                .unwrap(),
+            ValidateError::new(7, None)
+        );
+        assert_eq!(reader.read_char().unwrap(), None);
+
+        let reader = RecodeReader::<_, Big5>::new(&data[..]).on_invalid(OnInvalid::Replace);
+        let chars = reader.collect::<io::Result<StdString>>().unwrap();
+        assert_eq!(chars, "ab\u{4E00}c\u{FFFD}d\u{FFFD}");
+
+        // Valid data is returned before the error
+        let mut reader = RecodeReader::<_, Big5>::new(&data[..]);
+        let mut out = Vec::new();
+        let err = reader.read_to_end(&mut out).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(out, "ab\u{4E00}c".as_bytes());
+    }
+
+    #[test]
+    fn test_reader_small_reads() {
+        let mut reader = RecodeReader::<_, Utf8>::new("a\u{1F600}b".as_bytes());
+        let mut out = Vec::new();
+        let mut buf = [0; 1];
+        while reader.read(&mut buf).unwrap() != 0 {
+            out.push(buf[0]);
+        }
+        assert_eq!(out, "a\u{1F600}b".as_bytes());
+    }
+}
@@ -4,17 +4,32 @@
 
 #[cfg(any(feature = "alloc", test))]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+pub mod arraystring;
 pub mod cstr;
 #[cfg(feature = "alloc")]
 pub mod cstring;
+#[cfg(feature = "detect")]
+pub mod detect;
+#[cfg(feature = "embedded-io")]
+pub mod embedded;
 pub mod encoding;
-pub(crate) mod err;
+pub mod errors;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod lossy;
 pub mod str;
 #[cfg(feature = "alloc")]
 pub mod string;
+pub mod tagged;
+#[cfg(any(feature = "test-util", test))]
+pub mod test_util;
 pub(crate) mod utils;
+pub mod wcstr;
 
+pub use arraystring::ArrayString;
 pub use cstr::CStr;
 #[cfg(feature = "alloc")]
 pub use cstring::CString;
@@ -22,3 +37,144 @@ pub use encoding::Encoding;
 pub use str::Str;
 #[cfg(feature = "alloc")]
 pub use string::String;
+pub use wcstr::WideCStr;
+
+#[cfg(test)]
+mod tests {
+    use crate::cstr::IterPacked;
+    use crate::encoding::*;
+    use crate::str::*;
+    #[cfg(feature = "alloc")]
+    use crate::{
+        string::{Drain, IntoCharIndices, IntoChars},
+        CString, String,
+    };
+    use crate::{ArrayString, CStr};
+    #[cfg(feature = "alloc")]
+    use alloc::boxed::Box;
+
+    const fn assert_auto_traits<T: Send + Sync + Unpin + ?Sized>() {}
+
+    /// Not `Send` or `Sync` - custom encodings shouldn't inherit auto traits from their table type
+    struct PtrTable(#[allow(dead_code)] *const u8);
+
+    impl SingleByteTable for PtrTable {
+        const NAME: &'static str = "ptr";
+        const TABLE: [char; 256] = ['?'; 256];
+    }
+
+    macro_rules! assert_all_auto_traits {
+        ($($enc:ty),* $(,)?) => {
+            $(
+            const _: () = {
+                assert_auto_traits::<$enc>();
+                assert_auto_traits::<Str<$enc>>();
+                assert_auto_traits::<CStr<$enc>>();
+                assert_auto_traits::<ArrayString<$enc, 8>>();
+                assert_auto_traits::<Chars<'static, $enc>>();
+                assert_auto_traits::<CharIndices<'static, $enc>>();
+                assert_auto_traits::<CharSpans<'static, $enc>>();
+                assert_auto_traits::<CharBoundaries<'static, $enc>>();
+                assert_auto_traits::<CharSlices<'static, $enc>>();
+                assert_auto_traits::<EncodeUtf16<'static, $enc>>();
+                assert_auto_traits::<EscapeDebug<'static, $enc>>();
+                assert_auto_traits::<EscapeDefault<'static, $enc>>();
+                assert_auto_traits::<EscapeBytes<'static, $enc>>();
+                assert_auto_traits::<Matches<'static, $enc, char>>();
+                assert_auto_traits::<MatchIndices<'static, $enc, &Str<$enc>>>();
+                assert_auto_traits::<DecodeChunks<'static, $enc>>();
+                assert_auto_traits::<DecodeChunk<'static, $enc>>();
+                assert_auto_traits::<IterPacked<'static, $enc>>();
+                #[cfg(feature = "alloc")]
+                {
+                    assert_auto_traits::<String<$enc>>();
+                    assert_auto_traits::<CString<$enc>>();
+                    assert_auto_traits::<Box<Str<$enc>>>();
+                    assert_auto_traits::<Drain<'static, $enc>>();
+                    assert_auto_traits::<IntoChars<$enc>>();
+                    assert_auto_traits::<IntoCharIndices<$enc>>();
+                }
+            };
+            )*
+        };
+    }
+
+    assert_all_auto_traits!(
+        Ascii,
+        ExtendedAscii,
+        Big5,
+        CustomSingleByte<PtrTable>,
+        Iso8859_2,
+        Iso8859_15,
+        JisX0201,
+        JisX0208,
+        MacRoman,
+        ShiftJis,
+        ShiftJisAscii,
+        Utf8,
+        Utf16LE,
+        Utf16BE,
+        Utf32LE,
+        Utf32BE,
+        Win1251,
+        Win1252,
+        Win1252Loose,
+    );
+
+    const _: () = {
+        assert_auto_traits::<CodeUnits<'static, Utf16LE>>();
+        assert_auto_traits::<CodeUnits<'static, Utf16BE>>();
+        assert_auto_traits::<Iso2022JpChars<'static>>();
+        assert_auto_traits::<&'static crate::WideCStr<Utf16LE>>();
+    };
+
+    #[test]
+    fn test_defaults() {
+        macro_rules! check {
+            ($enc:ty, $name:literal) => {
+                let str = <&Str<$enc>>::default();
+                assert!(str.is_empty() && str.chars().next().is_none());
+                assert!(ArrayString::<$enc, 4>::default().is_empty());
+                #[cfg(feature = "alloc")]
+                {
+                    assert!(String::<$enc>::default().is_empty());
+                    assert!(Box::<Str<$enc>>::default().is_empty());
+                }
+            };
+        }
+        crate::for_each_encoding!(check);
+        check!(CustomSingleByte<PtrTable>, "");
+
+        fn check_cstr<E: NullTerminable + 'static>() {
+            let cstr = <&CStr<E>>::default();
+            assert_eq!(cstr.as_bytes_with_nul(), b"\0");
+            assert!(cstr.is_empty());
+            #[cfg(feature = "alloc")]
+            assert_eq!(CString::<E>::default().as_bytes_with_nul(), b"\0");
+        }
+        check_cstr::<Ascii>();
+        check_cstr::<ExtendedAscii>();
+        check_cstr::<Big5>();
+        check_cstr::<Iso8859_2>();
+        check_cstr::<Iso8859_15>();
+        check_cstr::<JisX0201>();
+        check_cstr::<JisX0208>();
+        check_cstr::<MacRoman>();
+        check_cstr::<ShiftJis>();
+        check_cstr::<ShiftJisAscii>();
+        check_cstr::<Utf8>();
+        check_cstr::<Win1251>();
+        check_cstr::<Win1252>();
+        check_cstr::<Win1252Loose>();
+
+        fn check_wcstr<E: WideNullTerminable>() {
+            let wcstr = <&crate::WideCStr<E>>::default();
+            assert_eq!(wcstr.as_bytes_with_nul(), &[0; 4][..E::UNIT_LEN]);
+            assert!(wcstr.is_empty());
+        }
+        check_wcstr::<Utf16LE>();
+        check_wcstr::<Utf16BE>();
+        check_wcstr::<Utf32LE>();
+        check_wcstr::<Utf32BE>();
+    }
+}
@@ -0,0 +1,225 @@
+//! Iterators decoding bytes which may not be valid for an encoding, without allocating.
+//!
+//! These yield the same characters as [`String::from_bytes_lossy`](crate::String::from_bytes_lossy)
+//! would contain, replacing each invalid sequence with the encoding's
+//! [replacement character](Encoding::REPLACEMENT), but decode on the fly straight from the input.
+//!
+//! ```
+//! # use enrede::{encoding::Utf8, lossy::LossyChars};
+//! let chars = LossyChars::<Utf8>::new(b"caf\xC3\xA9 \xFF!");
+//! assert_eq!(chars.collect::<String>(), "café \u{FFFD}!");
+//! ```
+
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::encoding::Encoding;
+use crate::str::{CharSpans, DecodeChunks, Str};
+
+/// Character iterator over bytes which may not be valid for an encoding. Each invalid sequence
+/// yields a single [replacement character](Encoding::REPLACEMENT).
+///
+/// See [`LossyCharIndices`] to also get the bytes each character was decoded from.
+pub struct LossyChars<'a, E> {
+    iter: LossyCharIndices<'a, E>,
+}
+
+impl<'a, E: Encoding> LossyChars<'a, E> {
+    /// Create an iterator over the characters of `bytes`, decoded lossily
+    pub fn new(bytes: &'a [u8]) -> Self {
+        LossyChars {
+            iter: LossyCharIndices::new(bytes),
+        }
+    }
+
+    /// The byte position of the next character, or the length of the input if the iterator is
+    /// exhausted
+    pub fn offset(&self) -> usize {
+        self.iter.offset()
+    }
+}
+
+impl<E> Clone for LossyChars<'_, E> {
+    fn clone(&self) -> Self {
+        LossyChars {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<E: Encoding> fmt::Debug for LossyChars<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LossyChars")
+            .field("offset", &self.offset())
+            .finish()
+    }
+}
+
+impl<'a, E: Encoding + 'a> Iterator for LossyChars<'a, E> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, c)| c)
+    }
+}
+
+impl<'a, E: Encoding + 'a> FusedIterator for LossyChars<'a, E> {}
+
+/// Character and span iterator over bytes which may not be valid for an encoding. This yields each
+/// character along with the range of bytes it was decoded from, like
+/// [`Str::char_spans`](crate::Str::char_spans).
+///
+/// Each invalid sequence yields a single [replacement character](Encoding::REPLACEMENT), spanning
+/// all the invalid bytes. The spans of all characters tile the input exactly, with no gaps or
+/// overlaps.
+///
+/// ```
+/// # use enrede::{encoding::Utf8, lossy::LossyCharIndices};
+/// let mut iter = LossyCharIndices::<Utf8>::new(b"a\xE6\x97b");
+/// assert_eq!(iter.next(), Some((0..1, 'a')));
+/// assert_eq!(iter.next(), Some((1..3, '\u{FFFD}')));
+/// assert_eq!(iter.next(), Some((3..4, 'b')));
+/// assert_eq!(iter.next(), None);
+/// ```
+pub struct LossyCharIndices<'a, E> {
+    chunks: DecodeChunks<'a, E>,
+    valid: CharSpans<'a, E>,
+    invalid: Option<Range<usize>>,
+    // Position of the start of `valid` in the input
+    start: usize,
+    // Position of the next chunk in the input
+    end: usize,
+}
+
+impl<'a, E: Encoding> LossyCharIndices<'a, E> {
+    /// Create an iterator over the characters of `bytes` and their spans, decoded lossily
+    pub fn new(bytes: &'a [u8]) -> Self {
+        LossyCharIndices {
+            chunks: Str::chunks(bytes),
+            valid: <&Str<E>>::default().char_spans(),
+            invalid: None,
+            start: 0,
+            end: 0,
+        }
+    }
+
+    /// The byte position of the next character, or the length of the input if the iterator is
+    /// exhausted
+    pub fn offset(&self) -> usize {
+        self.start + self.valid.offset()
+    }
+}
+
+impl<E> Clone for LossyCharIndices<'_, E> {
+    fn clone(&self) -> Self {
+        LossyCharIndices {
+            chunks: self.chunks.clone(),
+            valid: self.valid.clone(),
+            invalid: self.invalid.clone(),
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+impl<E: Encoding> fmt::Debug for LossyCharIndices<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LossyCharIndices")
+            .field("offset", &self.offset())
+            .finish()
+    }
+}
+
+impl<'a, E: Encoding + 'a> Iterator for LossyCharIndices<'a, E> {
+    type Item = (Range<usize>, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((span, c)) = self.valid.next() {
+                return Some((self.start + span.start..self.start + span.end, c));
+            }
+            if let Some(invalid) = self.invalid.take() {
+                // Move past the invalid bytes, so the offset points to the next chunk
+                self.start = self.end;
+                self.valid = <&Str<E>>::default().char_spans();
+                return Some((invalid, E::REPLACEMENT));
+            }
+
+            let chunk = self.chunks.next()?;
+            let valid_end = self.end + chunk.valid().len();
+            self.start = self.end;
+            self.end = valid_end + chunk.invalid().len();
+            self.valid = chunk.valid().char_spans();
+            if !chunk.invalid().is_empty() {
+                self.invalid = Some(valid_end..self.end);
+            }
+        }
+    }
+}
+
+impl<'a, E: Encoding + 'a> FusedIterator for LossyCharIndices<'a, E> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::{Ascii, ShiftJis, Utf16LE, Utf8};
+    use alloc::vec::Vec;
+
+    fn spans<E: Encoding>(bytes: &[u8]) -> Vec<(Range<usize>, char)> {
+        let mut iter = LossyCharIndices::<E>::new(bytes);
+        let mut out = Vec::new();
+        loop {
+            let offset = iter.offset();
+            match iter.next() {
+                Some((span, c)) => {
+                    assert_eq!(span.start, offset);
+                    out.push((span, c));
+                }
+                None => {
+                    assert_eq!(offset, bytes.len());
+                    return out;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_spans() {
+        assert_eq!(spans::<Utf8>(b""), []);
+        assert_eq!(spans::<Utf8>(b"ab"), [(0..1, 'a'), (1..2, 'b')]);
+        assert_eq!(
+            spans::<Utf8>(b"\xFF\xFF"),
+            [(0..1, '\u{FFFD}'), (1..2, '\u{FFFD}')]
+        );
+        assert_eq!(
+            spans::<Utf8>(b"\xFF\xC3\xA9\xE6\x97"),
+            [(0..1, '\u{FFFD}'), (1..3, 'é'), (3..5, '\u{FFFD}')]
+        );
+        assert_eq!(
+            spans::<Ascii>(b"a\x80b"),
+            [(0..1, 'a'), (1..2, Ascii::REPLACEMENT), (2..3, 'b')]
+        );
+        assert_eq!(
+            spans::<ShiftJis>(b"\x82\xA0\xFFa"),
+            [(0..2, 'あ'), (2..3, ShiftJis::REPLACEMENT), (3..4, 'a')]
+        );
+        assert_eq!(
+            spans::<Utf16LE>(b"a\0\x00\xDCb\0c"),
+            [
+                (0..2, 'a'),
+                (2..4, '\u{FFFD}'),
+                (4..6, 'b'),
+                (6..7, '\u{FFFD}')
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chars() {
+        let chars = LossyChars::<Utf8>::new(b"a\xFFb");
+        assert_eq!(chars.clone().collect::<Vec<_>>(), ['a', '\u{FFFD}', 'b']);
+        let mut chars = chars.skip(1);
+        assert_eq!(chars.next(), Some('\u{FFFD}'));
+    }
+}
@@ -5,14 +5,23 @@
 #[cfg(feature = "alloc")]
 use alloc::borrow::ToOwned;
 #[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::collections::TryReserveError;
+#[cfg(feature = "alloc")]
 use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use bytemuck::must_cast_slice as cast_slice;
 use core::cmp::Ordering;
 use core::fmt::Write;
 use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
-use core::ops::{Bound, Index, RangeBounds};
+#[cfg(feature = "alloc")]
+use core::ops::Mul;
+use core::ops::{Bound, Index, Range, RangeBounds};
 use core::slice::SliceIndex;
+use core::str::FromStr;
 use core::{fmt, mem, ptr, slice};
 #[cfg(feature = "serde")]
 use serde::{
@@ -20,16 +29,56 @@ use serde::{
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
+use crate::encoding::sealed::UnicodeForm;
 #[cfg(feature = "alloc")]
 use crate::encoding::RecodeCause;
-use crate::encoding::{AlwaysValid, Encoding, Utf16, Utf32, Utf8, ValidateError};
-pub use crate::err::RecodeError;
+#[cfg(feature = "alloc")]
+use crate::encoding::UnicodeComplete;
+use crate::encoding::{
+    AlwaysValid, ArrayLike, Ascii, AsciiCompatible, Encoding, SingleByte, Utf16BE, Utf16LE,
+    Utf16Native, Utf32Native, Utf8, ValidateError,
+};
+#[cfg(feature = "alloc")]
+pub use crate::errors::TryRecodeError;
+pub use crate::errors::{
+    FillError, LengthMismatch, ParseError, RecodeError, SliceError, SliceErrorKind,
+};
 #[cfg(feature = "alloc")]
 use crate::string::String;
+use crate::utils::paranoid_assert;
 
+mod chunks;
+mod display;
+mod fullwidth;
 mod iter;
+mod pattern;
 
-pub use iter::{CharIndices, Chars};
+pub use chunks::{DecodeChunk, DecodeChunks};
+pub use display::{DebugTruncated, DisplayBytesLossy};
+pub use iter::{
+    CharBoundaries, CharIndices, CharSlices, CharSpans, Chars, CodeUnits, EncodeUtf16, EscapeBytes,
+    EscapeDebug, EscapeDefault, RecodeBytes, RecodeBytesLossy,
+};
+#[cfg(feature = "unicode-normalization")]
+pub use iter::{NfcChars, NfdChars};
+pub use pattern::{MatchIndices, Matches, Pattern};
+
+/// The byte order mark, recognized at the start of UTF-8, UTF-16, and UTF-32 strings
+const BOM: char = '\u{FEFF}';
+
+/// What to do with a character that can't be represented in the destination encoding, returned
+/// by the policy passed to [`Str::recode_with`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Fallback<'a> {
+    /// Replace the character with another character
+    Replace(char),
+    /// Replace the character with a sequence of characters, such as a transliteration
+    ReplaceStr(&'a str),
+    /// Drop the character from the output
+    Skip,
+    /// Stop recoding and return an error
+    Error,
+}
 
 /// Implementation of a generically encoded [`str`] type. This type is similar to the standard
 /// library [`str`] type in many ways, but instead of having a fixed UTF-8 encoding scheme, it uses
@@ -38,6 +87,11 @@ pub use iter::{CharIndices, Chars};
 /// `Str` only implements `==` between instances with the same encoding. To compare strings of
 /// different encoding by characters, use `a.chars().eq(b.chars())`.
 ///
+/// Hashing a `Str<Utf8>` hashes its bytes the same way [`str`] does, so it hashes identically to
+/// the equivalent `str`. Other encodings hash like a byte slice, with a length prefix. [`String`],
+/// [`CStr`](crate::CStr), and [`CString`](crate::CString) hash the same as the `Str` they contain,
+/// so any of them can be looked up by `&Str<E>` in a hash map.
+///
 /// ## Invariant
 ///
 /// Rust libraries may assume that a `Str<E>` is valid for the [`Encoding`] `E`.
@@ -47,21 +101,56 @@ pub use iter::{CharIndices, Chars};
 #[repr(transparent)]
 pub struct Str<E>(PhantomData<E>, [u8]);
 
+impl<E: Encoding + 'static> Str<E> {
+    /// An empty string, valid in every encoding. This is the same as [`Default`], but usable in
+    /// const contexts.
+    pub const EMPTY: &'static Str<E> = {
+        // SAFETY: Empty string slice can never be invalid
+        unsafe { Str::cast_unchecked(&[]) }
+    };
+}
+
 impl<E: Encoding> Str<E> {
     /// Create a `Str` from a byte slice without checking whether it is valid for the current
     /// encoding.
     ///
+    /// This isn't a `const fn`, as validity is asserted in debug builds. In const contexts, use
+    /// [`Str::from_ascii`] or [`Str::from_std`] instead.
+    ///
     /// # Safety
     ///
     /// The bytes passed must be valid for the current encoding.
     pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Str<E> {
-        debug_assert!(E::validate(bytes).is_ok());
+        paranoid_assert!(E::validate(bytes).is_ok());
+        // SAFETY: Provided bytes have precondition of being valid encoding
+        unsafe { Str::cast_unchecked(bytes) }
+    }
+
+    /// Implementation of [`Str::from_bytes_unchecked`], without any assertions so it can be used
+    /// in const contexts.
+    ///
+    /// # Safety
+    ///
+    /// The bytes passed must be valid for the current encoding.
+    const unsafe fn cast_unchecked(bytes: &[u8]) -> &Str<E> {
         let ptr = ptr::from_ref(bytes) as *const Str<E>;
         // SAFETY: `Str` is `repr(transparent)` containing a [u8].
         //         Provided bytes have precondition of being valid encoding
         unsafe { &*ptr }
     }
 
+    /// Mutable version of [`Str::cast_unchecked`].
+    ///
+    /// # Safety
+    ///
+    /// The bytes passed must be valid for the current encoding.
+    unsafe fn cast_unchecked_mut(bytes: &mut [u8]) -> &mut Str<E> {
+        let ptr = ptr::from_mut(bytes) as *mut Str<E>;
+        // SAFETY: `Str` is `repr(transparent)` containing a [u8].
+        //         Provided bytes have precondition of being valid encoding
+        unsafe { &mut *ptr }
+    }
+
     /// Create a `Str` from a mutable byte slice without checking whether it is valid for the
     /// current encoding.
     ///
@@ -69,7 +158,7 @@ impl<E: Encoding> Str<E> {
     ///
     /// The bytes passed must be valid for the current encoding.
     pub unsafe fn from_bytes_unchecked_mut(bytes: &mut [u8]) -> &mut Str<E> {
-        debug_assert!(E::validate(bytes).is_ok());
+        paranoid_assert!(E::validate(bytes).is_ok());
         let ptr = ptr::from_mut(bytes) as *mut Str<E>;
         // SAFETY: `Str` is `repr(transparent)` containing a [u8].
         //         Provided bytes have precondition of being valid encoding
@@ -92,21 +181,86 @@ impl<E: Encoding> Str<E> {
         Ok(unsafe { Self::from_bytes_unchecked_mut(bytes) })
     }
 
+    /// Create a `Str` from a pointer and a length in bytes, without checking whether the data is
+    /// valid for the current encoding. This is the `Str` equivalent of [`slice::from_raw_parts`].
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` and `len` must uphold all the requirements of [`slice::from_raw_parts`].
+    /// - The `len` bytes starting at `ptr` must be valid for the current encoding.
+    /// - The lifetime `'a` is chosen by the caller, and must not outlive the pointed-to data.
+    pub unsafe fn from_raw_parts<'a>(ptr: *const u8, len: usize) -> &'a Str<E> {
+        // SAFETY: Precondition that pointer and length are valid for a slice
+        let bytes = unsafe { slice::from_raw_parts(ptr, len) };
+        // SAFETY: Precondition that the data is valid for the encoding
+        unsafe { Self::from_bytes_unchecked(bytes) }
+    }
+
+    /// Create a mutable `Str` from a pointer and a length in bytes, without checking whether the
+    /// data is valid for the current encoding. This is the `Str` equivalent of
+    /// [`slice::from_raw_parts_mut`].
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` and `len` must uphold all the requirements of [`slice::from_raw_parts_mut`].
+    /// - The `len` bytes starting at `ptr` must be valid for the current encoding.
+    /// - The lifetime `'a` is chosen by the caller, and must not outlive the pointed-to data.
+    pub unsafe fn from_raw_parts_mut<'a>(ptr: *mut u8, len: usize) -> &'a mut Str<E> {
+        // SAFETY: Precondition that pointer and length are valid for a mutable slice
+        let bytes = unsafe { slice::from_raw_parts_mut(ptr, len) };
+        // SAFETY: Precondition that the data is valid for the encoding
+        unsafe { Self::from_bytes_unchecked_mut(bytes) }
+    }
+
+    /// Split a byte slice into chunks of valid and invalid data for the current encoding. This is
+    /// similar to `<[u8]>::utf8_chunks` in the standard library, but works for any encoding.
+    ///
+    /// See [`DecodeChunks`] for further details.
+    pub fn chunks(bytes: &[u8]) -> DecodeChunks<'_, E> {
+        DecodeChunks::new(bytes)
+    }
+
+    /// Display a byte slice in the current encoding, without validating it first. Valid data is
+    /// written out as text, and invalid bytes as `\xNN` escapes. This doesn't allocate, so is
+    /// suitable for logging data which may not be valid.
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::Utf8};
+    /// let display = Str::<Utf8>::display_bytes_lossy(b"caf\xC3\xA9 \xFF\xC3");
+    /// assert_eq!(display.to_string(), "café \\xff\\xc3");
+    /// ```
+    pub fn display_bytes_lossy(bytes: &[u8]) -> DisplayBytesLossy<'_, E> {
+        DisplayBytesLossy::new(bytes)
+    }
+
     /// Get the length of this string in bytes
-    pub fn len(&self) -> usize {
+    pub const fn len(&self) -> usize {
         self.as_bytes().len()
     }
 
     /// Whether this string is empty - IE is a zero-length slice.
-    pub fn is_empty(&self) -> bool {
+    pub const fn is_empty(&self) -> bool {
         self.as_bytes().is_empty()
     }
 
     /// Get the underlying bytes for this string
-    pub fn as_bytes(&self) -> &[u8] {
+    pub const fn as_bytes(&self) -> &[u8] {
         &self.1
     }
 
+    /// Get a pointer to the first byte of this string. The pointer is valid for [`Str::len`]
+    /// bytes, and may be passed back to [`Str::from_raw_parts`] along with that length.
+    pub fn as_ptr(&self) -> *const u8 {
+        self.1.as_ptr()
+    }
+
+    /// Get a mutable pointer to the first byte of this string. The pointer is valid for
+    /// [`Str::len`] bytes. Writing invalid data for the encoding through this pointer breaks the
+    /// invariant of `Str`, see [`Str::as_bytes_mut`].
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.1.as_mut_ptr()
+    }
+
     /// Get the underlying bytes for this string mutably. This method is unsafe because it is
     /// possible to write invalid bytes for the encoding into the slice.
     ///
@@ -117,30 +271,71 @@ impl<E: Encoding> Str<E> {
         &mut self.1
     }
 
-    fn check_bounds<R>(&self, idx: &R) -> Option<()>
+    /// Re-validate the contents of this string for the current encoding. A `Str` is always valid
+    /// unless its invariant has been broken, EG by writing invalid data through
+    /// [`Str::as_bytes_mut`], so this is mostly useful for debugging unsafe code.
+    pub fn validate_self(&self) -> Result<(), ValidateError> {
+        E::validate(self.as_bytes())
+    }
+
+    /// Check that `idx` is in bounds and on character boundaries, returning the range of bytes it
+    /// covers. Slicing must use the returned range rather than `idx` itself, as an exhausted
+    /// [`RangeInclusive`](core::ops::RangeInclusive) slices differently from its bounds.
+    fn check_bounds<R>(&self, idx: &R) -> Result<Range<usize>, SliceError>
     where
         R: RangeBounds<usize>,
     {
-        let start = idx.start_bound();
-        let end = idx.end_bound();
-
-        let start_idx = match start {
+        let start = match idx.start_bound() {
             Bound::Included(i) => *i,
-            Bound::Excluded(i) => *i + 1,
+            Bound::Excluded(i) => i.saturating_add(1),
             Bound::Unbounded => 0,
         };
 
-        let end_idx = match end {
-            Bound::Included(i) => *i,
-            Bound::Excluded(i) => *i - 1,
-            Bound::Unbounded => self.as_bytes().len(),
+        let end = match idx.end_bound() {
+            Bound::Included(i) => i.saturating_add(1),
+            Bound::Excluded(i) => *i,
+            Bound::Unbounded => self.len(),
         };
 
-        if !self.is_char_boundary(start_idx) || !self.is_char_boundary(end_idx) {
-            None
+        let kind = if end > self.len() {
+            SliceErrorKind::OutOfBounds { len: self.len() }
+        } else if start > end {
+            SliceErrorKind::StartAfterEnd
+        } else if !self.is_char_boundary(start) {
+            self.boundary_error(start)
+        } else if !self.is_char_boundary(end) {
+            self.boundary_error(end)
         } else {
-            Some(())
+            return Ok(start..end);
+        };
+
+        Err(SliceError {
+            start,
+            end,
+            kind,
+            encoding: E::shorthand(),
+        })
+    }
+
+    /// Find the character containing the non-boundary index `idx`
+    #[cold]
+    fn boundary_error(&self, idx: usize) -> SliceErrorKind {
+        let mut iter = self.char_indices().peekable();
+        while let Some((prev, char)) = iter.next() {
+            let next = iter.peek().map_or(self.len(), |(next, _)| *next);
+            if idx < next {
+                return SliceErrorKind::NotCharBoundary {
+                    idx,
+                    prev,
+                    next,
+                    char,
+                };
+            }
         }
+        unreachable!(
+            "Index {} is within the string, so must be inside a character",
+            idx
+        )
     }
 
     /// Return a subslice of this `Str`. This is a non-panicking alternative to indexing, returning
@@ -149,10 +344,34 @@ impl<E: Encoding> Str<E> {
     where
         R: RangeBounds<usize> + SliceIndex<[u8], Output = [u8]>,
     {
-        self.check_bounds(&idx)?;
-        // SAFETY: The provided range has been validated as landing on character boundaries.
-        //         Our internal bytes are guaranteed valid for the encoding.
-        Some(unsafe { Str::from_bytes_unchecked(self.as_bytes().get(idx)?) })
+        self.get_checked(idx).ok()
+    }
+
+    /// Return a subslice of this `Str`. This is similar to [`Str::get`], but on failure returns an
+    /// error describing why the range was invalid, instead of [`None`].
+    pub fn get_checked<R>(&self, idx: R) -> Result<&Self, SliceError>
+    where
+        R: RangeBounds<usize> + SliceIndex<[u8], Output = [u8]>,
+    {
+        let range = self.check_bounds(&idx)?;
+        // SAFETY: The range has been validated as in-bounds and landing on character boundaries.
+        Ok(unsafe { self.slice_unchecked(range) })
+    }
+
+    /// Slice this string without checking that the range lies on character boundaries. Finding a
+    /// boundary isn't constant time in every encoding, so code which already knows its positions
+    /// are boundaries, such as those reached by decoding, uses this to stay linear.
+    ///
+    /// A slice of a valid string at character boundaries is always valid, so unlike
+    /// [`Str::from_bytes_unchecked`] this doesn't re-validate the result, even in paranoid mode.
+    ///
+    /// # Safety
+    ///
+    /// The range must be in bounds, and both ends must be character boundaries.
+    pub(crate) unsafe fn slice_unchecked(&self, range: Range<usize>) -> &Str<E> {
+        // SAFETY: The caller guarantees the range is in bounds and on character boundaries, and
+        //         our internal bytes are guaranteed valid for the encoding.
+        unsafe { Str::cast_unchecked(self.as_bytes().get_unchecked(range)) }
     }
 
     /// Return a mutable subslice of this `Str`. This is a non-panicking alternative to indexing,
@@ -161,10 +380,10 @@ impl<E: Encoding> Str<E> {
     where
         R: RangeBounds<usize> + SliceIndex<[u8], Output = [u8]>,
     {
-        self.check_bounds(&idx)?;
-        // SAFETY: The provided range has been validated as landing on character boundaries.
+        let range = self.check_bounds(&idx).ok()?;
+        // SAFETY: The range has been validated as in-bounds and landing on character boundaries.
         //         Our internal bytes are guaranteed valid for the encoding.
-        Some(unsafe { Str::from_bytes_unchecked_mut(self.1.get_mut(idx)?) })
+        Some(unsafe { Str::cast_unchecked_mut(self.1.get_unchecked_mut(range)) })
     }
 
     /// Check whether the byte at `idx` is on a character boundary - IE is the first byte in a code
@@ -172,6 +391,11 @@ impl<E: Encoding> Str<E> {
     ///
     /// The start and end of the string are considered boundaries, indexes greater than `self.len()`
     /// are considered not boundaries.
+    ///
+    /// This is constant time for most encodings. In [`JisX0208`](crate::encoding::JisX0208),
+    /// [`ShiftJis`](crate::encoding::ShiftJis), and [`Big5`](crate::encoding::Big5), it's linear
+    /// in the distance back to the nearest byte which can only end a character, which in the worst
+    /// case is the start of the string. Slicing checks both ends of the range the same way.
     pub fn is_char_boundary(&self, idx: usize) -> bool {
         match idx.cmp(&self.len()) {
             Ordering::Equal => true,
@@ -180,6 +404,193 @@ impl<E: Encoding> Str<E> {
         }
     }
 
+    /// Find the closest `x` not exceeding `idx` where
+    /// [`is_char_boundary(x)`](Str::is_char_boundary) is `true`. Indices past the end of the
+    /// string return the length of the string.
+    ///
+    /// This checks at most [`MAX_LEN`](Encoding::MAX_LEN) positions, so is never slower than
+    /// [`Str::is_char_boundary`] by more than a constant factor.
+    pub fn floor_char_boundary(&self, idx: usize) -> usize {
+        if idx >= self.len() {
+            return self.len();
+        }
+        let lower = idx.saturating_sub(E::MAX_LEN - 1);
+        (lower..=idx)
+            .rev()
+            .find(|&idx| self.is_char_boundary(idx))
+            // All characters are at most `MAX_LEN` bytes, so this is unreachable
+            .unwrap_or(lower)
+    }
+
+    /// Find the closest `x` not below `idx` where
+    /// [`is_char_boundary(x)`](Str::is_char_boundary) is `true`. Indices past the end of the
+    /// string return the length of the string.
+    ///
+    /// This checks at most [`MAX_LEN`](Encoding::MAX_LEN) positions, so is never slower than
+    /// [`Str::is_char_boundary`] by more than a constant factor.
+    pub fn ceil_char_boundary(&self, idx: usize) -> usize {
+        if idx > self.len() {
+            return self.len();
+        }
+        let upper = usize::min(idx + E::MAX_LEN - 1, self.len());
+        (idx..=upper)
+            .find(|&idx| self.is_char_boundary(idx))
+            // All characters are at most `MAX_LEN` bytes, so this is unreachable
+            .unwrap_or(upper)
+    }
+
+    /// Shorten this string slice to at most `len` bytes, without splitting a character. This is
+    /// useful when fitting a string into a fixed-size field.
+    pub fn truncate_to_fit(&self, len: usize) -> &Str<E> {
+        let end = self.floor_char_boundary(len);
+        // SAFETY: `end` is a character boundary no greater than our length
+        unsafe { Str::from_bytes_unchecked(self.as_bytes().get_unchecked(..end)) }
+    }
+
+    /// Get the display width of this string in terminal columns, as determined by
+    /// [`unicode-width`](unicode_width). Fullwidth characters take two columns, while control
+    /// characters and combining marks take none.
+    #[cfg(feature = "width")]
+    pub fn width(&self) -> usize {
+        self.chars().map(char_width).sum()
+    }
+
+    /// Shorten this string slice to the longest prefix which takes at most `cols` columns to
+    /// display, without splitting a character. See [`Str::width`] for how width is calculated.
+    #[cfg(feature = "width")]
+    pub fn truncate_to_width(&self, cols: usize) -> &Str<E> {
+        let mut width = 0;
+        for (idx, c) in self.char_indices() {
+            width += char_width(c);
+            if width > cols {
+                // SAFETY: `idx` is the start of a character, so is a character boundary
+                return unsafe { Str::from_bytes_unchecked(self.as_bytes().get_unchecked(..idx)) };
+            }
+        }
+        self
+    }
+
+    /// Return an iterator over the characters of this string in Unicode Normalization Form C, as
+    /// determined by [`unicode-normalization`](unicode_normalization). Decomposed characters, such
+    /// as `'e'` followed by a combining acute accent, are composed into a single `'é'`.
+    #[cfg(feature = "unicode-normalization")]
+    pub fn nfc_chars(&self) -> NfcChars<'_, E> {
+        NfcChars::new(self)
+    }
+
+    /// Return an iterator over the characters of this string in Unicode Normalization Form D, as
+    /// determined by [`unicode-normalization`](unicode_normalization). Composed characters, such
+    /// as `'é'`, are split into a base character followed by combining marks.
+    #[cfg(feature = "unicode-normalization")]
+    pub fn nfd_chars(&self) -> NfdChars<'_, E> {
+        NfdChars::new(self)
+    }
+
+    /// Whether this string and `other` contain the same text after normalizing both to
+    /// [NFC](Str::nfc_chars). This compares lazily, without allocating, so works across any pair
+    /// of encodings.
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::MacRoman};
+    /// let mac = Str::<MacRoman>::from_bytes(b"caf\x8E").unwrap();
+    /// assert!(mac.eq_normalized(Str::from_std("cafe\u{301}")));
+    /// assert!(!mac.chars().eq("cafe\u{301}".chars()));
+    /// ```
+    #[cfg(feature = "unicode-normalization")]
+    pub fn eq_normalized<E2: Encoding>(&self, other: &Str<E2>) -> bool {
+        self.nfc_chars().eq(other.nfc_chars())
+    }
+
+    /// Whether this string and `other` contain the same text, ignoring the difference between
+    /// halfwidth and fullwidth forms. Both strings are compared as if converted with
+    /// [`Str::to_fullwidth`], so a halfwidth katakana and voicing mark equal the voiced fullwidth
+    /// katakana. This compares lazily, without allocating, so works across any pair of encodings.
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::ShiftJis};
+    /// // "ｶﾞｲﾄﾞ", in halfwidth katakana
+    /// let str = Str::<ShiftJis>::from_bytes(b"\xB6\xDE\xB2\xC4\xDE").unwrap();
+    /// assert!(str.eq_ignore_width(Str::from_std("ガイド")));
+    /// assert!(!str.eq_ignore_width(Str::from_std("カイド")));
+    /// ```
+    pub fn eq_ignore_width<E2: Encoding>(&self, other: &Str<E2>) -> bool {
+        let this = fullwidth::Widen::new(self.chars(), |_| true);
+        this.eq(fullwidth::Widen::new(other.chars(), |_| true))
+    }
+
+    /// Compare this string with `other` by their encoded bytes. This is a total order, and the
+    /// cheapest comparison, but its order only means something for some encodings. See
+    /// [`Str::cmp_chars`] for ordering by character.
+    pub fn cmp_bytes(&self, other: &Str<E>) -> Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+
+    /// Compare this string with `other` by their characters, in Unicode code point order. This is
+    /// a plain comparison of code points - no Unicode normalization or locale-aware collation is
+    /// performed.
+    ///
+    /// For UTF-8 and UTF-32BE this is the same as [`Str::cmp_bytes`], and is just as cheap, but
+    /// other encodings can disagree. In UTF-16, characters above `U+FFFF` are encoded as
+    /// surrogates, which sort by bytes before characters from `U+E000` to `U+FFFF`:
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::Utf16LE};
+    /// # use std::cmp::Ordering;
+    /// let supplementary = Str::<Utf16LE>::from_bytes(b"\x00\xD8\x00\xDC").unwrap();
+    /// let private_use = Str::<Utf16LE>::from_bytes(b"\x00\xE0").unwrap();
+    /// assert_eq!(supplementary.chars().next(), Some('\u{10000}'));
+    /// assert_eq!(private_use.chars().next(), Some('\u{E000}'));
+    ///
+    /// assert_eq!(supplementary.cmp_bytes(private_use), Ordering::Less);
+    /// assert_eq!(supplementary.cmp_chars(private_use), Ordering::Greater);
+    /// ```
+    pub fn cmp_chars(&self, other: &Str<E>) -> Ordering {
+        match E::UNICODE {
+            // Both of these encode code points so their bytes sort in code point order
+            Some(UnicodeForm::Utf8 | UnicodeForm::Utf32BE) => self.cmp_bytes(other),
+            _ => self.chars().cmp(other.chars()),
+        }
+    }
+
+    /// Compare this string with `other`, in any encoding, by their characters in Unicode code
+    /// point order. This is the same as [`Str::cmp_chars`], but works across encodings.
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::{ShiftJis, Win1252}};
+    /// # use std::cmp::Ordering;
+    /// let win = Str::<Win1252>::from_bytes(b"caf\xE9").unwrap();
+    /// let sjis = Str::<ShiftJis>::from_bytes(b"caf\x82\xA0").unwrap();
+    /// assert_eq!(win.cmp_chars_cross(sjis), Ordering::Less);
+    /// assert_eq!(win.cmp_chars_cross(Str::from_std("café")), Ordering::Equal);
+    /// ```
+    pub fn cmp_chars_cross<E2: Encoding>(&self, other: &Str<E2>) -> Ordering {
+        self.chars().cmp(other.chars())
+    }
+
+    /// Returns `true` if this string starts with a byte order mark, the character `U+FEFF`. This is
+    /// always `false` for encodings other than UTF-8, UTF-16, and UTF-32, which have no concept of
+    /// a byte order mark.
+    pub fn has_bom(&self) -> bool {
+        E::UNICODE.is_some() && self.chars().next() == Some(BOM)
+    }
+
+    /// Remove a byte order mark from the start of this string, if it has one. Only a single leading
+    /// mark is removed - `U+FEFF` anywhere else is an ordinary character, and is left in place.
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::Utf16BE};
+    /// let str = Str::<Utf16BE>::from_bytes(b"\xFE\xFF\0a\xFE\xFF").unwrap();
+    /// assert!(str.has_bom());
+    /// assert!(str.strip_bom().chars().eq(['a', '\u{FEFF}']));
+    /// ```
+    pub fn strip_bom(&self) -> &Str<E> {
+        if self.has_bom() {
+            E::decode_char(self).1
+        } else {
+            self
+        }
+    }
+
     /// Returns `true` if the given pattern is a prefix of this string slice, `false` otherwise.
     pub fn starts_with(&self, other: &Self) -> bool {
         self.as_bytes().starts_with(other.as_bytes())
@@ -190,6 +601,59 @@ impl<E: Encoding> Str<E> {
         self.as_bytes().ends_with(other.as_bytes())
     }
 
+    /// Returns `true` if `prefix` is a prefix of this string slice, ignoring ASCII case. Only the
+    /// ASCII letters `a-z` and `A-Z` are folded - all other characters must match exactly.
+    pub fn starts_with_ignore_ascii_case(&self, prefix: &Self) -> bool {
+        self.strip_prefix_ignore_ascii_case(prefix).is_some()
+    }
+
+    /// Returns this string slice with `prefix` removed from the start, ignoring ASCII case, or
+    /// [`None`] if it doesn't start with `prefix`. As with
+    /// [`starts_with_ignore_ascii_case`](Self::starts_with_ignore_ascii_case), only ASCII letters
+    /// are folded.
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::Win1252};
+    /// let str = Str::<Win1252>::from_bytes(b"Content-Type: text/plain").unwrap();
+    /// let prefix = Str::from_bytes(b"content-type:").unwrap();
+    /// assert_eq!(str.strip_prefix_ignore_ascii_case(prefix).unwrap(), " text/plain");
+    /// ```
+    pub fn strip_prefix_ignore_ascii_case(&self, prefix: &Self) -> Option<&Self> {
+        self.strip_chars_ignore_ascii_case(prefix.chars())
+    }
+
+    /// Implementation of [`Str::strip_prefix_ignore_ascii_case`], for a prefix of any characters
+    fn strip_chars_ignore_ascii_case(&self, prefix: impl Iterator<Item = char>) -> Option<&Self> {
+        let mut chars = self.chars();
+        for p in prefix {
+            if !chars.next()?.eq_ignore_ascii_case(&p) {
+                return None;
+            }
+        }
+        let (_, rest) = self.1.split_at(chars.offset());
+        // SAFETY: The offset of a char iterator always lies on a character boundary
+        Some(unsafe { Str::from_bytes_unchecked(rest) })
+    }
+
+    /// Returns `true` if the given pattern matches a sub-slice of this string slice, `false`
+    /// otherwise. Unlike a naive byte search, this will only find matches starting on a character
+    /// boundary.
+    pub fn contains<P: Pattern<E>>(&self, pat: P) -> bool {
+        pat.find_in(self).is_some()
+    }
+
+    /// Return an iterator over the non-overlapping matches of a pattern in this string slice. See
+    /// [`str::matches`] for more details.
+    pub fn matches<P: Pattern<E>>(&self, pat: P) -> Matches<'_, E, P> {
+        Matches::new(self, pat)
+    }
+
+    /// Return an iterator over the non-overlapping matches of a pattern in this string slice, as
+    /// well as the byte index each match starts at. See [`str::match_indices`] for more details.
+    pub fn match_indices<P: Pattern<E>>(&self, pat: P) -> MatchIndices<'_, E, P> {
+        MatchIndices::new(self, pat)
+    }
+
     /// Return an iterator over the [`char`]s of this string slice. See [`str::chars`] for caveats
     /// about this method.
     pub fn chars(&self) -> Chars<'_, E> {
@@ -202,453 +666,2928 @@ impl<E: Encoding> Str<E> {
         CharIndices::new(self)
     }
 
-    /// Copy the data from another string into this one.
-    pub fn copy_from(&mut self, other: &Str<E>) {
-        if self.len() != other.len() {
-            panic!(
-                "Source string length ({}) doesn't match destination string length ({})",
-                other.len(),
-                self.len(),
-            );
-        }
-        self.1.copy_from_slice(other.as_bytes());
+    /// Return an iterator over the [`char`]s of this string slice and the byte ranges they occupy.
+    /// The spans cover the whole string, each starting where the previous one ended.
+    ///
+    /// ```
+    /// # use enrede::Str;
+    /// let str = Str::from_std("aé𐐷");
+    /// let spans = str.char_spans().collect::<Vec<_>>();
+    /// assert_eq!(spans, [(0..1, 'a'), (1..3, 'é'), (3..7, '𐐷')]);
+    /// ```
+    pub fn char_spans(&self) -> CharSpans<'_, E> {
+        CharSpans::new(self)
     }
 
-    /// Split this string at an index, returning the two substrings on either side. This method
-    /// panics if the index doesn't lie on a character boundary.
-    pub fn split_at(&self, idx: usize) -> Option<(&Str<E>, &Str<E>)> {
-        if self.is_char_boundary(idx) && idx < self.len() {
-            let (start, end) = self.1.split_at(idx);
-            // SAFETY: Index is a character boundary. Internal data guaranteed valid.
-            let start = unsafe { Str::from_bytes_unchecked(start) };
-            // SAFETY: Index is a character boundary. Internal data guaranteed valid.
-            let end = unsafe { Str::from_bytes_unchecked(end) };
-            Some((start, end))
-        } else {
+    /// Return an iterator over the character boundaries of this string slice, in ascending order.
+    /// This includes both `0` and `self.len()`, so an empty string yields a single `0`.
+    pub fn char_boundaries(&self) -> CharBoundaries<'_, E> {
+        CharBoundaries::new(self)
+    }
+
+    /// Convert a byte index into this string into the index of the character starting there,
+    /// counting from zero. The length of the string maps to the number of characters in it.
+    /// Returns [`None`] if `byte_idx` isn't a character boundary, or is out of bounds.
+    ///
+    /// This is constant time for encodings where every character has the same length, such as
+    /// single-byte encodings and UTF-32, and linear otherwise.
+    pub fn byte_to_char_index(&self, byte_idx: usize) -> Option<usize> {
+        if !self.is_char_boundary(byte_idx) {
             None
+        } else if E::FIXED_LEN {
+            Some(byte_idx / E::MAX_LEN)
+        } else {
+            Some(self[..byte_idx].chars().count())
         }
     }
 
-    /// Split this string mutably at an index, returning the two substrings on either side. This
-    /// method panics if the index doesn't lie on a character boundary.
-    pub fn split_at_mut(&mut self, idx: usize) -> Option<(&mut Str<E>, &mut Str<E>)> {
-        if self.is_char_boundary(idx) && idx < self.len() {
-            let (start, end) = self.1.split_at_mut(idx);
-            // SAFETY: Index is a character boundary. Internal data guaranteed valid.
-            let start = unsafe { Str::from_bytes_unchecked_mut(start) };
-            // SAFETY: Index is a character boundary. Internal data guaranteed valid.
-            let end = unsafe { Str::from_bytes_unchecked_mut(end) };
-            Some((start, end))
+    /// Convert the index of a character in this string, counting from zero, into the byte index
+    /// it starts at. The number of characters in the string maps to its length. Returns [`None`]
+    /// if `char_idx` is greater than the number of characters.
+    ///
+    /// This is constant time for encodings where every character has the same length, such as
+    /// single-byte encodings and UTF-32, and linear otherwise.
+    pub fn char_to_byte_index(&self, char_idx: usize) -> Option<usize> {
+        if E::FIXED_LEN {
+            char_idx
+                .checked_mul(E::MAX_LEN)
+                .filter(|idx| *idx <= self.len())
         } else {
-            None
+            self.char_boundaries().nth(char_idx)
         }
     }
 
-    /// Get this `Str` in a different [`Encoding`]. This method allocates a new [`String`] with the
-    /// desired encoding, and returns an error if the source string contains any characters that
-    /// cannot be represented in the destination encoding.
-    #[cfg(feature = "alloc")]
-    pub fn recode<E2: Encoding>(&self) -> Result<String<E2>, RecodeError> {
-        let mut ptr = self;
-        let mut total_len = 0;
-        let mut out = vec![0; self.1.len()];
-        loop {
-            match E2::recode(ptr, &mut out[total_len..]) {
-                Ok(len) => {
-                    out.truncate(total_len + len);
-                    // SAFETY: Value written into `out` by `recode` is guaranteed valid in encoding
-                    //         E2.
-                    return Ok(unsafe { String::<E2>::from_bytes_unchecked(out) });
-                }
-                Err(e) => match e.cause() {
-                    RecodeCause::NeedSpace { .. } => {
-                        out.resize(out.len() + self.1.len(), 0);
-                        ptr = &ptr[e.input_used()..];
-                        total_len += e.output_valid();
-                    }
-                    &RecodeCause::InvalidChar { char, len } => {
-                        return Err(RecodeError {
-                            valid_up_to: e.input_used(),
-                            char,
-                            char_len: len as u8,
-                        });
-                    }
-                },
-            }
+    /// Return a subslice of this `Str` by character positions instead of byte indices, as
+    /// converted by [`Str::char_to_byte_index`]. Returns [`None`] if either end of the range is
+    /// greater than the number of characters, or the start is after the end.
+    ///
+    /// ```
+    /// # use enrede::Str;
+    /// let str = Str::from_std("a→b→c");
+    /// assert_eq!(str.slice_chars(1..4), Some(Str::from_std("→b→")));
+    /// assert_eq!(str.slice_chars(5..), Some(Str::from_std("")));
+    /// assert_eq!(str.slice_chars(..6), None);
+    /// ```
+    pub fn slice_chars<R: RangeBounds<usize>>(&self, range: R) -> Option<&Str<E>> {
+        let start = match range.start_bound() {
+            Bound::Included(i) => *i,
+            Bound::Excluded(i) => i.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(i) => Some(i.checked_add(1)?),
+            Bound::Excluded(i) => Some(*i),
+            Bound::Unbounded => None,
+        };
+        if end.is_some_and(|end| start > end) {
+            return None;
         }
+
+        let (start, end) = if E::FIXED_LEN {
+            let start = self.char_to_byte_index(start)?;
+            let end = match end {
+                Some(end) => self.char_to_byte_index(end)?,
+                None => self.len(),
+            };
+            (start, end)
+        } else {
+            // Find both ends in a single pass
+            let mut boundaries = self.char_boundaries();
+            let start_byte = boundaries.nth(start)?;
+            let end = match end {
+                Some(end) if end == start => start_byte,
+                Some(end) => boundaries.nth(end - start - 1)?,
+                None => self.len(),
+            };
+            (start_byte, end)
+        };
+        // SAFETY: Both indices are character boundaries, with `start <= end <= self.len()`. Our
+        //         internal bytes are guaranteed valid for the encoding.
+        Some(unsafe { Str::from_bytes_unchecked(self.as_bytes().get_unchecked(start..end)) })
     }
 
-    /// Get this `Str` in a different [`Encoding`]. This method allocates a new [`String`] with the
-    /// desired encoding, replacing any characters that can't be represented in the destination
-    /// encoding with the encoding's replacement character.
-    #[cfg(feature = "alloc")]
-    pub fn recode_lossy<E2: Encoding>(&self) -> String<E2> {
-        let mut ptr = self;
-        let mut total_len = 0;
-        let mut out = vec![0; self.1.len()];
-        loop {
-            match E2::recode(ptr, &mut out[total_len..]) {
-                Ok(len) => {
-                    out.truncate(total_len + len);
-                    // SAFETY: Value written into `out` by `recode` is guaranteed valid in encoding
-                    //         E2.
-                    return unsafe { String::from_bytes_unchecked(out) };
-                }
-                Err(e) => match e.cause() {
-                    RecodeCause::NeedSpace { .. } => {
-                        out.resize(out.len() + self.1.len(), 0);
-                        ptr = &ptr[e.input_used()..];
-                        total_len += e.output_valid();
-                    }
-                    &RecodeCause::InvalidChar { char: _, len } => {
-                        let replace_len = E2::char_len(E2::REPLACEMENT);
-                        out.resize(out.len() + replace_len, 0);
-                        E2::encode(E2::REPLACEMENT, &mut out[total_len + e.output_valid()..])
-                            .unwrap();
-                        ptr = &ptr[e.input_used() + len..];
-                        total_len += e.output_valid() + replace_len;
-                    }
-                },
-            }
+    /// Return the character at index `n` of this string, counting from zero, or [`None`] if the
+    /// string doesn't have that many characters.
+    ///
+    /// This is constant time for encodings where every character has the same length, such as
+    /// single-byte encodings and UTF-32, and linear otherwise.
+    pub fn nth_char(&self, n: usize) -> Option<char> {
+        if E::FIXED_LEN {
+            let start = self.char_to_byte_index(n).filter(|idx| *idx < self.len())?;
+            Some(E::decode_char(&self[start..]).0)
+        } else {
+            self.chars().nth(n)
         }
     }
-}
 
-impl<E: AlwaysValid> Str<E> {
-    /// Create a `Str` from a byte slice, never failing.
-    ///
-    /// This method is provided for encodings that have no invalid byte patterns, meaning encoding
-    /// validity checking is skipped.
-    pub fn from_bytes_infallible(bytes: &[u8]) -> &Str<E> {
-        // SAFETY: All possible byte patterns are valid for this encoding.
-        unsafe { Self::from_bytes_unchecked(bytes) }
+    /// Return the character index of the first occurrence of `c` in this string, counting from
+    /// zero, or [`None`] if it doesn't occur. See [`Str::match_indices`] for byte indices instead.
+    pub fn char_index_of(&self, c: char) -> Option<usize> {
+        self.chars().position(|c2| c2 == c)
     }
 
-    /// Create a `Str` from a mutable byte slice, never failing.
+    /// Return the character index of the last occurrence of `c` in this string, counting from
+    /// zero, or [`None`] if it doesn't occur.
     ///
-    /// This method is provided for encodings that have no invalid byte patterns, meaning encoding
-    /// validity checking is skipped.
-    pub fn from_bytes_infallible_mut(bytes: &mut [u8]) -> &mut Str<E> {
-        // SAFETY: All possible byte patterns are valid for this encoding.
-        unsafe { Self::from_bytes_unchecked_mut(bytes) }
+    /// This searches from the end of the string for encodings where every character has the same
+    /// length, such as single-byte encodings and UTF-32, and from the start otherwise.
+    pub fn last_char_index_of(&self, c: char) -> Option<usize> {
+        if E::FIXED_LEN {
+            (0..self.len() / E::MAX_LEN)
+                .rev()
+                .find(|idx| E::decode_char(&self[idx * E::MAX_LEN..]).0 == c)
+        } else {
+            self.chars()
+                .enumerate()
+                .filter(|(_, c2)| *c2 == c)
+                .last()
+                .map(|(idx, _)| idx)
+        }
     }
-}
 
-impl Str<Utf8> {
-    /// Equivalent to [`Str::from_bytes_unchecked`] but for UTF-8 specifically
+    /// Return an iterator over consecutive slices of this string, each at most `max_bytes` long.
+    /// Slices never split a character, so may be shorter than `max_bytes` - this is useful for
+    /// writing text into fixed-size fields.
     ///
-    /// # Safety
+    /// If a single character is longer than `max_bytes`, it is yielded alone in a slice longer
+    /// than `max_bytes`. Check the length of each slice if this must never occur.
     ///
-    /// The bytes passed must be valid UTF-8.
-    pub unsafe fn from_utf8_unchecked(str: &[u8]) -> &Self {
-        // SAFETY: Precondition that input is valid UTF-8
-        Self::from_bytes_unchecked(str)
+    /// # Panics
+    ///
+    /// If `max_bytes` is zero
+    pub fn char_slices(&self, max_bytes: usize) -> CharSlices<'_, E> {
+        CharSlices::new(self, max_bytes)
     }
 
-    /// Equivalent to [`Str::from_bytes`] but for UTF-8 specifically
-    pub fn from_utf8(str: &[u8]) -> Result<&Self, ValidateError> {
-        Self::from_bytes(str)
+    /// Return an iterator over this string slice as UTF-16 code units. See [`str::encode_utf16`]
+    /// for more details.
+    pub fn encode_utf16(&self) -> EncodeUtf16<'_, E> {
+        EncodeUtf16::new(self)
     }
 
-    /// Convert a [`str`] directly into a [`Str<Utf8>`].
-    pub fn from_std(value: &str) -> &Str<Utf8> {
-        // SAFETY: `&str` is UTF-8 by its validity guarantees.
-        unsafe { Self::from_bytes_unchecked(value.as_bytes()) }
+    /// Return an iterator over this string slice with each character escaped as by
+    /// [`char::escape_debug`]. See [`str::escape_debug`] for more details.
+    ///
+    /// The iterator also implements [`Display`](fmt::Display), allowing it to be written out
+    /// without allocating.
+    pub fn escape_debug(&self) -> EscapeDebug<'_, E> {
+        EscapeDebug::new(self)
     }
 
-    /// Convert a [`Str<Utf8>`] directly into a [`str`]
-    pub fn as_std(&self) -> &str {
-        // SAFETY: `&Str` is UTF-8 by our validity guarantees.
-        unsafe { core::str::from_utf8_unchecked(&self.1) }
+    /// Return an iterator over this string slice with each character escaped as by
+    /// [`char::escape_default`]. See [`str::escape_default`] for more details.
+    ///
+    /// The iterator also implements [`Display`](fmt::Display), allowing it to be written out
+    /// without allocating.
+    pub fn escape_default(&self) -> EscapeDefault<'_, E> {
+        EscapeDefault::new(self)
     }
-}
 
-impl Str<Utf16> {
-    /// Equivalent to [`Str::from_bytes_unchecked`] but for UTF-16 specifically
+    /// Return an iterator over this string slice with every character other than printable ASCII,
+    /// tab, carriage return and newline replaced by `\xNN` escapes of its encoded bytes. ASCII
+    /// characters are escaped as by [`char::escape_default`].
     ///
-    /// # Safety
+    /// This shows the exact encoded data for characters which may not display correctly. The
+    /// iterator also implements [`Display`](fmt::Display), allowing it to be written out without
+    /// allocating.
     ///
-    /// The bytes passed must be valid UTF-16.
-    pub unsafe fn from_utf16_unchecked(str: &[u16]) -> &Self {
-        // SAFETY: Precondition that input is valid UTF-16
-        Self::from_bytes_unchecked(cast_slice(str))
+    /// ```
+    /// # use enrede::{Str, encoding::ExtendedAscii};
+    /// let str = Str::<ExtendedAscii>::from_bytes(b"caf\xE9\t\x81").unwrap();
+    /// assert_eq!(str.escape_bytes().to_string(), "caf\\xe9\\t\\x81");
+    /// ```
+    pub fn escape_bytes(&self) -> EscapeBytes<'_, E> {
+        EscapeBytes::new(self)
     }
 
-    /// Equivalent to [`Str::from_bytes`] but for UTF-16 specifically
-    pub fn from_utf16(str: &[u16]) -> Result<&Self, ValidateError> {
-        Self::from_bytes(cast_slice(str))
+    /// Get an adapter which formats this string like its [`Debug`](fmt::Debug) implementation, but
+    /// only writes out the first `max_chars` characters. If the string is longer, the number of
+    /// remaining bytes is written instead of the rest of the string, along with the number of
+    /// remaining characters for encodings where that doesn't require decoding them.
+    ///
+    /// This is useful for logging strings which may be very large, as formatting only does work
+    /// proportional to `max_chars`.
+    ///
+    /// ```
+    /// # use enrede::Str;
+    /// let str = Str::from_std("Hello, world!");
+    /// assert_eq!(format!("{:?}", str.debug_truncated(5)), "\"Hello\"utf8… (8 more bytes)");
+    /// assert_eq!(format!("{:?}", str.debug_truncated(20)), "\"Hello, world!\"utf8");
+    /// ```
+    pub fn debug_truncated(&self, max_chars: usize) -> DebugTruncated<'_, E> {
+        DebugTruncated::new(self, max_chars)
     }
-}
 
-impl Str<Utf32> {
-    /// Equivalent to [`Str::from_bytes_unchecked`] but for UTF-32 specifically
+    /// Copy the data from another string into this one.
     ///
-    /// # Safety
+    /// # Panics
     ///
-    /// The bytes passed must be valid UTF-32.
-    pub unsafe fn from_utf32_unchecked(str: &[u32]) -> &Self {
-        // SAFETY: Precondition that input is valid UTF-32
-        Self::from_bytes_unchecked(cast_slice(str))
+    /// If the two strings have different lengths. See [`Str::try_copy_from`] for a non-panicking
+    /// variant.
+    pub fn copy_from(&mut self, other: &Str<E>) {
+        if let Err(err) = self.try_copy_from(other) {
+            panic!("{}", err);
+        }
     }
 
-    /// Equivalent to [`Str::from_bytes`] but for UTF-32 specifically
-    pub fn from_utf32(str: &[u32]) -> Result<&Self, ValidateError> {
-        Self::from_bytes(cast_slice(str))
+    /// Copy the data from another string into this one, returning a [`LengthMismatch`] error if the
+    /// two strings have different lengths.
+    pub fn try_copy_from(&mut self, other: &Str<E>) -> Result<(), LengthMismatch> {
+        if self.len() != other.len() {
+            return Err(LengthMismatch {
+                source_len: other.len(),
+                dest_len: self.len(),
+            });
+        }
+        self.1.copy_from_slice(other.as_bytes());
+        Ok(())
     }
 
-    /// Convert a [`&[char]`] directly into a [`Str<Utf32>`]
-    pub fn from_chars(str: &[char]) -> &Self {
-        // SAFETY: Utf32 encoding is exactly equivalent to `char` encoding.
-        unsafe { Self::from_bytes_unchecked(cast_slice(str)) }
+    /// Overwrite this string with repetitions of a character, such as to blank out a fixed-size
+    /// field. Returns an error if the character can't be represented in this encoding, or if the
+    /// length of this string isn't a multiple of the character's encoded length.
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::Utf16LE};
+    /// let mut bytes = *b"a\0b\0c\0";
+    /// let str = Str::<Utf16LE>::from_bytes_mut(&mut bytes).unwrap();
+    /// str.fill('*').unwrap();
+    /// assert_eq!(str, "***");
+    /// assert!(str.fill('𐐷').is_err());
+    /// ```
+    pub fn fill(&mut self, c: char) -> Result<(), FillError> {
+        let bytes = E::encode_char(c).ok_or(FillError::InvalidChar)?;
+        let bytes = bytes.slice();
+        if !self.len().is_multiple_of(bytes.len()) {
+            return Err(FillError::Length {
+                char_len: bytes.len(),
+            });
+        }
+        self.1
+            .chunks_exact_mut(bytes.len())
+            .for_each(|chunk| chunk.copy_from_slice(bytes));
+        Ok(())
     }
 
-    /// Attemp to convert a [`Str<Utf32>`] directly into a [`&[char]`]. This will fail if the `Str`
-    /// is not sufficiently aligned for a `char`.
-    pub fn try_chars(&self) -> Option<&[char]> {
-        let len = self.1.len();
-        let ptr = ptr::from_ref(&self.1);
-        if (ptr.cast::<()>() as usize) % mem::align_of::<char>() != 0 {
+    /// Split this string at an index, returning the two substrings on either side. Returns [`None`]
+    /// if the index doesn't lie on a character boundary, or isn't before the end of the string.
+    /// See [`Str::split_at_checked`] to also allow splitting at the end.
+    pub fn split_at(&self, idx: usize) -> Option<(&Str<E>, &Str<E>)> {
+        if idx < self.len() {
+            self.split_at_checked(idx)
+        } else {
             None
+        }
+    }
+
+    /// Split this string mutably at an index, returning the two substrings on either side. Returns
+    /// [`None`] if the index doesn't lie on a character boundary, or isn't before the end of the
+    /// string. See [`Str::split_at_mut_checked`] to also allow splitting at the end.
+    pub fn split_at_mut(&mut self, idx: usize) -> Option<(&mut Str<E>, &mut Str<E>)> {
+        if idx < self.len() {
+            self.split_at_mut_checked(idx)
         } else {
-            // SAFETY: We have guaranteed correct alignment, and Utf32 encoding is exactly
-            //         equivalent to `char` encoding.
-            Some(unsafe { slice::from_raw_parts(ptr.cast(), len / 4) })
+            None
+        }
+    }
+
+    /// Split this string at an index, returning the two substrings on either side. Returns [`None`]
+    /// if the index doesn't lie on a character boundary. Like [`str::split_at_checked`], splitting
+    /// at the end of the string is allowed, and returns an empty right-side substring.
+    pub fn split_at_checked(&self, idx: usize) -> Option<(&Str<E>, &Str<E>)> {
+        if self.is_char_boundary(idx) {
+            let (start, end) = self.1.split_at(idx);
+            // SAFETY: Index is a character boundary. Internal data guaranteed valid.
+            let start = unsafe { Str::from_bytes_unchecked(start) };
+            // SAFETY: Index is a character boundary. Internal data guaranteed valid.
+            let end = unsafe { Str::from_bytes_unchecked(end) };
+            Some((start, end))
+        } else {
+            None
+        }
+    }
+
+    /// Split this string mutably at an index, returning the two substrings on either side. Returns
+    /// [`None`] if the index doesn't lie on a character boundary. Like
+    /// [`str::split_at_mut_checked`], splitting at the end of the string is allowed, and returns an
+    /// empty right-side substring.
+    pub fn split_at_mut_checked(&mut self, idx: usize) -> Option<(&mut Str<E>, &mut Str<E>)> {
+        if self.is_char_boundary(idx) {
+            let (start, end) = self.1.split_at_mut(idx);
+            // SAFETY: Index is a character boundary. Internal data guaranteed valid.
+            let start = unsafe { Str::from_bytes_unchecked_mut(start) };
+            // SAFETY: Index is a character boundary. Internal data guaranteed valid.
+            let end = unsafe { Str::from_bytes_unchecked_mut(end) };
+            Some((start, end))
+        } else {
+            None
+        }
+    }
+
+    /// Check whether every character in this string is ASCII. This checks the decoded characters,
+    /// not the bytes - in [`JisX0201`](crate::encoding::JisX0201), byte `0x5C` decodes to `'¥'`,
+    /// so a string containing it isn't ASCII, while in UTF-16 `"A"` is ASCII despite being encoded
+    /// as `[0x41, 0x00]`.
+    ///
+    /// For [`AsciiCompatible`] encodings and UTF-16, this only scans the bytes without decoding.
+    pub fn is_ascii(&self) -> bool {
+        let bytes = self.as_bytes();
+        if E::ASCII_COMPATIBLE {
+            return bytes.is_ascii();
+        }
+        match E::UNICODE {
+            Some(UnicodeForm::Utf16LE) => bytes
+                .chunks_exact(2)
+                .all(|unit| unit[0].is_ascii() && unit[1] == 0),
+            Some(UnicodeForm::Utf16BE) => bytes
+                .chunks_exact(2)
+                .all(|unit| unit[0] == 0 && unit[1].is_ascii()),
+            _ => self.chars().all(|c| c.is_ascii()),
+        }
+    }
+
+    /// Reinterpret this string as [`Ascii`] without copying, if every character is ASCII and
+    /// encoded as the matching single ASCII byte. This is always the case for ASCII strings in
+    /// [`AsciiCompatible`] encodings, but never for non-empty strings in UTF-16 or UTF-32, where
+    /// ASCII characters are encoded with extra null bytes.
+    pub fn as_ascii_str(&self) -> Option<&Str<Ascii>> {
+        let bytes = self.as_bytes();
+        let is_ascii = if E::ASCII_COMPATIBLE {
+            bytes.is_ascii()
+        } else {
+            let mut chars = self.chars();
+            bytes
+                .iter()
+                .all(|b| b.is_ascii() && chars.next() == Some(char::from(*b)))
+        };
+
+        if is_ascii {
+            // SAFETY: Every byte was just checked to be ASCII
+            Some(unsafe { Str::from_bytes_unchecked(bytes) })
+        } else {
+            None
+        }
+    }
+
+    /// Parse this string into another type, similar to [`str::parse`]. The string is converted to
+    /// UTF-8 and passed to [`FromStr::from_str`].
+    ///
+    /// If the string only contains ASCII characters encoded as single ASCII bytes, the bytes are
+    /// used directly without allocating. Otherwise, the string is recoded to UTF-8, which requires
+    /// the `alloc` feature - without it, [`ParseError::Unrepresentable`] is returned.
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError<T::Err>> {
+        if let Some(ascii) = self.as_ascii_str() {
+            // SAFETY: ASCII is always valid UTF-8
+            let str = unsafe { core::str::from_utf8_unchecked(ascii.as_bytes()) };
+            return str.parse().map_err(ParseError::Parse);
+        }
+
+        #[cfg(feature = "alloc")]
+        {
+            self.recode::<Utf8>()
+                .map_err(|_| ParseError::Unrepresentable)?
+                .as_std()
+                .parse()
+                .map_err(ParseError::Parse)
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            Err(ParseError::Unrepresentable)
+        }
+    }
+
+    /// Convert a [`Box<Str<E>>`] into a [`String`] without copying or allocating.
+    #[cfg(feature = "alloc")]
+    pub fn into_string(self: Box<Self>) -> String<E> {
+        let bytes = self.into_boxed_bytes();
+        // SAFETY: Our internal bytes are guaranteed valid for the encoding
+        unsafe { String::from_bytes_unchecked(bytes.into_vec()) }
+    }
+
+    /// Convert a [`Box<Str<E>>`] into a [`Box<[u8]>`] without copying or allocating.
+    #[cfg(feature = "alloc")]
+    pub fn into_boxed_bytes(self: Box<Self>) -> Box<[u8]> {
+        let raw = Box::into_raw(self) as *mut [u8];
+        // SAFETY: `Str` is `repr(transparent)` containing a [u8], so has the same layout.
+        unsafe { Box::from_raw(raw) }
+    }
+
+    /// Copy this string into a new [`String`], like [`ToOwned::to_owned`], but returning an error
+    /// instead of aborting if the allocation fails.
+    #[cfg(feature = "alloc")]
+    pub fn try_to_owned(&self) -> Result<String<E>, TryReserveError> {
+        let mut bytes = Vec::new();
+        bytes.try_reserve_exact(self.1.len())?;
+        bytes.extend_from_slice(&self.1);
+        // SAFETY: Our internal bytes are guaranteed valid for our encoding
+        Ok(unsafe { String::from_bytes_unchecked(bytes) })
+    }
+
+    /// Write this string into a self-describing buffer tagged with its encoding, which can be read
+    /// back with [`from_tagged_bytes`](crate::tagged::from_tagged_bytes). See the
+    /// [`tagged`](crate::tagged) module for the format.
+    #[cfg(feature = "alloc")]
+    pub fn to_tagged_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(2 + E::KIND.shorthand().len() + self.1.len());
+        crate::tagged::write_header::<E>(&mut out);
+        out.extend_from_slice(&self.1);
+        out
+    }
+
+    /// Convert halfwidth characters in this string to their fullwidth forms, as used in Japanese
+    /// text. ASCII characters become fullwidth forms `U+FF01` to `U+FF5E`, with the space becoming
+    /// an ideographic space, and halfwidth katakana `U+FF61` to `U+FF9F` become the standard
+    /// katakana and punctuation.
+    ///
+    /// A halfwidth katakana followed by a halfwidth dakuten or handakuten becomes a single voiced
+    /// katakana, so the result may have fewer characters than the input. Characters whose
+    /// fullwidth form can't be represented in this encoding are left unchanged.
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::ShiftJis};
+    /// // "ｶﾞｲﾄﾞ ok", in halfwidth katakana and ASCII
+    /// let str = Str::<ShiftJis>::from_bytes(b"\xB6\xDE\xB2\xC4\xDE ok").unwrap();
+    /// // Shift-JIS has no fullwidth ASCII forms, so only the space changes
+    /// assert_eq!(str.to_fullwidth(), "ガイド\u{3000}ok");
+    /// assert_eq!(Str::from_std("ｶﾞｲﾄﾞ ok").to_fullwidth(), "ガイド\u{3000}ｏｋ");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_fullwidth(&self) -> String<E> {
+        let mut out = String::with_capacity(self.len());
+        for c in fullwidth::Widen::new(self.chars(), |c| E::encode_char(c).is_some()) {
+            out.push(c);
+        }
+        out
+    }
+
+    /// Convert fullwidth characters in this string to their halfwidth forms. This is the reverse
+    /// of [`Str::to_fullwidth`] - fullwidth ASCII forms become ASCII, and katakana and Japanese
+    /// punctuation become halfwidth katakana.
+    ///
+    /// Halfwidth katakana have no voiced forms, so a voiced katakana becomes two characters, the
+    /// halfwidth katakana followed by a halfwidth dakuten or handakuten. Characters whose
+    /// halfwidth form can't be represented in this encoding are left unchanged. Hiragana have no
+    /// halfwidth forms, so are also left unchanged.
+    ///
+    /// The fullwidth macron `'￣'` is the fullwidth form of both the macron `'¯'` and the overline
+    /// `'‾'`, but only narrows to the macron, so an overline doesn't survive a round trip through
+    /// [`Str::to_fullwidth`] in encodings that can represent both. JIS X 0201 and Shift-JIS can't
+    /// represent the fullwidth macron, so their overline is never widened.
+    ///
+    /// ```
+    /// # use enrede::Str;
+    /// assert_eq!(Str::from_std("ガイド　ｏｋ").to_halfwidth(), "ｶﾞｲﾄﾞ ok");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_halfwidth(&self) -> String<E> {
+        let mut out = String::with_capacity(self.len());
+        for c in fullwidth::Narrow::new(self.chars(), |c| E::encode_char(c).is_some()) {
+            out.push(c);
+        }
+        out
+    }
+
+    /// Create a new [`String`] by repeating this string `n` times.
+    ///
+    /// # Panics
+    ///
+    /// If the length of the resulting string would overflow.
+    #[cfg(feature = "alloc")]
+    pub fn repeat(&self, n: usize) -> String<E> {
+        // SAFETY: Concatenating valid strings produces a valid string
+        unsafe { String::from_bytes_unchecked(self.1.repeat(n)) }
+    }
+
+    /// Return an iterator over the bytes of this string recoded into a different [`Encoding`].
+    /// Characters are recoded lazily as the iterator advances, without allocating. If a character
+    /// can't be represented in the destination encoding, an error is yielded, after which the
+    /// iterator ends.
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::{Ascii, Utf16LE}};
+    /// let bytes = Str::from_std("Hi").recode_bytes::<Utf16LE>().collect::<Result<Vec<_>, _>>();
+    /// assert_eq!(bytes.unwrap(), b"H\0i\0");
+    ///
+    /// let mut iter = Str::from_std("a→b").recode_bytes::<Ascii>();
+    /// assert_eq!(iter.next(), Some(Ok(b'a')));
+    /// assert_eq!(iter.next().unwrap().unwrap_err().char(), '→');
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn recode_bytes<E2: Encoding>(&self) -> RecodeBytes<'_, E, E2> {
+        RecodeBytes::new(self, false)
+    }
+
+    /// Return an iterator over the bytes of this string recoded into a different [`Encoding`],
+    /// replacing any characters that can't be represented in the destination encoding with the
+    /// encoding's replacement character. Like [`Str::recode_bytes`], this doesn't allocate.
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::Ascii};
+    /// let bytes = Str::from_std("a→b").recode_bytes_lossy::<Ascii>().collect::<Vec<_>>();
+    /// assert_eq!(bytes, b"a\x1Ab");
+    /// ```
+    pub fn recode_bytes_lossy<E2: Encoding>(&self) -> RecodeBytesLossy<'_, E, E2> {
+        RecodeBytesLossy::new(self)
+    }
+
+    /// Get this `Str` in a different [`Encoding`]. This method allocates a new [`String`] with the
+    /// desired encoding, and returns an error if the source string contains any characters that
+    /// cannot be represented in the destination encoding.
+    #[cfg(feature = "alloc")]
+    pub fn recode<E2: Encoding>(&self) -> Result<String<E2>, RecodeError> {
+        match self.recode_partial() {
+            (out, None) => Ok(out),
+            (_, Some(err)) => Err(err),
+        }
+    }
+
+    /// Get this `Str` in a different [`Encoding`], like [`Str::recode`], but returning an error
+    /// instead of aborting if allocating the output fails.
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::Utf16LE, str::TryRecodeError};
+    /// let out = Str::from_std("Hi").try_recode::<Utf16LE>();
+    /// assert_eq!(out.unwrap().as_bytes(), b"H\0i\0");
+    ///
+    /// let err = Str::from_std("→").try_recode::<enrede::encoding::Ascii>();
+    /// assert!(matches!(err, Err(TryRecodeError::Recode(_))));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn try_recode<E2: Encoding>(&self) -> Result<String<E2>, TryRecodeError> {
+        match self.recode_partial_impl(Vec::new(), true)? {
+            (out, None) => Ok(out),
+            (_, Some(err)) => Err(TryRecodeError::Recode(err)),
+        }
+    }
+
+    /// Get this `Str` in a different [`Encoding`], stopping at the first character that can't be
+    /// represented in the destination encoding. Returns the output produced up to that point, and
+    /// the error if one occurred.
+    ///
+    /// The output is the same as recoding `&self[..err.valid_up_to()]`, without recoding the input
+    /// a second time.
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::Ascii};
+    /// let (out, err) = Str::from_std("abc→def").recode_partial::<Ascii>();
+    /// assert_eq!(out.as_bytes(), b"abc");
+    /// assert_eq!(err.unwrap().char(), '→');
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn recode_partial<E2: Encoding>(&self) -> (String<E2>, Option<RecodeError>) {
+        self.recode_partial_in(Vec::new())
+    }
+
+    /// Implementation of [`Str::recode_partial`], writing into the allocation of `out`
+    #[cfg(feature = "alloc")]
+    pub(crate) fn recode_partial_in<E2: Encoding>(
+        &self,
+        out: Vec<u8>,
+    ) -> (String<E2>, Option<RecodeError>) {
+        match self.recode_partial_impl(out, false) {
+            Ok(res) => res,
+            Err(_) => unreachable!("Infallible allocation doesn't return errors"),
+        }
+    }
+
+    /// Implementation of [`Str::recode_partial_in`] and [`Str::try_recode`]. If `fallible` is set,
+    /// allocation failure is returned as an error rather than aborting.
+    #[cfg(feature = "alloc")]
+    fn recode_partial_impl<E2: Encoding>(
+        &self,
+        mut out: Vec<u8>,
+        fallible: bool,
+    ) -> Result<(String<E2>, Option<RecodeError>), TryReserveError> {
+        fn grow(
+            out: &mut Vec<u8>,
+            additional: usize,
+            fallible: bool,
+        ) -> Result<(), TryReserveError> {
+            if fallible {
+                out.try_reserve(additional)?;
+            }
+            out.resize(out.len() + additional, 0);
+            Ok(())
+        }
+
+        let mut in_pos = 0;
+        let mut out_pos = 0;
+        out.clear();
+        let len = usize::max(out.capacity(), self.1.len());
+        grow(&mut out, len, fallible)?;
+        loop {
+            // SAFETY: `in_pos` is always at the end of a recoded character
+            let rest = unsafe { self.slice_unchecked(in_pos..self.len()) };
+            let (len, err) = match E2::recode(rest, &mut out[out_pos..]) {
+                Ok(len) => (len, None),
+                Err(e) => match *e.cause() {
+                    RecodeCause::NeedSpace { .. } => {
+                        grow(&mut out, self.1.len(), fallible)?;
+                        in_pos += e.input_used();
+                        out_pos += e.output_valid();
+                        continue;
+                    }
+                    RecodeCause::InvalidChar { char, len } => (
+                        e.output_valid(),
+                        Some(RecodeError {
+                            valid_up_to: in_pos + e.input_used(),
+                            char,
+                            char_len: len as u8,
+                        }),
+                    ),
+                },
+            };
+            out.truncate(out_pos + len);
+            // SAFETY: Value written into `out` by `recode` is guaranteed valid in encoding E2.
+            return Ok((unsafe { String::<E2>::from_bytes_unchecked(out) }, err));
+        }
+    }
+
+    /// Get this `Str` in a different [`Encoding`] which can represent every character. This method
+    /// allocates a new [`String`] with the desired encoding, and unlike [`Str::recode`] can never
+    /// fail.
+    #[cfg(feature = "alloc")]
+    pub fn recode_infallible<E2: UnicodeComplete>(&self) -> String<E2> {
+        if E::UNICODE.is_some() && E::UNICODE == E2::UNICODE {
+            // SAFETY: Both encodings use the same Unicode encoding form, so represent characters
+            //         with identical bytes.
+            return unsafe { String::from_bytes_unchecked(self.1.to_vec()) };
+        }
+        match self.recode() {
+            Ok(str) => str,
+            Err(_) => unreachable!("{} can encode every character", E2::shorthand()),
+        }
+    }
+
+    /// Get this `Str` in a Unicode [`Encoding`], starting with a byte order mark. A byte order mark
+    /// at the start of this string is replaced rather than kept, so the output only ever starts
+    /// with one.
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::Utf16LE};
+    /// let str = Str::from_std("Hi");
+    /// assert_eq!(str.recode_with_bom::<Utf16LE>().as_bytes(), b"\xFF\xFEH\0i\0");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn recode_with_bom<E2: UnicodeComplete>(&self) -> String<E2> {
+        let str = self.strip_bom();
+        let bom = E2::encode_char('\u{FEFF}')
+            .unwrap_or_else(|| unreachable!("{} can encode every character", E2::shorthand()));
+        let bom = bom.slice();
+        // Measure the output first, so the mark and the content share a single allocation
+        let len = if E::UNICODE.is_some() && E::UNICODE == E2::UNICODE {
+            str.len()
+        } else {
+            str.chars()
+                .map(|c| E2::char_len(c).unwrap_or(E2::MAX_LEN))
+                .sum()
+        };
+
+        let mut out = vec![0; bom.len() + len];
+        out[..bom.len()].copy_from_slice(bom);
+        match E2::recode(str, &mut out[bom.len()..]) {
+            Ok(written) => paranoid_assert!(written == len),
+            Err(_) => unreachable!("{} can encode every character", E2::shorthand()),
+        }
+        // SAFETY: The output is a byte order mark followed by the recoded string, which are both
+        //         valid in encoding E2.
+        unsafe { String::from_bytes_unchecked(out) }
+    }
+
+    /// Get this `Str` in a different [`Encoding`]. This method allocates a new [`String`] with the
+    /// desired encoding, replacing any characters that can't be represented in the destination
+    /// encoding with the encoding's replacement character.
+    #[cfg(feature = "alloc")]
+    pub fn recode_lossy<E2: Encoding>(&self) -> String<E2> {
+        match self.recode_with(|_| Fallback::Replace(E2::REPLACEMENT)) {
+            Ok(str) => str,
+            Err(_) => unreachable!("Encoding replacement character should always be encodable"),
+        }
+    }
+
+    /// Get this `Str` in a different [`Encoding`], calling `policy` for each character that can't
+    /// be represented in the destination encoding to decide what to do with it. See [`Fallback`]
+    /// for the available options.
+    ///
+    /// If the policy returns [`Fallback::Error`], or a replacement which also can't be represented
+    /// in the destination encoding, recoding stops and the error for the original character is
+    /// returned.
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::Ascii, str::Fallback};
+    /// let str = Str::from_std("a→b—c");
+    /// let out = str.recode_with::<Ascii>(|c| match c {
+    ///     '→' => Fallback::ReplaceStr("->"),
+    ///     _ => Fallback::Skip,
+    /// });
+    /// assert_eq!(out.unwrap().as_bytes(), b"a->bc");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn recode_with<'a, E2: Encoding>(
+        &self,
+        mut policy: impl FnMut(char) -> Fallback<'a>,
+    ) -> Result<String<E2>, RecodeError> {
+        // Write in place, only growing when full, so replacing many characters stays linear
+        fn write_char<E2: Encoding>(out: &mut Vec<u8>, pos: &mut usize, char: char) -> bool {
+            let Some(bytes) = E2::encode_char(char) else {
+                return false;
+            };
+            let bytes = bytes.slice();
+            if out.len() - *pos < bytes.len() {
+                out.resize(out.len() + usize::max(out.len(), bytes.len()), 0);
+            }
+            out[*pos..*pos + bytes.len()].copy_from_slice(bytes);
+            *pos += bytes.len();
+            true
+        }
+
+        let mut in_pos = 0;
+        let mut out_pos = 0;
+        let mut out = vec![0; self.1.len()];
+        loop {
+            // SAFETY: `in_pos` is always at the end of a recoded or replaced character
+            let rest = unsafe { self.slice_unchecked(in_pos..self.len()) };
+            let e = match E2::recode(rest, &mut out[out_pos..]) {
+                Ok(len) => {
+                    out.truncate(out_pos + len);
+                    // SAFETY: Value written into `out` by `recode` or `encode_char` is guaranteed
+                    //         valid in encoding E2.
+                    return Ok(unsafe { String::from_bytes_unchecked(out) });
+                }
+                Err(e) => e,
+            };
+            in_pos += e.input_used();
+            out_pos += e.output_valid();
+            match *e.cause() {
+                RecodeCause::NeedSpace { .. } => out.resize(out.len() + self.1.len(), 0),
+                RecodeCause::InvalidChar { char, len } => {
+                    let replaced = match policy(char) {
+                        Fallback::Replace(c) => write_char::<E2>(&mut out, &mut out_pos, c),
+                        Fallback::ReplaceStr(s) => s
+                            .chars()
+                            .all(|c| write_char::<E2>(&mut out, &mut out_pos, c)),
+                        Fallback::Skip => true,
+                        Fallback::Error => false,
+                    };
+                    if !replaced {
+                        return Err(RecodeError {
+                            valid_up_to: in_pos,
+                            char,
+                            char_len: len as u8,
+                        });
+                    }
+                    in_pos += len;
+                }
+            }
         }
     }
 }
 
-impl<E: Encoding> fmt::Debug for Str<E> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "\"")?;
-        for c in self.chars() {
-            f.write_char(c)?;
+impl<E: AsciiCompatible> Str<E> {
+    /// Create a `Str` from a byte slice containing only ASCII, which is valid in any
+    /// [`AsciiCompatible`] encoding. Unlike [`Str::from_bytes`], this is a `const fn`, so can be
+    /// used to build `static` strings - a non-ASCII byte is then a compile error.
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::{Ascii, Win1252}};
+    /// static PROMPTS: [&Str<Ascii>; 2] = [Str::from_ascii(b"> "), Str::from_ascii(b"? ")];
+    /// const NAME: &Str<Win1252> = Str::from_ascii(b"enrede");
+    /// assert_eq!(PROMPTS[0], "> ");
+    /// assert_eq!(NAME.len(), 6);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `bytes` contains any non-ASCII bytes
+    pub const fn from_ascii(bytes: &[u8]) -> &Str<E> {
+        assert!(
+            bytes.is_ascii(),
+            "Bytes passed to `Str::from_ascii` must be ASCII"
+        );
+        // SAFETY: ASCII bytes are valid in any ASCII-compatible encoding
+        unsafe { Str::cast_unchecked(bytes) }
+    }
+
+    /// Returns `true` if the standard string `prefix` is a prefix of this string slice, ignoring
+    /// ASCII case. This is [`Str::starts_with_ignore_ascii_case`], without first encoding the
+    /// prefix.
+    pub fn starts_with_ignore_ascii_case_str(&self, prefix: &str) -> bool {
+        self.strip_prefix_ignore_ascii_case_str(prefix).is_some()
+    }
+
+    /// Returns this string slice with the standard string `prefix` removed from the start,
+    /// ignoring ASCII case. This is [`Str::strip_prefix_ignore_ascii_case`], without first
+    /// encoding the prefix.
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::Win1252};
+    /// let str = Str::<Win1252>::from_bytes(b"HOST: example.com").unwrap();
+    /// assert_eq!(str.strip_prefix_ignore_ascii_case_str("host:").unwrap(), " example.com");
+    /// assert_eq!(str.strip_prefix_ignore_ascii_case_str("hostname:"), None);
+    /// ```
+    pub fn strip_prefix_ignore_ascii_case_str(&self, prefix: &str) -> Option<&Str<E>> {
+        if !prefix.is_ascii() {
+            return self.strip_chars_ignore_ascii_case(prefix.chars());
         }
-        write!(f, "\"{}", E::shorthand())
+        let (head, rest) = self.1.split_at_checked(prefix.len())?;
+        if !head.eq_ignore_ascii_case(prefix.as_bytes()) {
+            return None;
+        }
+        // SAFETY: Each matched byte is ASCII, so starting from the beginning of the string, each
+        //         is a whole character in an ASCII-compatible encoding. `rest` therefore starts on
+        //         a character boundary.
+        Some(unsafe { Str::from_bytes_unchecked(rest) })
+    }
+}
+
+impl<E: AlwaysValid> Str<E> {
+    /// Create a `Str` from a byte slice, never failing.
+    ///
+    /// This method is provided for encodings that have no invalid byte patterns, meaning encoding
+    /// validity checking is skipped.
+    pub fn from_bytes_infallible(bytes: &[u8]) -> &Str<E> {
+        // SAFETY: All possible byte patterns are valid for this encoding.
+        unsafe { Self::from_bytes_unchecked(bytes) }
+    }
+
+    /// Create a `Str` from a mutable byte slice, never failing.
+    ///
+    /// This method is provided for encodings that have no invalid byte patterns, meaning encoding
+    /// validity checking is skipped.
+    pub fn from_bytes_infallible_mut(bytes: &mut [u8]) -> &mut Str<E> {
+        // SAFETY: All possible byte patterns are valid for this encoding.
+        unsafe { Self::from_bytes_unchecked_mut(bytes) }
+    }
+}
+
+impl<E: SingleByte + AsciiCompatible> Str<E> {
+    /// Convert this string to its ASCII upper case equivalent in-place. Non-ASCII characters are
+    /// unchanged.
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::MacRoman};
+    /// let mut bytes = *b"caf\x8E";
+    /// let str = Str::<MacRoman>::from_bytes_mut(&mut bytes).unwrap();
+    /// str.make_ascii_uppercase();
+    /// assert_eq!(str, "CAFé");
+    /// ```
+    pub fn make_ascii_uppercase(&mut self) {
+        // Every byte is a whole character, and ASCII bytes are ASCII characters, so swapping one
+        // ASCII byte for another keeps the string valid
+        self.1.make_ascii_uppercase();
+    }
+
+    /// Convert this string to its ASCII lower case equivalent in-place. Non-ASCII characters are
+    /// unchanged.
+    pub fn make_ascii_lowercase(&mut self) {
+        // Every byte is a whole character, and ASCII bytes are ASCII characters, so swapping one
+        // ASCII byte for another keeps the string valid
+        self.1.make_ascii_lowercase();
+    }
+}
+
+impl Str<Utf8> {
+    /// Equivalent to [`Str::from_bytes_unchecked`] but for UTF-8 specifically
+    ///
+    /// # Safety
+    ///
+    /// The bytes passed must be valid UTF-8.
+    pub unsafe fn from_utf8_unchecked(str: &[u8]) -> &Self {
+        // SAFETY: Precondition that input is valid UTF-8
+        Self::from_bytes_unchecked(str)
+    }
+
+    /// Equivalent to [`Str::from_bytes`] but for UTF-8 specifically
+    pub fn from_utf8(str: &[u8]) -> Result<&Self, ValidateError> {
+        Self::from_bytes(str)
+    }
+
+    /// Convert a [`str`] directly into a [`Str<Utf8>`].
+    pub const fn from_std(value: &str) -> &Str<Utf8> {
+        // SAFETY: `&str` is UTF-8 by its validity guarantees.
+        unsafe { Self::cast_unchecked(value.as_bytes()) }
+    }
+
+    /// Convert a [`Str<Utf8>`] directly into a [`str`]
+    pub const fn as_std(&self) -> &str {
+        // SAFETY: `&Str` is UTF-8 by our validity guarantees.
+        unsafe { core::str::from_utf8_unchecked(&self.1) }
+    }
+}
+
+impl Str<Utf16Native> {
+    /// Equivalent to [`Str::from_bytes_unchecked`] but for native-endian UTF-16 specifically
+    ///
+    /// # Safety
+    ///
+    /// The bytes passed must be valid UTF-16.
+    pub unsafe fn from_utf16_unchecked(str: &[u16]) -> &Self {
+        // SAFETY: Precondition that input is valid UTF-16
+        Self::from_bytes_unchecked(cast_slice(str))
+    }
+
+    /// Equivalent to [`Str::from_bytes`] but for native-endian UTF-16 specifically
+    pub fn from_utf16(str: &[u16]) -> Result<&Self, ValidateError> {
+        Self::from_bytes(cast_slice(str))
+    }
+}
+
+impl Str<Utf16LE> {
+    /// Return an iterator over the code units of this string. Unlike [`Str::encode_utf16`], this
+    /// reads the code units directly without decoding characters.
+    pub fn code_units(&self) -> CodeUnits<'_, Utf16LE> {
+        CodeUnits::new(self)
+    }
+}
+
+impl Str<Utf16BE> {
+    /// Return an iterator over the code units of this string. Unlike [`Str::encode_utf16`], this
+    /// reads the code units directly without decoding characters.
+    pub fn code_units(&self) -> CodeUnits<'_, Utf16BE> {
+        CodeUnits::new(self)
+    }
+}
+
+impl Str<Utf32Native> {
+    /// Equivalent to [`Str::from_bytes_unchecked`] but for native-endian UTF-32 specifically
+    ///
+    /// # Safety
+    ///
+    /// The bytes passed must be valid UTF-32.
+    pub unsafe fn from_utf32_unchecked(str: &[u32]) -> &Self {
+        // SAFETY: Precondition that input is valid UTF-32
+        Self::from_bytes_unchecked(cast_slice(str))
+    }
+
+    /// Equivalent to [`Str::from_bytes`] but for native-endian UTF-32 specifically
+    pub fn from_utf32(str: &[u32]) -> Result<&Self, ValidateError> {
+        Self::from_bytes(cast_slice(str))
+    }
+
+    /// Convert a [`&[char]`] directly into a [`Str<Utf32Native>`]
+    pub fn from_chars(str: &[char]) -> &Self {
+        // SAFETY: Native-endian UTF-32 is exactly equivalent to `char` encoding.
+        unsafe { Self::from_bytes_unchecked(cast_slice(str)) }
+    }
+
+    /// Attemp to convert a [`Str<Utf32Native>`] directly into a [`&[char]`]. This will fail if the
+    /// `Str` is not sufficiently aligned for a `char`. An empty `Str` always succeeds.
+    pub fn try_chars(&self) -> Option<&[char]> {
+        if self.1.is_empty() {
+            return Some(&[]);
+        }
+        let ptr = self.1.as_ptr().cast::<char>();
+        // `align_offset` rather than checking the address, so Miri's symbolic alignment checking
+        // can see the pointer is aligned
+        if ptr.align_offset(mem::align_of::<char>()) != 0 {
+            None
+        } else {
+            // SAFETY: We have guaranteed correct alignment, and native-endian UTF-32 is exactly
+            //         equivalent to `char` encoding.
+            Some(unsafe { slice::from_raw_parts(ptr, self.1.len() / 4) })
+        }
+    }
+}
+
+impl<E: Encoding> fmt::Debug for Str<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"")?;
+        for c in self.chars() {
+            f.write_char(c)?;
+        }
+        write!(f, "\"{}", E::shorthand())
+    }
+}
+
+impl<E: Encoding> fmt::Display for Str<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.chars() {
+            f.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "width")]
+fn char_width(c: char) -> usize {
+    // ASCII is common in every encoding, and the width is simple enough to skip the table lookup
+    if c.is_ascii() {
+        usize::from(!c.is_ascii_control())
+    } else {
+        unicode_width::UnicodeWidthChar::width(c).unwrap_or(0)
+    }
+}
+
+#[cfg(any(feature = "defmt", feature = "ufmt"))]
+impl<E: Encoding> Str<E> {
+    /// Write this string out as UTF-8, in chunks no larger than a small stack buffer. This allows
+    /// formatting through UTF-8-only sinks without needing to allocate.
+    pub(crate) fn write_utf8<Err>(
+        &self,
+        mut f: impl FnMut(&str) -> Result<(), Err>,
+    ) -> Result<(), Err> {
+        let mut buf = [0u8; 32];
+        let mut len = 0;
+        for c in self.chars() {
+            if len + c.len_utf8() > buf.len() {
+                // SAFETY: Buffer is only ever filled with whole UTF-8 encoded chars
+                f(unsafe { core::str::from_utf8_unchecked(&buf[..len]) })?;
+                len = 0;
+            }
+            len += c.encode_utf8(&mut buf[len..]).len();
+        }
+        if len > 0 {
+            // SAFETY: Buffer is only ever filled with whole UTF-8 encoded chars
+            f(unsafe { core::str::from_utf8_unchecked(&buf[..len]) })?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: Encoding> Default for &Str<E> {
+    fn default() -> Self {
+        // SAFETY: Empty string slice can never be invalid
+        unsafe { Str::cast_unchecked(&[]) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: Encoding> Mul<usize> for &Str<E> {
+    type Output = String<E>;
+
+    /// Repeat this string `rhs` times. See [`Str::repeat`].
+    fn mul(self, rhs: usize) -> Self::Output {
+        self.repeat(rhs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: Encoding> ToOwned for Str<E> {
+    type Owned = String<E>;
+
+    fn to_owned(&self) -> Self::Owned {
+        let bytes = self.as_bytes().to_vec();
+        // SAFETY: Our internal bytes are guaranteed valid for our encoding
+        unsafe { String::from_bytes_unchecked(bytes) }
+    }
+
+    fn clone_into(&self, target: &mut Self::Owned) {
+        // SAFETY: The contents are replaced with our bytes, which are guaranteed valid for our
+        //         encoding
+        let bytes = unsafe { target.as_mut_vec() };
+        bytes.clear();
+        bytes.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl<E, R> Index<R> for Str<E>
+where
+    E: Encoding,
+    R: RangeBounds<usize> + SliceIndex<[u8], Output = [u8]>,
+{
+    type Output = Str<E>;
+
+    fn index(&self, index: R) -> &Self::Output {
+        match self.get_checked(index) {
+            Ok(str) => str,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+impl<E: Encoding> PartialEq for Str<E> {
+    fn eq(&self, other: &Str<E>) -> bool {
+        self.1 == other.1
+    }
+}
+
+impl<E: Encoding> Eq for Str<E> {}
+
+impl<E: Encoding> Str<E> {
+    fn eq_std(&self, other: &str) -> bool {
+        // The bytes of a `str` only mean the same characters in UTF-8, or for ASCII text in an
+        // ASCII-compatible encoding
+        if E::UNICODE == Some(UnicodeForm::Utf8) || (E::ASCII_COMPATIBLE && other.is_ascii()) {
+            self.as_bytes() == other.as_bytes()
+        } else {
+            self.chars().eq(other.chars())
+        }
+    }
+}
+
+/// Compares characters, so a `Str` in any encoding is equal to a `str` holding the same sequence of
+/// characters. This is a plain comparison of code points - no Unicode normalization is performed.
+///
+/// ```
+/// # use enrede::Str;
+/// # use enrede::encoding::{JisX0208, Win1252};
+/// let str = Str::<Win1252>::from_bytes(b"caf\xE9").unwrap();
+/// assert_eq!(str, "café");
+/// assert_ne!(str, "cafe\u{301}");
+/// let str = Str::<JisX0208>::from_bytes(b"%O%m!<").unwrap();
+/// assert_eq!(str, "ハロー");
+/// ```
+impl<E: Encoding> PartialEq<str> for Str<E> {
+    fn eq(&self, other: &str) -> bool {
+        self.eq_std(other)
+    }
+}
+
+impl<E: Encoding> PartialEq<&str> for Str<E> {
+    fn eq(&self, other: &&str) -> bool {
+        self.eq_std(other)
+    }
+}
+
+impl<E: Encoding> PartialEq<Str<E>> for str {
+    fn eq(&self, other: &Str<E>) -> bool {
+        other.eq_std(self)
+    }
+}
+
+impl<E: Encoding> PartialEq<Str<E>> for &str {
+    fn eq(&self, other: &Str<E>) -> bool {
+        other.eq_std(self)
+    }
+}
+
+impl<E: Encoding> Hash for Str<E> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if E::UNICODE == Some(UnicodeForm::Utf8) {
+            // Matches `str`, terminating with a byte that never appears in UTF-8 rather than a
+            // length prefix
+            state.write(self.as_bytes());
+            state.write_u8(0xFF);
+        } else {
+            // `0xFF` is a valid byte in most other encodings, so can't mark the end
+            self.as_bytes().hash(state);
+        }
+    }
+}
+
+impl<E: Encoding> AsRef<[u8]> for Str<E> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<E: Encoding> AsRef<Str<E>> for Str<E> {
+    fn as_ref(&self) -> &Str<E> {
+        self
+    }
+}
+
+impl<E: Encoding> AsMut<Str<E>> for Str<E> {
+    fn as_mut(&mut self) -> &mut Str<E> {
+        self
+    }
+}
+
+impl<'a, E: Encoding> TryFrom<&'a [u8]> for &'a Str<E> {
+    type Error = ValidateError;
+
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        Str::from_bytes(value)
+    }
+}
+
+impl<'a, E: Encoding> TryFrom<&'a mut [u8]> for &'a mut Str<E> {
+    type Error = ValidateError;
+
+    fn try_from(value: &'a mut [u8]) -> Result<Self, Self::Error> {
+        Str::from_bytes_mut(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<E: Encoding> Serialize for Str<E> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        <[u8]>::serialize(self.as_bytes(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct StrVisitor<'de, E>(PhantomData<&'de Str<E>>);
+
+#[cfg(feature = "serde")]
+impl<'de, E: Encoding> de::Visitor<'de> for StrVisitor<'de, E> {
+    type Value = &'de Str<E>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "borrowed bytes or string valid for the {} encoding",
+            E::shorthand()
+        )
+    }
+
+    fn visit_borrowed_bytes<Err: de::Error>(self, v: &'de [u8]) -> Result<Self::Value, Err> {
+        Str::from_bytes(v).map_err(|_| Err::invalid_value(Unexpected::Bytes(v), &self))
+    }
+
+    fn visit_borrowed_str<Err: de::Error>(self, v: &'de str) -> Result<Self::Value, Err> {
+        // The bytes of a `str` only mean the same characters in UTF-8, or for ASCII text in an
+        // ASCII-compatible encoding
+        if E::UNICODE == Some(UnicodeForm::Utf8) || (E::ASCII_COMPATIBLE && v.is_ascii()) {
+            // SAFETY: The bytes are valid UTF-8, or ASCII in an encoding which encodes ASCII as
+            //         single bytes.
+            Ok(unsafe { Str::from_bytes_unchecked(v.as_bytes()) })
+        } else {
+            Err(Err::custom(format_args!(
+                "invalid value: string {:?}, expected a string with the same bytes in the {} \
+                 encoding - deserialize a `String<E>` to convert it instead",
+                v,
+                E::shorthand(),
+            )))
+        }
+    }
+
+    fn visit_bytes<Err: de::Error>(self, _: &[u8]) -> Result<Self::Value, Err> {
+        Err(Err::custom(format_args!(
+            "can't borrow a {} string from transient bytes - deserialize a `String<E>` instead",
+            E::shorthand(),
+        )))
+    }
+
+    fn visit_str<Err: de::Error>(self, _: &str) -> Result<Self::Value, Err> {
+        Err(Err::custom(format_args!(
+            "can't borrow a {} string from a transient string, such as one containing escapes - \
+             deserialize a `String<E>` instead",
+            E::shorthand(),
+        )))
+    }
+}
+
+/// Deserializes from borrowed bytes. Human-readable formats, which usually represent text as
+/// strings, may also provide a borrowed string. This is accepted for UTF-8, or when it only
+/// contains ASCII and `E` is [`AsciiCompatible`](crate::encoding::AsciiCompatible). Data which
+/// can't be borrowed from the input, such as escaped strings, requires deserializing a
+/// [`String<E>`](crate::String) instead.
+#[cfg(feature = "serde")]
+impl<'de, E: Encoding> Deserialize<'de> for &'de Str<E> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let visitor = StrVisitor(PhantomData);
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(visitor)
+        } else {
+            deserializer.deserialize_bytes(visitor)
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<E: Encoding> defmt::Format for Str<E> {
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        let _ = self.write_utf8(|s| {
+            defmt::write!(fmt, "{=str}", s);
+            Ok::<_, core::convert::Infallible>(())
+        });
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<E: Encoding> ufmt::uDisplay for Str<E> {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        self.write_utf8(|s| f.write_str(s))
+    }
+}
+
+// Encoding-specific implementations
+
+impl<'a> From<&'a Str<Utf8>> for &'a str {
+    fn from(value: &'a Str<Utf8>) -> Self {
+        value.as_std()
+    }
+}
+
+impl<'a> From<&'a str> for &'a Str<Utf8> {
+    fn from(value: &'a str) -> Self {
+        Str::from_std(value)
+    }
+}
+
+impl<'a> From<&'a [char]> for &'a Str<Utf32Native> {
+    fn from(value: &'a [char]) -> Self {
+        Str::from_chars(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::{Ascii, JisX0208, Win1252};
+    use alloc::vec::Vec;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_debug_truncated() {
+        use crate::encoding::{ShiftJis, Utf32LE};
+        use alloc::format;
+
+        let str = Str::<ShiftJis>::from_bytes(b"a\x82\xA0\x82\xA2b").unwrap();
+        assert_eq!(
+            format!("{:?}", str.debug_truncated(0)),
+            "\"\"shiftjis… (6 more bytes)"
+        );
+        assert_eq!(
+            format!("{:?}", str.debug_truncated(2)),
+            "\"aあ\"shiftjis… (3 more bytes)"
+        );
+        assert_eq!(
+            format!("{:?}", str.debug_truncated(3)),
+            "\"aあい\"shiftjis… (1 more bytes)"
+        );
+        assert_eq!(
+            format!("{:?}", str.debug_truncated(4)),
+            format!("{:?}", str)
+        );
+        assert_eq!(
+            format!("{:?}", str.debug_truncated(10)),
+            format!("{:?}", str)
+        );
+
+        let str = Str::from_std("𐐷𐐷");
+        assert_eq!(
+            format!("{:?}", str.debug_truncated(1)),
+            "\"𐐷\"utf8… (4 more bytes)"
+        );
+
+        // Fixed-length encodings can count the rest without decoding it
+        let str = Str::from_std("abc").recode::<Utf32LE>().unwrap();
+        assert_eq!(
+            format!("{:?}", str.debug_truncated(1)),
+            "\"a\"utf32le… (2 more chars, 8 bytes)"
+        );
+        let str = Str::<Ascii>::from_bytes(b"abc").unwrap();
+        assert_eq!(
+            format!("{:?}", str.debug_truncated(0)),
+            "\"\"ascii… (3 more chars, 3 bytes)"
+        );
+        assert_eq!(
+            format!("{:?}", Str::<Utf8>::EMPTY.debug_truncated(0)),
+            "\"\"utf8"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_display_bytes_lossy() {
+        use crate::encoding::{ShiftJis, Utf16LE};
+        use alloc::string::ToString;
+
+        let display = Str::<Utf8>::display_bytes_lossy(b"\xFFab\xE2\x82c\xE2\x82\xAC");
+        assert_eq!(display.to_string(), "\\xffab\\xe2\\x82c€");
+        assert_eq!(Str::<Utf8>::display_bytes_lossy(b"").to_string(), "");
+
+        let display = Str::<ShiftJis>::display_bytes_lossy(b"\x82\xA0\x80\x82");
+        assert_eq!(display.to_string(), "あ\\x80\\x82");
+
+        let display = Str::<Utf16LE>::display_bytes_lossy(b"a\0\x37\xDCb\0c");
+        assert_eq!(display.to_string(), "a\\x37\\xdcb\\x63");
+
+        let str = Str::<Ascii>::from_bytes(b"valid").unwrap();
+        assert_eq!(
+            Str::<Ascii>::display_bytes_lossy(str.as_bytes()).to_string(),
+            str.to_string()
+        );
+    }
+
+    #[test]
+    fn test_fill() {
+        use crate::encoding::{ExtendedAscii, MacRoman, Utf16LE};
+
+        let mut bytes = *b"hunter2";
+        let str = Str::<ExtendedAscii>::from_bytes_mut(&mut bytes).unwrap();
+        str.fill('\u{FF}').unwrap();
+        assert_eq!(bytes, [0xFF; 7]);
+
+        let mut bytes = *b"abcd";
+        let str = Str::<MacRoman>::from_bytes_mut(&mut bytes).unwrap();
+        str.fill('é').unwrap();
+        assert_eq!(str, "éééé");
+        assert_eq!(str.fill('Ж'), Err(FillError::InvalidChar));
+
+        let mut bytes = *b"abc";
+        let str = Str::<Utf8>::from_bytes_mut(&mut bytes).unwrap();
+        assert_eq!(str.fill('é'), Err(FillError::Length { char_len: 2 }));
+        assert_eq!(str, "abc");
+        str.fill('✓').unwrap();
+        assert_eq!(str, "✓");
+
+        let mut bytes = *b"a\0b\0c\0d\0";
+        let str = Str::<Utf16LE>::from_bytes_mut(&mut bytes).unwrap();
+        str.fill('𐐷').unwrap();
+        assert_eq!(str, "𐐷𐐷");
+
+        let str = Str::<Utf8>::from_bytes_mut(&mut []).unwrap();
+        str.fill('a').unwrap();
+        assert!(str.is_empty());
+    }
+
+    #[test]
+    fn test_cmp_chars() {
+        use crate::encoding::{ShiftJis, Utf32BE, Utf32LE};
+
+        // Shift-JIS encodes '¥' as 0x5C, below 'a'
+        let yen = Str::<ShiftJis>::from_bytes(b"\x5C").unwrap();
+        let a = Str::<ShiftJis>::from_bytes(b"a").unwrap();
+        assert_eq!(yen.cmp_bytes(a), Ordering::Less);
+        assert_eq!(yen.cmp_chars(a), Ordering::Greater);
+        assert_eq!(yen.cmp_chars_cross(Str::from_std("¥")), Ordering::Equal);
+
+        let a = Str::<Utf32BE>::from_bytes(b"\0\0\x01\x00").unwrap();
+        let b = Str::<Utf32BE>::from_bytes(b"\0\0\0\xFF\0\0\0\xFF").unwrap();
+        assert_eq!(a.cmp_chars(b), Ordering::Greater);
+        let a = Str::<Utf32LE>::from_bytes(b"\x00\x01\0\0").unwrap();
+        let b = Str::<Utf32LE>::from_bytes(b"\xFF\0\0\0").unwrap();
+        assert_eq!(a.cmp_bytes(b), Ordering::Less);
+        assert_eq!(a.cmp_chars(b), Ordering::Greater);
+        assert_eq!(a.cmp_chars(a), Ordering::Equal);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_fullwidth() {
+        use crate::encoding::{ShiftJis, Utf8};
+
+        // "ｶﾞﾊﾟﾜﾞ ¥1". Shift-JIS has no ヷ or fullwidth ASCII forms, so those aren't widened
+        let sjis = Str::<ShiftJis>::from_bytes(b"\xB6\xDE\xCA\xDF\xDC\xDE \x5C1").unwrap();
+        let wide = sjis.to_fullwidth();
+        assert_eq!(wide, "ガパワ゛\u{3000}¥1");
+        assert_eq!(*wide.to_halfwidth(), *sjis);
+        assert!(wide.eq_ignore_width(Str::from_std("ガパワ゛\u{3000}￥１")));
+        assert!(sjis.eq_ignore_width(Str::from_std("ガパヷ\u{3000}￥１")));
+        assert!(!sjis.eq_ignore_width(Str::from_std("ガパワ\u{3000}￥１")));
+
+        let utf8 = Str::<Utf8>::from_std("ｶﾞﾊﾟﾜﾞ ¥1");
+        let wide = utf8.to_fullwidth();
+        assert_eq!(wide, "ガパヷ\u{3000}￥１");
+        assert_eq!(*wide.to_halfwidth(), *utf8);
+        assert!(utf8.eq_ignore_width(sjis));
+        assert!(!utf8.eq_ignore_width(&sjis.to_fullwidth()));
+
+        // Marks only combine with a preceding halfwidth kana with a voiced form
+        let utf8 = Str::<Utf8>::from_std("カﾞｱﾞﾞ");
+        assert_eq!(utf8.to_fullwidth(), "カ゛ア゛゛");
+        assert!(utf8.eq_ignore_width(Str::from_std("カ゛ア゛゛")));
+        // The overline and macron share a fullwidth form, which only narrows to the macron.
+        // Shift-JIS can't represent it, so its overline is left alone.
+        let sjis = Str::<ShiftJis>::from_bytes(b"a\x7E").unwrap();
+        assert_eq!(*sjis.to_fullwidth(), *sjis);
+        let utf8 = Str::<Utf8>::from_std("‾¯");
+        assert_eq!(utf8.to_fullwidth(), "\u{FFE3}\u{FFE3}");
+        assert_eq!(utf8.to_fullwidth().to_halfwidth(), "¯¯");
+        assert!(sjis.eq_ignore_width(Str::from_std("a¯")));
+
+        // Hiragana and kanji have no halfwidth forms
+        assert_eq!(Str::<Utf8>::from_std("がカ漢").to_halfwidth(), "がｶ漢");
+    }
+
+    #[test]
+    fn test_strip_prefix_ignore_ascii_case() {
+        use crate::encoding::Big5;
+
+        let str = Str::<Win1252>::from_bytes(b"CoNtEnT-\xC9tat: ok").unwrap();
+        let prefix = Str::from_bytes(b"content-\xC9").unwrap();
+        assert!(str.starts_with_ignore_ascii_case(prefix));
+        assert_eq!(
+            str.strip_prefix_ignore_ascii_case(prefix).unwrap(),
+            "tat: ok"
+        );
+        assert_eq!(
+            str.strip_prefix_ignore_ascii_case_str("content-É").unwrap(),
+            "tat: ok"
+        );
+        assert_eq!(
+            str.strip_prefix_ignore_ascii_case_str("CONTENT-").unwrap(),
+            "État: ok"
+        );
+        // Only ASCII letters are folded
+        let prefix = Str::from_bytes(b"content-\xE9").unwrap();
+        assert!(!str.starts_with_ignore_ascii_case(prefix));
+        assert!(!str.starts_with_ignore_ascii_case_str("content-é"));
+
+        // Prefixes longer than the string never match
+        let long = Str::from_bytes(b"content-\xC9tat: ok!").unwrap();
+        assert_eq!(str.strip_prefix_ignore_ascii_case(long), None);
+        assert_eq!(
+            str.strip_prefix_ignore_ascii_case_str("content-état: ok!"),
+            None
+        );
+        assert_eq!(
+            str.strip_prefix_ignore_ascii_case_str("CONTENT-ÉTAT: OK!"),
+            None
+        );
+        assert!(str.starts_with_ignore_ascii_case(<&Str<Win1252>>::default()));
+        assert!(str.starts_with_ignore_ascii_case_str(""));
+
+        let str = Str::<Utf16LE>::from_bytes(b"A\0b\0\x37\xD8\x37\xDCc\0").unwrap();
+        let prefix = Str::from_bytes(b"a\0B\0\x37\xD8\x37\xDC").unwrap();
+        assert_eq!(str.strip_prefix_ignore_ascii_case(prefix).unwrap(), "c");
+
+        // Bytes below 0x80 after a lead byte aren't characters of their own
+        let str = Str::<Big5>::from_bytes(b"a\xA4\x40b").unwrap();
+        assert_eq!(str.strip_prefix_ignore_ascii_case_str("A").unwrap(), "一b");
+        assert_eq!(str.strip_prefix_ignore_ascii_case_str("a@"), None);
+    }
+
+    #[test]
+    fn test_make_ascii_case() {
+        use crate::encoding::{ExtendedAscii, MacRoman};
+
+        let mut bytes = *b"Hello, W\xF6rld!";
+        let str = Str::<ExtendedAscii>::from_bytes_mut(&mut bytes).unwrap();
+        str.make_ascii_uppercase();
+        assert_eq!(str, "HELLO, WöRLD!");
+        str.make_ascii_lowercase();
+        assert_eq!(str, "hello, wörld!");
+
+        let mut bytes = *b"\x80ber Caf\x8E";
+        let str = Str::<MacRoman>::from_bytes_mut(&mut bytes).unwrap();
+        str.make_ascii_uppercase();
+        assert_eq!(str, "ÄBER CAFé");
+        str.make_ascii_lowercase();
+        assert_eq!(str, "Äber café");
+    }
+
+    #[test]
+    fn test_try_from_bytes() {
+        let str: &Str<Utf8> = b"abc".as_slice().try_into().unwrap();
+        assert_eq!(str, "abc");
+        let err = <&Str<Ascii>>::try_from(b"a\xFF".as_slice()).unwrap_err();
+        assert_eq!(err, ValidateError::new(1, Some(1)));
+
+        let mut bytes = *b"abc";
+        let str: &mut Str<Win1252> = bytes.as_mut_slice().try_into().unwrap();
+        assert_eq!(*str, "abc");
+        let mut bytes = *b"\xE3\x81";
+        let err = <&mut Str<Utf8>>::try_from(bytes.as_mut_slice()).unwrap_err();
+        assert_eq!(err, ValidateError::new(0, None));
+    }
+
+    #[test]
+    fn test_chars() {
+        let str = Str::from_std("Abc𐐷d");
+        assert_eq!(&str.chars().collect::<Vec<_>>(), &['A', 'b', 'c', '𐐷', 'd'],);
+
+        let str = Str::<Utf16Native>::from_utf16(&[
+            b'A' as u16,
+            b'b' as u16,
+            b'c' as u16,
+            0xD801,
+            0xDC37,
+            b'd' as u16,
+        ])
+        .unwrap();
+        assert_eq!(&str.chars().collect::<Vec<_>>(), &['A', 'b', 'c', '𐐷', 'd'],);
+
+        let str = Str::from_chars(&['A', 'b', 'c', '𐐷', 'd']);
+        assert_eq!(&str.chars().collect::<Vec<_>>(), &['A', 'b', 'c', '𐐷', 'd'],);
+    }
+
+    #[test]
+    fn test_copy_from() {
+        let mut bytes = *b"Hello";
+        let str = Str::<Utf8>::from_bytes_mut(&mut bytes).unwrap();
+        str.copy_from(Str::from_std("World"));
+        assert_eq!(str, Str::from_std("World"));
+
+        let err = str.try_copy_from(Str::from_std("Hi")).unwrap_err();
+        assert_eq!((err.source_len(), err.dest_len()), (2, 5));
+        assert_eq!(str, Str::from_std("World"));
+    }
+
+    #[test]
+    #[should_panic = "source string length (2) doesn't match destination string length (5)"]
+    fn test_copy_from_mismatch() {
+        let mut bytes = *b"Hello";
+        let str = Str::<Utf8>::from_bytes_mut(&mut bytes).unwrap();
+        str.copy_from(Str::from_std("Hi"));
+    }
+
+    #[test]
+    fn test_char_indices() {
+        let str = Str::from_std("Abc𐐷d");
+        assert_eq!(
+            &str.char_indices().collect::<Vec<_>>(),
+            &[(0, 'A'), (1, 'b'), (2, 'c'), (3, '𐐷'), (7, 'd')],
+        );
+
+        let str = Str::<Utf16Native>::from_utf16(&[
+            b'A' as u16,
+            b'b' as u16,
+            b'c' as u16,
+            0xD801,
+            0xDC37,
+            b'd' as u16,
+        ])
+        .unwrap();
+        assert_eq!(
+            &str.char_indices().collect::<Vec<_>>(),
+            &[(0, 'A'), (2, 'b'), (4, 'c'), (6, '𐐷'), (10, 'd')],
+        );
+
+        let str = Str::from_chars(&['A', 'b', 'c', '𐐷', 'd']);
+        assert_eq!(
+            &str.char_indices().collect::<Vec<_>>(),
+            &[(0, 'A'), (4, 'b'), (8, 'c'), (12, '𐐷'), (16, 'd')],
+        );
+    }
+
+    #[test]
+    fn test_chars_offset() {
+        let str = Str::from_std("a𐐷é");
+        let mut chars = str.chars();
+        assert_eq!(chars.offset(), 0);
+        chars.next();
+        assert_eq!(chars.offset(), 1);
+
+        // Cloning allows looking ahead without consuming
+        let mut lookahead = chars.clone();
+        assert_eq!(lookahead.next(), Some('𐐷'));
+        assert_eq!(lookahead.offset(), 5);
+        assert_eq!(chars.offset(), 1);
+        assert!(chars.eq(['𐐷', 'é']));
+
+        let mut spans = str.char_spans();
+        spans.next();
+        assert_eq!(spans.clone().last(), Some((5..7, 'é')));
+        assert_eq!(spans.offset(), 1);
+        assert_eq!(spans.next(), Some((1..5, '𐐷')));
+
+        let mut indices = str.char_indices();
+        indices.by_ref().for_each(drop);
+        assert_eq!(indices.offset(), str.len());
+    }
+
+    #[test]
+    fn test_try_chars() {
+        let chars = ['A', '𐐷', 'd'];
+        let str = Str::from_chars(&chars);
+        assert_eq!(str.try_chars(), Some(&chars[..]));
+
+        // Zeros are a valid null character in either byte order, so any four bytes can be used
+        let units = [0u32; 2];
+        let bytes: &[u8] = bytemuck::must_cast_slice(&units);
+        let str = Str::<Utf32Native>::from_bytes(&bytes[..4]).unwrap();
+        assert_eq!(str.try_chars(), Some(&['\0'][..]));
+        let str = Str::<Utf32Native>::from_bytes(&bytes[1..5]).unwrap();
+        assert_eq!(str.try_chars(), None);
+
+        let str = Str::<Utf32Native>::from_bytes(&bytes[1..1]).unwrap();
+        assert_eq!(str.try_chars(), Some(&[][..]));
+        assert_eq!(<&Str<Utf32Native>>::default().try_chars(), Some(&[][..]));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_recode_small_to_large() {
+        let a = Str::from_std("Hello World!");
+        let b = a.recode::<Utf32Native>().unwrap();
+
+        assert_eq!(
+            &*b,
+            Str::from_chars(&['H', 'e', 'l', 'l', 'o', ' ', 'W', 'o', 'r', 'l', 'd', '!']),
+        );
+
+        let a = Str::from_std("A𐐷b");
+        let b = a.recode::<Utf16Native>().unwrap();
+
+        assert_eq!(
+            &*b,
+            Str::from_utf16(&[b'A' as u16, 0xD801, 0xDC37, b'b' as u16]).unwrap()
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_recode_invalid_chars() {
+        let a = Str::from_std("A𐐷b");
+        let b = a.recode::<Ascii>();
+
+        assert_eq!(
+            b,
+            Err(RecodeError {
+                valid_up_to: 1,
+                char: '𐐷',
+                char_len: 4,
+            })
+        );
+
+        let a = Str::from_std("€𐐷b");
+        let b = a.recode::<Win1252>();
+
+        assert_eq!(
+            b,
+            Err(RecodeError {
+                valid_up_to: 3,
+                char: '𐐷',
+                char_len: 4,
+            })
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_recode_partial() {
+        use crate::encoding::{Big5, MacRoman};
+
+        let a = Str::from_std("Hello, €𐐷 world");
+        let (out, err) = a.recode_partial::<Win1252>();
+        let err = err.unwrap();
+        assert_eq!(err.valid_up_to(), 10);
+        assert_eq!(err.char(), '𐐷');
+        assert_eq!(out, a[..err.valid_up_to()].recode::<Win1252>().unwrap());
+        assert_eq!(out.as_bytes(), b"Hello, \x80");
+
+        let (out, err) = a[..10].recode_partial::<Win1252>();
+        assert_eq!(err, None);
+        assert_eq!(out.as_bytes(), b"Hello, \x80");
+
+        // Output grows past the input length before the error, so the input position has to be
+        // carried across multiple passes
+        let a = Str::<MacRoman>::from_bytes(b"\xB0\xB0\xB0\xB0\xB0\x8E").unwrap();
+        let (out, err) = a.recode_partial::<Big5>();
+        let err = err.unwrap();
+        assert_eq!(err.valid_up_to(), 5);
+        assert_eq!(err.char(), 'é');
+        assert_eq!(out, a[..5].recode::<Big5>().unwrap());
+        assert_eq!(out.chars().collect::<Vec<_>>(), ['∞'; 5]);
+        assert_eq!(a.recode::<Big5>(), Err(err));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_try_recode() {
+        use crate::encoding::{Big5, MacRoman};
+
+        let a = Str::from_std("A𐐷b");
+        assert_eq!(a.try_recode::<Utf16Native>(), Ok(a.recode().unwrap()));
+        assert_eq!(
+            a.try_recode::<Ascii>(),
+            Err(TryRecodeError::Recode(a.recode::<Ascii>().unwrap_err()))
+        );
+
+        // Output grows past the input length, going through the fallible reallocation
+        let a = Str::<MacRoman>::from_bytes(b"\xB0\xB0\xB0\xB0\xB0").unwrap();
+        assert_eq!(a.try_recode::<Big5>(), Ok(a.recode().unwrap()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_try_to_owned() {
+        let a = Str::from_std("Hello");
+        assert_eq!(a.try_to_owned(), Ok(a.to_owned()));
+        assert_eq!(<&Str<Utf8>>::default().try_to_owned(), Ok(String::new()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_recode_lossy_invalid_chars() {
+        let a = Str::from_std("A𐐷b");
+        let b = a.recode_lossy::<Ascii>();
+
+        assert_eq!(&*b, Str::from_bytes(b"A\x1Ab").unwrap());
+
+        let a = Str::from_std("€𐐷b");
+        let b = a.recode_lossy::<Win1252>();
+
+        assert_eq!(&*b, Str::from_bytes(b"\x80\x1Ab").unwrap());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_recode_multibyte_run() {
+        use crate::encoding::{ShiftJis, Utf8};
+
+        // Every byte of a run of '亜' could start a character, so finding a boundary scans back to
+        // the start of the run. Recoding this was quadratic when it did so for each character.
+        let len = 1 << 17;
+        let a = core::iter::repeat_n('亜', len).collect::<String<ShiftJis>>();
+
+        let b = a.recode::<Utf8>().unwrap();
+        assert_eq!(b.len(), len * 3);
+        assert!(b.chars().all(|c| c == '亜'));
+
+        let b = a.recode_lossy::<Ascii>();
+        assert_eq!(b.len(), len);
+        assert!(b.as_bytes().iter().all(|&b| b == 0x1A));
+
+        let a = core::iter::repeat_n('日', len).collect::<String<JisX0208>>();
+        let b = a.recode::<Utf8>().unwrap();
+        assert_eq!(b.len(), len * 3);
+        assert!(b.chars().all(|c| c == '日'));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_recode_with() {
+        fn transliterate(c: char) -> Fallback<'static> {
+            match c {
+                '—' | '–' => Fallback::Replace('-'),
+                '‘' | '’' => Fallback::Replace('\''),
+                '“' | '”' => Fallback::Replace('"'),
+                'œ' => Fallback::ReplaceStr("oe"),
+                'Œ' => Fallback::ReplaceStr("OE"),
+                '…' => Fallback::ReplaceStr("..."),
+                'é' => Fallback::Replace('e'),
+                _ => Fallback::Error,
+            }
+        }
+
+        let a = Str::<Win1252>::from_bytes(b"\x93\x9Cuvre\x94 \x97 \x8C \x85 caf\xE9").unwrap();
+        let b = a.recode_with::<Ascii>(transliterate).unwrap();
+        assert_eq!(b.as_bytes(), b"\"oeuvre\" - OE ... cafe");
+
+        let a = Str::<Win1252>::from_bytes(b"a\x80b\x9Cc").unwrap();
+        let b = a.recode_with::<Ascii>(|_| Fallback::Skip).unwrap();
+        assert_eq!(b.as_bytes(), b"abc");
+
+        let b = a.recode_with::<Ascii>(transliterate);
+        assert_eq!(
+            b,
+            Err(RecodeError {
+                valid_up_to: 1,
+                char: '€',
+                char_len: 1,
+            })
+        );
+
+        let b = a.recode_with::<Ascii>(|_| Fallback::Replace('?')).unwrap();
+        assert_eq!(b.as_bytes(), b"a?b?c");
+        assert_eq!(a.recode_with::<Ascii>(|_| Fallback::Error), a.recode());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_recode_with_unencodable_fallback() {
+        let a = Str::from_std("a→b");
+
+        let b = a.recode_with::<Ascii>(|_| Fallback::Replace('←'));
+        assert_eq!(
+            b,
+            Err(RecodeError {
+                valid_up_to: 1,
+                char: '→',
+                char_len: 3,
+            })
+        );
+
+        // Fails even if only part of the replacement is unencodable
+        let b = a.recode_with::<Ascii>(|_| Fallback::ReplaceStr("-←"));
+        assert_eq!(b.map_err(|e| e.char()), Err('→'));
+
+        // Replacements may themselves grow the output past the input length
+        let b = a.recode_with::<Utf32Native>(|_| unreachable!()).unwrap();
+        assert_eq!(b.len(), 12);
+        let b = Str::from_std("→→").recode_with::<Ascii>(|_| Fallback::ReplaceStr("-->"));
+        assert_eq!(b.unwrap().as_bytes(), b"-->-->");
+    }
+
+    #[test]
+    fn test_validate_self_fuzz() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0x656E_7265_6465);
+        for _ in 0..1024 {
+            // Valid text of one to four byte characters, so mutations hit every kind of byte
+            let len = rng.gen_range(0..32);
+            let mut bytes = (0..len)
+                .map(|_| match rng.gen_range(0..4) {
+                    0 => rng.gen_range('\0'..='\x7F'),
+                    1 => rng.gen_range('\u{80}'..='\u{7FF}'),
+                    2 => rng.gen_range('\u{800}'..='\u{FFFF}'),
+                    _ => rng.gen_range('\u{10000}'..=char::MAX),
+                })
+                .collect::<alloc::string::String>()
+                .into_bytes();
+            let str = Str::<Utf8>::from_bytes_mut(&mut bytes).unwrap();
+            for _ in 0..rng.gen_range(0..4) {
+                if str.is_empty() {
+                    break;
+                }
+                let idx = rng.gen_range(0..str.len());
+                // SAFETY: Not actually safe - this intentionally corrupts the string to check that
+                //         validation catches it.
+                unsafe { str.as_bytes_mut()[idx] = rng.gen() };
+            }
+
+            // Compare against the standard library's UTF-8 decoding
+            match (str.validate_self(), core::str::from_utf8(&bytes)) {
+                (Ok(()), Ok(std)) => {
+                    let str = Str::<Utf8>::from_bytes(&bytes).unwrap();
+                    assert!(str.chars().eq(std.chars()), "{bytes:x?}");
+                }
+                (Err(err), Err(std)) => {
+                    assert_eq!(err.valid_up_to(), std.valid_up_to(), "{bytes:x?}");
+                    assert_eq!(err.error_len(), std.error_len(), "{bytes:x?}");
+                }
+                (res, std) => panic!("{bytes:x?}: {res:?} != {std:?}"),
+            }
+        }
+    }
+
+    #[cfg(any(debug_assertions, feature = "paranoid-debug"))]
+    #[test]
+    #[should_panic]
+    fn test_paranoid_from_bytes_unchecked() {
+        // SAFETY: Not actually safe - checks that invalid data is caught in paranoid mode
+        let _ = unsafe { Str::<Utf8>::from_bytes_unchecked(b"Ab\xF0\x90c") };
+    }
+
+    #[test]
+    fn test_chunks() {
+        fn collect(bytes: &[u8]) -> Vec<(&[u8], &[u8])> {
+            Str::<Utf8>::chunks(bytes)
+                .map(|c| (c.valid().as_bytes(), c.invalid()))
+                .collect()
+        }
+
+        assert_eq!(collect(b""), &[]);
+        assert_eq!(collect(b"Abc"), &[(&b"Abc"[..], &b""[..])]);
+        assert_eq!(
+            collect(b"\xFFAbc"),
+            &[(&b""[..], &b"\xFF"[..]), (&b"Abc"[..], &b""[..])]
+        );
+        assert_eq!(
+            collect(b"A\xD8B\xD9C"),
+            &[
+                (&b"A"[..], &b"\xD8"[..]),
+                (&b"B"[..], &b"\xD9"[..]),
+                (&b"C"[..], &b""[..])
+            ]
+        );
+        assert_eq!(
+            collect(b"Abc\xF0\x90\x90"),
+            &[(&b"Abc"[..], &b"\xF0\x90\x90"[..])]
+        );
+    }
+
+    #[test]
+    fn test_chunks_reconstruct() {
+        let bytes = b"\xF0A\xF0\x90\x90\xB7b\xD8\xD8c\xF0\x90";
+        let mut out = Vec::new();
+        for chunk in Str::<Utf8>::chunks(bytes) {
+            out.extend_from_slice(chunk.valid().as_bytes());
+            out.extend_from_slice(chunk.invalid());
+        }
+        assert_eq!(out, bytes);
+    }
+
+    #[test]
+    fn test_raw_parts() {
+        let str = Str::from_std("Abc𐐷d");
+        let (ptr, len) = (str.as_ptr(), str.len());
+        // SAFETY: Pointer and length come from a valid `Str<Utf8>` which outlives the new one
+        let str2 = unsafe { Str::<Utf8>::from_raw_parts(ptr, len) };
+        assert_eq!(str, str2);
+
+        let mut bytes = *b"Hello";
+        let str = Str::<Utf8>::from_bytes_mut(&mut bytes).unwrap();
+        let (ptr, len) = (str.as_mut_ptr(), str.len());
+        // SAFETY: Pointer and length come from a valid `Str<Utf8>`, which isn't used again
+        let str2 = unsafe { Str::<Utf8>::from_raw_parts_mut(ptr, len) };
+        // SAFETY: Writing an ASCII character over an ASCII character is valid for UTF-8
+        unsafe { str2.as_bytes_mut()[0] = b'J' };
+        assert_eq!(&bytes, b"Jello");
+
+        // SAFETY: A dangling pointer is valid for zero-length reads
+        let empty = unsafe { Str::<Utf8>::from_raw_parts(ptr::NonNull::dangling().as_ptr(), 0) };
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_parse() {
+        use crate::encoding::Win1252;
+
+        let str = Str::<Win1252>::from_bytes(b"1234").unwrap();
+        assert_eq!(str.parse::<u32>(), Ok(1234));
+        let str = Str::<Win1252>::from_bytes(b"-1.5").unwrap();
+        assert_eq!(str.parse::<f32>(), Ok(-1.5));
+        let str = Str::<Win1252>::from_bytes(b"12a").unwrap();
+        assert!(matches!(str.parse::<u32>(), Err(ParseError::Parse(_))));
+
+        let str = Str::<Utf16Native>::from_utf16(&[b'4' as u16, b'2' as u16]).unwrap();
+        #[cfg(feature = "alloc")]
+        assert_eq!(str.parse::<i8>(), Ok(42));
+        #[cfg(not(feature = "alloc"))]
+        assert_eq!(str.parse::<i8>(), Err(ParseError::Unrepresentable));
+
+        let str = Str::<Utf16Native>::from_utf16(&[b'1' as u16, 0x0663]).unwrap();
+        #[cfg(feature = "alloc")]
+        assert!(matches!(str.parse::<u32>(), Err(ParseError::Parse(_))));
+        #[cfg(not(feature = "alloc"))]
+        assert_eq!(str.parse::<u32>(), Err(ParseError::Unrepresentable));
+    }
+
+    #[test]
+    fn test_is_ascii() {
+        use crate::encoding::{Big5, JisX0201, JisX0208};
+
+        let str = Str::from_std("Hello!");
+        assert!(str.is_ascii());
+        assert_eq!(str.as_ascii_str().unwrap().as_bytes(), b"Hello!");
+        let str = Str::from_std("Héllo");
+        assert!(!str.is_ascii());
+        assert_eq!(str.as_ascii_str(), None);
+
+        // 0x5C and 0x7E don't decode to ASCII characters in JIS X 0201
+        let str = Str::<JisX0201>::from_bytes(b"a\\b").unwrap();
+        assert!(!str.is_ascii());
+        assert_eq!(str.as_ascii_str(), None);
+        let str = Str::<JisX0201>::from_bytes(b"a~").unwrap();
+        assert!(!str.is_ascii());
+        let str = Str::<JisX0201>::from_bytes(b"a/b").unwrap();
+        assert!(str.is_ascii());
+        assert_eq!(str.as_ascii_str().unwrap().as_bytes(), b"a/b");
+
+        // Double-byte characters made of bytes in the ASCII range
+        let str = Str::<JisX0208>::from_bytes(b"\x30\x21").unwrap();
+        assert!(!str.is_ascii());
+        assert_eq!(str.as_ascii_str(), None);
+
+        // ASCII trail byte after a lead byte
+        let str = Str::<Big5>::from_bytes(b"a\xA4\x40").unwrap();
+        assert!(!str.is_ascii());
+        assert_eq!(str.as_ascii_str(), None);
+        let str = Str::<Big5>::from_bytes(b"a@").unwrap();
+        assert_eq!(str.as_ascii_str().unwrap().as_bytes(), b"a@");
+
+        // ASCII in UTF-16 can't be reinterpreted, due to the null bytes
+        let str = Str::<Utf16LE>::from_bytes(b"A\0b\0").unwrap();
+        assert!(str.is_ascii());
+        assert_eq!(str.as_ascii_str(), None);
+        let str = Str::<Utf16BE>::from_bytes(b"\0A\0b").unwrap();
+        assert!(str.is_ascii());
+        assert!(!Str::<Utf16BE>::from_bytes(b"A\0").unwrap().is_ascii());
+        assert!(!Str::<Utf16LE>::from_bytes(b"A\x01").unwrap().is_ascii());
+        assert!(!Str::<Utf16LE>::from_bytes(b"\x80\0").unwrap().is_ascii());
+        assert_eq!(
+            Str::<Utf16LE>::from_bytes(b"").unwrap().as_ascii_str(),
+            Some(Str::from_bytes(b"").unwrap())
+        );
+        assert!(Str::<Utf32Native>::from_chars(&['a', 'b']).is_ascii());
+        assert!(!Str::<Utf32Native>::from_chars(&['a', 'é']).is_ascii());
+    }
+
+    #[test]
+    fn test_ascii_compatible() {
+        // Check the fast path flag matches how each encoding handles bytes in the ASCII range
+        fn check<E: Encoding>() {
+            let decodes_ascii = (0..0x80u8).all(|b| match Str::<E>::from_bytes(&[b]) {
+                Ok(str) => str.chars().eq([char::from(b)]),
+                Err(_) => false,
+            });
+            assert!(!E::ASCII_COMPATIBLE || decodes_ascii, "{}", E::shorthand());
+        }
+
+        // Built-in encodings setting the flag must also implement the marker. Method resolution
+        // prefers `Marked` when its bound holds, as it takes the receiver without dereferencing.
+        struct Probe<E>(core::marker::PhantomData<E>);
+        trait Marked {
+            fn marked(&self) -> bool {
+                true
+            }
+        }
+        impl<E: AsciiCompatible> Marked for &Probe<E> {}
+        trait Unmarked {
+            fn marked(&self) -> bool {
+                false
+            }
+        }
+        impl<E> Unmarked for Probe<E> {}
+
+        macro_rules! check {
+            ($enc:ty, $name:literal) => {
+                check::<$enc>();
+                let marked = (&&Probe::<$enc>(core::marker::PhantomData)).marked();
+                assert_eq!(<$enc>::ASCII_COMPATIBLE, marked, "{}", $name);
+            };
+        }
+        crate::for_each_encoding!(check);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_borrowed() {
+        use crate::encoding::Win1252;
+        use alloc::string::ToString;
+        use serde::de::value::{BorrowedBytesDeserializer, Error};
+
+        let json = r#"["héllo", "hello"]"#;
+        let [a, b] = serde_json::from_str::<[&Str<Utf8>; 2]>(json).unwrap();
+        assert_eq!(a, Str::from_std("héllo"));
+        assert_eq!(b, Str::from_std("hello"));
+        // Borrowed from the input document
+        assert!(json.as_bytes().as_ptr_range().contains(&a.as_ptr()));
+
+        let str = serde_json::from_str::<&Str<Ascii>>(r#""hello""#).unwrap();
+        assert_eq!(str.as_bytes(), b"hello");
+        let str = serde_json::from_str::<&Str<Win1252>>(r#""hello""#).unwrap();
+        assert_eq!(str.as_bytes(), b"hello");
+
+        let err = serde_json::from_str::<&Str<Ascii>>(r#""héllo""#).unwrap_err();
+        assert!(err.to_string().contains("deserialize a `String<E>`"));
+        let err = serde_json::from_str::<&Str<Win1252>>(r#""héllo""#).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("same bytes in the win1252 encoding"));
+        let err = serde_json::from_str::<&Str<Utf8>>(r#""a\nb""#).unwrap_err();
+        assert!(err.to_string().contains("transient string"));
+
+        let de = BorrowedBytesDeserializer::<Error>::new(b"a\x80b");
+        let str = <&Str<Win1252>>::deserialize(de).unwrap();
+        assert_eq!(str.as_bytes(), b"a\x80b");
+        let de = BorrowedBytesDeserializer::<Error>::new(b"a\x80b");
+        assert!(<&Str<Ascii>>::deserialize(de).is_err());
+    }
+
+    #[cfg(feature = "ufmt")]
+    #[test]
+    fn test_ufmt() {
+        use alloc::string::String;
+        use core::convert::Infallible;
+
+        struct Out(String);
+
+        impl ufmt::uWrite for Out {
+            type Error = Infallible;
+
+            fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+                self.0.push_str(s);
+                Ok(())
+            }
+        }
+
+        let text = "Hello, 世界! Ünïcödé 𐐷 text long enough to need several chunks of the buffer";
+        let utf16 = text.encode_utf16().collect::<Vec<_>>();
+        let str = Str::<Utf16Native>::from_utf16(&utf16).unwrap();
+
+        let mut chunks = Vec::new();
+        str.write_utf8(|s| {
+            chunks.push(s.len());
+            Ok::<_, Infallible>(())
+        })
+        .unwrap();
+        assert!(chunks.len() > 1);
+
+        let mut out = Out(String::new());
+        ufmt::uwrite!(out, "{}", str).unwrap();
+        assert_eq!(out.0, alloc::format!("{}", str));
+        assert_eq!(out.0, text);
+
+        let mut out = Out(String::new());
+        ufmt::uwrite!(out, "{}", Str::<Utf8>::from_std("")).unwrap();
+        assert_eq!(out.0, "");
     }
-}
 
-impl<E: Encoding> fmt::Display for Str<E> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for c in self.chars() {
-            f.write_char(c)?;
-        }
-        Ok(())
+    #[test]
+    fn test_get() {
+        let str = Str::from_std("a𐐷b");
+        assert_eq!(str.get(..0), Some(Str::from_std("")));
+        assert_eq!(str.get(..1), Some(Str::from_std("a")));
+        assert_eq!(str.get(1..5), Some(Str::from_std("𐐷")));
+        assert_eq!(str.get(..3), None);
+        assert_eq!(str.get(1..4), None);
+        assert_eq!(str.get(5..6), Some(Str::from_std("b")));
     }
-}
 
-impl<E: Encoding> Default for &Str<E> {
-    fn default() -> Self {
-        // SAFETY: Empty string slice can never be invalid
-        unsafe { Str::from_bytes_unchecked(&[]) }
+    #[test]
+    fn test_matches() {
+        let str = Str::from_std("abcabc");
+        assert!(str.contains('c'));
+        assert!(str.contains(Str::from_std("ca")));
+        assert!(!str.contains('d'));
+        assert!(!str.contains(Str::from_std("cc")));
+        assert!(str
+            .matches(Str::from_std("bc"))
+            .eq(["bc", "bc"].map(Str::from_std)));
+        assert!(str
+            .match_indices(Str::from_std("bc"))
+            .eq([(1, Str::from_std("bc")), (4, Str::from_std("bc"))]));
+        assert!(str.match_indices('a').map(|(idx, _)| idx).eq([0, 3]));
+
+        let str = Str::from_std("aaaa");
+        assert!(str
+            .match_indices(Str::from_std("aa"))
+            .map(|(idx, _)| idx)
+            .eq([0, 2]));
+
+        let str = Str::from_std("a𐐷b");
+        assert!(str
+            .match_indices(Str::from_std(""))
+            .map(|(idx, _)| idx)
+            .eq("a𐐷b".match_indices("").map(|(idx, _)| idx)));
+        assert!(Str::from_std("")
+            .match_indices(Str::from_std(""))
+            .map(|(idx, _)| idx)
+            .eq([0]));
     }
-}
 
-#[cfg(feature = "alloc")]
-impl<E: Encoding> ToOwned for Str<E> {
-    type Owned = String<E>;
+    #[test]
+    fn test_matches_unrepresentable() {
+        use crate::encoding::Ascii;
 
-    fn to_owned(&self) -> Self::Owned {
-        let bytes = self.as_bytes().to_vec();
-        // SAFETY: Our internal bytes are guaranteed valid for our encoding
-        unsafe { String::from_bytes_unchecked(bytes) }
+        let str = Str::<Ascii>::from_bytes(b"abc").unwrap();
+        assert!(!str.contains('é'));
+        assert_eq!(str.matches('é').count(), 0);
     }
-}
 
-impl<E, R> Index<R> for Str<E>
-where
-    E: Encoding,
-    R: RangeBounds<usize> + SliceIndex<[u8], Output = [u8]>,
-{
-    type Output = Str<E>;
+    #[test]
+    fn test_matches_boundary() {
+        use crate::encoding::JisX0208;
+
+        // In JIS X 0208, `亜` is `0x30 0x21` and `唖` is `0x30 0x22`, so the bytes of
+        // `!0` (`0x21 0x30`) appear in the middle of `亜唖` without being a match.
+        let str = Str::<JisX0208>::from_bytes(b"\x30\x21\x30\x22").unwrap();
+        let pat = Str::<JisX0208>::from_bytes(b"\x21\x30").unwrap();
+        assert!(!str.contains(pat));
+        assert_eq!(str.match_indices(pat).count(), 0);
+
+        let pat = Str::<JisX0208>::from_bytes(b"\x30\x22").unwrap();
+        assert!(str.match_indices(pat).map(|(idx, _)| idx).eq([2]));
+
+        // A match can overlap the bytes of a misaligned one: `＾旭` is `0x21 0x30 0x30 0x30`, so
+        // `旭` first appears at index 1 before its real position at index 2
+        let str = Str::<JisX0208>::from_bytes(b"\x21\x30\x30\x30").unwrap();
+        let pat = Str::<JisX0208>::from_bytes(b"\x30\x30").unwrap();
+        assert!(str.match_indices(pat).map(|(idx, _)| idx).eq([2]));
+    }
 
-    fn index(&self, index: R) -> &Self::Output {
-        self.get(index)
-            .expect("Attempted to slice string at non-character boundary")
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_matches_run() {
+        // `旭` is `0x30 0x30`, so a run of it has a misaligned candidate between every match, and
+        // finding each boundary scans back to the start of the run unless the search tracks them.
+        // `^` is `0x21 0x30`, so the first candidate is misaligned.
+        let len = 1 << 16;
+        let mut str = String::<JisX0208>::new();
+        str.push('^');
+        str.extend(core::iter::repeat_n('旭', len));
+
+        assert!(str
+            .match_indices('旭')
+            .map(|(idx, _)| idx)
+            .eq((0..len).map(|idx| idx * 2 + 2)));
     }
-}
 
-impl<E: Encoding> PartialEq for Str<E> {
-    fn eq(&self, other: &Str<E>) -> bool {
-        self.1 == other.1
+    #[test]
+    fn test_encode_utf16() {
+        let text = "Ab𐐷c\u{FFFF}";
+        let str = Str::from_std(text);
+        assert!(str.encode_utf16().eq(text.encode_utf16()));
+
+        let str = Str::<Utf16BE>::from_bytes(b"\0A\xD8\x01\xDC\x37").unwrap();
+        assert!(str.encode_utf16().eq("A𐐷".encode_utf16()));
+        assert!(Str::from_std("").encode_utf16().eq([0u16; 0]));
     }
-}
 
-impl<E: Encoding> Eq for Str<E> {}
+    #[test]
+    fn test_escape_std() {
+        use alloc::string::ToString;
+
+        for text in [
+            "",
+            "Hello, World!",
+            "tab\there\r\n\0\x01\x7F",
+            "'quotes' \"double\" \\",
+            "\u{301}start and middle\u{301}",
+            "é𐐷\u{200B}\u{FFFF}\u{10FFFF}",
+        ] {
+            let str = Str::from_std(text);
+            assert!(str.escape_debug().eq(text.escape_debug()), "{:?}", text);
+            assert!(str.escape_default().eq(text.escape_default()), "{:?}", text);
+            assert_eq!(
+                str.escape_debug().to_string(),
+                text.escape_debug().to_string()
+            );
+            assert_eq!(
+                str.escape_default().to_string(),
+                text.escape_default().to_string()
+            );
+        }
+    }
 
-impl<E: Encoding> Hash for Str<E> {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.1.hash(state)
+    #[test]
+    fn test_escape_encoded() {
+        use crate::encoding::JisX0208;
+        use alloc::string::ToString;
+
+        let text = "日本語\t\x01\x1Bテキスト\n";
+        let bytes = text
+            .chars()
+            .flat_map(|c| JisX0208::encode_char(c).unwrap())
+            .collect::<Vec<_>>();
+        let str = Str::<JisX0208>::from_bytes(&bytes).unwrap();
+        assert!(str.escape_debug().eq(text.escape_debug()));
+        assert!(str.escape_default().eq(text.escape_default()));
+        assert_eq!(
+            str.escape_bytes().to_string(),
+            concat!(
+                r"\x46\x7c\x4b\x5c\x38\x6c\t\x01\x1b",
+                r"\x25\x46\x25\x2d\x25\x39\x25\x48\n",
+            ),
+        );
+
+        let str = Str::<Utf16LE>::from_bytes(b"a\0\\\0\x01\xD8\x37\xDC").unwrap();
+        assert_eq!(str.escape_bytes().to_string(), "a\\\\\\x01\\xd8\\x37\\xdc");
+        let escape = str.escape_bytes();
+        assert_eq!(escape.clone().count(), escape.to_string().len());
+        assert_eq!(Str::from_std("").escape_bytes().next(), None);
     }
-}
 
-impl<E: Encoding> AsRef<[u8]> for Str<E> {
-    fn as_ref(&self) -> &[u8] {
-        self.as_bytes()
+    #[test]
+    fn test_code_units() {
+        let text = "Ab𐐷c";
+        let units = text.encode_utf16().collect::<Vec<_>>();
+
+        let le = units
+            .iter()
+            .flat_map(|u| u.to_le_bytes())
+            .collect::<Vec<_>>();
+        let le = Str::<Utf16LE>::from_bytes(&le).unwrap();
+        assert!(le.code_units().eq(units.iter().copied()));
+        assert!(le.code_units().rev().eq(units.iter().rev().copied()));
+        assert_eq!(le.code_units().len(), units.len());
+
+        let be = units
+            .iter()
+            .flat_map(|u| u.to_be_bytes())
+            .collect::<Vec<_>>();
+        let be = Str::<Utf16BE>::from_bytes(&be).unwrap();
+        assert!(be.code_units().eq(units.iter().copied()));
+        assert!(be.code_units().eq(be.encode_utf16()));
     }
-}
 
-#[cfg(feature = "serde")]
-impl<E: Encoding> Serialize for Str<E> {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        <[u8]>::serialize(self.as_bytes(), serializer)
+    #[test]
+    fn test_get_checked() {
+        let str = Str::from_std("a𐐷b");
+        assert_eq!(str.get_checked(1..5), Ok(Str::from_std("𐐷")));
+        assert_eq!(str.get_checked(..=0), Ok(Str::from_std("a")));
+
+        let err = str.get_checked(2..).unwrap_err();
+        assert_eq!((err.start(), err.end()), (2, 6));
+        assert_eq!(
+            err.kind(),
+            &SliceErrorKind::NotCharBoundary {
+                idx: 2,
+                prev: 1,
+                next: 5,
+                char: '𐐷',
+            }
+        );
+        assert_eq!(
+            str.get_checked(..7).unwrap_err().kind(),
+            &SliceErrorKind::OutOfBounds { len: 6 }
+        );
+        assert_eq!(
+            str.get_checked((Bound::Included(5), Bound::Excluded(1)))
+                .unwrap_err()
+                .kind(),
+            &SliceErrorKind::StartAfterEnd
+        );
+        assert_eq!(
+            str.get_checked(..=6).unwrap_err().kind(),
+            &SliceErrorKind::OutOfBounds { len: 6 }
+        );
     }
-}
 
-#[cfg(feature = "serde")]
-impl<'de, E: Encoding> Deserialize<'de> for &'de Str<E> {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let bytes = <&'de [u8]>::deserialize(deserializer)?;
-        Str::from_bytes(bytes).map_err(|_| {
-            #[cfg(feature = "alloc")]
-            let msg = &*alloc::format!("a valid string for the {} encoding", E::shorthand());
-            #[cfg(not(feature = "alloc"))]
-            let msg = "a valid string for this encoding";
-            de::Error::invalid_value(Unexpected::Bytes(bytes), &msg)
-        })
+    #[test]
+    fn test_eq_std() {
+        let str = Str::from_std("Ab𐐷c");
+        assert_eq!(str, "Ab𐐷c");
+        assert_eq!(*str, "Ab𐐷c");
+        assert_eq!("Ab𐐷c", str);
+        assert_eq!("Ab𐐷c", *str);
+        assert_ne!(str, "Ab𐐷");
+        assert_ne!(str, "Ab𐐷cd");
+
+        let str = Str::<Win1252>::from_bytes(b"na\xEFve \x80").unwrap();
+        assert_eq!(str, "naïve €");
+        assert_ne!(str, "nai\u{308}ve €");
+        assert_ne!(str, "na\u{EF}ve \u{80}");
+        let str = Str::<Win1252>::from_bytes(b"plain").unwrap();
+        assert_eq!(str, "plain");
+        assert_ne!(str, "plai");
+
+        let str = Str::<Ascii>::from_bytes(b"Hello").unwrap();
+        assert_eq!(str, "Hello");
+        assert_ne!(str, "Héllo");
+
+        let str = Str::<Utf16LE>::from_bytes(b"A\0\x01\xD8\x37\xDC").unwrap();
+        assert_eq!(str, "A𐐷");
+        // Same bytes, different characters
+        assert_ne!(str, "A\0\x01\u{D8}7\u{DC}");
+
+        let str = Str::<JisX0208>::from_bytes(b"%O%m!<").unwrap();
+        assert_eq!(str, "ハロー");
+        assert_ne!(str, "%O%m!<");
+        assert_eq!(Str::<JisX0208>::from_bytes(b"").unwrap(), "");
     }
-}
 
-// Encoding-specific implementations
+    #[test]
+    fn test_get_inclusive() {
+        // An inclusive end of `i` slices up to `i + 1`, which must be the boundary checked
+        let str = Str::from_std("𐐷a");
+        assert_eq!(str.get(0..=2), None);
+        assert_eq!(str.get(..=2), None);
+        assert_eq!(str.get(0..=3), Some(Str::from_std("𐐷")));
+        assert_eq!(str.get(1..=3), None);
+        assert_eq!(str.get(0..=usize::MAX), None);
+        assert_eq!(
+            str.get_checked(0..=2).unwrap_err().kind(),
+            &SliceErrorKind::NotCharBoundary {
+                idx: 3,
+                prev: 0,
+                next: 4,
+                char: '𐐷',
+            }
+        );
 
-impl<'a> From<&'a Str<Utf8>> for &'a str {
-    fn from(value: &'a Str<Utf8>) -> Self {
-        value.as_std()
+        let mut bytes = *b"\xF0\x90\x90\xB7a";
+        let str = Str::<Utf8>::from_bytes_mut(&mut bytes).unwrap();
+        assert!(str.get_mut(0..=2).is_none());
+        assert!(str.get_mut(0..=3).is_some());
+
+        let str = Str::<Utf16LE>::from_bytes(b"\x01\xD8\x37\xDCb\0").unwrap();
+        assert_eq!(str.get(0..=1), None);
+        assert_eq!(str.get(0..=2), None);
+        assert!(str.get(0..=3).unwrap().chars().eq(['𐐷']));
+        assert!(str.get(4..=5).unwrap().chars().eq(['b']));
+
+        let str = Str::<Utf16BE>::from_bytes(b"\xD8\x01\xDC\x37\0b").unwrap();
+        assert_eq!(str.get(0..=2), None);
+        assert!(str.get(0..=3).unwrap().chars().eq(['𐐷']));
     }
-}
 
-impl<'a> From<&'a str> for &'a Str<Utf8> {
-    fn from(value: &'a str) -> Self {
-        Str::from_std(value)
+    #[test]
+    fn test_get_exhausted_inclusive() {
+        // An exhausted range reports an excluded end, but slices past it, so it must not be used
+        // directly after checking its bounds
+        let mut range = 4..=4;
+        range.next();
+        assert!(range.is_empty());
+        let str = Str::from_std("𐐷a");
+        assert_eq!(str.get(range.clone()), Some(Str::from_std("")));
+        let mut range = 5..=5;
+        range.next();
+        assert_eq!(str.get(range.clone()), Some(Str::from_std("")));
+
+        let mut bytes = *b"\xF0\x90\x90\xB7a";
+        let str = Str::<Utf8>::from_bytes_mut(&mut bytes).unwrap();
+        assert_eq!(str.get_mut(range).map(|s| s.len()), Some(0));
+        // Slicing this range directly would give `1..1`, inside the first character
+        let mut range = 0..=0;
+        range.next();
+        assert_eq!(str.get_mut(range).map(|s| s.len()), Some(0));
     }
-}
 
-impl<'a> From<&'a [char]> for &'a Str<Utf32> {
-    fn from(value: &'a [char]) -> Self {
-        Str::from_chars(value)
+    #[test]
+    #[should_panic = "byte index 3 is not a char boundary in utf16le string; it is inside '𐐷', \
+                      between boundaries 2 and 6"]
+    fn test_index_inclusive() {
+        let str = Str::<Utf16LE>::from_bytes(b"A\0\x01\xD8\x37\xDCb\0").unwrap();
+        let _ = &str[..=2];
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[cfg(feature = "alloc")]
-    use crate::encoding::{Ascii, Win1252};
-    use alloc::vec::Vec;
+    #[test]
+    #[should_panic = "byte index 3 is not a char boundary in utf16le string; it is inside '𐐷', \
+                      between boundaries 2 and 6"]
+    fn test_index_utf16() {
+        let str = Str::<Utf16LE>::from_bytes(b"A\0\x01\xD8\x37\xDCb\0").unwrap();
+        let _ = &str[3..];
+    }
 
     #[test]
-    fn test_chars() {
-        let str = Str::from_std("Abc𐐷d");
-        assert_eq!(&str.chars().collect::<Vec<_>>(), &['A', 'b', 'c', '𐐷', 'd'],);
+    #[should_panic = "byte index 1 is not a char boundary in jisx0208 string; it is inside '亜', \
+                      between boundaries 0 and 2"]
+    fn test_index_jis() {
+        use crate::encoding::JisX0208;
 
-        let str = Str::<Utf16>::from_utf16(&[
-            b'A' as u16,
-            b'b' as u16,
-            b'c' as u16,
-            0xD801,
-            0xDC37,
-            b'd' as u16,
-        ])
-        .unwrap();
-        assert_eq!(&str.chars().collect::<Vec<_>>(), &['A', 'b', 'c', '𐐷', 'd'],);
+        let str = Str::<JisX0208>::from_bytes(b"\x30\x21 ").unwrap();
+        let _ = &str[..1];
+    }
 
-        let str = Str::from_chars(&['A', 'b', 'c', '𐐷', 'd']);
-        assert_eq!(&str.chars().collect::<Vec<_>>(), &['A', 'b', 'c', '𐐷', 'd'],);
+    #[test]
+    #[should_panic = "range end 4 is out of bounds of utf8 string of length 3"]
+    fn test_index_out_of_bounds() {
+        let _ = &Str::from_std("abc")[..4];
     }
 
     #[test]
-    fn test_char_indices() {
-        let str = Str::from_std("Abc𐐷d");
+    fn test_char_boundary_search() {
+        let str = Str::from_std("a𐐷b");
         assert_eq!(
-            &str.char_indices().collect::<Vec<_>>(),
-            &[(0, 'A'), (1, 'b'), (2, 'c'), (3, '𐐷'), (7, 'd')],
+            (0..8)
+                .map(|i| str.floor_char_boundary(i))
+                .collect::<Vec<_>>(),
+            [0, 1, 1, 1, 1, 5, 6, 6]
+        );
+        assert_eq!(
+            (0..8)
+                .map(|i| str.ceil_char_boundary(i))
+                .collect::<Vec<_>>(),
+            [0, 1, 5, 5, 5, 5, 6, 6]
         );
 
-        let str = Str::<Utf16>::from_utf16(&[
-            b'A' as u16,
-            b'b' as u16,
-            b'c' as u16,
-            0xD801,
-            0xDC37,
-            b'd' as u16,
-        ])
-        .unwrap();
+        let str = Str::<Utf16LE>::from_bytes(b"A\0\x01\xD8\x37\xDCb\0").unwrap();
+        assert_eq!(str.floor_char_boundary(4), 2);
+        assert_eq!(str.floor_char_boundary(5), 2);
+        assert_eq!(str.ceil_char_boundary(3), 6);
+        assert_eq!(str.truncate_to_fit(5).as_bytes(), b"A\0");
+        assert_eq!(str.truncate_to_fit(6).as_bytes(), b"A\0\x01\xD8\x37\xDC");
+    }
+
+    #[test]
+    fn test_truncate_to_fit_jis() {
+        use crate::encoding::JisX0208;
+
+        let str = Str::<JisX0208>::from_bytes(b" \x30\x21\x30\x22").unwrap();
+        assert_eq!(str.floor_char_boundary(2), 1);
+        assert_eq!(str.ceil_char_boundary(2), 3);
+        assert_eq!(str.truncate_to_fit(4).as_bytes(), b" \x30\x21");
+        assert_eq!(str.truncate_to_fit(5), str);
+        assert_eq!(str.truncate_to_fit(100), str);
+        assert!(str.truncate_to_fit(0).is_empty());
+    }
+
+    #[test]
+    fn test_split_at() {
+        let str = Str::from_std("a𐐷");
+        assert_eq!(str.split_at(0), Some((Str::from_std(""), str)));
         assert_eq!(
-            &str.char_indices().collect::<Vec<_>>(),
-            &[(0, 'A'), (2, 'b'), (4, 'c'), (6, '𐐷'), (10, 'd')],
+            str.split_at(1),
+            Some((Str::from_std("a"), Str::from_std("𐐷")))
         );
+        assert_eq!(str.split_at(5), None);
+        assert_eq!(str.split_at(2), None);
+        assert_eq!(str.split_at(6), None);
+    }
 
-        let str = Str::from_chars(&['A', 'b', 'c', '𐐷', 'd']);
+    #[test]
+    fn test_split_at_checked() {
+        let str = Str::from_std("a𐐷");
+        assert_eq!(str.split_at_checked(0), Some((Str::from_std(""), str)));
         assert_eq!(
-            &str.char_indices().collect::<Vec<_>>(),
-            &[(0, 'A'), (4, 'b'), (8, 'c'), (12, '𐐷'), (16, 'd')],
+            str.split_at_checked(1),
+            Some((Str::from_std("a"), Str::from_std("𐐷")))
         );
+        assert_eq!(str.split_at_checked(5), Some((str, Str::from_std(""))));
+        assert_eq!(str.split_at_checked(2), None);
+        assert_eq!(str.split_at_checked(6), None);
+
+        let mut bytes = *b"a\xF0\x90\x90\xB7";
+        let str = Str::<Utf8>::from_bytes_mut(&mut bytes).unwrap();
+        let (start, end) = str.split_at_mut_checked(5).unwrap();
+        assert_eq!((start.len(), end.len()), (5, 0));
+        assert!(str.split_at_mut(5).is_none());
+        assert!(str.split_at_mut_checked(3).is_none());
     }
 
-    #[cfg(feature = "alloc")]
     #[test]
-    fn test_recode_small_to_large() {
-        let a = Str::from_std("Hello World!");
-        let b = a.recode::<Utf32>().unwrap();
+    fn test_char_index() {
+        use crate::encoding::{JisX0208, Win1252};
 
-        assert_eq!(
-            &*b,
-            Str::from_chars(&['H', 'e', 'l', 'l', 'o', ' ', 'W', 'o', 'r', 'l', 'd', '!']),
+        fn check<E: Encoding>(str: &Str<E>, boundaries: &[usize]) {
+            for (char_idx, byte_idx) in boundaries.iter().copied().enumerate() {
+                assert_eq!(str.char_to_byte_index(char_idx), Some(byte_idx));
+                assert_eq!(str.byte_to_char_index(byte_idx), Some(char_idx));
+            }
+            assert_eq!(str.char_to_byte_index(boundaries.len()), None);
+            assert_eq!(str.char_to_byte_index(usize::MAX), None);
+            for byte_idx in 0..str.len() + 2 {
+                if !boundaries.contains(&byte_idx) {
+                    assert_eq!(str.byte_to_char_index(byte_idx), None);
+                }
+            }
+
+            let chars = boundaries.len() - 1;
+            for start in 0..=chars + 1 {
+                for end in 0..=chars + 1 {
+                    let expected = (start <= end && end <= chars)
+                        .then(|| &str[boundaries[start]..boundaries[end]]);
+                    assert_eq!(str.slice_chars(start..end), expected);
+                }
+                let expected = (start <= chars).then(|| &str[boundaries[start]..]);
+                assert_eq!(str.slice_chars(start..), expected);
+            }
+            assert_eq!(str.slice_chars(..), Some(str));
+            let expected = boundaries.get(1).map(|end| &str[..*end]);
+            assert_eq!(str.slice_chars(..=0), expected);
+        }
+
+        check(Str::from_std("a→b𐐷c"), &[0, 1, 4, 5, 9, 10]);
+        check(Str::from_std("a"), &[0, 1]);
+        check(Str::from_std(""), &[0]);
+        let str = Str::<Utf16LE>::from_bytes(b"a\0\x92\x21b\0\x01\xD8\x37\xDCc\0").unwrap();
+        check(str, &[0, 2, 4, 6, 10, 12]);
+        check(Str::from_chars(&['a', '→', 'b', '𐐷']), &[0, 4, 8, 12, 16]);
+        check(
+            Str::<Win1252>::from_bytes(b"a\x80b").unwrap(),
+            &[0, 1, 2, 3],
+        );
+        check(
+            Str::<JisX0208>::from_bytes(b"\x25\x4F \x25\x6D").unwrap(),
+            &[0, 2, 3, 5],
         );
+    }
 
-        let a = Str::from_std("A𐐷b");
-        let b = a.recode::<Utf16>().unwrap();
+    #[test]
+    fn test_char_search() {
+        use crate::encoding::{JisX0208, Win1252};
+
+        fn check<E: Encoding>(str: &Str<E>) {
+            let chars = str.chars().collect::<Vec<_>>();
+            for n in 0..chars.len() + 2 {
+                assert_eq!(str.nth_char(n), chars.get(n).copied());
+            }
+            assert_eq!(str.nth_char(usize::MAX), None);
+            for c in chars.iter().copied().chain(['z']) {
+                assert_eq!(str.char_index_of(c), chars.iter().position(|c2| *c2 == c));
+                assert_eq!(
+                    str.last_char_index_of(c),
+                    chars.iter().rposition(|c2| *c2 == c)
+                );
+            }
+        }
+
+        let str = Str::from_std("héllo");
+        assert_eq!(str.nth_char(1), Some('é'));
+        assert_eq!(str.char_index_of('l'), Some(2));
+        assert_eq!(str.last_char_index_of('l'), Some(3));
+
+        check(Str::from_std("a→b𐐷a→"));
+        check(Str::from_std(""));
+        check(Str::<Utf16LE>::from_bytes(b"a\0\x01\xD8\x37\xDCa\0").unwrap());
+        check(Str::<Utf32Native>::from_chars(&['a', '→', 'a', '𐐷']));
+        check(Str::<Utf32Native>::from_chars(&[]));
+        check(Str::<Win1252>::from_bytes(b"a\x80ba\x80").unwrap());
+        check(Str::<Win1252>::from_bytes(b"").unwrap());
+        check(Str::<JisX0208>::from_bytes(b"\x25\x4F \x25\x4F").unwrap());
+    }
+
+    #[test]
+    fn test_char_boundaries() {
+        let str = Str::from_std("a𐐷bé");
+        assert!(str.char_boundaries().eq([0, 1, 5, 6, 8]));
+        assert!(Str::from_std("").char_boundaries().eq([0]));
+
+        let str = Str::<Utf16LE>::from_bytes(b"A\0\x01\xD8\x37\xDC").unwrap();
+        assert!(str.char_boundaries().eq([0, 2, 6]));
+    }
 
+    #[test]
+    fn test_char_slices() {
+        use crate::encoding::JisX0208;
+
+        // A two-byte character straddles the end of the first slice
+        let str = Str::<JisX0208>::from_bytes(b" \x30\x21\x30\x22 ").unwrap();
+        let slices = str.char_slices(2).map(Str::as_bytes).collect::<Vec<_>>();
+        assert_eq!(slices, [&b" "[..], b"\x30\x21", b"\x30\x22", b" "]);
+        let slices = str.char_slices(4).map(Str::as_bytes).collect::<Vec<_>>();
+        assert_eq!(slices, [&b" \x30\x21"[..], b"\x30\x22 "]);
+        assert!(str.char_slices(100).eq([str]));
         assert_eq!(
-            &*b,
-            Str::from_utf16(&[b'A' as u16, 0xD801, 0xDC37, b'b' as u16]).unwrap()
+            Str::<JisX0208>::from_bytes(b"")
+                .unwrap()
+                .char_slices(2)
+                .count(),
+            0
         );
+
+        // Characters longer than the maximum are yielded alone
+        let str = Str::from_std("a𐐷b");
+        assert!(str.char_slices(2).eq(["a", "𐐷", "b"].map(Str::from_std)));
+        assert!(str.char_slices(1).eq(["a", "𐐷", "b"].map(Str::from_std)));
+    }
+
+    #[test]
+    #[should_panic = "Slice size must be non-zero"]
+    fn test_char_slices_zero() {
+        let _ = Str::from_std("abc").char_slices(0);
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn test_normalized() {
+        use crate::encoding::MacRoman;
+
+        let composed = Str::<MacRoman>::from_bytes(b"\x8E").unwrap();
+        let decomposed = Str::from_std("e\u{301}");
+        assert!(composed.nfd_chars().eq(['e', '\u{301}']));
+        assert!(decomposed.nfc_chars().eq(['é']));
+        assert!(composed.eq_normalized(decomposed));
+        assert!(decomposed.eq_normalized(composed));
+        assert!(Str::from_std("é").eq_normalized(decomposed));
+        assert_ne!(composed, "e\u{301}");
+
+        let utf16 = Str::<Utf16BE>::from_bytes(b"\0e\x03\x01\0x").unwrap();
+        assert!(utf16.eq_normalized(Str::from_std("éx")));
+        assert!(!utf16.eq_normalized(Str::from_std("é")));
+        assert!(!utf16.eq_normalized(Str::from_std("ex")));
+        assert!(Str::<Utf8>::EMPTY.eq_normalized(Str::<MacRoman>::EMPTY));
+    }
+
+    #[cfg(feature = "width")]
+    #[test]
+    fn test_width() {
+        use crate::encoding::{JisX0201, JisX0208};
+
+        // 'アイ' followed by a space
+        let str = Str::<JisX0208>::from_bytes(b"\x25\x22\x25\x24 ").unwrap();
+        assert_eq!(str.width(), 5);
+        assert_eq!(str.truncate_to_width(5), str);
+        assert_eq!(str.truncate_to_width(4).as_bytes(), b"\x25\x22\x25\x24");
+        assert_eq!(str.truncate_to_width(3).as_bytes(), b"\x25\x22");
+        assert!(str.truncate_to_width(1).is_empty());
+
+        // Halfwidth 'ｶﾞ' followed by a space - the halfwidth voiced mark takes no space
+        let str = Str::<JisX0201>::from_bytes(b"\xB6\xDE ").unwrap();
+        assert_eq!(str.width(), 2);
+        assert_eq!(str.truncate_to_width(1).as_bytes(), b"\xB6\xDE");
+        assert!(str.truncate_to_width(0).is_empty());
+
+        // Combining marks take no space, and stay with the character they follow
+        let str = Str::from_std("ae\u{301}\u{302}アb");
+        assert_eq!(str.width(), 5);
+        assert_eq!(str.truncate_to_width(2), Str::from_std("ae\u{301}\u{302}"));
+        assert_eq!(str.truncate_to_width(3), Str::from_std("ae\u{301}\u{302}"));
+        assert_eq!(str.truncate_to_width(0), Str::from_std(""));
+    }
+
+    #[cfg(feature = "width")]
+    #[test]
+    fn test_width_single_byte() {
+        use crate::encoding::{
+            Ascii, ExtendedAscii, Iso8859_15, Iso8859_2, JisX0201, MacRoman, Win1251, Win1252,
+            Win1252Loose,
+        };
+
+        // The ASCII fast path should always agree with `unicode-width`
+        fn check<E: Encoding>() {
+            for b in 0..=u8::MAX {
+                let bytes = [b];
+                if let Ok(str) = Str::<E>::from_bytes(&bytes) {
+                    let c = str.chars().next().unwrap();
+                    let width = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+                    assert_eq!(str.width(), width, "{:?} in {}", c, E::shorthand());
+                }
+            }
+        }
+
+        check::<Ascii>();
+        check::<ExtendedAscii>();
+        check::<Iso8859_2>();
+        check::<Iso8859_15>();
+        check::<JisX0201>();
+        check::<MacRoman>();
+        check::<Win1251>();
+        check::<Win1252>();
+        check::<Win1252Loose>();
     }
 
     #[cfg(feature = "alloc")]
     #[test]
-    fn test_recode_invalid_chars() {
-        let a = Str::from_std("A𐐷b");
-        let b = a.recode::<Ascii>();
+    fn test_repeat() {
+        use crate::encoding::JisX0208;
+
+        // Ideographic space
+        let str = Str::<JisX0208>::from_bytes(b"\x21\x21").unwrap();
+        assert_eq!(str.repeat(5).chars().count(), 5);
+        assert!(str.repeat(5).chars().all(|c| c == '\u{3000}'));
+        assert!(str.repeat(0).is_empty());
+
+        let str = Str::<Utf16LE>::from_bytes(b"A\0\x01\xD8\x37\xDC").unwrap();
+        assert_eq!((str * 3).chars().count(), 6);
+        assert!((str * 3).chars().eq("A𐐷A𐐷A𐐷".chars()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic = "capacity overflow"]
+    fn test_repeat_overflow() {
+        let _ = Str::from_std("ab").repeat(usize::MAX / 2 + 1);
+    }
+
+    #[test]
+    fn test_bom() {
+        let str = Str::<Utf16BE>::from_bytes(b"\xFE\xFF\0a\xFE\xFF\0b").unwrap();
+        assert!(str.has_bom());
+        assert!(str.strip_bom().chars().eq(['a', '\u{FEFF}', 'b']));
+        // Only a single leading mark is removed
+        assert!(!str.strip_bom().has_bom());
+        assert_eq!(str.strip_bom().strip_bom(), str.strip_bom());
+
+        let str = Str::from_std("\u{FEFF}\u{FEFF}a");
+        assert!(str.strip_bom().has_bom());
+        assert_eq!(str.strip_bom(), Str::from_std("\u{FEFF}a"));
+
+        let str = Str::from_std("a\u{FEFF}");
+        assert!(!str.has_bom());
+        assert_eq!(str.strip_bom(), str);
+
+        let str = Str::<Ascii>::from_bytes(b"abc").unwrap();
+        assert!(!str.has_bom());
+        assert_eq!(str.strip_bom(), str);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_recode_with_bom() {
+        use crate::encoding::Utf32LE;
+
+        let str = Str::from_std("ab");
+        let out = str.recode_with_bom::<Utf16BE>();
+        assert_eq!(out.as_bytes(), b"\xFE\xFF\0a\0b");
+        assert!(out.has_bom());
+
+        // Round trip back to UTF-8 doesn't double up the mark
+        let back = out.recode_with_bom::<Utf8>();
+        assert_eq!(back, "\u{FEFF}ab");
+        assert!(back.strip_bom().chars().eq(str.chars()));
+
+        let out = Str::<Win1252>::from_bytes(b"\x80")
+            .unwrap()
+            .recode_with_bom::<Utf16LE>();
+        assert_eq!(out.as_bytes(), b"\xFF\xFE\xAC\x20");
+
+        // The output is allocated once, at exactly the needed size
+        let out = Str::from_std("a𐐷").recode_with_bom::<Utf32LE>();
+        assert_eq!(out.len(), 12);
+        assert_eq!(out.capacity(), 12);
+        let out = Str::from_std("\u{FEFF}a𐐷").recode_with_bom::<Utf8>();
+        assert_eq!(out.as_bytes(), "\u{FEFF}a𐐷".as_bytes());
+        assert_eq!(out.capacity(), out.len());
+    }
 
+    #[test]
+    fn test_recode_bytes() {
+        let str = Str::from_std("a→bc");
+        let mut iter = str.recode_bytes::<Ascii>();
+        assert_eq!(iter.size_hint(), (0, Some(6)));
+        assert_eq!(iter.next(), Some(Ok(b'a')));
+        let err = iter.next().unwrap().unwrap_err();
+        assert_eq!((err.valid_up_to(), err.char(), err.char_len()), (1, '→', 3));
+        // Iteration ends after an error, without skipping to the next valid character
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+
+        let mut iter = str.recode_bytes::<Utf16BE>();
+        assert_eq!(iter.next(), Some(Ok(0)));
+        assert_eq!(iter.size_hint(), (1, Some(1 + 5 * 4)));
         assert_eq!(
-            b,
-            Err(RecodeError {
-                valid_up_to: 1,
-                char: '𐐷',
-                char_len: 4,
-            })
+            iter.map(Result::unwrap).collect::<Vec<_>>(),
+            b"a\x21\x92\0b\0c"
         );
 
-        let a = Str::from_std("€𐐷b");
-        let b = a.recode::<Win1252>();
-
+        let lossy = str.recode_bytes_lossy::<Ascii>().collect::<Vec<_>>();
+        assert_eq!(lossy, b"a\x1Abc");
         assert_eq!(
-            b,
-            Err(RecodeError {
-                valid_up_to: 3,
-                char: '𐐷',
-                char_len: 4,
-            })
+            Str::<Win1252>::from_bytes(b"")
+                .unwrap()
+                .recode_bytes::<Utf8>()
+                .next(),
+            None
         );
     }
 
-    #[cfg(feature = "alloc")]
     #[test]
-    fn test_recode_lossy_invalid_chars() {
-        let a = Str::from_std("A𐐷b");
-        let b = a.recode_lossy::<Ascii>();
-
-        assert_eq!(&*b, Str::from_bytes(b"A\x1Ab").unwrap());
-
-        let a = Str::from_std("€𐐷b");
-        let b = a.recode_lossy::<Win1252>();
+    fn test_const() {
+        static PROMPTS: [&Str<Ascii>; 3] =
+            [Str::from_ascii(b"> "), Str::from_ascii(b"? "), Str::EMPTY];
+        static GREETING: &Str<Utf8> = Str::from_std("Héllo");
+        const NAME: &Str<Win1252> = Str::from_ascii(b"enrede");
+        const _: () = assert!(GREETING.len() == 6 && !NAME.is_empty());
+
+        assert!(PROMPTS[0].chars().eq("> ".chars()));
+        assert_eq!(PROMPTS[1].as_bytes(), b"? ");
+        assert!(PROMPTS[2].is_empty());
+        assert_eq!(GREETING.as_std(), "Héllo");
+        assert_eq!(NAME, "enrede");
+
+        macro_rules! check {
+            ($enc:ty, $name:literal) => {
+                assert_eq!(Str::<$enc>::EMPTY, <&Str<$enc>>::default());
+                assert_eq!(Str::<$enc>::EMPTY.chars().next(), None);
+            };
+        }
+        crate::for_each_encoding!(check);
+    }
 
-        assert_eq!(&*b, Str::from_bytes(b"\x80\x1Ab").unwrap());
+    #[test]
+    #[should_panic = "must be ASCII"]
+    fn test_from_ascii_invalid() {
+        let _ = Str::<Utf8>::from_ascii("é".as_bytes());
     }
 }
@@ -0,0 +1,124 @@
+use crate::encoding::Encoding;
+use crate::str::Str;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+/// Iterator over a byte slice, splitting it into chunks of valid and invalid data for an encoding.
+/// See [`Str::chunks`].
+///
+/// Each chunk is a run of valid data followed by a run of invalid data. Concatenating the valid
+/// and invalid portions of every chunk in order will always reconstruct the input exactly.
+///
+/// Validation stops at the first error, and the next chunk resumes after it, so each byte is only
+/// validated once. Splitting an input is linear in its length, however many errors it contains.
+pub struct DecodeChunks<'a, E> {
+    src: &'a [u8],
+    _phantom: PhantomData<E>,
+}
+
+impl<'a, E: Encoding> DecodeChunks<'a, E> {
+    pub(super) fn new(src: &'a [u8]) -> Self {
+        DecodeChunks {
+            src,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E> Clone for DecodeChunks<'_, E> {
+    fn clone(&self) -> Self {
+        DecodeChunks {
+            src: self.src,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: Encoding> fmt::Debug for DecodeChunks<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecodeChunks")
+            .field("src", &self.src)
+            .finish()
+    }
+}
+
+/// A single chunk yielded by [`DecodeChunks`]. Contains some valid data for the encoding,
+/// followed by some invalid data. Either portion may be empty, though never both.
+pub struct DecodeChunk<'a, E> {
+    valid: &'a Str<E>,
+    invalid: &'a [u8],
+}
+
+impl<'a, E: Encoding> DecodeChunk<'a, E> {
+    /// The valid portion of this chunk. This may be empty if the chunk starts with invalid data.
+    pub fn valid(&self) -> &'a Str<E> {
+        self.valid
+    }
+
+    /// The invalid portion of this chunk. This will only be empty if this is the final chunk, and
+    /// the input ended with valid data.
+    pub fn invalid(&self) -> &'a [u8] {
+        self.invalid
+    }
+}
+
+impl<E> Clone for DecodeChunk<'_, E> {
+    fn clone(&self) -> Self {
+        DecodeChunk {
+            valid: self.valid,
+            invalid: self.invalid,
+        }
+    }
+}
+
+impl<E: Encoding> fmt::Debug for DecodeChunk<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecodeChunk")
+            .field("valid", &self.valid)
+            .field("invalid", &self.invalid)
+            .finish()
+    }
+}
+
+impl<'a, E: Encoding + 'a> Iterator for DecodeChunks<'a, E> {
+    type Item = DecodeChunk<'a, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.src.is_empty() {
+            return None;
+        }
+
+        Some(match Str::<E>::from_bytes(self.src) {
+            Ok(valid) => {
+                let out = DecodeChunk {
+                    valid,
+                    invalid: &[],
+                };
+                self.src = &[];
+                out
+            }
+            Err(err) => {
+                let valid_to = err.valid_up_to();
+                // SAFETY: Data up to `valid_to` is guaranteed valid for the provided encoding
+                let valid = unsafe { Str::from_bytes_unchecked(&self.src[..valid_to]) };
+                let invalid = match err.error_len() {
+                    Some(len) => {
+                        let i = &self.src[valid_to..valid_to + len];
+                        self.src = &self.src[valid_to + len..];
+                        i
+                    }
+                    None => {
+                        let i = &self.src[valid_to..];
+                        self.src = &[];
+                        i
+                    }
+                };
+
+                DecodeChunk { valid, invalid }
+            }
+        })
+    }
+}
+
+impl<'a, E: Encoding + 'a> FusedIterator for DecodeChunks<'a, E> {}
@@ -0,0 +1,105 @@
+//! Formatting adapters for [`Str`], which avoid allocating or writing out more than needed.
+
+use crate::encoding::Encoding;
+use crate::str::Str;
+use core::fmt;
+use core::marker::PhantomData;
+
+/// Formats a string like its [`Debug`](fmt::Debug) implementation, but stops after a maximum
+/// number of characters and summarizes the rest.
+///
+/// See [`Str::debug_truncated`].
+pub struct DebugTruncated<'a, E> {
+    str: &'a Str<E>,
+    max_chars: usize,
+}
+
+impl<'a, E: Encoding> DebugTruncated<'a, E> {
+    pub(super) fn new(str: &'a Str<E>, max_chars: usize) -> Self {
+        DebugTruncated { str, max_chars }
+    }
+}
+
+impl<E> Clone for DebugTruncated<'_, E> {
+    fn clone(&self) -> Self {
+        DebugTruncated {
+            str: self.str,
+            max_chars: self.max_chars,
+        }
+    }
+}
+
+impl<E: Encoding> fmt::Debug for DebugTruncated<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let end = self
+            .str
+            .char_indices()
+            .nth(self.max_chars)
+            .map_or(self.str.len(), |(idx, _)| idx);
+        fmt::Debug::fmt(&self.str[..end], f)?;
+        let rest = &self.str[end..];
+        if rest.is_empty() {
+            return Ok(());
+        }
+        // Counting the remaining characters would mean decoding the whole string, so it's only
+        // done when it can be worked out from the length
+        if E::FIXED_LEN {
+            write!(
+                f,
+                "… ({} more chars, {} bytes)",
+                rest.len() / E::MAX_LEN,
+                rest.len()
+            )?;
+        } else {
+            write!(f, "… ({} more bytes)", rest.len())?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats bytes in an encoding, writing out valid data as text and invalid bytes as `\xNN`
+/// escapes.
+///
+/// See [`Str::display_bytes_lossy`].
+pub struct DisplayBytesLossy<'a, E> {
+    bytes: &'a [u8],
+    _phantom: PhantomData<E>,
+}
+
+impl<'a, E: Encoding> DisplayBytesLossy<'a, E> {
+    pub(super) fn new(bytes: &'a [u8]) -> Self {
+        DisplayBytesLossy {
+            bytes,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E> Clone for DisplayBytesLossy<'_, E> {
+    fn clone(&self) -> Self {
+        DisplayBytesLossy {
+            bytes: self.bytes,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: Encoding> fmt::Debug for DisplayBytesLossy<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DisplayBytesLossy")
+            .field("bytes", &self.bytes)
+            .finish()
+    }
+}
+
+impl<E: Encoding> fmt::Display for DisplayBytesLossy<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for chunk in Str::<E>::chunks(self.bytes) {
+            fmt::Display::fmt(chunk.valid(), f)?;
+            for byte in chunk.invalid() {
+                write!(f, "\\x{:02x}", byte)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,234 @@
+//! Conversion between the halfwidth and fullwidth forms of characters used in Japanese text.
+
+use core::iter::{FusedIterator, Peekable};
+
+const DAKUTEN: char = '\u{FF9E}';
+const HANDAKUTEN: char = '\u{FF9F}';
+
+/// The fullwidth forms of the halfwidth characters `U+FF61` to `U+FF9F`. The halfwidth voicing
+/// marks map to the spacing fullwidth marks, as they're only combined with a preceding kana when
+/// one has a voiced form.
+const HALFWIDTH_KANA: [char; 63] = [
+    '。', '「', '」', '、', '・', 'ヲ', 'ァ', 'ィ', 'ゥ', 'ェ', 'ォ', 'ャ', 'ュ', 'ョ', 'ッ', 'ー',
+    'ア', 'イ', 'ウ', 'エ', 'オ', 'カ', 'キ', 'ク', 'ケ', 'コ', 'サ', 'シ', 'ス', 'セ', 'ソ', 'タ',
+    'チ', 'ツ', 'テ', 'ト', 'ナ', 'ニ', 'ヌ', 'ネ', 'ノ', 'ハ', 'ヒ', 'フ', 'ヘ', 'ホ', 'マ', 'ミ',
+    'ム', 'メ', 'モ', 'ヤ', 'ユ', 'ヨ', 'ラ', 'リ', 'ル', 'レ', 'ロ', 'ワ', 'ン', '゛', '゜',
+];
+
+/// The halfwidth forms of the fullwidth symbols `U+FFE0` to `U+FFE6`
+const FULLWIDTH_SYMBOLS: [char; 7] = ['¢', '£', '¬', '¯', '¦', '¥', '₩'];
+
+/// Whether a fullwidth katakana has a voiced form, written with a dakuten, one code point after it
+fn has_voiced(c: u32) -> bool {
+    match c {
+        // カ to チ, and ツ to ト, alternating with their voiced forms
+        0x30AB..=0x30C1 => (c - 0x30AB).is_multiple_of(2),
+        0x30C4..=0x30C8 => (c - 0x30C4).is_multiple_of(2),
+        // ハ to ホ, each followed by its voiced and semi-voiced forms
+        0x30CF..=0x30DB => (c - 0x30CF).is_multiple_of(3),
+        _ => false,
+    }
+}
+
+/// Whether a fullwidth katakana has a semi-voiced form, written with a handakuten, two code points
+/// after it
+fn has_semi_voiced(c: u32) -> bool {
+    matches!(c, 0x30CF..=0x30DB) && (c - 0x30CF).is_multiple_of(3)
+}
+
+/// Combine a fullwidth katakana with a following halfwidth voicing mark, if it has a form with
+/// that mark
+fn combine(base: char, mark: char) -> Option<char> {
+    let code = base as u32;
+    match (base, mark) {
+        ('ウ', DAKUTEN) => Some('ヴ'),
+        ('ワ', DAKUTEN) => Some('ヷ'),
+        ('ヲ', DAKUTEN) => Some('ヺ'),
+        (_, DAKUTEN) if has_voiced(code) => char::from_u32(code + 1),
+        (_, HANDAKUTEN) if has_semi_voiced(code) => char::from_u32(code + 2),
+        _ => None,
+    }
+}
+
+/// Split a voiced fullwidth katakana into its unvoiced form and a halfwidth voicing mark
+#[cfg(feature = "alloc")]
+fn split(c: char) -> Option<(char, char)> {
+    let code = c as u32;
+    match c {
+        'ヴ' => Some(('ウ', DAKUTEN)),
+        'ヷ' => Some(('ワ', DAKUTEN)),
+        'ヺ' => Some(('ヲ', DAKUTEN)),
+        '\u{30AC}'..='\u{30DD}' if has_voiced(code - 1) => {
+            Some((char::from_u32(code - 1)?, DAKUTEN))
+        }
+        '\u{30AC}'..='\u{30DD}' if has_semi_voiced(code - 2) => {
+            Some((char::from_u32(code - 2)?, HANDAKUTEN))
+        }
+        _ => None,
+    }
+}
+
+/// The fullwidth form of a character, if it has one
+fn widen(c: char) -> Option<char> {
+    match c {
+        ' ' => Some('\u{3000}'),
+        '!'..='~' => char::from_u32(c as u32 + 0xFEE0),
+        '\u{FF61}'..='\u{FF9F}' => Some(HALFWIDTH_KANA[c as usize - 0xFF61]),
+        // The overline also widens to the fullwidth macron, which only narrows back to the macron,
+        // so this is one way. Shift-JIS and JIS X 0201 decode 0x7E as an overline, but can't
+        // represent the fullwidth macron, so it round trips there by never widening.
+        '‾' => Some('\u{FFE3}'),
+        _ => FULLWIDTH_SYMBOLS
+            .iter()
+            .position(|&s| s == c)
+            .and_then(|idx| char::from_u32(0xFFE0 + idx as u32)),
+    }
+}
+
+/// The halfwidth form of a character, if it has one, along with a voicing mark if it's voiced
+#[cfg(feature = "alloc")]
+fn narrow(c: char) -> Option<(char, Option<char>)> {
+    match c {
+        '\u{3000}' => Some((' ', None)),
+        '\u{FF01}'..='\u{FF5E}' => Some((char::from_u32(c as u32 - 0xFEE0)?, None)),
+        '\u{FFE0}'..='\u{FFE6}' => Some((FULLWIDTH_SYMBOLS[c as usize - 0xFFE0], None)),
+        // The combining voicing marks, as well as the spacing ones in the table
+        '\u{3099}' => Some((DAKUTEN, None)),
+        '\u{309A}' => Some((HANDAKUTEN, None)),
+        _ => match HALFWIDTH_KANA.iter().position(|&k| k == c) {
+            Some(idx) => Some((char::from_u32(0xFF61 + idx as u32)?, None)),
+            None => {
+                let (base, mark) = split(c)?;
+                let (base, _) = narrow(base)?;
+                Some((base, Some(mark)))
+            }
+        },
+    }
+}
+
+/// Iterator converting characters to their fullwidth forms, where they have one for which
+/// `keep` returns `true`. A halfwidth katakana followed by a halfwidth voicing mark becomes a
+/// single voiced fullwidth katakana.
+pub(super) struct Widen<I: Iterator<Item = char>, F> {
+    iter: Peekable<I>,
+    keep: F,
+}
+
+impl<I: Iterator<Item = char>, F: Fn(char) -> bool> Widen<I, F> {
+    pub(super) fn new(iter: I, keep: F) -> Self {
+        Widen {
+            iter: iter.peekable(),
+            keep,
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>, F: Fn(char) -> bool> Iterator for Widen<I, F> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.iter.next()?;
+        let Some(wide) = widen(c).filter(|&w| (self.keep)(w)) else {
+            return Some(c);
+        };
+        if matches!(c, '\u{FF66}'..='\u{FF9D}') {
+            let voiced = self
+                .iter
+                .next_if(|&mark| combine(wide, mark).is_some_and(|v| (self.keep)(v)));
+            if let Some(mark) = voiced {
+                return combine(wide, mark);
+            }
+        }
+        Some(wide)
+    }
+}
+
+impl<I: FusedIterator<Item = char>, F: Fn(char) -> bool> FusedIterator for Widen<I, F> {}
+
+/// Iterator converting characters to their halfwidth forms, where they have one for which `keep`
+/// returns `true`. A voiced fullwidth katakana becomes a halfwidth katakana followed by a
+/// halfwidth voicing mark.
+#[cfg(feature = "alloc")]
+pub(super) struct Narrow<I, F> {
+    iter: I,
+    keep: F,
+    mark: Option<char>,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item = char>, F: Fn(char) -> bool> Narrow<I, F> {
+    pub(super) fn new(iter: I, keep: F) -> Self {
+        Narrow {
+            iter,
+            keep,
+            mark: None,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item = char>, F: Fn(char) -> bool> Iterator for Narrow<I, F> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(mark) = self.mark.take() {
+            return Some(mark);
+        }
+        let c = self.iter.next()?;
+        match narrow(c) {
+            Some((base, mark)) if (self.keep)(base) && mark.is_none_or(&self.keep) => {
+                self.mark = mark;
+                Some(base)
+            }
+            _ => Some(c),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: FusedIterator<Item = char>, F: Fn(char) -> bool> FusedIterator for Narrow<I, F> {}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for c in '\u{FF61}'..='\u{FF9F}' {
+            let wide = widen(c).unwrap();
+            assert_eq!(narrow(wide), Some((c, None)), "{c:?}");
+        }
+        for c in '!'..='~' {
+            let wide = widen(c).unwrap();
+            assert_eq!(narrow(wide), Some((c, None)), "{c:?}");
+        }
+        for (idx, &c) in FULLWIDTH_SYMBOLS.iter().enumerate() {
+            let wide = char::from_u32(0xFFE0 + idx as u32).unwrap();
+            assert_eq!(widen(c), Some(wide));
+            assert_eq!(narrow(wide), Some((c, None)));
+        }
+        // The overline shares the macron's fullwidth form, but doesn't narrow back to itself
+        assert_eq!(widen('‾'), Some('\u{FFE3}'));
+        assert_eq!(narrow('\u{FFE3}'), Some(('¯', None)));
+    }
+
+    #[test]
+    fn test_voiced() {
+        // Every voiced and semi-voiced katakana, which all split and recombine
+        let voiced = "ガギグゲゴザジズゼゾダヂヅデドバビブベボパピプペポヴヷヺ";
+        for c in voiced.chars() {
+            let (base, mark) = split(c).unwrap();
+            assert_eq!(combine(base, mark), Some(c), "{c:?}");
+        }
+        // Unvoiced katakana don't split, and only some have voiced forms
+        for c in "カタハ".chars() {
+            assert!(combine(c, DAKUTEN).is_some(), "{c:?}");
+            assert_eq!(split(c), None, "{c:?}");
+        }
+        for c in "ッヵヶアンー".chars() {
+            assert_eq!(combine(c, DAKUTEN), None, "{c:?}");
+            assert_eq!(split(c), None, "{c:?}");
+        }
+        assert_eq!(combine('カ', HANDAKUTEN), None);
+    }
+}
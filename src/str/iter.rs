@@ -1,20 +1,41 @@
-use crate::encoding::Encoding;
+use crate::encoding::{ArrayLike, Encoding, Utf16BE, Utf16LE};
+use crate::errors::RecodeError;
 use crate::str::Str;
+use core::fmt::{self, Write};
 use core::iter::FusedIterator;
 use core::marker::PhantomData;
-use core::slice;
+use core::ops::Range;
+use core::{mem, slice};
+#[cfg(feature = "unicode-normalization")]
+use unicode_normalization::{Decompositions, Recompositions, UnicodeNormalization};
 
 /// Character iterator for encoded strings. This iterates the encoding yielding Unicode code points.
 pub struct Chars<'a, E> {
     str: &'a Str<E>,
-    _phantom: PhantomData<E>,
+    // Length of the whole string, to track how much has been consumed
+    len: usize,
 }
 
 impl<'a, E: Encoding> Chars<'a, E> {
     pub(super) fn new(str: &'a Str<E>) -> Self {
         Chars {
             str,
-            _phantom: PhantomData,
+            len: str.len(),
+        }
+    }
+
+    /// The byte position of the next character, or the length of the string if the iterator is
+    /// exhausted. This is the number of bytes consumed so far.
+    pub fn offset(&self) -> usize {
+        self.len - self.str.len()
+    }
+}
+
+impl<E> Clone for Chars<'_, E> {
+    fn clone(&self) -> Self {
+        Chars {
+            str: self.str,
+            len: self.len,
         }
     }
 }
@@ -37,30 +58,632 @@ impl<'a, E: Encoding> FusedIterator for Chars<'a, E> where slice::Iter<'a, u8>:
 /// Character and index iterator for encoded strings. This iterates the encoding yielding Unicode
 /// code points and their byte index in the encoded string.
 pub struct CharIndices<'a, E> {
-    offset: usize,
     iter: Chars<'a, E>,
 }
 
 impl<'a, E: Encoding> CharIndices<'a, E> {
     pub(super) fn new(str: &'a Str<E>) -> Self {
         CharIndices {
-            offset: 0,
             iter: Chars::new(str),
         }
     }
+
+    /// The byte position of the next character, or the length of the string if the iterator is
+    /// exhausted.
+    pub fn offset(&self) -> usize {
+        self.iter.offset()
+    }
+}
+
+impl<E> Clone for CharIndices<'_, E> {
+    fn clone(&self) -> Self {
+        CharIndices {
+            iter: self.iter.clone(),
+        }
+    }
 }
 
 impl<'a, E: Encoding> Iterator for CharIndices<'a, E> {
     type Item = (usize, char);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let pre_len = self.iter.str.len();
+        let offset = self.iter.offset();
         let c = self.iter.next()?;
-        let offset = self.offset;
-        let len = self.iter.str.len();
-        self.offset += pre_len - len;
         Some((offset, c))
     }
 }
 
 impl<'a, E: Encoding> FusedIterator for CharIndices<'a, E> where Chars<'a, E>: FusedIterator {}
+
+/// Character and span iterator for encoded strings. This iterates the encoding yielding Unicode
+/// code points and the range of bytes they occupy in the encoded string.
+///
+/// See [`Str::char_spans`].
+pub struct CharSpans<'a, E> {
+    iter: Chars<'a, E>,
+}
+
+impl<'a, E: Encoding> CharSpans<'a, E> {
+    pub(super) fn new(str: &'a Str<E>) -> Self {
+        CharSpans {
+            iter: Chars::new(str),
+        }
+    }
+
+    /// The byte position of the next character, or the length of the string if the iterator is
+    /// exhausted.
+    pub fn offset(&self) -> usize {
+        self.iter.offset()
+    }
+}
+
+impl<E> Clone for CharSpans<'_, E> {
+    fn clone(&self) -> Self {
+        CharSpans {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<'a, E: Encoding> Iterator for CharSpans<'a, E> {
+    type Item = (Range<usize>, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.iter.offset();
+        let c = self.iter.next()?;
+        Some((start..self.iter.offset(), c))
+    }
+}
+
+impl<'a, E: Encoding> FusedIterator for CharSpans<'a, E> where Chars<'a, E>: FusedIterator {}
+
+/// Iterator over the character boundaries of an encoded string, including the start and end of the
+/// string.
+///
+/// See [`Str::char_boundaries`].
+pub struct CharBoundaries<'a, E> {
+    iter: CharIndices<'a, E>,
+    len: Option<usize>,
+}
+
+impl<'a, E: Encoding> CharBoundaries<'a, E> {
+    pub(super) fn new(str: &'a Str<E>) -> Self {
+        CharBoundaries {
+            iter: CharIndices::new(str),
+            len: Some(str.len()),
+        }
+    }
+}
+
+impl<E> Clone for CharBoundaries<'_, E> {
+    fn clone(&self) -> Self {
+        CharBoundaries {
+            iter: self.iter.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<E: Encoding> Iterator for CharBoundaries<'_, E> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some((idx, _)) => Some(idx),
+            None => self.len.take(),
+        }
+    }
+}
+
+impl<'a, E: Encoding> FusedIterator for CharBoundaries<'a, E> where CharIndices<'a, E>: FusedIterator
+{}
+
+/// Iterator over consecutive slices of an encoded string, each at most a fixed number of bytes
+/// long, without splitting characters.
+///
+/// See [`Str::char_slices`].
+pub struct CharSlices<'a, E> {
+    str: &'a Str<E>,
+    max_bytes: usize,
+}
+
+impl<'a, E: Encoding> CharSlices<'a, E> {
+    pub(super) fn new(str: &'a Str<E>, max_bytes: usize) -> Self {
+        assert!(max_bytes != 0, "Slice size must be non-zero");
+        CharSlices { str, max_bytes }
+    }
+}
+
+impl<E> Clone for CharSlices<'_, E> {
+    fn clone(&self) -> Self {
+        CharSlices {
+            str: self.str,
+            max_bytes: self.max_bytes,
+        }
+    }
+}
+
+impl<'a, E: Encoding> Iterator for CharSlices<'a, E> {
+    type Item = &'a Str<E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.str.is_empty() {
+            return None;
+        }
+        let mut end = self.str.floor_char_boundary(self.max_bytes);
+        if end == 0 {
+            // The first character is longer than `max_bytes`, yield it alone
+            end = self.str.ceil_char_boundary(1);
+        }
+        let (slice, rest) = self.str.split_at_checked(end)?;
+        self.str = rest;
+        Some(slice)
+    }
+}
+
+impl<E: Encoding> FusedIterator for CharSlices<'_, E> {}
+
+/// Iterator over the UTF-16 code units of an encoded string. This decodes each character, then
+/// re-encodes it as UTF-16.
+///
+/// See [`Str::encode_utf16`].
+pub struct EncodeUtf16<'a, E> {
+    chars: Chars<'a, E>,
+    extra: u16,
+}
+
+impl<'a, E: Encoding> EncodeUtf16<'a, E> {
+    pub(super) fn new(str: &'a Str<E>) -> Self {
+        EncodeUtf16 {
+            chars: Chars::new(str),
+            extra: 0,
+        }
+    }
+}
+
+impl<E> Clone for EncodeUtf16<'_, E> {
+    fn clone(&self) -> Self {
+        EncodeUtf16 {
+            chars: self.chars.clone(),
+            extra: self.extra,
+        }
+    }
+}
+
+impl<E: Encoding> Iterator for EncodeUtf16<'_, E> {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.extra != 0 {
+            return Some(mem::take(&mut self.extra));
+        }
+        let mut buf = [0; 2];
+        let units = self.chars.next()?.encode_utf16(&mut buf);
+        if let [_, low] = units {
+            self.extra = *low;
+        }
+        Some(units[0])
+    }
+}
+
+impl<'a, E: Encoding> FusedIterator for EncodeUtf16<'a, E> where Chars<'a, E>: FusedIterator {}
+
+/// Iterator over the characters of an encoded string in Unicode Normalization Form C.
+///
+/// See [`Str::nfc_chars`].
+#[cfg(feature = "unicode-normalization")]
+pub struct NfcChars<'a, E>(Recompositions<Chars<'a, E>>);
+
+#[cfg(feature = "unicode-normalization")]
+impl<'a, E: Encoding> NfcChars<'a, E> {
+    pub(super) fn new(str: &'a Str<E>) -> Self {
+        NfcChars(Chars::new(str).nfc())
+    }
+}
+
+#[cfg(feature = "unicode-normalization")]
+impl<E> Clone for NfcChars<'_, E> {
+    fn clone(&self) -> Self {
+        NfcChars(self.0.clone())
+    }
+}
+
+#[cfg(feature = "unicode-normalization")]
+impl<E: Encoding> Iterator for NfcChars<'_, E> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// Iterator over the characters of an encoded string in Unicode Normalization Form D.
+///
+/// See [`Str::nfd_chars`].
+#[cfg(feature = "unicode-normalization")]
+pub struct NfdChars<'a, E>(Decompositions<Chars<'a, E>>);
+
+#[cfg(feature = "unicode-normalization")]
+impl<'a, E: Encoding> NfdChars<'a, E> {
+    pub(super) fn new(str: &'a Str<E>) -> Self {
+        NfdChars(Chars::new(str).nfd())
+    }
+}
+
+#[cfg(feature = "unicode-normalization")]
+impl<E> Clone for NfdChars<'_, E> {
+    fn clone(&self) -> Self {
+        NfdChars(self.0.clone())
+    }
+}
+
+#[cfg(feature = "unicode-normalization")]
+impl<E: Encoding> Iterator for NfdChars<'_, E> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+/// Iterator over the bytes of an encoded string recoded into another encoding. This decodes each
+/// character, then encodes it in the output encoding as it's needed, without allocating.
+///
+/// If a character can't be represented in the output encoding, an error is yielded and iteration
+/// ends.
+///
+/// See [`Str::recode_bytes`].
+pub struct RecodeBytes<'a, E, E2: Encoding> {
+    str: &'a Str<E>,
+    pos: usize,
+    current: Option<E2::Bytes>,
+    current_pos: usize,
+    lossy: bool,
+}
+
+impl<'a, E: Encoding, E2: Encoding> RecodeBytes<'a, E, E2> {
+    pub(super) fn new(str: &'a Str<E>, lossy: bool) -> Self {
+        RecodeBytes {
+            str,
+            pos: 0,
+            current: None,
+            current_pos: 0,
+            lossy,
+        }
+    }
+
+    fn remaining_current(&self) -> usize {
+        self.current
+            .as_ref()
+            .map_or(0, |bytes| bytes.slice().len() - self.current_pos)
+    }
+}
+
+impl<E, E2: Encoding> Clone for RecodeBytes<'_, E, E2>
+where
+    E2::Bytes: Clone,
+{
+    fn clone(&self) -> Self {
+        RecodeBytes {
+            str: self.str,
+            pos: self.pos,
+            current: self.current.clone(),
+            current_pos: self.current_pos,
+            lossy: self.lossy,
+        }
+    }
+}
+
+impl<E: Encoding, E2: Encoding> Iterator for RecodeBytes<'_, E, E2> {
+    type Item = Result<u8, RecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(bytes) = &self.current {
+                if let Some(&b) = bytes.slice().get(self.current_pos) {
+                    self.current_pos += 1;
+                    return Some(Ok(b));
+                }
+                self.current = None;
+            }
+
+            if self.str.is_empty() {
+                return None;
+            }
+            let (c, rest) = E::decode_char(self.str);
+            let len = self.str.len() - rest.len();
+            let bytes = match E2::encode_char(c) {
+                Some(bytes) => bytes,
+                None if self.lossy => E2::encode_char(E2::REPLACEMENT)
+                    .expect("Encoding replacement character should always be encodable"),
+                None => {
+                    // End iteration, so later characters aren't silently skipped
+                    self.str = &self.str[self.str.len()..];
+                    return Some(Err(RecodeError {
+                        valid_up_to: self.pos,
+                        char: c,
+                        char_len: len as u8,
+                    }));
+                }
+            };
+            self.str = rest;
+            self.pos += len;
+            self.current = Some(bytes);
+            self.current_pos = 0;
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every input character is at least one byte, and produces at most `MAX_LEN` bytes
+        let current = self.remaining_current();
+        let rest = self.str.len().checked_mul(E2::MAX_LEN);
+        (current, rest.and_then(|rest| rest.checked_add(current)))
+    }
+}
+
+impl<E: Encoding, E2: Encoding> FusedIterator for RecodeBytes<'_, E, E2> {}
+
+/// Iterator over the bytes of an encoded string recoded into another encoding, replacing any
+/// characters that can't be represented in the output encoding with its replacement character.
+///
+/// See [`Str::recode_bytes_lossy`].
+pub struct RecodeBytesLossy<'a, E, E2: Encoding>(RecodeBytes<'a, E, E2>);
+
+impl<'a, E: Encoding, E2: Encoding> RecodeBytesLossy<'a, E, E2> {
+    pub(super) fn new(str: &'a Str<E>) -> Self {
+        RecodeBytesLossy(RecodeBytes::new(str, true))
+    }
+}
+
+impl<E, E2: Encoding> Clone for RecodeBytesLossy<'_, E, E2>
+where
+    E2::Bytes: Clone,
+{
+    fn clone(&self) -> Self {
+        RecodeBytesLossy(self.0.clone())
+    }
+}
+
+impl<E: Encoding, E2: Encoding> Iterator for RecodeBytesLossy<'_, E, E2> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0
+            .next()
+            .map(|res| res.expect("Lossy recoding should never fail"))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<E: Encoding, E2: Encoding> FusedIterator for RecodeBytesLossy<'_, E, E2> {}
+
+/// Iterator over the code units of a UTF-16 string, read directly from its bytes without decoding
+/// characters.
+///
+/// See [`Str::<Utf16LE>::code_units`] and [`Str::<Utf16BE>::code_units`].
+pub struct CodeUnits<'a, E> {
+    bytes: slice::ChunksExact<'a, u8>,
+    _phantom: PhantomData<E>,
+}
+
+impl<'a, E: Encoding> CodeUnits<'a, E> {
+    pub(super) fn new(str: &'a Str<E>) -> Self {
+        CodeUnits {
+            bytes: str.as_bytes().chunks_exact(2),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E> Clone for CodeUnits<'_, E> {
+    fn clone(&self) -> Self {
+        CodeUnits {
+            bytes: self.bytes.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+macro_rules! code_units_impl {
+    ($name:ident, $method_from:ident) => {
+        impl Iterator for CodeUnits<'_, $name> {
+            type Item = u16;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.bytes
+                    .next()
+                    .map(|unit| u16::$method_from([unit[0], unit[1]]))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.bytes.size_hint()
+            }
+        }
+
+        impl DoubleEndedIterator for CodeUnits<'_, $name> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.bytes
+                    .next_back()
+                    .map(|unit| u16::$method_from([unit[0], unit[1]]))
+            }
+        }
+
+        impl ExactSizeIterator for CodeUnits<'_, $name> {}
+
+        impl FusedIterator for CodeUnits<'_, $name> {}
+    };
+}
+
+code_units_impl!(Utf16LE, from_le_bytes);
+code_units_impl!(Utf16BE, from_be_bytes);
+
+/// The remaining output for a single character of an escape iterator
+#[derive(Clone, Debug)]
+enum EscapeChar<'a> {
+    Raw(Option<char>),
+    Debug(core::char::EscapeDebug),
+    Default(core::char::EscapeDefault),
+    Bytes { bytes: &'a [u8], pos: u8 },
+}
+
+impl EscapeChar<'_> {
+    /// Escape a character which isn't the first in its string. This matches the behavior of
+    /// [`str::escape_debug`], which only escapes grapheme extenders at the start of a string.
+    fn debug_continue(c: char) -> Self {
+        let escape = c.escape_debug();
+        if escape.len() == 1 || !escape.clone().eq(c.escape_unicode()) {
+            return EscapeChar::Debug(escape);
+        }
+        let mut buf = [b'a'; 5];
+        let len = c.encode_utf8(&mut buf[1..]).len() + 1;
+        // SAFETY: The buffer starts with an ASCII character, followed by the UTF-8 encoding of `c`
+        let str = unsafe { core::str::from_utf8_unchecked(&buf[..len]) };
+        if str.escape_debug().nth(1) == Some('\\') {
+            EscapeChar::Debug(escape)
+        } else {
+            EscapeChar::Raw(Some(c))
+        }
+    }
+}
+
+impl Iterator for EscapeChar<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            EscapeChar::Raw(c) => c.take(),
+            EscapeChar::Debug(escape) => escape.next(),
+            EscapeChar::Default(escape) => escape.next(),
+            EscapeChar::Bytes { bytes, pos } => {
+                let (&byte, rest) = bytes.split_first()?;
+                let out = match *pos {
+                    0 => '\\',
+                    1 => 'x',
+                    2 => char::from(b"0123456789abcdef"[usize::from(byte >> 4)]),
+                    _ => char::from(b"0123456789abcdef"[usize::from(byte & 0xF)]),
+                };
+                *pos += 1;
+                if *pos == 4 {
+                    *pos = 0;
+                    *bytes = rest;
+                }
+                Some(out)
+            }
+        }
+    }
+}
+
+macro_rules! escape_impl {
+    ($name:ident, |$first:ident, $c:ident, $raw:ident| $escape:expr) => {
+        impl<'a, E> $name<'a, E> {
+            pub(super) fn new(str: &'a Str<E>) -> Self {
+                $name {
+                    str,
+                    first: true,
+                    cur: EscapeChar::Raw(None),
+                }
+            }
+        }
+
+        impl<E> Clone for $name<'_, E> {
+            fn clone(&self) -> Self {
+                $name {
+                    str: self.str,
+                    first: self.first,
+                    cur: self.cur.clone(),
+                }
+            }
+        }
+
+        impl<E: Encoding> fmt::Debug for $name<'_, E> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.str).finish()
+            }
+        }
+
+        impl<E: Encoding> fmt::Display for $name<'_, E> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                for c in self.clone() {
+                    f.write_char(c)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl<'a, E: Encoding> Iterator for $name<'a, E> {
+            type Item = char;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                loop {
+                    if let Some(c) = self.cur.next() {
+                        return Some(c);
+                    }
+                    if self.str.is_empty() {
+                        return None;
+                    }
+                    let ($c, rest) = E::decode_char(self.str);
+                    let $raw: &'a [u8] = &self.str.as_bytes()[..self.str.len() - rest.len()];
+                    let $first = mem::replace(&mut self.first, false);
+                    self.str = rest;
+                    self.cur = $escape;
+                }
+            }
+        }
+
+        impl<E: Encoding> FusedIterator for $name<'_, E> {}
+    };
+}
+
+/// Iterator over the characters of an encoded string, escaped as by [`char::escape_debug`].
+///
+/// See [`Str::escape_debug`].
+pub struct EscapeDebug<'a, E> {
+    str: &'a Str<E>,
+    first: bool,
+    cur: EscapeChar<'a>,
+}
+
+escape_impl!(EscapeDebug, |first, c, _raw| if first {
+    EscapeChar::Debug(c.escape_debug())
+} else {
+    EscapeChar::debug_continue(c)
+});
+
+/// Iterator over the characters of an encoded string, escaped as by [`char::escape_default`].
+///
+/// See [`Str::escape_default`].
+pub struct EscapeDefault<'a, E> {
+    str: &'a Str<E>,
+    first: bool,
+    cur: EscapeChar<'a>,
+}
+
+escape_impl!(EscapeDefault, |_first, c, _raw| EscapeChar::Default(
+    c.escape_default()
+));
+
+/// Iterator over the characters of an encoded string, with anything other than printable ASCII
+/// escaped as the hex values of its encoded bytes.
+///
+/// See [`Str::escape_bytes`].
+pub struct EscapeBytes<'a, E> {
+    str: &'a Str<E>,
+    first: bool,
+    cur: EscapeChar<'a>,
+}
+
+escape_impl!(EscapeBytes, |_first, c, raw| match c {
+    ' '..='~' | '\t' | '\r' | '\n' => EscapeChar::Default(c.escape_default()),
+    _ => EscapeChar::Bytes { bytes: raw, pos: 0 },
+});
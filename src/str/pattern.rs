@@ -0,0 +1,179 @@
+use crate::encoding::{ArrayLike, Encoding};
+use crate::str::Str;
+use core::fmt;
+use core::iter::FusedIterator;
+use memchr::memmem::Finder;
+
+mod sealed {
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+/// A pattern which can be searched for in a [`Str<E>`]. This is implemented for [`char`] and
+/// `&Str<E>`.
+///
+/// Matches always begin and end on character boundaries, so a pattern will never match the tail
+/// bytes of one character and the lead bytes of the next, even in encodings where those bytes
+/// could look like a valid encoding of the pattern.
+pub trait Pattern<E: Encoding>: Sealed {
+    /// Find the first match of this pattern in `haystack`, returning the byte index and length of
+    /// the match.
+    #[doc(hidden)]
+    fn find_in(&self, haystack: &Str<E>) -> Option<(usize, usize)>;
+}
+
+impl Sealed for char {}
+
+impl<E: Encoding> Pattern<E> for char {
+    fn find_in(&self, haystack: &Str<E>) -> Option<(usize, usize)> {
+        // A character the encoding can't represent can't be contained in the string
+        let bytes = E::encode_char(*self)?;
+        let needle = bytes.slice();
+        find_bytes(haystack, needle).map(|idx| (idx, needle.len()))
+    }
+}
+
+impl<E> Sealed for &Str<E> {}
+
+impl<E: Encoding> Pattern<E> for &Str<E> {
+    fn find_in(&self, haystack: &Str<E>) -> Option<(usize, usize)> {
+        find_bytes(haystack, self.as_bytes()).map(|idx| (idx, self.len()))
+    }
+}
+
+/// Find the first instance of `needle` in `haystack` that starts on a character boundary.
+/// `needle` must be a valid string in the encoding, so that a match starting on a boundary also
+/// ends on one.
+fn find_bytes<E: Encoding>(haystack: &Str<E>, needle: &[u8]) -> Option<usize> {
+    let bytes = haystack.as_bytes();
+    let finder = Finder::new(needle);
+    let mut pos = 0;
+    // Checking for a boundary may scan back through the string, so check candidates in the rest
+    // of the string after the last boundary found, rather than in the whole string
+    let mut bound = 0;
+    // A match which doesn't start on a boundary may overlap one which does, so resume searching
+    // just after the start of the previous candidate
+    while let Some(found) = finder.find(&bytes[pos..]) {
+        let idx = pos + found;
+        // SAFETY: `bound` is always a character boundary no greater than `idx`
+        let rest = unsafe { haystack.slice_unchecked(bound..bytes.len()) };
+        if rest.is_char_boundary(idx - bound) {
+            return Some(idx);
+        }
+        bound += rest.floor_char_boundary(idx - bound);
+        pos = idx + 1;
+    }
+    None
+}
+
+/// Iterator over the non-overlapping matches of a pattern in a string, and their byte indices.
+///
+/// See [`Str::match_indices`].
+pub struct MatchIndices<'a, E, P> {
+    str: &'a Str<E>,
+    pat: P,
+    pos: usize,
+    finished: bool,
+}
+
+impl<'a, E: Encoding, P: Pattern<E>> MatchIndices<'a, E, P> {
+    pub(super) fn new(str: &'a Str<E>, pat: P) -> Self {
+        MatchIndices {
+            str,
+            pat,
+            pos: 0,
+            finished: false,
+        }
+    }
+}
+
+impl<E, P: Clone> Clone for MatchIndices<'_, E, P> {
+    fn clone(&self) -> Self {
+        MatchIndices {
+            str: self.str,
+            pat: self.pat.clone(),
+            pos: self.pos,
+            finished: self.finished,
+        }
+    }
+}
+
+impl<E: Encoding, P: fmt::Debug> fmt::Debug for MatchIndices<'_, E, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MatchIndices")
+            .field("str", &self.str)
+            .field("pat", &self.pat)
+            .field("pos", &self.pos)
+            .finish()
+    }
+}
+
+impl<'a, E: Encoding, P: Pattern<E>> Iterator for MatchIndices<'a, E, P> {
+    type Item = (usize, &'a Str<E>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        // SAFETY: `pos` is always the end of a match or character, so is a boundary. Indexing
+        //         would check that by scanning back from it, for every match.
+        let rest = unsafe { self.str.slice_unchecked(self.pos..self.str.len()) };
+        let Some((idx, len)) = self.pat.find_in(rest) else {
+            self.finished = true;
+            return None;
+        };
+
+        let start = self.pos + idx;
+        let end = start + len;
+        self.pos = end;
+        if len == 0 {
+            // Empty matches occur between every character, so step over the next character to
+            // avoid matching at the same position forever.
+            if end < self.str.len() {
+                // SAFETY: `end` is the end of a match, so is a boundary
+                let (_, rest) =
+                    E::decode_char(unsafe { self.str.slice_unchecked(end..self.str.len()) });
+                self.pos = self.str.len() - rest.len();
+            } else {
+                self.finished = true;
+            }
+        }
+        // SAFETY: Matches always start and end on character boundaries
+        Some((start, unsafe { self.str.slice_unchecked(start..end) }))
+    }
+}
+
+impl<E: Encoding, P: Pattern<E>> FusedIterator for MatchIndices<'_, E, P> {}
+
+/// Iterator over the non-overlapping matches of a pattern in a string.
+///
+/// See [`Str::matches`].
+pub struct Matches<'a, E, P>(MatchIndices<'a, E, P>);
+
+impl<'a, E: Encoding, P: Pattern<E>> Matches<'a, E, P> {
+    pub(super) fn new(str: &'a Str<E>, pat: P) -> Self {
+        Matches(MatchIndices::new(str, pat))
+    }
+}
+
+impl<E, P: Clone> Clone for Matches<'_, E, P> {
+    fn clone(&self) -> Self {
+        Matches(self.0.clone())
+    }
+}
+
+impl<E: Encoding, P: fmt::Debug> fmt::Debug for Matches<'_, E, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Matches").field(&self.0).finish()
+    }
+}
+
+impl<'a, E: Encoding, P: Pattern<E>> Iterator for Matches<'a, E, P> {
+    type Item = &'a Str<E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, str)| str)
+    }
+}
+
+impl<E: Encoding, P: Pattern<E>> FusedIterator for Matches<'_, E, P> {}
@@ -2,31 +2,98 @@
 //! equivalent type.
 
 use alloc::borrow::{Borrow, BorrowMut, Cow, ToOwned};
+use alloc::boxed::Box;
+use alloc::collections::TryReserveError;
+use alloc::rc::Rc;
 use alloc::string::String as StdString;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::fmt;
 use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
 use core::marker::PhantomData;
-use core::ops::{Deref, DerefMut};
+use core::ops::{Deref, DerefMut, Mul, RangeBounds};
 #[cfg(feature = "serde")]
 use serde::{
     de::{self, Unexpected},
     Deserialize, Deserializer, Serialize, Serializer,
 };
+#[cfg(feature = "unicode-normalization")]
+use unicode_normalization::{is_nfc_quick, IsNormalized};
 
+use crate::arraystring::{CapacityError, PushError};
 use crate::cstring::{CString, NulError};
-use crate::encoding::{AlwaysValid, ArrayLike, Encoding, NullTerminable, Utf8, ValidateError};
+use crate::encoding::{
+    AlwaysValid, ArrayLike, Ascii, Big5, CustomSingleByte, Encoding, ExtendedAscii, Iso8859_15,
+    Iso8859_2, JisX0201, JisX0208, MacRoman, NullTerminable, ShiftJis, ShiftJisAscii,
+    SingleByteTable, UnicodeComplete, Utf16BE, Utf16LE, Utf32BE, Utf32LE, Utf8, ValidateError,
+    Win1251, Win1252, Win1252Loose,
+};
+use crate::str::RecodeError;
 use crate::str::Str;
-
-mod chunks;
-
-use chunks::EncodedChunks;
+use crate::utils::paranoid_assert;
 
 /// An error returned when you attempt to perform operations using a character not supported in a
 /// specific encoding.
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 #[non_exhaustive]
-pub struct InvalidChar;
+pub struct InvalidChar {
+    pub(crate) char: char,
+}
+
+impl InvalidChar {
+    /// The character that isn't supported by the encoding
+    pub fn char(&self) -> char {
+        self.char
+    }
+}
+
+impl fmt::Display for InvalidChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "character {:?} isn't valid for the encoding", self.char)
+    }
+}
+
+impl core::error::Error for InvalidChar {}
+
+/// An error returned by [`String::from_bytes_reuse`] when the input isn't valid for the encoding.
+/// The rejected bytes can be recovered with [`into_vec`](Self::into_vec), to reuse their
+/// allocation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnValidateError {
+    bytes: Vec<u8>,
+    error: ValidateError,
+}
+
+impl OwnValidateError {
+    /// The validation error for the input bytes
+    pub fn validate_error(&self) -> ValidateError {
+        self.error
+    }
+
+    /// The input bytes which failed to validate
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Consume this error, returning the input bytes which generated the error in the first place.
+    pub fn into_vec(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl fmt::Display for OwnValidateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl core::error::Error for OwnValidateError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
 
 /// Implementation of a generically encoded [`std::String`](std::string::String) type. This type is
 /// similar to the standard library [`String`](std::string::String) type in many ways, but instead
@@ -35,7 +102,12 @@ pub struct InvalidChar;
 ///
 /// `String` only implements `==` between instances with the same encoding. To compare strings of
 /// different encoding by characters, use `a.chars().eq(b.chars())`.
-#[derive(Clone)]
+///
+/// As with the standard library, methods that allocate abort on allocation failure, and panic if
+/// the requested capacity overflows. Where that isn't acceptable, reserve space up front with
+/// [`String::try_reserve`] and then use [`String::push_within_capacity`] or
+/// [`String::push_str_within_capacity`], or create strings with [`Str::try_to_owned`] and
+/// [`Str::try_recode`].
 pub struct String<E>(PhantomData<E>, Vec<u8>);
 
 impl<E: Encoding> String<E> {
@@ -44,17 +116,74 @@ impl<E: Encoding> String<E> {
         String(PhantomData, Vec::new())
     }
 
-    /// Create an empty string with a pre-allocated capacity for `len` bytes.
+    /// Create an empty string with a pre-allocated capacity for `len` bytes. Characters may take
+    /// more than one byte each - use [`String::with_capacity_chars`] to allocate by character
+    /// count instead.
     pub fn with_capacity(len: usize) -> String<E> {
         String(PhantomData, Vec::with_capacity(len))
     }
 
+    /// Create an empty string with a pre-allocated capacity for `chars` characters. Space is
+    /// reserved for the longest character in the encoding, so for encodings with variable length
+    /// characters this may allocate several times more than is needed.
+    ///
+    /// ```
+    /// # use enrede::{String, encoding::{Utf8, Utf32LE}};
+    /// assert!(String::<Utf32LE>::with_capacity_chars(10).capacity() >= 40);
+    /// assert!(String::<Utf8>::with_capacity_chars(10).capacity() >= 40);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity in bytes overflows `usize`.
+    pub fn with_capacity_chars(chars: usize) -> String<E> {
+        String::with_capacity(Self::chars_to_bytes(chars))
+    }
+
+    fn chars_to_bytes(chars: usize) -> usize {
+        chars.checked_mul(E::MAX_LEN).expect("capacity overflow")
+    }
+
+    /// The number of bytes this string can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.1.capacity()
+    }
+
+    /// Reserve capacity for at least `additional` more bytes.
+    pub fn reserve(&mut self, additional: usize) {
+        self.1.reserve(additional)
+    }
+
+    /// Reserve capacity for at least `additional` more characters. As with
+    /// [`String::with_capacity_chars`], this reserves space for the longest character in the
+    /// encoding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity in bytes overflows `usize`.
+    pub fn reserve_chars(&mut self, additional: usize) {
+        self.reserve(Self::chars_to_bytes(additional))
+    }
+
+    /// Try to reserve capacity for at least `additional` more bytes, returning an error instead of
+    /// panicking or aborting if allocation fails.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.1.try_reserve(additional)
+    }
+
+    /// Try to reserve capacity for exactly `additional` more bytes, returning an error instead of
+    /// panicking or aborting if allocation fails.
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.1.try_reserve_exact(additional)
+    }
+
     /// Create a `String` from bytes without checking whether it is valid for the current encoding.
     ///
     /// # Safety
     ///
     /// The bytes passed must be valid for the current encoding.
     pub unsafe fn from_bytes_unchecked(bytes: Vec<u8>) -> String<E> {
+        paranoid_assert!(E::validate(&bytes).is_ok());
         String(PhantomData, bytes)
     }
 
@@ -66,11 +195,54 @@ impl<E: Encoding> String<E> {
         Ok(unsafe { String::from_bytes_unchecked(bytes) })
     }
 
+    /// Create a `String` from bytes, validating the encoding. This never reallocates - the string
+    /// keeps the allocation of `bytes`, and on failure the bytes are returned inside the
+    /// [`OwnValidateError`] so their allocation can be reused.
+    ///
+    /// ```
+    /// # use enrede::{Str, String, encoding::Utf8};
+    /// let err = String::<Utf8>::from_bytes_reuse(vec![0xFF, b'a']).unwrap_err();
+    /// let mut bytes = err.into_vec();
+    /// bytes.clear();
+    /// bytes.extend_from_slice(b"abc");
+    /// assert_eq!(&*String::<Utf8>::from_bytes_reuse(bytes).unwrap(), Str::from_std("abc"));
+    /// ```
+    pub fn from_bytes_reuse(bytes: Vec<u8>) -> Result<String<E>, OwnValidateError> {
+        match E::validate(&bytes) {
+            // SAFETY: Bytes have been validated, they are guaranteed valid for the encoding
+            Ok(()) => Ok(unsafe { String::from_bytes_unchecked(bytes) }),
+            Err(error) => Err(OwnValidateError { bytes, error }),
+        }
+    }
+
+    /// Recode `src` into this encoding, writing into the allocation of `buf`. Any contents of `buf`
+    /// are discarded, and its allocation only grows if the output doesn't fit in its capacity.
+    /// Passing the previous output back in as `buf` converts many strings without allocating once
+    /// the buffer is large enough.
+    ///
+    /// ```
+    /// # use enrede::{Str, String, encoding::{Utf8, Win1252}};
+    /// let mut buf = String::<Win1252>::new();
+    /// for word in ["café", "naïve", "crème"] {
+    ///     buf = String::recode_from_reuse(Str::<Utf8>::from_std(word), buf).unwrap();
+    ///     assert!(buf.chars().eq(word.chars()));
+    /// }
+    /// ```
+    pub fn recode_from_reuse<E2: Encoding>(
+        src: &Str<E2>,
+        buf: String<E>,
+    ) -> Result<String<E>, RecodeError> {
+        match src.recode_partial_in(buf.1) {
+            (out, None) => Ok(out),
+            (_, Some(err)) => Err(err),
+        }
+    }
+
     /// Attempt to convert bytes into a [`Str<E>`]. If any bytes are invalid for the current
     /// encoding, a new `String` will instead be allocated that replaces the invalid bytes with the
     /// replacement character for the encoding.
     pub fn from_bytes_lossy(bytes: &[u8]) -> Cow<'_, Str<E>> {
-        let mut chunks = EncodedChunks::new(bytes);
+        let mut chunks = Str::<E>::chunks(bytes);
 
         let first_valid = if let Some(chunk) = chunks.next() {
             let valid = chunk.valid();
@@ -97,11 +269,62 @@ impl<E: Encoding> String<E> {
         Cow::Owned(res)
     }
 
+    /// Convert bytes into a `String`, replacing any bytes invalid for the current encoding with the
+    /// replacement character for the encoding. Unlike [`String::from_bytes_lossy`], this takes
+    /// ownership of `bytes`, so valid input is used without copying or reallocating.
+    ///
+    /// ```
+    /// # use enrede::{String, encoding::Utf8};
+    /// let bytes = b"Hello".to_vec();
+    /// let ptr = bytes.as_ptr();
+    /// let str = String::<Utf8>::from_bytes_lossy_owned(bytes);
+    /// assert_eq!(str.as_bytes().as_ptr(), ptr);
+    ///
+    /// let str = String::<Utf8>::from_bytes_lossy_owned(b"Hi\xFF".to_vec());
+    /// assert_eq!(str, "Hi\u{FFFD}");
+    /// ```
+    pub fn from_bytes_lossy_owned(bytes: Vec<u8>) -> String<E> {
+        match String::from_bytes_reuse(bytes) {
+            Ok(str) => str,
+            Err(err) => String::from_bytes_lossy(err.as_bytes()).into_owned(),
+        }
+    }
+
+    /// Create a `String` by recoding a [`str`] into this encoding, returning a [`RecodeError`] if
+    /// it contains any characters that can't be represented in this encoding.
+    pub fn from_utf8_str(str: &str) -> Result<String<E>, RecodeError> {
+        Str::from_std(str).recode()
+    }
+
+    /// Create a `String` by recoding a [`str`] into this encoding, replacing any characters that
+    /// can't be represented with the encoding's replacement character.
+    pub fn from_str_lossy(str: &str) -> String<E> {
+        Str::from_std(str).recode_lossy()
+    }
+
     /// Convert this `String` into a vector of its contained bytes
     pub fn into_bytes(self) -> Vec<u8> {
         self.1
     }
 
+    /// Get a mutable reference to the bytes of this `String`. This allows writing pre-validated
+    /// data straight into the string's allocation.
+    ///
+    /// # Safety
+    ///
+    /// The bytes must be valid for the current encoding when the returned reference is dropped.
+    pub unsafe fn as_mut_vec(&mut self) -> &mut Vec<u8> {
+        &mut self.1
+    }
+
+    /// Convert this `String` into a [`Box<Str<E>>`]. This will drop any excess capacity.
+    pub fn into_boxed_str(self) -> Box<Str<E>> {
+        let raw = Box::into_raw(self.1.into_boxed_slice()) as *mut Str<E>;
+        // SAFETY: `Str` is `repr(transparent)` containing a [u8], so has the same layout.
+        //         Our internal bytes are guaranteed valid for the encoding.
+        unsafe { Box::from_raw(raw) }
+    }
+
     /// Add a new character to this string. This method panics if the provided character isn't valid
     /// for the current encoding.
     pub fn push(&mut self, c: char) {
@@ -113,13 +336,193 @@ impl<E: Encoding> String<E> {
     /// Add a new character to this string. This method returns [`InvalidChar`] if the provided
     /// character isn't valid for the current encoding.
     pub fn try_push(&mut self, c: char) -> Result<(), InvalidChar> {
-        self.1.extend(E::encode_char(c).ok_or(InvalidChar)?.slice());
+        if E::ASCII_COMPATIBLE && c.is_ascii() {
+            // ASCII characters are encoded as their own byte, so skip encoding them
+            self.1.push(c as u8);
+            return Ok(());
+        }
+        let bytes = E::encode_char(c).ok_or(InvalidChar { char: c })?;
+        let bytes = bytes.slice();
+        if E::MAX_LEN == 1 {
+            // Single-byte encodings store one byte, without going through a slice copy
+            self.1.push(bytes[0]);
+        } else {
+            self.1.extend_from_slice(bytes);
+        }
         Ok(())
     }
 
     /// Extend this `String` with the contents of the provided [`Str`].
     pub fn push_str(&mut self, str: &Str<E>) {
-        self.1.extend(str.as_bytes());
+        self.1.extend_from_slice(str.as_bytes());
+    }
+
+    /// Add a new character to this string without reallocating. This method returns
+    /// [`PushError::Capacity`] if the string doesn't have enough spare capacity to hold the
+    /// character, and [`PushError::InvalidChar`] if it isn't valid for the current encoding.
+    ///
+    /// ```
+    /// # use enrede::{String, encoding::Utf8, errors::PushError};
+    /// let mut str = String::<Utf8>::new();
+    /// assert_eq!(str.push_within_capacity('a'), Err(PushError::Capacity));
+    /// str.try_reserve(1).unwrap();
+    /// assert_eq!(str.push_within_capacity('a'), Ok(()));
+    /// ```
+    pub fn push_within_capacity(&mut self, c: char) -> Result<(), PushError> {
+        let bytes = E::encode_char(c).ok_or(PushError::InvalidChar)?;
+        let bytes = bytes.slice();
+        if self.1.capacity() - self.1.len() < bytes.len() {
+            return Err(PushError::Capacity);
+        }
+        self.1.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Extend this `String` with the contents of the provided [`Str`] without reallocating. This
+    /// method returns [`CapacityError`] if the string doesn't have enough spare capacity, leaving
+    /// it unchanged.
+    pub fn push_str_within_capacity(&mut self, str: &Str<E>) -> Result<(), CapacityError> {
+        if self.1.capacity() - self.1.len() < str.len() {
+            return Err(CapacityError);
+        }
+        self.1.extend_from_slice(str.as_bytes());
+        Ok(())
+    }
+
+    /// Retain only the characters for which `f` returns `true`, removing all others. This operates
+    /// in place, visiting each character exactly once in order.
+    pub fn retain(&mut self, mut f: impl FnMut(char) -> bool) {
+        // Truncating to the written length on drop means a panic in `f` leaves only the
+        // characters kept so far, rather than an invalid mix of kept and unprocessed bytes.
+        struct SetLenOnDrop<'a> {
+            bytes: &'a mut Vec<u8>,
+            len: usize,
+        }
+
+        impl Drop for SetLenOnDrop<'_> {
+            fn drop(&mut self) {
+                self.bytes.truncate(self.len);
+            }
+        }
+
+        let len = self.1.len();
+        let mut guard = SetLenOnDrop {
+            bytes: &mut self.1,
+            len: 0,
+        };
+        let mut read = 0;
+        while read < len {
+            // SAFETY: `read` is always on a character boundary of the original string, and bytes
+            //         at or after it haven't been modified yet.
+            let rest = unsafe { Str::<E>::from_bytes_unchecked(&guard.bytes[read..]) };
+            let (c, next) = E::decode_char(rest);
+            let char_len = rest.len() - next.len();
+
+            if f(c) {
+                guard.bytes.copy_within(read..read + char_len, guard.len);
+                guard.len += char_len;
+            }
+            read += char_len;
+        }
+    }
+
+    /// Remove the provided range from this string, returning an iterator over the removed
+    /// characters. The range is removed even if the iterator isn't fully consumed.
+    ///
+    /// # Panics
+    ///
+    /// If the start or end of the range don't lie on character boundaries, or are out of bounds.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, E> {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        let drained = &self[range];
+        let start = drained.as_ptr() as usize - self.as_ptr() as usize;
+        let end = start + drained.len();
+        Drain {
+            bytes: &mut self.1,
+            start,
+            pos: start,
+            end,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Split this string in two at the provided byte index, returning the bytes after it as a new
+    /// `String`. This string keeps the bytes before the index, and its capacity.
+    ///
+    /// # Panics
+    ///
+    /// If `at` doesn't lie on a character boundary, or is past the end of the string.
+    pub fn split_off(&mut self, at: usize) -> String<E> {
+        // Slicing checks the index, and panics with the same message as other indexing
+        let _ = &self[at..];
+        let rest = self.1.split_off(at);
+        // SAFETY: `at` is a character boundary, so the bytes after it are valid for the encoding.
+        unsafe { String::from_bytes_unchecked(rest) }
+    }
+
+    /// Convert this `String` into an iterator over its characters, which owns the string data.
+    pub fn into_chars(self) -> IntoChars<E> {
+        IntoChars {
+            bytes: self.1,
+            pos: 0,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Convert this `String` into an iterator over its characters and their byte indices, which
+    /// owns the string data.
+    pub fn into_char_indices(self) -> IntoCharIndices<E> {
+        IntoCharIndices {
+            iter: self.into_chars(),
+        }
+    }
+
+    /// Consume and leak this `String`, returning a mutable reference to its contents. The memory
+    /// will never be freed, including any excess capacity.
+    pub fn leak<'a>(self) -> &'a mut Str<E> {
+        // SAFETY: Our internal bytes are guaranteed valid for the encoding.
+        unsafe { Str::from_bytes_unchecked_mut(self.1.leak()) }
+    }
+}
+
+#[cfg(feature = "unicode-normalization")]
+impl<E: Encoding> String<E> {
+    /// Convert this string into Unicode Normalization Form C. Strings which are already normalized
+    /// are returned as-is, without reallocating.
+    ///
+    /// Normalizing can produce characters not in the original string, which the encoding may not
+    /// be able to represent. If so, an [`InvalidChar`] error is returned - use
+    /// [`String::normalize_nfc_lossy`] to replace them instead.
+    ///
+    /// ```
+    /// # use enrede::{String, encoding::Utf8};
+    /// let str = String::<Utf8>::from("cafe\u{301}").normalize_nfc().unwrap();
+    /// assert_eq!(str, "café");
+    /// ```
+    pub fn normalize_nfc(self) -> Result<String<E>, InvalidChar> {
+        if is_nfc_quick(self.chars()) == IsNormalized::Yes {
+            return Ok(self);
+        }
+        let mut out = String::with_capacity(self.len());
+        for c in self.nfc_chars() {
+            out.try_push(c)?;
+        }
+        Ok(out)
+    }
+
+    /// Convert this string into Unicode Normalization Form C, replacing any characters the
+    /// encoding can't represent after normalizing with the encoding's replacement character.
+    pub fn normalize_nfc_lossy(self) -> String<E> {
+        if is_nfc_quick(self.chars()) == IsNormalized::Yes {
+            return self;
+        }
+        let mut out = String::with_capacity(self.len());
+        for c in self.nfc_chars() {
+            if out.try_push(c).is_err() {
+                out.push(E::REPLACEMENT);
+            }
+        }
+        out
     }
 }
 
@@ -145,6 +548,24 @@ impl<E: AlwaysValid> String<E> {
     }
 }
 
+impl<E: UnicodeComplete> String<E> {
+    /// Create a string containing only a byte order mark, the character `U+FEFF`. Text pushed
+    /// after it will be marked with the byte order of the encoding, as expected by many Windows
+    /// tools.
+    ///
+    /// ```
+    /// # use enrede::{String, Str, encoding::Utf16LE};
+    /// let mut str = String::<Utf16LE>::with_bom();
+    /// str.push_str(&Str::from_std("Hi").recode_infallible());
+    /// assert_eq!(str.as_bytes(), b"\xFF\xFEH\0i\0");
+    /// ```
+    pub fn with_bom() -> String<E> {
+        let mut out = String::new();
+        out.push('\u{FEFF}');
+        out
+    }
+}
+
 impl String<Utf8> {
     /// Convert an [`std::String`](std::string::String) directly into a [`String<Utf8>`]
     pub fn from_std(value: StdString) -> Self {
@@ -159,6 +580,76 @@ impl String<Utf8> {
     }
 }
 
+impl String<Utf16LE> {
+    /// Create a new string from UTF-16 code units. Returns an error if the code units contain an
+    /// unpaired surrogate.
+    pub fn from_code_units<I>(units: I) -> Result<Self, ValidateError>
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        let units = units.into_iter();
+        let mut bytes = Vec::with_capacity(units.size_hint().0 * 2);
+        Utf16LE::units_to_bytes(units, |unit| bytes.extend(unit))?;
+        // SAFETY: Code units have been validated to contain no unpaired surrogates
+        Ok(unsafe { String::from_bytes_unchecked(bytes) })
+    }
+
+    /// Convert this string to big-endian UTF-16 in place, reusing the allocation. This is
+    /// equivalent to recoding the string, but only needs to swap the bytes of each code unit.
+    pub fn swap_to_be(mut self) -> String<Utf16BE> {
+        swap_utf16(&mut self.1);
+        // SAFETY: Swapping the bytes of each code unit turns valid little-endian UTF-16 into
+        //         valid big-endian UTF-16
+        unsafe { String::from_bytes_unchecked(self.1) }
+    }
+}
+
+impl String<Utf16BE> {
+    /// Create a new string from UTF-16 code units. Returns an error if the code units contain an
+    /// unpaired surrogate.
+    pub fn from_code_units<I>(units: I) -> Result<Self, ValidateError>
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        let units = units.into_iter();
+        let mut bytes = Vec::with_capacity(units.size_hint().0 * 2);
+        Utf16BE::units_to_bytes(units, |unit| bytes.extend(unit))?;
+        // SAFETY: Code units have been validated to contain no unpaired surrogates
+        Ok(unsafe { String::from_bytes_unchecked(bytes) })
+    }
+
+    /// Convert this string to little-endian UTF-16 in place, reusing the allocation. This is
+    /// equivalent to recoding the string, but only needs to swap the bytes of each code unit.
+    pub fn swap_to_le(mut self) -> String<Utf16LE> {
+        swap_utf16(&mut self.1);
+        // SAFETY: Swapping the bytes of each code unit turns valid big-endian UTF-16 into
+        //         valid little-endian UTF-16
+        unsafe { String::from_bytes_unchecked(self.1) }
+    }
+}
+
+impl String<Utf32LE> {
+    /// Convert this string to big-endian UTF-32 in place, reusing the allocation. This is
+    /// equivalent to recoding the string, but only needs to swap the bytes of each code unit.
+    pub fn swap_to_be(mut self) -> String<Utf32BE> {
+        swap_utf32(&mut self.1);
+        // SAFETY: Reversing the bytes of each code unit turns valid little-endian UTF-32 into
+        //         valid big-endian UTF-32
+        unsafe { String::from_bytes_unchecked(self.1) }
+    }
+}
+
+impl String<Utf32BE> {
+    /// Convert this string to little-endian UTF-32 in place, reusing the allocation. This is
+    /// equivalent to recoding the string, but only needs to swap the bytes of each code unit.
+    pub fn swap_to_le(mut self) -> String<Utf32LE> {
+        swap_utf32(&mut self.1);
+        // SAFETY: Reversing the bytes of each code unit turns valid big-endian UTF-32 into
+        //         valid little-endian UTF-32
+        unsafe { String::from_bytes_unchecked(self.1) }
+    }
+}
+
 impl<E: Encoding> fmt::Debug for String<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         <Str<E> as fmt::Debug>::fmt(self, f)
@@ -171,6 +662,25 @@ impl<E: Encoding> fmt::Display for String<E> {
     }
 }
 
+impl<E: Encoding> Clone for String<E> {
+    fn clone(&self) -> Self {
+        String(PhantomData, self.1.clone())
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.1.clone_from(&source.1);
+    }
+}
+
+impl<E: Encoding> Mul<usize> for String<E> {
+    type Output = String<E>;
+
+    /// Repeat this string `rhs` times. See [`Str::repeat`].
+    fn mul(self, rhs: usize) -> Self::Output {
+        self.repeat(rhs)
+    }
+}
+
 impl<E: Encoding> Default for String<E> {
     fn default() -> Self {
         String::new()
@@ -185,9 +695,34 @@ impl<E: Encoding> PartialEq for String<E> {
 
 impl<E: Encoding> Eq for String<E> {}
 
+/// Compares characters, as with [`Str`]. No Unicode normalization is performed.
+impl<E: Encoding> PartialEq<str> for String<E> {
+    fn eq(&self, other: &str) -> bool {
+        **self == *other
+    }
+}
+
+impl<E: Encoding> PartialEq<&str> for String<E> {
+    fn eq(&self, other: &&str) -> bool {
+        **self == **other
+    }
+}
+
+impl<E: Encoding> PartialEq<String<E>> for str {
+    fn eq(&self, other: &String<E>) -> bool {
+        **other == *self
+    }
+}
+
+impl<E: Encoding> PartialEq<String<E>> for &str {
+    fn eq(&self, other: &String<E>) -> bool {
+        **other == **self
+    }
+}
+
 impl<E: Encoding> Hash for String<E> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.1.hash(state)
+        (**self).hash(state)
     }
 }
 
@@ -219,6 +754,12 @@ impl<E: Encoding> AsMut<Str<E>> for String<E> {
     }
 }
 
+impl<E: Encoding> AsRef<[u8]> for String<E> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
 impl<E: Encoding> Borrow<Str<E>> for String<E> {
     fn borrow(&self) -> &Str<E> {
         self
@@ -231,12 +772,119 @@ impl<E: Encoding> BorrowMut<Str<E>> for String<E> {
     }
 }
 
+/// Collecting reserves space for the lower bound of the iterator's size hint up front, using
+/// [`String::reserve_chars`].
 impl<E: Encoding> FromIterator<char> for String<E> {
     fn from_iter<T: IntoIterator<Item = char>>(iter: T) -> Self {
-        iter.into_iter().fold(String::new(), |mut acc, c| {
-            acc.push(c);
-            acc
-        })
+        let mut out = String::new();
+        out.extend(iter);
+        out
+    }
+}
+
+impl<E: Encoding> Extend<char> for String<E> {
+    fn extend<T: IntoIterator<Item = char>>(&mut self, iter: T) {
+        let iter = iter.into_iter();
+        self.reserve_chars(iter.size_hint().0);
+        iter.for_each(|c| self.push(c));
+    }
+}
+
+impl<E: Encoding> From<&Str<E>> for String<E> {
+    fn from(value: &Str<E>) -> Self {
+        value.to_owned()
+    }
+}
+
+impl<E: Encoding> From<Cow<'_, Str<E>>> for String<E> {
+    fn from(value: Cow<'_, Str<E>>) -> Self {
+        value.into_owned()
+    }
+}
+
+impl From<&Str<Utf16LE>> for String<Utf16BE> {
+    fn from(value: &Str<Utf16LE>) -> Self {
+        value.to_owned().swap_to_be()
+    }
+}
+
+impl From<&Str<Utf16BE>> for String<Utf16LE> {
+    fn from(value: &Str<Utf16BE>) -> Self {
+        value.to_owned().swap_to_le()
+    }
+}
+
+impl From<&Str<Utf32LE>> for String<Utf32BE> {
+    fn from(value: &Str<Utf32LE>) -> Self {
+        value.to_owned().swap_to_be()
+    }
+}
+
+impl From<&Str<Utf32BE>> for String<Utf32LE> {
+    fn from(value: &Str<Utf32BE>) -> Self {
+        value.to_owned().swap_to_le()
+    }
+}
+
+impl<E: Encoding> From<Box<Str<E>>> for String<E> {
+    fn from(value: Box<Str<E>>) -> Self {
+        value.into_string()
+    }
+}
+
+impl<E: Encoding> From<String<E>> for Box<Str<E>> {
+    fn from(value: String<E>) -> Self {
+        value.into_boxed_str()
+    }
+}
+
+impl<E: Encoding> From<&Str<E>> for Box<Str<E>> {
+    fn from(value: &Str<E>) -> Self {
+        value.to_owned().into_boxed_str()
+    }
+}
+
+impl<E: Encoding> Clone for Box<Str<E>> {
+    fn clone(&self) -> Self {
+        Box::from(&**self)
+    }
+}
+
+impl<E: Encoding> Default for Box<Str<E>> {
+    fn default() -> Self {
+        String::new().into_boxed_str()
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<E: Encoding> From<&Str<E>> for Arc<Str<E>> {
+    fn from(value: &Str<E>) -> Self {
+        let arc = Arc::<[u8]>::from(value.as_bytes());
+        // SAFETY: `Str` is `repr(transparent)` containing a [u8], so has the same layout.
+        //         The bytes are copied from a `Str`, so guaranteed valid for the encoding.
+        unsafe { Arc::from_raw(Arc::into_raw(arc) as *const Str<E>) }
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<E: Encoding> From<String<E>> for Arc<Str<E>> {
+    fn from(value: String<E>) -> Self {
+        Arc::from(&*value)
+    }
+}
+
+impl<E: Encoding> From<&Str<E>> for Rc<Str<E>> {
+    fn from(value: &Str<E>) -> Self {
+        let rc = Rc::<[u8]>::from(value.as_bytes());
+        // SAFETY: `Str` is `repr(transparent)` containing a [u8], so has the same layout.
+        //         The bytes are copied from a `Str`, so guaranteed valid for the encoding.
+        unsafe { Rc::from_raw(Rc::into_raw(rc) as *const Str<E>) }
+    }
+}
+
+impl<E: Encoding> From<String<E>> for Rc<Str<E>> {
+    fn from(value: String<E>) -> Self {
+        Rc::from(&*value)
     }
 }
 
@@ -248,6 +896,39 @@ impl<E: NullTerminable> From<CString<E>> for String<E> {
     }
 }
 
+impl<E: Encoding> TryFrom<Vec<u8>> for String<E> {
+    type Error = OwnValidateError;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        String::from_bytes_reuse(value)
+    }
+}
+
+impl<E: Encoding> TryFrom<&[u8]> for String<E> {
+    type Error = ValidateError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Str::from_bytes(value).map(String::from)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<E: Encoding> defmt::Format for String<E> {
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        <Str<E> as defmt::Format>::format(self, fmt)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl<E: Encoding> ufmt::uDisplay for String<E> {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        <Str<E> as ufmt::uDisplay>::fmt(self, f)
+    }
+}
+
 #[cfg(feature = "serde")]
 impl<E: Encoding> Serialize for String<E> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -275,11 +956,60 @@ impl<'de, E: Encoding> Deserialize<'de> for String<E> {
     }
 }
 
+/// Zeroes the full capacity of the string, leaving it empty. Wrap the string in
+/// [`Zeroizing`](zeroize::Zeroizing) to zero it when dropped.
+#[cfg(feature = "zeroize")]
+impl<E: Encoding> zeroize::Zeroize for String<E> {
+    fn zeroize(&mut self) {
+        self.1.zeroize();
+    }
+}
+
 // Encoding-specific implementations
 
-impl From<&str> for String<Utf8> {
+impl<E: UnicodeComplete> From<&str> for String<E> {
     fn from(value: &str) -> Self {
-        Str::from_std(value).to_owned()
+        Str::from_std(value).recode_infallible()
+    }
+}
+
+/// Implement `TryFrom<&str>` for encodings which can't represent every character. Encodings
+/// implementing [`UnicodeComplete`] get an infallible implementation through `From<&str>`.
+macro_rules! try_from_str_impl {
+    ($($name:ty),* $(,)?) => {
+        $(
+        impl TryFrom<&str> for String<$name> {
+            type Error = RecodeError;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                String::from_utf8_str(value)
+            }
+        }
+        )*
+    };
+}
+
+try_from_str_impl!(
+    Ascii,
+    ExtendedAscii,
+    Big5,
+    Iso8859_2,
+    Iso8859_15,
+    JisX0201,
+    JisX0208,
+    MacRoman,
+    ShiftJis,
+    ShiftJisAscii,
+    Win1251,
+    Win1252,
+    Win1252Loose,
+);
+
+impl<T: SingleByteTable> TryFrom<&str> for String<CustomSingleByte<T>> {
+    type Error = RecodeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        String::from_utf8_str(value)
     }
 }
 
@@ -295,9 +1025,168 @@ impl From<String<Utf8>> for StdString {
     }
 }
 
-#[cfg(test)]
+/// Swap the byte order of every UTF-16 code unit in a buffer
+fn swap_utf16(bytes: &mut [u8]) {
+    for unit in bytes.chunks_exact_mut(2) {
+        unit.swap(0, 1);
+    }
+}
+
+/// Reverse the byte order of every UTF-32 code unit in a buffer
+fn swap_utf32(bytes: &mut [u8]) {
+    for unit in bytes.chunks_exact_mut(4) {
+        unit.reverse();
+    }
+}
+
+/// Iterator over the characters removed from a [`String`] by [`String::drain`]. When dropped, the
+/// drained range is removed from the string.
+pub struct Drain<'a, E> {
+    bytes: &'a mut Vec<u8>,
+    start: usize,
+    pos: usize,
+    end: usize,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Encoding> Drain<'_, E> {
+    /// Get the remaining characters of this iterator as a string slice.
+    pub fn as_str(&self) -> &Str<E> {
+        // SAFETY: `pos` and `end` are always character boundaries of the original string, and the
+        //         bytes between them haven't been modified.
+        unsafe { Str::from_bytes_unchecked(&self.bytes[self.pos..self.end]) }
+    }
+}
+
+impl<E: Encoding> fmt::Debug for Drain<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Drain").field(&self.as_str()).finish()
+    }
+}
+
+impl<E: Encoding> Iterator for Drain<'_, E> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.as_str();
+        if rest.is_empty() {
+            return None;
+        }
+        let (c, next) = E::decode_char(rest);
+        self.pos = self.end - next.len();
+        Some(c)
+    }
+}
+
+impl<E: Encoding> FusedIterator for Drain<'_, E> {}
+
+impl<E> Drop for Drain<'_, E> {
+    fn drop(&mut self) {
+        self.bytes.drain(self.start..self.end);
+    }
+}
+
+/// Owning iterator over the characters of a [`String`]. See [`String::into_chars`].
+pub struct IntoChars<E> {
+    bytes: Vec<u8>,
+    pos: usize,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Encoding> IntoChars<E> {
+    /// Get the remaining characters of this iterator as a string slice.
+    pub fn as_str(&self) -> &Str<E> {
+        // SAFETY: `pos` is always a character boundary of the original string, which we own and
+        //         never modify.
+        unsafe { Str::from_bytes_unchecked(&self.bytes[self.pos..]) }
+    }
+}
+
+impl<E> Clone for IntoChars<E> {
+    fn clone(&self) -> Self {
+        IntoChars {
+            bytes: self.bytes.clone(),
+            pos: self.pos,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: Encoding> fmt::Debug for IntoChars<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoChars").field(&self.as_str()).finish()
+    }
+}
+
+impl<E: Encoding> Iterator for IntoChars<E> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.as_str();
+        if rest.is_empty() {
+            return None;
+        }
+        let (c, next) = E::decode_char(rest);
+        self.pos = self.bytes.len() - next.len();
+        Some(c)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bytes.len() - self.pos;
+        (len.div_ceil(E::MAX_LEN), Some(len))
+    }
+}
+
+impl<E: Encoding> FusedIterator for IntoChars<E> {}
+
+/// Owning iterator over the characters of a [`String`] and their byte indices. See
+/// [`String::into_char_indices`].
+pub struct IntoCharIndices<E> {
+    iter: IntoChars<E>,
+}
+
+impl<E: Encoding> IntoCharIndices<E> {
+    /// Get the remaining characters of this iterator as a string slice.
+    pub fn as_str(&self) -> &Str<E> {
+        self.iter.as_str()
+    }
+}
+
+impl<E> Clone for IntoCharIndices<E> {
+    fn clone(&self) -> Self {
+        IntoCharIndices {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<E: Encoding> fmt::Debug for IntoCharIndices<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoCharIndices")
+            .field(&self.as_str())
+            .finish()
+    }
+}
+
+impl<E: Encoding> Iterator for IntoCharIndices<E> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.iter.pos;
+        Some((idx, self.iter.next()?))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<E: Encoding> FusedIterator for IntoCharIndices<E> {}
+
+#[cfg(test)]
 mod tests {
     use super::*;
+    use crate::encoding::{Utf32BE, Utf32LE};
 
     #[test]
     fn test_from_lossy_utf8() {
@@ -307,11 +1196,713 @@ mod tests {
         );
         assert_eq!(
             String::<Utf8>::from_bytes_lossy(b"Abcd \xD8\xF0\x90\x90\xB7"),
-            Cow::Owned(Str::from_std("Abcd �𐐷").to_owned()),
+            Cow::<Str<Utf8>>::Owned(Str::from_std("Abcd �𐐷").to_owned()),
         );
         assert_eq!(
             String::<Utf8>::from_bytes_lossy(b"A\xD8B\xD9C\xDAD"),
-            Cow::Owned(Str::from_std("A�B�C�D").to_owned()),
+            Cow::<Str<Utf8>>::Owned(Str::from_std("A�B�C�D").to_owned()),
+        );
+    }
+
+    #[cfg(any(debug_assertions, feature = "paranoid-debug"))]
+    #[test]
+    #[should_panic]
+    fn test_paranoid_from_bytes_unchecked() {
+        // SAFETY: Not actually safe - checks that invalid data is caught in paranoid mode
+        let _ = unsafe { String::<Utf8>::from_bytes_unchecked(b"Ab\xD8c".to_vec()) };
+    }
+
+    #[test]
+    fn test_capacity_chars() {
+        let mut string = String::<Utf32LE>::with_capacity_chars(8);
+        assert!(string.capacity() >= 32);
+        string.reserve_chars(16);
+        assert!(string.capacity() >= 64);
+        assert!(String::<Win1252>::with_capacity_chars(8).capacity() >= 8);
+
+        let text = "Ab𐐷c d€";
+        let collected = text.chars().collect::<String<Utf16LE>>();
+        let mut pushed = String::<Utf16LE>::new();
+        text.chars().for_each(|c| pushed.push(c));
+        assert_eq!(collected, pushed);
+
+        let mut extended = String::<Utf8>::from("Ab");
+        extended.extend("𐐷c d€".chars());
+        assert_eq!(extended, text);
+    }
+
+    #[test]
+    #[should_panic = "capacity overflow"]
+    fn test_capacity_chars_overflow() {
+        let _ = String::<Utf32LE>::with_capacity_chars(usize::MAX / 2);
+    }
+
+    #[test]
+    fn test_eq_std() {
+        let string = String::<Win1252>::try_from("naïve €").unwrap();
+        assert_eq!(string, "naïve €");
+        assert_eq!(string, *"naïve €");
+        assert_eq!("naïve €", string);
+        assert_eq!(*"naïve €", string);
+        assert_ne!(string, "naive €");
+
+        let string = String::<Utf8>::from("Ab𐐷c");
+        assert_eq!(string, "Ab𐐷c");
+        assert_ne!(string, "Ab𐐷");
+    }
+
+    #[test]
+    fn test_boxed_str() {
+        let mut string = String::<Utf8>::with_capacity(32);
+        string.push_str(Str::from_std("Abc𐐷d"));
+        let boxed = string.to_owned().into_boxed_str();
+        assert_eq!(&*boxed, &*string);
+
+        let cloned = boxed.clone();
+        assert_eq!(cloned, boxed);
+        assert_eq!(String::from(boxed), string);
+        assert_eq!(cloned.into_string(), string);
+
+        let boxed = Box::<Str<Utf8>>::from(Str::from_std("Hello"));
+        assert_eq!(&*boxed, Str::from_std("Hello"));
+        assert!(Box::<Str<Utf8>>::default().is_empty());
+    }
+
+    #[test]
+    fn test_from_str() {
+        let str = Str::from_std("Abc𐐷d");
+        assert_eq!(String::from(str), str.to_owned());
+        assert_eq!(String::from(Cow::Borrowed(str)), str.to_owned());
+        assert_eq!(
+            String::from(Cow::<Str<Utf8>>::Owned(str.to_owned())),
+            str.to_owned()
+        );
+    }
+
+    #[test]
+    fn test_shared_str() {
+        let str = Str::from_std("Abc𐐷d");
+
+        let arc = Arc::<Str<Utf8>>::from(str);
+        let arc2 = Arc::clone(&arc);
+        assert_eq!(&*arc, str);
+        assert_eq!(arc, arc2);
+        assert_eq!(&*Arc::<Str<Utf8>>::from(str.to_owned()), str);
+
+        let rc = Rc::<Str<Utf8>>::from(str);
+        let rc2 = Rc::clone(&rc);
+        assert_eq!(&*rc, str);
+        assert_eq!(rc, rc2);
+        assert_eq!(&*Rc::<Str<Utf8>>::from(str.to_owned()), str);
+    }
+
+    #[test]
+    fn test_send_sync() {
+        use crate::cstr::CStr;
+        use crate::encoding::{JisX0208, Utf16};
+
+        fn assert_send_sync<T: Send + Sync + ?Sized>() {}
+
+        assert_send_sync::<Str<Utf8>>();
+        assert_send_sync::<&Str<Utf16>>();
+        assert_send_sync::<String<JisX0208>>();
+        assert_send_sync::<Box<Str<Utf8>>>();
+        assert_send_sync::<Arc<Str<Utf8>>>();
+        assert_send_sync::<CStr<Utf8>>();
+        assert_send_sync::<CString<Utf8>>();
+    }
+
+    #[test]
+    fn test_from_code_units() {
+        let text = "Ab𐐷c";
+        let str = String::<Utf16LE>::from_code_units(text.encode_utf16()).unwrap();
+        assert!(str.chars().eq(text.chars()));
+        assert!(str.code_units().eq(text.encode_utf16()));
+        let str = String::<Utf16BE>::from_code_units(text.encode_utf16()).unwrap();
+        assert_eq!(str.as_bytes(), b"\0A\0b\xD8\x01\xDC\x37\0c");
+        assert!(String::<Utf16LE>::from_code_units([]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_from_code_units_unpaired() {
+        // Each case should report the same error as validating the equivalent bytes
+        let cases: [&[u16]; 5] = [
+            &[0x41, 0xDC37, 0x42],
+            &[0x41, 0xD801, 0x42],
+            &[0x41, 0xD801, 0xD801, 0xDC37],
+            &[0x41, 0xD801],
+            &[0xDC37],
+        ];
+        for units in cases {
+            let bytes = units
+                .iter()
+                .flat_map(|u| u.to_le_bytes())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                String::<Utf16LE>::from_code_units(units.iter().copied()),
+                Err(Utf16LE::validate(&bytes).unwrap_err()),
+            );
+        }
+        assert_eq!(
+            String::<Utf16LE>::from_code_units([0x41, 0xD801])
+                .unwrap_err()
+                .valid_up_to(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut str = Str::<Utf16LE>::from_bytes(b"A\0\x01\xD8\x37\xDCb\0\x3D\xD8\x00\xDEc\0")
+            .unwrap()
+            .to_owned();
+        let mut keep = false;
+        str.retain(|_| {
+            keep = !keep;
+            keep
+        });
+        assert!(str.chars().eq("Abc".chars()));
+
+        let mut str = Str::<Utf16LE>::from_bytes(b"A\0\x01\xD8\x37\xDCb\0")
+            .unwrap()
+            .to_owned();
+        str.retain(|c| !c.is_ascii());
+        assert!(str.chars().eq("𐐷".chars()));
+        str.retain(|_| false);
+        assert!(str.is_empty());
+    }
+
+    #[test]
+    fn test_drain() {
+        let mut str = Str::from_std("Ab𐐷cd").to_owned();
+        assert_eq!(str.drain(2..2).count(), 0);
+        assert_eq!(str, Str::from_std("Ab𐐷cd").to_owned());
+
+        let mut drain = str.drain(1..7);
+        assert_eq!(drain.as_str(), Str::from_std("b𐐷c"));
+        assert_eq!(drain.next(), Some('b'));
+        assert_eq!(drain.as_str(), Str::from_std("𐐷c"));
+        assert!(drain.eq(['𐐷', 'c']));
+        assert_eq!(str, Str::from_std("Ad").to_owned());
+
+        let mut str = Str::from_std("Ab𐐷cd").to_owned();
+        assert!(str.drain(2..).eq(['𐐷', 'c', 'd']));
+        assert_eq!(str, Str::from_std("Ab").to_owned());
+    }
+
+    #[test]
+    fn test_drain_dropped() {
+        let mut str = Str::from_std("Ab𐐷cd").to_owned();
+        let mut drain = str.drain(..=5);
+        assert_eq!(drain.next(), Some('A'));
+        drop(drain);
+        assert_eq!(str, Str::from_std("cd").to_owned());
+
+        let mut str = Str::from_std("Ab𐐷cd").to_owned();
+        drop(str.drain(1..6));
+        assert_eq!(str, Str::from_std("Acd").to_owned());
+    }
+
+    #[test]
+    #[should_panic = "byte index 3 is not a char boundary"]
+    fn test_drain_non_boundary() {
+        let mut str = Str::from_std("Ab𐐷cd").to_owned();
+        str.drain(1..3);
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut str = Str::from_std("Ab𐐷cd").to_owned();
+        let rest = str.split_off(2);
+        assert_eq!(str, Str::from_std("Ab").to_owned());
+        assert_eq!(rest, Str::from_std("𐐷cd").to_owned());
+
+        let mut str = Str::from_std("Ab𐐷cd").to_owned();
+        assert_eq!(str.split_off(0), Str::from_std("Ab𐐷cd").to_owned());
+        assert!(str.is_empty());
+
+        let mut str = Str::from_std("Ab𐐷cd").to_owned();
+        assert!(str.split_off(8).is_empty());
+        assert_eq!(str, Str::from_std("Ab𐐷cd").to_owned());
+
+        let mut str = Str::from_std("Ab𐐷cd").recode::<Utf16LE>().unwrap();
+        let rest = str.split_off(4);
+        assert!(str.chars().eq("Ab".chars()));
+        assert!(rest.chars().eq("𐐷cd".chars()));
+    }
+
+    #[test]
+    #[should_panic = "byte index 3 is not a char boundary"]
+    fn test_split_off_non_boundary() {
+        let mut str = Str::from_std("Ab𐐷cd").to_owned();
+        str.split_off(3);
+    }
+
+    #[test]
+    fn test_leak() {
+        let str = Str::from_std("abc").to_owned();
+        let leaked: &'static mut Str<Utf8> = str.leak();
+        leaked.copy_from(Str::from_std("ABC"));
+        assert_eq!(leaked, Str::from_std("ABC"));
+    }
+
+    #[test]
+    fn test_into_chars() {
+        fn chars() -> IntoChars<Utf16LE> {
+            let str = Str::from_std("Ab𐐷cd").recode::<Utf16LE>().unwrap();
+            str.into_chars()
+        }
+
+        assert!(chars().eq("Ab𐐷cd".chars()));
+
+        let mut iter = chars();
+        assert_eq!(iter.next(), Some('A'));
+        assert_eq!(iter.next(), Some('b'));
+        assert!(iter.as_str().chars().eq("𐐷cd".chars()));
+        let cloned = iter.clone();
+        assert_eq!(iter.next(), Some('𐐷'));
+        assert!(iter.as_str().chars().eq("cd".chars()));
+        assert!(cloned.eq("𐐷cd".chars()));
+        assert!(iter.by_ref().eq(['c', 'd']));
+        assert_eq!(iter.next(), None);
+        assert!(iter.as_str().is_empty());
+
+        let str = String::<JisX0208>::from_bytes(b"\x25\x4F \x25\x6D".to_vec()).unwrap();
+        assert!(str.into_chars().eq("ハ ロ".chars()));
+    }
+
+    #[test]
+    fn test_into_char_indices() {
+        let str = Str::from_std("Ab𐐷cd").recode::<Utf16LE>().unwrap();
+        let mut iter = str.into_char_indices();
+        assert_eq!(iter.next(), Some((0, 'A')));
+        assert!(iter.as_str().chars().eq("b𐐷cd".chars()));
+        assert!(iter.eq([(2, 'b'), (4, '𐐷'), (8, 'c'), (10, 'd')]));
+
+        let str = String::<JisX0208>::from_bytes(b"\x25\x4F \x25\x6D".to_vec()).unwrap();
+        assert!(str.into_char_indices().eq([(0, 'ハ'), (2, ' '), (3, 'ロ')]));
+    }
+
+    #[test]
+    fn test_swap_utf16() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0x1616);
+        for _ in 0..100 {
+            let len = rng.gen_range(0..64);
+            let le = (0..len)
+                .map(|_| rng.gen::<char>())
+                .collect::<String<Utf16LE>>();
+
+            let mut out = alloc::vec![0; le.len()];
+            let out_len = Utf16BE::recode(&le, &mut out).unwrap();
+            let be = Str::<Utf16BE>::from_bytes(&out[..out_len]).unwrap();
+
+            assert_eq!(String::<Utf16BE>::from(&*le), be.to_owned());
+            assert_eq!(String::<Utf16LE>::from(be), le);
+            assert_eq!(le.to_owned().swap_to_be(), be.to_owned());
+            assert_eq!(be.to_owned().swap_to_le(), le);
+        }
+    }
+
+    #[test]
+    fn test_swap_utf32() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0x3232);
+        for _ in 0..100 {
+            let len = rng.gen_range(0..64);
+            let le = (0..len)
+                .map(|_| rng.gen::<char>())
+                .collect::<String<Utf32LE>>();
+
+            let mut out = alloc::vec![0; le.len()];
+            let out_len = Utf32BE::recode(&le, &mut out).unwrap();
+            let be = Str::<Utf32BE>::from_bytes(&out[..out_len]).unwrap();
+
+            assert_eq!(String::<Utf32BE>::from(&*le), be.to_owned());
+            assert_eq!(String::<Utf32LE>::from(be), le);
+            assert_eq!(le.to_owned().swap_to_be(), be.to_owned());
+            assert_eq!(be.to_owned().swap_to_le(), le);
+        }
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        use crate::encoding::Win1252;
+
+        let str = String::<Win1252>::try_from("Hello €!").unwrap();
+        assert_eq!(str.as_bytes(), b"Hello \x80!");
+        let err = String::<Win1252>::try_from("Hello 𐐷!").unwrap_err();
+        assert_eq!(err.valid_up_to(), 6);
+        assert_eq!(err.char(), '𐐷');
+        assert_eq!(
+            String::<Win1252>::from_utf8_str("€").unwrap().as_bytes(),
+            b"\x80"
+        );
+    }
+
+    #[test]
+    fn test_try_from_bytes() {
+        use crate::encoding::Ascii;
+
+        let bytes = b"abc".to_vec();
+        let ptr = bytes.as_ptr();
+        let str: String<Utf8> = bytes.try_into().unwrap();
+        assert_eq!(str, "abc");
+        assert_eq!(str.as_bytes().as_ptr(), ptr);
+        let err = String::<Ascii>::try_from(b"ab\xFF".to_vec()).unwrap_err();
+        assert_eq!(err.validate_error().valid_up_to(), 2);
+        assert_eq!(err.into_vec(), b"ab\xFF");
+
+        let str: String<Utf8> = b"abc".as_slice().try_into().unwrap();
+        assert_eq!(str, "abc");
+        let err = String::<Ascii>::try_from(b"ab\xFF".as_slice()).unwrap_err();
+        assert_eq!(err, ValidateError::new(2, Some(1)));
+    }
+
+    #[test]
+    fn test_from_str_infallible() {
+        use crate::encoding::Utf32;
+
+        let text = "Ab𐐷c";
+        assert_eq!(String::<Utf8>::from(text).as_bytes(), text.as_bytes());
+        assert!(String::<Utf16BE>::from(text).chars().eq(text.chars()));
+        assert!(String::<Utf32>::from(text).chars().eq(text.chars()));
+        assert_eq!(
+            Str::from_std(text).recode_infallible::<Utf16LE>(),
+            String::<Utf16LE>::from_code_units(text.encode_utf16()).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_from_str_lossy() {
+        use crate::encoding::Ascii;
+
+        assert_eq!(
+            String::<Ascii>::from_str_lossy("Ab𐐷c").as_bytes(),
+            b"Ab\x1Ac"
+        );
+        assert_eq!(String::<Ascii>::from_str_lossy("Abc").as_bytes(), b"Abc");
+    }
+
+    #[test]
+    fn test_clone() {
+        // Encodings don't implement `Clone`, so this relies on the impl not requiring it
+        let str = String::<Utf16LE>::from("Ab𐐷");
+        let mut cloned = str.clone();
+        assert_eq!(cloned, str);
+        cloned.clone_from(&String::from("Hello"));
+        assert!(cloned.chars().eq("Hello".chars()));
+    }
+
+    /// Generate mostly-valid data with random corruption, including truncated characters
+    fn corrupt_bytes(
+        rng: &mut impl rand::Rng,
+        valid: impl Fn(&mut dyn rand::RngCore) -> Vec<u8>,
+    ) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for _ in 0..rng.gen_range(0..16) {
+            match rng.gen_range(0..4) {
+                0 => bytes.push(rng.gen()),
+                1 => {
+                    // A truncated character
+                    let enc = valid(rng);
+                    bytes.extend(&enc[..rng.gen_range(0..=enc.len())]);
+                }
+                _ => bytes.extend(valid(rng)),
+            }
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_from_lossy_utf8_differential() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0x10551);
+        for _ in 0..2000 {
+            let bytes = corrupt_bytes(&mut rng, |rng| {
+                let c = rng.gen::<char>();
+                c.encode_utf8(&mut [0; 4]).as_bytes().to_vec()
+            });
+            let ours = String::<Utf8>::from_bytes_lossy(&bytes);
+            let std = StdString::from_utf8_lossy(&bytes);
+            assert_eq!(ours.as_std(), &*std, "{:?}", bytes);
+            assert_eq!(
+                matches!(ours, Cow::Borrowed(_)),
+                matches!(std, Cow::Borrowed(_))
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_lossy_alternating() {
+        // An error every other byte is the worst case for lossy decoding. If chunks re-validated
+        // the remaining input each time, this would be quadratic and take far too long.
+        let bytes = (0..1 << 20)
+            .map(|idx| if idx % 2 == 0 { 0xFF } else { b'a' })
+            .collect::<Vec<_>>();
+        let ours = String::<Utf8>::from_bytes_lossy(&bytes);
+        assert_eq!(ours.as_std(), StdString::from_utf8_lossy(&bytes));
+    }
+
+    #[test]
+    fn test_from_lossy_all() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        fn check<E: Encoding>(rng: &mut StdRng) {
+            for _ in 0..300 {
+                let bytes = corrupt_bytes(rng, |rng| loop {
+                    let c = rng.gen_range('\0'..='\u{FFFF}');
+                    if let Some(enc) = E::encode_char(c) {
+                        break enc.slice().to_vec();
+                    }
+                });
+                let lossy = String::<E>::from_bytes_lossy(&bytes);
+
+                // Output is always valid, and keeps everything up to the first error
+                assert!(
+                    E::validate(lossy.as_bytes()).is_ok(),
+                    "{:?} in {}",
+                    bytes,
+                    E::shorthand()
+                );
+                lossy.chars().for_each(drop);
+                match E::validate(&bytes) {
+                    Ok(()) => {
+                        assert!(matches!(lossy, Cow::Borrowed(_)));
+                        assert_eq!(lossy.as_bytes(), bytes);
+                    }
+                    Err(err) => {
+                        assert!(matches!(lossy, Cow::Owned(_)));
+                        let valid = &bytes[..err.valid_up_to()];
+                        assert_eq!(&lossy.as_bytes()[..valid.len()], valid);
+                    }
+                }
+
+                // Replacing errors can't cause new ones
+                assert_eq!(String::<E>::from_bytes_lossy(lossy.as_bytes()), lossy);
+                assert_eq!(*String::<E>::from_bytes_lossy_owned(bytes.clone()), *lossy);
+            }
+        }
+
+        let mut rng = StdRng::seed_from_u64(0x10552);
+        macro_rules! check {
+            ($enc:ty, $name:literal) => {
+                check::<$enc>(&mut rng);
+            };
+        }
+        crate::for_each_encoding!(check);
+    }
+
+    #[test]
+    fn test_as_ref() {
+        fn takes(s: impl AsRef<Str<Utf8>>) -> usize {
+            s.as_ref().len()
+        }
+        fn bytes(s: impl AsRef<[u8]>) -> usize {
+            s.as_ref().len()
+        }
+
+        let str = Str::from_std("Hello");
+        let string = String::from(str);
+        assert_eq!(takes(str), 5);
+        assert_eq!(takes(&string), 5);
+        assert_eq!(takes(Cow::Borrowed(str)), 5);
+        assert_eq!(takes(string.clone()), 5);
+        assert_eq!(bytes(&string), 5);
+        assert_eq!(bytes(string), 5);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize() {
+        use zeroize::{Zeroize, Zeroizing};
+
+        let mut str = String::<Utf8>::with_capacity(32);
+        str.push_str(Str::from_std("hunter2"));
+        let (ptr, cap) = (str.1.as_ptr(), str.1.capacity());
+        str.zeroize();
+        assert!(str.is_empty());
+        assert_eq!((str.1.as_ptr(), str.1.capacity()), (ptr, cap));
+        // SAFETY: Zeroizing initialized the full capacity, and the allocation is unchanged
+        let mem = unsafe { core::slice::from_raw_parts(ptr, cap) };
+        assert!(mem.iter().all(|b| *b == 0));
+
+        let str = Zeroizing::new(String::<Utf8>::from("hunter2"));
+        assert_eq!(str.as_bytes(), b"hunter2");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hash() {
+        use std::collections::HashMap;
+        use std::hash::{BuildHasher, RandomState};
+
+        let state = RandomState::new();
+        let str = Str::from_std("Abc𐐷d");
+        let string = str.to_owned();
+
+        // Consistent with `Borrow`, and with std `str` for UTF-8
+        assert_eq!(state.hash_one(str), state.hash_one(&string));
+        assert_eq!(state.hash_one(str), state.hash_one("Abc𐐷d"));
+
+        let mut map = HashMap::new();
+        map.insert(string, 1);
+        assert_eq!(map.get(str), Some(&1));
+
+        // Hashing a sequence of strings isn't ambiguous about where each string ends
+        let a = [Str::from_std("ab"), Str::from_std("c")];
+        let b = [Str::from_std("a"), Str::from_std("bc")];
+        assert_ne!(state.hash_one(a), state.hash_one(b));
+        assert_ne!(
+            state.hash_one((String::<Utf8>::from("ab"), String::<Utf8>::from(""))),
+            state.hash_one((String::<Utf8>::from(""), String::<Utf8>::from("ab"))),
         );
+        // Including encodings in which `0xFF` is a character
+        let ff = Str::<Win1252>::from_bytes(b"\xFF").unwrap();
+        let empty = Str::<Win1252>::from_bytes(b"").unwrap();
+        assert_ne!(state.hash_one((ff, empty)), state.hash_one((empty, ff)));
+        let ff = Str::<Utf16LE>::from_bytes(b"\xFF\0").unwrap();
+        let empty = Str::<Utf16LE>::from_bytes(b"").unwrap();
+        assert_ne!(state.hash_one((ff, empty)), state.hash_one((empty, ff)));
+    }
+
+    #[test]
+    fn test_with_bom() {
+        let str = String::<Utf16LE>::with_bom();
+        assert_eq!(str.as_bytes(), b"\xFF\xFE");
+        assert!(str.has_bom());
+        assert!(str.strip_bom().is_empty());
+
+        assert_eq!(String::<Utf8>::with_bom(), "\u{FEFF}");
+        assert_eq!(String::<Utf32BE>::with_bom().as_bytes(), b"\0\0\xFE\xFF");
+    }
+
+    #[test]
+    fn test_from_bytes_lossy_owned() {
+        let bytes = b"caf\xE9".to_vec();
+        let (ptr, cap) = (bytes.as_ptr(), bytes.capacity());
+        let str = String::<Win1252>::from_bytes_lossy_owned(bytes);
+        assert_eq!((str.as_bytes().as_ptr(), str.capacity()), (ptr, cap));
+        assert_eq!(str, "café");
+
+        let str = String::<Utf8>::from_bytes_lossy_owned(b"caf\xE9".to_vec());
+        assert_eq!(str, "caf\u{FFFD}");
+        let str = String::<Utf8>::from_bytes_lossy_owned(Vec::new());
+        assert!(str.is_empty());
+    }
+
+    #[test]
+    fn test_as_mut_vec() {
+        let mut str = String::<Utf16LE>::from_code_units([0x61]).unwrap();
+        let record = Str::<Utf16LE>::from_bytes(b"b\0c\0").unwrap();
+        // SAFETY: Appending valid UTF-16 to valid UTF-16 keeps it valid
+        unsafe { str.as_mut_vec() }.extend_from_slice(record.as_bytes());
+        assert_eq!(str, "abc");
+    }
+
+    #[test]
+    fn test_try_reserve() {
+        let mut str = String::<Utf8>::new();
+        str.try_reserve(16).unwrap();
+        assert!(str.capacity() >= 16);
+        str.try_reserve_exact(32).unwrap();
+        assert!(str.capacity() >= 32);
+        assert!(str.try_reserve(usize::MAX).is_err());
+        assert!(str.try_reserve_exact(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn test_push() {
+        let mut str = String::<Win1252>::new();
+        str.push('a');
+        str.push('€');
+        assert_eq!(str.try_push('→'), Err(InvalidChar { char: '→' }));
+        assert_eq!(str.as_bytes(), b"a\x80");
+
+        let mut str = String::<Utf16LE>::new();
+        str.push('a');
+        str.push('𐐷');
+        assert_eq!(str.try_push('b'), Ok(()));
+        assert_eq!(str, "a𐐷b");
+
+        // The ASCII fast path stores the same bytes as encoding each character
+        fn check<E: Encoding>() {
+            let mut str = String::<E>::new();
+            let mut expected = Vec::new();
+            for c in (0..0x80u8).map(char::from) {
+                str.push(c);
+                expected.extend_from_slice(E::encode_char(c).unwrap().slice());
+            }
+            assert_eq!(str.as_bytes(), expected, "{}", E::shorthand());
+        }
+
+        macro_rules! check {
+            ($enc:ty, $name:literal) => {
+                if <$enc>::ASCII_COMPATIBLE {
+                    check::<$enc>();
+                }
+            };
+        }
+        crate::for_each_encoding!(check);
+    }
+
+    #[test]
+    fn test_push_within_capacity() {
+        let mut str = String::<Utf16LE>::new();
+        assert_eq!(str.push_within_capacity('a'), Err(PushError::Capacity));
+        assert!(str.try_reserve(usize::MAX).is_err());
+        str.try_reserve_exact(5).unwrap();
+        let capacity = str.capacity();
+        assert_eq!(str.push_within_capacity('𐐷'), Ok(()));
+        while str.push_within_capacity('a').is_ok() {}
+        assert_eq!(str.push_within_capacity('a'), Err(PushError::Capacity));
+        assert_eq!(str.capacity(), capacity);
+        assert!(str.len() + 2 > capacity);
+
+        let mut str = String::<Win1252>::new();
+        str.try_reserve_exact(2).unwrap();
+        let capacity = str.capacity();
+        assert_eq!(str.push_within_capacity('→'), Err(PushError::InvalidChar));
+        assert_eq!(
+            str.push_str_within_capacity(Str::from_bytes(b"ab").unwrap()),
+            Ok(())
+        );
+        let extra = Str::from_bytes(&[b'c'; 64]).unwrap();
+        assert_eq!(str.push_str_within_capacity(extra), Err(CapacityError));
+        assert_eq!(str.capacity(), capacity);
+        assert_eq!(str.as_bytes(), b"ab");
+    }
+
+    #[test]
+    #[should_panic = "Invalid character '→' for encoding win1252"]
+    fn test_push_invalid() {
+        String::<Win1252>::new().push('→');
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn test_normalize_nfc() {
+        let str = String::<Utf8>::from("cafe\u{301}");
+        assert_eq!(str.normalize_nfc().unwrap(), "café");
+
+        // Already normalized strings keep their allocation
+        let str = String::<MacRoman>::from_bytes(b"caf\x8E".to_vec()).unwrap();
+        let ptr = str.as_bytes().as_ptr();
+        let str = str.normalize_nfc().unwrap();
+        assert_eq!(str.as_bytes().as_ptr(), ptr);
+        assert_eq!(str, "café");
+
+        // The angstrom sign normalizes to 'Å', which JIS X 0208 can't represent
+        let str = String::<JisX0208>::from_bytes(b"\x22\x72".to_vec()).unwrap();
+        assert_eq!(str, "\u{212B}");
+        assert_eq!(str.clone().normalize_nfc(), Err(InvalidChar { char: 'Å' }));
+        assert_eq!(str.normalize_nfc_lossy(), "?");
     }
 }
@@ -0,0 +1,275 @@
+//! A compact, self-describing binary format for strings tagged with their encoding, for storing
+//! strings of any encoding without depending on `serde`.
+//!
+//! Tagged strings are written with [`Str::to_tagged_bytes`], and read back with
+//! [`from_tagged_bytes`]. Reading borrows from the input without copying, so tagged strings can
+//! be read straight out of a memory-mapped cache.
+//!
+//! # Format
+//!
+//! | Offset  | Length | Contents                                                           |
+//! |---------|--------|--------------------------------------------------------------------|
+//! | 0       | 1      | Format version, currently [`VERSION`]                              |
+//! | 1       | 1      | Length `n` of the encoding tag                                     |
+//! | 2       | `n`    | Encoding tag - the ASCII [shorthand](EncodingKind::shorthand) name |
+//! | `2 + n` | rest   | The string's bytes, to the end of the input                        |
+//!
+//! The string runs to the end of the input, so storing several tagged strings together requires
+//! framing them with their lengths. Only built-in encodings can be read back - strings in a
+//! [`CustomSingleByte`](crate::encoding::CustomSingleByte) encoding are written with the tag
+//! `custom`, which is always rejected as unknown.
+//!
+//! This layout is stable. Any incompatible change will use a new version number, and this crate
+//! will continue to read every earlier version.
+//!
+//! ```
+//! # use enrede::{Str, encoding::{EncodingKind, Utf16LE}, tagged::{from_tagged_bytes, TaggedStr}};
+//! let str = Str::<Utf16LE>::from_bytes(b"H\0i\0").unwrap();
+//! let bytes = str.to_tagged_bytes();
+//! assert_eq!(bytes, b"\x01\x07utf16leH\0i\0");
+//!
+//! let tagged = from_tagged_bytes(&bytes).unwrap();
+//! assert_eq!(tagged, TaggedStr::Utf16LE(str));
+//! assert_eq!(tagged.kind(), EncodingKind::Utf16LE);
+//! ```
+//!
+//! [`Str::to_tagged_bytes`]: crate::Str::to_tagged_bytes
+
+use crate::encoding::{Encoding, EncodingKind};
+pub use crate::errors::TaggedError;
+use crate::str::Str;
+
+/// The current version of the tagged string format, written as the first byte of every tagged
+/// string
+pub const VERSION: u8 = 1;
+
+macro_rules! tagged_str {
+    ($($enc:ident),* $(,)?) => {
+        /// A string borrowed from tagged bytes, in the encoding named by its tag. There is one
+        /// variant for each built-in encoding.
+        #[derive(Copy, Clone, Debug, PartialEq)]
+        #[non_exhaustive]
+        pub enum TaggedStr<'a> {
+            $(
+                #[doc = concat!("A string in the [`", stringify!($enc), "`](crate::encoding::", stringify!($enc), ") encoding")]
+                $enc(&'a Str<crate::encoding::$enc>),
+            )*
+        }
+
+        impl<'a> TaggedStr<'a> {
+            /// The encoding of this string
+            pub fn kind(&self) -> EncodingKind {
+                match self {
+                    $(TaggedStr::$enc(_) => EncodingKind::$enc,)*
+                }
+            }
+
+            /// The bytes of this string, without the tag
+            pub fn as_bytes(&self) -> &'a [u8] {
+                match self {
+                    $(TaggedStr::$enc(str) => str.as_bytes(),)*
+                }
+            }
+
+            fn parse(tag: &[u8], bytes: &'a [u8]) -> Result<TaggedStr<'a>, TaggedError> {
+                $(
+                    if tag == EncodingKind::$enc.shorthand().as_bytes() {
+                        return Ok(TaggedStr::$enc(Str::from_bytes(bytes)?));
+                    }
+                )*
+                Err(TaggedError::UnknownEncoding)
+            }
+        }
+    };
+}
+
+tagged_str!(
+    Ascii,
+    ExtendedAscii,
+    Big5,
+    Iso8859_2,
+    Iso8859_15,
+    JisX0201,
+    JisX0208,
+    MacRoman,
+    ShiftJis,
+    ShiftJisAscii,
+    Utf8,
+    Utf16LE,
+    Utf16BE,
+    Utf32LE,
+    Utf32BE,
+    Win1251,
+    Win1252,
+    Win1252Loose,
+);
+
+impl<'a> TaggedStr<'a> {
+    /// Get this string as a [`Str`] in encoding `E`, or [`None`] if it's in a different encoding
+    ///
+    /// ```
+    /// # use enrede::{Str, encoding::{Utf8, Win1252}, tagged::from_tagged_bytes};
+    /// let bytes = Str::from_std("Hello").to_tagged_bytes();
+    /// let tagged = from_tagged_bytes(&bytes).unwrap();
+    /// assert_eq!(tagged.downcast::<Utf8>(), Some(Str::from_std("Hello")));
+    /// assert_eq!(tagged.downcast::<Win1252>(), None);
+    /// ```
+    pub fn downcast<E: Encoding>(&self) -> Option<&'a Str<E>> {
+        if E::KIND == EncodingKind::Custom || E::KIND != self.kind() {
+            return None;
+        }
+        // SAFETY: The bytes were validated for the encoding of this kind, which is `E`
+        Some(unsafe { Str::from_bytes_unchecked(self.as_bytes()) })
+    }
+}
+
+/// Read a string written by [`Str::to_tagged_bytes`](crate::Str::to_tagged_bytes), borrowing its
+/// data from `bytes`. See the [module documentation](self) for the format.
+///
+/// An error is returned if the header is truncated or written in an unsupported version, the
+/// encoding tag doesn't name a built-in encoding, or the string data isn't valid for its encoding.
+pub fn from_tagged_bytes(bytes: &[u8]) -> Result<TaggedStr<'_>, TaggedError> {
+    let (&version, rest) = bytes.split_first().ok_or(TaggedError::Truncated)?;
+    if version != VERSION {
+        return Err(TaggedError::UnsupportedVersion(version));
+    }
+    let (&tag_len, rest) = rest.split_first().ok_or(TaggedError::Truncated)?;
+    let tag_len = usize::from(tag_len);
+    if rest.len() < tag_len {
+        return Err(TaggedError::Truncated);
+    }
+    let (tag, data) = rest.split_at(tag_len);
+    TaggedStr::parse(tag, data)
+}
+
+/// Write the tagged string header for encoding `E` into `out`
+#[cfg(feature = "alloc")]
+pub(crate) fn write_header<E: Encoding>(out: &mut alloc::vec::Vec<u8>) {
+    let tag = E::KIND.shorthand();
+    out.push(VERSION);
+    out.push(tag.len() as u8);
+    out.extend_from_slice(tag.as_bytes());
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::encoding::{Ascii, CustomSingleByte, SingleByteTable, Utf8};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_round_trip() {
+        const TEXT: &str = "Hello, wörld! Привет 日本語 €";
+
+        macro_rules! check {
+            ($enc:ty, $name:literal) => {
+                let bytes = TEXT
+                    .chars()
+                    .filter_map(<$enc>::encode_char)
+                    .flat_map(|c| {
+                        use crate::encoding::ArrayLike;
+                        c.slice().to_vec()
+                    })
+                    .collect::<Vec<_>>();
+                let str = Str::<$enc>::from_bytes(&bytes).unwrap();
+                let tagged_bytes = str.to_tagged_bytes();
+                assert_eq!(tagged_bytes[0], VERSION);
+                assert_eq!(&tagged_bytes[2..2 + $name.len()], $name.as_bytes());
+
+                let tagged = from_tagged_bytes(&tagged_bytes).unwrap();
+                assert_eq!(tagged.kind(), <$enc>::KIND, "{}", $name);
+                assert_eq!(tagged.as_bytes(), str.as_bytes());
+                assert_eq!(tagged.downcast::<$enc>(), Some(str));
+            };
+        }
+        crate::for_each_encoding!(check);
+    }
+
+    #[test]
+    fn test_downcast_other() {
+        let bytes = Str::from_std("abc").to_tagged_bytes();
+        let tagged = from_tagged_bytes(&bytes).unwrap();
+        assert_eq!(tagged, TaggedStr::Utf8(Str::from_std("abc")));
+        assert_eq!(tagged.downcast::<Ascii>(), None);
+    }
+
+    #[test]
+    fn test_custom() {
+        struct Bytes;
+
+        impl SingleByteTable for Bytes {
+            const NAME: &'static str = "utf8";
+            const TABLE: [char; 256] = {
+                let mut table = ['\0'; 256];
+                let mut idx = 0;
+                while idx < 256 {
+                    table[idx] = idx as u8 as char;
+                    idx += 1;
+                }
+                table
+            };
+        }
+
+        // Custom encodings are tagged by kind rather than name, so can't be confused with a
+        // built-in encoding
+        let str = Str::<CustomSingleByte<Bytes>>::from_bytes(b"abc").unwrap();
+        let bytes = str.to_tagged_bytes();
+        assert_eq!(bytes, b"\x01\x06customabc");
+        assert_eq!(from_tagged_bytes(&bytes), Err(TaggedError::UnknownEncoding));
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert_eq!(from_tagged_bytes(b""), Err(TaggedError::Truncated));
+        assert_eq!(from_tagged_bytes(b"\x01"), Err(TaggedError::Truncated));
+        assert_eq!(
+            from_tagged_bytes(b"\x01\x04utf"),
+            Err(TaggedError::Truncated)
+        );
+        assert_eq!(
+            from_tagged_bytes(b"\x00\x04utf8"),
+            Err(TaggedError::UnsupportedVersion(0))
+        );
+        assert_eq!(
+            from_tagged_bytes(b"\x02\x04utf8"),
+            Err(TaggedError::UnsupportedVersion(2))
+        );
+        assert_eq!(
+            from_tagged_bytes(b"\x01\x04UTF8"),
+            Err(TaggedError::UnknownEncoding)
+        );
+        assert_eq!(
+            from_tagged_bytes(b"\x01\x03utf8"),
+            Err(TaggedError::UnknownEncoding)
+        );
+        assert_eq!(
+            from_tagged_bytes(b"\x01\x06custom"),
+            Err(TaggedError::UnknownEncoding)
+        );
+        assert!(matches!(
+            from_tagged_bytes(b"\x01\x04utf8\xFF"),
+            Err(TaggedError::Invalid(_))
+        ));
+        assert_eq!(
+            from_tagged_bytes(b"\x01\x04utf8"),
+            Ok(TaggedStr::Utf8(Str::from_std("")))
+        );
+    }
+
+    #[test]
+    fn test_truncations() {
+        // Every prefix of a valid tagged string either fails cleanly or reads a shorter string
+        let bytes = Str::from_std("日本").to_tagged_bytes();
+        for len in 0..bytes.len() {
+            match from_tagged_bytes(&bytes[..len]) {
+                Err(TaggedError::Truncated) => assert!(len < 6),
+                Ok(tagged) => assert_eq!(
+                    tagged.downcast::<Utf8>().unwrap().as_bytes(),
+                    &bytes[6..len]
+                ),
+                Err(TaggedError::Invalid(_)) => assert!(len > 6),
+                Err(err) => panic!("unexpected error {err:?} for length {len}"),
+            }
+        }
+    }
+}
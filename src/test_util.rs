@@ -0,0 +1,277 @@
+//! Deliberately tiny encodings, for testing code which is generic over [`Encoding`].
+//!
+//! The built-in encodings all have large, irregular sets of valid characters, making it hard to
+//! exercise cases such as "a character this encoding can't represent" or "a character longer
+//! than two bytes" without depending on the details of a real encoding. These encodings are
+//! simple enough to reason about by hand.
+//!
+//! **This module is unstable.** It's only available with the `test-util` feature, and may change
+//! in any release without being considered a breaking change. Don't use these encodings for real
+//! data - they don't correspond to any real-world format.
+//!
+//! ```
+//! # use enrede::{Str, test_util::{TestAB, TestWide}};
+//! // Only 'a' and 'b' are encodable, so anything else fails
+//! assert!(Str::from_std("abba").recode::<TestAB>().is_ok());
+//! assert!(Str::from_std("abc").recode::<TestAB>().is_err());
+//!
+//! // Non-ASCII characters take three bytes
+//! let str = Str::from_std("a\u{E9}").recode::<TestWide>().unwrap();
+//! assert_eq!(str.as_bytes(), b"a\xC0\x83\xA9");
+//! ```
+
+use crate::encoding::sealed::Sealed;
+use crate::encoding::{EncodingKind, NullTerminable, ValidateError};
+use crate::{Encoding, Str};
+use arrayvec::ArrayVec;
+#[cfg(feature = "rand")]
+use rand::{distributions::Distribution, Rng};
+
+/// A single-byte encoding in which only `'a'` and `'b'` are valid, encoded as their ASCII bytes.
+/// Every other byte, including null, is invalid.
+///
+/// With only two characters available, lossy conversions replace invalid data with `'b'`.
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TestAB;
+
+impl Sealed for TestAB {}
+
+impl Encoding for TestAB {
+    const REPLACEMENT: char = 'b';
+    const MAX_LEN: usize = 1;
+    type Bytes = u8;
+    const KIND: EncodingKind = EncodingKind::Custom;
+
+    fn shorthand() -> &'static str {
+        "test_ab"
+    }
+
+    fn name() -> &'static str {
+        "Test AB"
+    }
+
+    fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
+        match bytes.iter().position(|b| !matches!(b, b'a' | b'b')) {
+            Some(idx) => Err(ValidateError::new(idx, Some(1))),
+            None => Ok(()),
+        }
+    }
+
+    fn encode_char(c: char) -> Option<Self::Bytes> {
+        matches!(c, 'a' | 'b').then_some(c as u8)
+    }
+
+    fn decode_char(str: &Str<Self>) -> (char, &Str<Self>) {
+        (str.as_bytes()[0] as char, &str[1..])
+    }
+
+    fn char_bound(_: &Str<Self>, _: usize) -> bool {
+        true
+    }
+
+    fn char_len(c: char) -> Option<usize> {
+        Self::encode_char(c).map(|_| 1)
+    }
+}
+
+impl NullTerminable for TestAB {}
+
+#[cfg(feature = "rand")]
+impl Distribution<char> for TestAB {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        if rng.gen() {
+            'a'
+        } else {
+            'b'
+        }
+    }
+}
+
+/// Lead byte of a three-byte [`TestWide`] character
+const WIDE_LEAD: u8 = 0xC0;
+
+/// Whether a byte is a trailing byte of a three-byte [`TestWide`] character
+fn is_wide_trail(b: u8) -> bool {
+    (0x80..0xC0).contains(&b)
+}
+
+/// A variable-width encoding of the characters `U+0000` to `U+0FFF`. ASCII characters are single
+/// bytes, and all others are three bytes - `0xC0`, then two bytes in `0x80..=0xBF` holding the
+/// high and low six bits of the character. Characters which fit in a single byte can't also be
+/// written in three.
+///
+/// Trailing bytes are never valid on their own, so character boundaries can be found from any
+/// position, and null bytes only appear as the null character.
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TestWide;
+
+impl Sealed for TestWide {}
+
+impl Encoding for TestWide {
+    const REPLACEMENT: char = '?';
+    const MAX_LEN: usize = 3;
+    type Bytes = ArrayVec<u8, 3>;
+    const KIND: EncodingKind = EncodingKind::Custom;
+
+    fn shorthand() -> &'static str {
+        "test_wide"
+    }
+
+    fn name() -> &'static str {
+        "Test wide"
+    }
+
+    fn validate(bytes: &[u8]) -> Result<(), ValidateError> {
+        let mut idx = 0;
+        while idx < bytes.len() {
+            let error_len = match bytes[idx..] {
+                [b, ..] if b < 0x80 => {
+                    idx += 1;
+                    continue;
+                }
+                // The high bits must be at least 2, or the character fits in a single byte
+                [WIDE_LEAD, 0x82..0xC0, low, ..] if is_wide_trail(low) => {
+                    idx += 3;
+                    continue;
+                }
+                [WIDE_LEAD, 0x82..0xC0, _, ..] => Some(2),
+                // A valid start at the end of the input is incomplete, rather than invalid
+                [WIDE_LEAD, 0x82..0xC0] | [WIDE_LEAD] => None,
+                _ => Some(1),
+            };
+            return Err(ValidateError::new(idx, error_len));
+        }
+        Ok(())
+    }
+
+    fn encode_char(c: char) -> Option<Self::Bytes> {
+        let c = c as u32;
+        if c < 0x80 {
+            Some(ArrayVec::from_iter([c as u8]))
+        } else if c < 0x1000 {
+            let high = 0x80 | (c >> 6) as u8;
+            let low = 0x80 | (c & 0x3F) as u8;
+            Some(ArrayVec::from([WIDE_LEAD, high, low]))
+        } else {
+            None
+        }
+    }
+
+    fn decode_char(str: &Str<Self>) -> (char, &Str<Self>) {
+        match *str.as_bytes() {
+            [WIDE_LEAD, high, low, ..] => {
+                let c = (u32::from(high & 0x3F) << 6) | u32::from(low & 0x3F);
+                (char::from_u32(c).unwrap(), &str[3..])
+            }
+            [b, ..] => (char::from(b), &str[1..]),
+            [] => unreachable!(),
+        }
+    }
+
+    fn char_bound(str: &Str<Self>, idx: usize) -> bool {
+        str.as_bytes().get(idx).is_none_or(|&b| !is_wide_trail(b))
+    }
+
+    fn char_len(c: char) -> Option<usize> {
+        match c as u32 {
+            0..0x80 => Some(1),
+            0x80..0x1000 => Some(3),
+            _ => None,
+        }
+    }
+}
+
+impl NullTerminable for TestWide {}
+
+#[cfg(feature = "rand")]
+impl Distribution<char> for TestWide {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        // There are no surrogates below `U+D800`, so every value is a character
+        char::from_u32(rng.gen_range(0..0x1000)).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CStr;
+
+    #[test]
+    fn test_ab() {
+        assert_eq!(TestAB::validate(b"abba"), Ok(()));
+        assert_eq!(
+            TestAB::validate(b"abc"),
+            Err(ValidateError::new(2, Some(1)))
+        );
+        assert_eq!(TestAB::validate(b"\0"), Err(ValidateError::new(0, Some(1))));
+
+        for c in ['\0', 'A', 'c', '\u{FFFD}'] {
+            assert_eq!(TestAB::encode_char(c), None, "{c:?}");
+            assert_eq!(TestAB::char_len(c), None, "{c:?}");
+        }
+        let str = Str::<TestAB>::from_bytes(b"ab").unwrap();
+        assert!(str.chars().eq(['a', 'b']));
+
+        let cstr = CStr::<TestAB>::from_bytes_with_nul(b"ba\0").unwrap();
+        assert_eq!(cstr.as_str(), Str::from_bytes(b"ba").unwrap());
+    }
+
+    #[test]
+    fn test_wide_validate() {
+        assert_eq!(TestWide::validate(b""), Ok(()));
+        assert_eq!(TestWide::validate(b"a\0\xC0\x83\xA9\xC0\xBF\xBF"), Ok(()));
+
+        // Lone and misplaced trailing bytes
+        assert_eq!(
+            TestWide::validate(b"a\x80"),
+            Err(ValidateError::new(1, Some(1)))
+        );
+        assert_eq!(
+            TestWide::validate(b"\xC1"),
+            Err(ValidateError::new(0, Some(1)))
+        );
+        // Overlong forms of single-byte characters
+        assert_eq!(
+            TestWide::validate(b"\xC0\x81\xBF"),
+            Err(ValidateError::new(0, Some(1)))
+        );
+        // Invalid or missing low bytes
+        assert_eq!(
+            TestWide::validate(b"\xC0\x83a"),
+            Err(ValidateError::new(0, Some(2)))
+        );
+        assert_eq!(
+            TestWide::validate(b"\xC0\x83\xC0"),
+            Err(ValidateError::new(0, Some(2)))
+        );
+        assert_eq!(
+            TestWide::validate(b"a\xC0\x83"),
+            Err(ValidateError::new(1, None))
+        );
+        assert_eq!(
+            TestWide::validate(b"a\xC0"),
+            Err(ValidateError::new(1, None))
+        );
+    }
+
+    #[test]
+    fn test_wide_chars() {
+        for c in ['\0', 'a', '\u{7F}', '\u{80}', '\u{E9}', '\u{FFF}'] {
+            let bytes = TestWide::encode_char(c).unwrap();
+            assert_eq!(TestWide::char_len(c), Some(bytes.len()), "{c:?}");
+            let str = Str::<TestWide>::from_bytes(&bytes).unwrap();
+            assert!(str.chars().eq([c]), "{c:?}");
+        }
+        assert_eq!(TestWide::encode_char('\u{1000}'), None);
+        assert_eq!(TestWide::char_len('\u{1000}'), None);
+
+        let str = Str::<TestWide>::from_bytes(b"a\xC0\x83\xA9b").unwrap();
+        let bounds = (0..=str.len())
+            .filter(|&idx| str.is_char_boundary(idx))
+            .collect::<alloc::vec::Vec<_>>();
+        assert_eq!(bounds, [0, 1, 4, 5]);
+        assert_eq!(&str[1..4], Str::from_bytes(b"\xC0\x83\xA9").unwrap());
+    }
+}
@@ -1,17 +1,11 @@
-use core::ops::{Bound, RangeBounds, RangeFrom, RangeFull};
-
-pub trait RangeOpen<T> {
-    fn start_bound(&self) -> Bound<&T>;
-}
-
-impl<T> RangeOpen<T> for RangeFrom<T> {
-    fn start_bound(&self) -> Bound<&T> {
-        <Self as RangeBounds<T>>::start_bound(self)
-    }
+/// Assert that an internal invariant holds. This is checked in debug builds, and also in release
+/// builds when the `paranoid-debug` feature is enabled.
+macro_rules! paranoid_assert {
+    ($($arg:tt)*) => {
+        if cfg!(any(debug_assertions, feature = "paranoid-debug")) {
+            assert!($($arg)*);
+        }
+    };
 }
 
-impl<T> RangeOpen<T> for RangeFull {
-    fn start_bound(&self) -> Bound<&T> {
-        <Self as RangeBounds<T>>::start_bound(self)
-    }
-}
+pub(crate) use paranoid_assert;
@@ -0,0 +1,351 @@
+//! Implementation and utilities for wide C strings, terminated by a null code unit rather than a
+//! single null byte.
+//!
+//! See also the [`WideCStr<E>`] type.
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use bytemuck::must_cast_slice as cast_slice;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::{fmt, mem, ptr};
+
+use crate::cstr::{FromBytesUntilNulError, FromBytesWithNulError};
+#[cfg(feature = "alloc")]
+use crate::cstring::NulError;
+use crate::encoding::{Utf16Native, ValidateError, WideNullTerminable};
+use crate::str::Str;
+#[cfg(feature = "alloc")]
+use crate::string::String;
+use crate::utils::paranoid_assert;
+
+/// Find the byte index of the first null code unit in `bytes`. Only whole, aligned code units are
+/// checked, so null bytes which are part of other characters are skipped.
+fn find_nul<E: WideNullTerminable>(bytes: &[u8]) -> Option<usize> {
+    bytes
+        .chunks_exact(E::UNIT_LEN)
+        .position(|unit| unit.iter().all(|b| *b == 0))
+        .map(|pos| pos * E::UNIT_LEN)
+}
+
+/// A wide C-string slice, representing an encoded string with a single null code unit at the end.
+/// This is normally represented in C as a `wchar_t*`, and is the usual form of string for Windows
+/// APIs.
+///
+/// Encodings such as UTF-16 use null bytes to encode common characters, so can't be used with
+/// [`CStr`](crate::CStr). Instead, these strings are terminated by a whole code unit of zeros - two
+/// bytes for UTF-16, or four for UTF-32. The [`WideNullTerminable`] trait is implemented by all
+/// encodings that may be used in wide C strings.
+///
+/// Like [`CStr`](crate::CStr), this type implements `Deref` to [`Str<E>`], which doesn't include
+/// the terminating null.
+#[repr(transparent)]
+pub struct WideCStr<E>(PhantomData<E>, [u8]);
+
+impl<E: WideNullTerminable> WideCStr<E> {
+    /// Create a `WideCStr` from a byte slice without checking whether it is valid for the current
+    /// encoding, or whether it ends with a terminating null code unit.
+    ///
+    /// # Safety
+    ///
+    /// The bytes passed must be valid for the current encoding, and contain a single null code unit
+    /// at the end.
+    pub unsafe fn from_bytes_with_nul_unchecked(bytes: &[u8]) -> &WideCStr<E> {
+        paranoid_assert!(bytes.len() >= E::UNIT_LEN);
+        paranoid_assert!(E::validate(&bytes[..bytes.len() - E::UNIT_LEN]).is_ok());
+        paranoid_assert!(find_nul::<E>(bytes) == Some(bytes.len() - E::UNIT_LEN));
+        let ptr = ptr::from_ref(bytes) as *const WideCStr<E>;
+        // SAFETY: `WideCStr` is `repr(transparent)` containing a [u8].
+        //         Provided bytes have precondition of being valid encoding
+        unsafe { &*ptr }
+    }
+
+    /// Create a `WideCStr` from a byte slice, ending at the first null code unit. If there are no
+    /// null code units in the slice, or the data up till the first null isn't valid in the current
+    /// encoding, then an error will be returned.
+    ///
+    /// Only code units aligned to the start of the slice are checked, so null bytes which are part
+    /// of a character, such as the high byte of ASCII characters in UTF-16, don't end the string.
+    /// Data *past* the first null code unit isn't validated.
+    ///
+    /// ```
+    /// # use enrede::{WideCStr, encoding::Utf16LE};
+    /// let str = WideCStr::<Utf16LE>::from_bytes_until_nul(b"H\0i\0\0\0rest").unwrap();
+    /// assert_eq!(str.as_str(), "Hi");
+    /// ```
+    pub fn from_bytes_until_nul(bytes: &[u8]) -> Result<&WideCStr<E>, FromBytesUntilNulError> {
+        let nul = find_nul::<E>(bytes).ok_or(FromBytesUntilNulError::MissingNull)?;
+        E::validate(&bytes[..nul]).map_err(FromBytesUntilNulError::Invalid)?;
+        // SAFETY: End position is the location of the first null code unit, prior bytes have been
+        //         validated for the encoding.
+        Ok(unsafe { WideCStr::from_bytes_with_nul_unchecked(&bytes[..nul + E::UNIT_LEN]) })
+    }
+
+    /// Create a `WideCStr` from a byte slice, with a single null code unit at the end. If there is
+    /// no null code unit at the end, or there are null code units at any other position in the
+    /// slice, an error is returned. An error will also be returned if the data isn't valid in the
+    /// current encoding.
+    pub fn from_bytes_with_nul(bytes: &[u8]) -> Result<&WideCStr<E>, FromBytesWithNulError> {
+        let Some(len) = bytes.len().checked_sub(E::UNIT_LEN) else {
+            return Err(FromBytesWithNulError::MissingNull);
+        };
+        if bytes[len..].iter().any(|b| *b != 0) {
+            return Err(FromBytesWithNulError::MissingNull);
+        }
+        let slice = &bytes[..len];
+        E::validate(slice).map_err(FromBytesWithNulError::Invalid)?;
+        if let Some(idx) = find_nul::<E>(slice) {
+            return Err(FromBytesWithNulError::HasNull { idx });
+        }
+        // SAFETY: End position validated to be a null code unit and the only one, prior bytes have
+        //         been validated for the encoding.
+        Ok(unsafe { WideCStr::from_bytes_with_nul_unchecked(bytes) })
+    }
+
+    /// Get the underlying bytes for this string, including the terminating null code unit.
+    pub fn as_bytes_with_nul(&self) -> &[u8] {
+        &self.1
+    }
+
+    /// Convert this `WideCStr` into a [`Str`], not including the terminating null code unit.
+    ///
+    /// Note that this method should rarely be needed, as `WideCStr` implements `Deref` into
+    /// [`Str`].
+    pub fn as_str(&self) -> &Str<E> {
+        // This is the impl of `Deref` - no using `Str` methods.
+        let bytes = self.as_bytes_with_nul();
+        // SAFETY: Our internal bytes are guaranteed valid for the encoding.
+        unsafe { Str::from_bytes_unchecked(&bytes[..bytes.len() - E::UNIT_LEN]) }
+    }
+}
+
+impl WideCStr<Utf16Native> {
+    /// Create a `WideCStr` from UTF-16 code units, ending at the first null code unit. See
+    /// [`WideCStr::from_bytes_until_nul`].
+    ///
+    /// Strings created this way are always aligned for `u16`, so can be passed to
+    /// [`WideCStr::as_ptr`].
+    pub fn from_utf16_until_nul(units: &[u16]) -> Result<&Self, FromBytesUntilNulError> {
+        Self::from_bytes_until_nul(cast_slice(units))
+    }
+
+    /// Create a `WideCStr` from a pointer to null-terminated UTF-16 code units, such as one
+    /// returned by a Windows API. Returns an error if the data before the null isn't valid UTF-16.
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` must be non-null, aligned, and point to a sequence of `u16` ending with a zero.
+    /// - The memory up to and including the zero must be valid for reads, and not be mutated for
+    ///   the lifetime `'a`.
+    /// - The lifetime `'a` is chosen by the caller, and must not outlive the pointed-to data.
+    pub unsafe fn from_ptr<'a>(ptr: *const u16) -> Result<&'a Self, ValidateError> {
+        let mut len = 0;
+        // SAFETY: Precondition that the data up to the first zero is valid for reads
+        while unsafe { *ptr.add(len) } != 0 {
+            len += 1;
+        }
+        // SAFETY: Precondition that the data is valid for reads and not mutated for `'a`. `len` is
+        //         the position of the zero, so the slice includes it.
+        let units = unsafe { core::slice::from_raw_parts(ptr, len + 1) };
+        Str::<Utf16Native>::from_utf16(&units[..len])?;
+        // SAFETY: Data before the first null code unit has been validated, and the slice ends with
+        //         that null.
+        Ok(unsafe { Self::from_bytes_with_nul_unchecked(cast_slice(units)) })
+    }
+
+    /// Get a pointer to the code units of this string, suitable for passing to native code
+    /// expecting a `wchar_t*` on Windows. The pointer is valid for as long as the `WideCStr`.
+    ///
+    /// # Panics
+    ///
+    /// If the string isn't aligned for `u16`. This can only happen if it was created from a
+    /// misaligned byte slice - strings created from `[u16]` are always aligned.
+    pub fn as_ptr(&self) -> *const u16 {
+        let ptr = self.1.as_ptr();
+        assert!(
+            ptr.cast::<u16>().is_aligned(),
+            "WideCStr data isn't aligned for u16"
+        );
+        ptr.cast()
+    }
+}
+
+impl<E: WideNullTerminable> fmt::Debug for WideCStr<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "w")?;
+        <Str<E> as fmt::Debug>::fmt(self, f)
+    }
+}
+
+impl<E: WideNullTerminable> Default for &WideCStr<E> {
+    fn default() -> Self {
+        // SAFETY: Empty string slice can never be invalid. A code unit is at most four bytes, so
+        //         this is a single null code unit.
+        unsafe { WideCStr::from_bytes_with_nul_unchecked(&[0; 4][..E::UNIT_LEN]) }
+    }
+}
+
+impl<E: WideNullTerminable> PartialEq for WideCStr<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.1 == other.1
+    }
+}
+
+impl<E: WideNullTerminable> Eq for WideCStr<E> {}
+
+impl<E: WideNullTerminable> PartialEq<Str<E>> for WideCStr<E> {
+    fn eq(&self, other: &Str<E>) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<E: WideNullTerminable> Deref for WideCStr<E> {
+    type Target = Str<E>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<E: WideNullTerminable> AsRef<Str<E>> for WideCStr<E> {
+    fn as_ref(&self) -> &Str<E> {
+        self.as_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: WideNullTerminable> From<&WideCStr<E>> for String<E> {
+    fn from(value: &WideCStr<E>) -> Self {
+        String::from(value.as_str())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: WideNullTerminable> From<Box<WideCStr<E>>> for String<E> {
+    fn from(value: Box<WideCStr<E>>) -> Self {
+        let raw = Box::into_raw(value) as *mut [u8];
+        // SAFETY: `WideCStr` is `repr(transparent)` containing a [u8], so has the same layout.
+        let mut bytes = Vec::from(unsafe { Box::from_raw(raw) });
+        bytes.truncate(bytes.len() - E::UNIT_LEN);
+        // SAFETY: The bytes before the terminator are guaranteed valid for the encoding
+        unsafe { String::from_bytes_unchecked(bytes) }
+    }
+}
+
+/// Convert a string into a boxed wide C string by appending a null code unit. Fails if the string
+/// contains a null character.
+#[cfg(feature = "alloc")]
+impl<E: WideNullTerminable> TryFrom<String<E>> for Box<WideCStr<E>> {
+    type Error = NulError;
+
+    fn try_from(value: String<E>) -> Result<Self, Self::Error> {
+        let mut bytes = value.into_bytes();
+        if let Some(nul_pos) = find_nul::<E>(&bytes) {
+            return Err(NulError { bytes, nul_pos });
+        }
+        bytes.resize(bytes.len() + E::UNIT_LEN, 0);
+        let raw = Box::into_raw(bytes.into_boxed_slice()) as *mut WideCStr<E>;
+        // SAFETY: `WideCStr` is `repr(transparent)` containing a [u8], so has the same layout. The
+        //         data is valid for the encoding with no null code units, followed by a null.
+        Ok(unsafe { Box::from_raw(raw) })
+    }
+}
+
+const _: () = assert!(mem::size_of::<&WideCStr<Utf16Native>>() == mem::size_of::<&[u8]>());
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::{Utf16BE, Utf16LE, Utf32LE};
+
+    #[test]
+    fn test_until_nul_skips_null_bytes() {
+        // 'A' and U+0100 both contain null bytes, and together have two null bytes in a row, but
+        // not as an aligned code unit
+        let bytes = b"A\0\0\x01\0\0B\0";
+        let str = WideCStr::<Utf16LE>::from_bytes_until_nul(bytes).unwrap();
+        assert_eq!(str.as_str(), "A\u{100}");
+        assert_eq!(str.as_bytes_with_nul(), b"A\0\0\x01\0\0");
+
+        let str = WideCStr::<Utf16BE>::from_bytes_until_nul(b"\0A\x01\0\0\0").unwrap();
+        assert_eq!(str.as_str(), "A\u{100}");
+
+        let str = WideCStr::<Utf32LE>::from_bytes_until_nul(b"A\0\0\0\0\0\0\0").unwrap();
+        assert_eq!(str.as_str(), "A");
+
+        assert_eq!(
+            WideCStr::<Utf16LE>::from_bytes_until_nul(b"A\0\0\x01"),
+            Err(FromBytesUntilNulError::MissingNull)
+        );
+        // A trailing odd byte can't form a code unit
+        assert_eq!(
+            WideCStr::<Utf16LE>::from_bytes_until_nul(b"A\0\0"),
+            Err(FromBytesUntilNulError::MissingNull)
+        );
+        assert_eq!(
+            WideCStr::<Utf16LE>::from_bytes_until_nul(b"\x00\xDC\0\0"),
+            Err(FromBytesUntilNulError::Invalid(ValidateError::new(
+                0,
+                Some(2)
+            )))
+        );
+    }
+
+    #[test]
+    fn test_with_nul() {
+        let str = WideCStr::<Utf16LE>::from_bytes_with_nul(b"A\0\0\x01\0\0").unwrap();
+        assert_eq!(str.as_str(), "A\u{100}");
+        let str = WideCStr::<Utf16LE>::from_bytes_with_nul(b"\0\0").unwrap();
+        assert!(str.is_empty());
+
+        assert_eq!(
+            WideCStr::<Utf16LE>::from_bytes_with_nul(b"A\0\0\x01"),
+            Err(FromBytesWithNulError::MissingNull)
+        );
+        assert_eq!(
+            WideCStr::<Utf16LE>::from_bytes_with_nul(b"\0"),
+            Err(FromBytesWithNulError::MissingNull)
+        );
+        assert_eq!(
+            WideCStr::<Utf16LE>::from_bytes_with_nul(b"A\0\0\0B\0\0\0"),
+            Err(FromBytesWithNulError::HasNull { idx: 2 })
+        );
+        assert_eq!(
+            WideCStr::<Utf16LE>::from_bytes_with_nul(b"A\0\0\0\0"),
+            Err(FromBytesWithNulError::Invalid(ValidateError::new(2, None)))
+        );
+    }
+
+    #[test]
+    fn test_utf16_units() {
+        let units = [b'H' as u16, 0x100, 0, b'x' as u16];
+        let str = WideCStr::from_utf16_until_nul(&units).unwrap();
+        assert_eq!(str.as_str(), "H\u{100}");
+        assert_eq!(str.as_ptr(), units.as_ptr());
+
+        // SAFETY: Pointer is to a zero-terminated array which outlives the result
+        let str = unsafe { WideCStr::from_ptr(units.as_ptr()) }.unwrap();
+        assert_eq!(str.as_str(), "H\u{100}");
+
+        let units = [0xDC00, 0];
+        // SAFETY: Pointer is to a zero-terminated array which outlives the result
+        let err = unsafe { WideCStr::from_ptr(units.as_ptr()) }.unwrap_err();
+        assert_eq!(err, ValidateError::new(0, Some(2)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_string_conversions() {
+        let string = String::<Utf16LE>::from("Hi\u{100}");
+        let boxed = Box::<WideCStr<Utf16LE>>::try_from(string.clone()).unwrap();
+        assert_eq!(boxed.as_bytes_with_nul(), b"H\0i\0\0\x01\0\0");
+        assert_eq!(String::from(&*boxed), string);
+        assert_eq!(String::from(boxed), string);
+
+        let err = Box::<WideCStr<Utf16LE>>::try_from(String::from("a\0b")).unwrap_err();
+        assert_eq!(err.nul_position(), 2);
+        assert_eq!(err.into_vec(), b"a\0\0\0b\0");
+    }
+}
@@ -0,0 +1,193 @@
+//! Checks that the buffer-reusing `String` constructors and clones don't allocate once their buffer
+//! is large enough, and that building strings by character reserves space up front.
+
+#![cfg(feature = "alloc")]
+
+use enrede::encoding::{ArrayLike, Utf16LE, Utf32LE, Utf8, Win1252};
+use enrede::{CStr, CString, Encoding, Str, String};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Global allocator counting allocations made on the current thread, so tests running in
+/// parallel don't affect each other
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn count() {
+    let _ = ALLOCATIONS.try_with(|a| a.set(a.get() + 1));
+}
+
+// SAFETY: Forwards directly to the system allocator
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count();
+        // SAFETY: Same preconditions as our caller
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: Same preconditions as our caller
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count();
+        // SAFETY: Same preconditions as our caller
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count();
+        // SAFETY: Same preconditions as our caller
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let out = f();
+    (out, ALLOCATIONS.with(Cell::get) - before)
+}
+
+const WORDS: &[&str] = &[
+    "café",
+    "naïve",
+    "crème brûlée",
+    "",
+    "façade",
+    "déjà vu",
+    "a",
+];
+
+#[test]
+fn test_recode_from_reuse() {
+    let mut buf = String::<Win1252>::with_capacity(64);
+    for _ in 0..100 {
+        for word in WORDS {
+            let src = Str::<Utf8>::from_std(word);
+            let (out, allocs) = allocations(|| String::recode_from_reuse(src, buf).unwrap());
+            assert_eq!(allocs, 0, "recoding {word:?} allocated");
+            assert!(out.chars().eq(word.chars()));
+            buf = out;
+        }
+    }
+}
+
+#[test]
+fn test_recode_from_reuse_grows() {
+    let mut buf = String::<Utf16LE>::new();
+    let src = Str::<Utf8>::from_std("a longer string, which needs space to grow into");
+    let (out, allocs) = allocations(|| String::recode_from_reuse(src, buf).unwrap());
+    assert_ne!(allocs, 0);
+    assert!(out.chars().eq(src.chars()));
+    buf = out;
+
+    // Once grown, the buffer is large enough for every later conversion
+    for word in WORDS {
+        let src = Str::<Utf8>::from_std(word);
+        let (out, allocs) = allocations(|| String::recode_from_reuse(src, buf).unwrap());
+        assert_eq!(allocs, 0, "recoding {word:?} allocated");
+        assert!(out.chars().eq(word.chars()));
+        buf = out;
+    }
+}
+
+#[test]
+fn test_recode_from_reuse_invalid() {
+    let buf = String::<Win1252>::with_capacity(64);
+    let err = String::recode_from_reuse(Str::<Utf8>::from_std("ab€c☃d"), buf).unwrap_err();
+    assert_eq!(err.valid_up_to(), 6);
+    assert_eq!(err.char(), '☃');
+}
+
+#[test]
+fn test_from_bytes_reuse() {
+    let mut bytes = Vec::with_capacity(64);
+    for _ in 0..100 {
+        for word in WORDS {
+            bytes.extend_from_slice(word.as_bytes());
+            let (str, allocs) = allocations(|| String::<Utf8>::from_bytes_reuse(bytes).unwrap());
+            assert_eq!(allocs, 0, "validating {word:?} allocated");
+            assert_eq!(&*str, Str::from_std(word));
+            bytes = str.into_bytes();
+            bytes.clear();
+        }
+    }
+
+    bytes.extend_from_slice(b"ab\xFFc");
+    let (err, allocs) = allocations(|| String::<Utf8>::from_bytes_reuse(bytes).unwrap_err());
+    assert_eq!(allocs, 0);
+    assert_eq!(err.validate_error().valid_up_to(), 2);
+    assert_eq!(err.as_bytes(), b"ab\xFFc");
+    assert_eq!(err.into_vec().capacity(), 64);
+}
+
+#[test]
+fn test_collect_allocations() {
+    fn check<E: Encoding>(c: char) {
+        let chars = core::iter::repeat_n(c, 100_000);
+        let (str, allocs) = allocations(|| chars.collect::<String<E>>());
+        assert_eq!(allocs, 1, "{}", E::shorthand());
+        assert_eq!(
+            str.len(),
+            100_000 * E::encode_char(c).unwrap().slice().len()
+        );
+
+        let mut str = String::<E>::new();
+        let (_, allocs) = allocations(|| str.extend(core::iter::repeat_n(c, 1000)));
+        assert_eq!(allocs, 1, "{}", E::shorthand());
+    }
+
+    check::<Utf8>('𐐷');
+    check::<Utf16LE>('𐐷');
+    check::<Utf32LE>('a');
+    check::<Win1252>('€');
+}
+
+#[test]
+fn test_clone_into() {
+    let mut buf = String::<Utf8>::with_capacity(64);
+    for word in WORDS {
+        let src = Str::<Utf8>::from_std(word);
+        let (_, allocs) = allocations(|| src.clone_into(&mut buf));
+        assert_eq!(allocs, 0, "cloning {word:?} allocated");
+        assert_eq!(buf, src.to_owned());
+        assert_eq!(buf.capacity(), 64);
+    }
+
+    let mut buf = CString::<Utf8>::default();
+    CStr::from_bytes_with_nul(b"a long enough string\0")
+        .unwrap()
+        .clone_into(&mut buf);
+    for word in WORDS {
+        let src = CString::new(String::<Utf8>::from(*word)).unwrap();
+        let (_, allocs) = allocations(|| (*src).clone_into(&mut buf));
+        assert_eq!(allocs, 0, "cloning {word:?} allocated");
+        assert_eq!(buf, (*src).to_owned());
+        assert_eq!(buf.as_bytes_with_nul(), src.as_bytes_with_nul());
+    }
+}
+
+#[test]
+fn test_clone_from() {
+    let mut buf = String::<Utf16LE>::with_capacity(64);
+    let mut cbuf = CString::<Win1252>::new(&b"a long enough string"[..]).unwrap();
+    for word in WORDS {
+        let src = String::<Utf16LE>::from(*word);
+        let (_, allocs) = allocations(|| buf.clone_from(&src));
+        assert_eq!(allocs, 0, "cloning {word:?} allocated");
+        assert_eq!(buf, src.clone());
+
+        let src = CString::new(String::<Win1252>::try_from(*word).unwrap()).unwrap();
+        let (_, allocs) = allocations(|| cbuf.clone_from(&src));
+        assert_eq!(allocs, 0, "cloning {word:?} allocated");
+        assert_eq!(cbuf, src.clone());
+        assert_eq!(cbuf.as_bytes_with_nul(), src.as_bytes_with_nul());
+    }
+}
@@ -0,0 +1,60 @@
+//! Checks that encoding detection ranks fixture files in their real encoding first.
+
+#![cfg(feature = "detect")]
+
+use enrede::detect::{guess_encoding, DEFAULT_CANDIDATES};
+use enrede::encoding::EncodingKind;
+
+const SHIFTJIS: &[u8] = include_bytes!("fixtures/shiftjis.txt");
+const WIN1251: &[u8] = include_bytes!("fixtures/win1251.txt");
+const UTF16LE: &[u8] = include_bytes!("fixtures/utf16le.txt");
+const UTF8: &[u8] = include_bytes!("fixtures/utf8.txt");
+const UTF16BE: &[u8] = include_bytes!("fixtures/utf16be.txt");
+
+#[track_caller]
+fn check_first(bytes: &[u8], expected: EncodingKind) {
+    let ranked = guess_encoding(bytes, DEFAULT_CANDIDATES);
+    assert_eq!(ranked.len(), DEFAULT_CANDIDATES.len());
+    assert_eq!(ranked[0].0, expected, "{ranked:?}");
+    assert!(ranked[0].1 > ranked[1].1, "{ranked:?}");
+}
+
+#[test]
+fn test_fixtures_ranked_first() {
+    check_first(SHIFTJIS, EncodingKind::ShiftJis);
+    check_first(WIN1251, EncodingKind::Win1251);
+    check_first(UTF16LE, EncodingKind::Utf16LE);
+    check_first(UTF8, EncodingKind::Utf8);
+}
+
+#[test]
+fn test_utf16_endianness() {
+    // Skip the byte order mark, so only the null byte positions tell the endianness apart
+    let be = &UTF16BE[2..];
+    check_first(be, EncodingKind::Utf16BE);
+
+    let le = be
+        .chunks(2)
+        .flat_map(|unit| [unit[1], unit[0]])
+        .collect::<Vec<_>>();
+    check_first(&le, EncodingKind::Utf16LE);
+}
+
+#[test]
+fn test_ties_keep_candidate_order() {
+    let candidates = [EncodingKind::Win1252, EncodingKind::Utf8];
+    let ranked = guess_encoding(b"Plain ASCII text\n", &candidates);
+    assert_eq!(
+        ranked,
+        [(EncodingKind::Win1252, 1.0), (EncodingKind::Utf8, 1.0)]
+    );
+
+    let ranked = guess_encoding(b"", DEFAULT_CANDIDATES);
+    assert!(ranked.iter().map(|(kind, _)| kind).eq(DEFAULT_CANDIDATES));
+}
+
+#[test]
+fn test_custom_skipped() {
+    let ranked = guess_encoding(b"abc", &[EncodingKind::Custom, EncodingKind::Ascii]);
+    assert_eq!(ranked, [(EncodingKind::Ascii, 1.0)]);
+}
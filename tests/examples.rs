@@ -0,0 +1,139 @@
+//! End-to-end tests of the example programs, run against the fixture files in `tests/fixtures`.
+
+#![cfg(feature = "std")]
+
+#[path = "../examples/inspect.rs"]
+#[allow(dead_code)]
+mod inspect;
+#[path = "../examples/transcode.rs"]
+#[allow(dead_code)]
+mod transcode;
+
+use enrede::encoding::ValidateError;
+use inspect::{inspect, Report};
+use transcode::{transcode, Error, Transcoded};
+
+const WIN1251: &[u8] = include_bytes!("fixtures/win1251.txt");
+const SHIFTJIS: &[u8] = include_bytes!("fixtures/shiftjis.txt");
+const UTF16BE: &[u8] = include_bytes!("fixtures/utf16be.txt");
+
+const RUSSIAN: &str = "Привет, мир!\nЭто тестовый файл.\n";
+const JAPANESE: &str = "セーブデータを読み込んでいます。\nしばらくお待ちください。\n";
+const MIXED: &str = "Hello, 世界! Ünïcödé 𐐷\n";
+
+fn to_utf8(input: &[u8], from: Option<&str>) -> Result<String, Error> {
+    let out = transcode(input, from, "utf8", false)?;
+    Ok(String::from_utf8(out.bytes).unwrap())
+}
+
+#[test]
+fn test_transcode_to_utf8() {
+    assert_eq!(to_utf8(WIN1251, Some("win1251")).unwrap(), RUSSIAN);
+    assert_eq!(to_utf8(SHIFTJIS, Some("shiftjis")).unwrap(), JAPANESE);
+    // The byte order mark is detected and skipped
+    assert_eq!(to_utf8(UTF16BE, None).unwrap(), MIXED);
+    assert_eq!(to_utf8(UTF16BE, Some("utf16be")).unwrap(), MIXED);
+}
+
+#[test]
+fn test_transcode_from_utf8() {
+    let out = transcode(RUSSIAN.as_bytes(), None, "win1251", false).unwrap();
+    assert_eq!(
+        out,
+        Transcoded {
+            from: "utf8",
+            bytes: WIN1251.to_vec(),
+        }
+    );
+    let out = transcode(JAPANESE.as_bytes(), Some("utf8"), "shiftjis", false).unwrap();
+    assert_eq!(out.bytes, SHIFTJIS);
+}
+
+#[test]
+fn test_transcode_invalid() {
+    // Without a byte order mark, input is assumed to be UTF-8
+    assert_eq!(
+        to_utf8(WIN1251, None),
+        Err(Error::Invalid {
+            encoding: "utf8",
+            offset: 0,
+            len: Some(1),
+        })
+    );
+    // Offsets include the skipped byte order mark
+    assert_eq!(
+        to_utf8(&UTF16BE[..UTF16BE.len() - 1], None),
+        Err(Error::Invalid {
+            encoding: "utf16be",
+            offset: UTF16BE.len() - 2,
+            len: None,
+        })
+    );
+}
+
+#[test]
+fn test_transcode_unencodable() {
+    assert_eq!(
+        transcode(SHIFTJIS, Some("shiftjis"), "win1251", false),
+        Err(Error::Unencodable {
+            encoding: "win1251",
+            offset: 0,
+            char: 'セ',
+        })
+    );
+    assert_eq!(
+        transcode(UTF16BE, None, "win1252", false),
+        Err(Error::Unencodable {
+            encoding: "win1252",
+            offset: 2 + 2 * "Hello, ".len(),
+            char: '世',
+        })
+    );
+
+    let out = transcode(UTF16BE, None, "win1252", true).unwrap();
+    // Win1252 replaces unencodable characters with the substitute control character
+    let expected = MIXED.replace(['世', '界', '𐐷'], "\x1A");
+    assert_eq!(
+        out.bytes,
+        expected.chars().map(|c| c as u8).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_transcode_unknown() {
+    assert_eq!(
+        transcode(WIN1251, Some("cp1251"), "utf8", false),
+        Err(Error::UnknownEncoding("cp1251".to_owned()))
+    );
+    assert_eq!(
+        transcode(WIN1251, Some("win1251"), "latin1", false),
+        Err(Error::UnknownEncoding("latin1".to_owned()))
+    );
+}
+
+#[test]
+fn test_inspect() {
+    let report = inspect(WIN1251, "win1251").unwrap().unwrap();
+    assert_eq!(
+        report,
+        Report {
+            chars: RUSSIAN.chars().count(),
+            invalid: 0,
+            first_invalid: None,
+        }
+    );
+
+    let report = inspect(SHIFTJIS, "shiftjis").unwrap().unwrap();
+    assert_eq!(report.chars, JAPANESE.chars().count());
+    assert_eq!(report.invalid, 0);
+
+    // The byte order mark decodes as a character
+    let report = inspect(UTF16BE, "utf16be").unwrap().unwrap();
+    assert_eq!(report.chars, MIXED.chars().count() + 1);
+
+    let report = inspect(WIN1251, "utf8").unwrap().unwrap();
+    assert_eq!(report.first_invalid, Some(ValidateError::new(0, Some(1))));
+    assert!(report.invalid > 0);
+
+    assert!(inspect(WIN1251, "cp1251").is_none());
+}
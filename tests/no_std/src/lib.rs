@@ -0,0 +1,73 @@
+//! Build check that `enrede` is usable from a `#![no_std]` crate. This isn't run, only built for a
+//! target without `std`, so any use of `std` in the library breaks the build:
+//!
+//! ```text
+//! cargo build --manifest-path tests/no_std/Cargo.toml --target thumbv7em-none-eabihf
+//! cargo build --manifest-path tests/no_std/Cargo.toml --target thumbv7em-none-eabihf --features alloc
+//! ```
+//!
+//! Each width class of encoding is touched - single-byte, variable-width, and fixed-width UTF-16
+//! and UTF-32 - along with the borrowed string types and their iterators.
+
+#![no_std]
+
+use enrede::encoding::{Ascii, NullTerminable, ShiftJis, Utf16LE, Utf32BE, Utf8, Win1252};
+use enrede::{ArrayString, CStr, Encoding, Str};
+
+/// Count the characters in `bytes`, or `None` if they aren't valid for `E`
+pub fn count_chars<E: Encoding>(bytes: &[u8]) -> Option<usize> {
+    Str::<E>::from_bytes(bytes)
+        .ok()
+        .map(|str| str.chars().count())
+}
+
+/// Find the byte offset of the last character in `bytes`
+pub fn last_char_offset<E: Encoding>(bytes: &[u8]) -> Option<usize> {
+    let str = Str::<E>::from_bytes(bytes).ok()?;
+    str.char_indices().last().map(|(idx, _)| idx)
+}
+
+/// Count the characters in a null-terminated string, not including the null
+pub fn c_str_len<E: NullTerminable>(bytes: &[u8]) -> Option<usize> {
+    let str = CStr::<E>::from_bytes_with_nul(bytes).ok()?;
+    Some(str.chars().count())
+}
+
+/// Recode a string into a fixed-capacity buffer, without allocating
+pub fn recode_inline<E: Encoding, E2: Encoding>(bytes: &[u8]) -> Option<ArrayString<E2, 32>> {
+    let str = Str::<E>::from_bytes(bytes).ok()?;
+    let mut out = ArrayString::new();
+    for c in str.chars() {
+        out.try_push(c).ok()?;
+    }
+    Some(out)
+}
+
+/// Exercise every check, so none of them are unused
+pub fn check() -> bool {
+    count_chars::<Ascii>(b"abc") == Some(3)
+        && count_chars::<Win1252>(b"\x80") == Some(1)
+        && count_chars::<Utf8>("日本".as_bytes()) == Some(2)
+        && last_char_offset::<ShiftJis>(b"a\x93\xFA") == Some(1)
+        && last_char_offset::<Utf16LE>(b"a\0b\0") == Some(2)
+        && last_char_offset::<Utf32BE>(b"\0\0\0a") == Some(0)
+        && c_str_len::<Utf8>(b"abc\0") == Some(3)
+        && recode_inline::<Win1252, Utf16LE>(b"\x80").is_some()
+        && alloc_check()
+}
+
+#[cfg(feature = "alloc")]
+fn alloc_check() -> bool {
+    extern crate alloc;
+
+    use alloc::borrow::ToOwned;
+    use enrede::CString;
+
+    let str = Str::from_std("abc").to_owned();
+    CString::new(&str).is_ok() && str.recode::<Utf16LE>().is_ok()
+}
+
+#[cfg(not(feature = "alloc"))]
+fn alloc_check() -> bool {
+    true
+}
@@ -0,0 +1,582 @@
+//! Property tests checking the core invariants of every built-in encoding.
+
+#![cfg(feature = "alloc")]
+
+use enrede::encoding::{ArrayLike, EncodeError, Encoding};
+use enrede::{Str, String};
+use proptest::prelude::*;
+use proptest::sample::select;
+use proptest::test_runner::TestRunner;
+
+/// Every character the encoding can represent
+fn encodable<E: Encoding>() -> Vec<char> {
+    (0..=0x10FFFF)
+        .filter_map(char::from_u32)
+        .filter(|c| E::encode_char(*c).is_some())
+        .collect()
+}
+
+fn encode<E: Encoding>(chars: &[char]) -> Vec<u8> {
+    chars
+        .iter()
+        .flat_map(|c| E::encode_char(*c).unwrap().slice().to_vec())
+        .collect()
+}
+
+/// Invoke `$check::<E>()` for every built-in encoding, and the `test_util` encodings if enabled
+macro_rules! check_all {
+    ($check:ident) => {{
+        macro_rules! check_one {
+            ($enc:ty, $name:literal) => {
+                $check::<$enc>();
+            };
+        }
+        enrede::for_each_encoding!(check_one);
+        #[cfg(feature = "test-util")]
+        {
+            $check::<enrede::test_util::TestAB>();
+            $check::<enrede::test_util::TestWide>();
+        }
+    }};
+}
+
+/// Encoding any encodable characters produces valid data, which decodes back to the same
+/// characters
+#[test]
+fn encode_validates() {
+    fn check<E: Encoding>() {
+        let chars = encodable::<E>();
+        TestRunner::default()
+            .run(&prop::collection::vec(select(chars), 0..64), |chars| {
+                let bytes = encode::<E>(&chars);
+                prop_assert_eq!(E::validate(&bytes), Ok(()), "{}", E::shorthand());
+                let str = Str::<E>::from_bytes(&bytes).unwrap();
+                prop_assert_eq!(str.chars().collect::<Vec<_>>(), chars);
+                Ok(())
+            })
+            .unwrap();
+    }
+    check_all!(check);
+}
+
+/// Character boundaries reported by the encoding are exactly the positions where `char_indices`
+/// starts a character, plus the end of the string
+#[test]
+fn char_bound_matches_char_indices() {
+    fn check<E: Encoding>() {
+        let chars = encodable::<E>();
+        TestRunner::default()
+            .run(&prop::collection::vec(select(chars), 0..64), |chars| {
+                let bytes = encode::<E>(&chars);
+                let str = Str::<E>::from_bytes(&bytes).unwrap();
+                let starts = str.char_indices().map(|(idx, _)| idx).collect::<Vec<_>>();
+                for idx in 0..str.len() {
+                    prop_assert_eq!(
+                        E::char_bound(str, idx),
+                        starts.contains(&idx),
+                        "{} {} {:?}",
+                        E::shorthand(),
+                        idx,
+                        bytes
+                    );
+                }
+                prop_assert!(str.is_char_boundary(str.len()));
+                prop_assert!(!str.is_char_boundary(str.len() + 1));
+                Ok(())
+            })
+            .unwrap();
+    }
+    check_all!(check);
+}
+
+/// Character spans tile the string with no gaps or overlaps, each holding exactly the bytes of its
+/// character, and agree with `char_indices` and the iterators' offsets
+#[test]
+fn char_spans_tile_string() {
+    fn check<E: Encoding>() {
+        let chars = encodable::<E>();
+        TestRunner::default()
+            .run(&prop::collection::vec(select(chars), 0..64), |chars| {
+                let bytes = encode::<E>(&chars);
+                let str = Str::<E>::from_bytes(&bytes).unwrap();
+                let mut spans = str.char_spans();
+                let mut indices = str.char_indices();
+                let mut end = 0;
+                for &c in &chars {
+                    prop_assert_eq!(spans.offset(), end);
+                    prop_assert_eq!(indices.offset(), end);
+                    let (span, span_c) = spans.next().unwrap();
+                    prop_assert_eq!(indices.next(), Some((span.start, c)));
+                    prop_assert_eq!(span_c, c);
+                    prop_assert_eq!(span.start, end, "{}", E::shorthand());
+                    let encoded = E::encode_char(c).unwrap();
+                    prop_assert_eq!(&bytes[span.clone()], encoded.slice(), "{}", E::shorthand());
+                    end = span.end;
+                }
+                prop_assert_eq!(spans.next(), None);
+                prop_assert_eq!(spans.offset(), str.len());
+                prop_assert_eq!(end, str.len());
+                Ok(())
+            })
+            .unwrap();
+    }
+    check_all!(check);
+}
+
+/// Every character either encodes to bytes which decode back to it, with a matching `char_len`,
+/// or doesn't encode and has no `char_len`. This is exhaustive for non-Unicode encodings.
+#[test]
+fn encode_decode_char() {
+    fn check_char<E: Encoding>(c: char) -> Result<(), TestCaseError> {
+        match E::encode_char(c) {
+            Some(bytes) => {
+                let bytes = bytes.slice();
+                prop_assert_eq!(
+                    E::char_len(c),
+                    Some(bytes.len()),
+                    "{} {:?}",
+                    E::shorthand(),
+                    c
+                );
+                let str = Str::<E>::from_bytes(bytes);
+                prop_assert!(str.is_ok(), "{} {:?}", E::shorthand(), c);
+                prop_assert!(
+                    str.unwrap().chars().eq([c]),
+                    "{} {:?} {:?}",
+                    E::shorthand(),
+                    c,
+                    bytes
+                );
+            }
+            None => prop_assert_eq!(E::char_len(c), None, "{} {:?}", E::shorthand(), c),
+        }
+        Ok(())
+    }
+
+    fn check<E: Encoding>() {
+        if E::UNICODE.is_some() {
+            TestRunner::default()
+                .run(&any::<char>(), check_char::<E>)
+                .unwrap();
+        } else {
+            (0..=0x10FFFF)
+                .filter_map(char::from_u32)
+                .try_for_each(check_char::<E>)
+                .unwrap();
+        }
+    }
+    check_all!(check);
+}
+
+/// `char_len` agrees with `encode_char` for every character in the Basic Multilingual Plane, and a
+/// sample of characters beyond it, so length calculations never disagree with what can be written
+#[test]
+fn char_len_agrees() {
+    fn check_char<E: Encoding>(c: char) -> Result<(), TestCaseError> {
+        let len = E::encode_char(c).map(|bytes| bytes.slice().len());
+        prop_assert_eq!(E::char_len(c), len, "{} {:?}", E::shorthand(), c);
+        prop_assert!(!E::FIXED_LEN || len.is_none_or(|len| len == E::MAX_LEN));
+        Ok(())
+    }
+
+    fn check<E: Encoding>() {
+        (0..0x10000)
+            .filter_map(char::from_u32)
+            .try_for_each(check_char::<E>)
+            .unwrap();
+        TestRunner::default()
+            .run(
+                &prop::char::range('\u{10000}', '\u{10FFFF}'),
+                check_char::<E>,
+            )
+            .unwrap();
+    }
+    check_all!(check);
+}
+
+/// `encode` writes the same bytes as `encode_char`, and reports the space it needs when the output
+/// is too small
+#[test]
+fn encode_agrees() {
+    fn check_char<E: Encoding>(c: char) -> Result<(), TestCaseError> {
+        let mut out = [0xFF; 8];
+        match E::encode_char(c) {
+            Some(bytes) => {
+                let bytes = bytes.slice();
+                prop_assert_eq!(E::encode(c, &mut out), Ok(bytes.len()));
+                prop_assert_eq!(&out[..bytes.len()], bytes, "{} {:?}", E::shorthand(), c);
+                let short = &mut out[..bytes.len() - 1];
+                prop_assert_eq!(
+                    E::encode(c, short),
+                    Err(EncodeError::NeedSpace { len: bytes.len() })
+                );
+            }
+            None => prop_assert_eq!(E::encode(c, &mut out), Err(EncodeError::InvalidChar)),
+        }
+        Ok(())
+    }
+
+    fn check<E: Encoding>() {
+        (0..0x10000)
+            .filter_map(char::from_u32)
+            .try_for_each(check_char::<E>)
+            .unwrap();
+        TestRunner::default()
+            .run(
+                &prop::char::range('\u{10000}', '\u{10FFFF}'),
+                check_char::<E>,
+            )
+            .unwrap();
+    }
+    check_all!(check);
+}
+
+/// Any bytes which validate can be decoded, and re-encoding the characters produces the same
+/// characters again. Where an encoding maps multiple byte sequences to one character, the bytes
+/// may differ, but re-encoding a second time must then be stable.
+#[test]
+fn validated_bytes_decode() {
+    fn check<E: Encoding>() {
+        TestRunner::default()
+            .run(&prop::collection::vec(any::<u8>(), 0..64), |bytes| {
+                let Ok(str) = Str::<E>::from_bytes(&bytes) else {
+                    return Ok(());
+                };
+                let chars = str.chars().collect::<Vec<_>>();
+                prop_assert_eq!(str.char_indices().count(), chars.len());
+                let encoded = encode::<E>(&chars);
+                let decoded = Str::<E>::from_bytes(&encoded).unwrap();
+                prop_assert!(decoded.chars().eq(chars.iter().copied()));
+                prop_assert_eq!(encode::<E>(&chars), encoded);
+                Ok(())
+            })
+            .unwrap();
+    }
+    check_all!(check);
+}
+
+/// Bytes which validate re-encode to the exact same bytes, for encodings where every character has
+/// only one encoding
+#[test]
+fn validated_bytes_roundtrip() {
+    fn check<E: Encoding>() {
+        TestRunner::default()
+            .run(&prop::collection::vec(any::<u8>(), 0..64), |bytes| {
+                if let Ok(str) = Str::<E>::from_bytes(&bytes) {
+                    let chars = str.chars().collect::<Vec<_>>();
+                    prop_assert_eq!(encode::<E>(&chars), bytes, "{}", E::shorthand());
+                }
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    macro_rules! check_one {
+        // Big5 maps a few characters to two different byte sequences
+        ($enc:ty, "big5") => {};
+        // Shift-JIS has double-byte forms of characters which are also single bytes
+        ($enc:ty, "shiftjis") => {};
+        ($enc:ty, "shiftjis_ascii") => {};
+        ($enc:ty, $name:literal) => {
+            check::<$enc>();
+        };
+    }
+    enrede::for_each_encoding!(check_one);
+}
+
+/// Lossy conversion always produces valid output
+#[test]
+fn lossy_validates() {
+    fn check<E: Encoding>() {
+        TestRunner::default()
+            .run(&prop::collection::vec(any::<u8>(), 0..64), |bytes| {
+                let lossy = String::<E>::from_bytes_lossy(&bytes);
+                prop_assert_eq!(E::validate(lossy.as_bytes()), Ok(()), "{}", E::shorthand());
+                lossy.chars().for_each(drop);
+                Ok(())
+            })
+            .unwrap();
+    }
+    check_all!(check);
+}
+
+/// Recoding into another encoding and back gives the original string, whenever every character is
+/// representable in the other encoding
+#[test]
+fn recode_roundtrip() {
+    fn check_pair<E: Encoding, E2: Encoding>(chars: &[char]) {
+        let mut runner = TestRunner::default();
+        let strategy = prop::collection::vec(select(chars.to_vec()), 0..32);
+        runner
+            .run(&strategy, |chars| {
+                let bytes = encode::<E>(&chars);
+                let str = Str::<E>::from_bytes(&bytes).unwrap();
+                match str.recode::<E2>() {
+                    Ok(other) => {
+                        prop_assert!(other.chars().eq(chars.iter().copied()));
+                        let back = other.recode::<E>().unwrap();
+                        prop_assert!(
+                            back.chars().eq(chars.iter().copied()),
+                            "{} -> {}",
+                            E::shorthand(),
+                            E2::shorthand()
+                        );
+                    }
+                    Err(err) => {
+                        prop_assert!(E2::encode_char(err.char()).is_none());
+                        prop_assert!(str[..err.valid_up_to()].recode::<E2>().is_ok());
+                    }
+                }
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    fn check<E: Encoding>() {
+        // Mostly ASCII, so strings are often representable in both encodings
+        let mut chars = encodable::<E>();
+        chars.truncate(0x100);
+        macro_rules! check_one {
+            ($enc:ty, $name:literal) => {
+                check_pair::<E, $enc>(&chars);
+            };
+        }
+        enrede::for_each_encoding!(check_one);
+    }
+    check_all!(check);
+}
+
+/// Lossy recoding replaces each unrepresentable character with exactly one replacement character,
+/// so never drops or duplicates characters, whatever the input
+#[test]
+fn recode_lossy_keeps_chars() {
+    fn check_pair<E: Encoding, E2: Encoding>() {
+        TestRunner::default()
+            .run(&prop::collection::vec(any::<u8>(), 0..64), |bytes| {
+                let str = String::<E>::from_bytes_lossy(&bytes);
+                let lossy = str.recode_lossy::<E2>();
+                prop_assert_eq!(
+                    lossy.chars().count(),
+                    str.chars().count(),
+                    "{} -> {}",
+                    E::shorthand(),
+                    E2::shorthand()
+                );
+                for (c, out) in str.chars().zip(lossy.chars()) {
+                    prop_assert!(out == c || out == E2::REPLACEMENT, "{:?} -> {:?}", c, out);
+                }
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    fn check<E: Encoding>() {
+        macro_rules! check_one {
+            ($enc:ty, $name:literal) => {
+                check_pair::<E, $enc>();
+            };
+        }
+        enrede::for_each_encoding!(check_one);
+    }
+    check_all!(check);
+}
+
+/// Lazily recoding bytes produces the same output as recoding into a `String`, for every pair of
+/// encodings
+#[test]
+fn recode_bytes_matches_recode() {
+    fn check_pair<E: Encoding, E2: Encoding>() {
+        TestRunner::default()
+            .run(&prop::collection::vec(any::<u8>(), 0..64), |bytes| {
+                let str = String::<E>::from_bytes_lossy(&bytes);
+                let lazy = str.recode_bytes::<E2>().collect::<Result<Vec<_>, _>>();
+                prop_assert_eq!(
+                    lazy,
+                    str.recode::<E2>().map(String::into_bytes),
+                    "{} -> {}",
+                    E::shorthand(),
+                    E2::shorthand()
+                );
+                let lazy = str.recode_bytes_lossy::<E2>().collect::<Vec<_>>();
+                prop_assert_eq!(lazy, str.recode_lossy::<E2>().into_bytes());
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    fn check<E: Encoding>() {
+        macro_rules! check_one {
+            ($enc:ty, $name:literal) => {
+                check_pair::<E, $enc>();
+            };
+        }
+        enrede::for_each_encoding!(check_one);
+    }
+    check_all!(check);
+}
+
+/// Escaping a string in any encoding matches escaping the same text with `std`
+#[test]
+fn escape_matches_std() {
+    fn check<E: Encoding>() {
+        let chars = encodable::<E>();
+        TestRunner::default()
+            .run(&prop::collection::vec(select(chars), 0..32), |chars| {
+                let bytes = encode::<E>(&chars);
+                let str = Str::<E>::from_bytes(&bytes).unwrap();
+                let text = chars.iter().collect::<std::string::String>();
+                prop_assert!(str.escape_debug().eq(text.escape_debug()), "{:?}", text);
+                prop_assert!(str.escape_default().eq(text.escape_default()), "{:?}", text);
+                prop_assert!(str.escape_bytes().all(|c| matches!(c, ' '..='~')));
+                Ok(())
+            })
+            .unwrap();
+    }
+    check_all!(check);
+}
+
+/// Random characters are always encodable, and every character of the non-Unicode encodings can
+/// be generated
+#[cfg(feature = "rand")]
+#[test]
+fn random_chars_encodable() {
+    use rand::distributions::Distribution;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn check<E: Encoding + Default + Distribution<char>>() {
+        let mut rng = StdRng::seed_from_u64(0x1871);
+        let encodable = encodable::<E>();
+        let complete = encodable.len() < 0x10000;
+        // Enough samples that missing a character by chance is vanishingly unlikely
+        let samples = if complete {
+            usize::max(100_000, encodable.len() * 20)
+        } else {
+            100_000
+        };
+
+        let mut seen = vec![false; 0x110000];
+        for _ in 0..samples {
+            let c = rng.sample(E::default());
+            assert!(E::encode_char(c).is_some(), "{}: {:?}", E::shorthand(), c);
+            seen[c as usize] = true;
+        }
+        if complete {
+            for c in encodable {
+                assert!(
+                    seen[c as usize],
+                    "{}: {:?} never generated",
+                    E::shorthand(),
+                    c
+                );
+            }
+        }
+    }
+    check_all!(check);
+}
+
+/// Reading tagged strings never panics on malformed input, and anything read successfully is
+/// exactly what writing the string back out would produce
+#[test]
+fn tagged_bytes_malformed() {
+    use enrede::tagged::{from_tagged_bytes, VERSION};
+
+    fn check(bytes: &[u8]) -> Result<(), TestCaseError> {
+        if let Ok(tagged) = from_tagged_bytes(bytes) {
+            let tag = tagged.kind().shorthand();
+            let expected = [
+                &[VERSION, tag.len() as u8],
+                tag.as_bytes(),
+                tagged.as_bytes(),
+            ]
+            .concat();
+            prop_assert_eq!(expected, bytes);
+        }
+        Ok(())
+    }
+
+    TestRunner::default()
+        .run(&prop::collection::vec(any::<u8>(), 0..32), |bytes| {
+            check(&bytes)
+        })
+        .unwrap();
+
+    // Corrupt single bytes of valid tagged strings, so malformed headers are close to valid ones
+    let tags = select(enrede::encoding::ALL_SHORTHANDS);
+    let strategy = (
+        tags,
+        prop::collection::vec(any::<u8>(), 0..16),
+        any::<usize>(),
+        any::<u8>(),
+    );
+    TestRunner::default()
+        .run(&strategy, |(tag, data, idx, byte)| {
+            let mut bytes = [&[VERSION, tag.len() as u8], tag.as_bytes(), &data].concat();
+            let idx = idx % bytes.len();
+            bytes[idx] = byte;
+            check(&bytes)
+        })
+        .unwrap();
+}
+
+/// Lossy character iterators yield the same characters as lossy conversion, with spans tiling the
+/// input
+#[test]
+fn lossy_chars_match_lossy() {
+    use enrede::lossy::{LossyCharIndices, LossyChars};
+
+    fn check<E: Encoding>() {
+        TestRunner::default()
+            .run(&prop::collection::vec(any::<u8>(), 0..64), |bytes| {
+                let lossy = String::<E>::from_bytes_lossy(&bytes);
+                let chars = LossyChars::<E>::new(&bytes).collect::<Vec<_>>();
+                prop_assert_eq!(
+                    &chars,
+                    &lossy.chars().collect::<Vec<_>>(),
+                    "{}",
+                    E::shorthand()
+                );
+
+                let mut end = 0;
+                for (span, c) in LossyCharIndices::<E>::new(&bytes) {
+                    prop_assert_eq!(span.start, end, "{}", E::shorthand());
+                    prop_assert!(span.end > span.start);
+                    if let Ok(str) = Str::<E>::from_bytes(&bytes[span.clone()]) {
+                        prop_assert_eq!(str.chars().collect::<Vec<_>>(), [c]);
+                    } else {
+                        prop_assert_eq!(c, E::REPLACEMENT);
+                    }
+                    end = span.end;
+                }
+                prop_assert_eq!(end, bytes.len());
+                Ok(())
+            })
+            .unwrap();
+    }
+    check_all!(check);
+}
+
+/// Comparing strings by characters agrees with comparing their characters, within and across
+/// encodings
+#[test]
+fn cmp_chars_matches_chars() {
+    fn check<E: Encoding>() {
+        let chars = encodable::<E>();
+        let strategy = prop::collection::vec(select(chars), 0..8);
+        TestRunner::default()
+            .run(&(strategy.clone(), strategy), |(a, b)| {
+                let (a_bytes, b_bytes) = (encode::<E>(&a), encode::<E>(&b));
+                let a_str = Str::<E>::from_bytes(&a_bytes).unwrap();
+                let b_str = Str::<E>::from_bytes(&b_bytes).unwrap();
+                prop_assert_eq!(a_str.cmp_chars(b_str), a.cmp(&b), "{}", E::shorthand());
+                prop_assert_eq!(a_str.cmp_bytes(b_str), a_bytes.cmp(&b_bytes));
+
+                let b_std = b.iter().collect::<std::string::String>();
+                let cross = a_str.cmp_chars_cross(Str::from_std(&b_std));
+                prop_assert_eq!(cross, a.cmp(&b), "{}", E::shorthand());
+                Ok(())
+            })
+            .unwrap();
+    }
+    check_all!(check);
+}
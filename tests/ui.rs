@@ -0,0 +1,10 @@
+//! Checks that misuse of the API fails to compile, with the expected error rather than any error.
+//! Run with `TRYBUILD=overwrite` to update the expected output after a compiler upgrade.
+
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+    #[cfg(feature = "alloc")]
+    cases.compile_fail("tests/ui/alloc/*.rs");
+}
@@ -0,0 +1,22 @@
+//! `CString::new` and `CString::try_new` skip validation for input claiming to be valid, so
+//! `IntoCStringInput` can't be implemented outside the crate.
+
+use enrede::cstring::IntoCStringInput;
+use enrede::encoding::Utf8;
+use enrede::CString;
+
+struct Invalid;
+
+impl IntoCStringInput<Utf8> for Invalid {
+    fn into_input(self) -> (Vec<u8>, bool) {
+        (vec![0xFF, 0xFE], true)
+    }
+
+    fn try_into_input(self) -> Result<(Vec<u8>, bool), std::collections::TryReserveError> {
+        Ok((vec![0xFF, 0xFE], true))
+    }
+}
+
+fn main() {
+    let _ = CString::<Utf8>::try_new(Invalid);
+}
@@ -0,0 +1,10 @@
+//! C-strings can only be sliced with ranges that have no end, as any other end could cut off the
+//! terminating null byte.
+
+use enrede::encoding::Utf8;
+use enrede::CStr;
+
+fn main() {
+    let str = CStr::<Utf8>::from_bytes_with_nul(b"abc\0").unwrap();
+    let _ = &str[1..2];
+}
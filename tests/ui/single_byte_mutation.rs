@@ -0,0 +1,11 @@
+//! In-place ASCII case conversion requires a `SingleByte` encoding, as changing a byte of a
+//! multi-byte encoding may split or merge characters.
+
+use enrede::encoding::Utf8;
+use enrede::Str;
+
+fn main() {
+    let mut bytes = *b"abc";
+    let str = Str::<Utf8>::from_bytes_mut(&mut bytes).unwrap();
+    str.make_ascii_uppercase();
+}